@@ -0,0 +1,124 @@
+//! Render benchmarks for the widgets with the busiest render paths
+//!
+//! Run with `cargo bench --bench render`. Each case renders the same frame
+//! repeatedly for about a second and prints the mean time per frame.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer, layout::Rect, widgets::Widget};
+use ratatui_interact::components::{
+    ContextMenu, ContextMenuItem, ContextMenuState, ContextMenuStyle, DiffViewer, DiffViewerState,
+    ListPicker, ListPickerState, Menu, MenuBar, MenuBarItem, MenuBarState, MenuBarStyle,
+};
+
+/// Run `frame` repeatedly and report the mean time per call
+fn bench(name: &str, mut frame: impl FnMut()) {
+    for _ in 0..10 {
+        frame();
+    }
+    let start = Instant::now();
+    let mut frames = 0u32;
+    while start.elapsed() < Duration::from_secs(1) {
+        frame();
+        frames += 1;
+    }
+    println!(
+        "{:<28} {:>12.2?}/frame ({} frames)",
+        name,
+        start.elapsed() / frames,
+        frames
+    );
+}
+
+/// Unified diff text with `hunks` hunks of 8 lines each
+fn diff_text(hunks: usize) -> String {
+    let mut text = String::from("--- a/src/lib.rs\n+++ b/src/lib.rs\n");
+    for hunk in 0..hunks {
+        let start = hunk * 10 + 1;
+        text.push_str(&format!("@@ -{start},6 +{start},7 @@ fn hunk_{hunk}()\n"));
+        text.push_str("     let value = compute(input, options);\n");
+        text.push_str("-    if value > threshold {\n");
+        text.push_str("-        return Err(Error::TooLarge(value));\n");
+        text.push_str("+    if value > threshold && !options.allow_large {\n");
+        text.push_str("+        return Err(Error::TooLarge { value, threshold });\n");
+        text.push_str("+    }\n");
+        text.push_str("     Ok(value)\n");
+    }
+    text
+}
+
+fn main() {
+    // 50-item dropdown
+    let items: Vec<MenuBarItem> = (0..50)
+        .map(|i| {
+            MenuBarItem::action(format!("item{i}"), format!("Menu item {i}")).shortcut("Ctrl+K")
+        })
+        .collect();
+    let menus = vec![
+        Menu::new("File").items(items),
+        Menu::new("Edit"),
+        Menu::new("View"),
+    ];
+    let mut menu_state = MenuBarState::new();
+    menu_state.open_menu(0);
+    let mut terminal = Terminal::new(TestBackend::new(80, 60)).unwrap();
+    bench("menu_bar dropdown (50)", || {
+        terminal
+            .draw(|frame| {
+                let style = MenuBarStyle::default().dropdown_max_height(50);
+                let menu_bar = MenuBar::new(&menus, &menu_state).style(style);
+                black_box(menu_bar.render_stateful(frame, frame.area()));
+            })
+            .unwrap();
+    });
+
+    let context_items: Vec<ContextMenuItem> = (0..50)
+        .map(|i| {
+            ContextMenuItem::action(format!("item{i}"), format!("Context item {i}"))
+                .icon("•")
+                .shortcut("Ctrl+K")
+        })
+        .collect();
+    let mut context_state = ContextMenuState::new();
+    context_state.open_at(2, 2);
+    bench("context_menu (50)", || {
+        terminal
+            .draw(|frame| {
+                let style = ContextMenuStyle::default().max_visible_items(50);
+                let menu = ContextMenu::new(&context_items, &context_state).style(style);
+                black_box(menu.render_stateful(frame, frame.area()));
+            })
+            .unwrap();
+    });
+
+    // 5k-line diff
+    let diff = diff_text(625);
+    let area = Rect::new(0, 0, 160, 50);
+    let mut buf = Buffer::empty(area);
+    let mut diff_state = DiffViewerState::from_unified_diff(&diff);
+    diff_state.scroll_y = 2500;
+    bench("diff_viewer unified (5k)", || {
+        DiffViewer::new(&diff_state).render(area, &mut buf);
+        black_box(&buf);
+    });
+    diff_state.toggle_view_mode();
+    bench("diff_viewer side-by-side (5k)", || {
+        DiffViewer::new(&diff_state).render(area, &mut buf);
+        black_box(&buf);
+    });
+
+    // 50-item list
+    let entries: Vec<String> = (0..50).map(|i| format!("List entry {i}")).collect();
+    let picker_state = ListPickerState::new(entries.len());
+    bench("list_picker (50)", || {
+        ListPicker::new(&entries, &picker_state).render(area, &mut buf);
+        black_box(&buf);
+    });
+    bench("list_picker search (50)", || {
+        ListPicker::new(&entries, &picker_state)
+            .search("entry 1")
+            .render(area, &mut buf);
+        black_box(&buf);
+    });
+}
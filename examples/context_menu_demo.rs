@@ -6,11 +6,14 @@
 //! - Mouse click support
 //! - Actions with icons and shortcuts
 //! - Disabled items
-//! - Submenus
+//! - Submenus (open on click, or after hovering briefly)
 //!
 //! Run with: cargo run --example context_menu_demo
 
-use std::io;
+use std::{
+    io,
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
@@ -241,6 +244,16 @@ fn main() -> io::Result<()> {
     loop {
         terminal.draw(|f| ui(f, &mut app))?;
 
+        // Open hovered submenus once the hover delay has elapsed
+        app.context_menu_state.tick_hover(
+            Instant::now(),
+            ContextMenuStyle::default().submenu_hover_delay_ms,
+        );
+
+        if !event::poll(Duration::from_millis(50))? {
+            continue;
+        }
+
         if let Ok(event) = event::read() {
             match event {
                 Event::Key(key) => {
@@ -150,23 +150,17 @@ impl App {
                         content.email.move_end();
                     }
                 }
-                2 => {
-                    // Dark mode checkbox
-                    if is_activate_key(key) {
-                        content.dark_mode.toggle();
-                    }
+                // Dark mode checkbox
+                2 if is_activate_key(key) => {
+                    content.dark_mode.toggle();
                 }
-                3 => {
-                    // Notifications checkbox
-                    if is_activate_key(key) {
-                        content.notifications.toggle();
-                    }
+                // Notifications checkbox
+                3 if is_activate_key(key) => {
+                    content.notifications.toggle();
                 }
-                4 => {
-                    // Auto-save checkbox
-                    if is_activate_key(key) {
-                        content.auto_save.toggle();
-                    }
+                // Auto-save checkbox
+                4 if is_activate_key(key) => {
+                    content.auto_save.toggle();
                 }
                 _ => {}
             }
@@ -234,7 +228,6 @@ fn main() -> io::Result<()> {
                 );
 
                 let result = dialog.handle_key(key);
-                drop(dialog); // Drop dialog to release borrow
 
                 match result {
                     EventResult::Action(ContainerAction::Submit) => {
@@ -265,7 +258,6 @@ fn main() -> io::Result<()> {
                 let mut dialog = PopupDialog::new(&app.config, &mut app.dialog_state, |_, _, _| {});
 
                 let result = dialog.handle_mouse_with_screen(mouse, screen);
-                drop(dialog); // Drop dialog to release borrow
 
                 match result {
                     EventResult::Action(ContainerAction::Submit) => {
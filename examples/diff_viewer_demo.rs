@@ -232,7 +232,7 @@ fn ui(f: &mut Frame, app: &mut App) {
     let title = format!(
         "{} - {}",
         app.tab_names[app.selected_tab],
-        state.diff.old_path.as_deref().unwrap_or("unknown")
+        state.diff().old_path.as_deref().unwrap_or("unknown")
     );
 
     // Update visible dimensions in state
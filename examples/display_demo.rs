@@ -310,15 +310,11 @@ impl App {
                     self.paragraph_scroll += 1;
                 }
             },
-            KeyCode::Left => {
-                if self.focused_panel == FocusedPanel::Progress {
-                    self.progress_style_idx = (self.progress_style_idx + 3) % 4;
-                }
+            KeyCode::Left if self.focused_panel == FocusedPanel::Progress => {
+                self.progress_style_idx = (self.progress_style_idx + 3) % 4;
             }
-            KeyCode::Right => {
-                if self.focused_panel == FocusedPanel::Progress {
-                    self.progress_style_idx = (self.progress_style_idx + 1) % 4;
-                }
+            KeyCode::Right if self.focused_panel == FocusedPanel::Progress => {
+                self.progress_style_idx = (self.progress_style_idx + 1) % 4;
             }
             _ => {}
         }
@@ -349,10 +349,8 @@ fn main() -> io::Result<()> {
                 Event::Key(key) => {
                     app.handle_key(key);
                 }
-                Event::Mouse(mouse) => {
-                    if is_left_click(&mouse) {
-                        app.handle_click(mouse.column, mouse.row);
-                    }
+                Event::Mouse(mouse) if is_left_click(&mouse) => {
+                    app.handle_click(mouse.column, mouse.row);
                 }
                 _ => {}
             }
@@ -28,7 +28,7 @@ use ratatui::{
 
 use ratatui_interact::components::{
     Menu, MenuBar, MenuBarAction, MenuBarItem, MenuBarState, MenuBarStyle, Orientation,
-    SplitPaneAction, SplitPaneState, calculate_menu_bar_height, handle_menu_bar_key,
+    SplitPaneAction, SplitPaneState, calculate_menu_bar_height, handle_menu_bar_key_with_style,
     handle_menu_bar_mouse, handle_split_pane_mouse,
 };
 use ratatui_interact::events::is_close_key;
@@ -253,11 +253,13 @@ fn main() -> io::Result<()> {
 
         match event::read()? {
             Event::Key(key) => {
+                let style = app.current_style();
                 if is_close_key(&key) && !app.menu_state.is_open {
                     app.should_quit = true;
                 } else if key.code == KeyCode::Char('t') && !app.menu_state.is_open {
                     app.cycle_style();
-                } else if let Some(action) = handle_menu_bar_key(&key, &mut app.menu_state, &menus)
+                } else if let Some(action) =
+                    handle_menu_bar_key_with_style(&key, &mut app.menu_state, &menus, &style)
                 {
                     app.handle_action(action);
                 }
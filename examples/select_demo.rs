@@ -166,7 +166,7 @@ impl App {
             SelectAction::Close => {
                 self.message = "Dropdown closed.".to_string();
             }
-            SelectAction::Select(idx) => {
+            SelectAction::Select(idx) | SelectAction::Changed(idx) => {
                 let (name, value) = match self.focused {
                     FocusedSelect::Color => ("Color", self.colors[idx]),
                     FocusedSelect::Size => ("Size", self.sizes[idx]),
@@ -174,7 +174,7 @@ impl App {
                 };
                 self.message = format!("Selected {}: {}", name, value);
             }
-            SelectAction::Focus => {}
+            SelectAction::Focus | SelectAction::RetryRequested => {}
         }
     }
 
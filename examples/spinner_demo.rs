@@ -83,9 +83,8 @@ impl App {
     }
 
     fn tick_all(&mut self) {
-        for (i, state) in self.spinner_states.iter_mut().enumerate() {
-            let frame_count = SPINNER_TYPES[i].frames().len();
-            state.tick_with_frames(frame_count);
+        for state in &mut self.spinner_states {
+            state.advance_if_ready();
         }
     }
 
@@ -213,7 +212,7 @@ fn ui(f: &mut Frame, app: &mut App) {
 
 fn render_spinners(f: &mut Frame, app: &mut App, area: Rect) {
     // Split into rows of 3 spinners each
-    let rows_count = (SPINNER_TYPES.len() + 2) / 3;
+    let rows_count = SPINNER_TYPES.len().div_ceil(3);
     let row_constraints: Vec<Constraint> = (0..rows_count)
         .map(|_| Constraint::Length(3))
         .chain(std::iter::once(Constraint::Min(0)))
@@ -69,10 +69,11 @@ Try these shortcuts:
 - Ctrl+W: Delete word backward
 - Ctrl+D: Delete current line
 - Ctrl+L: Toggle line numbers
+- Ctrl+/: Toggle # comment on the current line
 
 Press Esc to quit."#;
 
-        let mut textarea = TextAreaState::new(initial_text);
+        let mut textarea = TextAreaState::new(initial_text).with_comment_prefix("#");
         textarea.focused = true;
         textarea.tab_config = TabConfig::Spaces(4);
 
@@ -139,6 +140,11 @@ fn main() -> io::Result<()> {
                 } else if key.code == KeyCode::Char('l') && has_ctrl(&key) {
                     // Toggle line numbers with Ctrl+L
                     app.show_line_numbers = !app.show_line_numbers;
+                } else if matches!(key.code, KeyCode::Char('/') | KeyCode::Char('7'))
+                    && has_ctrl(&key)
+                {
+                    // Ctrl+/ arrives as Ctrl+7 on many terminals
+                    app.textarea.toggle_line_comment();
                 } else if key.code == KeyCode::Left {
                     if has_ctrl(&key) {
                         app.textarea.move_word_left();
@@ -163,16 +169,14 @@ fn main() -> io::Result<()> {
                     app.textarea.insert_char(c);
                 }
             }
-            Event::Mouse(mouse) => {
-                if is_left_click(&mouse) {
-                    if app
+            Event::Mouse(mouse)
+                if is_left_click(&mouse)
+                    && app
                         .click_regions
                         .handle_click(mouse.column, mouse.row)
-                        .is_some()
-                    {
-                        app.textarea.focused = true;
-                    }
-                }
+                        .is_some() =>
+            {
+                app.textarea.focused = true;
             }
             _ => {}
         }
@@ -285,6 +289,8 @@ fn ui(f: &mut Frame, app: &mut App) {
         Line::from(vec![
             Span::styled("Ctrl+L", Style::default().fg(Color::Yellow)),
             Span::raw(": Toggle line numbers  "),
+            Span::styled("Ctrl+/", Style::default().fg(Color::Yellow)),
+            Span::raw(": Comment  "),
             Span::styled("Tab", Style::default().fg(Color::Yellow)),
             Span::raw(": Insert spaces  "),
             Span::styled("Esc", Style::default().fg(Color::Yellow)),
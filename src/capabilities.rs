@@ -0,0 +1,211 @@
+//! Terminal capabilities and ASCII glyph fallbacks.
+//!
+//! Several widget styles default to Unicode glyphs (box drawing, braille,
+//! block elements) that render as tofu on some terminals and fonts, and to
+//! RGB colors that terminals without truecolor approximate poorly.
+//! [`Capabilities`] describes what the terminal supports; styles with a
+//! `from_capabilities` constructor use it to pick their default glyphs or a
+//! pure-ASCII set, and to fall back to the 256-color palette.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::capabilities::Capabilities;
+//! use ratatui_interact::components::{ContextMenuStyle, TreeStyle};
+//!
+//! // Build it by hand, e.g. from a command line flag...
+//! let caps = Capabilities::new(false, true);
+//!
+//! // ...or from environment variables (see `Capabilities::detect`)
+//! let caps = Capabilities::from_env(|name| match name {
+//!     "LANG" => Some("C".to_string()),
+//!     _ => None,
+//! });
+//! assert!(!caps.unicode);
+//!
+//! let tree = TreeStyle::from_capabilities(&caps);
+//! assert_eq!(tree.connector_last, "`-- ");
+//! let menu = ContextMenuStyle::from_capabilities(&caps);
+//! assert_eq!(menu.submenu_indicator, ">");
+//! ```
+
+use ratatui::{
+    style::{Color, Style},
+    symbols::{border, scrollbar},
+};
+
+/// Border set using only ASCII characters.
+pub const ASCII_BORDER: border::Set<'static> = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+/// Vertical scrollbar symbols using only ASCII characters.
+pub const ASCII_VERTICAL_SCROLLBAR: scrollbar::Set<'static> = scrollbar::Set {
+    track: "|",
+    thumb: "#",
+    begin: "^",
+    end: "v",
+};
+
+/// Horizontal scrollbar symbols using only ASCII characters.
+pub const ASCII_HORIZONTAL_SCROLLBAR: scrollbar::Set<'static> = scrollbar::Set {
+    track: "-",
+    thumb: "#",
+    begin: "<",
+    end: ">",
+};
+
+/// What the terminal can display.
+///
+/// Defaults to full support, which matches the default widget styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// Unicode glyphs (box drawing, block elements, braille) render correctly.
+    pub unicode: bool,
+    /// 24-bit RGB colors are supported.
+    pub truecolor: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
+impl Capabilities {
+    /// Create capabilities from explicit flags.
+    pub const fn new(unicode: bool, truecolor: bool) -> Self {
+        Self { unicode, truecolor }
+    }
+
+    /// Unicode and truecolor support.
+    pub const fn full() -> Self {
+        Self::new(true, true)
+    }
+
+    /// ASCII glyphs and the 256-color palette only.
+    pub const fn basic() -> Self {
+        Self::new(false, false)
+    }
+
+    /// Best-effort guess from environment variables, read through `lookup`.
+    ///
+    /// Unicode is assumed when the locale (`LC_ALL`, then `LC_CTYPE`, then
+    /// `LANG`) names a UTF-8 codeset and `TERM` is not `dumb`. Truecolor is
+    /// assumed when `COLORTERM` is `truecolor` or `24bit`.
+    pub fn from_env(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .filter_map(&lookup)
+            .find(|value| !value.is_empty())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let utf8 = locale.contains("utf-8") || locale.contains("utf8");
+        let dumb = lookup("TERM").is_some_and(|term| term == "dumb");
+        let truecolor = lookup("COLORTERM")
+            .is_some_and(|value| matches!(value.as_str(), "truecolor" | "24bit"));
+        Self::new(utf8 && !dumb, truecolor)
+    }
+
+    /// Best-effort guess from the process environment; see [`from_env`](Self::from_env).
+    #[cfg(feature = "detect-capabilities")]
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok())
+    }
+
+    /// The plain box-drawing border set, or [`ASCII_BORDER`].
+    pub fn border_set(&self) -> border::Set<'static> {
+        if self.unicode {
+            border::PLAIN
+        } else {
+            ASCII_BORDER
+        }
+    }
+
+    /// `color` as the terminal can show it.
+    ///
+    /// Without truecolor, RGB colors map to the nearest entry of the
+    /// 256-color palette; other colors are returned unchanged.
+    pub fn color(&self, color: Color) -> Color {
+        match color {
+            Color::Rgb(r, g, b) if !self.truecolor => Color::Indexed(rgb_to_256(r, g, b)),
+            other => other,
+        }
+    }
+
+    /// `style` with its colors adapted by [`color`](Self::color).
+    pub fn style(&self, mut style: Style) -> Style {
+        style.fg = style.fg.map(|c| self.color(c));
+        style.bg = style.bg.map(|c| self.color(c));
+        style.underline_color = style.underline_color.map(|c| self.color(c));
+        style
+    }
+}
+
+/// Nearest xterm 256-color index: the grayscale ramp for grays, otherwise
+/// the 6x6x6 color cube.
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..8 => 16,
+            249.. => 231,
+            _ => 232 + ((r as u16 - 8) * 24 / 241) as u8,
+        };
+    }
+    let level = |c: u8| ((c as u16 * 5 + 127) / 255) as u8;
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+    }
+
+    #[test]
+    fn test_from_env() {
+        let caps = Capabilities::from_env(env(&[
+            ("LANG", "en_US.UTF-8"),
+            ("TERM", "xterm-256color"),
+            ("COLORTERM", "truecolor"),
+        ]));
+        assert_eq!(caps, Capabilities::full());
+
+        // LC_ALL wins over LANG
+        let caps = Capabilities::from_env(env(&[("LC_ALL", "C"), ("LANG", "en_US.utf8")]));
+        assert_eq!(caps, Capabilities::basic());
+
+        let caps = Capabilities::from_env(env(&[("LANG", "en_US.UTF-8"), ("TERM", "dumb")]));
+        assert!(!caps.unicode);
+
+        assert_eq!(Capabilities::from_env(env(&[])), Capabilities::basic());
+    }
+
+    #[test]
+    fn test_color_downgrade() {
+        let full = Capabilities::full();
+        let basic = Capabilities::basic();
+        assert_eq!(full.color(Color::Rgb(1, 2, 3)), Color::Rgb(1, 2, 3));
+        assert_eq!(basic.color(Color::Red), Color::Red);
+        assert_eq!(basic.color(Color::Rgb(255, 0, 0)), Color::Indexed(196));
+        assert_eq!(basic.color(Color::Rgb(0, 0, 0)), Color::Indexed(16));
+        assert_eq!(basic.color(Color::Rgb(40, 40, 40)), Color::Indexed(235));
+
+        let style = basic.style(Style::default().fg(Color::Rgb(0, 40, 0)).bg(Color::Blue));
+        assert_eq!(style.fg, Some(Color::Indexed(22)));
+        assert_eq!(style.bg, Some(Color::Blue));
+    }
+}
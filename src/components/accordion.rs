@@ -45,17 +45,53 @@
 //!         // Render answer content here
 //!     });
 //! ```
+//!
+//! # Scrolling
+//!
+//! When the sections don't fit, call [`calculate_layout`] before rendering.
+//! It records the viewport in the state so the mouse wheel and
+//! PageUp/PageDown can scroll, keeps the visible content in place when a
+//! section above the viewport collapses, and scrolls a newly expanded
+//! focused section into view. The returned header areas can be passed
+//! straight to [`handle_accordion_mouse`].
+//!
+//! With [`AccordionStyle::sticky_headers`], an expanded section whose header
+//! has scrolled out of view keeps it pinned to the top row while any of its
+//! content is visible. Use [`AccordionLayout::header_areas_with_sticky`] so
+//! clicks on the pinned header toggle its section.
+//!
+//! # Filtering
+//!
+//! Register section titles with [`AccordionState::set_sections`], then call
+//! [`AccordionState::apply_filter`] to hide sections whose titles don't
+//! match and expand the ones that do. With
+//! [`with_filterable`](AccordionState::with_filterable), a filter bar is
+//! drawn on the bottom row and `/` starts typing into it.
 
 use std::collections::HashSet;
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Paragraph, Widget},
+    widgets::{Paragraph, ScrollbarOrientation, Widget},
 };
 
+use super::input::InputState;
+use super::interactive_scrollbar::InteractiveScrollbar;
+use super::option_details::{find_matches, highlight_spans};
+
+/// Content height used when no height is given for an item
+const DEFAULT_CONTENT_HEIGHT: u16 = 3;
+
+/// Content rows kept visible below a newly expanded section's header
+const REVEAL_CONTENT_ROWS: u16 = 3;
+
+/// Lines scrolled per mouse wheel step
+const WHEEL_SCROLL_LINES: u16 = 3;
+
 /// Expansion mode for the accordion
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AccordionMode {
@@ -79,6 +115,22 @@ pub struct AccordionState {
     pub mode: AccordionMode,
     /// Scroll offset for when content exceeds viewport
     pub scroll: u16,
+    /// Viewport height from the last [`calculate_layout`]
+    pub visible_height: u16,
+    /// Screen area from the last [`calculate_layout`], for wheel hit-testing
+    area: Rect,
+    /// Item heights from the last [`calculate_layout`]
+    item_heights: Vec<u16>,
+    /// Active filter query, set by [`apply_filter`](Self::apply_filter)
+    pub filter_query: Option<String>,
+    /// Whether a filter bar is shown at the bottom of the accordion
+    pub filterable: bool,
+    /// Text of the filter bar; focused while the filter is being typed
+    pub filter_input: InputState,
+    /// Section `(id, title)` pairs matched by [`apply_filter`](Self::apply_filter)
+    sections: Vec<(String, String)>,
+    /// Indices of the sections hidden by the filter
+    hidden: HashSet<usize>,
 }
 
 impl AccordionState {
@@ -90,6 +142,14 @@ impl AccordionState {
             total_items,
             mode: AccordionMode::Multiple,
             scroll: 0,
+            visible_height: 0,
+            area: Rect::default(),
+            item_heights: Vec::new(),
+            filter_query: None,
+            filterable: false,
+            filter_input: InputState::default(),
+            sections: Vec::new(),
+            hidden: HashSet::new(),
         }
     }
 
@@ -116,6 +176,101 @@ impl AccordionState {
         self
     }
 
+    /// Show a filter bar at the bottom of the accordion
+    pub fn with_filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Set the `(id, title)` of each section, in item order, for filtering
+    pub fn with_sections<K, V>(mut self, sections: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.set_sections(sections);
+        self
+    }
+
+    /// Replace the `(id, title)` of each section and re-apply the filter
+    pub fn set_sections<K, V>(&mut self, sections: impl IntoIterator<Item = (K, V)>)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.sections = sections
+            .into_iter()
+            .map(|(id, title)| (id.into(), title.into()))
+            .collect();
+        if let Some(query) = self.filter_query.clone() {
+            self.apply_filter(&query);
+        }
+    }
+
+    /// Hide sections whose titles don't contain `query` (case-insensitive)
+    ///
+    /// Matching sections are expanded; in single mode only the first one is,
+    /// unless a matching section is already expanded. If the focused section
+    /// is hidden, focus moves to the first match. An empty query clears the
+    /// filter.
+    pub fn apply_filter(&mut self, query: &str) {
+        if query.is_empty() {
+            self.clear_filter();
+            return;
+        }
+        if self.filter_input.text() != query {
+            self.filter_input.set_text(query);
+        }
+        self.filter_query = Some(query.to_string());
+
+        let matches: Vec<usize> = (0..self.sections.len())
+            .filter(|&idx| !find_matches(&self.sections[idx].1, query).is_empty())
+            .collect();
+        self.hidden = (0..self.sections.len())
+            .filter(|idx| !matches.contains(idx))
+            .collect();
+
+        match self.mode {
+            AccordionMode::Multiple => {
+                for &idx in &matches {
+                    self.expanded.insert(self.sections[idx].0.clone());
+                }
+            }
+            AccordionMode::Single => {
+                let any_expanded = matches
+                    .iter()
+                    .any(|&idx| self.is_expanded(&self.sections[idx].0));
+                if !any_expanded && let Some(&first) = matches.first() {
+                    let id = self.sections[first].0.clone();
+                    self.expand(&id);
+                }
+            }
+        }
+
+        if self.is_hidden(self.focused_index)
+            && let Some(&first) = matches.first()
+        {
+            self.focused_index = first;
+        }
+    }
+
+    /// Remove the filter and show every section again
+    pub fn clear_filter(&mut self) {
+        self.filter_query = None;
+        self.filter_input.clear();
+        self.hidden.clear();
+    }
+
+    /// Check if a section is hidden by the filter
+    pub fn is_hidden(&self, index: usize) -> bool {
+        self.hidden.contains(&index)
+    }
+
+    /// Number of sections not hidden by the filter
+    pub fn visible_count(&self) -> usize {
+        self.total_items - self.hidden.len().min(self.total_items)
+    }
+
     /// Toggle the expansion state of an item
     pub fn toggle(&mut self, id: &str) {
         if self.expanded.contains(id) {
@@ -180,16 +335,33 @@ impl AccordionState {
         self.expanded.clear();
     }
 
-    /// Move focus to the next item
+    /// Move focus to the next item, skipping hidden sections
     pub fn focus_next(&mut self) {
-        if self.focused_index + 1 < self.total_items {
-            self.focused_index += 1;
+        if let Some(idx) = (self.focused_index + 1..self.total_items).find(|&i| !self.is_hidden(i))
+        {
+            self.focused_index = idx;
         }
     }
 
-    /// Move focus to the previous item
+    /// Move focus to the previous item, skipping hidden sections
     pub fn focus_prev(&mut self) {
-        self.focused_index = self.focused_index.saturating_sub(1);
+        if let Some(idx) = (0..self.focused_index).rev().find(|&i| !self.is_hidden(i)) {
+            self.focused_index = idx;
+        }
+    }
+
+    /// Move focus to the first visible item
+    pub fn focus_first(&mut self) {
+        if let Some(idx) = (0..self.total_items).find(|&i| !self.is_hidden(i)) {
+            self.focused_index = idx;
+        }
+    }
+
+    /// Move focus to the last visible item
+    pub fn focus_last(&mut self) {
+        if let Some(idx) = (0..self.total_items).rev().find(|&i| !self.is_hidden(i)) {
+            self.focused_index = idx;
+        }
     }
 
     /// Set focus to a specific index
@@ -237,6 +409,105 @@ impl AccordionState {
             self.scroll = (focused_start + focused_height).saturating_sub(viewport_height);
         }
     }
+
+    /// Total height of all items from the last [`calculate_layout`]
+    pub fn content_height(&self) -> u16 {
+        self.item_heights
+            .iter()
+            .fold(0u16, |total, &h| total.saturating_add(h))
+    }
+
+    /// Largest scroll offset that still fills the viewport
+    pub fn max_scroll(&self) -> u16 {
+        self.content_height().saturating_sub(self.visible_height)
+    }
+
+    /// Scroll up by the given number of lines
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Scroll down by the given number of lines
+    pub fn scroll_down(&mut self, lines: u16) {
+        self.scroll = self.scroll.saturating_add(lines).min(self.max_scroll());
+    }
+
+    /// Scroll up by one viewport
+    pub fn page_up(&mut self) {
+        self.scroll_up(self.visible_height.max(1));
+    }
+
+    /// Scroll down by one viewport
+    pub fn page_down(&mut self) {
+        self.scroll_down(self.visible_height.max(1));
+    }
+
+    /// Record a new layout and adjust the scroll offset for it
+    fn apply_layout(&mut self, item_heights: Vec<u16>, area: Rect) {
+        let previous = std::mem::replace(&mut self.item_heights, item_heights);
+        self.area = area;
+        self.visible_height = area.height;
+
+        if previous.len() == self.item_heights.len() {
+            self.anchor_scroll(&previous);
+            self.reveal_expanded_focus(&previous);
+        }
+        self.scroll = self.scroll.min(self.max_scroll());
+    }
+
+    /// Keep the row at the top of the viewport in place when items above it
+    /// change height. If that row disappeared, show its item's header.
+    fn anchor_scroll(&mut self, previous: &[u16]) {
+        let (mut old_top, mut new_top) = (0u16, 0u16);
+        for (&before, &after) in previous.iter().zip(&self.item_heights) {
+            if old_top.saturating_add(before) > self.scroll {
+                let offset = self.scroll - old_top;
+                self.scroll = if offset < after {
+                    new_top.saturating_add(offset)
+                } else {
+                    new_top
+                };
+                return;
+            }
+            old_top = old_top.saturating_add(before);
+            new_top = new_top.saturating_add(after);
+        }
+    }
+
+    /// Scroll so the focused item's header and first content rows are
+    /// visible if it was just expanded
+    fn reveal_expanded_focus(&mut self, previous: &[u16]) {
+        let idx = self.focused_index;
+        let (Some(&before), Some(&after)) = (previous.get(idx), self.item_heights.get(idx)) else {
+            return;
+        };
+        if after <= before {
+            return;
+        }
+        let top = self.item_heights[..idx]
+            .iter()
+            .fold(0u16, |total, &h| total.saturating_add(h));
+        let needed = after.min(1 + REVEAL_CONTENT_ROWS).min(self.visible_height);
+        if top < self.scroll {
+            self.scroll = top;
+        } else if top.saturating_add(needed) > self.scroll.saturating_add(self.visible_height) {
+            self.scroll = top.saturating_add(needed) - self.visible_height;
+        }
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_accordion_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑/k", "Previous section"),
+            ("↓/j", "Next section"),
+            ("Enter/Space", "Toggle section"),
+            ("Home", "First section"),
+            ("End", "Last section"),
+            ("PgUp/PgDn", "Scroll page"),
+            ("/", "Filter sections"),
+            ("Esc", "Clear filter"),
+        ]
+    }
 }
 
 impl Default for AccordionState {
@@ -266,6 +537,14 @@ pub struct AccordionStyle {
     pub content_indent: u16,
     /// Style for icons
     pub icon_style: Style,
+    /// Style patched onto the parts of headers matching the filter
+    pub filter_match_style: Style,
+    /// Style for the filter bar
+    pub filter_style: Style,
+    /// Pin an expanded section's header to the top while its content scrolls
+    pub sticky_headers: bool,
+    /// Style patched onto a pinned header
+    pub sticky_header_style: Style,
 }
 
 impl Default for AccordionStyle {
@@ -282,6 +561,10 @@ impl Default for AccordionStyle {
             show_borders: false,
             content_indent: 2,
             icon_style: Style::default().fg(Color::Cyan),
+            filter_match_style: Style::default().fg(Color::Black).bg(Color::Yellow),
+            filter_style: Style::default().fg(Color::White),
+            sticky_headers: false,
+            sticky_header_style: Style::default().bg(Color::DarkGray),
         }
     }
 }
@@ -299,6 +582,10 @@ impl From<&crate::theme::Theme> for AccordionStyle {
             show_borders: false,
             content_indent: 2,
             icon_style: Style::default().fg(p.secondary),
+            filter_match_style: Style::default().fg(p.highlight_fg).bg(p.highlight_bg),
+            filter_style: Style::default().fg(p.text),
+            sticky_headers: false,
+            sticky_header_style: Style::default().bg(p.surface_raised),
         }
     }
 }
@@ -368,6 +655,30 @@ impl AccordionStyle {
         self.show_borders = show;
         self
     }
+
+    /// Set the style for header text matching the filter
+    pub fn filter_match_style(mut self, style: Style) -> Self {
+        self.filter_match_style = style;
+        self
+    }
+
+    /// Set the filter bar style
+    pub fn filter_style(mut self, style: Style) -> Self {
+        self.filter_style = style;
+        self
+    }
+
+    /// Set whether expanded section headers stay pinned while scrolling
+    pub fn sticky_headers(mut self, sticky: bool) -> Self {
+        self.sticky_headers = sticky;
+        self
+    }
+
+    /// Set the style patched onto pinned headers
+    pub fn sticky_header_style(mut self, style: Style) -> Self {
+        self.sticky_header_style = style;
+        self
+    }
 }
 
 /// Accordion widget with collapsible sections
@@ -384,6 +695,7 @@ where
     render_content: C,
     id_fn: I,
     content_heights: Option<&'a [u16]>,
+    show_scrollbar: bool,
 }
 
 impl<'a, T>
@@ -418,6 +730,7 @@ impl<'a, T>
             render_content: |_item, _idx, _area, _buf| {},
             id_fn: |_item, idx| idx.to_string(),
             content_heights: None,
+            show_scrollbar: true,
         }
     }
 }
@@ -441,6 +754,7 @@ where
             render_content: self.render_content,
             id_fn,
             content_heights: self.content_heights,
+            show_scrollbar: self.show_scrollbar,
         }
     }
 
@@ -457,6 +771,7 @@ where
             render_content: self.render_content,
             id_fn: self.id_fn,
             content_heights: self.content_heights,
+            show_scrollbar: self.show_scrollbar,
         }
     }
 
@@ -473,6 +788,7 @@ where
             render_content,
             id_fn: self.id_fn,
             content_heights: self.content_heights,
+            show_scrollbar: self.show_scrollbar,
         }
     }
 
@@ -493,30 +809,128 @@ where
         self
     }
 
+    /// Show a scrollbar on the right edge when the content overflows (default: true)
+    pub fn show_scrollbar(mut self, show: bool) -> Self {
+        self.show_scrollbar = show;
+        self
+    }
+
     /// Get the ID for an item at the given index
     fn get_id(&self, item: &T, idx: usize) -> String {
         (self.id_fn)(item, idx)
     }
 
+    /// Content height of the item at the given index when expanded
+    fn content_height(&self, idx: usize) -> u16 {
+        self.content_heights
+            .and_then(|h| h.get(idx).copied())
+            .unwrap_or(DEFAULT_CONTENT_HEIGHT)
+    }
+
     /// Calculate heights for all items (useful for scrolling calculations)
     pub fn calculate_item_heights(&self) -> Vec<u16> {
-        self.items
-            .iter()
-            .enumerate()
-            .map(|(idx, item)| {
-                let id = self.get_id(item, idx);
-                let header_height = 1u16;
-                let content_height = if self.state.is_expanded(&id) {
-                    self.content_heights
-                        .and_then(|h| h.get(idx).copied())
-                        .unwrap_or(3) // Default content height
-                } else {
-                    0
-                };
-                let border_height = if self.style.show_borders { 1 } else { 0 };
-                header_height + content_height + border_height
-            })
-            .collect()
+        item_heights(
+            self.items,
+            self.state,
+            |item, idx| self.get_id(item, idx),
+            self.content_heights.unwrap_or(&[]),
+            self.style.show_borders,
+        )
+    }
+
+    /// Render the filter prompt, query, and cursor
+    fn render_filter_bar(&self, area: Rect, buf: &mut Buffer) {
+        let input = &self.state.filter_input;
+        let mut spans = vec![Span::styled("/ ", self.style.icon_style)];
+        if input.is_empty() && !input.focused {
+            spans.push(Span::styled("Filter", self.style.content_style));
+        } else {
+            let text_style = self.style.filter_style;
+            spans.push(Span::styled(
+                input.text_before_cursor().to_string(),
+                text_style,
+            ));
+            if input.focused {
+                spans.push(Span::styled("│", text_style));
+            }
+            spans.push(Span::styled(
+                input.text_after_cursor().to_string(),
+                text_style,
+            ));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    /// Content height of the item at `idx` if it is expanded, else 0
+    fn expanded_content_height(&self, idx: usize) -> u16 {
+        let expanded = self
+            .items
+            .get(idx)
+            .is_some_and(|item| self.state.is_expanded(&self.get_id(item, idx)));
+        if expanded {
+            self.content_height(idx)
+        } else {
+            0
+        }
+    }
+
+    /// Render a header row: the expand icon, then the header text with
+    /// filter matches highlighted and `sticky` patched on when pinned
+    fn render_header_row(
+        &self,
+        item: &T,
+        idx: usize,
+        is_expanded: bool,
+        sticky: Option<Style>,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let is_focused = idx == self.state.focused_index;
+        let icon = if is_expanded {
+            self.style.expanded_icon
+        } else {
+            self.style.collapsed_icon
+        };
+        let mut style = if is_focused {
+            self.style.header_focused_style
+        } else {
+            self.style.header_style
+        };
+        let mut icon_style = self.style.icon_style;
+        if let Some(sticky) = sticky {
+            style = style.patch(sticky);
+            icon_style = icon_style.patch(sticky);
+        }
+
+        let header_line = (self.render_header)(item, idx, is_focused);
+        let mut spans = vec![Span::styled(icon.to_string(), icon_style)];
+        let query = self.state.filter_query.as_deref().unwrap_or_default();
+        for span in header_line.spans {
+            spans.extend(highlight_spans(
+                &span.content,
+                query,
+                style,
+                self.style.filter_match_style,
+            ));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    /// Render content rows starting `skip` rows into the item's content
+    fn render_content_rows(&self, item: &T, idx: usize, skip: u16, area: Rect, buf: &mut Buffer) {
+        if skip == 0 {
+            (self.render_content)(item, idx, area, buf);
+            return;
+        }
+        // Render the full content off-screen and copy the visible rows
+        let full_height = skip.saturating_add(area.height);
+        let mut scratch = Buffer::empty(Rect::new(area.x, 0, area.width, full_height));
+        (self.render_content)(item, idx, scratch.area, &mut scratch);
+        for row in 0..area.height {
+            for x in area.left()..area.right() {
+                buf[(x, area.y + row)] = scratch[(x, skip + row)].clone();
+            }
+        }
     }
 }
 
@@ -531,83 +945,73 @@ where
             return;
         }
 
+        let (area, filter_bar) = split_filter_bar(area, self.state);
+        if let Some(bar) = filter_bar {
+            self.render_filter_bar(bar, buf);
+        }
+
+        let heights = self.calculate_item_heights();
+        let total_height = heights
+            .iter()
+            .fold(0u16, |total, &h| total.saturating_add(h));
+        let overflows = total_height > area.height;
+        let scroll = self
+            .state
+            .scroll
+            .min(total_height.saturating_sub(area.height));
+
+        let scrollbar_area = area;
+        let area = if self.show_scrollbar && overflows && area.width > 1 {
+            Rect {
+                width: area.width - 1,
+                ..area
+            }
+        } else {
+            area
+        };
+        let bottom = area.y + area.height;
+
         let mut y = area.y;
-        let scroll = self.state.scroll;
         let mut current_y: u16 = 0;
 
         for (idx, item) in self.items.iter().enumerate() {
+            if self.state.is_hidden(idx) {
+                continue;
+            }
             let id = self.get_id(item, idx);
             let is_expanded = self.state.is_expanded(&id);
-            let is_focused = idx == self.state.focused_index;
-
-            // Calculate item height
+            let item_height = heights[idx];
             let content_height = if is_expanded {
-                self.content_heights
-                    .and_then(|h| h.get(idx).copied())
-                    .unwrap_or(3)
+                self.content_height(idx)
             } else {
                 0
             };
-            let header_height = 1u16;
-            let item_height = header_height + content_height;
 
             // Skip items above scroll position
-            if current_y + item_height <= scroll {
-                current_y += item_height;
+            if current_y.saturating_add(item_height) <= scroll {
+                current_y = current_y.saturating_add(item_height);
                 continue;
             }
 
             // Stop if we've filled the area
-            if y >= area.y + area.height {
+            if y >= bottom {
                 break;
             }
 
-            // Calculate visible portion
+            // Rows of this item above the scroll position
             let skip_lines = scroll.saturating_sub(current_y);
-            let available_height = (area.y + area.height).saturating_sub(y);
 
             // Render header (if visible)
-            if skip_lines == 0 && available_height > 0 {
+            if skip_lines == 0 {
                 let header_area = Rect::new(area.x, y, area.width, 1);
-
-                // Build header line with icon
-                let icon = if is_expanded {
-                    self.style.expanded_icon
-                } else {
-                    self.style.collapsed_icon
-                };
-
-                let header_line = (self.render_header)(item, idx, is_focused);
-                let style = if is_focused {
-                    self.style.header_focused_style
-                } else {
-                    self.style.header_style
-                };
-
-                // Render icon
-                let icon_span = Span::styled(icon.to_string(), self.style.icon_style);
-                let mut spans = vec![icon_span];
-                spans.extend(
-                    header_line
-                        .spans
-                        .into_iter()
-                        .map(|s| Span::styled(s.content, style)),
-                );
-
-                let line = Line::from(spans);
-                let paragraph = Paragraph::new(line);
-                paragraph.render(header_area, buf);
-
+                self.render_header_row(item, idx, is_expanded, None, header_area, buf);
                 y += 1;
-            } else if skip_lines > 0 {
-                // Header is above scroll, skip it
             }
 
             // Render content (if expanded and visible)
-            if is_expanded && y < area.y + area.height {
-                let content_start_in_item = header_height;
-                let content_skip = skip_lines.saturating_sub(content_start_in_item);
-                let content_available = (area.y + area.height)
+            if is_expanded && y < bottom {
+                let content_skip = skip_lines.saturating_sub(1);
+                let content_available = bottom
                     .saturating_sub(y)
                     .min(content_height.saturating_sub(content_skip));
 
@@ -619,13 +1023,13 @@ where
                         area.width.saturating_sub(indent),
                         content_available,
                     );
-                    (self.render_content)(item, idx, content_area, buf);
+                    self.render_content_rows(item, idx, content_skip, content_area, buf);
                     y += content_available;
                 }
             }
 
             // Render border (if enabled)
-            if self.style.show_borders && y < area.y + area.height {
+            if self.style.show_borders && y < bottom {
                 let border_char = "─";
                 for x in area.x..area.x + area.width {
                     buf.set_string(x, y, border_char, self.style.border_style);
@@ -633,19 +1037,44 @@ where
                 y += 1;
             }
 
-            current_y += item_height;
+            current_y = current_y.saturating_add(item_height);
+        }
+
+        // Pin the header of a section scrolled partly out of view
+        if self.style.sticky_headers
+            && let Some(idx) = sticky_section(
+                &heights,
+                |idx| self.expanded_content_height(idx),
+                scroll,
+                area.height,
+            )
+        {
+            let header_area = Rect::new(area.x, area.y, area.width, 1);
+            buf.set_style(header_area, self.style.sticky_header_style);
+            let style = Some(self.style.sticky_header_style);
+            self.render_header_row(&self.items[idx], idx, true, style, header_area, buf);
+        }
+
+        if self.show_scrollbar && overflows {
+            InteractiveScrollbar::new(
+                ScrollbarOrientation::VerticalRight,
+                total_height as usize,
+                scroll as usize,
+            )
+            .viewport_length(area.height as usize)
+            .render(scrollbar_area, buf);
         }
     }
 }
 
-/// Calculate the total height needed for an accordion
-pub fn calculate_height<T, I>(
+/// Heights of each item: header, content when expanded, and border
+fn item_heights<T, I>(
     items: &[T],
     state: &AccordionState,
     id_fn: I,
     content_heights: &[u16],
     show_borders: bool,
-) -> u16
+) -> Vec<u16>
 where
     I: Fn(&T, usize) -> String,
 {
@@ -653,28 +1082,235 @@ where
         .iter()
         .enumerate()
         .map(|(idx, item)| {
+            if state.is_hidden(idx) {
+                return 0;
+            }
             let id = id_fn(item, idx);
             let header_height = 1u16;
             let content_height = if state.is_expanded(&id) {
-                content_heights.get(idx).copied().unwrap_or(3)
+                content_heights
+                    .get(idx)
+                    .copied()
+                    .unwrap_or(DEFAULT_CONTENT_HEIGHT)
             } else {
                 0
             };
             let border_height = if show_borders { 1 } else { 0 };
             header_height + content_height + border_height
         })
+        .collect()
+}
+
+/// Index of the expanded section whose header is scrolled above the
+/// viewport while some of its content is still visible
+///
+/// `content_height` gives a section's content rows when expanded, else 0.
+/// A viewport of one row has no room to pin a header.
+fn sticky_section(
+    heights: &[u16],
+    content_height: impl Fn(usize) -> u16,
+    scroll: u16,
+    visible_height: u16,
+) -> Option<usize> {
+    if visible_height < 2 {
+        return None;
+    }
+    let mut top: u16 = 0;
+    for (idx, &height) in heights.iter().enumerate() {
+        if top.saturating_add(height) > scroll {
+            let skip = scroll - top;
+            return (skip > 0 && skip <= content_height(idx)).then_some(idx);
+        }
+        top = top.saturating_add(height);
+    }
+    None
+}
+
+/// Split off the bottom row for the filter bar when the state is filterable
+fn split_filter_bar(area: Rect, state: &AccordionState) -> (Rect, Option<Rect>) {
+    if !state.filterable || area.height < 2 {
+        return (area, None);
+    }
+    let bar = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+    (
+        Rect {
+            height: area.height - 1,
+            ..area
+        },
+        Some(bar),
+    )
+}
+
+/// Calculate the total height needed for an accordion
+///
+/// Sections hidden by the filter take no space.
+pub fn calculate_height<T, I>(
+    items: &[T],
+    state: &AccordionState,
+    id_fn: I,
+    content_heights: &[u16],
+    show_borders: bool,
+) -> u16
+where
+    I: Fn(&T, usize) -> String,
+{
+    item_heights(items, state, id_fn, content_heights, show_borders)
+        .iter()
         .sum()
 }
 
+/// Layout of an accordion within a viewport, from [`calculate_layout`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccordionLayout {
+    /// Height of each item, including content and border rows
+    pub item_heights: Vec<u16>,
+    /// Total height of all items
+    pub total_height: u16,
+    /// Height of the viewport
+    pub visible_height: u16,
+    /// Visible header areas as `(index, header_area, id)`, for [`handle_accordion_mouse`]
+    pub header_areas: Vec<(usize, Rect, String)>,
+    /// Header pinned to the top row when [`AccordionStyle::sticky_headers`]
+    /// is on, as `(index, header_area, id)`
+    pub sticky_header: Option<(usize, Rect, String)>,
+}
+
+impl AccordionLayout {
+    /// Check if the items overflow the viewport
+    pub fn is_scrollable(&self) -> bool {
+        self.total_height > self.visible_height
+    }
+
+    /// Header areas including the pinned header, for accordions rendered
+    /// with [`AccordionStyle::sticky_headers`]
+    pub fn header_areas_with_sticky(&self) -> Vec<(usize, Rect, String)> {
+        self.sticky_header
+            .iter()
+            .chain(&self.header_areas)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Lay out an accordion in `area`, the viewport-aware companion to [`calculate_height`]
+///
+/// Call this before rendering each frame. It records the viewport in
+/// `state` for scrolling, keeps the visible rows steady when sections above
+/// the viewport change height, scrolls a newly expanded focused section so
+/// its header and first few content rows are visible, and clamps the
+/// scroll offset. The filter bar row of a filterable accordion is not part
+/// of the viewport.
+pub fn calculate_layout<T, I>(
+    items: &[T],
+    state: &mut AccordionState,
+    id_fn: I,
+    content_heights: &[u16],
+    show_borders: bool,
+    area: Rect,
+) -> AccordionLayout
+where
+    I: Fn(&T, usize) -> String,
+{
+    let (area, _) = split_filter_bar(area, state);
+    let heights = item_heights(items, state, &id_fn, content_heights, show_borders);
+    state.apply_layout(heights.clone(), area);
+
+    let mut header_areas = Vec::new();
+    let mut top: u16 = 0;
+    for (idx, (item, &height)) in items.iter().zip(&heights).enumerate() {
+        if !state.is_hidden(idx) && top >= state.scroll && top - state.scroll < area.height {
+            let y = area.y + (top - state.scroll);
+            header_areas.push((idx, Rect::new(area.x, y, area.width, 1), id_fn(item, idx)));
+        }
+        top = top.saturating_add(height);
+    }
+
+    let expanded_content = |idx: usize| {
+        let expanded = items
+            .get(idx)
+            .is_some_and(|item| state.is_expanded(&id_fn(item, idx)));
+        if expanded {
+            content_heights
+                .get(idx)
+                .copied()
+                .unwrap_or(DEFAULT_CONTENT_HEIGHT)
+        } else {
+            0
+        }
+    };
+    let sticky_header =
+        sticky_section(&heights, expanded_content, state.scroll, area.height).map(|idx| {
+            let header = Rect::new(area.x, area.y, area.width, 1);
+            (idx, header, id_fn(&items[idx], idx))
+        });
+
+    AccordionLayout {
+        total_height: state.content_height(),
+        visible_height: area.height,
+        item_heights: heights,
+        header_areas,
+        sticky_header,
+    }
+}
+
+/// Edit the filter bar text
+fn handle_filter_input_key(input: &mut InputState, key: &KeyEvent) -> bool {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char(c) if !ctrl => input.insert_char(c),
+        KeyCode::Backspace => {
+            input.delete_char_backward();
+        }
+        KeyCode::Delete => {
+            input.delete_char_forward();
+        }
+        KeyCode::Left => input.move_left(),
+        KeyCode::Right => input.move_right(),
+        KeyCode::Home => input.move_home(),
+        KeyCode::End => input.move_end(),
+        _ => return false,
+    }
+    true
+}
+
 /// Handle keyboard input for accordion navigation
+///
+/// In a filterable accordion, `/` focuses the filter bar. While it is
+/// focused, typing re-applies the filter, Enter returns to the sections, and
+/// Esc clears the query, then leaves the bar. Otherwise Esc clears an active
+/// filter, and is left unhandled when there is none.
 pub fn handle_accordion_key(
     state: &mut AccordionState,
-    key: &crossterm::event::KeyEvent,
+    key: &KeyEvent,
     get_id: impl Fn(usize) -> String,
 ) -> bool {
-    use crossterm::event::KeyCode;
+    if state.filter_input.focused {
+        match key.code {
+            KeyCode::Enter => state.filter_input.focused = false,
+            KeyCode::Esc if state.filter_input.is_empty() => state.filter_input.focused = false,
+            KeyCode::Esc => state.clear_filter(),
+            KeyCode::Up => state.focus_prev(),
+            KeyCode::Down => state.focus_next(),
+            _ => {
+                if !handle_filter_input_key(&mut state.filter_input, key) {
+                    return false;
+                }
+                let query = state.filter_input.text().to_string();
+                state.apply_filter(&query);
+            }
+        }
+        return true;
+    }
 
     match key.code {
+        KeyCode::Char('/') if state.filterable => {
+            state.filter_input.focused = true;
+            true
+        }
+        KeyCode::Esc if state.filter_query.is_some() => {
+            state.clear_filter();
+            true
+        }
         KeyCode::Up | KeyCode::Char('k') => {
             state.focus_prev();
             true
@@ -689,13 +1325,19 @@ pub fn handle_accordion_key(
             true
         }
         KeyCode::Home => {
-            state.focus(0);
+            state.focus_first();
             true
         }
         KeyCode::End => {
-            if state.total_items > 0 {
-                state.focus(state.total_items - 1);
-            }
+            state.focus_last();
+            true
+        }
+        KeyCode::PageUp => {
+            state.page_up();
+            true
+        }
+        KeyCode::PageDown => {
+            state.page_down();
             true
         }
         _ => false,
@@ -703,6 +1345,9 @@ pub fn handle_accordion_key(
 }
 
 /// Handle mouse click for accordion
+///
+/// The mouse wheel over the area from the last [`calculate_layout`] scrolls
+/// by three lines before any header hit-testing.
 pub fn handle_accordion_mouse(
     state: &mut AccordionState,
     mouse: &crossterm::event::MouseEvent,
@@ -710,6 +1355,19 @@ pub fn handle_accordion_mouse(
 ) -> bool {
     use crossterm::event::MouseEventKind;
 
+    let over_area = state.area.contains((mouse.column, mouse.row).into());
+    match mouse.kind {
+        MouseEventKind::ScrollUp if over_area => {
+            state.scroll_up(WHEEL_SCROLL_LINES);
+            return true;
+        }
+        MouseEventKind::ScrollDown if over_area => {
+            state.scroll_down(WHEEL_SCROLL_LINES);
+            return true;
+        }
+        _ => {}
+    }
+
     if let MouseEventKind::Down(crossterm::event::MouseButton::Left) = mouse.kind {
         for (idx, area, id) in item_areas {
             if mouse.column >= area.x
@@ -994,4 +1652,322 @@ mod tests {
         );
         assert_eq!(height, 14);
     }
+
+    fn ids(count: usize) -> Vec<String> {
+        (0..count).map(|i| i.to_string()).collect()
+    }
+
+    fn layout(items: &[String], state: &mut AccordionState, heights: &[u16]) -> AccordionLayout {
+        calculate_layout(
+            items,
+            state,
+            |item, _| item.clone(),
+            heights,
+            false,
+            Rect::new(0, 0, 20, 5),
+        )
+    }
+
+    #[test]
+    fn test_layout_scrolls_with_wheel_and_page_keys() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+
+        let items = ids(3);
+        let mut state = AccordionState::new(3);
+        state.expand_all(items.iter().cloned());
+        let result = layout(&items, &mut state, &[4, 4, 4]);
+        assert_eq!(result.total_height, 15);
+        assert!(result.is_scrollable());
+        assert_eq!(state.max_scroll(), 10);
+
+        let wheel = |kind, row| MouseEvent {
+            kind,
+            column: 5,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        // Wheel events are handled before header hit-testing
+        assert!(handle_accordion_mouse(
+            &mut state,
+            &wheel(MouseEventKind::ScrollDown, 0),
+            &result.header_areas
+        ));
+        assert_eq!(state.scroll, 3);
+        assert!(state.is_expanded("0"));
+        // Outside the accordion area the wheel is ignored
+        assert!(!handle_accordion_mouse(
+            &mut state,
+            &wheel(MouseEventKind::ScrollDown, 10),
+            &result.header_areas
+        ));
+
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        handle_accordion_key(&mut state, &key(KeyCode::PageDown), |i| i.to_string());
+        assert_eq!(state.scroll, 8);
+        handle_accordion_key(&mut state, &key(KeyCode::PageDown), |i| i.to_string());
+        assert_eq!(state.scroll, 10);
+        handle_accordion_key(&mut state, &key(KeyCode::PageUp), |i| i.to_string());
+        assert_eq!(state.scroll, 5);
+
+        // Only headers inside the viewport are reported
+        let result = layout(&items, &mut state, &[4, 4, 4]);
+        assert_eq!(
+            result.header_areas,
+            vec![(1, Rect::new(0, 0, 20, 1), "1".into())]
+        );
+    }
+
+    #[test]
+    fn test_collapse_above_viewport_keeps_content_in_place() {
+        let items = ids(3);
+        let mut state = AccordionState::new(3);
+        state.expand_all(items.iter().cloned());
+        layout(&items, &mut state, &[4, 4, 4]);
+        state.scroll = 7; // second row of item 1's content
+
+        state.focus(2);
+        state.collapse("0");
+        layout(&items, &mut state, &[4, 4, 4]);
+        assert_eq!(state.scroll, 3);
+
+        // Collapsing the item at the top of the viewport shows its header
+        state.collapse("1");
+        layout(&items, &mut state, &[4, 4, 4]);
+        assert_eq!(state.scroll, 1);
+    }
+
+    #[test]
+    fn test_expanding_focused_item_reveals_content() {
+        let items = ids(6);
+        let mut state = AccordionState::new(6);
+        layout(&items, &mut state, &[10; 6]);
+        assert_eq!(state.scroll, 0);
+
+        state.focus(4);
+        state.toggle("4");
+        layout(&items, &mut state, &[10; 6]);
+        // Header at row 4 plus three content rows end at row 8
+        assert_eq!(state.scroll, 3);
+    }
+
+    #[test]
+    fn test_render_scrolled_content_and_scrollbar() {
+        let items = ids(2);
+        let mut state = AccordionState::new(2);
+        state.expand("0");
+        layout(&items, &mut state, &[6, 6]);
+        state.scroll = 3;
+
+        let accordion = Accordion::new(&items, &state)
+            .id_fn(|item, _| item.clone())
+            .render_content(|_, _, area, buf| {
+                for row in 0..area.height {
+                    buf.set_string(
+                        area.x,
+                        area.y + row,
+                        format!("row{}", row),
+                        Style::default(),
+                    );
+                }
+            })
+            .content_heights(&[6, 6]);
+
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buf = Buffer::empty(area);
+        accordion.render(area, &mut buf);
+
+        let line = |y: u16| (0..19).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        // Scroll 3 skips the header and two content rows
+        assert!(line(0).contains("row2"));
+        assert!(line(3).contains("row5"));
+        assert!(line(4).contains("Item 1"));
+        // Scrollbar in the last column
+        assert_ne!(buf[(19, 0)].symbol(), " ");
+    }
+
+    fn filtered_state() -> AccordionState {
+        AccordionState::new(4)
+            .with_sections([
+                ("0", "Getting started"),
+                ("1", "Installation"),
+                ("2", "Configuration"),
+                ("3", "Troubleshooting"),
+            ])
+            .with_filterable(true)
+    }
+
+    #[test]
+    fn test_apply_filter_hides_and_expands_sections() {
+        let mut state = filtered_state();
+        state.focus(3);
+        state.apply_filter("TION");
+        assert_eq!(state.filter_query.as_deref(), Some("TION"));
+        assert!(state.is_hidden(0) && state.is_hidden(3));
+        assert_eq!(state.visible_count(), 2);
+        assert!(state.is_expanded("1") && state.is_expanded("2"));
+        // Focus moved off the hidden section
+        assert_eq!(state.focused_index(), 1);
+
+        // Navigation skips hidden sections
+        state.focus_next();
+        assert_eq!(state.focused_index(), 2);
+        state.focus_next();
+        assert_eq!(state.focused_index(), 2);
+        state.focus_first();
+        assert_eq!(state.focused_index(), 1);
+
+        // Hidden sections take no space
+        let items = ids(4);
+        let height = calculate_height(&items, &state, |item, _| item.clone(), &[2; 4], false);
+        assert_eq!(height, 6);
+
+        state.clear_filter();
+        assert!(state.filter_query.is_none());
+        assert_eq!(state.visible_count(), 4);
+        assert!(state.filter_input.is_empty());
+    }
+
+    #[test]
+    fn test_apply_filter_single_mode_expands_first_match() {
+        let mut state = filtered_state().with_mode(AccordionMode::Single);
+        state.apply_filter("ing");
+        assert!(state.is_expanded("0"));
+        assert_eq!(state.expanded.len(), 1);
+
+        // An already expanded match stays expanded
+        state.expand("3");
+        state.apply_filter("in");
+        assert!(state.is_expanded("3"));
+        assert_eq!(state.expanded.len(), 1);
+    }
+
+    #[test]
+    fn test_filter_bar_keys() {
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut state = filtered_state();
+        let press = |state: &mut AccordionState, code| {
+            handle_accordion_key(state, &KeyEvent::from(code), |i| i.to_string())
+        };
+
+        assert!(!press(&mut state, KeyCode::Esc));
+        assert!(press(&mut state, KeyCode::Char('/')));
+        assert!(state.filter_input.focused);
+        for c in "trouble".chars() {
+            press(&mut state, KeyCode::Char(c));
+        }
+        assert_eq!(state.filter_query.as_deref(), Some("trouble"));
+        assert_eq!(state.visible_count(), 1);
+        assert_eq!(state.focused_index(), 3);
+
+        // Enter returns to the sections with the filter kept
+        press(&mut state, KeyCode::Enter);
+        assert!(!state.filter_input.focused);
+        assert_eq!(state.visible_count(), 1);
+
+        // Esc clears the filter, then propagates
+        assert!(press(&mut state, KeyCode::Esc));
+        assert!(state.filter_query.is_none());
+        assert!(!press(&mut state, KeyCode::Esc));
+
+        // Inside the bar, Esc clears the query before leaving
+        press(&mut state, KeyCode::Char('/'));
+        press(&mut state, KeyCode::Char('x'));
+        assert_eq!(state.visible_count(), 0);
+        press(&mut state, KeyCode::Esc);
+        assert!(state.filter_input.focused);
+        assert_eq!(state.visible_count(), 4);
+        press(&mut state, KeyCode::Esc);
+        assert!(!state.filter_input.focused);
+    }
+
+    #[test]
+    fn test_render_filter_bar_and_highlight() {
+        let items: Vec<String> = ["Getting started", "Installation", "Configuration"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut state = AccordionState::new(3)
+            .with_sections(items.iter().map(|t| (t.clone(), t.clone())))
+            .with_filterable(true);
+        state.apply_filter("config");
+
+        let area = Rect::new(0, 0, 20, 4);
+        let result = calculate_layout(
+            &items,
+            &mut state,
+            |item, _| item.clone(),
+            &[1; 3],
+            false,
+            area,
+        );
+        assert_eq!(result.visible_height, 3);
+        assert_eq!(result.header_areas.len(), 1);
+
+        let accordion = Accordion::new(&items, &state)
+            .id_fn(|item, _| item.clone())
+            .render_header(|item, _, _| Line::raw(item.clone()))
+            .content_heights(&[1; 3]);
+        let mut buf = Buffer::empty(area);
+        accordion.render(area, &mut buf);
+
+        let line = |y: u16| (0..20).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert!(line(0).starts_with("▼ Configuration"));
+        assert_eq!(buf[(2, 0)].bg, Color::Yellow);
+        assert_ne!(buf[(9, 0)].bg, Color::Yellow);
+        assert!(line(3).starts_with("/ config"));
+    }
+
+    #[test]
+    fn test_sticky_header_pins_scrolled_section() {
+        let items = ids(2);
+        let mut state = AccordionState::new(2);
+        state.expand("0");
+        state.expand("1");
+        state.focus(1);
+        let area = Rect::new(0, 0, 20, 5);
+        let render = |state: &AccordionState, sticky: bool| {
+            let style = AccordionStyle::default().sticky_headers(sticky);
+            let mut buf = Buffer::empty(area);
+            Accordion::new(&items, state)
+                .id_fn(|item, _| item.clone())
+                .render_content(|_, _, area, buf| {
+                    for row in 0..area.height {
+                        let text = format!("row{}", row);
+                        buf.set_string(area.x, area.y + row, text, Style::default());
+                    }
+                })
+                .content_heights(&[6, 6])
+                .style(style)
+                .render(area, &mut buf);
+            buf
+        };
+        let line = |buf: &Buffer, y: u16| (0..19).map(|x| buf[(x, y)].symbol()).collect::<String>();
+
+        // Scroll 3 hides the header of item 0 and two of its content rows
+        let id_fn = |item: &String, _| item.clone();
+        calculate_layout(&items, &mut state, id_fn, &[6, 6], false, area);
+        state.scroll = 3;
+        let layout = calculate_layout(&items, &mut state, id_fn, &[6, 6], false, area);
+        let (idx, header, id) = layout.sticky_header.clone().unwrap();
+        assert_eq!((idx, header.y, id.as_str()), (0, 0, "0"));
+        assert_eq!(layout.header_areas_with_sticky().len(), 2);
+
+        let buf = render(&state, true);
+        assert!(line(&buf, 0).starts_with("▼ Item 0"));
+        assert_eq!(buf[(3, 0)].bg, Color::DarkGray);
+        // The content below the pinned header is not shifted
+        assert!(line(&buf, 1).contains("row3"));
+        assert!(line(&buf, 4).contains("Item 1"));
+
+        // Off by default: the top row shows content
+        let buf = render(&state, false);
+        assert!(line(&buf, 0).contains("row2"));
+
+        // Once only the next header is left, nothing is pinned
+        state.scroll = 7;
+        let layout = calculate_layout(&items, &mut state, id_fn, &[6, 6], false, area);
+        assert_eq!(layout.sticky_header, None);
+        assert!(line(&render(&state, true), 0).contains("Item 1"));
+    }
 }
@@ -36,6 +36,7 @@ use ratatui::{
 };
 
 use crate::traits::ClickRegion;
+use crate::utils::display::truncate_to_width;
 
 /// Actions a breadcrumb component can emit.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -88,6 +89,46 @@ impl BreadcrumbItem {
     }
 }
 
+/// Build breadcrumb items for path segments, with cumulative ids.
+fn path_items<S: AsRef<str>>(path: &[S]) -> Vec<BreadcrumbItem> {
+    let mut id = String::new();
+    path.iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            if i > 0 {
+                id.push('/');
+            }
+            id.push_str(segment.as_ref());
+            BreadcrumbItem::new(id.clone(), segment.as_ref())
+        })
+        .collect()
+}
+
+/// An in-flight slide from one path to another.
+///
+/// Started by [`BreadcrumbState::transition_to`] and advanced with
+/// [`BreadcrumbState::tick_transition`]. While it runs, the outgoing path is
+/// clipped from the right back to the part both paths share, then the
+/// incoming path is revealed.
+#[derive(Debug, Clone)]
+pub struct BreadcrumbTransition {
+    /// Items shown before the navigation.
+    pub from_items: Vec<BreadcrumbItem>,
+    /// Items shown once the transition completes.
+    pub to_items: Vec<BreadcrumbItem>,
+    /// Current frame, from 0 to `total_frames`.
+    pub frame: u8,
+    /// Number of frames the transition lasts.
+    pub total_frames: u8,
+}
+
+impl BreadcrumbTransition {
+    /// Whether the last frame has been reached.
+    pub fn is_complete(&self) -> bool {
+        self.frame >= self.total_frames
+    }
+}
+
 /// State for a breadcrumb component.
 #[derive(Debug, Clone)]
 pub struct BreadcrumbState {
@@ -101,6 +142,12 @@ pub struct BreadcrumbState {
     pub enabled: bool,
     /// Whether ellipsis is expanded (showing all items).
     pub expanded: bool,
+    /// Hovered truncated segment as (item index, full label).
+    pub tooltip: Option<(usize, String)>,
+    /// Screen position (column, row) at which to render the tooltip.
+    pub tooltip_position: Option<(u16, u16)>,
+    /// Slide animation in progress, if any.
+    pub transition: Option<BreadcrumbTransition>,
 }
 
 impl Default for BreadcrumbState {
@@ -111,6 +158,9 @@ impl Default for BreadcrumbState {
             focused: false,
             enabled: true,
             expanded: false,
+            tooltip: None,
+            tooltip_position: None,
+            transition: None,
         }
     }
 }
@@ -129,6 +179,19 @@ impl BreadcrumbState {
         Self::default()
     }
 
+    /// Create a breadcrumb state with one item per path segment.
+    ///
+    /// Each item's id is the `/`-joined path up to and including that
+    /// segment, so [`BreadcrumbAction::Navigate`] carries the target path.
+    pub fn from_path<S: AsRef<str>>(path: &[S]) -> Self {
+        Self::new(path_items(path))
+    }
+
+    /// Replace the items with one per path segment (see [`from_path`](Self::from_path)).
+    pub fn set_path<S: AsRef<str>>(&mut self, path: &[S]) {
+        self.set_items(path_items(path));
+    }
+
     /// Select the next item (move right).
     pub fn select_next(&mut self) {
         if self.items.is_empty() {
@@ -210,6 +273,7 @@ impl BreadcrumbState {
         self.items.clear();
         self.selected_index = None;
         self.expanded = false;
+        self.clear_tooltip();
     }
 
     /// Set new items, replacing existing ones.
@@ -226,6 +290,74 @@ impl BreadcrumbState {
             }
         }
         self.expanded = false;
+        self.clear_tooltip();
+        self.transition = None;
+    }
+
+    /// Replace the items, sliding from the current path over `total_frames`.
+    ///
+    /// The new items take effect immediately (selection, handlers, and
+    /// [`len`](Self::len) see them); only rendering is animated. Pass
+    /// [`BreadcrumbStyle::transition_frames`] to follow the style; with 0
+    /// frames this is the same as [`set_items`](Self::set_items).
+    ///
+    /// [`handle_breadcrumb_key_with_style`] and
+    /// [`handle_breadcrumb_mouse_with_style`] call this through
+    /// [`navigate_to`](Self::navigate_to).
+    pub fn transition_to(&mut self, items: Vec<BreadcrumbItem>, total_frames: u8) {
+        let from_items = std::mem::take(&mut self.items);
+        self.set_items(items);
+        if total_frames > 0 {
+            self.transition = Some(BreadcrumbTransition {
+                from_items,
+                to_items: self.items.clone(),
+                frame: 0,
+                total_frames,
+            });
+        }
+    }
+
+    /// Navigate up to the item `id`, dropping the items after it.
+    ///
+    /// Slides over `total_frames` (0 is instant) and selects the item.
+    /// Returns `false` when `id` is unknown or already the last item.
+    pub fn navigate_to(&mut self, id: &str, total_frames: u8) -> bool {
+        let Some(idx) = self.items.iter().position(|item| item.id == id) else {
+            return false;
+        };
+        if idx + 1 == self.items.len() {
+            return false;
+        }
+        let items = self.items[..=idx].to_vec();
+        self.transition_to(items, total_frames);
+        self.selected_index = Some(idx);
+        true
+    }
+
+    /// Like [`transition_to`](Self::transition_to), with one item per path segment.
+    pub fn transition_to_path<S: AsRef<str>>(&mut self, path: &[S], total_frames: u8) {
+        self.transition_to(path_items(path), total_frames);
+    }
+
+    /// Advance the transition by one frame.
+    ///
+    /// Returns `true` while a transition is still running, so callers know
+    /// to keep redrawing. The transition is dropped once it completes.
+    pub fn tick_transition(&mut self) -> bool {
+        let Some(transition) = &mut self.transition else {
+            return false;
+        };
+        transition.frame = transition.frame.saturating_add(1);
+        if transition.is_complete() {
+            self.transition = None;
+            return false;
+        }
+        true
+    }
+
+    /// Whether a transition is in progress.
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
     }
 
     /// Toggle expanded state (show/hide collapsed items).
@@ -233,6 +365,12 @@ impl BreadcrumbState {
         self.expanded = !self.expanded;
     }
 
+    /// Clear the hover tooltip.
+    pub fn clear_tooltip(&mut self) {
+        self.tooltip = None;
+        self.tooltip_position = None;
+    }
+
     /// Get the currently selected item.
     pub fn selected_item(&self) -> Option<&BreadcrumbItem> {
         self.selected_index.and_then(|idx| self.items.get(idx))
@@ -247,6 +385,18 @@ impl BreadcrumbState {
     pub fn is_empty(&self) -> bool {
         self.items.is_empty()
     }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_breadcrumb_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("←/h", "Previous segment"),
+            ("→/l", "Next segment"),
+            ("Home", "First segment"),
+            ("End", "Last segment"),
+            ("Enter/Space", "Navigate to segment"),
+            ("e", "Expand collapsed segments"),
+        ]
+    }
 }
 
 /// Style configuration for breadcrumb component.
@@ -286,8 +436,18 @@ pub struct BreadcrumbStyle {
     /// Separator between icon and label.
     pub icon_separator: &'static str,
 
+    /// Maximum label width; longer labels are truncated with "...".
+    pub max_item_width: Option<usize>,
+    /// Whether the widget draws the hover tooltip from `BreadcrumbState::tooltip`.
+    pub show_tooltips: bool,
+    /// Style for the hover tooltip.
+    pub tooltip_style: Style,
+
     /// Horizontal padding (left, right).
     pub padding: (u16, u16),
+
+    /// Frames used to slide between paths; 0 switches instantly.
+    pub transition_frames: u8,
 }
 
 impl Default for BreadcrumbStyle {
@@ -320,7 +480,13 @@ impl Default for BreadcrumbStyle {
             icon_style: Style::default(),
             icon_separator: " ",
 
+            max_item_width: None,
+            show_tooltips: false,
+            tooltip_style: Style::default().fg(Color::White).bg(Color::DarkGray),
+
             padding: (1, 1),
+
+            transition_frames: 0,
         }
     }
 }
@@ -352,7 +518,13 @@ impl From<&crate::theme::Theme> for BreadcrumbStyle {
             icon_style: Style::default(),
             icon_separator: " ",
 
+            max_item_width: None,
+            show_tooltips: false,
+            tooltip_style: Style::default().fg(p.text).bg(p.surface),
+
             padding: (1, 1),
+
+            transition_frames: 0,
         }
     }
 }
@@ -443,11 +615,35 @@ impl BreadcrumbStyle {
         self
     }
 
+    /// Truncate labels longer than `width` with "...".
+    pub fn max_item_width(mut self, width: usize) -> Self {
+        self.max_item_width = Some(width);
+        self
+    }
+
+    /// Set whether the widget draws the hover tooltip.
+    pub fn show_tooltips(mut self, show: bool) -> Self {
+        self.show_tooltips = show;
+        self
+    }
+
+    /// Set the tooltip style.
+    pub fn tooltip_style(mut self, style: Style) -> Self {
+        self.tooltip_style = style;
+        self
+    }
+
     /// Set padding (horizontal, vertical).
     pub fn padding(mut self, left: u16, right: u16) -> Self {
         self.padding = (left, right);
         self
     }
+
+    /// Set the number of frames used to slide between paths.
+    pub fn transition_frames(mut self, frames: u8) -> Self {
+        self.transition_frames = frames;
+        self
+    }
 }
 
 /// Represents a visible element in the rendered breadcrumb.
@@ -525,6 +721,14 @@ impl<'a> Breadcrumb<'a> {
         elements
     }
 
+    /// Label as displayed, truncated to `max_item_width` if set.
+    fn display_label(&self, item: &BreadcrumbItem) -> String {
+        match self.style.max_item_width {
+            Some(max) if item.label.chars().count() > max => truncate_to_width(&item.label, max),
+            _ => item.label.clone(),
+        }
+    }
+
     /// Get the style for an item at the given index.
     fn item_style(&self, idx: usize) -> Style {
         let item = &self.state.items[idx];
@@ -556,13 +760,63 @@ impl<'a> Breadcrumb<'a> {
     ) -> Vec<ClickRegion<BreadcrumbAction>> {
         let mut regions = Vec::new();
 
+        // Segments are mid-slide, so nothing is clickable until it settles
+        if let Some(transition) = &self.state.transition {
+            self.render_transition(transition, area, buf);
+            return regions;
+        }
+
         if self.state.items.is_empty() {
             return regions;
         }
 
+        let (spans, element_positions) = self.line_spans(area.x + self.style.padding.0);
+
+        // Create the line and render
+        let line = Line::from(spans);
+        let paragraph = Paragraph::new(line);
+        paragraph.render(area, buf);
+
+        if self.style.show_tooltips {
+            self.render_tooltip(buf);
+        }
+
+        // Create click regions
+        for (element, start_x, width) in element_positions {
+            if width == 0 {
+                continue;
+            }
+
+            let click_area = Rect::new(start_x, area.y, width, 1);
+
+            match element {
+                VisibleElement::Item(idx) => {
+                    let item = &self.state.items[idx];
+                    if item.enabled {
+                        regions.push(ClickRegion::new(
+                            click_area,
+                            BreadcrumbAction::Navigate(item.id.clone()),
+                        ));
+                    }
+                }
+                VisibleElement::Ellipsis => {
+                    regions.push(ClickRegion::new(
+                        click_area,
+                        BreadcrumbAction::ExpandEllipsis,
+                    ));
+                }
+            }
+        }
+
+        regions
+    }
+
+    /// Build the styled segments starting at column `x`, with each visible
+    /// element's (element, x, width) for click regions.
+    fn line_spans(&self, x: u16) -> (Vec<Span<'static>>, Vec<(VisibleElement, u16, u16)>) {
         let visible = self.visible_elements();
         let mut spans = Vec::new();
-        let mut x_offset = area.x + self.style.padding.0;
+        let mut x_offset = x;
 
         // Track positions for click regions
         let mut element_positions: Vec<(VisibleElement, u16, u16)> = Vec::new();
@@ -587,7 +841,7 @@ impl<'a> Breadcrumb<'a> {
                         item_text.push_str(icon);
                         item_text.push_str(self.style.icon_separator);
                     }
-                    item_text.push_str(&item.label);
+                    item_text.push_str(&self.display_label(item));
 
                     let item_width = item_text.chars().count() as u16;
                     element_positions.push((element.clone(), x_offset, item_width));
@@ -605,39 +859,72 @@ impl<'a> Breadcrumb<'a> {
             }
         }
 
-        // Create the line and render
-        let line = Line::from(spans);
-        let paragraph = Paragraph::new(line);
-        paragraph.render(area, buf);
+        (spans, element_positions)
+    }
 
-        // Create click regions
-        for (element, start_x, width) in element_positions {
-            if width == 0 {
-                continue;
-            }
+    /// Segments for `items` rendered with this widget's style, unselected.
+    fn spans_for(&self, items: &[BreadcrumbItem]) -> Vec<Span<'static>> {
+        let state = BreadcrumbState {
+            items: items.to_vec(),
+            selected_index: None,
+            transition: None,
+            ..self.state.clone()
+        };
+        Breadcrumb {
+            state: &state,
+            style: self.style.clone(),
+            hovered_index: None,
+        }
+        .line_spans(0)
+        .0
+    }
 
-            let click_area = Rect::new(start_x, area.y, width, 1);
+    /// Draw one frame of a slide: the outgoing path shrinks from the right
+    /// down to the text both paths share, then the incoming path grows.
+    fn render_transition(&self, transition: &BreadcrumbTransition, area: Rect, buf: &mut Buffer) {
+        let from = self.spans_for(&transition.from_items);
+        let to = self.spans_for(&transition.to_items);
+        let from_text: String = from.iter().map(|s| s.content.as_ref()).collect();
+        let to_text: String = to.iter().map(|s| s.content.as_ref()).collect();
+
+        let from_width = from_text.chars().count();
+        let to_width = to_text.chars().count();
+        let shared = from_text
+            .chars()
+            .zip(to_text.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let outgoing = from_width - shared;
+        let distance = outgoing + (to_width - shared);
+
+        let total = transition.total_frames.max(1) as usize;
+        let travelled = distance * (transition.frame as usize).min(total) / total;
+        let line = if travelled <= outgoing {
+            clip_spans(from, from_width - travelled)
+        } else {
+            clip_spans(to, shared + travelled - outgoing)
+        };
+        Paragraph::new(Line::from(line)).render(area, buf);
+    }
 
-            match element {
-                VisibleElement::Item(idx) => {
-                    let item = &self.state.items[idx];
-                    if item.enabled {
-                        regions.push(ClickRegion::new(
-                            click_area,
-                            BreadcrumbAction::Navigate(item.id.clone()),
-                        ));
-                    }
-                }
-                VisibleElement::Ellipsis => {
-                    regions.push(ClickRegion::new(
-                        click_area,
-                        BreadcrumbAction::ExpandEllipsis,
-                    ));
-                }
-            }
+    /// Draw the hover tooltip at `tooltip_position`, clamped to the buffer.
+    fn render_tooltip(&self, buf: &mut Buffer) {
+        let (Some((_, text)), Some((col, row))) =
+            (&self.state.tooltip, self.state.tooltip_position)
+        else {
+            return;
+        };
+        let bounds = *buf.area();
+        if row < bounds.y || row >= bounds.bottom() {
+            return;
         }
 
-        regions
+        let content = format!(" {} ", text);
+        let width = (content.chars().count() as u16).min(bounds.width);
+        let x = col.min(bounds.right().saturating_sub(width)).max(bounds.x);
+        let tooltip_area = Rect::new(x, row, width, 1);
+
+        Paragraph::new(Span::styled(content, self.style.tooltip_style)).render(tooltip_area, buf);
     }
 
     /// Calculate the width needed to render the breadcrumb.
@@ -662,7 +949,7 @@ impl<'a> Breadcrumb<'a> {
                         width += icon.chars().count() as u16;
                         width += self.style.icon_separator.chars().count() as u16;
                     }
-                    width += item.label.chars().count() as u16;
+                    width += self.display_label(item).chars().count() as u16;
                 }
                 VisibleElement::Ellipsis => {
                     width += self.style.ellipsis.chars().count() as u16;
@@ -674,9 +961,32 @@ impl<'a> Breadcrumb<'a> {
     }
 }
 
+/// Keep the first `width` characters of `spans`.
+fn clip_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Span<'static>> {
+    let mut remaining = width;
+    let mut clipped = Vec::new();
+    for span in spans {
+        if remaining == 0 {
+            break;
+        }
+        let len = span.content.chars().count();
+        if len <= remaining {
+            remaining -= len;
+            clipped.push(span);
+        } else {
+            let text: String = span.content.chars().take(remaining).collect();
+            clipped.push(Span::styled(text, span.style));
+            remaining = 0;
+        }
+    }
+    clipped
+}
+
 /// Handle keyboard events for breadcrumb component.
 ///
 /// Returns `Some(BreadcrumbAction)` if an action was triggered, `None` otherwise.
+/// The caller performs the navigation, typically with
+/// [`BreadcrumbState::transition_to`] to animate it.
 ///
 /// # Key Bindings
 ///
@@ -730,10 +1040,30 @@ pub fn handle_breadcrumb_key(
     }
 }
 
+/// Handle keyboard events for a breadcrumb rendered with `style`.
+///
+/// Like [`handle_breadcrumb_key`], but activating an item also navigates
+/// to it with [`BreadcrumbState::navigate_to`], sliding over the style's
+/// [`transition_frames`](BreadcrumbStyle::transition_frames) (0 is instant).
+pub fn handle_breadcrumb_key_with_style(
+    key: &KeyEvent,
+    state: &mut BreadcrumbState,
+    style: &BreadcrumbStyle,
+) -> Option<BreadcrumbAction> {
+    let action = handle_breadcrumb_key(key, state);
+    if let Some(BreadcrumbAction::Navigate(id)) = &action {
+        state.navigate_to(id, style.transition_frames);
+    }
+    action
+}
+
 /// Handle mouse events for breadcrumb component.
 ///
 /// Returns `Some(BreadcrumbAction)` if an action was triggered, `None` otherwise.
 ///
+/// `MouseEventKind::Moved` over a truncated segment stores its full label in
+/// `state.tooltip`, positioned on the row below; moving anywhere else clears it.
+///
 /// # Arguments
 ///
 /// * `mouse` - The mouse event
@@ -748,6 +1078,11 @@ pub fn handle_breadcrumb_mouse(
         return None;
     }
 
+    if mouse.kind == MouseEventKind::Moved {
+        update_tooltip(mouse.column, mouse.row, state, regions);
+        return None;
+    }
+
     if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
         let col = mouse.column;
         let row = mouse.row;
@@ -772,6 +1107,59 @@ pub fn handle_breadcrumb_mouse(
     None
 }
 
+/// Handle mouse events for a breadcrumb rendered with `style`.
+///
+/// Like [`handle_breadcrumb_mouse`], but clicking an item also navigates to
+/// it as [`handle_breadcrumb_key_with_style`] does.
+pub fn handle_breadcrumb_mouse_with_style(
+    mouse: &MouseEvent,
+    state: &mut BreadcrumbState,
+    regions: &[ClickRegion<BreadcrumbAction>],
+    style: &BreadcrumbStyle,
+) -> Option<BreadcrumbAction> {
+    let action = handle_breadcrumb_mouse(mouse, state, regions);
+    if let Some(BreadcrumbAction::Navigate(id)) = &action {
+        state.navigate_to(id, style.transition_frames);
+    }
+    action
+}
+
+/// Set or clear the tooltip for the segment under the pointer.
+fn update_tooltip(
+    col: u16,
+    row: u16,
+    state: &mut BreadcrumbState,
+    regions: &[ClickRegion<BreadcrumbAction>],
+) {
+    let hovered = regions.iter().find_map(|region| match &region.data {
+        BreadcrumbAction::Navigate(id) if region.contains(col, row) => state
+            .items
+            .iter()
+            .position(|item| &item.id == id)
+            .map(|idx| (idx, region.area)),
+        _ => None,
+    });
+    let Some((idx, area)) = hovered else {
+        state.clear_tooltip();
+        return;
+    };
+
+    // A region narrower than the full text means the label was truncated.
+    // Icons are assumed to use the default single-column separator.
+    let item = &state.items[idx];
+    let icon_width = item
+        .icon
+        .as_ref()
+        .map_or(0, |icon| icon.chars().count() + 1);
+    if icon_width + item.label.chars().count() <= area.width as usize {
+        state.clear_tooltip();
+        return;
+    }
+
+    state.tooltip = Some((idx, item.label.clone()));
+    state.tooltip_position = Some((area.x, area.y.saturating_add(1)));
+}
+
 /// Get the item index at a given mouse position.
 ///
 /// Useful for implementing hover effects.
@@ -1008,4 +1396,217 @@ mod tests {
         assert!(!region.contains(9, 5));
         assert!(!region.contains(30, 5));
     }
+
+    fn moved(col: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: col,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_max_item_width_truncates_label() {
+        let items = vec![
+            BreadcrumbItem::new("home", "Home"),
+            BreadcrumbItem::new("docs", "Documentation"),
+        ];
+        let state = BreadcrumbState::new(items);
+        let breadcrumb =
+            Breadcrumb::new(&state).style(BreadcrumbStyle::default().max_item_width(8));
+
+        // "Home" (4) + " > " (3) + "Docum..." (8) + padding (1+1) = 17
+        assert_eq!(breadcrumb.calculate_width(), 17);
+    }
+
+    #[test]
+    fn test_hover_truncated_segment_sets_tooltip() {
+        let items = vec![
+            BreadcrumbItem::new("home", "Home"),
+            BreadcrumbItem::new("docs", "Documentation"),
+        ];
+        let mut state = BreadcrumbState::new(items);
+        let style = BreadcrumbStyle::default().max_item_width(8);
+
+        let area = Rect::new(0, 0, 40, 1);
+        let mut buf = Buffer::empty(area);
+        let regions = Breadcrumb::new(&state)
+            .style(style.clone())
+            .render_stateful(area, &mut buf);
+
+        // Hovering "Home" (not truncated) leaves no tooltip
+        assert!(handle_breadcrumb_mouse(&moved(2, 0), &mut state, &regions).is_none());
+        assert!(state.tooltip.is_none());
+
+        // Hovering "Docum..." stores the full label below the segment
+        handle_breadcrumb_mouse(&moved(10, 0), &mut state, &regions);
+        assert_eq!(state.tooltip, Some((1, "Documentation".to_string())));
+        assert_eq!(state.tooltip_position, Some((8, 1)));
+
+        // Moving off the segments clears it
+        handle_breadcrumb_mouse(&moved(30, 0), &mut state, &regions);
+        assert!(state.tooltip.is_none());
+        assert!(state.tooltip_position.is_none());
+    }
+
+    #[test]
+    fn test_show_tooltips_renders_tooltip() {
+        let items = vec![BreadcrumbItem::new("docs", "Documentation")];
+        let mut state = BreadcrumbState::new(items);
+        state.tooltip = Some((0, "Documentation".to_string()));
+        state.tooltip_position = Some((1, 1));
+
+        let area = Rect::new(0, 0, 30, 2);
+        let line_area = Rect::new(0, 0, 30, 1);
+
+        let mut buf = Buffer::empty(area);
+        Breadcrumb::new(&state)
+            .style(BreadcrumbStyle::default().max_item_width(8))
+            .render_stateful(line_area, &mut buf);
+        assert_eq!(buf[(2, 1)].symbol(), " ");
+
+        let mut buf = Buffer::empty(area);
+        Breadcrumb::new(&state)
+            .style(
+                BreadcrumbStyle::default()
+                    .max_item_width(8)
+                    .show_tooltips(true),
+            )
+            .render_stateful(line_area, &mut buf);
+        let row: String = (1..16).map(|x| buf[(x, 1)].symbol().to_string()).collect();
+        assert_eq!(row, " Documentation ");
+    }
+
+    #[test]
+    fn test_breadcrumb_state_from_path() {
+        let state = BreadcrumbState::from_path(&["home", "docs", "api"]);
+        assert_eq!(state.len(), 3);
+        assert_eq!(state.items[0].id, "home");
+        assert_eq!(state.items[2].id, "home/docs/api");
+        assert_eq!(state.items[2].label, "api");
+
+        let mut state = state;
+        state.select(2);
+        state.set_path(&["home".to_string()]);
+        assert_eq!(state.len(), 1);
+        assert_eq!(state.selected_index, Some(0));
+    }
+
+    #[test]
+    fn test_breadcrumb_follows_history() {
+        use crate::state::NavigationHistory;
+
+        let mut history = NavigationHistory::new(vec!["home"]);
+        history.push(vec!["home", "docs"]);
+        let mut state = BreadcrumbState::from_path(history.current());
+        assert_eq!(state.len(), 2);
+
+        if let Some(path) = history.back() {
+            state.set_path(path);
+        }
+        assert_eq!(state.len(), 1);
+    }
+
+    fn render_row(state: &BreadcrumbState) -> String {
+        let area = Rect::new(0, 0, 30, 1);
+        let mut buf = Buffer::empty(area);
+        Breadcrumb::new(state).render_stateful(area, &mut buf);
+        (0..30)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_transition_renders_from_then_to() {
+        let mut state = BreadcrumbState::from_path(&["home", "docs", "api"]);
+        state.transition_to_path(&["home", "src"], 4);
+        assert_eq!(state.len(), 2);
+        assert_eq!(render_row(&state), "home > docs > api");
+
+        // Midway the outgoing segments are clipped back toward "home > "
+        state.tick_transition();
+        state.tick_transition();
+        let mid = render_row(&state);
+        assert!(mid.starts_with("home >") && mid.len() < "home > docs > api".len());
+
+        let transition = state.transition.as_mut().unwrap();
+        transition.frame = transition.total_frames;
+        assert_eq!(render_row(&state), "home > src");
+    }
+
+    #[test]
+    fn test_tick_transition_completes() {
+        let mut state = BreadcrumbState::from_path(&["a", "b"]);
+        state.transition_to_path(&["a"], 2);
+        assert!(state.is_transitioning());
+        assert!(state.tick_transition());
+        assert!(!state.tick_transition());
+        assert!(!state.is_transitioning());
+        assert_eq!(render_row(&state), "a");
+
+        // Zero frames switches instantly
+        state.transition_to_path(&["x"], BreadcrumbStyle::default().transition_frames);
+        assert!(!state.is_transitioning());
+        assert_eq!(state.items[0].id, "x");
+    }
+
+    #[test]
+    fn test_handlers_with_style_start_transition() {
+        let style = BreadcrumbStyle::default().transition_frames(3);
+        let mut state = BreadcrumbState::from_path(&["home", "docs", "api"]);
+        state.select_first();
+        let enter = KeyEvent::from(KeyCode::Enter);
+        assert_eq!(
+            handle_breadcrumb_key_with_style(&enter, &mut state, &style),
+            Some(BreadcrumbAction::Navigate("home".into()))
+        );
+        assert_eq!(state.len(), 1);
+        assert_eq!(state.transition.as_ref().unwrap().total_frames, 3);
+        assert_eq!(render_row(&state), "home > docs > api");
+
+        // The last item is already the current path
+        assert!(!state.navigate_to("home", 3));
+
+        // Default style switches instantly
+        let mut state = BreadcrumbState::from_path(&["a", "b"]);
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        let regions = Breadcrumb::new(&state).render_stateful(area, &mut buf);
+        let a = regions
+            .iter()
+            .find(|r| r.data == BreadcrumbAction::Navigate("a".into()))
+            .unwrap()
+            .area;
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: a.x,
+            row: a.y,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        let action = handle_breadcrumb_mouse_with_style(
+            &click,
+            &mut state,
+            &regions,
+            &BreadcrumbStyle::default(),
+        );
+        assert_eq!(action, Some(BreadcrumbAction::Navigate("a".into())));
+        assert!(!state.is_transitioning());
+        assert_eq!(render_row(&state), "a");
+    }
+
+    #[test]
+    fn test_transition_has_no_click_regions() {
+        let mut state = BreadcrumbState::from_path(&["a", "b"]);
+        state.transition_to_path(&["a", "c"], 3);
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        assert!(
+            Breadcrumb::new(&state)
+                .render_stateful(area, &mut buf)
+                .is_empty()
+        );
+    }
 }
@@ -0,0 +1,445 @@
+//! Busy overlay component
+//!
+//! Dims the UI during a blocking operation, shows a centered spinner with a
+//! message, and swallows input except for a cancel key (Ctrl+C by default).
+//!
+//! Render the overlay last, after any dialogs, and offer events to it first
+//! so it sits above everything else while visible.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{BusyOverlay, BusyOverlayState};
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+//! use std::time::Duration;
+//!
+//! let mut state = BusyOverlayState::new().with_min_display(Duration::from_millis(300));
+//! state.show("Saving...");
+//!
+//! // In the event loop
+//! state.tick();
+//!
+//! // In the render function, after everything else
+//! let area = Rect::new(0, 0, 60, 20);
+//! let mut buf = Buffer::empty(area);
+//! BusyOverlay::new(&state).render(area, &mut buf);
+//!
+//! // When the operation finishes; stays up until the minimum time passes
+//! state.hide();
+//! ```
+
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::spinner::{Spinner, SpinnerState, SpinnerStyle};
+use crate::state::{OverlayLayer, OverlayManager};
+use crate::traits::EventResult;
+
+/// Actions emitted by the busy overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusyOverlayAction {
+    /// A cancel key was pressed
+    Cancel,
+}
+
+/// State for the busy overlay
+#[derive(Debug, Clone)]
+pub struct BusyOverlayState {
+    /// Message shown next to the spinner
+    pub message: String,
+    /// Spinner animation state
+    pub spinner: SpinnerState,
+    /// Minimum time the overlay stays visible once shown
+    pub min_display: Duration,
+    /// Keys that cancel the operation (code, modifiers)
+    pub cancel_keys: Vec<(KeyCode, KeyModifiers)>,
+    /// Whether the overlay is visible
+    visible: bool,
+    /// When the overlay was shown
+    shown_at: Option<Instant>,
+    /// Whether hide() was called before the minimum display time
+    hide_pending: bool,
+}
+
+impl Default for BusyOverlayState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BusyOverlayState {
+    /// Create a hidden busy overlay state that cancels on Ctrl+C
+    pub fn new() -> Self {
+        Self {
+            message: String::new(),
+            spinner: SpinnerState::new(),
+            min_display: Duration::ZERO,
+            cancel_keys: vec![(KeyCode::Char('c'), KeyModifiers::CONTROL)],
+            visible: false,
+            shown_at: None,
+            hide_pending: false,
+        }
+    }
+
+    /// Set the minimum display time
+    pub fn with_min_display(mut self, min_display: Duration) -> Self {
+        self.min_display = min_display;
+        self
+    }
+
+    /// Set the keys that cancel the operation (empty for none)
+    pub fn with_cancel_keys(mut self, keys: Vec<(KeyCode, KeyModifiers)>) -> Self {
+        self.cancel_keys = keys;
+        self
+    }
+
+    /// Show the overlay with a message
+    ///
+    /// If already visible, only the message is updated.
+    pub fn show(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+        self.hide_pending = false;
+        if !self.visible {
+            self.visible = true;
+            self.shown_at = Some(Instant::now());
+            self.spinner.reset();
+        }
+    }
+
+    /// Hide the overlay
+    ///
+    /// If the minimum display time has not elapsed, the overlay stays
+    /// visible until a later [`tick`](Self::tick) after it has.
+    pub fn hide(&mut self) {
+        if self.min_display_elapsed() {
+            self.visible = false;
+            self.shown_at = None;
+            self.hide_pending = false;
+        } else {
+            self.hide_pending = true;
+        }
+    }
+
+    /// Advance the spinner and finish a pending hide
+    ///
+    /// Returns true if the overlay needs to be redrawn.
+    pub fn tick(&mut self) -> bool {
+        if !self.visible {
+            return false;
+        }
+        if self.hide_pending && self.min_display_elapsed() {
+            self.hide();
+            return true;
+        }
+        self.spinner.advance_if_ready()
+    }
+
+    /// Check if the overlay is visible
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Check if hide() was called and is waiting for the minimum display time
+    pub fn is_hide_pending(&self) -> bool {
+        self.hide_pending
+    }
+
+    /// Check if a key is one of the cancel keys
+    pub fn is_cancel_key(&self, key: &KeyEvent) -> bool {
+        self.cancel_keys
+            .iter()
+            .any(|(code, modifiers)| key.code == *code && key.modifiers.contains(*modifiers))
+    }
+
+    fn min_display_elapsed(&self) -> bool {
+        self.shown_at
+            .is_none_or(|shown| shown.elapsed() >= self.min_display)
+    }
+}
+
+/// Style configuration for the busy overlay
+#[derive(Debug, Clone)]
+pub struct BusyOverlayStyle {
+    /// Style patched onto every cell behind the box
+    pub shade_style: Style,
+    /// Border style of the box
+    pub border_style: Style,
+    /// Background style of the box
+    pub box_style: Style,
+    /// Spinner and message style
+    pub spinner: SpinnerStyle,
+    /// Horizontal padding inside the box
+    pub padding: u16,
+}
+
+impl Default for BusyOverlayStyle {
+    fn default() -> Self {
+        Self {
+            shade_style: Style::default()
+                .fg(Color::DarkGray)
+                .bg(Color::Black)
+                .add_modifier(Modifier::DIM),
+            border_style: Style::default().fg(Color::Cyan),
+            box_style: Style::default().bg(Color::Black),
+            spinner: SpinnerStyle::default(),
+            padding: 2,
+        }
+    }
+}
+
+impl From<&crate::theme::Theme> for BusyOverlayStyle {
+    fn from(theme: &crate::theme::Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            shade_style: Style::default()
+                .fg(p.text_disabled)
+                .bg(p.bg)
+                .add_modifier(Modifier::DIM),
+            border_style: Style::default().fg(p.border_accent),
+            box_style: Style::default().bg(p.surface),
+            spinner: SpinnerStyle::from(theme),
+            padding: 2,
+        }
+    }
+}
+
+/// Busy overlay widget
+///
+/// Renders nothing while the state is hidden.
+pub struct BusyOverlay<'a> {
+    state: &'a BusyOverlayState,
+    style: BusyOverlayStyle,
+}
+
+impl<'a> BusyOverlay<'a> {
+    /// Create a busy overlay for the given state
+    pub fn new(state: &'a BusyOverlayState) -> Self {
+        Self {
+            state,
+            style: BusyOverlayStyle::default(),
+        }
+    }
+
+    /// Set the style
+    pub fn style(mut self, style: BusyOverlayStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme to derive the style
+    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
+        self.style(BusyOverlayStyle::from(theme))
+    }
+
+    /// Calculate the area of the centered box
+    pub fn box_area(&self, area: Rect) -> Rect {
+        let mut content_width = self.style.spinner.frames.frames()[0].width();
+        if !self.state.message.is_empty() {
+            content_width += self.style.spinner.separator.width() + self.state.message.width();
+        }
+        let content_width = content_width as u16;
+
+        let width = (content_width + self.style.padding * 2 + 2).min(area.width);
+        let height = 3.min(area.height);
+        Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        )
+    }
+
+    /// Render into an [`OverlayManager`] on the [`OverlayLayer::Dialog`] layer
+    ///
+    /// The dimmed backdrop is applied as a style patch, so it doesn't count
+    /// as covering `area`.
+    pub fn render_into_overlays(self, area: Rect, overlays: &mut OverlayManager<'_>) {
+        if !self.state.visible {
+            return;
+        }
+        overlays.capture(OverlayLayer::Dialog, area, |buf| self.render(area, buf));
+    }
+}
+
+impl Widget for BusyOverlay<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if !self.state.visible {
+            return;
+        }
+
+        // Dim everything already drawn in the area
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let cell = &mut buf[(x, y)];
+                cell.set_style(cell.style().patch(self.style.shade_style));
+            }
+        }
+
+        let box_area = self.box_area(area);
+        Clear.render(box_area, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.style.border_style)
+            .style(self.style.box_style);
+        let inner = block.inner(box_area);
+        block.render(box_area, buf);
+
+        let content_area = Rect::new(
+            inner.x + self.style.padding.min(inner.width),
+            inner.y,
+            inner.width.saturating_sub(self.style.padding * 2),
+            inner.height,
+        );
+        let mut spinner = Spinner::new(&self.state.spinner).style(self.style.spinner);
+        if !self.state.message.is_empty() {
+            spinner = spinner.label(&self.state.message);
+        }
+        spinner.render(content_area, buf);
+    }
+}
+
+/// Handle keyboard input while the busy overlay is visible
+///
+/// Swallows every key, returning [`BusyOverlayAction::Cancel`] for a cancel
+/// key. Returns `NotHandled` while hidden so keys reach the UI below.
+pub fn handle_busy_overlay_key(
+    state: &BusyOverlayState,
+    key: &KeyEvent,
+) -> EventResult<BusyOverlayAction> {
+    if !state.visible {
+        EventResult::NotHandled
+    } else if state.is_cancel_key(key) {
+        EventResult::Action(BusyOverlayAction::Cancel)
+    } else {
+        EventResult::Consumed
+    }
+}
+
+/// Handle mouse input while the busy overlay is visible
+///
+/// Swallows every mouse event while visible.
+pub fn handle_busy_overlay_mouse(
+    state: &BusyOverlayState,
+    _mouse: &MouseEvent,
+) -> EventResult<BusyOverlayAction> {
+    if state.visible {
+        EventResult::Consumed
+    } else {
+        EventResult::NotHandled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::MouseEventKind;
+
+    #[test]
+    fn test_show_and_hide() {
+        let mut state = BusyOverlayState::new();
+        assert!(!state.is_visible());
+
+        state.show("Saving");
+        assert!(state.is_visible());
+        assert_eq!(state.message, "Saving");
+
+        state.hide();
+        assert!(!state.is_visible());
+    }
+
+    #[test]
+    fn test_min_display_delays_hide() {
+        let mut state = BusyOverlayState::new().with_min_display(Duration::from_secs(60));
+        state.show("Loading");
+        state.hide();
+        assert!(state.is_visible());
+        assert!(state.is_hide_pending());
+
+        // Pretend the overlay was shown long ago
+        state.shown_at = Some(Instant::now() - Duration::from_secs(61));
+        assert!(state.tick());
+        assert!(!state.is_visible());
+        assert!(!state.is_hide_pending());
+
+        // show() cancels a pending hide
+        state.show("Again");
+        state.hide();
+        state.show("Still busy");
+        assert!(!state.is_hide_pending());
+    }
+
+    #[test]
+    fn test_input_is_swallowed() {
+        let mut state = BusyOverlayState::new();
+        let key = KeyEvent::from(KeyCode::Char('q'));
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        assert_eq!(
+            handle_busy_overlay_key(&state, &key),
+            EventResult::NotHandled
+        );
+        assert_eq!(
+            handle_busy_overlay_mouse(&state, &mouse),
+            EventResult::NotHandled
+        );
+
+        state.show("Working");
+        assert_eq!(handle_busy_overlay_key(&state, &key), EventResult::Consumed);
+        assert_eq!(
+            handle_busy_overlay_key(&state, &ctrl_c),
+            EventResult::Action(BusyOverlayAction::Cancel)
+        );
+        assert_eq!(
+            handle_busy_overlay_mouse(&state, &mouse),
+            EventResult::Consumed
+        );
+
+        let mut state = state.with_cancel_keys(vec![(KeyCode::Esc, KeyModifiers::NONE)]);
+        state.show("Working");
+        assert_eq!(
+            handle_busy_overlay_key(&state, &ctrl_c),
+            EventResult::Consumed
+        );
+        assert_eq!(
+            handle_busy_overlay_key(&state, &KeyEvent::from(KeyCode::Esc)),
+            EventResult::Action(BusyOverlayAction::Cancel)
+        );
+    }
+
+    #[test]
+    fn test_render_dims_and_centers() {
+        let area = Rect::new(0, 0, 30, 9);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(0, 0, "behind", Style::default().fg(Color::White));
+
+        let mut state = BusyOverlayState::new();
+        BusyOverlay::new(&state).render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::White);
+
+        state.show("Saving");
+        BusyOverlay::new(&state).render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "b");
+        assert_eq!(buf[(0, 0)].fg, Color::DarkGray);
+        assert!(buf[(0, 0)].modifier.contains(Modifier::DIM));
+
+        // "⠋ Saving" (8 wide) + 2x2 padding + borders = 14, centered
+        let box_area = BusyOverlay::new(&state).box_area(area);
+        assert_eq!(box_area, Rect::new(8, 3, 14, 3));
+        assert_eq!(buf[(8, 3)].symbol(), "┌");
+        assert_eq!(buf[(13, 4)].symbol(), "S");
+    }
+}
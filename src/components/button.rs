@@ -1,11 +1,14 @@
 //! Button component - Various button views
 //!
-//! Supports single-line, multi-line (block), icon+text, and toggle button styles.
+//! Supports single-line, multi-line (block), icon+text, and toggle button styles,
+//! plus [`ToggleGroup`] segmented controls and [`ButtonGroup`] button rows.
 //!
 //! # Example
 //!
 //! ```rust
-//! use ratatui_interact::components::{Button, ButtonState, ButtonVariant};
+//! use ratatui_interact::components::{
+//!     Button, ButtonSize, ButtonState, ButtonVariant, IconPosition,
+//! };
 //!
 //! let state = ButtonState::enabled();
 //!
@@ -16,29 +19,60 @@
 //! // Icon button
 //! let save_btn = Button::new("Save", &state)
 //!     .icon("💾");
+//! let next_btn = Button::new("Next", &state)
+//!     .icon("▶")
+//!     .icon_position(IconPosition::Trailing);
+//!
+//! // Icon-only toolbar button; the label names it for tooltips
+//! let gear_state = ButtonState::enabled().with_tooltip("Settings");
+//! let gear = Button::new("Settings", &gear_state)
+//!     .icon("⚙")
+//!     .icon_position(IconPosition::Only);
 //!
 //! // Toggle button
 //! let mut toggle_state = ButtonState::enabled();
 //! toggle_state.toggled = true;
 //! let toggle = Button::new("Dark Mode", &toggle_state)
 //!     .variant(ButtonVariant::Toggle);
+//!
+//! // Size variants
+//! let icon_only = Button::new("", &state).icon("✕").size(ButtonSize::Compact);
+//! let wide = Button::new("Continue", &state).size(ButtonSize::Wide).min_width(20);
+//!
+//! // Tooltip shown while the pointer is over the button
+//! let hint_state = ButtonState::enabled().with_tooltip("Save the current file");
+//!
+//! // Disabled button explaining why, in its tooltip and status hint
+//! let save_state = ButtonState::disabled().with_disabled_reason("Fix the errors above first");
+//! assert_eq!(save_state.status_hint(), None);
 //! ```
 
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Position, Rect},
     style::{Color, Modifier, Style},
+    symbols::line,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Widget},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
+use unicode_width::UnicodeWidthStr;
 
-use crate::traits::{ClickRegion, ClickRegionRegistry, FocusId};
+use super::focus_ring::FocusRing;
+use crate::traits::{ClickRegion, ClickRegionRegistry, FocusId, FocusableWidget};
 
 /// Actions a button can emit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ButtonAction {
     /// Button was clicked/activated.
     Click,
+    /// Pointer moved onto the button.
+    Hover,
+    /// Pointer moved off the button.
+    Leave,
+    /// Space/Enter pressed while the button is focused but disabled, e.g.
+    /// to show [`ButtonState::disabled_reason`].
+    DisabledPressed,
 }
 
 /// State for a button.
@@ -46,25 +80,55 @@ pub enum ButtonAction {
 pub struct ButtonState {
     /// Whether the button has focus.
     pub focused: bool,
+    /// Focus ID matched by [`FocusManager::sync_from_vec`](crate::state::FocusManager::sync_from_vec).
+    pub focus_id: FocusId,
     /// Whether the button is currently pressed.
     pub pressed: bool,
     /// Whether the button is enabled.
     pub enabled: bool,
     /// For toggle buttons: whether the button is toggled on.
     pub toggled: bool,
+    /// Tooltip text shown while the pointer is over the button.
+    pub tooltip: Option<String>,
+    /// Whether the pointer is over the button.
+    pub hovered: bool,
+    /// Whether the caller should draw the tooltip (hovered with tooltip text,
+    /// see [`tooltip_text`](ButtonState::tooltip_text)).
+    pub tooltip_visible: bool,
+    /// Why the button is disabled, shown instead of the tooltip while it is.
+    pub disabled_reason: Option<String>,
 }
 
 impl Default for ButtonState {
     fn default() -> Self {
         Self {
             focused: false,
+            focus_id: FocusId::default(),
             pressed: false,
             enabled: true,
             toggled: false,
+            tooltip: None,
+            hovered: false,
+            tooltip_visible: false,
+            disabled_reason: None,
         }
     }
 }
 
+impl FocusableWidget for ButtonState {
+    fn focus_id(&self) -> FocusId {
+        self.focus_id
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
 impl ButtonState {
     /// Create an enabled button state.
     pub fn enabled() -> Self {
@@ -91,11 +155,59 @@ impl ButtonState {
         }
     }
 
+    /// Set the focus ID used by [`FocusableWidget`].
+    pub fn with_focus_id(mut self, id: FocusId) -> Self {
+        self.focus_id = id;
+        self
+    }
+
+    /// Set the tooltip text.
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Set why the button is disabled.
+    pub fn with_disabled_reason(mut self, reason: impl Into<String>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+
+    /// Set or clear why the button is disabled.
+    pub fn set_disabled_reason(&mut self, reason: Option<String>) {
+        self.disabled_reason = reason;
+        self.tooltip_visible = self.hovered && self.tooltip_text().is_some();
+    }
+
+    /// Text for the hover tooltip: the disabled reason while disabled,
+    /// otherwise the tooltip.
+    pub fn tooltip_text(&self) -> Option<&str> {
+        match &self.disabled_reason {
+            Some(reason) if !self.enabled => Some(reason),
+            _ => self.tooltip.as_deref(),
+        }
+    }
+
+    /// The disabled reason while the button is disabled and hovered or
+    /// focused, for a status bar.
+    pub fn status_hint(&self) -> Option<&str> {
+        if self.enabled || !(self.hovered || self.focused) {
+            return None;
+        }
+        self.disabled_reason.as_deref()
+    }
+
     /// Set the focus state.
     pub fn set_focused(&mut self, focused: bool) {
         self.focused = focused;
     }
 
+    /// Set the hover state, showing the tooltip if there is text for it.
+    pub fn set_hovered(&mut self, hovered: bool) {
+        self.hovered = hovered;
+        self.tooltip_visible = hovered && self.tooltip_text().is_some();
+    }
+
     /// Set the pressed state.
     pub fn set_pressed(&mut self, pressed: bool) {
         self.pressed = pressed;
@@ -104,6 +216,7 @@ impl ButtonState {
     /// Set the enabled state.
     pub fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
+        self.tooltip_visible = self.hovered && self.tooltip_text().is_some();
     }
 
     /// Toggle the toggled state.
@@ -130,11 +243,55 @@ pub enum ButtonVariant {
     Minimal,
 }
 
+/// Button size variants.
+///
+/// Sizes control the horizontal padding of the `SingleLine`, `Toggle`, and
+/// `Block` variants. `IconText` and `Minimal` are never padded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ButtonSize {
+    /// No side padding, for icon-only or narrow layouts.
+    Compact,
+    /// One column of padding on each side.
+    #[default]
+    Normal,
+    /// Extra padding, rendered with a border like `ButtonVariant::Block`.
+    Wide,
+}
+
+impl ButtonSize {
+    /// Default horizontal padding `(left, right)` for this size.
+    pub fn padding(&self) -> (u16, u16) {
+        match self {
+            ButtonSize::Compact => (0, 0),
+            ButtonSize::Normal => (1, 1),
+            ButtonSize::Wide => (3, 3),
+        }
+    }
+}
+
+/// Where a button's icon sits relative to its label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconPosition {
+    /// Icon before the label: `💾 Save`
+    #[default]
+    Leading,
+    /// Icon after the label: `Next ▶`
+    Trailing,
+    /// Icon alone; the label is only the accessible name, e.g. for a tooltip.
+    Only,
+}
+
 /// Button styling.
 #[derive(Debug, Clone)]
 pub struct ButtonStyle {
     /// The button variant.
     pub variant: ButtonVariant,
+    /// The button size.
+    pub size: ButtonSize,
+    /// Padding left of the content, in columns.
+    pub padding_left: u16,
+    /// Padding right of the content, in columns.
+    pub padding_right: u16,
     /// Foreground color when focused.
     pub focused_fg: Color,
     /// Background color when focused.
@@ -153,12 +310,19 @@ pub struct ButtonStyle {
     pub toggled_fg: Color,
     /// Background color when toggled.
     pub toggled_bg: Color,
+    /// Focus ring for the border, or an outline when borderless (none by default).
+    pub focus_ring: Option<FocusRing>,
+    /// Style for the hover tooltip drawn by the caller.
+    pub tooltip_style: Style,
 }
 
 impl Default for ButtonStyle {
     fn default() -> Self {
         Self {
             variant: ButtonVariant::SingleLine,
+            size: ButtonSize::Normal,
+            padding_left: 1,
+            padding_right: 1,
             focused_fg: Color::Black,
             focused_bg: Color::Yellow,
             unfocused_fg: Color::White,
@@ -168,6 +332,8 @@ impl Default for ButtonStyle {
             pressed_bg: Color::White,
             toggled_fg: Color::Black,
             toggled_bg: Color::Green,
+            focus_ring: None,
+            tooltip_style: Style::default().fg(Color::White).bg(Color::DarkGray),
         }
     }
 }
@@ -187,6 +353,20 @@ impl ButtonStyle {
         self
     }
 
+    /// Set the size, resetting the padding to the size's defaults.
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        (self.padding_left, self.padding_right) = size.padding();
+        self
+    }
+
+    /// Set the horizontal padding.
+    pub fn padding(mut self, left: u16, right: u16) -> Self {
+        self.padding_left = left;
+        self.padding_right = right;
+        self
+    }
+
     /// Set focused colors.
     pub fn focused(mut self, fg: Color, bg: Color) -> Self {
         self.focused_fg = fg;
@@ -208,6 +388,18 @@ impl ButtonStyle {
         self
     }
 
+    /// Set the focus ring.
+    pub fn focus_ring(mut self, ring: FocusRing) -> Self {
+        self.focus_ring = Some(ring);
+        self
+    }
+
+    /// Set the tooltip style.
+    pub fn tooltip_style(mut self, style: Style) -> Self {
+        self.tooltip_style = style;
+        self
+    }
+
     /// Primary button style (prominent).
     pub fn primary() -> Self {
         Self {
@@ -247,6 +439,9 @@ impl From<&crate::theme::Theme> for ButtonStyle {
         let p = &theme.palette;
         Self {
             variant: ButtonVariant::SingleLine,
+            size: ButtonSize::Normal,
+            padding_left: 1,
+            padding_right: 1,
             focused_fg: p.highlight_fg,
             focused_bg: p.highlight_bg,
             unfocused_fg: p.text,
@@ -256,6 +451,8 @@ impl From<&crate::theme::Theme> for ButtonStyle {
             pressed_bg: p.pressed_bg,
             toggled_fg: p.highlight_fg,
             toggled_bg: p.success,
+            focus_ring: None,
+            tooltip_style: Style::default().fg(p.text).bg(p.surface),
         }
     }
 }
@@ -266,10 +463,14 @@ impl From<&crate::theme::Theme> for ButtonStyle {
 pub struct Button<'a> {
     label: &'a str,
     icon: Option<&'a str>,
+    icon_position: IconPosition,
     state: &'a ButtonState,
     style: ButtonStyle,
     focus_id: FocusId,
     alignment: Alignment,
+    min_width: u16,
+    /// Show the toggled colors in any variant (toggle group segments).
+    segment: bool,
 }
 
 impl<'a> Button<'a> {
@@ -283,19 +484,34 @@ impl<'a> Button<'a> {
         Self {
             label,
             icon: None,
+            icon_position: IconPosition::Leading,
             state,
             style: ButtonStyle::default(),
             focus_id: FocusId::default(),
             alignment: Alignment::Center,
+            min_width: 0,
+            segment: false,
         }
     }
 
-    /// Set an icon to display before the label.
+    /// Set an icon, displayed before the label unless
+    /// [`icon_position`](Self::icon_position) says otherwise.
     pub fn icon(mut self, icon: &'a str) -> Self {
         self.icon = Some(icon);
         self
     }
 
+    /// Set where the icon sits relative to the label.
+    pub fn icon_position(mut self, position: IconPosition) -> Self {
+        self.icon_position = position;
+        self
+    }
+
+    /// The label, which names the button even when only its icon is shown.
+    pub fn accessible_name(&self) -> &'a str {
+        self.label
+    }
+
     /// Set the button style.
     pub fn style(mut self, style: ButtonStyle) -> Self {
         self.style = style;
@@ -313,6 +529,20 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Set the button size, resetting the padding to the size's defaults.
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.style = self.style.size(size);
+        self
+    }
+
+    /// Ensure the button is at least `width` columns wide.
+    ///
+    /// Padding is increased evenly on both sides to fill the width.
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = width;
+        self
+    }
+
     /// Set the focus ID.
     pub fn focus_id(mut self, id: FocusId) -> Self {
         self.focus_id = id;
@@ -333,7 +563,9 @@ impl<'a> Button<'a> {
             Style::default()
                 .fg(self.style.pressed_fg)
                 .bg(self.style.pressed_bg)
-        } else if self.style.variant == ButtonVariant::Toggle && self.state.toggled {
+        } else if (self.style.variant == ButtonVariant::Toggle || self.segment)
+            && self.state.toggled
+        {
             Style::default()
                 .fg(self.style.toggled_fg)
                 .bg(self.style.toggled_bg)
@@ -350,42 +582,85 @@ impl<'a> Button<'a> {
         }
     }
 
+    /// Whether the button renders with a border.
+    fn is_bordered(&self) -> bool {
+        self.style.variant == ButtonVariant::Block || self.style.size == ButtonSize::Wide
+    }
+
+    /// Whether padding applies to the current variant.
+    fn is_padded(&self) -> bool {
+        matches!(
+            self.style.variant,
+            ButtonVariant::SingleLine | ButtonVariant::Toggle | ButtonVariant::Block
+        )
+    }
+
+    /// Icon and label, without padding.
+    fn content(&self) -> String {
+        match (self.icon, self.icon_position) {
+            (Some(icon), _) if self.label.is_empty() => icon.to_string(),
+            (Some(icon), IconPosition::Only) => icon.to_string(),
+            (Some(icon), IconPosition::Leading) => format!("{} {}", icon, self.label),
+            (Some(icon), IconPosition::Trailing) => format!("{} {}", self.label, icon),
+            (None, _) => self.label.to_string(),
+        }
+    }
+
+    /// Padding `(left, right)` including any extra needed to reach `min_width`.
+    fn padding(&self) -> (u16, u16) {
+        if !self.is_padded() {
+            return (0, 0);
+        }
+        let (left, right) = (self.style.padding_left, self.style.padding_right);
+        let border = if self.is_bordered() { 2 } else { 0 };
+        let natural = self.content().width() as u16 + left + right + border;
+        let extra = self.min_width.saturating_sub(natural);
+        (left + extra / 2, right + extra - extra / 2)
+    }
+
     /// Build the button text.
     fn build_text(&self) -> String {
-        match self.style.variant {
-            ButtonVariant::SingleLine | ButtonVariant::Toggle => {
-                if let Some(icon) = self.icon {
-                    format!(" {} {} ", icon, self.label)
-                } else {
-                    format!(" {} ", self.label)
-                }
-            }
-            ButtonVariant::Block | ButtonVariant::IconText | ButtonVariant::Minimal => {
-                if let Some(icon) = self.icon {
-                    format!("{} {}", icon, self.label)
-                } else {
-                    self.label.to_string()
-                }
-            }
-        }
+        let (left, right) = self.padding();
+        format!(
+            "{}{}{}",
+            " ".repeat(left as usize),
+            self.content(),
+            " ".repeat(right as usize)
+        )
     }
 
-    /// Calculate minimum width for this button.
-    pub fn min_width(&self) -> u16 {
-        let text = self.build_text();
-        let text_len = text.chars().count() as u16;
+    /// Calculate the rendered width of this button.
+    pub fn width(&self) -> u16 {
+        let text_width = self.build_text().width() as u16;
+        let width = if self.is_bordered() {
+            text_width + 2
+        } else {
+            text_width
+        };
+        width.max(self.min_width)
+    }
 
-        match self.style.variant {
-            ButtonVariant::Block => text_len + 4, // Border + padding
-            _ => text_len,
-        }
+    /// Where a borderless button's text lands within `area`.
+    fn content_area(&self, area: Rect) -> Rect {
+        let width = self.width().min(area.width);
+        let aligned = matches!(
+            self.style.variant,
+            ButtonVariant::SingleLine | ButtonVariant::Toggle | ButtonVariant::Minimal
+        );
+        let offset = match self.alignment {
+            Alignment::Center if aligned => (area.width - width) / 2,
+            Alignment::Right if aligned => area.width - width,
+            _ => 0,
+        };
+        Rect::new(area.x + offset, area.y, width, area.height.min(1))
     }
 
     /// Calculate minimum height for this button.
     pub fn min_height(&self) -> u16 {
-        match self.style.variant {
-            ButtonVariant::Block => 3, // Border top + content + border bottom
-            _ => 1,
+        if self.is_bordered() {
+            3 // Border top + content + border bottom
+        } else {
+            1
         }
     }
 
@@ -415,9 +690,15 @@ impl<'a> Button<'a> {
     /// registry.register(region.area, 0);
     /// ```
     pub fn render_stateful(self, area: Rect, buf: &mut Buffer) -> ClickRegion<ButtonAction> {
-        let click_area = match self.style.variant {
-            ButtonVariant::Block => area,
-            _ => Rect::new(area.x, area.y, self.min_width().min(area.width), 1),
+        let click_area = if self.is_bordered() {
+            Rect::new(
+                area.x,
+                area.y,
+                area.width,
+                self.min_height().min(area.height),
+            )
+        } else {
+            Rect::new(area.x, area.y, self.width().min(area.width), 1)
         };
 
         self.render(area, buf);
@@ -481,6 +762,26 @@ impl Widget for Button<'_> {
         let style = self.current_style();
         let text = self.build_text();
 
+        if self.is_bordered() {
+            let block = Block::default().borders(Borders::ALL).border_style(style);
+
+            let inner = block.inner(area);
+            block.render(area, buf);
+            if let Some(ring) = self.style.focus_ring {
+                ring.paint_border(area, buf, self.state.focused, self.state.enabled);
+            }
+
+            let paragraph = Paragraph::new(text).style(style).alignment(self.alignment);
+            paragraph.render(inner, buf);
+            return;
+        }
+
+        let ring = self
+            .style
+            .focus_ring
+            .map(|ring| (ring, self.content_area(area)));
+        let focused = self.state.focused && self.state.enabled;
+
         match self.style.variant {
             ButtonVariant::SingleLine | ButtonVariant::Toggle | ButtonVariant::Minimal => {
                 let line = Line::from(Span::styled(text, style));
@@ -488,22 +789,791 @@ impl Widget for Button<'_> {
                 paragraph.render(area, buf);
             }
 
-            ButtonVariant::Block => {
-                let block = Block::default().borders(Borders::ALL).border_style(style);
+            ButtonVariant::IconText | ButtonVariant::Block => {
+                let line = Line::from(Span::styled(text, style));
+                let paragraph = Paragraph::new(line);
+                paragraph.render(area, buf);
+            }
+        }
+
+        if let Some((ring, content)) = ring {
+            ring.render_outline(content, buf, focused);
+        }
+    }
+}
 
-                let inner = block.inner(area);
-                block.render(area, buf);
+/// Draw the hover tooltip of a button rendered at `button_area`.
+///
+/// Does nothing unless `state.tooltip_visible`. The tooltip shows
+/// [`ButtonState::tooltip_text`] with [`ButtonStyle::tooltip_style`] on the
+/// row below the button, or above it when there is no room below, kept
+/// within `bounds` (usually the frame area). Render it after everything it
+/// may overlap.
+pub fn render_button_tooltip(
+    state: &ButtonState,
+    style: &ButtonStyle,
+    button_area: Rect,
+    bounds: Rect,
+    buf: &mut Buffer,
+) {
+    let Some(text) = state.tooltip_text().filter(|_| state.tooltip_visible) else {
+        return;
+    };
+    let bounds = bounds.intersection(buf.area);
+    if bounds.is_empty() {
+        return;
+    }
+    let y = if button_area.bottom() < bounds.bottom() {
+        button_area.bottom()
+    } else if button_area.y > bounds.y {
+        button_area.y - 1
+    } else {
+        return;
+    };
+    let text = format!(" {} ", text);
+    let width = (text.width() as u16).min(bounds.width);
+    let x = button_area
+        .x
+        .clamp(bounds.x, bounds.right().saturating_sub(width));
+    let area = Rect::new(x, y, width, 1);
+    Clear.render(area, buf);
+    Paragraph::new(text)
+        .style(style.tooltip_style)
+        .render(area, buf);
+}
 
-                let paragraph = Paragraph::new(text).style(style).alignment(self.alignment);
-                paragraph.render(inner, buf);
+/// Handle keyboard input for a focused button.
+///
+/// Space/Enter return [`ButtonAction::Click`], or
+/// [`ButtonAction::DisabledPressed`] when the button is disabled so the
+/// caller can say why (see [`ButtonState::disabled_reason`]). Keys are
+/// ignored while the button is not focused.
+pub fn handle_button_key(key: &KeyEvent, state: &ButtonState) -> Option<ButtonAction> {
+    if !state.focused {
+        return None;
+    }
+    match key.code {
+        KeyCode::Enter | KeyCode::Char(' ') if state.enabled => Some(ButtonAction::Click),
+        KeyCode::Enter | KeyCode::Char(' ') => Some(ButtonAction::DisabledPressed),
+        _ => None,
+    }
+}
+
+/// Handle mouse events for a button.
+///
+/// Returns `Some(ButtonAction)` if an action was triggered, `None` otherwise.
+///
+/// `MouseEventKind::Moved` onto the button sets `state.hovered` and returns
+/// [`ButtonAction::Hover`]; moving off clears it and returns
+/// [`ButtonAction::Leave`]. While hovered, `state.tooltip_visible` is set if
+/// the button has tooltip text (its tooltip, or its disabled reason while
+/// disabled), for the caller to draw with [`render_button_tooltip`]. A left
+/// click on an enabled button returns [`ButtonAction::Click`].
+///
+/// # Arguments
+///
+/// * `mouse` - The mouse event
+/// * `state` - Mutable reference to button state
+/// * `area` - The button's area, e.g. from `render_stateful`
+pub fn handle_button_mouse(
+    mouse: &MouseEvent,
+    state: &mut ButtonState,
+    area: Rect,
+) -> Option<ButtonAction> {
+    let inside = area.contains(Position::new(mouse.column, mouse.row));
+
+    match mouse.kind {
+        MouseEventKind::Moved if inside != state.hovered => {
+            state.set_hovered(inside);
+            Some(if inside {
+                ButtonAction::Hover
+            } else {
+                ButtonAction::Leave
+            })
+        }
+        MouseEventKind::Down(MouseButton::Left) if inside && state.enabled => {
+            Some(ButtonAction::Click)
+        }
+        _ => None,
+    }
+}
+
+/// Actions a toggle group can emit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToggleGroupAction<T> {
+    /// A different segment became active.
+    Changed(T),
+}
+
+/// State for a [`ToggleGroup`]: the segment values and which one is active.
+///
+/// Exactly one segment is active at a time (none only when there are no
+/// values).
+#[derive(Debug, Clone)]
+pub struct ToggleGroupState<T> {
+    values: Vec<T>,
+    active: usize,
+    /// Whether the group has focus.
+    pub focused: bool,
+    /// Whether the group is enabled.
+    pub enabled: bool,
+}
+
+impl<T> ToggleGroupState<T> {
+    /// Create a group over `values` with the first one active.
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+            active: 0,
+            focused: false,
+            enabled: true,
+        }
+    }
+
+    /// Make `value` the active segment, if present.
+    pub fn with_active(mut self, value: &T) -> Self
+    where
+        T: PartialEq,
+    {
+        self.set_active(value);
+        self
+    }
+
+    /// The segment values.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Number of segments.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether there are no segments.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The active value.
+    pub fn active(&self) -> Option<&T> {
+        self.values.get(self.active)
+    }
+
+    /// Index of the active segment.
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    /// Make `value` the active segment without emitting an action.
+    ///
+    /// Returns `false` if `value` is not one of the segments.
+    pub fn set_active(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.values.iter().position(|v| v == value) {
+            Some(index) => {
+                self.active = index;
+                true
             }
+            None => false,
+        }
+    }
 
-            ButtonVariant::IconText => {
-                let line = Line::from(Span::styled(text, style));
-                let paragraph = Paragraph::new(line);
-                paragraph.render(area, buf);
+    /// Activate the segment at `index`.
+    ///
+    /// Returns [`ToggleGroupAction::Changed`] if a different, existing
+    /// segment became active.
+    pub fn select_index(&mut self, index: usize) -> Option<ToggleGroupAction<T>>
+    where
+        T: Clone,
+    {
+        if index == self.active || index >= self.values.len() {
+            return None;
+        }
+        self.active = index;
+        Some(ToggleGroupAction::Changed(self.values[index].clone()))
+    }
+
+    /// Activate the next segment, stopping at the last.
+    pub fn select_next(&mut self) -> Option<ToggleGroupAction<T>>
+    where
+        T: Clone,
+    {
+        self.select_index(self.active + 1)
+    }
+
+    /// Activate the previous segment, stopping at the first.
+    pub fn select_prev(&mut self) -> Option<ToggleGroupAction<T>>
+    where
+        T: Clone,
+    {
+        self.select_index(self.active.checked_sub(1)?)
+    }
+
+    /// The toggled flag of each segment: `true` for the active one only.
+    pub fn toggled_flags(&self) -> Vec<bool> {
+        (0..self.values.len()).map(|i| i == self.active).collect()
+    }
+
+    /// A [`ButtonState`] per segment, for rendering the segments as
+    /// individual buttons.
+    ///
+    /// The active segment is toggled, and focused while the group is.
+    pub fn button_states(&self) -> Vec<ButtonState> {
+        self.toggled_flags()
+            .into_iter()
+            .map(|toggled| ButtonState {
+                toggled,
+                focused: toggled && self.focused,
+                enabled: self.enabled,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Key bindings handled by [`handle_toggle_group_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("←/→", "Previous/next segment"),
+            ("Home/End", "First/last segment"),
+        ]
+    }
+}
+
+/// Segmented control: adjacent toggle buttons of which exactly one is active.
+///
+/// Segments render with the `Toggle` variant by default. With the `Block`
+/// variant (or `ButtonSize::Wide`), neighbouring segments share a border so
+/// the group reads as one control.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect};
+/// use ratatui_interact::components::{ButtonVariant, ToggleGroup, ToggleGroupState};
+/// use ratatui_interact::traits::ClickRegionRegistry;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum View { List, Grid }
+///
+/// let state = ToggleGroupState::new([View::List, View::Grid]);
+/// let mut registry = ClickRegionRegistry::new();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+/// ToggleGroup::new(&["List", "Grid"], &state)
+///     .variant(ButtonVariant::Block)
+///     .render_with_registry(buf.area, &mut buf, &mut registry);
+/// assert_eq!(registry.len(), 2);
+/// ```
+pub struct ToggleGroup<'a, T> {
+    labels: &'a [&'a str],
+    icons: &'a [&'a str],
+    icon_position: IconPosition,
+    state: &'a ToggleGroupState<T>,
+    style: ButtonStyle,
+}
+
+impl<'a, T> ToggleGroup<'a, T> {
+    /// Create a toggle group with one label per segment.
+    pub fn new(labels: &'a [&'a str], state: &'a ToggleGroupState<T>) -> Self {
+        Self {
+            labels,
+            icons: &[],
+            icon_position: IconPosition::Leading,
+            state,
+            style: ButtonStyle::new(ButtonVariant::Toggle),
+        }
+    }
+
+    /// Set one icon per segment.
+    pub fn icons(mut self, icons: &'a [&'a str]) -> Self {
+        self.icons = icons;
+        self
+    }
+
+    /// Set where the icons sit. With [`IconPosition::Only`], each segment's
+    /// label becomes its tooltip.
+    pub fn icon_position(mut self, position: IconPosition) -> Self {
+        self.icon_position = position;
+        self
+    }
+
+    /// Set the segment style.
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme, keeping the current variant.
+    pub fn theme(mut self, theme: &crate::theme::Theme) -> Self {
+        let variant = self.style.variant;
+        self.style = ButtonStyle::from(theme).variant(variant);
+        self
+    }
+
+    /// Set the segment variant.
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.style.variant = variant;
+        self
+    }
+
+    /// Per-segment button states, with tooltips for icon-only segments.
+    fn segment_states(&self) -> Vec<ButtonState> {
+        let mut states = self.state.button_states();
+        if self.icon_position == IconPosition::Only {
+            for (state, label) in states.iter_mut().zip(self.labels) {
+                state.tooltip = Some(label.to_string());
+            }
+        }
+        states
+    }
+
+    fn segment<'b>(&self, index: usize, state: &'b ButtonState) -> Button<'b>
+    where
+        'a: 'b,
+    {
+        let mut button = Button::new(self.labels.get(index).copied().unwrap_or(""), state)
+            .style(self.style.clone())
+            .icon_position(self.icon_position);
+        if let Some(icon) = self.icons.get(index) {
+            button = button.icon(icon);
+        }
+        button.segment = true;
+        button
+    }
+
+    fn is_bordered(&self) -> bool {
+        self.style.variant == ButtonVariant::Block || self.style.size == ButtonSize::Wide
+    }
+
+    /// Total width, counting shared borders once.
+    pub fn width(&self) -> u16 {
+        let states = self.segment_states();
+        let widths = (0..states.len()).map(|i| self.segment(i, &states[i]).width());
+        let overlap = if self.is_bordered() {
+            states.len().saturating_sub(1) as u16
+        } else {
+            0
+        };
+        widths.sum::<u16>().saturating_sub(overlap)
+    }
+
+    /// Render the group and return one click region per visible segment,
+    /// carrying the segment index.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer) -> Vec<ClickRegion<usize>> {
+        let states = self.segment_states();
+        let bordered = self.is_bordered();
+        let height = if bordered { area.height.min(3) } else { 1 };
+
+        // Segment areas, overlapping by one column where borders are shared
+        let mut areas = Vec::with_capacity(states.len());
+        let mut x = area.x;
+        for (index, state) in states.iter().enumerate() {
+            if x >= area.right() {
+                break;
+            }
+            let width = self.segment(index, state).width().min(area.right() - x);
+            areas.push(Rect::new(x, area.y, width, height));
+            x += width;
+            if bordered {
+                x -= 1;
+            }
+        }
+
+        // The active segment last, so its border wins on shared columns
+        let active = self.state.active_index();
+        let order = (0..areas.len())
+            .filter(|&i| i != active)
+            .chain((active < areas.len()).then_some(active));
+        for index in order {
+            self.segment(index, &states[index])
+                .render(areas[index], buf);
+        }
+
+        if bordered && height == 3 {
+            for segment in areas.iter().skip(1) {
+                buf[(segment.x, segment.y)].set_symbol(line::NORMAL.horizontal_down);
+                buf[(segment.x, segment.y + 2)].set_symbol(line::NORMAL.horizontal_up);
             }
         }
+
+        areas
+            .into_iter()
+            .enumerate()
+            .map(|(index, area)| ClickRegion::new(area, index))
+            .collect()
+    }
+
+    /// Render the group and register each segment's click region.
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<usize>,
+    ) {
+        for region in self.render_stateful(area, buf) {
+            registry.register(region.area, region.data);
+        }
+    }
+}
+
+impl<T> Widget for ToggleGroup<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_stateful(area, buf);
+    }
+}
+
+/// Handle keyboard input for a toggle group.
+///
+/// Left/Right move to the previous/next segment and Home/End to the
+/// first/last, returning [`ToggleGroupAction::Changed`] when the active
+/// segment changes.
+pub fn handle_toggle_group_key<T: Clone>(
+    key: &KeyEvent,
+    state: &mut ToggleGroupState<T>,
+) -> Option<ToggleGroupAction<T>> {
+    if !state.enabled {
+        return None;
+    }
+    match key.code {
+        KeyCode::Left => state.select_prev(),
+        KeyCode::Right => state.select_next(),
+        KeyCode::Home => state.select_index(0),
+        KeyCode::End => state.select_index(state.len().checked_sub(1)?),
+        _ => None,
+    }
+}
+
+/// Handle mouse input for a toggle group.
+///
+/// A left click on a segment registered by
+/// [`ToggleGroup::render_with_registry`] activates it.
+pub fn handle_toggle_group_mouse<T: Clone>(
+    mouse: &MouseEvent,
+    state: &mut ToggleGroupState<T>,
+    registry: &ClickRegionRegistry<usize>,
+) -> Option<ToggleGroupAction<T>> {
+    if !state.enabled {
+        return None;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let &index = registry.handle_click(mouse.column, mouse.row)?;
+            state.select_index(index)
+        }
+        _ => None,
+    }
+}
+
+/// Actions a button group can emit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ButtonGroupAction<T> {
+    /// An enabled button was pressed.
+    Pressed(T),
+}
+
+/// State for a [`ButtonGroup`]: one value per button, which buttons are
+/// enabled, and which one has focus.
+#[derive(Debug, Clone)]
+pub struct ButtonGroupState<T> {
+    values: Vec<T>,
+    enabled: Vec<bool>,
+    /// Index of the focused button, or `None` while the group is unfocused.
+    pub focused: Option<usize>,
+}
+
+impl<T> ButtonGroupState<T> {
+    /// Create an unfocused group over `values` with every button enabled.
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let values: Vec<T> = values.into_iter().collect();
+        Self {
+            enabled: vec![true; values.len()],
+            values,
+            focused: None,
+        }
+    }
+
+    /// Enable or disable the button at `index`.
+    pub fn with_enabled(mut self, index: usize, enabled: bool) -> Self {
+        self.set_enabled(index, enabled);
+        self
+    }
+
+    /// The button values.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Number of buttons.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether there are no buttons.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Whether the button at `index` exists and is enabled.
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.enabled.get(index).copied().unwrap_or(false)
+    }
+
+    /// Enable or disable the button at `index`.
+    ///
+    /// Disabling the focused button leaves the group unfocused.
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(flag) = self.enabled.get_mut(index) {
+            *flag = enabled;
+            if !enabled && self.focused == Some(index) {
+                self.focused = None;
+            }
+        }
+    }
+
+    /// The focused button's value.
+    pub fn focused_value(&self) -> Option<&T> {
+        self.values.get(self.focused?)
+    }
+
+    /// Focus the enabled button holding `value`.
+    ///
+    /// Returns `false` if there is no such button.
+    pub fn focus_value(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.values.iter().position(|v| v == value) {
+            Some(index) if self.is_enabled(index) => {
+                self.focused = Some(index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Focus the next enabled button, stopping at the last.
+    ///
+    /// An unfocused group focuses its first enabled button.
+    pub fn focus_next(&mut self) {
+        let start = self.focused.map_or(0, |index| index + 1);
+        if let Some(index) = (start..self.len()).find(|&i| self.is_enabled(i)) {
+            self.focused = Some(index);
+        }
+    }
+
+    /// Focus the previous enabled button, stopping at the first.
+    ///
+    /// An unfocused group focuses its last enabled button.
+    pub fn focus_prev(&mut self) {
+        let end = self.focused.unwrap_or(self.len());
+        if let Some(index) = (0..end).rev().find(|&i| self.is_enabled(i)) {
+            self.focused = Some(index);
+        }
+    }
+
+    /// Press the button at `index`, focusing it.
+    ///
+    /// Returns [`ButtonGroupAction::Pressed`] if the button is enabled.
+    pub fn press(&mut self, index: usize) -> Option<ButtonGroupAction<T>>
+    where
+        T: Clone,
+    {
+        if !self.is_enabled(index) {
+            return None;
+        }
+        self.focused = Some(index);
+        Some(ButtonGroupAction::Pressed(self.values[index].clone()))
+    }
+
+    /// A [`ButtonState`] per button, for rendering them individually.
+    pub fn button_states(&self) -> Vec<ButtonState> {
+        self.enabled
+            .iter()
+            .enumerate()
+            .map(|(index, &enabled)| ButtonState {
+                enabled,
+                focused: self.focused == Some(index),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Key bindings handled by [`handle_button_group_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("←/→", "Previous/next button"),
+            ("Enter/Space", "Press focused button"),
+        ]
+    }
+}
+
+/// A row of buttons sharing one style, such as a dialog's Back/Next
+/// footer.
+///
+/// Unlike a [`ToggleGroup`], buttons are independent: each can be enabled
+/// or disabled, and pressing one emits its value. Disabled buttons are
+/// drawn but register no click region.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::{Alignment, Rect}};
+/// use ratatui_interact::components::{ButtonGroup, ButtonGroupState};
+/// use ratatui_interact::traits::ClickRegionRegistry;
+///
+/// let state = ButtonGroupState::new(["back", "next"]).with_enabled(0, false);
+/// let mut registry = ClickRegionRegistry::new();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 30, 1));
+/// ButtonGroup::new(&["Back", "Next"], &state)
+///     .alignment(Alignment::Right)
+///     .render_with_registry(buf.area, &mut buf, &mut registry);
+/// assert_eq!(registry.len(), 1);
+/// ```
+pub struct ButtonGroup<'a, T> {
+    labels: &'a [&'a str],
+    state: &'a ButtonGroupState<T>,
+    style: ButtonStyle,
+    spacing: u16,
+    alignment: Alignment,
+}
+
+impl<'a, T> ButtonGroup<'a, T> {
+    /// Create a button group with one label per button.
+    pub fn new(labels: &'a [&'a str], state: &'a ButtonGroupState<T>) -> Self {
+        Self {
+            labels,
+            state,
+            style: ButtonStyle::default(),
+            spacing: 1,
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Set the button style.
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme to derive the button style.
+    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
+        self.style(ButtonStyle::from(theme))
+    }
+
+    /// Set the gap between buttons (default 1).
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set where the row sits within the render area.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    fn button<'b>(&self, index: usize, state: &'b ButtonState) -> Button<'b>
+    where
+        'a: 'b,
+    {
+        Button::new(self.labels.get(index).copied().unwrap_or(""), state).style(self.style.clone())
+    }
+
+    /// Total width, including the gaps between buttons.
+    pub fn width(&self) -> u16 {
+        let states = self.state.button_states();
+        let buttons: u16 = (0..states.len())
+            .map(|i| self.button(i, &states[i]).width())
+            .sum();
+        buttons + self.spacing * states.len().saturating_sub(1) as u16
+    }
+
+    /// Render the group and return one click region per visible enabled
+    /// button, carrying the button index.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer) -> Vec<ClickRegion<usize>> {
+        let states = self.state.button_states();
+        let free = area.width.saturating_sub(self.width());
+        let mut x = area.x
+            + match self.alignment {
+                Alignment::Center => free / 2,
+                Alignment::Right => free,
+                _ => 0,
+            };
+
+        let mut regions = Vec::new();
+        for (index, state) in states.iter().enumerate() {
+            if x >= area.right() {
+                break;
+            }
+            let button = self.button(index, state);
+            let width = button.width().min(area.right() - x);
+            let height = button.min_height().min(area.height);
+            let region = button.render_stateful(Rect::new(x, area.y, width, height), buf);
+            if state.enabled {
+                regions.push(ClickRegion::new(region.area, index));
+            }
+            x = x.saturating_add(width + self.spacing);
+        }
+        regions
+    }
+
+    /// Render the group and register each enabled button's click region.
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<usize>,
+    ) {
+        for region in self.render_stateful(area, buf) {
+            registry.register(region.area, region.data);
+        }
+    }
+}
+
+impl<T> Widget for ButtonGroup<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_stateful(area, buf);
+    }
+}
+
+/// Handle keyboard input for a button group.
+///
+/// Left/Right move focus between enabled buttons, and Enter/Space press
+/// the focused one, returning [`ButtonGroupAction::Pressed`].
+pub fn handle_button_group_key<T: Clone>(
+    key: &KeyEvent,
+    state: &mut ButtonGroupState<T>,
+) -> Option<ButtonGroupAction<T>> {
+    match key.code {
+        KeyCode::Left => {
+            state.focus_prev();
+            None
+        }
+        KeyCode::Right => {
+            state.focus_next();
+            None
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => state.press(state.focused?),
+        _ => None,
+    }
+}
+
+/// Handle mouse input for a button group.
+///
+/// A left click on a button registered by
+/// [`ButtonGroup::render_with_registry`] focuses and presses it.
+pub fn handle_button_group_mouse<T: Clone>(
+    mouse: &MouseEvent,
+    state: &mut ButtonGroupState<T>,
+    registry: &ClickRegionRegistry<usize>,
+) -> Option<ButtonGroupAction<T>> {
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let &index = registry.handle_click(mouse.column, mouse.row)?;
+            state.press(index)
+        }
+        _ => None,
     }
 }
 
@@ -578,14 +1648,14 @@ mod tests {
     }
 
     #[test]
-    fn test_button_min_width() {
+    fn test_button_width() {
         let state = ButtonState::enabled();
 
         let button = Button::new("OK", &state).variant(ButtonVariant::SingleLine);
-        assert_eq!(button.min_width(), 4); // " OK "
+        assert_eq!(button.width(), 4); // " OK "
 
         let button = Button::new("OK", &state).variant(ButtonVariant::Block);
-        assert_eq!(button.min_width(), 6); // "OK" + 4 for border
+        assert_eq!(button.width(), 6); // "OK" + 4 for border
     }
 
     #[test]
@@ -614,6 +1684,66 @@ mod tests {
         assert_eq!(click_region.data, ButtonAction::Click);
     }
 
+    #[test]
+    fn test_mouse_hover_tooltip() {
+        use crossterm::event::KeyModifiers;
+
+        let mouse = |kind, column, row| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let area = Rect::new(5, 3, 10, 1);
+        let mut state = ButtonState::enabled().with_tooltip("Save");
+
+        let moved = mouse(MouseEventKind::Moved, 6, 3);
+        assert_eq!(
+            handle_button_mouse(&moved, &mut state, area),
+            Some(ButtonAction::Hover)
+        );
+        assert!(state.hovered && state.tooltip_visible);
+
+        // Moving within the button reports nothing new
+        let moved = mouse(MouseEventKind::Moved, 8, 3);
+        assert_eq!(handle_button_mouse(&moved, &mut state, area), None);
+
+        let moved = mouse(MouseEventKind::Moved, 20, 3);
+        assert_eq!(
+            handle_button_mouse(&moved, &mut state, area),
+            Some(ButtonAction::Leave)
+        );
+        assert!(!state.hovered && !state.tooltip_visible);
+
+        // No tooltip text: hovered but nothing to show
+        let mut state = ButtonState::enabled();
+        let moved = mouse(MouseEventKind::Moved, 6, 3);
+        handle_button_mouse(&moved, &mut state, area);
+        assert!(state.hovered && !state.tooltip_visible);
+    }
+
+    #[test]
+    fn test_mouse_click() {
+        use crossterm::event::KeyModifiers;
+
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 6,
+            row: 3,
+            modifiers: KeyModifiers::NONE,
+        };
+        let area = Rect::new(5, 3, 10, 1);
+
+        let mut state = ButtonState::enabled();
+        assert_eq!(
+            handle_button_mouse(&click, &mut state, area),
+            Some(ButtonAction::Click)
+        );
+
+        let mut state = ButtonState::disabled();
+        assert_eq!(handle_button_mouse(&click, &mut state, area), None);
+    }
+
     #[test]
     fn test_render_with_registry() {
         use crate::traits::ClickRegionRegistry;
@@ -717,4 +1847,396 @@ mod tests {
         assert_eq!(style.fg, Some(button.style.toggled_fg));
         assert_eq!(style.bg, Some(button.style.toggled_bg));
     }
+
+    #[test]
+    fn test_button_sizes() {
+        let state = ButtonState::enabled();
+
+        let compact = Button::new("OK", &state).size(ButtonSize::Compact);
+        assert_eq!(compact.build_text(), "OK");
+        assert_eq!(compact.width(), 2);
+        assert_eq!(compact.min_height(), 1);
+
+        let normal = Button::new("OK", &state).size(ButtonSize::Normal);
+        assert_eq!(normal.build_text(), " OK ");
+
+        // Wide adds padding and a border
+        let wide = Button::new("OK", &state).size(ButtonSize::Wide);
+        assert_eq!(wide.build_text(), "   OK   ");
+        assert_eq!(wide.width(), 10);
+        assert_eq!(wide.min_height(), 3);
+
+        // Icon-only compact button
+        let icon = Button::new("", &state).icon("✕").size(ButtonSize::Compact);
+        assert_eq!(icon.build_text(), "✕");
+    }
+
+    #[test]
+    fn test_button_sizes_in_block_and_toggle() {
+        let state = ButtonState::toggled(true);
+        for variant in [ButtonVariant::Block, ButtonVariant::Toggle] {
+            for size in [ButtonSize::Compact, ButtonSize::Normal, ButtonSize::Wide] {
+                let button = Button::new("Go", &state).variant(variant).size(size);
+                let (left, right) = size.padding();
+                let bordered = variant == ButtonVariant::Block || size == ButtonSize::Wide;
+                let expected = 2 + left + right + if bordered { 2 } else { 0 };
+                assert_eq!(button.width(), expected, "{:?} {:?}", variant, size);
+
+                let area = Rect::new(0, 0, expected, 3);
+                let mut buf = Buffer::empty(area);
+                let region = button.render_stateful(area, &mut buf);
+                assert_eq!(region.area.width, expected);
+                let text: String = (0..expected)
+                    .map(|x| buf[(x, if bordered { 1 } else { 0 })].symbol().to_string())
+                    .collect();
+                assert!(text.contains("Go"), "{:?} {:?}: {:?}", variant, size, text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_button_min_width_fills_padding() {
+        let state = ButtonState::enabled();
+        let button = Button::new("OK", &state).min_width(9);
+        assert_eq!(button.width(), 9);
+        // 5 extra columns split across both sides
+        assert_eq!(button.build_text(), "   OK    ");
+
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        let region = Button::new("OK", &state)
+            .min_width(9)
+            .render_stateful(area, &mut buf);
+        assert_eq!(region.area.width, 9);
+
+        // Already wider than the minimum: unchanged
+        let button = Button::new("Submit", &state).min_width(4);
+        assert_eq!(button.build_text(), " Submit ");
+    }
+
+    #[test]
+    fn test_style_padding() {
+        let style = ButtonStyle::default().size(ButtonSize::Wide).padding(2, 0);
+        assert_eq!(style.size, ButtonSize::Wide);
+        assert_eq!((style.padding_left, style.padding_right), (2, 0));
+
+        let state = ButtonState::enabled();
+        let button = Button::new("OK", &state).style(style);
+        assert_eq!(button.build_text(), "  OK");
+    }
+
+    #[test]
+    fn test_render_focus_ring() {
+        let style = ButtonStyle::default().focus_ring(FocusRing::default());
+        let area = Rect::new(0, 0, 8, 3);
+        let render = |focused: bool, style: ButtonStyle| {
+            let mut state = ButtonState::enabled();
+            state.focused = focused;
+            let mut buf = Buffer::empty(area);
+            Button::new("OK", &state)
+                .style(style)
+                .render(Rect::new(1, 1, 7, 1), &mut buf);
+            buf
+        };
+        let lines = |buf: &Buffer| -> Vec<String> {
+            (0..3)
+                .map(|y| (0..8).map(|x| buf[(x, y)].symbol()).collect())
+                .collect()
+        };
+
+        let buf = render(true, style.clone());
+        assert_eq!(lines(&buf), [" ┌────┐ ", " │ OK │ ", " └────┘ "]);
+        assert_eq!(buf[(1, 0)].fg, Color::Yellow);
+
+        let buf = render(false, style);
+        assert_eq!(lines(&buf), ["        ", "   OK   ", "        "]);
+
+        // Bordered buttons recolor their own border instead
+        let wide = ButtonStyle::default()
+            .size(ButtonSize::Wide)
+            .focus_ring(FocusRing::new(Color::Magenta, Color::Gray));
+        let mut state = ButtonState::enabled();
+        state.focused = true;
+        let mut buf = Buffer::empty(area);
+        Button::new("OK", &state).style(wide).render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "┌");
+        assert_eq!(buf[(0, 0)].fg, Color::Magenta);
+    }
+
+    #[test]
+    fn test_icon_position() {
+        let state = ButtonState::enabled();
+        let next = Button::new("Next", &state)
+            .icon("▶")
+            .icon_position(IconPosition::Trailing);
+        assert_eq!(next.build_text(), " Next ▶ ");
+
+        let gear = Button::new("Settings", &state)
+            .icon("⚙️")
+            .icon_position(IconPosition::Only);
+        assert_eq!(gear.build_text(), " ⚙️ ");
+        assert_eq!(gear.accessible_name(), "Settings");
+        assert_eq!(gear.width(), 4);
+
+        // Without an icon the label is still shown
+        let plain = Button::new("Settings", &state).icon_position(IconPosition::Only);
+        assert_eq!(plain.build_text(), " Settings ");
+    }
+
+    #[test]
+    fn test_double_width_icon_render() {
+        let state = ButtonState::enabled();
+        let button = Button::new("Save", &state)
+            .icon("💾")
+            .icon_position(IconPosition::Trailing)
+            .variant(ButtonVariant::Block);
+        assert_eq!(button.width(), 11);
+
+        let area = Rect::new(0, 0, 11, 3);
+        let mut buf = Buffer::empty(area);
+        button.render(area, &mut buf);
+        assert_eq!(buf[(10, 1)].symbol(), "│");
+        assert_eq!(buf[(7, 1)].symbol(), "💾");
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum View {
+        List,
+        Grid,
+        Table,
+    }
+
+    fn views() -> ToggleGroupState<View> {
+        ToggleGroupState::new([View::List, View::Grid, View::Table])
+    }
+
+    #[test]
+    fn test_toggle_group_state() {
+        let mut state = views().with_active(&View::Grid);
+        assert_eq!(state.active(), Some(&View::Grid));
+        assert_eq!(state.toggled_flags(), [false, true, false]);
+
+        assert_eq!(
+            state.select_next(),
+            Some(ToggleGroupAction::Changed(View::Table))
+        );
+        assert_eq!(state.select_next(), None);
+        assert_eq!(state.select_index(2), None);
+        assert_eq!(state.select_index(7), None);
+
+        state.focused = true;
+        let buttons = state.button_states();
+        assert!(buttons[2].toggled && buttons[2].focused);
+        assert!(!buttons[0].toggled && !buttons[0].focused);
+    }
+
+    #[test]
+    fn test_toggle_group_key() {
+        let mut state = views();
+        let key = |code| KeyEvent::from(code);
+        assert_eq!(
+            handle_toggle_group_key(&key(KeyCode::Left), &mut state),
+            None
+        );
+        assert_eq!(
+            handle_toggle_group_key(&key(KeyCode::Right), &mut state),
+            Some(ToggleGroupAction::Changed(View::Grid))
+        );
+        assert_eq!(
+            handle_toggle_group_key(&key(KeyCode::End), &mut state),
+            Some(ToggleGroupAction::Changed(View::Table))
+        );
+
+        state.enabled = false;
+        assert_eq!(
+            handle_toggle_group_key(&key(KeyCode::Home), &mut state),
+            None
+        );
+        assert_eq!(state.active(), Some(&View::Table));
+    }
+
+    #[test]
+    fn test_toggle_group_render_merges_borders() {
+        let state = views().with_active(&View::Grid);
+        let area = Rect::new(0, 0, 24, 3);
+        let mut buf = Buffer::empty(area);
+        let mut registry = ClickRegionRegistry::new();
+        let group =
+            ToggleGroup::new(&["List", "Grid", "Table"], &state).variant(ButtonVariant::Block);
+        assert_eq!(group.width(), 23);
+        group.render_with_registry(area, &mut buf, &mut registry);
+
+        let row = |y: u16| (0..23).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(0), "┌──────┬──────┬───────┐");
+        assert_eq!(row(1), "│ List │ Grid │ Table │");
+        assert_eq!(row(2), "└──────┴──────┴───────┘");
+        assert_eq!(buf[(9, 1)].bg, Color::Green);
+
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 1,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        let mut state = state;
+        assert_eq!(
+            handle_toggle_group_mouse(&click(2), &mut state, &registry),
+            Some(ToggleGroupAction::Changed(View::List))
+        );
+        assert_eq!(
+            handle_toggle_group_mouse(&click(2), &mut state, &registry),
+            None
+        );
+    }
+
+    #[test]
+    fn test_toggle_group_icon_only_tooltips() {
+        let state = views();
+        let group = ToggleGroup::new(&["List", "Grid", "Table"], &state)
+            .icons(&["≡", "▦", "▤"])
+            .icon_position(IconPosition::Only);
+        assert_eq!(group.width(), 9);
+        let tooltips: Vec<_> = group
+            .segment_states()
+            .into_iter()
+            .map(|s| s.tooltip.unwrap())
+            .collect();
+        assert_eq!(tooltips, ["List", "Grid", "Table"]);
+
+        let area = Rect::new(0, 0, 9, 1);
+        let mut buf = Buffer::empty(area);
+        group.render(area, &mut buf);
+        let row: String = (0..9).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, " ≡  ▦  ▤ ");
+    }
+
+    #[test]
+    fn test_disabled_reason_tooltip_and_keys() {
+        use crossterm::event::KeyModifiers;
+
+        let mut state = ButtonState::disabled()
+            .with_tooltip("Save the file")
+            .with_disabled_reason("Nothing to save");
+        assert_eq!(state.tooltip_text(), Some("Nothing to save"));
+        assert_eq!(state.status_hint(), None);
+
+        let area = Rect::new(5, 3, 10, 1);
+        let moved = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 6,
+            row: 3,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(
+            handle_button_mouse(&moved, &mut state, area),
+            Some(ButtonAction::Hover)
+        );
+        assert!(state.hovered && state.tooltip_visible);
+        assert_eq!(state.status_hint(), Some("Nothing to save"));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+        let style = ButtonStyle::default();
+        render_button_tooltip(&state, &style, area, buf.area, &mut buf);
+        let row: String = (5..22).map(|x| buf[(x, 4)].symbol()).collect();
+        assert_eq!(row, " Nothing to save ");
+        assert_eq!(buf[(5, 4)].bg, Color::DarkGray);
+
+        // No room below: drawn above, shifted left to fit
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
+        render_button_tooltip(&state, &style, area, buf.area, &mut buf);
+        let row: String = (3..20).map(|x| buf[(x, 2)].symbol()).collect();
+        assert_eq!(row, " Nothing to save ");
+
+        // Keyboard
+        let enter = KeyEvent::from(KeyCode::Enter);
+        let space = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(handle_button_key(&enter, &state), None);
+        state.set_focused(true);
+        assert_eq!(
+            handle_button_key(&enter, &state),
+            Some(ButtonAction::DisabledPressed)
+        );
+
+        // Enabling restores the normal tooltip and click
+        state.set_enabled(true);
+        assert_eq!(state.tooltip_text(), Some("Save the file"));
+        assert_eq!(state.status_hint(), None);
+        assert_eq!(handle_button_key(&space, &state), Some(ButtonAction::Click));
+    }
+
+    #[test]
+    fn test_button_group_focus_skips_disabled() {
+        let mut state = ButtonGroupState::new(['a', 'b', 'c']).with_enabled(1, false);
+        assert_eq!(state.focused_value(), None);
+
+        state.focus_next();
+        assert_eq!(state.focused_value(), Some(&'a'));
+        state.focus_next();
+        assert_eq!(state.focused_value(), Some(&'c'));
+        // Stops at the last enabled button
+        state.focus_next();
+        assert_eq!(state.focused_value(), Some(&'c'));
+        state.focus_prev();
+        assert_eq!(state.focused_value(), Some(&'a'));
+
+        assert!(!state.focus_value(&'b'));
+        assert!(state.focus_value(&'c'));
+        state.set_enabled(2, false);
+        assert_eq!(state.focused, None);
+    }
+
+    #[test]
+    fn test_button_group_key_presses_focused() {
+        let mut state = ButtonGroupState::new(["back", "next"]);
+        let key = |code| KeyEvent::new(code, crossterm::event::KeyModifiers::NONE);
+
+        assert_eq!(
+            handle_button_group_key(&key(KeyCode::Enter), &mut state),
+            None
+        );
+        handle_button_group_key(&key(KeyCode::Left), &mut state);
+        assert_eq!(state.focused, Some(1));
+        assert_eq!(
+            handle_button_group_key(&key(KeyCode::Char(' ')), &mut state),
+            Some(ButtonGroupAction::Pressed("next"))
+        );
+
+        state.set_enabled(0, false);
+        assert_eq!(state.press(0), None);
+    }
+
+    #[test]
+    fn test_button_group_render_aligned() {
+        let state = ButtonGroupState::new([0, 1]).with_enabled(0, false);
+        let group = ButtonGroup::new(&["Back", "Next"], &state);
+        // Two 6-cell buttons and a 1-cell gap
+        assert_eq!(group.width(), 13);
+
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        let mut registry = ClickRegionRegistry::new();
+        group
+            .alignment(Alignment::Right)
+            .render_with_registry(area, &mut buf, &mut registry);
+
+        let row: String = (0..20).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "        Back   Next ");
+        assert_eq!(buf[(9, 0)].fg, ButtonStyle::default().disabled_fg);
+
+        // Only the enabled button is clickable
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.handle_click(8, 0), None);
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 15,
+            row: 0,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        let mut state = state;
+        assert_eq!(
+            handle_button_group_mouse(&click, &mut state, &registry),
+            Some(ButtonGroupAction::Pressed(1))
+        );
+        assert_eq!(state.focused, Some(1));
+    }
 }
@@ -16,7 +16,23 @@
 //! state.toggle();
 //! assert!(state.checked);
 //! ```
+//!
+//! # Keyboard shortcuts
+//!
+//! A checkbox can have an Alt+key shortcut; the first matching letter of
+//! its label is underlined.
+//!
+//! ```rust
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use ratatui_interact::components::{CheckBoxState, handle_checkbox_key};
+//!
+//! let mut state = CheckBoxState::new(false).with_shortcut('n');
+//! let key = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::ALT);
+//! assert!(handle_checkbox_key(&key, &mut state).is_some());
+//! assert!(state.checked);
+//! ```
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -25,7 +41,9 @@ use ratatui::{
     widgets::{Paragraph, Widget},
 };
 
-use crate::traits::{ClickRegion, FocusId};
+use super::focus_ring::FocusRing;
+use crate::capabilities::Capabilities;
+use crate::traits::{ClickRegion, FocusId, FocusableWidget};
 
 /// Actions a checkbox can emit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,8 +59,18 @@ pub struct CheckBoxState {
     pub checked: bool,
     /// Whether the checkbox has focus.
     pub focused: bool,
+    /// Focus ID matched by [`FocusManager::sync_from_vec`](crate::state::FocusManager::sync_from_vec).
+    pub focus_id: FocusId,
     /// Whether the checkbox is enabled (can be toggled).
     pub enabled: bool,
+    /// Whether a toggle animation is in progress.
+    pub animating: bool,
+    /// Current frame of the toggle animation.
+    pub animation_frame: u8,
+    /// Frames in the toggle animation (0 = toggle instantly).
+    pub animation_frames: u8,
+    /// Alt+key shortcut that toggles the checkbox, matched case-insensitively.
+    pub shortcut: Option<char>,
 }
 
 impl Default for CheckBoxState {
@@ -50,11 +78,30 @@ impl Default for CheckBoxState {
         Self {
             checked: false,
             focused: false,
+            focus_id: FocusId::default(),
             enabled: true,
+            animating: false,
+            animation_frame: 0,
+            animation_frames: 0,
+            shortcut: None,
         }
     }
 }
 
+impl FocusableWidget for CheckBoxState {
+    fn focus_id(&self) -> FocusId {
+        self.focus_id
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
 impl CheckBoxState {
     /// Create a new checkbox state.
     ///
@@ -68,22 +115,89 @@ impl CheckBoxState {
         }
     }
 
+    /// Set the focus ID used by [`FocusableWidget`].
+    pub fn with_focus_id(mut self, id: FocusId) -> Self {
+        self.focus_id = id;
+        self
+    }
+
+    /// Toggle on Alt+`shortcut`; see [`handle_checkbox_key`].
+    pub fn with_shortcut(mut self, shortcut: char) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Check if `key` is Alt plus this checkbox's shortcut.
+    pub fn matches_shortcut(&self, key: &KeyEvent) -> bool {
+        let Some(shortcut) = self.shortcut else {
+            return false;
+        };
+        let modifiers = key.modifiers - KeyModifiers::SHIFT;
+        match key.code {
+            KeyCode::Char(c) if modifiers == KeyModifiers::ALT => chars_match(c, shortcut),
+            _ => false,
+        }
+    }
+
+    /// Animate toggles over `frames` calls to [`tick_animation`](Self::tick_animation).
+    pub fn with_animation_frames(mut self, frames: u8) -> Self {
+        self.animation_frames = frames;
+        self
+    }
+
     /// Toggle the checkbox state.
     ///
-    /// Does nothing if the checkbox is disabled.
+    /// With `animation_frames` set, this starts the toggle animation and
+    /// `checked` changes once it finishes; toggling again mid-animation
+    /// cancels it. Does nothing if the checkbox is disabled.
     pub fn toggle(&mut self) {
-        if self.enabled {
+        if !self.enabled {
+            return;
+        }
+        if self.animating {
+            self.stop_animation();
+        } else if self.animation_frames == 0 {
             self.checked = !self.checked;
+        } else {
+            self.animating = true;
+            self.animation_frame = 0;
         }
     }
 
-    /// Set the checked state.
+    /// Set the checked state, cancelling any toggle animation.
     pub fn set_checked(&mut self, checked: bool) {
         if self.enabled {
+            self.stop_animation();
             self.checked = checked;
         }
     }
 
+    /// The checked state once any running animation finishes.
+    pub fn is_checked_final(&self) -> bool {
+        self.checked != self.animating
+    }
+
+    /// Advance the toggle animation by one frame.
+    ///
+    /// Call this from the event loop; `checked` flips after the last frame.
+    /// Returns `true` if an animation was running (the checkbox needs a redraw).
+    pub fn tick_animation(&mut self) -> bool {
+        if !self.animating {
+            return false;
+        }
+        self.animation_frame = self.animation_frame.saturating_add(1);
+        if self.animation_frame >= self.animation_frames {
+            self.checked = !self.checked;
+            self.stop_animation();
+        }
+        true
+    }
+
+    fn stop_animation(&mut self) {
+        self.animating = false;
+        self.animation_frame = 0;
+    }
+
     /// Set the focus state.
     pub fn set_focused(&mut self, focused: bool) {
         self.focused = focused;
@@ -110,6 +224,13 @@ pub struct CheckBoxStyle {
     pub disabled_fg: Color,
     /// Foreground color when checked (unfocused).
     pub checked_fg: Color,
+    /// Intermediate symbols shown while animating from unchecked to checked
+    /// (played in reverse when unchecking).
+    pub animation_symbols: Vec<&'static str>,
+    /// Outline drawn around the checkbox when focused (none by default).
+    pub focus_ring: Option<FocusRing>,
+    /// Style patched onto the shortcut character in the label.
+    pub shortcut_style: Style,
 }
 
 impl Default for CheckBoxStyle {
@@ -121,6 +242,9 @@ impl Default for CheckBoxStyle {
             unfocused_fg: Color::White,
             disabled_fg: Color::DarkGray,
             checked_fg: Color::Green,
+            animation_symbols: Vec::new(),
+            focus_ring: None,
+            shortcut_style: Style::default().add_modifier(Modifier::UNDERLINED),
         }
     }
 }
@@ -135,6 +259,9 @@ impl From<&crate::theme::Theme> for CheckBoxStyle {
             unfocused_fg: p.text,
             disabled_fg: p.text_disabled,
             checked_fg: p.success,
+            animation_symbols: Vec::new(),
+            focus_ring: None,
+            shortcut_style: Style::default().add_modifier(Modifier::UNDERLINED),
         }
     }
 }
@@ -145,6 +272,18 @@ impl CheckBoxStyle {
         Self::default()
     }
 
+    /// The default style, or [`ascii`](Self::ascii) without Unicode support.
+    ///
+    /// The default symbols are already ASCII, so this only matters if the
+    /// default changes; it keeps call sites uniform with other styles.
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        if caps.unicode {
+            Self::default()
+        } else {
+            Self::ascii()
+        }
+    }
+
     /// Unicode box style: `☑` and `☐`
     pub fn unicode() -> Self {
         Self {
@@ -195,6 +334,24 @@ impl CheckBoxStyle {
         self.checked_fg = color;
         self
     }
+
+    /// Set the symbols shown while animating, from unchecked to checked.
+    pub fn animation_symbols(mut self, symbols: Vec<&'static str>) -> Self {
+        self.animation_symbols = symbols;
+        self
+    }
+
+    /// Draw a focus ring outline around the checkbox when focused.
+    pub fn focus_ring(mut self, ring: FocusRing) -> Self {
+        self.focus_ring = Some(ring);
+        self
+    }
+
+    /// Set the style patched onto the shortcut character.
+    pub fn shortcut_style(mut self, style: Style) -> Self {
+        self.shortcut_style = style;
+        self
+    }
 }
 
 /// CheckBox widget.
@@ -241,13 +398,30 @@ impl<'a> CheckBox<'a> {
         self
     }
 
-    /// Build the display line for this checkbox.
-    fn build_line(&self) -> Line<'a> {
-        let symbol = if self.state.checked {
+    /// The symbol for the current state or animation frame.
+    fn symbol(&self) -> &'static str {
+        let symbols = &self.style.animation_symbols;
+        if self.state.animating && !symbols.is_empty() {
+            let frame = (self.state.animation_frame as usize).min(symbols.len() - 1);
+            // Unchecking plays the symbols in reverse
+            let idx = if self.state.checked {
+                symbols.len() - 1 - frame
+            } else {
+                frame
+            };
+            return symbols[idx];
+        }
+
+        if self.state.checked {
             self.style.checked_symbol
         } else {
             self.style.unchecked_symbol
-        };
+        }
+    }
+
+    /// Build the display line for this checkbox.
+    fn build_line(&self) -> Line<'a> {
+        let symbol = self.symbol();
 
         let fg_color = if !self.state.enabled {
             self.style.disabled_fg
@@ -264,20 +438,43 @@ impl<'a> CheckBox<'a> {
             style = style.add_modifier(Modifier::BOLD);
         }
 
-        Line::from(vec![
-            Span::styled(symbol, style),
-            Span::styled(" ", style),
-            Span::styled(self.label, style),
-        ])
+        let mut spans = vec![Span::styled(symbol, style), Span::styled(" ", style)];
+        let shortcut_at = self.state.shortcut.and_then(|shortcut| {
+            self.label
+                .char_indices()
+                .find(|&(_, c)| chars_match(c, shortcut))
+        });
+        match shortcut_at {
+            Some((i, c)) => {
+                let end = i + c.len_utf8();
+                spans.push(Span::styled(&self.label[..i], style));
+                spans.push(Span::styled(
+                    &self.label[i..end],
+                    style.patch(self.style.shortcut_style),
+                ));
+                spans.push(Span::styled(&self.label[end..], style));
+            }
+            None => spans.push(Span::styled(self.label, style)),
+        }
+        Line::from(spans)
+    }
+
+    /// Route `key` from a top-level handler to the first checkbox in
+    /// `states` whose shortcut it matches; see [`handle_checkbox_key`].
+    ///
+    /// Returns `true` if a checkbox handled the key.
+    pub fn handle_global_shortcut<'s>(
+        key: &KeyEvent,
+        states: impl IntoIterator<Item = &'s mut CheckBoxState>,
+    ) -> bool {
+        states
+            .into_iter()
+            .any(|state| handle_checkbox_key(key, state).is_some())
     }
 
     /// Calculate width needed for this checkbox.
     pub fn width(&self) -> u16 {
-        let symbol_len = if self.state.checked {
-            self.style.checked_symbol.chars().count()
-        } else {
-            self.style.unchecked_symbol.chars().count()
-        };
+        let symbol_len = self.symbol().chars().count();
         (symbol_len + 1 + self.label.chars().count()) as u16
     }
 
@@ -288,9 +485,7 @@ impl<'a> CheckBox<'a> {
         let width = self.width().min(area.width);
         let click_area = Rect::new(area.x, area.y, width, 1);
 
-        let line = self.build_line();
-        let paragraph = Paragraph::new(line);
-        paragraph.render(area, buf);
+        self.render(area, buf);
 
         ClickRegion::new(click_area, CheckBoxAction::Toggle)
     }
@@ -298,12 +493,37 @@ impl<'a> CheckBox<'a> {
 
 impl Widget for CheckBox<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let outline = Rect::new(area.x, area.y, self.width().min(area.width), 1);
+        let focused = self.state.focused && self.state.enabled;
+        let ring = self.style.focus_ring;
+
         let line = self.build_line();
         let paragraph = Paragraph::new(line);
         paragraph.render(area, buf);
+
+        if let Some(ring) = ring {
+            ring.render_outline(outline, buf, focused);
+        }
     }
 }
 
+/// Toggle the checkbox when `key` is Alt plus its shortcut.
+///
+/// Works regardless of focus, so forms can be driven from the keyboard.
+/// Disabled checkboxes ignore the key.
+pub fn handle_checkbox_key(key: &KeyEvent, state: &mut CheckBoxState) -> Option<CheckBoxAction> {
+    if !state.enabled || !state.matches_shortcut(key) {
+        return None;
+    }
+    state.toggle();
+    Some(CheckBoxAction::Toggle)
+}
+
+/// Case-insensitive character comparison for shortcuts.
+fn chars_match(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -463,4 +683,150 @@ mod tests {
         assert!(!click_region.contains(10, 4));
         assert!(!click_region.contains(10, 6));
     }
+
+    #[test]
+    fn test_animated_toggle() {
+        let mut state = CheckBoxState::new(false).with_animation_frames(3);
+
+        state.toggle();
+        assert!(state.animating);
+        assert!(!state.checked);
+        assert!(state.is_checked_final());
+
+        assert!(state.tick_animation());
+        assert!(state.tick_animation());
+        assert!(!state.checked);
+        assert!(state.tick_animation());
+        assert!(state.checked);
+        assert!(!state.animating);
+        assert!(!state.tick_animation());
+
+        // Toggling mid-animation cancels it
+        state.toggle();
+        state.tick_animation();
+        state.toggle();
+        assert!(!state.animating);
+        assert!(state.checked);
+        assert!(state.is_checked_final());
+    }
+
+    #[test]
+    fn test_animation_symbols() {
+        let style =
+            CheckBoxStyle::default().animation_symbols(vec!["[ ]", "[·]", "[○]", "[●]", "[☑]"]);
+        let mut state = CheckBoxState::new(false).with_animation_frames(4);
+        state.toggle();
+        state.tick_animation();
+
+        let symbol =
+            |state: &CheckBoxState| CheckBox::new("x", state).style(style.clone()).symbol();
+        assert_eq!(symbol(&state), "[·]");
+
+        // Unchecking plays in reverse
+        let mut state = CheckBoxState::new(true).with_animation_frames(4);
+        state.toggle();
+        assert_eq!(symbol(&state), "[☑]");
+        state.tick_animation();
+        assert_eq!(symbol(&state), "[●]");
+
+        // Without symbols the current state's symbol is shown
+        let checkbox = CheckBox::new("x", &state);
+        assert_eq!(checkbox.symbol(), "[x]");
+    }
+
+    #[test]
+    fn test_render_focus_ring() {
+        let style = CheckBoxStyle::default().focus_ring(FocusRing::default());
+        let area = Rect::new(0, 0, 9, 3);
+
+        let mut state = CheckBoxState::new(false);
+        state.focused = true;
+        let mut buf = Buffer::empty(area);
+        CheckBox::new("On", &state)
+            .style(style.clone())
+            .render(Rect::new(1, 1, 8, 1), &mut buf);
+        let lines: Vec<String> = (0..3)
+            .map(|y| (0..9).map(|x| buf[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(lines, ["┌──────┐ ", "│[ ] On│ ", "└──────┘ "]);
+        assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+
+        state.focused = false;
+        let mut buf = Buffer::empty(area);
+        CheckBox::new("On", &state)
+            .style(style)
+            .render(Rect::new(1, 1, 8, 1), &mut buf);
+        let lines: Vec<String> = (0..3)
+            .map(|y| (0..9).map(|x| buf[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(lines, ["         ", " [ ] On  ", "         "]);
+    }
+
+    #[test]
+    fn test_from_capabilities_is_ascii() {
+        let style = CheckBoxStyle::from_capabilities(&Capabilities::basic());
+        assert!(style.checked_symbol.is_ascii());
+        assert!(style.unchecked_symbol.is_ascii());
+    }
+
+    #[test]
+    fn test_shortcut_key() {
+        let mut state = CheckBoxState::new(false).with_shortcut('n');
+        let alt = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT);
+
+        assert_eq!(
+            handle_checkbox_key(&alt('N'), &mut state),
+            Some(CheckBoxAction::Toggle)
+        );
+        assert!(state.checked);
+
+        // Plain and Ctrl keys are left for other handlers
+        let plain = KeyEvent::from(KeyCode::Char('n'));
+        let ctrl = KeyEvent::new(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        );
+        assert_eq!(handle_checkbox_key(&plain, &mut state), None);
+        assert_eq!(handle_checkbox_key(&ctrl, &mut state), None);
+        assert_eq!(handle_checkbox_key(&alt('x'), &mut state), None);
+
+        state.enabled = false;
+        assert_eq!(handle_checkbox_key(&alt('n'), &mut state), None);
+        assert!(state.checked);
+    }
+
+    #[test]
+    fn test_global_shortcut_routing() {
+        let mut wifi = CheckBoxState::new(false).with_shortcut('w');
+        let mut sound = CheckBoxState::new(false).with_shortcut('s');
+        let key = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT);
+
+        assert!(CheckBox::handle_global_shortcut(
+            &key,
+            [&mut wifi, &mut sound]
+        ));
+        assert!(!wifi.checked);
+        assert!(sound.checked);
+
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::ALT);
+        assert!(!CheckBox::handle_global_shortcut(
+            &key,
+            [&mut wifi, &mut sound]
+        ));
+    }
+
+    #[test]
+    fn test_render_underlines_shortcut() {
+        let state = CheckBoxState::new(false).with_shortcut('n');
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        CheckBox::new("Enable notices", &state).render(area, &mut buf);
+
+        // First case-insensitive match: the "n" of "Enable"
+        let row: String = (0..18).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "[ ] Enable notices");
+        assert!(buf[(5, 0)].modifier.contains(Modifier::UNDERLINED));
+        assert!(!buf[(4, 0)].modifier.contains(Modifier::UNDERLINED));
+        assert!(!buf[(11, 0)].modifier.contains(Modifier::UNDERLINED));
+    }
 }
@@ -29,6 +29,8 @@
 //! dialog.render(frame);
 //! ```
 
+use std::sync::Arc;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
@@ -39,7 +41,8 @@ use ratatui::{
 };
 
 use crate::{
-    state::FocusManager,
+    events::CloseRequest,
+    state::{FocusManager, OverlayLayer, OverlayManager},
     traits::{ClickRegionRegistry, ContainerAction, EventResult},
 };
 
@@ -54,8 +57,58 @@ pub enum DialogFocusTarget {
     Close,
 }
 
+/// Action emitted by a dialog whose content reports its own actions.
+///
+/// Returned by [`PopupDialog::handle_key_with`], which lets the dialog consume
+/// the keys it owns (Tab, button activation, and Esc once the content has
+/// passed on it) and bubble everything else to the content's key handler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogAction<A> {
+    /// An action produced by the dialog itself (buttons, Esc).
+    Container(ContainerAction),
+    /// An action produced by the dialog content.
+    Content(A),
+}
+
+/// The dialog's own Close counts as a close; content results as consumed.
+impl<A> From<EventResult<DialogAction<A>>> for CloseRequest {
+    fn from(result: EventResult<DialogAction<A>>) -> Self {
+        match result {
+            EventResult::NotHandled => CloseRequest::Propagate,
+            EventResult::Action(DialogAction::Container(ContainerAction::Close)) => {
+                CloseRequest::Close
+            }
+            _ => CloseRequest::Consumed,
+        }
+    }
+}
+
+impl<A> DialogAction<A> {
+    /// Get the container action, if this is one.
+    pub fn container(&self) -> Option<&ContainerAction> {
+        match self {
+            DialogAction::Container(action) => Some(action),
+            DialogAction::Content(_) => None,
+        }
+    }
+
+    /// Get the content action, if this is one.
+    pub fn content(&self) -> Option<&A> {
+        match self {
+            DialogAction::Content(action) => Some(action),
+            DialogAction::Container(_) => None,
+        }
+    }
+}
+
+/// Reports whether the dialog content has unsaved changes.
+pub type DialogDirtyFn<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// Closes a popup in the dialog content, returning whether one was open.
+pub type DialogPopupCloserFn<T> = Arc<dyn Fn(&mut T) -> bool + Send + Sync>;
+
 /// State for a dialog.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct DialogState<T> {
     /// Child component state.
     pub children: T,
@@ -65,6 +118,31 @@ pub struct DialogState<T> {
     pub click_regions: ClickRegionRegistry<DialogFocusTarget>,
     /// Whether the dialog is visible.
     pub visible: bool,
+    /// Unsaved-changes check used by [`EscapeAction::Cancel`] and
+    /// [`DialogConfig::confirm_discard`].
+    pub is_dirty: Option<DialogDirtyFn<T>>,
+    /// Whether the discard-changes prompt is showing.
+    pub confirming_discard: bool,
+    /// Area the dialog was last rendered in, used for mouse hit testing.
+    pub area: Option<Rect>,
+    /// Content popups closed when their focus target loses focus, see
+    /// [`close_popup_on_blur`](Self::close_popup_on_blur).
+    pub popup_closers: Vec<(DialogFocusTarget, DialogPopupCloserFn<T>)>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for DialogState<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DialogState")
+            .field("children", &self.children)
+            .field("focus", &self.focus)
+            .field("click_regions", &self.click_regions)
+            .field("visible", &self.visible)
+            .field("is_dirty", &self.is_dirty.as_ref().map(|_| "<fn>"))
+            .field("confirming_discard", &self.confirming_discard)
+            .field("area", &self.area)
+            .field("popup_closers", &self.popup_closers.len())
+            .finish()
+    }
 }
 
 impl<T: Default> Default for DialogState<T> {
@@ -81,7 +159,60 @@ impl<T> DialogState<T> {
             focus: FocusManager::new(),
             click_regions: ClickRegionRegistry::new(),
             visible: false,
+            is_dirty: None,
+            confirming_discard: false,
+            area: None,
+            popup_closers: Vec::new(),
+        }
+    }
+
+    /// Close a popup in the content when `target` loses focus.
+    ///
+    /// `closer` closes the popup and returns whether it was open, e.g.
+    /// `|form: &mut Form| form.fruit.on_focus_lost()` with
+    /// [`PopupOwner`](crate::traits::PopupOwner). Tab, Shift+Tab, and clicks
+    /// on another child run it before moving focus, so an open dropdown
+    /// closes instead of staying over the next field.
+    pub fn close_popup_on_blur(
+        mut self,
+        target: DialogFocusTarget,
+        closer: impl Fn(&mut T) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.popup_closers.push((target, Arc::new(closer)));
+        self
+    }
+
+    /// Run the popup closers of the focused target, returning whether any
+    /// popup was open.
+    fn close_focused_popups(&mut self) -> bool {
+        let Some(current) = self.focus.current() else {
+            return false;
+        };
+        let mut closed = false;
+        for (target, closer) in &self.popup_closers {
+            if target == current {
+                closed |= closer(&mut self.children);
+            }
         }
+        closed
+    }
+
+    /// Set the unsaved-changes check used by [`EscapeAction::Cancel`] and
+    /// [`DialogConfig::confirm_discard`].
+    ///
+    /// Content such as [`TextAreaState`](crate::components::TextAreaState)
+    /// and [`InputState`](crate::components::InputState) tracks its own
+    /// `is_dirty()`; call their `mark_clean()` once a submit succeeds.
+    pub fn dirty_when(mut self, is_dirty: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.is_dirty = Some(Arc::new(is_dirty));
+        self
+    }
+
+    /// Check if the content has unsaved changes; `false` without a check.
+    pub fn is_dirty(&self) -> bool {
+        self.is_dirty
+            .as_ref()
+            .is_some_and(|is_dirty| is_dirty(&self.children))
     }
 
     /// Show the dialog.
@@ -89,9 +220,10 @@ impl<T> DialogState<T> {
         self.visible = true;
     }
 
-    /// Hide the dialog.
+    /// Hide the dialog, dismissing any discard-changes prompt.
     pub fn hide(&mut self) {
         self.visible = false;
+        self.confirming_discard = false;
     }
 
     /// Toggle dialog visibility.
@@ -130,8 +262,34 @@ impl<T> DialogState<T> {
     }
 }
 
+/// What Escape does in a dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum EscapeAction {
+    /// Hide the dialog and emit [`ContainerAction::Close`].
+    #[default]
+    Close,
+    /// Close like [`Close`](Self::Close) unless [`DialogState::is_dirty`];
+    /// with unsaved changes, keep the dialog open and emit
+    /// `ContainerAction::Custom(EscapeAction::CONFIRM_CANCEL)` so the app
+    /// can ask for confirmation.
+    Cancel,
+    /// Keep the dialog open and emit [`ContainerAction::Custom`] with this ID.
+    Custom(String),
+    /// Consume Escape without doing anything.
+    Disabled,
+}
+
+impl EscapeAction {
+    /// Custom action ID emitted by [`EscapeAction::Cancel`] when the
+    /// content has unsaved changes.
+    pub const CONFIRM_CANCEL: &'static str = "confirm_cancel";
+}
+
+/// Measures the content height (in rows) needed at a given content width.
+pub type DialogMeasureFn = Arc<dyn Fn(u16) -> u16 + Send + Sync>;
+
 /// Configuration for a popup dialog.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct DialogConfig {
     /// Dialog title.
     pub title: String,
@@ -139,6 +297,12 @@ pub struct DialogConfig {
     pub width_percent: u16,
     /// Height as percentage of screen (0-100).
     pub height_percent: u16,
+    /// Fixed width in columns, overriding `width_percent`.
+    pub width: Option<u16>,
+    /// Fixed height in rows, overriding `height_percent`.
+    pub height: Option<u16>,
+    /// Content measure for size-to-content mode, overriding the height settings.
+    pub measure: Option<DialogMeasureFn>,
     /// Minimum width in columns.
     pub min_width: u16,
     /// Minimum height in rows.
@@ -151,20 +315,43 @@ pub struct DialogConfig {
     pub border_color: Color,
     /// Border color when focused.
     pub focused_border_color: Color,
-    /// Close dialog on Escape.
+    /// Handle Escape at all; when `false` it is left unhandled.
     pub close_on_escape: bool,
+    /// What Escape does when `close_on_escape` is set.
+    pub escape_action: EscapeAction,
+    /// Close the dialog when the backdrop (anything outside it) is clicked.
+    ///
+    /// Backdrop clicks are consumed either way, so they never reach the
+    /// widgets underneath a visible dialog.
+    pub dismiss_on_backdrop_click: bool,
     /// Close dialog when clicking outside.
+    ///
+    /// Kept for compatibility; setting either this or
+    /// [`dismiss_on_backdrop_click`](Self::dismiss_on_backdrop_click) enables
+    /// dismissal.
+    #[deprecated(note = "use `dismiss_on_backdrop_click`")]
     pub close_on_outside_click: bool,
+    /// Prompt shown before closing a dirty dialog.
+    ///
+    /// When set, any close (Escape, a Close button, a backdrop click) while
+    /// [`DialogState::is_dirty`] shows this prompt instead; y or Enter
+    /// discards the changes and emits [`ContainerAction::Close`], n or Esc
+    /// returns to the content.
+    pub confirm_discard: Option<String>,
     /// Dialog buttons (label, action).
     pub buttons: Vec<(String, ContainerAction)>,
 }
 
 impl Default for DialogConfig {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             title: String::new(),
             width_percent: 60,
             height_percent: 50,
+            width: None,
+            height: None,
+            measure: None,
             min_width: 40,
             min_height: 10,
             max_width: 120,
@@ -172,7 +359,10 @@ impl Default for DialogConfig {
             border_color: Color::Blue,
             focused_border_color: Color::Cyan,
             close_on_escape: true,
-            close_on_outside_click: true,
+            escape_action: EscapeAction::Close,
+            dismiss_on_backdrop_click: false,
+            close_on_outside_click: false,
+            confirm_discard: None,
             buttons: vec![
                 ("Cancel".to_string(), ContainerAction::Close),
                 ("OK".to_string(), ContainerAction::Submit),
@@ -202,6 +392,33 @@ impl DialogConfig {
         self
     }
 
+    /// Set a fixed width in columns.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set a fixed height in rows.
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set a fixed size in cells.
+    pub fn size(self, width: u16, height: u16) -> Self {
+        self.width(width).height(height)
+    }
+
+    /// Size the dialog height to its content.
+    ///
+    /// `measure` receives the content width and returns the rows the
+    /// content needs; borders and the button row are added on top. The
+    /// result is still clamped to the min/max bounds and the screen.
+    pub fn size_to_content(mut self, measure: impl Fn(u16) -> u16 + Send + Sync + 'static) -> Self {
+        self.measure = Some(Arc::new(measure));
+        self
+    }
+
     /// Set minimum dimensions.
     pub fn min_size(mut self, width: u16, height: u16) -> Self {
         self.min_width = width;
@@ -234,12 +451,37 @@ impl DialogConfig {
         self
     }
 
-    /// Set close on outside click behavior.
-    pub fn close_on_outside_click(mut self, close: bool) -> Self {
-        self.close_on_outside_click = close;
+    /// Set what Escape does.
+    pub fn escape_action(mut self, action: EscapeAction) -> Self {
+        self.escape_action = action;
+        self
+    }
+
+    /// Set whether clicking the backdrop closes the dialog.
+    pub fn dismiss_on_backdrop_click(mut self, dismiss: bool) -> Self {
+        self.dismiss_on_backdrop_click = dismiss;
+        self
+    }
+
+    /// Ask with `prompt` before closing a dirty dialog.
+    pub fn confirm_discard(mut self, prompt: impl Into<String>) -> Self {
+        self.confirm_discard = Some(prompt.into());
         self
     }
 
+    /// Set close on outside click behavior.
+    #[deprecated(note = "use `dismiss_on_backdrop_click`")]
+    pub fn close_on_outside_click(self, close: bool) -> Self {
+        self.dismiss_on_backdrop_click(close)
+    }
+
+    /// Whether a backdrop click closes the dialog, honoring the deprecated
+    /// `close_on_outside_click` field.
+    #[allow(deprecated)]
+    fn dismisses_on_backdrop_click(&self) -> bool {
+        self.dismiss_on_backdrop_click || self.close_on_outside_click
+    }
+
     /// Set dialog buttons.
     pub fn buttons(mut self, buttons: Vec<(String, ContainerAction)>) -> Self {
         self.buttons = buttons;
@@ -289,6 +531,82 @@ impl DialogConfig {
         ];
         self
     }
+
+    /// Rows taken by the border and button row.
+    pub fn chrome_height(&self) -> u16 {
+        let button_height = if self.buttons.is_empty() { 0 } else { 2 };
+        2 + button_height
+    }
+
+    /// Calculate the dialog area centered on `screen`.
+    ///
+    /// Sizes are clamped to the min/max bounds and then to the screen,
+    /// keeping a 2-cell margin when the screen is large enough for the
+    /// minimum size. Screens smaller than the minimum shrink the dialog to
+    /// fit. Odd remainders place the extra cell after the dialog. Pass a
+    /// [`SafeArea`](crate::state::SafeArea) to center between reserved bars.
+    pub fn calculate_area(&self, screen: impl Into<Rect>) -> Rect {
+        let screen = screen.into();
+        let width = self
+            .width
+            .unwrap_or_else(|| percent_of(screen.width, self.width_percent));
+        let width = fit_to_screen(width, self.min_width, self.max_width, screen.width);
+
+        let height = match &self.measure {
+            Some(measure) => measure(width.saturating_sub(2)).saturating_add(self.chrome_height()),
+            None => self
+                .height
+                .unwrap_or_else(|| percent_of(screen.height, self.height_percent)),
+        };
+        let height = fit_to_screen(height, self.min_height, self.max_height, screen.height);
+
+        let x = screen.x + (screen.width - width) / 2;
+        let y = screen.y + (screen.height - height) / 2;
+
+        Rect::new(x, y, width, height)
+    }
+}
+
+impl std::fmt::Debug for DialogConfig {
+    #[allow(deprecated)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DialogConfig")
+            .field("title", &self.title)
+            .field("width_percent", &self.width_percent)
+            .field("height_percent", &self.height_percent)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("measure", &self.measure.as_ref().map(|_| "<fn>"))
+            .field("min_width", &self.min_width)
+            .field("min_height", &self.min_height)
+            .field("max_width", &self.max_width)
+            .field("max_height", &self.max_height)
+            .field("border_color", &self.border_color)
+            .field("focused_border_color", &self.focused_border_color)
+            .field("close_on_escape", &self.close_on_escape)
+            .field("escape_action", &self.escape_action)
+            .field("dismiss_on_backdrop_click", &self.dismiss_on_backdrop_click)
+            .field("close_on_outside_click", &self.close_on_outside_click)
+            .field("confirm_discard", &self.confirm_discard)
+            .field("buttons", &self.buttons)
+            .finish()
+    }
+}
+
+/// `percent`% of `total`, without overflowing on large screens.
+fn percent_of(total: u16, percent: u16) -> u16 {
+    (u32::from(total) * u32::from(percent) / 100) as u16
+}
+
+/// Clamp a dialog dimension to its bounds and then to the screen.
+fn fit_to_screen(size: u16, min: u16, max: u16, screen: u16) -> u16 {
+    let size = size.max(min).min(max);
+    let available = if screen >= min.saturating_add(4) {
+        screen - 4
+    } else {
+        screen
+    };
+    size.min(available)
 }
 
 /// Generic popup dialog container.
@@ -327,31 +645,29 @@ where
     }
 
     /// Calculate dialog area centered on screen.
-    pub fn calculate_area(&self, screen: Rect) -> Rect {
-        let width = (screen.width * self.config.width_percent / 100)
-            .max(self.config.min_width)
-            .min(self.config.max_width)
-            .min(screen.width.saturating_sub(4));
-
-        let height = (screen.height * self.config.height_percent / 100)
-            .max(self.config.min_height)
-            .min(self.config.max_height)
-            .min(screen.height.saturating_sub(4));
-
-        let x = (screen.width.saturating_sub(width)) / 2;
-        let y = (screen.height.saturating_sub(height)) / 2;
-
-        Rect::new(x, y, width, height)
+    ///
+    /// See [`DialogConfig::calculate_area`].
+    pub fn calculate_area(&self, screen: impl Into<Rect>) -> Rect {
+        self.config.calculate_area(screen)
     }
 
-    /// Render the popup.
+    /// Render the popup centered on the frame.
     pub fn render(&mut self, frame: &mut Frame) {
+        let screen = frame.area();
+        self.render_in(frame, screen);
+    }
+
+    /// Render the popup centered on `screen` instead of the whole frame.
+    ///
+    /// Pass a [`SafeArea`](crate::state::SafeArea) to keep the dialog off
+    /// reserved bars.
+    pub fn render_in(&mut self, frame: &mut Frame, screen: impl Into<Rect>) {
         if !self.state.visible {
             return;
         }
 
-        let screen = frame.area();
         let area = self.calculate_area(screen);
+        self.state.area = Some(area);
 
         // Clear click regions before rendering
         self.state.click_regions.clear();
@@ -370,7 +686,7 @@ where
         frame.render_widget(block, area);
 
         // Split inner area for content and buttons
-        let button_height = if self.config.buttons.is_empty() { 0 } else { 2 };
+        let button_height = self.config.chrome_height() - 2;
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Min(1), Constraint::Length(button_height)])
@@ -383,6 +699,48 @@ where
         if !self.config.buttons.is_empty() {
             self.render_buttons(frame, chunks[1]);
         }
+
+        // The discard prompt covers the bottom row
+        if self.state.confirming_discard
+            && let Some(prompt) = &self.config.confirm_discard
+            && inner.height > 0
+        {
+            let row = Rect::new(inner.x, inner.bottom() - 1, inner.width, 1);
+            frame.render_widget(Clear, row);
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    prompt.as_str(),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .alignment(Alignment::Center),
+                row,
+            );
+        }
+    }
+
+    /// Defer rendering to an [`OverlayManager`] on the
+    /// [`OverlayLayer::Dialog`] layer.
+    ///
+    /// `screen` must be the frame area the overlays are rendered into. The
+    /// dialog's click regions are refreshed when the overlays render.
+    pub fn render_into_overlays(
+        mut self,
+        screen: impl Into<Rect>,
+        overlays: &mut OverlayManager<'a>,
+    ) where
+        T: 'a,
+        F: 'a,
+    {
+        if !self.state.visible {
+            return;
+        }
+        let screen = screen.into();
+        let area = self.calculate_area(screen);
+        overlays.push(OverlayLayer::Dialog, area, move |frame| {
+            self.render_in(frame, screen)
+        });
     }
 
     fn render_buttons(&mut self, frame: &mut Frame, area: Rect) {
@@ -430,36 +788,61 @@ where
     }
 
     /// Handle keyboard event.
+    ///
+    /// Escape follows [`DialogConfig::escape_action`].
     pub fn handle_key(&mut self, key: KeyEvent) -> EventResult {
         if !self.state.visible {
             return EventResult::NotHandled;
         }
 
+        if self.state.confirming_discard {
+            return match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    self.state.hide();
+                    EventResult::Action(ContainerAction::Close)
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state.confirming_discard = false;
+                    EventResult::Consumed
+                }
+                // Modal while confirming
+                _ => EventResult::Consumed,
+            };
+        }
+
         match key.code {
-            KeyCode::Esc if self.config.close_on_escape => {
-                self.state.hide();
-                EventResult::Action(ContainerAction::Close)
-            }
+            KeyCode::Esc if self.config.close_on_escape => match &self.config.escape_action {
+                EscapeAction::Cancel
+                    if self.config.confirm_discard.is_none() && self.state.is_dirty() =>
+                {
+                    EventResult::Action(ContainerAction::custom(EscapeAction::CONFIRM_CANCEL))
+                }
+                EscapeAction::Close | EscapeAction::Cancel => self.close(),
+                EscapeAction::Custom(id) => EventResult::Action(ContainerAction::custom(id)),
+                EscapeAction::Disabled => EventResult::Consumed,
+            },
             KeyCode::Tab if !key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.state.close_focused_popups();
                 self.state.focus.next();
                 EventResult::Consumed
             }
             KeyCode::BackTab => {
+                self.state.close_focused_popups();
                 self.state.focus.prev();
                 EventResult::Consumed
             }
             KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.state.close_focused_popups();
                 self.state.focus.prev();
                 EventResult::Consumed
             }
             KeyCode::Enter => {
                 if let Some(DialogFocusTarget::Button(idx)) = self.state.focus.current() {
                     if let Some((_, action)) = self.config.buttons.get(*idx) {
-                        let action = action.clone();
                         if action.is_close() {
-                            self.state.hide();
+                            return self.close();
                         }
-                        return EventResult::Action(action);
+                        return EventResult::Action(action.clone());
                     }
                 }
                 EventResult::NotHandled
@@ -468,106 +851,148 @@ where
         }
     }
 
-    /// Handle mouse event.
-    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> EventResult {
+    /// Handle keyboard event, forwarding unconsumed keys to the content.
+    ///
+    /// The dialog handles its own keys first (Tab navigation, Enter on a
+    /// focused button). Any key the dialog does not consume is passed to
+    /// `content_handler` along with the content state, and the content's
+    /// result is bubbled up as [`DialogAction::Content`].
+    ///
+    /// Esc is the exception: it goes to the content first, so the content can
+    /// use it to leave an inner mode such as a search, and only closes the
+    /// dialog when the content returns [`EventResult::NotHandled`]. See
+    /// [`CloseRequest`] for the full ordering.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // `PreviewContent::handle_key` returns `EventResult<PreviewAction>`
+    /// match dialog.handle_key_with(key, |key, content| content.handle_key(key)) {
+    ///     EventResult::Action(DialogAction::Container(ContainerAction::Submit)) => { /* apply */ }
+    ///     EventResult::Action(DialogAction::Content(action)) => { /* content action */ }
+    ///     _ => {}
+    /// }
+    /// ```
+    pub fn handle_key_with<A, H>(
+        &mut self,
+        key: KeyEvent,
+        content_handler: H,
+    ) -> EventResult<DialogAction<A>>
+    where
+        H: FnOnce(KeyEvent, &mut T) -> EventResult<A>,
+    {
         if !self.state.visible {
             return EventResult::NotHandled;
         }
 
-        let screen = Rect::new(0, 0, 80, 24); // Will be passed from actual screen
-        let area = self.calculate_area(screen);
+        if self.state.confirming_discard {
+            return self.handle_key(key).map(DialogAction::Container);
+        }
 
-        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-            let col = mouse.column;
-            let row = mouse.row;
+        if key.code == KeyCode::Esc {
+            return match content_handler(key, &mut self.state.children) {
+                EventResult::NotHandled => self.handle_key(key).map(DialogAction::Container),
+                result => result.map(DialogAction::Content),
+            };
+        }
 
-            // Check if click is outside dialog
-            if self.config.close_on_outside_click
-                && (col < area.x
-                    || col >= area.x + area.width
-                    || row < area.y
-                    || row >= area.y + area.height)
-            {
-                self.state.hide();
-                return EventResult::Action(ContainerAction::Close);
-            }
-
-            // Check click regions
-            if let Some(target) = self.state.click_regions.handle_click(col, row) {
-                match target {
-                    DialogFocusTarget::Button(idx) => {
-                        if let Some((_, action)) = self.config.buttons.get(*idx) {
-                            let action = action.clone();
-                            if action.is_close() {
-                                self.state.hide();
-                            }
-                            return EventResult::Action(action);
-                        }
-                    }
-                    DialogFocusTarget::Child(idx) => {
-                        self.state.focus.set(DialogFocusTarget::Child(*idx));
-                        return EventResult::Consumed;
-                    }
-                    DialogFocusTarget::Close => {
-                        self.state.hide();
-                        return EventResult::Action(ContainerAction::Close);
-                    }
-                }
+        match self.handle_key(key) {
+            EventResult::NotHandled => {
+                content_handler(key, &mut self.state.children).map(DialogAction::Content)
             }
+            result => result.map(DialogAction::Container),
         }
+    }
 
-        EventResult::NotHandled
+    /// Handle mouse event.
+    ///
+    /// Hit tests against the area of the last render, falling back to an
+    /// 80x24 screen before the first one. A visible dialog is modal: every
+    /// mouse event outside it is consumed, and a left click there closes it
+    /// when [`DialogConfig::dismiss_on_backdrop_click`] is set.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> EventResult {
+        let area = self
+            .state
+            .area
+            .unwrap_or_else(|| self.calculate_area(Rect::new(0, 0, 80, 24)));
+        self.handle_mouse_in(mouse, area)
     }
 
     /// Handle mouse event with screen dimensions.
-    pub fn handle_mouse_with_screen(&mut self, mouse: MouseEvent, screen: Rect) -> EventResult {
+    ///
+    /// Like [`handle_mouse`](Self::handle_mouse), but hit tests against the
+    /// dialog area centered on `screen`.
+    pub fn handle_mouse_with_screen(
+        &mut self,
+        mouse: MouseEvent,
+        screen: impl Into<Rect>,
+    ) -> EventResult {
+        let area = self.calculate_area(screen);
+        self.handle_mouse_in(mouse, area)
+    }
+
+    fn handle_mouse_in(&mut self, mouse: MouseEvent, area: Rect) -> EventResult {
         if !self.state.visible {
             return EventResult::NotHandled;
         }
 
-        let area = self.calculate_area(screen);
-
-        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-            let col = mouse.column;
-            let row = mouse.row;
+        let col = mouse.column;
+        let row = mouse.row;
+        let outside = col < area.x || col >= area.right() || row < area.y || row >= area.bottom();
 
-            // Check if click is outside dialog
-            if self.config.close_on_outside_click
-                && (col < area.x
-                    || col >= area.x + area.width
-                    || row < area.y
-                    || row >= area.y + area.height)
+        // The backdrop swallows clicks and scrolling meant for the widgets below
+        if outside {
+            if mouse.kind == MouseEventKind::Down(MouseButton::Left)
+                && self.config.dismisses_on_backdrop_click()
+                && !self.state.confirming_discard
             {
-                self.state.hide();
-                return EventResult::Action(ContainerAction::Close);
+                return self.close();
             }
+            return EventResult::Consumed;
+        }
+
+        // The discard prompt is answered with the keyboard
+        if self.state.confirming_discard {
+            return EventResult::Consumed;
+        }
 
-            // Check click regions (same as handle_mouse)
-            if let Some(target) = self.state.click_regions.handle_click(col, row) {
-                match target {
-                    DialogFocusTarget::Button(idx) => {
-                        if let Some((_, action)) = self.config.buttons.get(*idx) {
-                            let action = action.clone();
-                            if action.is_close() {
-                                self.state.hide();
-                            }
-                            return EventResult::Action(action);
+        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind
+            && let Some(target) = self.state.click_regions.handle_click(col, row)
+        {
+            match target {
+                DialogFocusTarget::Button(idx) => {
+                    if let Some((_, action)) = self.config.buttons.get(*idx) {
+                        if action.is_close() {
+                            return self.close();
                         }
+                        return EventResult::Action(action.clone());
                     }
-                    DialogFocusTarget::Child(idx) => {
-                        self.state.focus.set(DialogFocusTarget::Child(*idx));
-                        return EventResult::Consumed;
-                    }
-                    DialogFocusTarget::Close => {
-                        self.state.hide();
-                        return EventResult::Action(ContainerAction::Close);
+                }
+                DialogFocusTarget::Child(idx) => {
+                    let target = DialogFocusTarget::Child(*idx);
+                    if !self.state.focus.is_focused(&target) {
+                        self.state.close_focused_popups();
                     }
+                    self.state.focus.set(target);
+                    return EventResult::Consumed;
                 }
+                DialogFocusTarget::Close => return self.close(),
             }
         }
 
         EventResult::NotHandled
     }
+
+    /// Close the dialog, or show the discard prompt first when configured
+    /// and the content is dirty.
+    fn close(&mut self) -> EventResult {
+        if self.config.confirm_discard.is_some() && self.state.is_dirty() {
+            self.state.confirming_discard = true;
+            return EventResult::Consumed;
+        }
+        self.state.hide();
+        EventResult::Action(ContainerAction::Close)
+    }
 }
 
 #[cfg(test)]
@@ -624,7 +1049,7 @@ mod tests {
         assert_eq!(config.width_percent, 60);
         assert_eq!(config.height_percent, 50);
         assert!(config.close_on_escape);
-        assert!(config.close_on_outside_click);
+        assert!(!config.dismiss_on_backdrop_click);
         assert_eq!(config.buttons.len(), 2);
     }
 
@@ -634,13 +1059,13 @@ mod tests {
             .width_percent(80)
             .height_percent(60)
             .close_on_escape(false)
-            .close_on_outside_click(false);
+            .dismiss_on_backdrop_click(true);
 
         assert_eq!(config.title, "Test Dialog");
         assert_eq!(config.width_percent, 80);
         assert_eq!(config.height_percent, 60);
         assert!(!config.close_on_escape);
-        assert!(!config.close_on_outside_click);
+        assert!(config.dismiss_on_backdrop_click);
     }
 
     #[test]
@@ -709,6 +1134,189 @@ mod tests {
         assert_eq!(area.height, 30);
     }
 
+    #[test]
+    fn test_calculate_area_fixed_size() {
+        let config = DialogConfig::new("Test").size(50, 12);
+
+        for (w, h) in [(80, 24), (120, 40), (200, 60)] {
+            let area = config.calculate_area(Rect::new(0, 0, w, h));
+            assert_eq!((area.width, area.height), (50, 12));
+            assert_eq!(area.x, (w - 50) / 2);
+            assert_eq!(area.y, (h - 12) / 2);
+        }
+    }
+
+    #[test]
+    fn test_calculate_area_odd_remainder_and_offset_screen() {
+        let config = DialogConfig::new("Test").size(40, 10);
+
+        // 81 - 40 = 41 and 25 - 10 = 15: the extra cell goes after the dialog
+        let area = config.calculate_area(Rect::new(0, 0, 81, 25));
+        assert_eq!((area.x, area.y), (20, 7));
+        assert_eq!(81 - area.right(), 21);
+        assert_eq!(25 - area.bottom(), 8);
+
+        // Centered within the given screen, not the terminal origin
+        let area = config.calculate_area(Rect::new(10, 5, 81, 25));
+        assert_eq!((area.x, area.y), (30, 12));
+    }
+
+    #[test]
+    fn test_calculate_area_safe_area() {
+        use crate::state::SafeArea;
+
+        let config = DialogConfig::new("Test").size(40, 10);
+        let screen = Rect::new(0, 0, 80, 24);
+
+        // No reservations: same as the full screen
+        assert_eq!(
+            config.calculate_area(SafeArea::new(screen)),
+            config.calculate_area(screen)
+        );
+
+        // Centered between a 2-row header and a 4-row footer
+        let safe = SafeArea::new(screen).reserve_top(2).reserve_bottom(4);
+        let area = config.calculate_area(safe);
+        assert_eq!(area, Rect::new(20, 6, 40, 10));
+    }
+
+    #[test]
+    fn test_calculate_area_tiny_terminal_shrinks_to_fit() {
+        let config = DialogConfig::new("Test").min_size(40, 10);
+
+        for (w, h) in [(30, 8), (40, 10), (42, 11), (1, 1), (0, 0)] {
+            let screen = Rect::new(0, 0, w, h);
+            let area = config.calculate_area(screen);
+            assert!(area.width <= w && area.height <= h, "{w}x{h}: {area:?}");
+            assert_eq!(screen.intersection(area), area);
+        }
+
+        // Below the minimum, the dialog fills the screen
+        let area = config.calculate_area(Rect::new(0, 0, 30, 8));
+        assert_eq!(area, Rect::new(0, 0, 30, 8));
+
+        // Large enough for the minimum but not the margin: minimum wins
+        let area = config.calculate_area(Rect::new(0, 0, 42, 11));
+        assert_eq!((area.width, area.height), (40, 10));
+    }
+
+    #[test]
+    fn test_calculate_area_large_screen_percent() {
+        // 1000 * 60 would overflow u16 arithmetic
+        let config = DialogConfig::new("Test").max_size(u16::MAX, u16::MAX);
+        let area = config.calculate_area(Rect::new(0, 0, 1000, 800));
+        assert_eq!((area.width, area.height), (600, 400));
+    }
+
+    #[test]
+    fn test_calculate_area_size_to_content() {
+        // 120 characters of text wrapped to the content width
+        let config = DialogConfig::new("Test")
+            .width(42)
+            .min_size(20, 3)
+            .size_to_content(|width| 120_u16.div_ceil(width));
+
+        // Content width 40 -> 3 rows, + 2 borders + 2 button rows
+        let area = config.calculate_area(Rect::new(0, 0, 80, 24));
+        assert_eq!((area.width, area.height), (42, 7));
+        assert_eq!(area.y, 8);
+
+        // Without buttons the chrome is just the border
+        let config = config.no_buttons();
+        let area = config.calculate_area(Rect::new(0, 0, 80, 24));
+        assert_eq!(area.height, 5);
+
+        // Tall content is clamped to the screen
+        let config = DialogConfig::new("Test").size_to_content(|_| 100);
+        for h in [24, 30, 40] {
+            let area = config.calculate_area(Rect::new(0, 0, 80, h));
+            assert_eq!(area.height, h - 4);
+        }
+        // No room for the margin above the minimum height: fill the screen
+        let area = config.calculate_area(Rect::new(0, 0, 80, 12));
+        assert_eq!(area.height, 12);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum ContentAction {
+        Scrolled,
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_handle_key_with_forwards_to_content() {
+        let config = DialogConfig::new("Test");
+        let mut state: DialogState<u32> = DialogState::new(0);
+        state.show();
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let result = dialog.handle_key_with(key(KeyCode::Down), |_, count| {
+            *count += 1;
+            EventResult::Action(ContentAction::Scrolled)
+        });
+
+        assert_eq!(
+            result,
+            EventResult::Action(DialogAction::Content(ContentAction::Scrolled))
+        );
+        assert_eq!(state.children, 1);
+    }
+
+    #[test]
+    fn test_handle_key_with_dialog_consumes_escape() {
+        let config = DialogConfig::new("Test");
+        let mut state: DialogState<u32> = DialogState::new(0);
+        state.show();
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let result = dialog.handle_key_with(key(KeyCode::Esc), |_, count| {
+            *count += 1;
+            EventResult::<ContentAction>::NotHandled
+        });
+
+        assert_eq!(
+            result,
+            EventResult::Action(DialogAction::Container(ContainerAction::Close))
+        );
+        assert_eq!(state.children, 1);
+        assert!(!state.visible);
+    }
+
+    #[test]
+    fn test_handle_key_with_content_gets_escape_first() {
+        let config = DialogConfig::new("Test");
+        let mut state: DialogState<u32> = DialogState::new(0);
+        state.show();
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let result = dialog.handle_key_with(key(KeyCode::Esc), |_, count| {
+            *count += 1;
+            EventResult::Action(ContentAction::Scrolled)
+        });
+
+        assert_eq!(
+            result,
+            EventResult::Action(DialogAction::Content(ContentAction::Scrolled))
+        );
+        assert_eq!(state.children, 1);
+        assert!(state.visible);
+    }
+
+    #[test]
+    fn test_handle_key_with_hidden_dialog() {
+        let config = DialogConfig::new("Test");
+        let mut state: DialogState<u32> = DialogState::new(0);
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let result: EventResult<DialogAction<ContentAction>> =
+            dialog.handle_key_with(key(KeyCode::Down), |_, _| EventResult::Consumed);
+
+        assert_eq!(result, EventResult::NotHandled);
+    }
+
     #[test]
     fn test_dialog_focus_target_equality() {
         assert_eq!(DialogFocusTarget::Child(0), DialogFocusTarget::Child(0));
@@ -716,4 +1324,248 @@ mod tests {
         assert_ne!(DialogFocusTarget::Child(0), DialogFocusTarget::Button(0));
         assert_eq!(DialogFocusTarget::Close, DialogFocusTarget::Close);
     }
+
+    #[test]
+    fn test_escape_action() {
+        let esc = key(KeyCode::Esc);
+        let press = |config: &DialogConfig, state: &mut DialogState<bool>| {
+            state.show();
+            PopupDialog::new(config, state, |_, _, _| {}).handle_key(esc)
+        };
+        let mut state = DialogState::new(false).dirty_when(|changed| *changed);
+
+        let config = DialogConfig::new("Test").escape_action(EscapeAction::Disabled);
+        assert_eq!(press(&config, &mut state), EventResult::Consumed);
+        assert!(state.visible);
+
+        let config = DialogConfig::new("Test").escape_action(EscapeAction::Custom("ask".into()));
+        assert_eq!(
+            press(&config, &mut state),
+            EventResult::Action(ContainerAction::custom("ask"))
+        );
+        assert!(state.visible);
+
+        // Cancel closes a clean dialog and asks before closing a dirty one
+        let config = DialogConfig::new("Test").escape_action(EscapeAction::Cancel);
+        state.children = true;
+        assert_eq!(
+            press(&config, &mut state),
+            EventResult::Action(ContainerAction::custom(EscapeAction::CONFIRM_CANCEL))
+        );
+        assert!(state.visible);
+        state.children = false;
+        assert_eq!(
+            press(&config, &mut state),
+            EventResult::Action(ContainerAction::Close)
+        );
+        assert!(!state.visible);
+
+        let config = DialogConfig::new("Test").close_on_escape(false);
+        assert_eq!(press(&config, &mut state), EventResult::NotHandled);
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn render_dialog(config: &DialogConfig, state: &mut DialogState<()>) -> Rect {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|frame| PopupDialog::new(config, state, |_, _, _| {}).render(frame))
+            .unwrap();
+        state.area.unwrap()
+    }
+
+    #[test]
+    fn test_mouse_click_inside_input_region() {
+        let config = DialogConfig::new("Test");
+        let mut state: DialogState<()> = DialogState::new(());
+        state.register_child(0);
+        state.register_button(0);
+        state.show();
+        let area = render_dialog(&config, &mut state);
+        // Hit testing uses the rendered 100x30 area, not an assumed 80x24
+        assert_eq!(area, config.calculate_area(Rect::new(0, 0, 100, 30)));
+
+        let input = Rect::new(area.x + 2, area.y + 2, 20, 1);
+        state
+            .click_regions
+            .register(input, DialogFocusTarget::Child(0));
+        state.focus.set(DialogFocusTarget::Button(0));
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let click = mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            input.x + 3,
+            input.y,
+        );
+        assert_eq!(dialog.handle_mouse(click), EventResult::Consumed);
+        assert!(state.is_child_focused(0));
+        assert!(state.visible);
+    }
+
+    #[test]
+    fn test_mouse_backdrop_click_dismisses() {
+        let config = DialogConfig::new("Test").dismiss_on_backdrop_click(true);
+        let mut state: DialogState<()> = DialogState::new(());
+        state.show();
+        render_dialog(&config, &mut state);
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let click = mouse(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert_eq!(
+            dialog.handle_mouse(click),
+            EventResult::Action(ContainerAction::Close)
+        );
+        assert!(!state.visible);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_mouse_backdrop_click_dismisses_with_legacy_field() {
+        let mut config = DialogConfig::new("Test");
+        config.close_on_outside_click = true;
+        let mut state: DialogState<()> = DialogState::new(());
+        state.show();
+        render_dialog(&config, &mut state);
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let click = mouse(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert_eq!(
+            dialog.handle_mouse(click),
+            EventResult::Action(ContainerAction::Close)
+        );
+        assert!(!state.visible);
+    }
+
+    #[test]
+    fn test_mouse_backdrop_consumed_without_dismissal() {
+        let config = DialogConfig::new("Test");
+        let mut state: DialogState<()> = DialogState::new(());
+        state.show();
+        let area = render_dialog(&config, &mut state);
+
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let below = area.bottom() + 1;
+        for kind in [
+            MouseEventKind::Down(MouseButton::Left),
+            MouseEventKind::Down(MouseButton::Right),
+            MouseEventKind::ScrollDown,
+            MouseEventKind::ScrollUp,
+        ] {
+            assert_eq!(
+                dialog.handle_mouse(mouse(kind, 1, below)),
+                EventResult::Consumed
+            );
+        }
+        // Inside the dialog but off any region, the content may handle it
+        let inside = mouse(MouseEventKind::ScrollDown, area.x + 1, area.y + 1);
+        assert_eq!(dialog.handle_mouse(inside), EventResult::NotHandled);
+        assert!(state.visible);
+
+        // A hidden dialog lets everything through
+        state.hide();
+        let mut dialog = PopupDialog::new(&config, &mut state, |_, _, _| {});
+        let click = mouse(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert_eq!(dialog.handle_mouse(click), EventResult::NotHandled);
+    }
+
+    #[test]
+    fn test_confirm_discard_flow() {
+        use crate::components::TextAreaState;
+
+        let config = DialogConfig::new("Edit").confirm_discard("Discard changes? (y/n)");
+        let mut state = DialogState::new(TextAreaState::new("draft")).dirty_when(|t| t.is_dirty());
+        state.show();
+        let type_char = |key: KeyEvent, text: &mut TextAreaState| match key.code {
+            KeyCode::Char(c) => {
+                text.insert_char(c);
+                EventResult::Action(())
+            }
+            _ => EventResult::<()>::NotHandled,
+        };
+        let send = |state: &mut DialogState<TextAreaState>, code| {
+            PopupDialog::new(&config, state, |_, _, _| {}).handle_key_with(key(code), type_char)
+        };
+
+        // Clean content closes straight away
+        let mut clean = state.clone();
+        assert_eq!(
+            send(&mut clean, KeyCode::Esc),
+            EventResult::Action(DialogAction::Container(ContainerAction::Close))
+        );
+
+        // Edit, then Esc asks instead of closing
+        send(&mut state, KeyCode::Char('!'));
+        assert!(state.children.is_dirty());
+        assert_eq!(send(&mut state, KeyCode::Esc), EventResult::Consumed);
+        assert!(state.confirming_discard && state.visible);
+
+        // Keys do not reach the content while asking; n returns to editing
+        send(&mut state, KeyCode::Char('x'));
+        assert_eq!(send(&mut state, KeyCode::Char('n')), EventResult::Consumed);
+        assert!(!state.confirming_discard && state.visible);
+        assert_eq!(state.children.text(), "!draft");
+
+        // Asking again and confirming propagates the original Close
+        send(&mut state, KeyCode::Esc);
+        assert_eq!(
+            send(&mut state, KeyCode::Char('y')),
+            EventResult::Action(DialogAction::Container(ContainerAction::Close))
+        );
+        assert!(!state.visible && !state.confirming_discard);
+
+        // After a successful submit the content is clean again
+        state.show();
+        state.children.mark_clean();
+        assert_eq!(
+            send(&mut state, KeyCode::Esc),
+            EventResult::Action(DialogAction::Container(ContainerAction::Close))
+        );
+    }
+
+    #[test]
+    fn test_tab_closes_open_select_before_moving_focus() {
+        use crate::components::{SelectAction, SelectState, handle_select_key};
+        use crate::traits::PopupOwner;
+
+        let config = DialogConfig::new("Pick");
+        let mut state = DialogState::new(SelectState::new(3))
+            .close_popup_on_blur(DialogFocusTarget::Child(0), |select| select.on_focus_lost());
+        state.register_child(0);
+        state.register_child(1);
+        state.show();
+        let send = |state: &mut DialogState<SelectState>, code| {
+            PopupDialog::new(&config, state, |_, _, _| {}).handle_key_with(
+                key(code),
+                |key, select| match handle_select_key(&key, select) {
+                    Some(action) => EventResult::Action(action),
+                    None => EventResult::NotHandled,
+                },
+            )
+        };
+
+        assert_eq!(
+            send(&mut state, KeyCode::Enter),
+            EventResult::Action(DialogAction::Content(SelectAction::Open))
+        );
+        assert!(state.children.is_open);
+
+        assert_eq!(send(&mut state, KeyCode::Tab), EventResult::Consumed);
+        assert!(!state.children.is_open);
+        assert!(state.is_child_focused(1));
+
+        // Leaving another child does not touch the select
+        state.children.open();
+        send(&mut state, KeyCode::Tab);
+        assert!(state.children.is_open);
+        assert!(state.is_child_focused(0));
+    }
 }
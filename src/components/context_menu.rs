@@ -1,7 +1,7 @@
 //! Context Menu component - Right-click popup menu
 //!
 //! A context menu component that displays a popup menu at a specified position
-//! with support for actions, separators, and nested submenus.
+//! with support for actions, separators, labelled groups, and nested submenus.
 //!
 //! # Example
 //!
@@ -30,26 +30,39 @@
 //! // Render and handle events (see handle_context_menu_key, handle_context_menu_mouse)
 //! ```
 
+use std::any::Any;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
+    symbols::border,
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
-use crate::traits::ClickRegion;
+use crate::capabilities::{ASCII_BORDER, Capabilities};
+use crate::events::CloseRequest;
+use crate::state::{OverlayLayer, OverlayManager};
+use crate::traits::{ClickRegion, MenuAction, PopupOwner};
+use crate::utils::display::{display_width, spaces};
 
 /// Actions a context menu can emit.
+///
+/// `A` is the item action type; see [`MenuAction`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ContextMenuAction {
+pub enum TypedContextMenuAction<A> {
     /// Menu was opened.
     Open,
     /// Menu was closed.
     Close,
-    /// An action item was selected (item ID).
-    Select(String),
+    /// An action item was selected (item action).
+    Select(A),
     /// A submenu was opened (parent index).
     SubmenuOpen(usize),
     /// A submenu was closed.
@@ -58,13 +71,28 @@ pub enum ContextMenuAction {
     HighlightChange(usize),
 }
 
+/// Context menu actions for items with string IDs.
+pub type ContextMenuAction = TypedContextMenuAction<String>;
+
+/// For the result of handling Esc: closing the menu or a submenu consumes the key.
+impl<A> From<Option<TypedContextMenuAction<A>>> for CloseRequest {
+    fn from(action: Option<TypedContextMenuAction<A>>) -> Self {
+        match action {
+            Some(_) => CloseRequest::Consumed,
+            None => CloseRequest::Propagate,
+        }
+    }
+}
+
 /// A single item in a context menu.
+///
+/// `A` is the action the item triggers; see [`MenuAction`].
 #[derive(Debug, Clone)]
-pub enum ContextMenuItem {
+pub enum TypedContextMenuItem<A> {
     /// A clickable action item.
     Action {
-        /// Unique identifier for this action.
-        id: String,
+        /// Action emitted when this item is selected.
+        id: A,
         /// Display label.
         label: String,
         /// Optional icon (emoji or character).
@@ -73,6 +101,8 @@ pub enum ContextMenuItem {
         shortcut: Option<String>,
         /// Whether the item is enabled.
         enabled: bool,
+        /// One-line description shown in a status line while highlighted.
+        hint: Option<String>,
     },
     /// A visual separator line.
     Separator,
@@ -83,21 +113,42 @@ pub enum ContextMenuItem {
         /// Optional icon.
         icon: Option<String>,
         /// Child menu items.
-        items: Vec<ContextMenuItem>,
+        items: Vec<TypedContextMenuItem<A>>,
         /// Whether the submenu is enabled.
         enabled: bool,
+        /// One-line description shown in a status line while highlighted.
+        hint: Option<String>,
+    },
+    /// A labelled section: a non-selectable header row followed by its
+    /// items, indented by [`ContextMenuStyle::group_indent`].
+    Group {
+        /// Header text.
+        label: String,
+        /// Items in this section.
+        items: Vec<TypedContextMenuItem<A>>,
     },
 }
 
+/// A context menu item with a string ID.
+pub type ContextMenuItem = TypedContextMenuItem<String>;
+
 impl ContextMenuItem {
-    /// Create a new action item.
+    /// Create a new action item with a string ID.
     pub fn action(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::from_action(id.into(), label)
+    }
+}
+
+impl<A> TypedContextMenuItem<A> {
+    /// Create a new action item that emits `action` when selected.
+    pub fn from_action(action: A, label: impl Into<String>) -> Self {
         Self::Action {
-            id: id.into(),
+            id: action,
             label: label.into(),
             icon: None,
             shortcut: None,
             enabled: true,
+            hint: None,
         }
     }
 
@@ -107,12 +158,24 @@ impl ContextMenuItem {
     }
 
     /// Create a submenu.
-    pub fn submenu(label: impl Into<String>, items: Vec<ContextMenuItem>) -> Self {
+    pub fn submenu(label: impl Into<String>, items: Vec<TypedContextMenuItem<A>>) -> Self {
         Self::Submenu {
             label: label.into(),
             icon: None,
             items,
             enabled: true,
+            hint: None,
+        }
+    }
+
+    /// Create a labelled group of items.
+    ///
+    /// The header row is skipped by keyboard navigation and has no click
+    /// region; the items behave like top-level items.
+    pub fn group(label: impl Into<String>, items: Vec<TypedContextMenuItem<A>>) -> Self {
+        Self::Group {
+            label: label.into(),
+            items,
         }
     }
 
@@ -121,7 +184,7 @@ impl ContextMenuItem {
         match &mut self {
             Self::Action { icon: i, .. } => *i = Some(icon.into()),
             Self::Submenu { icon: i, .. } => *i = Some(icon.into()),
-            Self::Separator => {}
+            Self::Separator | Self::Group { .. } => {}
         }
         self
     }
@@ -134,21 +197,31 @@ impl ContextMenuItem {
         self
     }
 
+    /// Add a status-line hint to this item.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        match &mut self {
+            Self::Action { hint: h, .. } => *h = Some(hint.into()),
+            Self::Submenu { hint: h, .. } => *h = Some(hint.into()),
+            Self::Separator | Self::Group { .. } => {}
+        }
+        self
+    }
+
     /// Set whether this item is enabled.
     pub fn enabled(mut self, enabled: bool) -> Self {
         match &mut self {
             Self::Action { enabled: e, .. } => *e = enabled,
             Self::Submenu { enabled: e, .. } => *e = enabled,
-            Self::Separator => {}
+            Self::Separator | Self::Group { .. } => {}
         }
         self
     }
 
-    /// Check if this item is selectable (not a separator and enabled).
+    /// Check if this item is selectable (not a separator or group header, and enabled).
     pub fn is_selectable(&self) -> bool {
         match self {
             Self::Action { enabled, .. } => *enabled,
-            Self::Separator => false,
+            Self::Separator | Self::Group { .. } => false,
             Self::Submenu { enabled, .. } => *enabled,
         }
     }
@@ -158,8 +231,8 @@ impl ContextMenuItem {
         matches!(self, Self::Submenu { .. })
     }
 
-    /// Get the ID if this is an action item.
-    pub fn id(&self) -> Option<&str> {
+    /// Get the action if this is an action item.
+    pub fn get_action(&self) -> Option<&A> {
         if let Self::Action { id, .. } = self {
             Some(id)
         } else {
@@ -172,6 +245,7 @@ impl ContextMenuItem {
         match self {
             Self::Action { label, .. } => Some(label),
             Self::Submenu { label, .. } => Some(label),
+            Self::Group { label, .. } => Some(label),
             Self::Separator => None,
         }
     }
@@ -181,7 +255,7 @@ impl ContextMenuItem {
         match self {
             Self::Action { icon, .. } => icon.as_deref(),
             Self::Submenu { icon, .. } => icon.as_deref(),
-            Self::Separator => None,
+            Self::Separator | Self::Group { .. } => None,
         }
     }
 
@@ -194,23 +268,106 @@ impl ContextMenuItem {
         }
     }
 
+    /// Get the status-line hint for this item.
+    pub fn get_hint(&self) -> Option<&str> {
+        match self {
+            Self::Action { hint, .. } => hint.as_deref(),
+            Self::Submenu { hint, .. } => hint.as_deref(),
+            Self::Separator | Self::Group { .. } => None,
+        }
+    }
+
     /// Check if this item is enabled.
     pub fn is_enabled(&self) -> bool {
         match self {
             Self::Action { enabled, .. } => *enabled,
-            Self::Separator => false,
+            Self::Separator | Self::Group { .. } => false,
             Self::Submenu { enabled, .. } => *enabled,
         }
     }
 
     /// Get submenu items if this is a submenu.
-    pub fn submenu_items(&self) -> Option<&[ContextMenuItem]> {
+    pub fn submenu_items(&self) -> Option<&[TypedContextMenuItem<A>]> {
         if let Self::Submenu { items, .. } = self {
             Some(items)
         } else {
             None
         }
     }
+
+    /// Get the grouped items if this is a group.
+    pub fn group_items(&self) -> Option<&[TypedContextMenuItem<A>]> {
+        if let Self::Group { items, .. } = self {
+            Some(items)
+        } else {
+            None
+        }
+    }
+}
+
+/// Push the icon cell of a row: the icon, if any, padded to `column` cells
+/// plus a gap. Nothing is pushed when no item has an icon.
+fn push_icon<'s>(spans: &mut Vec<Span<'s>>, icon: Option<&'s str>, column: usize, style: Style) {
+    if column == 0 {
+        return;
+    }
+    let icon_width = icon.map_or(0, display_width);
+    if let Some(icon) = icon {
+        spans.push(Span::styled(icon, style));
+    }
+    spans.push(Span::styled(
+        spaces(column.saturating_sub(icon_width) + 1),
+        style,
+    ));
+}
+
+/// Items as laid out in the menu, with their group nesting depth.
+///
+/// Groups expand into their header row followed by their items, so menu
+/// indices (highlight, submenu, click regions) count header rows. Without
+/// groups this is the items one-to-one.
+fn menu_rows<A>(items: &[TypedContextMenuItem<A>]) -> Vec<(&TypedContextMenuItem<A>, u16)> {
+    fn push<'i, A>(
+        items: &'i [TypedContextMenuItem<A>],
+        depth: u16,
+        rows: &mut Vec<(&'i TypedContextMenuItem<A>, u16)>,
+    ) {
+        for item in items {
+            rows.push((item, depth));
+            if let TypedContextMenuItem::Group { items, .. } = item {
+                push(items, depth + 1, rows);
+            }
+        }
+    }
+
+    let mut rows = Vec::with_capacity(items.len());
+    push(items, 0, &mut rows);
+    rows
+}
+
+/// The item on menu row `index`; see [`menu_rows`].
+fn menu_row<A>(
+    items: &[TypedContextMenuItem<A>],
+    index: usize,
+) -> Option<&TypedContextMenuItem<A>> {
+    menu_rows(items).get(index).map(|&(item, _)| item)
+}
+
+impl<A: MenuAction> TypedContextMenuItem<A> {
+    /// Get the action ID if this is an action item.
+    pub fn id(&self) -> Option<&str> {
+        self.get_action().map(MenuAction::id)
+    }
+}
+
+/// Builds context menu items for a right-clicked target.
+///
+/// Lets the menu content depend on what was clicked, e.g. different items
+/// for a file and a folder in a file explorer. The target is passed as
+/// `&dyn Any`; downcast it to the types the provider knows about.
+pub trait ContextMenuProvider<A = String> {
+    /// Build the items for `context`.
+    fn build_items(&self, context: &dyn Any) -> Vec<TypedContextMenuItem<A>>;
 }
 
 /// State for a context menu.
@@ -228,6 +385,11 @@ pub struct ContextMenuState {
     pub active_submenu: Option<usize>,
     /// State for active submenu (boxed to avoid infinite size).
     pub submenu_state: Option<Box<ContextMenuState>>,
+    /// Submenu item being hovered and when the hover started.
+    pub hover_start: Option<(usize, Instant)>,
+    /// Right-clicked target passed to a [`ContextMenuProvider`], kept until
+    /// the menu closes.
+    pub context: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 impl Default for ContextMenuState {
@@ -236,6 +398,14 @@ impl Default for ContextMenuState {
     }
 }
 
+impl PopupOwner for ContextMenuState {
+    fn on_focus_lost(&mut self) -> bool {
+        let was_open = self.is_open;
+        self.close();
+        was_open
+    }
+}
+
 impl ContextMenuState {
     /// Create a new context menu state.
     pub fn new() -> Self {
@@ -246,6 +416,8 @@ impl ContextMenuState {
             scroll_offset: 0,
             active_submenu: None,
             submenu_state: None,
+            hover_start: None,
+            context: None,
         }
     }
 
@@ -255,18 +427,45 @@ impl ContextMenuState {
         self.anchor_position = (x, y);
         self.highlighted_index = 0;
         self.scroll_offset = 0;
+        self.hover_start = None;
         self.close_submenu();
     }
 
+    /// Open the menu at the specified position for a right-clicked target.
+    ///
+    /// The context is kept in [`context`](Self::context) until the menu
+    /// closes, for [`build_items`](Self::build_items) and
+    /// [`handle_context_menu_key_with_provider`].
+    pub fn open_with_context(&mut self, x: u16, y: u16, context: impl Any + Send + Sync) {
+        self.open_at(x, y);
+        self.context = Some(Arc::new(context));
+    }
+
     /// Close the menu.
     pub fn close(&mut self) {
         self.is_open = false;
+        self.hover_start = None;
+        self.context = None;
         self.close_submenu();
     }
 
+    /// Build the items for the stored context.
+    ///
+    /// Without a context, the provider receives `&()`.
+    pub fn build_items<A>(
+        &self,
+        provider: &dyn ContextMenuProvider<A>,
+    ) -> Vec<TypedContextMenuItem<A>> {
+        match &self.context {
+            Some(context) => provider.build_items(context.as_ref()),
+            None => provider.build_items(&()),
+        }
+    }
+
     /// Move highlight to previous selectable item.
-    pub fn highlight_prev(&mut self, items: &[ContextMenuItem]) {
-        if items.is_empty() {
+    pub fn highlight_prev<A>(&mut self, items: &[TypedContextMenuItem<A>]) {
+        let rows = menu_rows(items);
+        if rows.is_empty() {
             return;
         }
 
@@ -276,7 +475,7 @@ impl ContextMenuState {
                 break;
             }
             new_index -= 1;
-            if items.get(new_index).is_some_and(|i| i.is_selectable()) {
+            if rows.get(new_index).is_some_and(|(i, _)| i.is_selectable()) {
                 self.highlighted_index = new_index;
                 break;
             }
@@ -284,18 +483,19 @@ impl ContextMenuState {
     }
 
     /// Move highlight to next selectable item.
-    pub fn highlight_next(&mut self, items: &[ContextMenuItem]) {
-        if items.is_empty() {
+    pub fn highlight_next<A>(&mut self, items: &[TypedContextMenuItem<A>]) {
+        let rows = menu_rows(items);
+        if rows.is_empty() {
             return;
         }
 
         let mut new_index = self.highlighted_index;
         loop {
             new_index += 1;
-            if new_index >= items.len() {
+            if new_index >= rows.len() {
                 break;
             }
-            if items.get(new_index).is_some_and(|i| i.is_selectable()) {
+            if rows.get(new_index).is_some_and(|(i, _)| i.is_selectable()) {
                 self.highlighted_index = new_index;
                 break;
             }
@@ -303,8 +503,8 @@ impl ContextMenuState {
     }
 
     /// Move to first selectable item.
-    pub fn highlight_first(&mut self, items: &[ContextMenuItem]) {
-        for (i, item) in items.iter().enumerate() {
+    pub fn highlight_first<A>(&mut self, items: &[TypedContextMenuItem<A>]) {
+        for (i, (item, _)) in menu_rows(items).into_iter().enumerate() {
             if item.is_selectable() {
                 self.highlighted_index = i;
                 self.scroll_offset = 0;
@@ -314,8 +514,8 @@ impl ContextMenuState {
     }
 
     /// Move to last selectable item.
-    pub fn highlight_last(&mut self, items: &[ContextMenuItem]) {
-        for (i, item) in items.iter().enumerate().rev() {
+    pub fn highlight_last<A>(&mut self, items: &[TypedContextMenuItem<A>]) {
+        for (i, (item, _)) in menu_rows(items).into_iter().enumerate().rev() {
             if item.is_selectable() {
                 self.highlighted_index = i;
                 break;
@@ -331,12 +531,49 @@ impl ContextMenuState {
         self.submenu_state = Some(Box::new(submenu_state));
     }
 
+    /// Open the hovered submenu once the hover delay has elapsed.
+    ///
+    /// Call this from the frame tick with the menu's
+    /// [`ContextMenuStyle::submenu_hover_delay_ms`]. Returns
+    /// `Some(SubmenuOpen)` when the submenu was opened.
+    pub fn tick_hover(&mut self, now: Instant, delay_ms: u64) -> Option<ContextMenuAction> {
+        let (idx, start) = self.hover_start?;
+        if idx != self.highlighted_index {
+            self.hover_start = None;
+            return None;
+        }
+        if now.saturating_duration_since(start) < Duration::from_millis(delay_ms) {
+            return None;
+        }
+        self.hover_start = None;
+        if self.active_submenu == Some(idx) {
+            return None;
+        }
+        self.open_submenu();
+        Some(ContextMenuAction::SubmenuOpen(idx))
+    }
+
     /// Close any open submenu.
     pub fn close_submenu(&mut self) {
         self.active_submenu = None;
         self.submenu_state = None;
     }
 
+    /// Hint of the highlighted item, for display in a status line.
+    ///
+    /// Looks into the open submenu. Returns `None` when the menu is closed,
+    /// a separator is highlighted, or the highlighted item has no hint.
+    pub fn current_hint<'m, A>(&self, items: &'m [TypedContextMenuItem<A>]) -> Option<&'m str> {
+        if !self.is_open {
+            return None;
+        }
+        if let (Some(index), Some(submenu_state)) = (self.active_submenu, &self.submenu_state) {
+            let sub_items = menu_row(items, index)?.submenu_items()?;
+            return submenu_state.current_hint(sub_items);
+        }
+        menu_row(items, self.highlighted_index)?.get_hint()
+    }
+
     /// Check if a submenu is open.
     pub fn has_open_submenu(&self) -> bool {
         self.active_submenu.is_some()
@@ -353,6 +590,18 @@ impl ContextMenuState {
             self.scroll_offset = (self.highlighted_index - viewport_height + 1) as u16;
         }
     }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_context_menu_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑/↓", "Move highlight"),
+            ("Home/End", "First/last item"),
+            ("Enter/Space", "Select item"),
+            ("→", "Open submenu"),
+            ("←", "Close submenu"),
+            ("Esc", "Close menu"),
+        ]
+    }
 }
 
 /// Style configuration for context menu.
@@ -386,8 +635,22 @@ pub struct ContextMenuStyle {
     pub submenu_indicator: &'static str,
     /// Separator character.
     pub separator_char: char,
+    /// Border characters.
+    pub border_set: border::Set<'static>,
+    /// Hover delay before a submenu auto-opens, in milliseconds.
+    pub submenu_hover_delay_ms: u64,
+    /// Rows per item (separators always take one row). Values above 1 give
+    /// larger click targets, e.g. for touch input.
+    pub item_height: u16,
+    /// Style for group header rows.
+    pub group_header_style: Style,
+    /// Extra indentation of items within a group, per nesting level.
+    pub group_indent: u16,
 }
 
+/// Default hover delay before a submenu auto-opens.
+const DEFAULT_SUBMENU_HOVER_DELAY_MS: u64 = 300;
+
 impl Default for ContextMenuStyle {
     fn default() -> Self {
         Self {
@@ -405,6 +668,13 @@ impl Default for ContextMenuStyle {
             padding: 1,
             submenu_indicator: "▶",
             separator_char: '─',
+            border_set: border::PLAIN,
+            submenu_hover_delay_ms: DEFAULT_SUBMENU_HOVER_DELAY_MS,
+            item_height: 1,
+            group_header_style: Style::default()
+                .fg(Color::Rgb(140, 140, 140))
+                .add_modifier(Modifier::BOLD),
+            group_indent: 2,
         }
     }
 }
@@ -427,6 +697,13 @@ impl From<&crate::theme::Theme> for ContextMenuStyle {
             padding: 1,
             submenu_indicator: "▶",
             separator_char: '─',
+            border_set: border::PLAIN,
+            submenu_hover_delay_ms: DEFAULT_SUBMENU_HOVER_DELAY_MS,
+            item_height: 1,
+            group_header_style: Style::default()
+                .fg(p.text_muted)
+                .add_modifier(Modifier::BOLD),
+            group_indent: 2,
         }
     }
 }
@@ -443,6 +720,9 @@ impl ContextMenuStyle {
             disabled_fg: Color::Rgb(160, 160, 160),
             shortcut_fg: Color::Rgb(100, 100, 100),
             separator_fg: Color::Rgb(200, 200, 200),
+            group_header_style: Style::default()
+                .fg(Color::Rgb(100, 100, 100))
+                .add_modifier(Modifier::BOLD),
             ..Default::default()
         }
     }
@@ -458,10 +738,44 @@ impl ContextMenuStyle {
             disabled_fg: Color::DarkGray,
             shortcut_fg: Color::Gray,
             separator_fg: Color::DarkGray,
+            group_header_style: Style::default().fg(Color::Gray),
             ..Default::default()
         }
     }
 
+    /// Create an ASCII-only style: `>` submenu indicator, `-` separators,
+    /// and `+-|` borders.
+    pub fn ascii() -> Self {
+        Self {
+            submenu_indicator: ">",
+            separator_char: '-',
+            border_set: ASCII_BORDER,
+            ..Default::default()
+        }
+    }
+
+    /// The default style adapted to the terminal: [`ascii`](Self::ascii)
+    /// glyphs without Unicode, palette colors without truecolor.
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        let style = if caps.unicode {
+            Self::default()
+        } else {
+            Self::ascii()
+        };
+        Self {
+            background: caps.color(style.background),
+            border: caps.color(style.border),
+            normal_fg: caps.color(style.normal_fg),
+            highlight_bg: caps.color(style.highlight_bg),
+            highlight_fg: caps.color(style.highlight_fg),
+            disabled_fg: caps.color(style.disabled_fg),
+            shortcut_fg: caps.color(style.shortcut_fg),
+            separator_fg: caps.color(style.separator_fg),
+            group_header_style: caps.style(style.group_header_style),
+            ..style
+        }
+    }
+
     /// Set minimum width.
     pub fn min_width(mut self, width: u16) -> Self {
         self.min_width = width;
@@ -480,35 +794,76 @@ impl ContextMenuStyle {
         self
     }
 
+    /// Set the number of rows each item occupies (minimum 1).
+    pub fn item_height(mut self, rows: u16) -> Self {
+        self.item_height = rows.max(1);
+        self
+    }
+
     /// Set the submenu indicator.
     pub fn submenu_indicator(mut self, indicator: &'static str) -> Self {
         self.submenu_indicator = indicator;
         self
     }
 
+    /// Set the hover delay before a submenu auto-opens.
+    pub fn submenu_hover_delay(mut self, delay_ms: u64) -> Self {
+        self.submenu_hover_delay_ms = delay_ms;
+        self
+    }
+
     /// Set the highlight colors.
     pub fn highlight(mut self, fg: Color, bg: Color) -> Self {
         self.highlight_fg = fg;
         self.highlight_bg = bg;
         self
     }
+
+    /// Set the group header style.
+    pub fn group_header_style(mut self, style: Style) -> Self {
+        self.group_header_style = style;
+        self
+    }
+
+    /// Set the indentation of items within a group.
+    pub fn group_indent(mut self, indent: u16) -> Self {
+        self.group_indent = indent;
+        self
+    }
 }
 
 /// Context menu widget.
 ///
 /// A popup menu that appears at a specified position, typically triggered
-/// by a right-click event.
-pub struct ContextMenu<'a> {
-    items: &'a [ContextMenuItem],
+/// by a right-click event. `A` is the action its items trigger; see
+/// [`MenuAction`].
+pub struct TypedContextMenu<'a, A: Clone> {
+    items: Cow<'a, [TypedContextMenuItem<A>]>,
     state: &'a ContextMenuState,
     style: ContextMenuStyle,
 }
 
-impl<'a> ContextMenu<'a> {
+/// A context menu whose items have string IDs.
+pub type ContextMenu<'a> = TypedContextMenu<'a, String>;
+
+impl<'a, A: MenuAction> TypedContextMenu<'a, A> {
     /// Create a new context menu.
-    pub fn new(items: &'a [ContextMenuItem], state: &'a ContextMenuState) -> Self {
+    pub fn new(items: &'a [TypedContextMenuItem<A>], state: &'a ContextMenuState) -> Self {
         Self {
-            items,
+            items: Cow::Borrowed(items),
+            state,
+            style: ContextMenuStyle::default(),
+        }
+    }
+
+    /// Create a context menu whose items are built by `provider` for `context`.
+    pub fn from_provider(
+        provider: &dyn ContextMenuProvider<A>,
+        context: &dyn Any,
+        state: &'a ContextMenuState,
+    ) -> Self {
+        Self {
+            items: Cow::Owned(provider.build_items(context)),
             state,
             style: ContextMenuStyle::default(),
         }
@@ -525,33 +880,51 @@ impl<'a> ContextMenu<'a> {
         self.style(ContextMenuStyle::from(theme))
     }
 
+    /// Display width of the icon column, 0 when no item has an icon.
+    ///
+    /// Every action and submenu row reserves this width plus a gap, so
+    /// labels line up whether or not their item has an icon.
+    fn icon_column_width(&self) -> usize {
+        menu_rows(&self.items)
+            .into_iter()
+            .filter_map(|(item, _)| item.get_icon())
+            .map(display_width)
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Calculate the required width for the menu.
     fn calculate_width(&self) -> u16 {
         let mut max_label_width = 0u16;
         let mut max_shortcut_width = 0u16;
+        let icon_width = match self.icon_column_width() {
+            0 => 0,
+            column => column + 1,
+        };
 
-        for item in self.items {
+        for (item, depth) in menu_rows(&self.items) {
+            let indent = (depth * self.style.group_indent) as usize;
             match item {
-                ContextMenuItem::Action {
-                    label,
-                    icon,
-                    shortcut,
-                    ..
+                TypedContextMenuItem::Action {
+                    label, shortcut, ..
                 } => {
-                    let icon_width = icon.as_ref().map(|i| i.chars().count() + 1).unwrap_or(0);
-                    let label_width = label.chars().count() + icon_width;
+                    let label_width = display_width(label) + icon_width + indent;
                     max_label_width = max_label_width.max(label_width as u16);
                     if let Some(s) = shortcut {
-                        max_shortcut_width = max_shortcut_width.max(s.chars().count() as u16);
+                        max_shortcut_width = max_shortcut_width.max(display_width(s) as u16);
                     }
                 }
-                ContextMenuItem::Submenu { label, icon, .. } => {
-                    let icon_width = icon.as_ref().map(|i| i.chars().count() + 1).unwrap_or(0);
-                    // +2 for submenu indicator
-                    let label_width = label.chars().count() + icon_width + 2;
+                TypedContextMenuItem::Submenu { label, .. } => {
+                    // Gap plus the submenu indicator
+                    let indicator_width = 1 + display_width(self.style.submenu_indicator);
+                    let label_width = display_width(label) + icon_width + indicator_width + indent;
                     max_label_width = max_label_width.max(label_width as u16);
                 }
-                ContextMenuItem::Separator => {}
+                TypedContextMenuItem::Group { label, .. } => {
+                    let label_width = display_width(label) + indent;
+                    max_label_width = max_label_width.max(label_width as u16);
+                }
+                TypedContextMenuItem::Separator => {}
             }
         }
 
@@ -573,9 +946,7 @@ impl<'a> ContextMenu<'a> {
 
     /// Calculate the required height for the menu.
     fn calculate_height(&self) -> u16 {
-        let item_count = self.items.len() as u16;
-        let visible = item_count.min(self.style.max_visible_items);
-        visible + 2 // +2 for borders
+        calculate_menu_height_for_items(&self.items, &self.style)
     }
 
     /// Calculate the menu area based on anchor and screen bounds.
@@ -584,17 +955,18 @@ impl<'a> ContextMenu<'a> {
         let width = self.calculate_width();
         let height = self.calculate_height();
 
-        // Prefer right-down positioning, flip if needed
+        // Prefer right-down positioning, flip if needed, but never past the
+        // top-left of the screen (which may be a reserved safe area)
         let x = if anchor_x + width <= screen.x + screen.width {
             anchor_x
         } else {
-            anchor_x.saturating_sub(width)
+            anchor_x.saturating_sub(width).max(screen.x)
         };
 
         let y = if anchor_y + height <= screen.y + screen.height {
             anchor_y
         } else {
-            anchor_y.saturating_sub(height)
+            anchor_y.saturating_sub(height).max(screen.y)
         };
 
         // Ensure we stay within screen bounds
@@ -606,12 +978,46 @@ impl<'a> ContextMenu<'a> {
 
     /// Render the context menu and return click regions for items.
     ///
-    /// Returns a tuple of (menu_area, item_click_regions).
+    /// Returns a tuple of (menu_area, item_click_regions). Each enabled action
+    /// also gets a `HighlightChange(index)` region over the same area, which
+    /// [`handle_context_menu_mouse`] uses to map hovered rows to items.
     pub fn render_stateful(
         &self,
         frame: &mut Frame,
-        screen: Rect,
-    ) -> (Rect, Vec<ClickRegion<ContextMenuAction>>) {
+        screen: impl Into<Rect>,
+    ) -> (Rect, Vec<ClickRegion<TypedContextMenuAction<A>>>) {
+        self.render_to_buffer(screen, frame.buffer_mut())
+    }
+
+    /// Render the context menu into an [`OverlayManager`] on the
+    /// [`OverlayLayer::Menu`] layer.
+    ///
+    /// Returns the same tuple as [`render_stateful`](Self::render_stateful).
+    pub fn render_into_overlays(
+        &self,
+        screen: impl Into<Rect>,
+        overlays: &mut OverlayManager<'_>,
+    ) -> (Rect, Vec<ClickRegion<TypedContextMenuAction<A>>>) {
+        let screen = screen.into();
+        if !self.state.is_open || self.items.is_empty() {
+            return (Rect::default(), Vec::new());
+        }
+        overlays.capture(OverlayLayer::Menu, screen, |buf| {
+            self.render_to_buffer(screen, buf)
+        })
+    }
+
+    /// Render the context menu using Buffer (Widget-style rendering).
+    ///
+    /// `screen` bounds the menu and submenu placement; pass a
+    /// [`SafeArea`](crate::state::SafeArea) to keep the menu off reserved
+    /// bars. Returns the same tuple as [`render_stateful`](Self::render_stateful).
+    pub fn render_to_buffer(
+        &self,
+        screen: impl Into<Rect>,
+        buf: &mut Buffer,
+    ) -> (Rect, Vec<ClickRegion<TypedContextMenuAction<A>>>) {
+        let screen = screen.into();
         let mut regions = Vec::new();
 
         if !self.state.is_open || self.items.is_empty() {
@@ -621,36 +1027,43 @@ impl<'a> ContextMenu<'a> {
         let menu_area = self.calculate_menu_area(screen);
 
         // Clear background (overlay)
-        frame.render_widget(Clear, menu_area);
+        Clear.render(menu_area, buf);
 
         // Render border
         let block = Block::default()
             .borders(Borders::ALL)
+            .border_set(self.style.border_set)
             .border_style(Style::default().fg(self.style.border))
             .style(Style::default().bg(self.style.background));
 
         let inner = block.inner(menu_area);
-        frame.render_widget(block, menu_area);
+        let icon_column = self.icon_column_width();
+        block.render(menu_area, buf);
 
         // Render items
-        let visible_count = inner.height as usize;
         let scroll = self.state.scroll_offset as usize;
+        let inner_bottom = inner.y + inner.height;
+        let mut y = inner.y;
+        let mut submenu_anchor_y = None;
 
-        for (display_idx, (item_idx, item)) in self
-            .items
-            .iter()
-            .enumerate()
-            .skip(scroll)
-            .take(visible_count)
-            .enumerate()
+        for (item_idx, (item, depth)) in menu_rows(&self.items).into_iter().enumerate().skip(scroll)
         {
-            let y = inner.y + display_idx as u16;
-            let item_area = Rect::new(inner.x, y, inner.width, 1);
+            let height = item_row_height(item, &self.style);
+            if y + height > inner_bottom {
+                break;
+            }
+            let item_area = Rect::new(inner.x, y, inner.width, height);
+            // Labels sit on the middle row of taller items
+            let label_area = Rect::new(inner.x, y + (height - 1) / 2, inner.width, 1);
+            if self.state.active_submenu == Some(item_idx) {
+                submenu_anchor_y = Some(y);
+            }
+            y += height;
 
             let is_highlighted = item_idx == self.state.highlighted_index;
 
             match item {
-                ContextMenuItem::Separator => {
+                TypedContextMenuItem::Separator => {
                     // Render separator line
                     let sep_line: String =
                         std::iter::repeat_n(self.style.separator_char, inner.width as usize)
@@ -659,14 +1072,15 @@ impl<'a> ContextMenu<'a> {
                         sep_line,
                         Style::default().fg(self.style.separator_fg),
                     ));
-                    frame.render_widget(para, item_area);
+                    para.render(item_area, buf);
                 }
-                ContextMenuItem::Action {
+                TypedContextMenuItem::Action {
                     label,
                     icon,
                     shortcut,
                     enabled,
                     id,
+                    ..
                 } => {
                     let (fg, bg) = if !enabled {
                         (self.style.disabled_fg, self.style.background)
@@ -677,6 +1091,7 @@ impl<'a> ContextMenu<'a> {
                     };
 
                     let style = Style::default().fg(fg).bg(bg);
+                    buf.set_style(item_area, Style::default().bg(bg));
                     let shortcut_style = Style::default()
                         .fg(if *enabled {
                             self.style.shortcut_fg
@@ -685,51 +1100,56 @@ impl<'a> ContextMenu<'a> {
                         })
                         .bg(bg);
 
+                    let padding = spaces(self.style.padding as usize);
+                    let indent =
+                        spaces((self.style.padding + depth * self.style.group_indent) as usize);
                     let mut spans = Vec::new();
 
-                    // Padding
-                    spans.push(Span::styled(" ".repeat(self.style.padding as usize), style));
+                    // Padding, plus the group indent
+                    spans.push(Span::styled(indent, style));
 
-                    // Icon
-                    if let Some(ic) = icon {
-                        spans.push(Span::styled(format!("{} ", ic), style));
-                    }
+                    // Icon column
+                    push_icon(&mut spans, icon.as_deref(), icon_column, style);
 
                     // Label
-                    spans.push(Span::styled(label.clone(), style));
+                    spans.push(Span::styled(label.as_str(), style));
 
                     // Fill space before shortcut
-                    let current_len: usize = spans.iter().map(|s| s.content.chars().count()).sum();
-                    let shortcut_len = shortcut.as_ref().map(|s| s.chars().count()).unwrap_or(0);
+                    let current_len: usize = spans.iter().map(Span::width).sum();
+                    let shortcut_len = shortcut.as_deref().map_or(0, display_width);
                     let fill_len = (inner.width as usize)
                         .saturating_sub(current_len)
                         .saturating_sub(shortcut_len)
                         .saturating_sub(self.style.padding as usize);
 
                     if fill_len > 0 {
-                        spans.push(Span::styled(" ".repeat(fill_len), style));
+                        spans.push(Span::styled(spaces(fill_len), style));
                     }
 
                     // Shortcut
                     if let Some(sc) = shortcut {
-                        spans.push(Span::styled(sc.clone(), shortcut_style));
+                        spans.push(Span::styled(sc.as_str(), shortcut_style));
                     }
 
                     // Right padding
-                    spans.push(Span::styled(" ".repeat(self.style.padding as usize), style));
+                    spans.push(Span::styled(padding, style));
 
                     let para = Paragraph::new(Line::from(spans));
-                    frame.render_widget(para, item_area);
+                    para.render(label_area, buf);
 
-                    // Register click region
+                    // Register click region, plus the item index for hover tracking
                     if *enabled {
                         regions.push(ClickRegion::new(
                             item_area,
-                            ContextMenuAction::Select(id.clone()),
+                            TypedContextMenuAction::Select(id.clone()),
+                        ));
+                        regions.push(ClickRegion::new(
+                            item_area,
+                            TypedContextMenuAction::HighlightChange(item_idx),
                         ));
                     }
                 }
-                ContextMenuItem::Submenu {
+                TypedContextMenuItem::Submenu {
                     label,
                     icon,
                     enabled,
@@ -744,48 +1164,64 @@ impl<'a> ContextMenu<'a> {
                     };
 
                     let style = Style::default().fg(fg).bg(bg);
+                    buf.set_style(item_area, Style::default().bg(bg));
 
+                    let padding = spaces(self.style.padding as usize);
+                    let indent =
+                        spaces((self.style.padding + depth * self.style.group_indent) as usize);
                     let mut spans = Vec::new();
 
-                    // Padding
-                    spans.push(Span::styled(" ".repeat(self.style.padding as usize), style));
+                    // Padding, plus the group indent
+                    spans.push(Span::styled(indent, style));
 
-                    // Icon
-                    if let Some(ic) = icon {
-                        spans.push(Span::styled(format!("{} ", ic), style));
-                    }
+                    // Icon column
+                    push_icon(&mut spans, icon.as_deref(), icon_column, style);
 
                     // Label
-                    spans.push(Span::styled(label.clone(), style));
+                    spans.push(Span::styled(label.as_str(), style));
 
                     // Fill and submenu indicator
-                    let current_len: usize = spans.iter().map(|s| s.content.chars().count()).sum();
-                    let indicator_len = self.style.submenu_indicator.chars().count();
+                    let current_len: usize = spans.iter().map(Span::width).sum();
+                    let indicator_len = display_width(self.style.submenu_indicator);
                     let fill_len = (inner.width as usize)
                         .saturating_sub(current_len)
                         .saturating_sub(indicator_len)
                         .saturating_sub(self.style.padding as usize);
 
                     if fill_len > 0 {
-                        spans.push(Span::styled(" ".repeat(fill_len), style));
+                        spans.push(Span::styled(spaces(fill_len), style));
                     }
 
                     spans.push(Span::styled(self.style.submenu_indicator, style));
 
                     // Right padding
-                    spans.push(Span::styled(" ".repeat(self.style.padding as usize), style));
+                    spans.push(Span::styled(padding, style));
 
                     let para = Paragraph::new(Line::from(spans));
-                    frame.render_widget(para, item_area);
+                    para.render(label_area, buf);
 
                     // Register click region for submenu
                     if *enabled {
                         regions.push(ClickRegion::new(
                             item_area,
-                            ContextMenuAction::SubmenuOpen(item_idx),
+                            TypedContextMenuAction::SubmenuOpen(item_idx),
                         ));
                     }
                 }
+                TypedContextMenuItem::Group { label, .. } => {
+                    // Header only; the group's items follow as their own rows
+                    let style = Style::default()
+                        .fg(self.style.normal_fg)
+                        .bg(self.style.background)
+                        .patch(self.style.group_header_style);
+                    let indent =
+                        spaces((self.style.padding + depth * self.style.group_indent) as usize);
+                    let para = Paragraph::new(Line::from(vec![
+                        Span::styled(indent, style),
+                        Span::styled(label.as_str(), style),
+                    ]));
+                    para.render(label_area, buf);
+                }
             }
         }
 
@@ -793,19 +1229,20 @@ impl<'a> ContextMenu<'a> {
         if let (Some(submenu_idx), Some(submenu_state)) =
             (self.state.active_submenu, &self.state.submenu_state)
         {
-            if let Some(ContextMenuItem::Submenu { items, .. }) = self.items.get(submenu_idx) {
+            if let Some(TypedContextMenuItem::Submenu { items, .. }) =
+                menu_row(&self.items, submenu_idx)
+            {
                 // Position submenu to the right of the parent item
                 let submenu_anchor_x = menu_area.x + menu_area.width;
-                let submenu_anchor_y =
-                    menu_area.y + 1 + (submenu_idx as u16).saturating_sub(self.state.scroll_offset);
+                let submenu_anchor_y = submenu_anchor_y.unwrap_or(inner.y);
 
                 let mut adjusted_state = (**submenu_state).clone();
                 adjusted_state.anchor_position = (submenu_anchor_x, submenu_anchor_y);
 
                 let adjusted_submenu =
-                    ContextMenu::new(items, &adjusted_state).style(self.style.clone());
+                    TypedContextMenu::new(items, &adjusted_state).style(self.style.clone());
 
-                let (_, submenu_regions) = adjusted_submenu.render_stateful(frame, screen);
+                let (_, submenu_regions) = adjusted_submenu.render_to_buffer(screen, buf);
                 regions.extend(submenu_regions);
             }
         }
@@ -820,7 +1257,8 @@ impl<'a> ContextMenu<'a> {
 ///
 /// # Key Bindings
 ///
-/// - `Esc` - Close menu
+/// - `Esc` - Close the open submenu, or the menu (left unhandled while closed,
+///   so it can propagate; see [`CloseRequest`])
 /// - `Up` - Move highlight up
 /// - `Down` - Move highlight down
 /// - `Enter`, `Space` - Select highlighted item
@@ -828,11 +1266,11 @@ impl<'a> ContextMenu<'a> {
 /// - `Left` - Close submenu (if one is open)
 /// - `Home` - Move to first item
 /// - `End` - Move to last item
-pub fn handle_context_menu_key(
+pub fn handle_context_menu_key<A: MenuAction>(
     key: &KeyEvent,
     state: &mut ContextMenuState,
-    items: &[ContextMenuItem],
-) -> Option<ContextMenuAction> {
+    items: &[TypedContextMenuItem<A>],
+) -> Option<TypedContextMenuAction<A>> {
     if !state.is_open {
         return None;
     }
@@ -841,14 +1279,14 @@ pub fn handle_context_menu_key(
     if let (Some(submenu_idx), Some(submenu_state)) =
         (state.active_submenu, &mut state.submenu_state)
     {
-        if let Some(ContextMenuItem::Submenu {
+        if let Some(TypedContextMenuItem::Submenu {
             items: sub_items, ..
-        }) = items.get(submenu_idx)
+        }) = menu_row(items, submenu_idx)
         {
             match key.code {
                 KeyCode::Left | KeyCode::Esc => {
                     state.close_submenu();
-                    return Some(ContextMenuAction::SubmenuClose);
+                    return Some(TypedContextMenuAction::SubmenuClose);
                 }
                 _ => {
                     if let Some(action) =
@@ -865,38 +1303,46 @@ pub fn handle_context_menu_key(
     match key.code {
         KeyCode::Esc => {
             state.close();
-            Some(ContextMenuAction::Close)
+            Some(TypedContextMenuAction::Close)
         }
         KeyCode::Up => {
             state.highlight_prev(items);
             state.ensure_visible(8);
-            Some(ContextMenuAction::HighlightChange(state.highlighted_index))
+            Some(TypedContextMenuAction::HighlightChange(
+                state.highlighted_index,
+            ))
         }
         KeyCode::Down => {
             state.highlight_next(items);
             state.ensure_visible(8);
-            Some(ContextMenuAction::HighlightChange(state.highlighted_index))
+            Some(TypedContextMenuAction::HighlightChange(
+                state.highlighted_index,
+            ))
         }
         KeyCode::Home => {
             state.highlight_first(items);
-            Some(ContextMenuAction::HighlightChange(state.highlighted_index))
+            Some(TypedContextMenuAction::HighlightChange(
+                state.highlighted_index,
+            ))
         }
         KeyCode::End => {
             state.highlight_last(items);
-            state.ensure_visible(items.len());
-            Some(ContextMenuAction::HighlightChange(state.highlighted_index))
+            state.ensure_visible(menu_rows(items).len());
+            Some(TypedContextMenuAction::HighlightChange(
+                state.highlighted_index,
+            ))
         }
         KeyCode::Enter | KeyCode::Char(' ') => {
-            if let Some(item) = items.get(state.highlighted_index) {
+            if let Some(item) = menu_row(items, state.highlighted_index) {
                 match item {
-                    ContextMenuItem::Action { id, enabled, .. } if *enabled => {
+                    TypedContextMenuItem::Action { id, enabled, .. } if *enabled => {
                         let action_id = id.clone();
                         state.close();
-                        Some(ContextMenuAction::Select(action_id))
+                        Some(TypedContextMenuAction::Select(action_id))
                     }
-                    ContextMenuItem::Submenu { enabled, .. } if *enabled => {
+                    TypedContextMenuItem::Submenu { enabled, .. } if *enabled => {
                         state.open_submenu();
-                        Some(ContextMenuAction::SubmenuOpen(state.highlighted_index))
+                        Some(TypedContextMenuAction::SubmenuOpen(state.highlighted_index))
                     }
                     _ => None,
                 }
@@ -905,10 +1351,10 @@ pub fn handle_context_menu_key(
             }
         }
         KeyCode::Right => {
-            if let Some(item) = items.get(state.highlighted_index) {
+            if let Some(item) = menu_row(items, state.highlighted_index) {
                 if item.has_submenu() && item.is_enabled() {
                     state.open_submenu();
-                    return Some(ContextMenuAction::SubmenuOpen(state.highlighted_index));
+                    return Some(TypedContextMenuAction::SubmenuOpen(state.highlighted_index));
                 }
             }
             None
@@ -921,6 +1367,20 @@ pub fn handle_context_menu_key(
     }
 }
 
+/// Handle keyboard events for a context menu built by a provider.
+///
+/// Builds the items from the context stored by
+/// [`ContextMenuState::open_with_context`] and handles the key as
+/// [`handle_context_menu_key`] does.
+pub fn handle_context_menu_key_with_provider<A: MenuAction>(
+    key: &KeyEvent,
+    state: &mut ContextMenuState,
+    provider: &dyn ContextMenuProvider<A>,
+) -> Option<TypedContextMenuAction<A>> {
+    let items = state.build_items(provider);
+    handle_context_menu_key(key, state, &items)
+}
+
 /// Handle mouse events for context menu.
 ///
 /// Returns `Some(ContextMenuAction)` if an action was triggered, `None` otherwise.
@@ -931,12 +1391,12 @@ pub fn handle_context_menu_key(
 /// * `state` - Mutable reference to context menu state
 /// * `menu_area` - The rendered menu area
 /// * `item_regions` - Click regions from `render_stateful`
-pub fn handle_context_menu_mouse(
+pub fn handle_context_menu_mouse<A: MenuAction>(
     mouse: &MouseEvent,
     state: &mut ContextMenuState,
     menu_area: Rect,
-    item_regions: &[ClickRegion<ContextMenuAction>],
-) -> Option<ContextMenuAction> {
+    item_regions: &[ClickRegion<TypedContextMenuAction<A>>],
+) -> Option<TypedContextMenuAction<A>> {
     if !state.is_open {
         return None;
     }
@@ -950,15 +1410,16 @@ pub fn handle_context_menu_mouse(
             for region in item_regions {
                 if region.contains(col, row) {
                     match &region.data {
-                        ContextMenuAction::Select(id) => {
+                        TypedContextMenuAction::Select(id) => {
                             let action_id = id.clone();
                             state.close();
-                            return Some(ContextMenuAction::Select(action_id));
+                            return Some(TypedContextMenuAction::Select(action_id));
                         }
-                        ContextMenuAction::SubmenuOpen(idx) => {
+                        TypedContextMenuAction::SubmenuOpen(idx) => {
                             state.highlighted_index = *idx;
+                            state.hover_start = None;
                             state.open_submenu();
-                            return Some(ContextMenuAction::SubmenuOpen(*idx));
+                            return Some(TypedContextMenuAction::SubmenuOpen(*idx));
                         }
                         _ => {}
                     }
@@ -968,7 +1429,7 @@ pub fn handle_context_menu_mouse(
             // Check if clicked outside menu
             if !menu_area.intersects(Rect::new(col, row, 1, 1)) {
                 state.close();
-                return Some(ContextMenuAction::Close);
+                return Some(TypedContextMenuAction::Close);
             }
             None
         }
@@ -977,20 +1438,44 @@ pub fn handle_context_menu_mouse(
             for region in item_regions.iter() {
                 if region.contains(col, row) {
                     // Find the actual item index from the region
-                    if let ContextMenuAction::Select(_) | ContextMenuAction::SubmenuOpen(_) =
-                        &region.data
+                    if let TypedContextMenuAction::Select(_)
+                    | TypedContextMenuAction::SubmenuOpen(_) = &region.data
                     {
-                        // The item_regions index may not match the items index due to separators
-                        // We need to find the corresponding item
-                        let inner_start_y = menu_area.y + 1; // +1 for border
-                        let item_idx =
-                            (row - inner_start_y) as usize + state.scroll_offset as usize;
+                        // Prefer the index recorded at render time; rows don't map
+                        // to items once separators or taller items are involved
+                        let item_idx = match region.data {
+                            TypedContextMenuAction::SubmenuOpen(idx) => idx,
+                            _ => item_regions
+                                .iter()
+                                .find_map(|r| match r.data {
+                                    TypedContextMenuAction::HighlightChange(idx)
+                                        if r.contains(col, row) =>
+                                    {
+                                        Some(idx)
+                                    }
+                                    _ => None,
+                                })
+                                .unwrap_or_else(|| {
+                                    let inner_start_y = menu_area.y + 1; // +1 for border
+                                    (row - inner_start_y) as usize + state.scroll_offset as usize
+                                }),
+                        };
+
+                        // Submenus open after a hover delay (see `tick_hover`)
+                        let hovering_submenu =
+                            matches!(region.data, TypedContextMenuAction::SubmenuOpen(_))
+                                && state.active_submenu != Some(item_idx);
+                        if !hovering_submenu {
+                            state.hover_start = None;
+                        } else if state.hover_start.is_none_or(|(idx, _)| idx != item_idx) {
+                            state.hover_start = Some((item_idx, Instant::now()));
+                        }
 
                         if item_idx < item_regions.len() + state.scroll_offset as usize
                             && state.highlighted_index != item_idx
                         {
                             state.highlighted_index = item_idx;
-                            return Some(ContextMenuAction::HighlightChange(item_idx));
+                            return Some(TypedContextMenuAction::HighlightChange(item_idx));
                         }
                     }
                     break;
@@ -1008,14 +1493,49 @@ pub fn is_context_menu_trigger(mouse: &MouseEvent) -> bool {
 }
 
 /// Calculate the height needed for a context menu.
+///
+/// Assumes one row per item, counting each group header as an item; see
+/// [`calculate_menu_height_for_items`] when [`ContextMenuStyle::item_height`]
+/// is above 1 or the menu has groups.
 pub fn calculate_menu_height(item_count: usize, max_visible: u16) -> u16 {
     let visible = (item_count as u16).min(max_visible);
     visible + 2 // +2 for borders
 }
 
+/// Calculate the height needed for a context menu, honoring
+/// [`ContextMenuStyle::item_height`].
+///
+/// Separators and group headers take one row and every other item
+/// `item_height` rows. A menu longer than `max_visible_items` rows is sized
+/// for that many full-height items.
+pub fn calculate_menu_height_for_items<A>(
+    items: &[TypedContextMenuItem<A>],
+    style: &ContextMenuStyle,
+) -> u16 {
+    let menu_rows = menu_rows(items);
+    let rows = if menu_rows.len() <= style.max_visible_items as usize {
+        menu_rows
+            .into_iter()
+            .map(|(item, _)| item_row_height(item, style))
+            .sum()
+    } else {
+        style.max_visible_items * style.item_height.max(1)
+    };
+    rows + 2 // +2 for borders
+}
+
+/// Rows occupied by a single item.
+fn item_row_height<A>(item: &TypedContextMenuItem<A>, style: &ContextMenuStyle) -> u16 {
+    match item {
+        TypedContextMenuItem::Separator | TypedContextMenuItem::Group { .. } => 1,
+        _ => style.item_height.max(1),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::SafeArea;
 
     #[test]
     fn test_context_menu_item_action() {
@@ -1211,6 +1731,58 @@ mod tests {
         assert!(!state.is_open);
     }
 
+    #[test]
+    fn test_typed_actions() {
+        use crossterm::event::KeyModifiers;
+
+        #[derive(Debug, Clone, PartialEq)]
+        enum EditAction {
+            Cut,
+            Paste,
+        }
+
+        impl MenuAction for EditAction {
+            fn id(&self) -> &str {
+                match self {
+                    EditAction::Cut => "cut",
+                    EditAction::Paste => "paste",
+                }
+            }
+        }
+
+        let items = vec![
+            TypedContextMenuItem::from_action(EditAction::Cut, "Cut"),
+            TypedContextMenuItem::submenu(
+                "More",
+                vec![TypedContextMenuItem::from_action(
+                    EditAction::Paste,
+                    "Paste",
+                )],
+            ),
+        ];
+        assert_eq!(items[0].get_action(), Some(&EditAction::Cut));
+        assert_eq!(items[0].id(), Some("cut"));
+
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+        let screen = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(screen);
+        let (menu_area, regions) =
+            TypedContextMenu::new(&items, &state).render_to_buffer(screen, &mut buf);
+
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: regions[0].area.x,
+            row: regions[0].area.y,
+            modifiers: KeyModifiers::NONE,
+        };
+        let action = handle_context_menu_mouse(&click, &mut state, menu_area, &regions);
+        assert_eq!(
+            action,
+            Some(TypedContextMenuAction::Select(EditAction::Cut))
+        );
+    }
+
     #[test]
     fn test_is_context_menu_trigger() {
         use crossterm::event::KeyModifiers;
@@ -1558,7 +2130,8 @@ mod tests {
             modifiers: KeyModifiers::NONE,
         };
 
-        let action = handle_context_menu_mouse(&mouse, &mut state, Rect::default(), &[]);
+        let action: Option<ContextMenuAction> =
+            handle_context_menu_mouse(&mouse, &mut state, Rect::default(), &[]);
 
         assert!(action.is_none());
     }
@@ -1696,7 +2269,6 @@ mod tests {
         let state = ContextMenuState::new();
         let _menu = ContextMenu::new(&items, &state);
 
-        // Verify menu is created (we can't easily test rendering without Frame)
         assert!(!state.is_open);
     }
 
@@ -1773,4 +2345,494 @@ mod tests {
         assert!(action.is_none());
         assert!(!state.has_open_submenu());
     }
+
+    #[test]
+    fn test_submenu_hover_delay() {
+        use crossterm::event::KeyModifiers;
+
+        let mut state = ContextMenuState::new();
+        state.open_at(10, 10);
+
+        let menu_area = Rect::new(10, 10, 20, 4);
+        let regions = vec![
+            ClickRegion::new(
+                Rect::new(11, 11, 18, 1),
+                ContextMenuAction::Select("a".into()),
+            ),
+            ClickRegion::new(Rect::new(11, 12, 18, 1), ContextMenuAction::SubmenuOpen(1)),
+        ];
+
+        let hover = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 12,
+            row: 12,
+            modifiers: KeyModifiers::NONE,
+        };
+        let action = handle_context_menu_mouse(&hover, &mut state, menu_area, &regions);
+        assert_eq!(action, Some(ContextMenuAction::HighlightChange(1)));
+        assert!(!state.has_open_submenu());
+
+        let (idx, start) = state.hover_start.unwrap();
+        assert_eq!(idx, 1);
+
+        // Not yet expired
+        assert!(
+            state
+                .tick_hover(start + Duration::from_millis(100), 300)
+                .is_none()
+        );
+        assert!(!state.has_open_submenu());
+
+        // Expired
+        assert_eq!(
+            state.tick_hover(start + Duration::from_millis(300), 300),
+            Some(ContextMenuAction::SubmenuOpen(1))
+        );
+        assert!(state.has_open_submenu());
+        assert!(state.hover_start.is_none());
+    }
+
+    #[test]
+    fn test_submenu_hover_cancelled_by_moving_away() {
+        use crossterm::event::KeyModifiers;
+
+        let mut state = ContextMenuState::new();
+        state.open_at(10, 10);
+
+        let menu_area = Rect::new(10, 10, 20, 4);
+        let regions = vec![
+            ClickRegion::new(
+                Rect::new(11, 11, 18, 1),
+                ContextMenuAction::Select("a".into()),
+            ),
+            ClickRegion::new(Rect::new(11, 12, 18, 1), ContextMenuAction::SubmenuOpen(1)),
+        ];
+
+        let mut hover = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 12,
+            row: 12,
+            modifiers: KeyModifiers::NONE,
+        };
+        handle_context_menu_mouse(&hover, &mut state, menu_area, &regions);
+        assert!(state.hover_start.is_some());
+
+        hover.row = 11;
+        handle_context_menu_mouse(&hover, &mut state, menu_area, &regions);
+        assert!(state.hover_start.is_none());
+        assert!(
+            state
+                .tick_hover(Instant::now() + Duration::from_secs(1), 300)
+                .is_none()
+        );
+        assert!(!state.has_open_submenu());
+
+        // Clicking a submenu item still opens it immediately
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 12,
+            row: 12,
+            modifiers: KeyModifiers::NONE,
+        };
+        let action = handle_context_menu_mouse(&click, &mut state, menu_area, &regions);
+        assert_eq!(action, Some(ContextMenuAction::SubmenuOpen(1)));
+        assert!(state.has_open_submenu());
+    }
+
+    #[test]
+    fn test_submenu_hover_delay_style() {
+        assert_eq!(ContextMenuStyle::default().submenu_hover_delay_ms, 300);
+        let style = ContextMenuStyle::default().submenu_hover_delay(150);
+        assert_eq!(style.submenu_hover_delay_ms, 150);
+
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+        state.highlighted_index = 1;
+        let start = Instant::now();
+        state.hover_start = Some((1, start));
+        let at = start + Duration::from_millis(200);
+        assert!(state.tick_hover(at, 300).is_none());
+        assert_eq!(
+            state.tick_hover(at, style.submenu_hover_delay_ms),
+            Some(ContextMenuAction::SubmenuOpen(1))
+        );
+    }
+
+    #[test]
+    fn test_render_to_buffer() {
+        let items = vec![
+            ContextMenuItem::action("copy", "Copy").shortcut("^C"),
+            ContextMenuItem::separator(),
+            ContextMenuItem::action("paste", "Paste"),
+        ];
+        let mut state = ContextMenuState::new();
+        state.open_at(2, 1);
+
+        let screen = Rect::new(0, 0, 20, 7);
+        let mut buf = Buffer::empty(screen);
+        for y in 0..7 {
+            buf.set_string(0, y, "x".repeat(20), Style::default());
+        }
+        let (menu_area, regions) =
+            ContextMenu::new(&items, &state).render_to_buffer(screen, &mut buf);
+
+        let selects = regions
+            .iter()
+            .filter(|r| matches!(r.data, ContextMenuAction::Select(_)))
+            .count();
+        assert_eq!(selects, 2);
+        let row = |y: u16| (0..20).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(menu_area, Rect::new(2, 1, 15, 5));
+        assert_eq!(row(2), "xx│ Copy     ^C │xxx");
+        assert_eq!(row(3), "xx│─────────────│xxx");
+        assert_eq!(row(4), "xx│ Paste       │xxx");
+        assert_eq!(row(6), "xxxxxxxxxxxxxxxxxxxx");
+    }
+
+    #[test]
+    fn test_flips_above_reserved_bottom_bar() {
+        let items = vec![
+            ContextMenuItem::action("copy", "Copy"),
+            ContextMenuItem::separator(),
+            ContextMenuItem::action("paste", "Paste"),
+        ];
+        let mut state = ContextMenuState::new();
+        state.open_at(2, 5);
+
+        let screen = Rect::new(0, 0, 20, 10);
+        let menu = ContextMenu::new(&items, &state);
+
+        // Against the full frame the menu fits below the anchor...
+        let (full_area, _) = menu.render_to_buffer(screen, &mut Buffer::empty(screen));
+        assert_eq!(full_area, Rect::new(2, 5, 15, 5));
+
+        // ...but that covers the status bar, so with it reserved the menu
+        // flips up above the anchor
+        let safe = SafeArea::new(screen).reserve_bottom(1);
+        let mut buf = Buffer::empty(screen);
+        buf.set_string(0, 9, "status", Style::default());
+        let (menu_area, _) = menu.render_to_buffer(safe, &mut buf);
+
+        assert_eq!(menu_area, Rect::new(2, 0, 15, 5));
+        assert!(menu_area.bottom() <= safe.area().bottom());
+        let bar: String = (0..6).map(|x| buf[(x, 9)].symbol()).collect();
+        assert_eq!(bar, "status");
+    }
+
+    #[test]
+    fn test_flip_stays_below_reserved_top_bar() {
+        let items = vec![
+            ContextMenuItem::action("copy", "Copy"),
+            ContextMenuItem::action("paste", "Paste"),
+            ContextMenuItem::action("cut", "Cut"),
+        ];
+        let mut state = ContextMenuState::new();
+        state.open_at(2, 5);
+
+        let screen = Rect::new(0, 0, 20, 8);
+        let safe = SafeArea::new(screen).reserve_top(2).reserve_bottom(1);
+        let (menu_area, _) =
+            ContextMenu::new(&items, &state).render_to_buffer(safe, &mut Buffer::empty(screen));
+
+        assert_eq!(menu_area.y, 2);
+        assert!(menu_area.bottom() <= safe.area().bottom());
+    }
+
+    #[test]
+    fn test_item_height_style() {
+        assert_eq!(ContextMenuStyle::default().item_height, 1);
+        assert_eq!(ContextMenuStyle::default().item_height(3).item_height, 3);
+        assert_eq!(ContextMenuStyle::default().item_height(0).item_height, 1);
+
+        let items = vec![
+            ContextMenuItem::action("a", "A"),
+            ContextMenuItem::separator(),
+            ContextMenuItem::action("b", "B"),
+        ];
+        let style = ContextMenuStyle::default().item_height(2);
+        assert_eq!(calculate_menu_height_for_items(&items, &style), 7);
+        assert_eq!(
+            calculate_menu_height_for_items(&items, &style.max_visible_items(2)),
+            6
+        );
+    }
+
+    #[test]
+    fn test_render_with_item_height() {
+        use crossterm::event::KeyModifiers;
+
+        let items = vec![
+            ContextMenuItem::action("copy", "Copy"),
+            ContextMenuItem::separator(),
+            ContextMenuItem::action("paste", "Paste"),
+        ];
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+
+        let screen = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(screen);
+        let style = ContextMenuStyle::default().item_height(3);
+        let (menu_area, regions) = ContextMenu::new(&items, &state)
+            .style(style.clone())
+            .render_to_buffer(screen, &mut buf);
+
+        assert_eq!(menu_area.height, 9);
+        let row = |y: u16| (0..15).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(1), "│             │");
+        assert_eq!(row(2), "│ Copy        │");
+        assert_eq!(row(4), "│─────────────│");
+        assert_eq!(row(6), "│ Paste       │");
+        // The highlight fills every row of the item
+        assert_eq!(buf[(5, 1)].bg, style.highlight_bg);
+        assert_eq!(buf[(5, 3)].bg, style.highlight_bg);
+
+        let paste = regions
+            .iter()
+            .find(|r| r.data == ContextMenuAction::Select("paste".into()))
+            .unwrap();
+        assert_eq!(paste.area, Rect::new(1, 5, 13, 3));
+
+        // Hovering the bottom row of "Paste" highlights item 2, not row 6
+        let hover = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 3,
+            row: 7,
+            modifiers: KeyModifiers::NONE,
+        };
+        let action = handle_context_menu_mouse(&hover, &mut state, menu_area, &regions);
+        assert_eq!(action, Some(ContextMenuAction::HighlightChange(2)));
+        assert_eq!(state.highlighted_index, 2);
+
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            ..hover
+        };
+        let action = handle_context_menu_mouse(&click, &mut state, menu_area, &regions);
+        assert_eq!(action, Some(ContextMenuAction::Select("paste".into())));
+    }
+
+    #[test]
+    fn test_current_hint() {
+        let items = vec![
+            ContextMenuItem::action("copy", "Copy").hint("Copy the selection"),
+            ContextMenuItem::separator(),
+            ContextMenuItem::submenu(
+                "Share",
+                vec![ContextMenuItem::action("link", "Copy Link").hint("Copy a share link")],
+            ),
+        ];
+        let mut state = ContextMenuState::new();
+        assert_eq!(state.current_hint(&items), None);
+
+        state.open_at(0, 0);
+        assert_eq!(state.current_hint(&items), Some("Copy the selection"));
+
+        state.highlighted_index = 1;
+        assert_eq!(state.current_hint(&items), None);
+
+        state.highlighted_index = 2;
+        assert_eq!(state.current_hint(&items), None);
+        state.open_submenu();
+        assert_eq!(state.current_hint(&items), Some("Copy a share link"));
+
+        state.close();
+        assert_eq!(state.current_hint(&items), None);
+    }
+
+    enum Target {
+        File,
+        Folder,
+    }
+
+    struct ExplorerMenu;
+
+    impl ContextMenuProvider for ExplorerMenu {
+        fn build_items(&self, context: &dyn Any) -> Vec<ContextMenuItem> {
+            match context.downcast_ref::<Target>() {
+                Some(Target::File) => vec![ContextMenuItem::action("open", "Open")],
+                Some(Target::Folder) => vec![
+                    ContextMenuItem::action("expand", "Expand"),
+                    ContextMenuItem::action("new_file", "New File"),
+                ],
+                None => Vec::new(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_provider() {
+        let state = ContextMenuState::new();
+        let menu = ContextMenu::from_provider(&ExplorerMenu, &Target::Folder, &state);
+        assert_eq!(menu.items.len(), 2);
+        let menu = ContextMenu::from_provider(&ExplorerMenu, &Target::File, &state);
+        assert_eq!(menu.items[0].id(), Some("open"));
+    }
+
+    #[test]
+    fn test_key_with_provider_uses_stored_context() {
+        let mut state = ContextMenuState::new();
+        state.open_with_context(5, 5, Target::Folder);
+        assert_eq!(state.build_items(&ExplorerMenu).len(), 2);
+
+        let down = KeyEvent::from(KeyCode::Down);
+        handle_context_menu_key_with_provider(&down, &mut state, &ExplorerMenu);
+        let enter = KeyEvent::from(KeyCode::Enter);
+        let action = handle_context_menu_key_with_provider(&enter, &mut state, &ExplorerMenu);
+        assert_eq!(action, Some(ContextMenuAction::Select("new_file".into())));
+
+        // Closing drops the context
+        assert!(!state.is_open);
+        assert!(state.context.is_none());
+        assert!(state.build_items(&ExplorerMenu).is_empty());
+    }
+
+    fn grouped_items() -> Vec<ContextMenuItem> {
+        vec![
+            ContextMenuItem::group(
+                "Clipboard",
+                vec![
+                    ContextMenuItem::action("copy", "Copy"),
+                    ContextMenuItem::action("paste", "Paste"),
+                ],
+            ),
+            ContextMenuItem::group(
+                "File",
+                vec![ContextMenuItem::submenu(
+                    "Open",
+                    vec![ContextMenuItem::action("recent", "Recent")],
+                )],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_group_navigation_skips_headers() {
+        let items = grouped_items();
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+
+        state.highlight_first(&items);
+        assert_eq!(state.highlighted_index, 1);
+        state.highlight_next(&items);
+        assert_eq!(state.highlighted_index, 2);
+        state.highlight_next(&items);
+        assert_eq!(state.highlighted_index, 4, "skips the File header");
+        state.highlight_prev(&items);
+        state.highlight_prev(&items);
+        state.highlight_prev(&items);
+        assert_eq!(state.highlighted_index, 1, "never lands on a header");
+
+        // Enter on a grouped submenu opens it by row index
+        state.highlighted_index = 4;
+        let enter = KeyEvent::from(KeyCode::Enter);
+        let action = handle_context_menu_key(&enter, &mut state, &items);
+        assert_eq!(action, Some(ContextMenuAction::SubmenuOpen(4)));
+        let action = handle_context_menu_key(&enter, &mut state, &items);
+        assert_eq!(action, Some(ContextMenuAction::Select("recent".into())));
+    }
+
+    #[test]
+    fn test_group_height_counts_headers() {
+        let items = grouped_items();
+        let style = ContextMenuStyle::default().item_height(2);
+        // Two headers at one row, three items at two rows, plus borders
+        assert_eq!(calculate_menu_height_for_items(&items, &style), 2 + 6 + 2);
+    }
+
+    #[test]
+    fn test_render_groups() {
+        let items = grouped_items();
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+        state.highlight_first(&items);
+
+        let screen = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(screen);
+        let (menu_area, regions) =
+            ContextMenu::new(&items, &state).render_to_buffer(screen, &mut buf);
+        assert_eq!(menu_area.height, 7);
+
+        let row = |y: u16| {
+            (0..menu_area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(row(1), "│ Clipboard   │");
+        assert_eq!(row(2), "│   Copy      │");
+        assert_eq!(row(4), "│ File        │");
+        assert_eq!(row(5), "│   Open    ▶ │");
+        assert!(buf[(2, 1)].modifier.contains(Modifier::BOLD));
+
+        // No region covers a header row
+        assert!(regions.iter().all(|r| r.area.y != 1 && r.area.y != 4));
+        assert!(
+            regions
+                .iter()
+                .any(|r| r.data == ContextMenuAction::HighlightChange(2))
+        );
+    }
+
+    #[test]
+    fn test_ascii_render() {
+        let items = vec![
+            ContextMenuItem::action("copy", "Copy"),
+            ContextMenuItem::separator(),
+            ContextMenuItem::submenu("More", vec![ContextMenuItem::action("a", "A")]),
+        ];
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+
+        let screen = Rect::new(0, 0, 20, 7);
+        let mut buf = Buffer::empty(screen);
+        ContextMenu::new(&items, &state)
+            .style(ContextMenuStyle::from_capabilities(&Capabilities::basic()))
+            .render_to_buffer(screen, &mut buf);
+
+        assert!(buf.content().iter().all(|c| c.symbol().is_ascii()));
+        let row = |y: u16| (0..15).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(0), "+-------------+");
+        assert_eq!(row(2), "|-------------|");
+        assert_eq!(row(3), "| More      > |");
+    }
+
+    #[test]
+    fn test_render_aligns_icon_column() {
+        let items = vec![
+            ContextMenuItem::action("cut", "Cut").shortcut("Ctrl+X"),
+            ContextMenuItem::action("copy", "Copy")
+                .icon("c")
+                .shortcut("Ctrl+C"),
+            ContextMenuItem::action("paste", "Paste")
+                .icon("📋")
+                .shortcut("Ctrl+V"),
+            ContextMenuItem::submenu("More", vec![ContextMenuItem::action("a", "A")]).icon("📋"),
+        ];
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+
+        let screen = Rect::new(0, 0, 40, 8);
+        let mut buf = Buffer::empty(screen);
+        let (menu_area, _) = ContextMenu::new(&items, &state).render_to_buffer(screen, &mut buf);
+        // Border, padding, 2-cell icon column and gap, "More" with the
+        // indicator gap and "▶", shortcut gap, shortcut, padding, border
+        assert_eq!(menu_area.width, 1 + 1 + 3 + 6 + 2 + 6 + 1 + 1);
+
+        let x_of =
+            |y: u16, symbol: &str| (0..menu_area.width).find(|&x| buf[(x, y)].symbol() == symbol);
+        // Labels start in the same column with or without an icon
+        assert_eq!(x_of(1, "C"), Some(5));
+        assert_eq!(x_of(2, "C"), Some(5));
+        assert_eq!(x_of(3, "P"), Some(5));
+        assert_eq!(x_of(4, "M"), Some(5));
+        assert_eq!(buf[(2, 3)].symbol(), "📋");
+        assert_eq!(buf[(2, 2)].symbol(), "c");
+
+        // Shortcuts and the submenu indicator end at the right padding
+        let right = menu_area.width - 3;
+        for (y, key) in [(1, "X"), (2, "C"), (3, "V")] {
+            assert_eq!(buf[(right, y)].symbol(), key);
+        }
+        assert_eq!(buf[(right, 4)].symbol(), "▶");
+        assert_eq!(buf[(menu_area.width - 1, 3)].symbol(), "│");
+    }
 }
@@ -1,7 +1,7 @@
 //! Diff viewer widget
 //!
-//! A scrollable diff viewer with unified and side-by-side modes, syntax highlighting,
-//! search functionality, and hunk navigation.
+//! A scrollable diff viewer with unified, side-by-side, and word-level modes,
+//! syntax highlighting, search functionality, and hunk navigation.
 //!
 //! # Example
 //!
@@ -29,20 +29,31 @@
 //!     .title("Changes");
 //! ```
 
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols::{border, scrollbar},
     text::{Line, Span},
-    widgets::{
-        Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget,
-        Widget,
-    },
+    widgets::{Block, Borders, Paragraph, ScrollbarOrientation, Widget},
 };
 
+use super::interactive_scrollbar::{InteractiveScrollbar, ScrollbarDragState, ScrollbarPart};
+use crate::capabilities::{ASCII_BORDER, ASCII_VERTICAL_SCROLLBAR, Capabilities};
+use crate::state::ScrollMemory;
+use crate::traits::ClickRegionRegistry;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 
 use super::log_viewer::SearchState;
+use super::text_selection::{
+    SelectionRow, TextSelection, handle_text_selection_key, render_selection_overlay,
+};
+use crate::utils::display::{char_slice, display_width, spaces};
+use crate::utils::{ClipboardResult, copy_to_clipboard};
 
 // ============================================================================
 // Enums
@@ -56,6 +67,19 @@ pub enum DiffViewMode {
     /// Unified view with + and - prefixes (default)
     #[default]
     Unified,
+    /// Unified view with each deleted/added line pair merged into one row,
+    /// highlighting the words that changed
+    WordLevel,
+}
+
+/// A half of the side-by-side view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffSide {
+    /// Old file, on the left
+    #[default]
+    Left,
+    /// New file, on the right
+    Right,
 }
 
 /// Type of diff line
@@ -80,6 +104,8 @@ pub enum DiffViewerAction {
     JumpToHunk(usize),
     /// Toggle between side-by-side and unified modes
     ToggleViewMode,
+    /// Copy the diff to the clipboard as unified diff text
+    ExportToClipboard,
 }
 
 // ============================================================================
@@ -352,6 +378,105 @@ impl DiffData {
     pub fn is_empty(&self) -> bool {
         self.hunks.is_empty()
     }
+
+    /// Word-by-word diff of a deleted line and the added line replacing it
+    ///
+    /// Lines are split into words, whitespace runs, and single punctuation
+    /// characters. Returns spans for the deletion and for the addition:
+    /// unchanged words are unstyled, removed and inserted words use the
+    /// inline highlights of [`DiffViewerStyle::default`]. See
+    /// [`compute_word_diff_styled`](Self::compute_word_diff_styled) for other
+    /// styles.
+    pub fn compute_word_diff<'a>(
+        deletion: &'a DiffLine,
+        addition: &'a DiffLine,
+    ) -> (Vec<Span<'a>>, Vec<Span<'a>>) {
+        Self::compute_word_diff_styled(deletion, addition, &DiffViewerStyle::default())
+    }
+
+    /// [`compute_word_diff`](Self::compute_word_diff) with the inline
+    /// highlights of `style`
+    pub fn compute_word_diff_styled<'a>(
+        deletion: &'a DiffLine,
+        addition: &'a DiffLine,
+        style: &DiffViewerStyle,
+    ) -> (Vec<Span<'a>>, Vec<Span<'a>>) {
+        let (mut old, mut new) = (Vec::new(), Vec::new());
+        for (op, text) in word_diff(&deletion.content, &addition.content) {
+            match op {
+                WordOp::Equal => {
+                    old.push(Span::raw(text));
+                    new.push(Span::raw(text));
+                }
+                WordOp::Delete => old.push(Span::styled(text, style.inline_deletion_style)),
+                WordOp::Insert => new.push(Span::styled(text, style.inline_addition_style)),
+            }
+        }
+        (old, new)
+    }
+
+    /// Write `--- a/...` / `+++ b/...` file headers, if either path is set
+    fn write_file_header(&self, out: &mut String) {
+        if self.old_path.is_none() && self.new_path.is_none() {
+            return;
+        }
+        let path = |path: &Option<String>, prefix: &str| match path.as_deref() {
+            None | Some("/dev/null") => "/dev/null".to_string(),
+            Some(path) => format!("{}{}", prefix, path),
+        };
+        out.push_str(&format!("--- {}\n", path(&self.old_path, "a/")));
+        out.push_str(&format!("+++ {}\n", path(&self.new_path, "b/")));
+    }
+}
+
+impl DiffHunk {
+    /// Write the hunk header and lines in unified diff format
+    fn write_unified(&self, out: &mut String) {
+        // Keep the original header (and any section heading) when it
+        // still describes this hunk's ranges
+        let ranges = (
+            self.old_start,
+            self.old_count,
+            self.new_start,
+            self.new_count,
+        );
+        if parse_hunk_header(&self.header) == Some(ranges) {
+            out.push_str(&self.header);
+        } else {
+            out.push_str(&format!(
+                "@@ -{},{} +{},{} @@",
+                self.old_start, self.old_count, self.new_start, self.new_count
+            ));
+        }
+        out.push('\n');
+
+        for line in &self.lines {
+            let prefix = match line.line_type {
+                DiffLineType::Context => ' ',
+                DiffLineType::Addition => '+',
+                DiffLineType::Deletion => '-',
+                DiffLineType::HunkHeader => continue,
+            };
+            out.push(prefix);
+            out.push_str(&line.content);
+            out.push('\n');
+        }
+    }
+}
+
+/// Format one hunk of `data` as a standalone unified diff patch
+///
+/// The patch has `--- a/...` / `+++ b/...` file headers (when `data` has
+/// paths), the `@@ ... @@` hunk header, and the hunk's lines, so it can be
+/// fed to `git apply` or parsed back with [`DiffData::from_unified_diff`].
+/// Returns an empty string if `hunk_idx` is out of range.
+pub fn format_hunk_as_patch(data: &DiffData, hunk_idx: usize) -> String {
+    let mut out = String::new();
+    if let Some(hunk) = data.hunks.get(hunk_idx) {
+        data.write_file_header(&mut out);
+        hunk.write_unified(&mut out);
+    }
+    out
 }
 
 /// Parse a hunk header line like "@@ -1,3 +1,4 @@" or "@@ -1 +1 @@"
@@ -380,21 +505,230 @@ fn parse_range(range: &str) -> (usize, usize) {
     }
 }
 
+/// Step of a word-level edit script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordOp {
+    /// Text in both lines
+    Equal,
+    /// Text only in the old line
+    Delete,
+    /// Text only in the new line
+    Insert,
+}
+
+/// Largest token grid compared word by word; longer lines are shown as
+/// replaced outright
+const WORD_DIFF_MAX_CELLS: usize = 250_000;
+
+/// Byte ranges of the words, whitespace runs, and punctuation characters of `s`
+fn word_tokens(s: &str) -> Vec<(usize, usize)> {
+    fn class(c: char) -> u8 {
+        if c.is_whitespace() {
+            0
+        } else if c.is_alphanumeric() || c == '_' {
+            1
+        } else {
+            2
+        }
+    }
+
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+        // Punctuation is compared one character at a time
+        if class(c) != 2 {
+            while let Some(&(i, next)) = chars.peek()
+                && class(next) == class(c)
+            {
+                end = i + next.len_utf8();
+                chars.next();
+            }
+        }
+        tokens.push((start, end));
+    }
+    tokens
+}
+
+/// Word-level edit script turning `old` into `new`
+///
+/// Runs of the same step are merged, and deletions come before the
+/// insertions replacing them.
+fn word_diff<'a>(old: &'a str, new: &'a str) -> Vec<(WordOp, &'a str)> {
+    let a = word_tokens(old);
+    let b = word_tokens(new);
+    let old_token = |i: usize| &old[a[i].0..a[i].1];
+    let new_token = |j: usize| &new[b[j].0..b[j].1];
+
+    // Edit steps over token indices, in `old` for Equal/Delete and `new` for Insert
+    let mut steps = Vec::with_capacity(a.len() + b.len());
+    let prefix = (0..a.len().min(b.len()))
+        .take_while(|&i| old_token(i) == new_token(i))
+        .count();
+    let suffix = (0..(a.len() - prefix).min(b.len() - prefix))
+        .take_while(|&k| old_token(a.len() - 1 - k) == new_token(b.len() - 1 - k))
+        .count();
+    steps.extend((0..prefix).map(|i| (WordOp::Equal, i)));
+
+    let (a_end, b_end) = (a.len() - suffix, b.len() - suffix);
+    let (n, m) = (a_end - prefix, b_end - prefix);
+    if n.saturating_mul(m) <= WORD_DIFF_MAX_CELLS {
+        // Longest common subsequence lengths of each pair of suffixes
+        let width = m + 1;
+        let mut lcs = vec![0u32; (n + 1) * width];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i * width + j] = if old_token(prefix + i) == new_token(prefix + j) {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if old_token(prefix + i) == new_token(prefix + j) {
+                steps.push((WordOp::Equal, prefix + i));
+                (i, j) = (i + 1, j + 1);
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                steps.push((WordOp::Delete, prefix + i));
+                i += 1;
+            } else {
+                steps.push((WordOp::Insert, prefix + j));
+                j += 1;
+            }
+        }
+        steps.extend((prefix + i..a_end).map(|i| (WordOp::Delete, i)));
+        steps.extend((prefix + j..b_end).map(|j| (WordOp::Insert, j)));
+    } else {
+        steps.extend((prefix..a_end).map(|i| (WordOp::Delete, i)));
+        steps.extend((prefix..b_end).map(|j| (WordOp::Insert, j)));
+    }
+    steps.extend((a_end..a.len()).map(|i| (WordOp::Equal, i)));
+
+    // Merge adjacent tokens of the same step into one byte range
+    let mut runs: Vec<(WordOp, usize, usize)> = Vec::new();
+    for (op, index) in steps {
+        let (start, end) = match op {
+            WordOp::Insert => b[index],
+            WordOp::Equal | WordOp::Delete => a[index],
+        };
+        match runs.last_mut() {
+            Some((last_op, _, last_end)) if *last_op == op && *last_end == start => {
+                *last_end = end;
+            }
+            _ => runs.push((op, start, end)),
+        }
+    }
+    runs.into_iter()
+        .map(|(op, start, end)| {
+            let text = if op == WordOp::Insert { new } else { old };
+            (op, &text[start..end])
+        })
+        .collect()
+}
+
 // ============================================================================
 // State
 // ============================================================================
 
+/// Row offsets of a diff, so navigation doesn't rescan every line
+///
+/// Rows are numbered as the unified view draws them: each hunk's header
+/// followed by its lines.
+#[derive(Debug, Clone, Default)]
+struct DiffLineIndex {
+    /// Row of each hunk header, followed by the total row count
+    hunk_starts: Vec<usize>,
+    /// Rows of addition and deletion lines, ascending
+    change_rows: Vec<usize>,
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of line index builds on this thread
+    static LINE_INDEX_BUILDS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+impl DiffLineIndex {
+    fn build(diff: &DiffData) -> Self {
+        #[cfg(test)]
+        LINE_INDEX_BUILDS.with(|builds| builds.set(builds.get() + 1));
+
+        let mut index = Self::default();
+        let mut row = 0;
+        for hunk in &diff.hunks {
+            index.hunk_starts.push(row);
+            row += 1; // hunk header
+            for line in &hunk.lines {
+                if matches!(
+                    line.line_type,
+                    DiffLineType::Addition | DiffLineType::Deletion
+                ) {
+                    index.change_rows.push(row);
+                }
+                row += 1;
+            }
+        }
+        index.hunk_starts.push(row);
+        index
+    }
+
+    fn total_rows(&self) -> usize {
+        self.hunk_starts.last().copied().unwrap_or(0)
+    }
+}
+
+/// Scroll position, selected hunk, and view prefs of a [`DiffViewerState`],
+/// for [`ScrollMemory`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffViewerSnapshot {
+    /// Vertical scroll position
+    pub scroll_y: usize,
+    /// Horizontal scroll position
+    pub scroll_x: usize,
+    /// Horizontal scroll position of the right half in side-by-side mode
+    pub scroll_x_right: usize,
+    /// Selected hunk index
+    pub selected_hunk: Option<usize>,
+    /// View mode
+    pub view_mode: DiffViewMode,
+    /// Left half width in side-by-side mode, in percent
+    pub split_ratio: u16,
+}
+
+impl Default for DiffViewerSnapshot {
+    fn default() -> Self {
+        Self {
+            scroll_y: 0,
+            scroll_x: 0,
+            scroll_x_right: 0,
+            selected_hunk: None,
+            view_mode: DiffViewMode::default(),
+            split_ratio: DiffViewerState::DEFAULT_SPLIT_RATIO,
+        }
+    }
+}
+
 /// State for the diff viewer widget
 #[derive(Debug, Clone)]
 pub struct DiffViewerState {
-    /// The diff data to display
-    pub diff: DiffData,
+    /// The diff data to display; read it with [`diff`](Self::diff) and
+    /// change it with [`set_diff`](Self::set_diff) or
+    /// [`diff_mut`](Self::diff_mut), which keep the line index current
+    diff: DiffData,
     /// Current view mode
     pub view_mode: DiffViewMode,
     /// Vertical scroll position
     pub scroll_y: usize,
-    /// Horizontal scroll position
+    /// Horizontal scroll position; of the left half in side-by-side mode
     pub scroll_x: usize,
+    /// Horizontal scroll position of the right half in side-by-side mode
+    pub scroll_x_right: usize,
+    /// Half scrolled horizontally in side-by-side mode
+    pub focused_side: DiffSide,
+    /// Left half width in side-by-side mode, in percent
+    pub split_ratio: u16,
     /// Visible viewport height (set during render)
     pub visible_height: usize,
     /// Visible viewport width (set during render)
@@ -405,9 +739,37 @@ pub struct DiffViewerState {
     pub show_line_numbers: bool,
     /// Search state
     pub search: SearchState,
+    /// Scrollbar thumb drag state
+    pub scrollbar_drag: ScrollbarDragState,
+    /// Keyboard copy mode cursor and selection, over [`copy_lines`](Self::copy_lines)
+    pub selection: TextSelection,
+    /// Key of the diff set with [`set_diff_keyed`](Self::set_diff_keyed)
+    pub content_key: Option<String>,
+    /// Scroll positions of previously shown diffs, by key
+    pub scroll_memory: ScrollMemory<String, DiffViewerSnapshot>,
+    /// Row offsets of `diff`, built on first use and cleared when it changes
+    line_index: OnceLock<DiffLineIndex>,
+}
+
+/// Which text a display row copies, and where it is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CopyRowKind {
+    /// Hunk header spanning the full width
+    Header,
+    /// Unified-mode line content after the prefix
+    Unified,
+    /// Left (old) half in side-by-side mode
+    OldSide,
+    /// Right (new) half in side-by-side mode
+    NewSide,
 }
 
 impl DiffViewerState {
+    /// Default [`split_ratio`](Self::split_ratio): equal halves.
+    pub const DEFAULT_SPLIT_RATIO: u16 = 50;
+    /// Smallest and largest [`split_ratio`](Self::split_ratio).
+    pub const SPLIT_RATIO_RANGE: (u16, u16) = (20, 80);
+
     /// Create a new diff viewer state with diff data
     pub fn new(diff: DiffData) -> Self {
         let selected_hunk = if diff.hunks.is_empty() { None } else { Some(0) };
@@ -416,11 +778,19 @@ impl DiffViewerState {
             view_mode: DiffViewMode::default(),
             scroll_y: 0,
             scroll_x: 0,
+            scroll_x_right: 0,
+            focused_side: DiffSide::default(),
+            split_ratio: Self::DEFAULT_SPLIT_RATIO,
             visible_height: 0,
             visible_width: 0,
             selected_hunk,
             show_line_numbers: true,
             search: SearchState::default(),
+            scrollbar_drag: ScrollbarDragState::default(),
+            selection: TextSelection::new(),
+            content_key: None,
+            scroll_memory: ScrollMemory::default(),
+            line_index: OnceLock::new(),
         }
     }
 
@@ -437,24 +807,133 @@ impl DiffViewerState {
 
     /// Set the diff data
     pub fn set_diff(&mut self, diff: DiffData) {
+        self.content_key = None;
         self.diff = diff;
+        self.line_index = OnceLock::new();
         self.scroll_y = 0;
         self.scroll_x = 0;
+        self.scroll_x_right = 0;
         self.selected_hunk = if self.diff.hunks.is_empty() {
             None
         } else {
             Some(0)
         };
         self.search.matches.clear();
+        self.selection.exit();
+    }
+
+    /// Set diff data identified by `key`, remembering the scroll position
+    ///
+    /// The position, selected hunk, and view mode of the diff being replaced
+    /// are saved under its own key, if it had one, and those last saved for
+    /// `key` are restored, clamped to the new diff.
+    pub fn set_diff_keyed(&mut self, key: impl Into<String>, diff: DiffData) {
+        let key = key.into();
+        if let Some(previous) = self.content_key.take() {
+            self.scroll_memory.save(previous, self.snapshot());
+        }
+        self.set_diff(diff);
+        if let Some(snapshot) = self.scroll_memory.restore(&key).copied() {
+            self.apply_snapshot(&snapshot);
+        }
+        self.content_key = Some(key);
+    }
+
+    /// Capture the scroll position, selected hunk, view mode, and split ratio
+    pub fn snapshot(&self) -> DiffViewerSnapshot {
+        DiffViewerSnapshot {
+            scroll_y: self.scroll_y,
+            scroll_x: self.scroll_x,
+            scroll_x_right: self.scroll_x_right,
+            selected_hunk: self.selected_hunk,
+            view_mode: self.view_mode,
+            split_ratio: self.split_ratio,
+        }
+    }
+
+    /// Restore a [`snapshot`](Self::snapshot), clamped to the current diff
+    pub fn apply_snapshot(&mut self, snapshot: &DiffViewerSnapshot) {
+        self.set_view_mode(snapshot.view_mode);
+        self.scroll_y = snapshot.scroll_y.min(self.total_lines().saturating_sub(1));
+        self.scroll_x = snapshot.scroll_x;
+        self.scroll_x_right = snapshot.scroll_x_right;
+        self.set_split_ratio(snapshot.split_ratio);
+        let hunks = self.diff.hunks.len();
+        self.selected_hunk = match snapshot.selected_hunk {
+            _ if hunks == 0 => None,
+            Some(index) => Some(index.min(hunks - 1)),
+            None => None,
+        };
+    }
+
+    /// Display rows in the current view mode with the text each one copies
+    fn copy_rows(&self) -> Vec<(CopyRowKind, &str)> {
+        let mut rows = Vec::new();
+        for hunk in &self.diff.hunks {
+            rows.push((CopyRowKind::Header, hunk.header.as_str()));
+            match self.view_mode {
+                DiffViewMode::Unified => rows.extend(
+                    hunk.lines
+                        .iter()
+                        .map(|line| (CopyRowKind::Unified, line.content.as_str())),
+                ),
+                DiffViewMode::SideBySide | DiffViewMode::WordLevel => {
+                    let side_by_side = self.view_mode == DiffViewMode::SideBySide;
+                    let (old_kind, new_kind) = if side_by_side {
+                        (CopyRowKind::OldSide, CopyRowKind::NewSide)
+                    } else {
+                        (CopyRowKind::Unified, CopyRowKind::Unified)
+                    };
+                    for pair in pair_lines_for_side_by_side(&hunk.lines) {
+                        rows.push(match pair {
+                            (_, Some(new)) => (new_kind, new.content.as_str()),
+                            (Some(old), None) => (old_kind, old.content.as_str()),
+                            (None, None) => (new_kind, ""),
+                        });
+                    }
+                }
+            }
+        }
+        rows
+    }
+
+    /// Text of each display row, as used by keyboard copy mode
+    ///
+    /// Hunk headers copy as-is, other rows copy their content without the
+    /// `+`/`-` prefix. In side-by-side and word-level modes rows copy the new
+    /// side, or the old side for deletions with no matching addition.
+    pub fn copy_lines(&self) -> Vec<&str> {
+        self.copy_rows().into_iter().map(|(_, text)| text).collect()
+    }
+
+    /// Enter keyboard copy mode with the cursor on the top visible row
+    pub fn enter_copy_mode(&mut self) {
+        self.selection.enter(self.scroll_y);
+    }
+
+    /// The diff data being displayed
+    pub fn diff(&self) -> &DiffData {
+        &self.diff
+    }
+
+    /// Mutable access to the diff data
+    ///
+    /// Unlike [`set_diff`](Self::set_diff) this keeps the scroll position and
+    /// selection. The line index is rebuilt on next use.
+    pub fn diff_mut(&mut self) -> &mut DiffData {
+        self.line_index = OnceLock::new();
+        &mut self.diff
+    }
+
+    /// Row offsets of the diff, built on first use
+    fn line_index(&self) -> &DiffLineIndex {
+        self.line_index
+            .get_or_init(|| DiffLineIndex::build(&self.diff))
     }
 
     /// Get total line count for scrolling
     fn total_lines(&self) -> usize {
-        self.diff
-            .hunks
-            .iter()
-            .map(|h| h.lines.len() + 1)
-            .sum::<usize>() // +1 for hunk header
+        self.line_index().total_rows()
     }
 
     // Navigation methods
@@ -472,14 +951,57 @@ impl DiffViewerState {
         }
     }
 
-    /// Scroll left
+    /// Scroll left; in side-by-side mode only the focused half
     pub fn scroll_left(&mut self) {
-        self.scroll_x = self.scroll_x.saturating_sub(4);
+        let scroll_x = self.horizontal_scroll_mut();
+        *scroll_x = scroll_x.saturating_sub(4);
     }
 
-    /// Scroll right
+    /// Scroll right; in side-by-side mode only the focused half
     pub fn scroll_right(&mut self) {
-        self.scroll_x += 4;
+        *self.horizontal_scroll_mut() += 4;
+    }
+
+    /// Horizontal scroll position moved by [`scroll_left`](Self::scroll_left)
+    /// and [`scroll_right`](Self::scroll_right)
+    fn horizontal_scroll_mut(&mut self) -> &mut usize {
+        match (self.view_mode, self.focused_side) {
+            (DiffViewMode::SideBySide, DiffSide::Right) => &mut self.scroll_x_right,
+            _ => &mut self.scroll_x,
+        }
+    }
+
+    /// Horizontal scroll position of a half in side-by-side mode
+    pub fn side_scroll_x(&self, side: DiffSide) -> usize {
+        match side {
+            DiffSide::Left => self.scroll_x,
+            DiffSide::Right => self.scroll_x_right,
+        }
+    }
+
+    /// Switch which half scrolls horizontally in side-by-side mode
+    pub fn toggle_focused_side(&mut self) {
+        self.focused_side = match self.focused_side {
+            DiffSide::Left => DiffSide::Right,
+            DiffSide::Right => DiffSide::Left,
+        };
+    }
+
+    /// Set the left half width in percent, clamped to
+    /// [`SPLIT_RATIO_RANGE`](Self::SPLIT_RATIO_RANGE)
+    pub fn set_split_ratio(&mut self, percent: u16) {
+        let (min, max) = Self::SPLIT_RATIO_RANGE;
+        self.split_ratio = percent.clamp(min, max);
+    }
+
+    /// Move the side-by-side separator left by 5 percent
+    pub fn shrink_left_side(&mut self) {
+        self.set_split_ratio(self.split_ratio.saturating_sub(5));
+    }
+
+    /// Move the side-by-side separator right by 5 percent
+    pub fn grow_left_side(&mut self) {
+        self.set_split_ratio(self.split_ratio + 5);
     }
 
     /// Scroll up by one page
@@ -525,14 +1047,8 @@ impl DiffViewerState {
 
     /// Get the line index where a hunk starts
     fn hunk_start_line(&self, hunk_index: usize) -> usize {
-        let mut line = 0;
-        for (i, hunk) in self.diff.hunks.iter().enumerate() {
-            if i == hunk_index {
-                return line;
-            }
-            line += hunk.lines.len() + 1; // +1 for hunk header
-        }
-        line
+        let starts = &self.line_index().hunk_starts;
+        starts[hunk_index.min(starts.len() - 1)]
     }
 
     /// Jump to the next hunk
@@ -566,60 +1082,22 @@ impl DiffViewerState {
     }
 
     /// Navigate to next change (addition or deletion)
+    ///
+    /// Scrolls so the change is on the second visible row, wrapping to the
+    /// first change after the last one.
     pub fn next_change(&mut self) {
-        let total = self.total_lines();
-        let line_idx = self.scroll_y + 1;
-        let mut running_line = 0;
-
-        for hunk in &self.diff.hunks {
-            // Skip hunk header
-            running_line += 1;
-            if running_line > line_idx {
-                // Check if this is a change line
-                if hunk
-                    .lines
-                    .first()
-                    .map(|l| l.line_type != DiffLineType::Context)
-                    .unwrap_or(false)
-                {
-                    self.scroll_y = running_line - 1;
-                    return;
-                }
-            }
-
-            for line in &hunk.lines {
-                if running_line > line_idx
-                    && (line.line_type == DiffLineType::Addition
-                        || line.line_type == DiffLineType::Deletion)
-                {
-                    self.scroll_y = running_line - 1;
-                    return;
-                }
-                running_line += 1;
-            }
-        }
-
-        // Wrap around to beginning
-        self.scroll_y = 0;
-        if total > 0 {
-            // Find first change
-            running_line = 0;
-            for hunk in &self.diff.hunks {
-                running_line += 1; // hunk header
-                for line in &hunk.lines {
-                    if line.line_type == DiffLineType::Addition
-                        || line.line_type == DiffLineType::Deletion
-                    {
-                        self.scroll_y = running_line - 1;
-                        return;
-                    }
-                    running_line += 1;
-                }
-            }
-        }
+        let changes = &self.line_index().change_rows;
+        // A change is shown from the row above it
+        let next = changes.partition_point(|&row| row <= self.scroll_y + 1);
+        self.scroll_y = changes
+            .get(next)
+            .or(changes.first())
+            .map_or(0, |&row| row - 1);
     }
 
     /// Navigate to previous change (addition or deletion)
+    ///
+    /// From the top, starts again from the bottom.
     pub fn prev_change(&mut self) {
         if self.scroll_y == 0 {
             // Start from end
@@ -627,48 +1105,41 @@ impl DiffViewerState {
         }
 
         let line_idx = self.scroll_y.saturating_sub(1);
-        let mut changes: Vec<usize> = Vec::new();
-        let mut running_line = 0;
-
-        // Collect all change line positions
-        for hunk in &self.diff.hunks {
-            running_line += 1; // hunk header
-            for line in &hunk.lines {
-                if line.line_type == DiffLineType::Addition
-                    || line.line_type == DiffLineType::Deletion
-                {
-                    changes.push(running_line - 1);
-                }
-                running_line += 1;
-            }
-        }
-
-        // Find the closest change before current position
-        for &change_line in changes.iter().rev() {
-            if change_line <= line_idx {
-                self.scroll_y = change_line;
-                return;
-            }
-        }
-
-        // Wrap to last change
-        if let Some(&last) = changes.last() {
-            self.scroll_y = last;
+        let changes = &self.line_index().change_rows;
+        let before = changes.partition_point(|&row| row - 1 <= line_idx);
+        // Fall back to the last change to wrap around
+        let target = before.checked_sub(1).or(changes.len().checked_sub(1));
+        if let Some(target) = target {
+            self.scroll_y = changes[target] - 1;
         }
     }
 
     // View mode
 
     /// Toggle between side-by-side and unified view modes
+    ///
+    /// Word-level mode switches back to unified.
     pub fn toggle_view_mode(&mut self) {
+        self.selection.exit();
         self.view_mode = match self.view_mode {
-            DiffViewMode::SideBySide => DiffViewMode::Unified,
+            DiffViewMode::SideBySide | DiffViewMode::WordLevel => DiffViewMode::Unified,
             DiffViewMode::Unified => DiffViewMode::SideBySide,
         };
     }
 
+    /// Switch to word-level mode, or from it back to unified
+    pub fn toggle_word_level(&mut self) {
+        let mode = if self.view_mode == DiffViewMode::WordLevel {
+            DiffViewMode::Unified
+        } else {
+            DiffViewMode::WordLevel
+        };
+        self.set_view_mode(mode);
+    }
+
     /// Set the view mode
     pub fn set_view_mode(&mut self, mode: DiffViewMode) {
+        self.selection.exit();
         self.view_mode = mode;
     }
 
@@ -742,6 +1213,91 @@ impl DiffViewerState {
         }
         self.scroll_y = self.search.matches[self.search.current_match];
     }
+
+    // Export methods
+
+    /// Reconstruct the diff as unified diff text
+    ///
+    /// The output parses back with [`DiffData::from_unified_diff`] into
+    /// equivalent data.
+    pub fn export_unified(&self) -> String {
+        let mut out = String::new();
+        self.diff.write_file_header(&mut out);
+        for hunk in &self.diff.hunks {
+            hunk.write_unified(&mut out);
+        }
+        out
+    }
+
+    /// Export the selected hunk as shown in the current view mode
+    ///
+    /// In unified and word-level modes this is a standalone patch for the
+    /// hunk, including file headers. In side-by-side mode it is the new side's text (context
+    /// and added lines). Returns `None` when no hunk is selected.
+    pub fn export_side_selection(&self) -> Option<String> {
+        let hunk_idx = self.selected_hunk?;
+        let hunk = self.diff.hunks.get(hunk_idx)?;
+        let mut out = String::new();
+        match self.view_mode {
+            DiffViewMode::Unified | DiffViewMode::WordLevel => {
+                out = format_hunk_as_patch(&self.diff, hunk_idx)
+            }
+            DiffViewMode::SideBySide => {
+                for line in &hunk.lines {
+                    if matches!(
+                        line.line_type,
+                        DiffLineType::Context | DiffLineType::Addition
+                    ) {
+                        out.push_str(&line.content);
+                        out.push('\n');
+                    }
+                }
+            }
+        }
+        Some(out)
+    }
+
+    /// Copy [`export_unified`](Self::export_unified) to the clipboard
+    ///
+    /// Requires the `clipboard` feature; returns
+    /// [`ClipboardResult::NotAvailable`] otherwise.
+    pub fn export_to_clipboard(&self) -> ClipboardResult {
+        copy_to_clipboard(&self.export_unified())
+    }
+
+    /// Copy the selected hunk to the clipboard as a patch
+    ///
+    /// See [`format_hunk_as_patch`]. Returns `None` when no hunk is selected.
+    pub fn copy_current_hunk_as_patch(&self) -> Option<ClipboardResult> {
+        let hunk_idx = self
+            .selected_hunk
+            .filter(|&idx| idx < self.diff.hunks.len())?;
+        Some(copy_to_clipboard(&format_hunk_as_patch(
+            &self.diff, hunk_idx,
+        )))
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_diff_viewer_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑↓/jk", "Scroll"),
+            ("←→/hl", "Scroll horizontally"),
+            ("Tab", "Switch scrolled half (side-by-side)"),
+            ("< / >", "Move split (side-by-side)"),
+            ("PgUp/PgDn", "Page"),
+            ("Ctrl+U/Ctrl+D", "Half page"),
+            ("g/Home", "Top"),
+            ("G/End", "Bottom"),
+            ("[ / ]", "Previous/next hunk"),
+            ("n/N", "Next/previous change or match"),
+            ("v/m", "Toggle view mode"),
+            ("w", "Toggle word-level diff"),
+            ("/", "Search"),
+            ("Ctrl+E", "Copy diff to clipboard"),
+            ("Ctrl+Y", "Copy hunk as patch"),
+            ("c", "Copy mode (v/Ctrl+V select, y copy)"),
+        ]
+    }
 }
 
 // ============================================================================
@@ -779,6 +1335,16 @@ pub struct DiffViewerStyle {
     pub gutter_separator: &'static str,
     /// Side-by-side mode separator character
     pub side_separator: &'static str,
+    /// Border characters
+    pub border_set: border::Set<'static>,
+    /// Scrollbar symbols (ratatui's default set when `None`)
+    pub scrollbar_symbols: Option<scrollbar::Set<'static>>,
+    /// Cursor shown after the search query
+    pub search_cursor: &'static str,
+    /// Copy mode selection highlight
+    pub selection_style: Style,
+    /// Copy mode cursor
+    pub selection_cursor_style: Style,
 }
 
 impl Default for DiffViewerStyle {
@@ -808,6 +1374,11 @@ impl Default for DiffViewerStyle {
             current_match_style: Style::default().bg(Color::Yellow).fg(Color::Black),
             gutter_separator: "│",
             side_separator: "│",
+            border_set: border::PLAIN,
+            scrollbar_symbols: None,
+            search_cursor: "▌",
+            selection_style: Style::default().bg(Color::Blue).fg(Color::White),
+            selection_cursor_style: Style::default().bg(Color::White).fg(Color::Black),
         }
     }
 }
@@ -838,6 +1409,11 @@ impl From<&crate::theme::Theme> for DiffViewerStyle {
             current_match_style: Style::default().bg(p.highlight_bg).fg(p.highlight_fg),
             gutter_separator: "│",
             side_separator: "│",
+            border_set: border::PLAIN,
+            scrollbar_symbols: None,
+            search_cursor: "▌",
+            selection_style: Style::default().bg(p.highlight_bg).fg(p.highlight_fg),
+            selection_cursor_style: Style::default().bg(p.text).fg(p.bg),
         }
     }
 }
@@ -854,6 +1430,46 @@ impl DiffViewerStyle {
         }
     }
 
+    /// Create an ASCII-only style: `|` separators, `+-|` borders, and an
+    /// ASCII scrollbar
+    pub fn ascii() -> Self {
+        Self {
+            gutter_separator: "|",
+            side_separator: "|",
+            border_set: ASCII_BORDER,
+            scrollbar_symbols: Some(ASCII_VERTICAL_SCROLLBAR),
+            search_cursor: "_",
+            ..Default::default()
+        }
+    }
+
+    /// The default style adapted to the terminal: [`ascii`](Self::ascii)
+    /// glyphs without Unicode, palette colors without truecolor
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        let style = if caps.unicode {
+            Self::default()
+        } else {
+            Self::ascii()
+        };
+        Self {
+            border_style: caps.style(style.border_style),
+            line_number_style: caps.style(style.line_number_style),
+            context_style: caps.style(style.context_style),
+            addition_style: caps.style(style.addition_style),
+            addition_bg: caps.color(style.addition_bg),
+            deletion_style: caps.style(style.deletion_style),
+            deletion_bg: caps.color(style.deletion_bg),
+            inline_addition_style: caps.style(style.inline_addition_style),
+            inline_deletion_style: caps.style(style.inline_deletion_style),
+            hunk_header_style: caps.style(style.hunk_header_style),
+            match_style: caps.style(style.match_style),
+            current_match_style: caps.style(style.current_match_style),
+            selection_style: caps.style(style.selection_style),
+            selection_cursor_style: caps.style(style.selection_cursor_style),
+            ..style
+        }
+    }
+
     /// Create a monochrome style
     pub fn monochrome() -> Self {
         Self {
@@ -868,6 +1484,126 @@ impl DiffViewerStyle {
             ..Default::default()
         }
     }
+
+    /// Create a style from addition, deletion, and context colors
+    ///
+    /// Inline (character-level) highlights swap each side's foreground and
+    /// background. Everything else keeps the default style.
+    pub fn from_base_colors(
+        addition_fg: Color,
+        addition_bg: Color,
+        deletion_fg: Color,
+        deletion_bg: Color,
+        context_fg: Color,
+    ) -> Self {
+        Self {
+            context_style: Style::default().fg(context_fg),
+            addition_style: Style::default().fg(addition_fg),
+            addition_bg,
+            deletion_style: Style::default().fg(deletion_fg),
+            deletion_bg,
+            inline_addition_style: Style::default()
+                .fg(addition_bg)
+                .bg(addition_fg)
+                .add_modifier(Modifier::BOLD),
+            inline_deletion_style: Style::default()
+                .fg(deletion_bg)
+                .bg(deletion_fg)
+                .add_modifier(Modifier::BOLD),
+            ..Default::default()
+        }
+    }
+
+    /// Create a style matching GitHub's dark diff colors
+    pub fn github_dark() -> Self {
+        Self {
+            border_style: Style::default().fg(Color::Rgb(48, 54, 61)),
+            line_number_style: Style::default().fg(Color::Rgb(110, 118, 129)),
+            hunk_header_style: Style::default()
+                .fg(Color::Rgb(121, 192, 255))
+                .add_modifier(Modifier::BOLD),
+            ..Self::from_base_colors(
+                Color::Rgb(63, 185, 80),
+                Color::Rgb(18, 38, 30),
+                Color::Rgb(248, 81, 73),
+                Color::Rgb(37, 23, 28),
+                Color::Rgb(230, 237, 243),
+            )
+        }
+    }
+
+    /// Create a style using the Solarized dark palette
+    pub fn solarized_dark() -> Self {
+        Self {
+            border_style: Style::default().fg(Color::Rgb(38, 139, 210)),
+            line_number_style: Style::default().fg(Color::Rgb(88, 110, 117)),
+            hunk_header_style: Style::default()
+                .fg(Color::Rgb(42, 161, 152))
+                .add_modifier(Modifier::BOLD),
+            ..Self::from_base_colors(
+                Color::Rgb(133, 153, 0),
+                Color::Rgb(20, 56, 32),
+                Color::Rgb(220, 50, 47),
+                Color::Rgb(60, 36, 44),
+                Color::Rgb(131, 148, 150),
+            )
+        }
+    }
+
+    /// Create a style using the Monokai palette
+    pub fn monokai() -> Self {
+        Self {
+            border_style: Style::default().fg(Color::Rgb(102, 217, 239)),
+            line_number_style: Style::default().fg(Color::Rgb(117, 113, 94)),
+            hunk_header_style: Style::default()
+                .fg(Color::Rgb(174, 129, 255))
+                .add_modifier(Modifier::BOLD),
+            match_style: Style::default()
+                .bg(Color::Rgb(73, 72, 62))
+                .fg(Color::Rgb(230, 219, 116)),
+            ..Self::from_base_colors(
+                Color::Rgb(166, 226, 46),
+                Color::Rgb(61, 74, 30),
+                Color::Rgb(249, 38, 114),
+                Color::Rgb(74, 30, 48),
+                Color::Rgb(248, 248, 242),
+            )
+        }
+    }
+}
+
+/// Built-in diff viewer color presets
+///
+/// Converts into a [`DiffViewerStyle`], so it can be passed to
+/// [`DiffViewer::theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DiffViewerTheme {
+    /// [`DiffViewerStyle::default`]
+    #[default]
+    Default,
+    /// [`DiffViewerStyle::high_contrast`]
+    HighContrast,
+    /// [`DiffViewerStyle::monochrome`]
+    Monochrome,
+    /// [`DiffViewerStyle::github_dark`]
+    GithubDark,
+    /// [`DiffViewerStyle::solarized_dark`]
+    SolarizedDark,
+    /// [`DiffViewerStyle::monokai`]
+    Monokai,
+}
+
+impl From<DiffViewerTheme> for DiffViewerStyle {
+    fn from(theme: DiffViewerTheme) -> Self {
+        match theme {
+            DiffViewerTheme::Default => Self::default(),
+            DiffViewerTheme::HighContrast => Self::high_contrast(),
+            DiffViewerTheme::Monochrome => Self::monochrome(),
+            DiffViewerTheme::GithubDark => Self::github_dark(),
+            DiffViewerTheme::SolarizedDark => Self::solarized_dark(),
+            DiffViewerTheme::Monokai => Self::monokai(),
+        }
+    }
 }
 
 // ============================================================================
@@ -906,8 +1642,11 @@ impl<'a> DiffViewer<'a> {
     }
 
     /// Apply a theme to derive the style
-    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
-        self.style(DiffViewerStyle::from(theme))
+    ///
+    /// Accepts an app-wide [`Theme`](crate::theme::Theme) or a
+    /// [`DiffViewerTheme`] preset.
+    pub fn theme(self, theme: impl Into<DiffViewerStyle>) -> Self {
+        self.style(theme.into())
     }
 
     /// Enable or disable line numbers
@@ -940,8 +1679,52 @@ impl<'a> DiffViewer<'a> {
         max_line.to_string().len().max(3)
     }
 
+    /// Widths of the left and right halves in side-by-side mode, which
+    /// share `width` minus the separator according to the split ratio
+    fn half_widths(&self, width: u16) -> (u16, u16) {
+        let separator = display_width(self.style.side_separator) as u16;
+        let available = width.saturating_sub(separator);
+        let left = (u32::from(available) * u32::from(self.state.split_ratio) / 100) as u16;
+        (left, available - left)
+    }
+
+    /// Screen placement of the visible copy mode rows
+    fn selection_rows(&self, inner: Rect) -> Vec<SelectionRow> {
+        let numbers = self.state.show_line_numbers;
+        let line_num_width = self.line_number_width() as u16;
+        let (half_width, _) = self.half_widths(inner.width);
+        let separator = display_width(self.style.side_separator) as u16;
+        let side_offset = if numbers { line_num_width + 2 } else { 1 };
+        let unified_offset = if numbers { line_num_width * 2 + 5 } else { 1 };
+
+        self.state
+            .copy_rows()
+            .into_iter()
+            .enumerate()
+            .skip(self.state.scroll_y)
+            .take(inner.height as usize)
+            .enumerate()
+            .map(|(row, (line, (kind, _)))| {
+                let (offset, width) = match kind {
+                    CopyRowKind::Header => (0, inner.width),
+                    CopyRowKind::Unified => (unified_offset, inner.width),
+                    CopyRowKind::OldSide => (side_offset, half_width),
+                    CopyRowKind::NewSide => (half_width + separator + side_offset, inner.width),
+                };
+                let offset = offset.min(inner.width);
+                let end = width.min(inner.width);
+                SelectionRow {
+                    line,
+                    y: inner.y + row as u16,
+                    x: inner.x + offset,
+                    width: end.saturating_sub(offset),
+                }
+            })
+            .collect()
+    }
+
     /// Build lines for unified view
-    fn build_unified_lines(&self, inner: Rect) -> Vec<Line<'static>> {
+    fn build_unified_lines(&self, inner: Rect) -> Vec<Line<'a>> {
         let visible_height = inner.height as usize;
         let line_num_width = self.line_number_width();
         let visible_width = if self.state.show_line_numbers {
@@ -955,7 +1738,15 @@ impl<'a> DiffViewer<'a> {
         let start_line = self.state.scroll_y;
         let end_line = start_line + visible_height;
 
-        for hunk in &self.state.diff.hunks {
+        let state = self.state;
+        for hunk in &state.diff.hunks {
+            // Skip hunks above the viewport without visiting their lines
+            let hunk_rows = 1 + hunk.lines.len();
+            if current_line + hunk_rows <= start_line {
+                current_line += hunk_rows;
+                continue;
+            }
+
             // Hunk header
             if current_line >= start_line && current_line < end_line {
                 let is_match = self.state.search.matches.contains(&current_line);
@@ -974,12 +1765,7 @@ impl<'a> DiffViewer<'a> {
                     self.style.hunk_header_style
                 };
 
-                let header_content: String = hunk
-                    .header
-                    .chars()
-                    .skip(self.state.scroll_x)
-                    .take(inner.width as usize)
-                    .collect();
+                let header_content = char_slice(&hunk.header, state.scroll_x, inner.width as usize);
                 lines.push(Line::from(Span::styled(header_content, header_style)));
             }
             current_line += 1;
@@ -1021,33 +1807,13 @@ impl<'a> DiffViewer<'a> {
     /// Build a single unified diff line
     fn build_unified_line(
         &self,
-        line: &DiffLine,
+        line: &'a DiffLine,
         line_num_width: usize,
         visible_width: usize,
         is_match: bool,
         is_current_match: bool,
-    ) -> Line<'static> {
-        let mut spans = Vec::new();
-
-        // Line numbers
-        if self.state.show_line_numbers {
-            let old_num = line
-                .old_line_num
-                .map(|n| format!("{:>width$}", n, width = line_num_width))
-                .unwrap_or_else(|| " ".repeat(line_num_width));
-            let new_num = line
-                .new_line_num
-                .map(|n| format!("{:>width$}", n, width = line_num_width))
-                .unwrap_or_else(|| " ".repeat(line_num_width));
-
-            spans.push(Span::styled(old_num, self.style.line_number_style));
-            spans.push(Span::styled(" ", self.style.line_number_style));
-            spans.push(Span::styled(new_num, self.style.line_number_style));
-            spans.push(Span::styled(
-                format!(" {} ", self.style.gutter_separator),
-                self.style.line_number_style,
-            ));
-        }
+    ) -> Line<'a> {
+        let mut spans = self.unified_gutter(line.old_line_num, line.new_line_num, line_num_width);
 
         // Prefix and content
         let (prefix, content_style, bg_style) = match line.line_type {
@@ -1074,30 +1840,56 @@ impl<'a> DiffViewer<'a> {
             content_style.patch(bg_style)
         };
 
-        spans.push(Span::styled(prefix.to_string(), final_style));
+        spans.push(Span::styled(prefix, final_style));
 
         // Content with horizontal scroll
-        let content: String = line
-            .content
-            .chars()
-            .skip(self.state.scroll_x)
-            .take(visible_width)
-            .collect();
-
+        let content = char_slice(&line.content, self.state.scroll_x, visible_width);
         spans.push(Span::styled(content, final_style));
 
         Line::from(spans)
     }
 
-    /// Build lines for side-by-side view
-    fn build_side_by_side_lines(&self, inner: Rect) -> Vec<Line<'static>> {
+    /// Old and new line numbers of a unified row, when shown
+    fn unified_gutter(
+        &self,
+        old_line_num: Option<usize>,
+        new_line_num: Option<usize>,
+        line_num_width: usize,
+    ) -> Vec<Span<'a>> {
+        let mut spans = Vec::new();
+        if self.state.show_line_numbers {
+            let old_num = old_line_num.map_or_else(
+                || spaces(line_num_width),
+                |n| line_number(n, line_num_width),
+            );
+            let new_num = new_line_num.map_or_else(
+                || spaces(line_num_width),
+                |n| line_number(n, line_num_width),
+            );
+
+            let number_style = self.style.line_number_style;
+            spans.push(Span::styled(old_num, number_style));
+            spans.push(Span::styled(" ", number_style));
+            spans.push(Span::styled(new_num, number_style));
+            spans.push(Span::styled(" ", number_style));
+            spans.push(Span::styled(self.style.gutter_separator, number_style));
+            spans.push(Span::styled(" ", number_style));
+        }
+        spans
+    }
+
+    /// Build lines for word-level view
+    ///
+    /// Rows pair lines like the side-by-side view. A deletion paired with an
+    /// addition is drawn as one row, merged word by word; other rows are
+    /// drawn as in the unified view.
+    fn build_word_level_lines(&self, inner: Rect) -> Vec<Line<'a>> {
         let visible_height = inner.height as usize;
-        let half_width = (inner.width.saturating_sub(1) / 2) as usize; // -1 for separator
         let line_num_width = self.line_number_width();
-        let content_width = if self.state.show_line_numbers {
-            half_width.saturating_sub(line_num_width + 3) // line num + prefix + separator
+        let visible_width = if self.state.show_line_numbers {
+            inner.width.saturating_sub((line_num_width * 2 + 4) as u16) as usize
         } else {
-            half_width.saturating_sub(2) // Just prefix
+            inner.width.saturating_sub(2) as usize // Just prefix space
         };
 
         let mut lines = Vec::new();
@@ -1105,32 +1897,39 @@ impl<'a> DiffViewer<'a> {
         let start_line = self.state.scroll_y;
         let end_line = start_line + visible_height;
 
-        for hunk in &self.state.diff.hunks {
-            // Hunk header (spans both sides)
+        let state = self.state;
+        for hunk in &state.diff.hunks {
+            // Skip hunks above the viewport without pairing their lines
+            let hunk_rows = 1 + side_by_side_row_count(&hunk.lines);
+            if current_line + hunk_rows <= start_line {
+                current_line += hunk_rows;
+                continue;
+            }
+
             if current_line >= start_line && current_line < end_line {
-                let header_style = self.style.hunk_header_style;
-                let header_content: String = hunk
-                    .header
-                    .chars()
-                    .skip(self.state.scroll_x)
-                    .take(inner.width as usize)
-                    .collect();
-                lines.push(Line::from(Span::styled(header_content, header_style)));
+                let header_content = char_slice(&hunk.header, state.scroll_x, inner.width as usize);
+                lines.push(Line::from(Span::styled(
+                    header_content,
+                    self.style.hunk_header_style,
+                )));
             }
             current_line += 1;
 
-            // Process lines in pairs for side-by-side
-            let paired_lines = self.pair_lines_for_side_by_side(&hunk.lines);
-
-            for (old_line, new_line) in paired_lines {
+            for pair in pair_lines_for_side_by_side(&hunk.lines) {
                 if current_line >= start_line && current_line < end_line {
-                    lines.push(self.build_side_by_side_line(
-                        old_line,
-                        new_line,
-                        line_num_width,
-                        content_width,
-                        half_width,
-                    ));
+                    lines.push(match pair {
+                        (Some(old), Some(new)) if old.line_type == DiffLineType::Deletion => {
+                            self.build_word_level_line(old, new, line_num_width, visible_width)
+                        }
+                        (_, Some(line)) | (Some(line), None) => self.build_unified_line(
+                            line,
+                            line_num_width,
+                            visible_width,
+                            false,
+                            false,
+                        ),
+                        (None, None) => Line::default(),
+                    });
                 }
                 current_line += 1;
 
@@ -1147,74 +1946,127 @@ impl<'a> DiffViewer<'a> {
         lines
     }
 
-    /// Pair deletion/addition lines for side-by-side display
-    fn pair_lines_for_side_by_side<'b>(
+    /// Build a deletion and its addition merged into one row, with removed
+    /// and inserted words highlighted
+    fn build_word_level_line(
         &self,
-        lines: &'b [DiffLine],
-    ) -> Vec<(Option<&'b DiffLine>, Option<&'b DiffLine>)> {
-        let mut pairs = Vec::new();
-        let mut deletions: Vec<&DiffLine> = Vec::new();
-        let mut additions: Vec<&DiffLine> = Vec::new();
-
-        for line in lines {
-            match line.line_type {
-                DiffLineType::Context => {
-                    // Flush any pending deletions/additions
-                    Self::flush_changes(&mut pairs, &mut deletions, &mut additions);
-                    pairs.push((Some(line), Some(line)));
-                }
-                DiffLineType::Deletion => {
-                    deletions.push(line);
-                }
-                DiffLineType::Addition => {
-                    additions.push(line);
-                }
-                DiffLineType::HunkHeader => {
-                    // Shouldn't happen here
-                }
+        deletion: &'a DiffLine,
+        addition: &'a DiffLine,
+        line_num_width: usize,
+        visible_width: usize,
+    ) -> Line<'a> {
+        let mut spans =
+            self.unified_gutter(deletion.old_line_num, addition.new_line_num, line_num_width);
+        spans.push(Span::styled("~", self.style.context_style));
+
+        // Content with horizontal scroll, cut across the word runs
+        let (mut skip, mut remaining) = (self.state.scroll_x, visible_width);
+        for (op, text) in word_diff(&deletion.content, &addition.content) {
+            if remaining == 0 {
+                break;
             }
+            let count = text.chars().count();
+            if skip >= count {
+                skip -= count;
+                continue;
+            }
+            let piece = char_slice(text, skip, remaining);
+            skip = 0;
+            remaining -= piece.chars().count();
+            let style = match op {
+                WordOp::Equal => self.style.context_style,
+                WordOp::Delete => self.style.inline_deletion_style,
+                WordOp::Insert => self.style.inline_addition_style,
+            };
+            spans.push(Span::styled(piece, style));
         }
 
-        // Flush remaining
-        Self::flush_changes(&mut pairs, &mut deletions, &mut additions);
-
-        pairs
+        Line::from(spans)
     }
 
-    /// Flush accumulated deletions and additions into pairs
-    fn flush_changes<'b>(
-        pairs: &mut Vec<(Option<&'b DiffLine>, Option<&'b DiffLine>)>,
-        deletions: &mut Vec<&'b DiffLine>,
-        additions: &mut Vec<&'b DiffLine>,
-    ) {
-        let max_len = deletions.len().max(additions.len());
-        for i in 0..max_len {
-            let del = deletions.get(i).copied();
-            let add = additions.get(i).copied();
-            pairs.push((del, add));
+    /// Build lines for side-by-side view
+    fn build_side_by_side_lines(&self, inner: Rect) -> Vec<Line<'a>> {
+        let visible_height = inner.height as usize;
+        let (left_width, right_width) = self.half_widths(inner.width);
+        let line_num_width = self.line_number_width();
+        // Line number, space, and prefix, plus a trailing space on the left
+        let chrome = if self.state.show_line_numbers {
+            line_num_width + 3
+        } else {
+            2
+        };
+        let widths = SideWidths {
+            line_num: line_num_width,
+            half: left_width as usize,
+            left_content: (left_width as usize).saturating_sub(chrome),
+            right_content: (right_width as usize).saturating_sub(chrome),
+        };
+
+        let mut lines = Vec::new();
+        let mut current_line = 0;
+        let start_line = self.state.scroll_y;
+        let end_line = start_line + visible_height;
+
+        let state = self.state;
+        for hunk in &state.diff.hunks {
+            // Skip hunks above the viewport without pairing their lines
+            let hunk_rows = 1 + side_by_side_row_count(&hunk.lines);
+            if current_line + hunk_rows <= start_line {
+                current_line += hunk_rows;
+                continue;
+            }
+
+            // Hunk header (spans both sides)
+            if current_line >= start_line && current_line < end_line {
+                let header_style = self.style.hunk_header_style;
+                let header_content = char_slice(&hunk.header, state.scroll_x, inner.width as usize);
+                lines.push(Line::from(Span::styled(header_content, header_style)));
+            }
+            current_line += 1;
+
+            // Process lines in pairs for side-by-side
+            let paired_lines = pair_lines_for_side_by_side(&hunk.lines);
+
+            for (old_line, new_line) in paired_lines {
+                if current_line >= start_line && current_line < end_line {
+                    lines.push(self.build_side_by_side_line(old_line, new_line, &widths));
+                }
+                current_line += 1;
+
+                if current_line >= end_line {
+                    break;
+                }
+            }
+
+            if current_line >= end_line {
+                break;
+            }
         }
-        deletions.clear();
-        additions.clear();
+
+        lines
     }
 
     /// Build a side-by-side line
     fn build_side_by_side_line(
         &self,
-        old_line: Option<&DiffLine>,
-        new_line: Option<&DiffLine>,
-        line_num_width: usize,
-        content_width: usize,
-        half_width: usize,
-    ) -> Line<'static> {
+        old_line: Option<&'a DiffLine>,
+        new_line: Option<&'a DiffLine>,
+        widths: &SideWidths,
+    ) -> Line<'a> {
         let mut spans = Vec::new();
 
         // Left side (old)
-        spans.extend(self.build_half_line(old_line, line_num_width, content_width, true));
+        spans.extend(self.build_half_line(
+            old_line,
+            widths.line_num,
+            widths.left_content,
+            DiffSide::Left,
+        ));
 
         // Pad to half width
         let left_len: usize = spans.iter().map(|s| s.content.chars().count()).sum();
-        if left_len < half_width {
-            spans.push(Span::raw(" ".repeat(half_width - left_len)));
+        if left_len < widths.half {
+            spans.push(Span::raw(spaces(widths.half - left_len)));
         }
 
         // Separator
@@ -1224,7 +2076,12 @@ impl<'a> DiffViewer<'a> {
         ));
 
         // Right side (new)
-        spans.extend(self.build_half_line(new_line, line_num_width, content_width, false));
+        spans.extend(self.build_half_line(
+            new_line,
+            widths.line_num,
+            widths.right_content,
+            DiffSide::Right,
+        ));
 
         Line::from(spans)
     }
@@ -1232,25 +2089,25 @@ impl<'a> DiffViewer<'a> {
     /// Build one half of a side-by-side line
     fn build_half_line(
         &self,
-        line: Option<&DiffLine>,
+        line: Option<&'a DiffLine>,
         line_num_width: usize,
         content_width: usize,
-        is_old: bool,
-    ) -> Vec<Span<'static>> {
+        side: DiffSide,
+    ) -> Vec<Span<'a>> {
         let mut spans = Vec::new();
 
         match line {
             Some(l) => {
                 // Line number
                 if self.state.show_line_numbers {
-                    let num = if is_old {
-                        l.old_line_num
-                    } else {
-                        l.new_line_num
+                    let num = match side {
+                        DiffSide::Left => l.old_line_num,
+                        DiffSide::Right => l.new_line_num,
                     };
-                    let num_str = num
-                        .map(|n| format!("{:>width$}", n, width = line_num_width))
-                        .unwrap_or_else(|| " ".repeat(line_num_width));
+                    let num_str = num.map_or_else(
+                        || spaces(line_num_width),
+                        |n| line_number(n, line_num_width),
+                    );
                     spans.push(Span::styled(num_str, self.style.line_number_style));
                     spans.push(Span::raw(" "));
                 }
@@ -1275,23 +2132,19 @@ impl<'a> DiffViewer<'a> {
 
                 let final_style = style.patch(bg);
 
-                spans.push(Span::styled(prefix.to_string(), final_style));
+                spans.push(Span::styled(prefix, final_style));
 
                 // Content with scroll
-                let content: String = l
-                    .content
-                    .chars()
-                    .skip(self.state.scroll_x)
-                    .take(content_width)
-                    .collect();
+                let scroll_x = self.state.side_scroll_x(side);
+                let content = char_slice(&l.content, scroll_x, content_width);
                 spans.push(Span::styled(content, final_style));
             }
             None => {
                 // Empty half
                 if self.state.show_line_numbers {
-                    spans.push(Span::raw(" ".repeat(line_num_width + 1)));
+                    spans.push(Span::raw(spaces(line_num_width + 1)));
                 }
-                spans.push(Span::raw(" ".repeat(content_width + 1)));
+                spans.push(Span::raw(spaces(content_width + 1)));
             }
         }
 
@@ -1299,8 +2152,113 @@ impl<'a> DiffViewer<'a> {
     }
 }
 
+/// Column widths of a side-by-side row
+struct SideWidths {
+    /// Line number gutter of each half
+    line_num: usize,
+    /// Left half, up to the separator
+    half: usize,
+    /// Content of the left half
+    left_content: usize,
+    /// Content of the right half
+    right_content: usize,
+}
+
+/// Right-aligned line number in a gutter column of `width`
+fn line_number(number: usize, width: usize) -> Cow<'static, str> {
+    Cow::Owned(format!("{:>width$}", number))
+}
+
+/// Pair deletion/addition lines for side-by-side display
+fn pair_lines_for_side_by_side(lines: &[DiffLine]) -> Vec<(Option<&DiffLine>, Option<&DiffLine>)> {
+    let mut pairs = Vec::new();
+    let mut deletions: Vec<&DiffLine> = Vec::new();
+    let mut additions: Vec<&DiffLine> = Vec::new();
+
+    for line in lines {
+        match line.line_type {
+            DiffLineType::Context => {
+                // Flush any pending deletions/additions
+                flush_changes(&mut pairs, &mut deletions, &mut additions);
+                pairs.push((Some(line), Some(line)));
+            }
+            DiffLineType::Deletion => {
+                deletions.push(line);
+            }
+            DiffLineType::Addition => {
+                additions.push(line);
+            }
+            DiffLineType::HunkHeader => {
+                // Shouldn't happen here
+            }
+        }
+    }
+
+    // Flush remaining
+    flush_changes(&mut pairs, &mut deletions, &mut additions);
+
+    pairs
+}
+
+/// Number of rows [`pair_lines_for_side_by_side`] produces for `lines`
+fn side_by_side_row_count(lines: &[DiffLine]) -> usize {
+    let (mut rows, mut deletions, mut additions) = (0, 0, 0);
+    for line in lines {
+        match line.line_type {
+            DiffLineType::Context => {
+                rows += deletions.max(additions) + 1;
+                (deletions, additions) = (0, 0);
+            }
+            DiffLineType::Deletion => deletions += 1,
+            DiffLineType::Addition => additions += 1,
+            DiffLineType::HunkHeader => {}
+        }
+    }
+    rows + deletions.max(additions)
+}
+
+/// Flush accumulated deletions and additions into pairs
+fn flush_changes<'b>(
+    pairs: &mut Vec<(Option<&'b DiffLine>, Option<&'b DiffLine>)>,
+    deletions: &mut Vec<&'b DiffLine>,
+    additions: &mut Vec<&'b DiffLine>,
+) {
+    let max_len = deletions.len().max(additions.len());
+    for i in 0..max_len {
+        let del = deletions.get(i).copied();
+        let add = additions.get(i).copied();
+        pairs.push((del, add));
+    }
+    deletions.clear();
+    additions.clear();
+}
+
 impl Widget for DiffViewer<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_impl(area, buf, None);
+    }
+}
+
+impl DiffViewer<'_> {
+    /// Render and register the scrollbar parts for click handling
+    ///
+    /// Pass the registry to [`handle_diff_viewer_scrollbar_mouse`].
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<ScrollbarPart>,
+    ) {
+        registry.clear();
+        self.render_impl(area, buf, Some(registry));
+    }
+
+    fn render_impl(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: Option<&mut ClickRegionRegistry<ScrollbarPart>>,
+    ) {
         // Layout: content + status bar + optional search bar
         let constraints = if self.state.search.active {
             vec![
@@ -1337,6 +2295,7 @@ impl Widget for DiffViewer<'_> {
         let block = Block::default()
             .title(title_text)
             .borders(Borders::ALL)
+            .border_set(self.style.border_set)
             .border_style(self.style.border_style);
 
         let inner = block.inner(chunks[0]);
@@ -1346,18 +2305,49 @@ impl Widget for DiffViewer<'_> {
         let lines = match self.state.view_mode {
             DiffViewMode::Unified => self.build_unified_lines(inner),
             DiffViewMode::SideBySide => self.build_side_by_side_lines(inner),
+            DiffViewMode::WordLevel => self.build_word_level_lines(inner),
         };
 
         let para = Paragraph::new(lines);
         para.render(inner, buf);
 
+        // Copy mode selection
+        if self.state.selection.active {
+            // Right-half rows follow that half's scroll position
+            let (right, rows): (Vec<_>, Vec<_>) = self
+                .selection_rows(inner)
+                .into_iter()
+                .zip(self.state.copy_rows().into_iter().skip(self.state.scroll_y))
+                .partition(|(_, (kind, _))| {
+                    *kind == CopyRowKind::NewSide
+                        && self.state.view_mode == DiffViewMode::SideBySide
+                });
+            for (rows, scroll_x) in [
+                (rows, self.state.scroll_x),
+                (right, self.state.scroll_x_right),
+            ] {
+                render_selection_overlay(
+                    &self.state.selection,
+                    rows.into_iter().map(|(row, _)| row),
+                    scroll_x,
+                    self.style.selection_style,
+                    self.style.selection_cursor_style,
+                    buf,
+                );
+            }
+        }
+
         // Scrollbar
         let total_lines = self.state.total_lines();
         if total_lines > inner.height as usize {
-            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
-            let mut scrollbar_state =
-                ScrollbarState::new(total_lines).position(self.state.scroll_y);
-            scrollbar.render(inner, buf, &mut scrollbar_state);
+            let mut scrollbar = diff_viewer_scrollbar(self.state);
+            if let Some(symbols) = &self.style.scrollbar_symbols {
+                scrollbar = scrollbar.symbols(symbols.clone());
+            }
+            match registry {
+                Some(registry) => scrollbar.render_with_registry(inner, buf, registry),
+                None => scrollbar.render(inner, buf),
+            }
         }
 
         // Status bar
@@ -1365,7 +2355,7 @@ impl Widget for DiffViewer<'_> {
 
         // Search bar
         if self.state.search.active && chunks.len() > 2 {
-            render_diff_search_bar(self.state, chunks[2], buf);
+            render_diff_search_bar(self.state, &self.style, chunks[2], buf);
         }
     }
 }
@@ -1373,7 +2363,7 @@ impl Widget for DiffViewer<'_> {
 /// Render the status bar
 fn render_diff_status_bar(
     state: &DiffViewerState,
-    _style: &DiffViewerStyle,
+    style: &DiffViewerStyle,
     area: Rect,
     buf: &mut Buffer,
 ) {
@@ -1388,6 +2378,7 @@ fn render_diff_status_bar(
     let mode_str = match state.view_mode {
         DiffViewMode::Unified => "Unified",
         DiffViewMode::SideBySide => "Side-by-Side",
+        DiffViewMode::WordLevel => "Word",
     };
 
     let hunk_info = if let Some(hunk_idx) = state.selected_hunk {
@@ -1396,10 +2387,16 @@ fn render_diff_status_bar(
         String::new()
     };
 
-    let h_scroll_info = if state.scroll_x > 0 {
-        format!(" | Col: {}", state.scroll_x + 1)
-    } else {
-        String::new()
+    let h_scroll_info = match state.view_mode {
+        DiffViewMode::SideBySide if state.scroll_x > 0 || state.scroll_x_right > 0 => format!(
+            " | Col: {} / {}",
+            state.scroll_x + 1,
+            state.scroll_x_right + 1
+        ),
+        DiffViewMode::Unified | DiffViewMode::WordLevel if state.scroll_x > 0 => {
+            format!(" | Col: {}", state.scroll_x + 1)
+        }
+        _ => String::new(),
     };
 
     let search_info = if !state.search.matches.is_empty() {
@@ -1414,33 +2411,53 @@ fn render_diff_status_bar(
         String::new()
     };
 
-    let status = Line::from(vec![
-        Span::styled(" j/k", Style::default().fg(Color::Yellow)),
-        Span::raw(": scroll "),
-        Span::styled("]/[", Style::default().fg(Color::Yellow)),
-        Span::raw(": hunk "),
-        Span::styled("n/N", Style::default().fg(Color::Yellow)),
-        Span::raw(": change "),
-        Span::styled("v", Style::default().fg(Color::Yellow)),
-        Span::raw(": mode "),
-        Span::styled("/", Style::default().fg(Color::Yellow)),
-        Span::raw(": search | "),
-        Span::raw(format!(
-            "{} | Line {}/{} ({}%){}{}{}",
-            mode_str, current_line, total_lines, percent, hunk_info, h_scroll_info, search_info
-        )),
-    ]);
+    let position = Span::raw(format!(
+        "{} | Line {}/{} ({}%){}{}{}",
+        mode_str, current_line, total_lines, percent, hunk_info, h_scroll_info, search_info
+    ));
+    let status = match state.selection.status_label() {
+        Some(label) => Line::from(vec![
+            Span::styled(format!(" {} ", label), style.selection_style),
+            Span::styled(" v", Style::default().fg(Color::Yellow)),
+            Span::raw(": lines "),
+            Span::styled("^V", Style::default().fg(Color::Yellow)),
+            Span::raw(": block "),
+            Span::styled("y", Style::default().fg(Color::Yellow)),
+            Span::raw(": copy "),
+            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::raw(": exit | "),
+            position,
+        ]),
+        None => Line::from(vec![
+            Span::styled(" j/k", Style::default().fg(Color::Yellow)),
+            Span::raw(": scroll "),
+            Span::styled("]/[", Style::default().fg(Color::Yellow)),
+            Span::raw(": hunk "),
+            Span::styled("n/N", Style::default().fg(Color::Yellow)),
+            Span::raw(": change "),
+            Span::styled("v", Style::default().fg(Color::Yellow)),
+            Span::raw(": mode "),
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw(": search | "),
+            position,
+        ]),
+    };
 
     let para = Paragraph::new(status).style(Style::default().bg(Color::DarkGray));
     para.render(area, buf);
 }
 
 /// Render the search bar
-fn render_diff_search_bar(state: &DiffViewerState, area: Rect, buf: &mut Buffer) {
+fn render_diff_search_bar(
+    state: &DiffViewerState,
+    style: &DiffViewerStyle,
+    area: Rect,
+    buf: &mut Buffer,
+) {
     let search_line = Line::from(vec![
         Span::styled(" Search: ", Style::default().fg(Color::Yellow)),
         Span::raw(state.search.query.clone()),
-        Span::styled("▌", Style::default().fg(Color::White)),
+        Span::styled(style.search_cursor, Style::default().fg(Color::White)),
     ]);
 
     let para = Paragraph::new(search_line).style(Style::default().bg(Color::Rgb(40, 40, 60)));
@@ -1453,7 +2470,9 @@ fn render_diff_search_bar(state: &DiffViewerState, area: Rect, buf: &mut Buffer)
 
 /// Handle keyboard input for diff viewer
 ///
-/// Returns true if the key was handled
+/// Returns true if the key was handled. Esc is only handled while searching
+/// or in copy mode, so it otherwise propagates (see
+/// [`CloseRequest`](crate::events::CloseRequest)).
 pub fn handle_diff_viewer_key(state: &mut DiffViewerState, key: &KeyEvent) -> bool {
     // Search mode handling
     if state.search.active {
@@ -1480,6 +2499,21 @@ pub fn handle_diff_viewer_key(state: &mut DiffViewerState, key: &KeyEvent) -> bo
         }
     }
 
+    // Keyboard copy mode
+    if state.selection.active {
+        let lines: Vec<String> = state.copy_lines().into_iter().map(String::from).collect();
+        if handle_text_selection_key(&mut state.selection, key, &lines).is_some() {
+            let (height, width) = (state.visible_height, state.visible_width);
+            state.selection.scroll_into_view(
+                &mut state.scroll_y,
+                &mut state.scroll_x,
+                height,
+                width,
+            );
+            return true;
+        }
+    }
+
     match key.code {
         // Vertical scroll
         KeyCode::Char('j') | KeyCode::Down => {
@@ -1570,6 +2604,24 @@ pub fn handle_diff_viewer_key(state: &mut DiffViewerState, key: &KeyEvent) -> bo
             state.toggle_view_mode();
             true
         }
+        KeyCode::Char('w') => {
+            state.toggle_word_level();
+            true
+        }
+
+        // Side-by-side halves
+        KeyCode::Tab if state.view_mode == DiffViewMode::SideBySide => {
+            state.toggle_focused_side();
+            true
+        }
+        KeyCode::Char('<') if state.view_mode == DiffViewMode::SideBySide => {
+            state.shrink_left_side();
+            true
+        }
+        KeyCode::Char('>') if state.view_mode == DiffViewMode::SideBySide => {
+            state.grow_left_side();
+            true
+        }
 
         // Search
         KeyCode::Char('/') => {
@@ -1577,6 +2629,22 @@ pub fn handle_diff_viewer_key(state: &mut DiffViewerState, key: &KeyEvent) -> bo
             true
         }
 
+        // Copy mode
+        KeyCode::Char('c') if key.modifiers.is_empty() && !state.selection.active => {
+            state.enter_copy_mode();
+            true
+        }
+
+        // Export
+        KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.export_to_clipboard();
+            true
+        }
+        KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.copy_current_hunk_as_patch();
+            true
+        }
+
         _ => false,
     }
 }
@@ -1605,6 +2673,37 @@ pub fn handle_diff_viewer_mouse(
     }
 }
 
+fn diff_viewer_scrollbar(state: &DiffViewerState) -> InteractiveScrollbar {
+    InteractiveScrollbar::new(
+        ScrollbarOrientation::VerticalRight,
+        state.total_lines(),
+        state.scroll_y,
+    )
+}
+
+/// Handle mouse input on the diff viewer scrollbar
+///
+/// Clicking the arrows scrolls one line, clicking the track pages, and
+/// dragging the thumb scrolls proportionally. `registry` must come from
+/// [`DiffViewer::render_with_registry`].
+///
+/// Returns `true` if the event was consumed.
+pub fn handle_diff_viewer_scrollbar_mouse(
+    state: &mut DiffViewerState,
+    registry: &ClickRegionRegistry<ScrollbarPart>,
+    mouse: &MouseEvent,
+) -> bool {
+    let was_dragging = state.scrollbar_drag.is_dragging();
+    let scrollbar = diff_viewer_scrollbar(state);
+    match scrollbar.handle_mouse(registry.regions(), &mut state.scrollbar_drag, mouse) {
+        Some(position) => {
+            state.scroll_y = position;
+            true
+        }
+        None => was_dragging || state.scrollbar_drag.is_dragging(),
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1871,6 +2970,54 @@ mod tests {
         assert_eq!(style.side_separator, "│");
     }
 
+    #[test]
+    fn test_style_from_base_colors() {
+        let style = DiffViewerStyle::from_base_colors(
+            Color::Green,
+            Color::Rgb(0, 20, 0),
+            Color::Red,
+            Color::Rgb(20, 0, 0),
+            Color::Gray,
+        );
+        assert_eq!(style.addition_style.fg, Some(Color::Green));
+        assert_eq!(style.addition_bg, Color::Rgb(0, 20, 0));
+        assert_eq!(style.deletion_style.fg, Some(Color::Red));
+        assert_eq!(style.deletion_bg, Color::Rgb(20, 0, 0));
+        assert_eq!(style.context_style.fg, Some(Color::Gray));
+        assert_eq!(style.inline_addition_style.bg, Some(Color::Green));
+        assert_eq!(style.inline_deletion_style.fg, Some(Color::Rgb(20, 0, 0)));
+    }
+
+    #[test]
+    fn test_theme_presets() {
+        let style = DiffViewerStyle::from(DiffViewerTheme::GithubDark);
+        assert_eq!(style.addition_style.fg, Some(Color::Rgb(63, 185, 80)));
+        assert_eq!(style.deletion_style.fg, Some(Color::Rgb(248, 81, 73)));
+
+        let style = DiffViewerStyle::from(DiffViewerTheme::SolarizedDark);
+        assert_eq!(style.addition_style.fg, Some(Color::Rgb(133, 153, 0)));
+        assert_eq!(style.deletion_style.fg, Some(Color::Rgb(220, 50, 47)));
+
+        let style = DiffViewerStyle::from(DiffViewerTheme::Monokai);
+        assert_eq!(style.addition_style.fg, Some(Color::Rgb(166, 226, 46)));
+        assert_eq!(style.deletion_style.fg, Some(Color::Rgb(249, 38, 114)));
+
+        let style = DiffViewerStyle::from(DiffViewerTheme::default());
+        assert_eq!(style.addition_bg, DiffViewerStyle::default().addition_bg);
+    }
+
+    #[test]
+    fn test_widget_theme_accepts_preset_and_app_theme() {
+        let state = DiffViewerState::from_unified_diff(SAMPLE_DIFF);
+
+        let viewer = DiffViewer::new(&state).theme(DiffViewerTheme::Monokai);
+        assert_eq!(viewer.style.addition_bg, Color::Rgb(61, 74, 30));
+
+        let theme = crate::theme::Theme::dark();
+        let viewer = DiffViewer::new(&state).theme(&theme);
+        assert_eq!(viewer.style.addition_bg, theme.palette.diff_add_bg);
+    }
+
     #[test]
     fn test_key_handler_scroll() {
         let mut state = DiffViewerState::from_unified_diff(SAMPLE_DIFF);
@@ -1928,4 +3075,479 @@ mod tests {
         let mut buf = Buffer::empty(Rect::new(0, 0, 120, 20));
         viewer.render(Rect::new(0, 0, 120, 20), &mut buf);
     }
+
+    const WIDE_DIFF: &str =
+        "@@ -1,2 +1,2 @@ first\n ctx é\n-old ünï\n+new 你好\n@@ -9 +9,2 @@ second\n nine\n+ten\n";
+
+    fn render_rows(state: &DiffViewerState) -> Vec<String> {
+        let area = Rect::new(0, 0, 30, 6);
+        let mut buf = Buffer::empty(area);
+        DiffViewer::new(state)
+            .show_stats(false)
+            .render(area, &mut buf);
+        (1..4)
+            .map(|y| (1..28).map(|x| buf[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_scrolled_unified_rows() {
+        let mut state = DiffViewerState::from_unified_diff(WIDE_DIFF);
+        state.scroll_x = 2;
+        assert_eq!(
+            render_rows(&state),
+            vec![
+                " -1,2 +1,2 @@ first        ",
+                "  1   1 │  x é             ",
+                "  2     │ -d ünï           ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_side_by_side_skips_hunks_above() {
+        let mut state = DiffViewerState::from_unified_diff(WIDE_DIFF);
+        state.view_mode = DiffViewMode::SideBySide;
+        state.show_line_numbers = false;
+        state.scroll_y = 3;
+        assert_eq!(
+            render_rows(&state),
+            vec![
+                "@@ -9 +9,2 @@ second       ",
+                " nine        │ nine        ",
+                "             │+ten         ",
+            ]
+        );
+
+        let hunk = &state.diff.hunks[0];
+        assert_eq!(
+            side_by_side_row_count(&hunk.lines),
+            pair_lines_for_side_by_side(&hunk.lines).len()
+        );
+    }
+
+    fn assert_equivalent(a: &DiffData, b: &DiffData) {
+        assert_eq!(a.old_path, b.old_path);
+        assert_eq!(a.new_path, b.new_path);
+        assert_eq!(a.hunks.len(), b.hunks.len());
+        for (ha, hb) in a.hunks.iter().zip(&b.hunks) {
+            assert_eq!(
+                (ha.old_start, ha.old_count, ha.new_start, ha.new_count),
+                (hb.old_start, hb.old_count, hb.new_start, hb.new_count)
+            );
+            assert_eq!(ha.lines.len(), hb.lines.len());
+            for (la, lb) in ha.lines.iter().zip(&hb.lines) {
+                assert_eq!(la.line_type, lb.line_type);
+                assert_eq!(la.content, lb.content);
+                assert_eq!(la.old_line_num, lb.old_line_num);
+                assert_eq!(la.new_line_num, lb.new_line_num);
+            }
+        }
+    }
+
+    #[test]
+    fn test_export_unified_round_trip() {
+        let text = "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,3 +1,3 @@ fn main\n one\n-two\n+TWO\n \n@@ -10 +10,2 @@\n ten\n+eleven\n";
+        let state = DiffViewerState::from_unified_diff(text);
+        let exported = state.export_unified();
+
+        // Original headers, including section headings, are kept
+        assert!(
+            exported.starts_with("--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,3 +1,3 @@ fn main\n")
+        );
+        assert!(exported.contains("@@ -10 +10,2 @@\n ten\n+eleven\n"));
+        assert_equivalent(&state.diff, &DiffData::from_unified_diff(&exported));
+
+        let sample = DiffViewerState::from_unified_diff(SAMPLE_DIFF);
+        assert_eq!(sample.export_unified(), SAMPLE_DIFF);
+    }
+
+    #[test]
+    fn test_export_unified_new_file() {
+        let mut diff = DiffData::new(None, Some("new.txt".into()));
+        // No header text: it is generated from the ranges
+        let mut hunk = DiffHunk::new(String::new(), 0, 0, 1, 1);
+        hunk.add_line(DiffLine::addition("hello".into(), 1));
+        diff.hunks.push(hunk);
+        let state = DiffViewerState::new(diff);
+
+        assert_eq!(
+            state.export_unified(),
+            "--- /dev/null\n+++ b/new.txt\n@@ -0,0 +1,1 @@\n+hello\n"
+        );
+    }
+
+    #[test]
+    fn test_export_side_selection() {
+        let mut state = DiffViewerState::from_unified_diff(SAMPLE_DIFF);
+
+        let patch = state.export_side_selection().unwrap();
+        assert_eq!(patch, SAMPLE_DIFF);
+
+        state.set_view_mode(DiffViewMode::SideBySide);
+        assert_eq!(
+            state.export_side_selection().unwrap(),
+            "context line 1\nadded line\nanother added line\ncontext line 2\ncontext line 3\n"
+        );
+
+        assert_eq!(DiffViewerState::empty().export_side_selection(), None);
+    }
+
+    #[test]
+    fn test_format_hunk_as_patch_round_trip() {
+        let text = "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,3 +1,3 @@ fn main\n one\n-two\n+TWO\n \n@@ -10 +10,2 @@\n ten\n+eleven\n";
+        let diff = DiffData::from_unified_diff(text);
+
+        for hunk_idx in 0..diff.hunks.len() {
+            let patch = format_hunk_as_patch(&diff, hunk_idx);
+            assert!(patch.starts_with("--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ "));
+
+            let mut expected = DiffData::new(diff.old_path.clone(), diff.new_path.clone());
+            expected.hunks.push(diff.hunks[hunk_idx].clone());
+            assert_equivalent(&expected, &DiffData::from_unified_diff(&patch));
+        }
+        assert_eq!(
+            format_hunk_as_patch(&diff, 1),
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -10 +10,2 @@\n ten\n+eleven\n"
+        );
+        assert_eq!(format_hunk_as_patch(&diff, 2), "");
+    }
+
+    #[test]
+    fn test_copy_current_hunk_as_patch() {
+        let mut state = DiffViewerState::from_unified_diff(SAMPLE_DIFF);
+        assert!(state.copy_current_hunk_as_patch().is_some());
+        assert!(handle_diff_viewer_key(
+            &mut state,
+            &KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL)
+        ));
+
+        assert_eq!(DiffViewerState::empty().copy_current_hunk_as_patch(), None);
+    }
+
+    #[test]
+    fn test_copy_lines_follow_view_mode() {
+        let mut state = DiffViewerState::from_unified_diff(SAMPLE_DIFF);
+        let unified = state.copy_lines().len();
+        assert_eq!(unified, state.total_lines());
+        assert!(state.copy_lines()[0].starts_with("@@"));
+
+        state.enter_copy_mode();
+        state.set_view_mode(DiffViewMode::SideBySide);
+        assert!(!state.selection.active);
+        assert!(state.copy_lines().len() <= unified);
+    }
+
+    #[test]
+    fn test_copy_mode_keys_and_overlay() {
+        let diff = "@@ -1,2 +1,2 @@\n keep\n-old line\n+new line\n";
+        let mut state = DiffViewerState::from_unified_diff(diff);
+        state.show_line_numbers = false;
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert!(handle_diff_viewer_key(&mut state, &key('c')));
+        assert!(state.selection.active);
+        // 'v' selects instead of toggling the view mode
+        assert!(handle_diff_viewer_key(&mut state, &key('v')));
+        assert_eq!(state.view_mode, DiffViewMode::Unified);
+        assert!(handle_diff_viewer_key(&mut state, &key('j')));
+        assert!(handle_diff_viewer_key(&mut state, &key('j')));
+        assert_eq!(
+            state.selection.selected_text(&state.copy_lines()),
+            "@@ -1,2 +1,2 @@\nkeep\nold line"
+        );
+
+        let area = Rect::new(0, 0, 40, 7);
+        let mut buf = Buffer::empty(area);
+        DiffViewer::new(&state).render(area, &mut buf);
+        let selected = DiffViewerStyle::default().selection_style.bg.unwrap();
+        // Header row starts at the inner edge; content rows after the prefix
+        assert_eq!(buf[(1, 1)].bg, selected);
+        assert_eq!(buf[(2, 2)].bg, selected);
+        let status: String = (0..40).map(|x| buf[(x, 6)].symbol()).collect();
+        assert!(status.starts_with(" V-LINE "));
+
+        assert!(handle_diff_viewer_key(
+            &mut state,
+            &KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE)
+        ));
+        assert!(!state.selection.active);
+    }
+
+    /// 10k hunks of a header, two context lines, and eight changes
+    fn large_diff() -> DiffData {
+        let mut diff = DiffData::empty();
+        for h in 0..10_000 {
+            let start = h * 20 + 1;
+            let mut hunk =
+                DiffHunk::new(format!("@@ -{start},6 +{start},6 @@"), start, 6, start, 6);
+            hunk.add_line(DiffLine::context("ctx".into(), start, start));
+            for i in 0..4 {
+                hunk.add_line(DiffLine::deletion("old".into(), start + 1 + i));
+                hunk.add_line(DiffLine::addition("new".into(), start + 1 + i));
+            }
+            hunk.add_line(DiffLine::context("ctx".into(), start + 5, start + 5));
+            diff.hunks.push(hunk);
+        }
+        diff
+    }
+
+    #[test]
+    fn test_diff_mut_line_type_change_updates_navigation() {
+        let mut small = DiffData::empty();
+        small.hunks.push(large_diff().hunks.remove(0));
+        let mut state = DiffViewerState::new(small);
+        state.visible_height = 4;
+        state.scroll_y = 8;
+        state.next_change();
+        assert_eq!(state.scroll_y, 1, "wraps past the last change");
+
+        // Same shape, but the trailing context line becomes an addition
+        state.diff_mut().hunks[0].lines[9].line_type = DiffLineType::Addition;
+        state.scroll_y = 8;
+        state.next_change();
+        assert_eq!(state.scroll_y, 9);
+        assert_eq!(state.diff().hunks[0].lines.len(), 10);
+    }
+
+    #[test]
+    fn test_large_diff_navigation_uses_line_index() {
+        let builds = || LINE_INDEX_BUILDS.with(|builds| builds.get());
+        let before = builds();
+
+        let mut state = DiffViewerState::new(large_diff());
+        state.visible_height = 50;
+        assert_eq!(state.total_lines(), 110_000);
+
+        // Changes are rows 2..=9 of each 11-row hunk, shown from the row above
+        state.next_change();
+        assert_eq!(state.scroll_y, 1);
+        for _ in 0..8 {
+            state.next_change();
+        }
+        assert_eq!(state.scroll_y, 12);
+        state.prev_change();
+        assert_eq!(state.scroll_y, 8);
+
+        state.jump_to_hunk(9_999);
+        assert_eq!(state.scroll_y, 109_989);
+        state.next_change();
+        assert_eq!(state.scroll_y, 109_990);
+        state.go_to_line(109_997);
+        state.next_change();
+        assert_eq!(state.scroll_y, 1, "wraps to the first change");
+        state.go_to_top();
+        state.prev_change();
+        assert_eq!(state.scroll_y, 109_949, "continues from the bottom");
+
+        state.go_to_bottom();
+        assert_eq!(state.scroll_y, 109_950);
+        for _ in 0..100 {
+            state.scroll_down();
+            state.page_down();
+        }
+        assert_eq!(state.scroll_y, 109_950);
+        state.prev_hunk();
+        assert_eq!(state.scroll_y, 109_978);
+
+        assert_eq!(builds() - before, 1, "navigation reuses one line index");
+
+        // Replacing the diff rebuilds the index on next use
+        let mut small = DiffData::empty();
+        small.hunks.push(large_diff().hunks.remove(0));
+        state.set_diff(small);
+        state.go_to_bottom();
+        assert_eq!(state.total_lines(), 11);
+        state.diff_mut().hunks.clear();
+        assert_eq!(state.total_lines(), 0);
+        assert_eq!(builds() - before, 3);
+    }
+
+    #[test]
+    fn test_ascii_render() {
+        let diff: String = (1..=20).map(|i| format!("-old {i}\n+new {i}\n")).collect();
+        let mut state = DiffViewerState::from_unified_diff(&format!("@@ -1,20 +1,20 @@\n{diff}"));
+        state.search.active = true;
+        let style = DiffViewerStyle::from_capabilities(&Capabilities::basic());
+        assert_eq!(style.addition_bg, Color::Indexed(22));
+
+        for mode in [DiffViewMode::Unified, DiffViewMode::SideBySide] {
+            state.view_mode = mode;
+            let area = Rect::new(0, 0, 60, 12);
+            let mut buf = Buffer::empty(area);
+            DiffViewer::new(&state)
+                .style(style.clone())
+                .render(area, &mut buf);
+            assert!(buf.content().iter().all(|c| c.symbol().is_ascii()));
+        }
+    }
+
+    #[test]
+    fn test_set_diff_keyed_restores_position() {
+        let mut state = DiffViewerState::empty();
+        state.set_diff_keyed("a.txt", DiffData::from_unified_diff(SAMPLE_DIFF));
+        state.scroll_y = 4;
+        state.set_view_mode(DiffViewMode::Unified);
+
+        state.set_diff_keyed("b.txt", DiffData::empty());
+        assert_eq!(state.scroll_y, 0);
+        assert_eq!(state.selected_hunk, None);
+
+        state.set_diff_keyed("a.txt", DiffData::from_unified_diff(SAMPLE_DIFF));
+        assert_eq!(state.scroll_y, 4);
+        assert_eq!(state.view_mode, DiffViewMode::Unified);
+        assert_eq!(state.content_key.as_deref(), Some("a.txt"));
+
+        // A snapshot from a longer diff is clamped rather than trusted
+        state.apply_snapshot(&DiffViewerSnapshot {
+            scroll_y: 500,
+            scroll_x: 0,
+            selected_hunk: Some(9),
+            view_mode: DiffViewMode::SideBySide,
+            ..DiffViewerSnapshot::default()
+        });
+        assert_eq!(state.scroll_y, state.total_lines() - 1);
+        assert_eq!(state.selected_hunk, Some(0));
+
+        // Unkeyed content is not remembered
+        state.set_diff(DiffData::empty());
+        assert_eq!(state.content_key, None);
+    }
+
+    #[test]
+    fn test_side_by_side_independent_scroll_and_split() {
+        let mut state = DiffViewerState::from_unified_diff(WIDE_DIFF);
+        state.view_mode = DiffViewMode::SideBySide;
+        state.show_line_numbers = false;
+        state.scroll_y = 3;
+
+        // Tab picks the right half; h/l then leave the left half alone
+        assert!(handle_diff_viewer_key(
+            &mut state,
+            &KeyEvent::from(KeyCode::Tab)
+        ));
+        assert_eq!(state.focused_side, DiffSide::Right);
+        state.scroll_right();
+        assert_eq!((state.scroll_x, state.scroll_x_right), (0, 4));
+        state.scroll_x_right = 2;
+
+        for _ in 0..6 {
+            handle_diff_viewer_key(&mut state, &KeyEvent::from(KeyCode::Char('<')));
+        }
+        assert_eq!(state.split_ratio, DiffViewerState::SPLIT_RATIO_RANGE.0);
+        state.set_split_ratio(30);
+        assert_eq!(
+            render_rows(&state),
+            vec![
+                "@@ -9 +9,2 @@ second       ",
+                " nine   │ ne               ",
+                "        │+n                ",
+            ]
+        );
+
+        // Both offsets show in the status bar
+        let area = Rect::new(0, 0, 140, 6);
+        let mut buf = Buffer::empty(area);
+        DiffViewer::new(&state).render(area, &mut buf);
+        let status: String = (0..140).map(|x| buf[(x, 5)].symbol()).collect();
+        assert!(status.contains("Col: 1 / 3"), "{status}");
+
+        // The split and offsets are part of the saved view
+        let snapshot = state.snapshot();
+        state.set_diff(DiffData::from_unified_diff(WIDE_DIFF));
+        assert_eq!(state.scroll_x_right, 0);
+        state.split_ratio = 50;
+        state.apply_snapshot(&snapshot);
+        assert_eq!((state.split_ratio, state.scroll_x_right), (30, 2));
+
+        // Unified mode has no halves
+        state.set_view_mode(DiffViewMode::Unified);
+        assert!(!handle_diff_viewer_key(
+            &mut state,
+            &KeyEvent::from(KeyCode::Tab)
+        ));
+        state.scroll_right();
+        assert_eq!((state.scroll_x, state.scroll_x_right), (4, 2));
+    }
+
+    #[test]
+    fn test_word_diff_tokens() {
+        assert_eq!(
+            word_diff("timeout = 30;", "timeout = 60;"),
+            vec![
+                (WordOp::Equal, "timeout = "),
+                (WordOp::Delete, "30"),
+                (WordOp::Insert, "60"),
+                (WordOp::Equal, ";"),
+            ]
+        );
+        // Punctuation is compared per character
+        assert_eq!(
+            word_diff("a.b", "a,b"),
+            vec![
+                (WordOp::Equal, "a"),
+                (WordOp::Delete, "."),
+                (WordOp::Insert, ","),
+                (WordOp::Equal, "b"),
+            ]
+        );
+        assert_eq!(word_diff("", "new"), vec![(WordOp::Insert, "new")]);
+        assert_eq!(word_diff("same", "same"), vec![(WordOp::Equal, "same")]);
+    }
+
+    #[test]
+    fn test_compute_word_diff() {
+        let deletion = DiffLine::deletion("port: 80 # http".into(), 1);
+        let addition = DiffLine::addition("port: 8080 # http".into(), 1);
+        let (old, new) = DiffData::compute_word_diff(&deletion, &addition);
+        let style = DiffViewerStyle::default();
+
+        let text = |spans: &[Span]| spans.iter().map(|s| s.content.as_ref()).collect::<String>();
+        assert_eq!(text(&old), deletion.content);
+        assert_eq!(text(&new), addition.content);
+        assert_eq!(old[1].content, "80");
+        assert_eq!(old[1].style, style.inline_deletion_style);
+        assert_eq!(new[1].content, "8080");
+        assert_eq!(new[1].style, style.inline_addition_style);
+        assert_eq!(new[0].style, Style::default());
+    }
+
+    #[test]
+    fn test_render_word_level_merges_pairs() {
+        let text = "@@ -1,3 +1,3 @@\n ctx\n-key = 1\n+key = 2\n+extra\n";
+        let mut state = DiffViewerState::from_unified_diff(text);
+        state.show_line_numbers = false;
+
+        let key_w = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE);
+        assert!(handle_diff_viewer_key(&mut state, &key_w));
+        assert_eq!(state.view_mode, DiffViewMode::WordLevel);
+        assert_eq!(
+            state.copy_lines(),
+            vec!["@@ -1,3 +1,3 @@", "ctx", "key = 2", "extra"]
+        );
+
+        let area = Rect::new(0, 0, 30, 7);
+        let mut buf = Buffer::empty(area);
+        DiffViewer::new(&state)
+            .show_stats(false)
+            .render(area, &mut buf);
+        let rows: Vec<String> = (1..5)
+            .map(|y| (1..16).map(|x| buf[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                "@@ -1,3 +1,3 @@",
+                " ctx           ",
+                "~key = 12      ",
+                "+extra         ",
+            ]
+        );
+        let style = DiffViewerStyle::default();
+        assert_eq!(buf[(8, 3)].bg, style.inline_deletion_style.bg.unwrap());
+        assert_eq!(buf[(9, 3)].bg, style.inline_addition_style.bg.unwrap());
+
+        assert!(handle_diff_viewer_key(&mut state, &key_w));
+        assert_eq!(state.view_mode, DiffViewMode::Unified);
+    }
 }
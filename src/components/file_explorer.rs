@@ -18,10 +18,83 @@
 //! let explorer = FileExplorer::new(&state)
 //!     .title_format(|path| format!("Browse: {}", path.display()));
 //! ```
-
-use std::collections::HashSet;
-use std::path::PathBuf;
-
+//!
+//! # Virtual sources
+//!
+//! Entries come from a [`FileSource`]. [`LocalFs`] (with the `filesystem`
+//! feature) reads the local disk; implement the trait to browse archives,
+//! object stores, or remote hosts. Listing errors are shown above the
+//! entries instead of failing.
+//!
+//! ```rust
+//! use ratatui_interact::components::{FileEntry, FileExplorerState, FileSource};
+//! use std::path::{Path, PathBuf};
+//!
+//! #[derive(Debug)]
+//! struct Bucket;
+//!
+//! impl FileSource for Bucket {
+//!     fn list(&self, _path: &Path) -> std::io::Result<Vec<FileEntry>> {
+//!         Ok(vec![FileEntry::file("report.csv", PathBuf::from("/report.csv")).size(2048)])
+//!     }
+//! }
+//!
+//! let mut state = FileExplorerState::with_source(PathBuf::from("/"), Bucket);
+//! state.load_entries().unwrap();
+//! assert_eq!(state.entries[0].name, "report.csv");
+//! ```
+//!
+//! # Icons
+//!
+//! Files get an icon from [`FileExplorerStyle::file_icons`] by extension,
+//! falling back to [`FileExplorerStyle::default_file_icon`]. Use
+//! [`FileExplorerStyle::with_nerd_fonts`] or [`FileExplorerStyle::with_emoji`]
+//! for a pre-populated set.
+//!
+//! ```rust
+//! use ratatui_interact::components::FileExplorerStyle;
+//!
+//! let style = FileExplorerStyle::with_emoji().file_icon(".lock", "🔐");
+//! assert_eq!(style.icon_for_extension(Some("rs")), "🦀");
+//! assert_eq!(style.icon_for_extension(Some("lock")), "🔐");
+//! assert_eq!(style.icon_for_extension(None), "📄");
+//! ```
+//!
+//! # Multi-selection
+//!
+//! Selection is keyed by path, so it survives sorting, filtering, and
+//! reloads. [`handle_file_explorer_selection_key`] adds Shift+Up/Down range
+//! extension and `*` to invert; [`handle_file_explorer_mouse`] adds
+//! Shift+Click ranges and Ctrl+Click toggling. Both return
+//! [`FileExplorerAction::SelectionChanged`] when the selection changes.
+//!
+//! ```rust
+//! use ratatui_interact::components::{FileEntry, FileExplorerState};
+//! use std::path::PathBuf;
+//!
+//! let mut state = FileExplorerState::new(PathBuf::from("/tmp"));
+//! state.entries = vec![
+//!     FileEntry::file("a.log", PathBuf::from("/tmp/a.log")).size(1024),
+//!     FileEntry::file("b.log", PathBuf::from("/tmp/b.log")).size(512),
+//! ];
+//! state.select_range(0, 1);
+//! assert_eq!(state.selection_summary().as_deref(), Some("2 items, 1.5 KB"));
+//! ```
+//!
+//! # Search
+//!
+//! [`handle_file_explorer_search_key`] opens a search bar on Ctrl+F that
+//! filters the current directory as the user types. Enter picks the
+//! highlighted entry with [`FileExplorerAction::EntrySelected`]; Esc
+//! restores the full listing.
+
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
     buffer::Buffer,
@@ -30,7 +103,10 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
+use unicode_width::UnicodeWidthStr;
 
+use crate::state::{PageMove, PageSize, sync_selection};
+use crate::traits::EventResult;
 use crate::utils::display::format_size;
 
 /// Type of file system entry
@@ -58,6 +134,10 @@ pub struct FileEntry {
     pub path: PathBuf,
     /// Entry type
     pub entry_type: EntryType,
+    /// Last modification time, if known
+    pub modified: Option<SystemTime>,
+    /// Unix permission bits, if known
+    pub permissions: Option<u32>,
 }
 
 impl FileEntry {
@@ -67,16 +147,53 @@ impl FileEntry {
             name: name.into(),
             path,
             entry_type,
+            modified: None,
+            permissions: None,
         }
     }
 
+    /// Create a regular file entry with its extension taken from the path
+    pub fn file(name: impl Into<String>, path: PathBuf) -> Self {
+        let extension = path.extension().map(|e| e.to_string_lossy().to_string());
+        Self::new(name, path, EntryType::File { extension, size: 0 })
+    }
+
+    /// Create a directory entry
+    pub fn directory(name: impl Into<String>, path: PathBuf) -> Self {
+        Self::new(name, path, EntryType::Directory)
+    }
+
     /// Create a parent directory entry
     pub fn parent_dir(parent_path: PathBuf) -> Self {
-        Self {
-            name: "..".into(),
-            path: parent_path,
-            entry_type: EntryType::ParentDir,
+        Self::new("..", parent_path, EntryType::ParentDir)
+    }
+
+    /// Set the size (regular files only)
+    pub fn size(mut self, bytes: u64) -> Self {
+        if let EntryType::File { size, .. } = &mut self.entry_type {
+            *size = bytes;
         }
+        self
+    }
+
+    /// Set the last modification time
+    pub fn modified(mut self, modified: SystemTime) -> Self {
+        self.modified = Some(modified);
+        self
+    }
+
+    /// Set the Unix permission bits
+    pub fn permissions(mut self, mode: u32) -> Self {
+        self.permissions = Some(mode);
+        self
+    }
+
+    /// Mark this entry as a symbolic link to `target`
+    pub fn symlink_target(mut self, target: PathBuf) -> Self {
+        self.entry_type = EntryType::Symlink {
+            target: Some(target),
+        };
+        self
     }
 
     /// Check if this is a directory (including parent dir)
@@ -90,6 +207,110 @@ impl FileEntry {
     }
 }
 
+/// A source of directory listings for [`FileExplorerState`]
+///
+/// Implement this to browse things other than the local filesystem, such as
+/// archive listings, object stores, or remote hosts. Paths are opaque to the
+/// explorer; they only need to round-trip through the source.
+pub trait FileSource: std::fmt::Debug + Send + Sync {
+    /// List the entries in `path`, without a parent (`..`) entry
+    ///
+    /// The explorer filters hidden entries and sorts the result.
+    fn list(&self, path: &Path) -> io::Result<Vec<FileEntry>>;
+
+    /// Get the parent of `path`, or `None` at the root
+    fn parent(&self, path: &Path) -> Option<PathBuf> {
+        path.parent().map(Path::to_path_buf)
+    }
+
+    /// Check if `path` is the root of the source
+    fn is_root(&self, path: &Path) -> bool {
+        self.parent(path).is_none()
+    }
+}
+
+/// [`FileSource`] backed by the local filesystem via `std::fs`
+#[cfg(feature = "filesystem")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalFs;
+
+#[cfg(feature = "filesystem")]
+impl FileSource for LocalFs {
+    fn list(&self, path: &Path) -> io::Result<Vec<FileEntry>> {
+        let mut entries = Vec::new();
+
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            let metadata = entry.metadata()?;
+            let mut file_entry = if metadata.is_dir() {
+                FileEntry::directory(name, path)
+            } else if metadata.is_symlink() {
+                let target = std::fs::read_link(&path).ok();
+                FileEntry::new(name, path, EntryType::Symlink { target })
+            } else {
+                FileEntry::file(name, path).size(metadata.len())
+            };
+
+            if let Ok(modified) = metadata.modified() {
+                file_entry = file_entry.modified(modified);
+            }
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                file_entry = file_entry.permissions(metadata.permissions().mode());
+            }
+
+            entries.push(file_entry);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Actions emitted by the file explorer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileExplorerAction {
+    /// The multi-selection changed; carries the selected paths
+    SelectionChanged(HashSet<PathBuf>),
+    /// An entry was picked from the search results
+    EntrySelected(PathBuf),
+}
+
+/// How search queries match entry names (always case-insensitive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchMatchMode {
+    /// The name contains the query anywhere
+    #[default]
+    Contains,
+    /// The name starts with the query
+    Prefix,
+}
+
+impl SearchMatchMode {
+    /// Byte range of the first match of `query` in `name`
+    pub fn find(self, name: &str, query: &str) -> Option<std::ops::Range<usize>> {
+        let matches_at = |start: usize| {
+            let mut rest = name[start..].char_indices();
+            let mut end = start;
+            for q in query.chars() {
+                let (i, c) = rest.next()?;
+                if !c.to_lowercase().eq(q.to_lowercase()) {
+                    return None;
+                }
+                end = start + i + c.len_utf8();
+            }
+            Some(start..end)
+        };
+        match self {
+            SearchMatchMode::Prefix => matches_at(0),
+            SearchMatchMode::Contains => name.char_indices().find_map(|(i, _)| matches_at(i)),
+        }
+    }
+}
+
 /// Mode for the file explorer
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FileExplorerMode {
@@ -113,6 +334,8 @@ pub struct FileExplorerState {
     pub scroll: u16,
     /// Selected files (for multi-select)
     pub selected_files: HashSet<PathBuf>,
+    /// Entry that range selections extend from, set by the last toggle or click
+    pub selection_anchor: Option<PathBuf>,
     /// Whether to show hidden files
     pub show_hidden: bool,
     /// Current mode
@@ -121,67 +344,115 @@ pub struct FileExplorerState {
     pub search_query: String,
     /// Filtered entry indices (None = show all)
     pub filtered_indices: Option<Vec<usize>>,
+    /// How the search query matches entry names
+    pub search_match_mode: SearchMatchMode,
+    /// Error from the last listing, rendered above the entries
+    pub error: Option<String>,
+    /// How far PageUp/PageDown move the cursor
+    pub page_size: PageSize,
+    /// Source of directory listings (None = entries are set manually)
+    source: Option<Arc<dyn FileSource>>,
 }
 
 impl FileExplorerState {
     /// Create a new file explorer state
+    ///
+    /// Uses [`LocalFs`] when the `filesystem` feature is enabled; otherwise
+    /// entries must be set manually or a source given with
+    /// [`with_source`](Self::with_source).
     pub fn new(start_dir: PathBuf) -> Self {
+        #[cfg(feature = "filesystem")]
+        let source: Option<Arc<dyn FileSource>> = Some(Arc::new(LocalFs));
+        #[cfg(not(feature = "filesystem"))]
+        let source: Option<Arc<dyn FileSource>> = None;
+
         Self {
             current_dir: start_dir,
             entries: Vec::new(),
             cursor_index: 0,
             scroll: 0,
             selected_files: HashSet::new(),
+            selection_anchor: None,
             show_hidden: false,
             mode: FileExplorerMode::Browse,
             search_query: String::new(),
             filtered_indices: None,
+            search_match_mode: SearchMatchMode::default(),
+            error: None,
+            page_size: PageSize::Full,
+            source,
         }
     }
 
+    /// Create a file explorer state that lists entries from `source`
+    pub fn with_source(start_dir: PathBuf, source: impl FileSource + 'static) -> Self {
+        let mut state = Self::new(start_dir);
+        state.source = Some(Arc::new(source));
+        state
+    }
+
+    /// Set how far PageUp/PageDown move the cursor
+    pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Set how the search query matches entry names
+    pub fn with_search_match_mode(mut self, mode: SearchMatchMode) -> Self {
+        self.search_match_mode = mode;
+        self
+    }
+
+    /// Replace the source of directory listings
+    pub fn set_source(&mut self, source: impl FileSource + 'static) {
+        self.source = Some(Arc::new(source));
+    }
+
+    /// Get the source of directory listings
+    pub fn source(&self) -> Option<&dyn FileSource> {
+        self.source.as_deref()
+    }
+
     /// Load entries from the current directory
-    #[cfg(feature = "filesystem")]
-    pub fn load_entries(&mut self) -> std::io::Result<()> {
+    ///
+    /// On error, the entries are reduced to the parent entry and the message
+    /// is kept in [`error`](Self::error) for rendering. Does nothing without
+    /// a source.
+    pub fn load_entries(&mut self) -> io::Result<()> {
+        let Some(source) = self.source.clone() else {
+            return Ok(());
+        };
+
         self.entries.clear();
         self.cursor_index = 0;
         self.scroll = 0;
         self.filtered_indices = None;
+        self.error = None;
 
         // Add parent directory if not at root
-        if let Some(parent) = self.current_dir.parent() {
-            self.entries
-                .push(FileEntry::parent_dir(parent.to_path_buf()));
+        if !source.is_root(&self.current_dir)
+            && let Some(parent) = source.parent(&self.current_dir)
+        {
+            self.entries.push(FileEntry::parent_dir(parent));
         }
 
-        // Read directory entries
+        let listing = match source.list(&self.current_dir) {
+            Ok(listing) => listing,
+            Err(err) => {
+                self.error = Some(err.to_string());
+                return Err(err);
+            }
+        };
+
         let mut dirs = Vec::new();
         let mut files = Vec::new();
 
-        for entry in std::fs::read_dir(&self.current_dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            let name = entry.file_name().to_string_lossy().to_string();
-
+        for file_entry in listing {
             // Skip hidden files if not showing them
-            if !self.show_hidden && name.starts_with('.') {
+            if !self.show_hidden && file_entry.name.starts_with('.') {
                 continue;
             }
 
-            let metadata = entry.metadata()?;
-            let entry_type = if metadata.is_dir() {
-                EntryType::Directory
-            } else if metadata.is_symlink() {
-                EntryType::Symlink {
-                    target: std::fs::read_link(&path).ok(),
-                }
-            } else {
-                EntryType::File {
-                    extension: path.extension().map(|e| e.to_string_lossy().to_string()),
-                    size: metadata.len(),
-                }
-            };
-
-            let file_entry = FileEntry::new(name, path, entry_type);
             if file_entry.is_dir() {
                 dirs.push(file_entry);
             } else {
@@ -190,8 +461,8 @@ impl FileExplorerState {
         }
 
         // Sort: directories first (alphabetically), then files (alphabetically)
-        dirs.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
-        files.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        dirs.sort_by_key(|e| e.name.to_lowercase());
+        files.sort_by_key(|e| e.name.to_lowercase());
 
         self.entries.extend(dirs);
         self.entries.extend(files);
@@ -199,18 +470,67 @@ impl FileExplorerState {
         Ok(())
     }
 
+    /// Reload the current directory, keeping the cursor on the same path
+    ///
+    /// Unlike [`load_entries`](Self::load_entries), the search filter is
+    /// kept, and selected files that no longer exist are dropped.
+    pub fn reload_entries(&mut self) -> io::Result<()> {
+        let old_paths = self.visible_paths();
+        let (cursor, scroll) = (self.cursor_index, self.scroll as usize);
+        let result = self.load_entries();
+        self.update_filter();
+        self.restore_cursor(&old_paths, cursor, scroll);
+        result
+    }
+
+    /// Replace the entries, keeping the cursor on the same path
+    ///
+    /// The cursor follows its entry's path and stays on the same viewport
+    /// row when possible; if the entry is gone it falls back to the nearest
+    /// index (see [`sync_selection`]). Selected files that are no longer
+    /// listed are dropped.
+    pub fn sync_entries(&mut self, entries: Vec<FileEntry>) {
+        let old_paths = self.visible_paths();
+        let (cursor, scroll) = (self.cursor_index, self.scroll as usize);
+        self.entries = entries;
+        self.update_filter();
+        self.restore_cursor(&old_paths, cursor, scroll);
+    }
+
+    /// Paths of the visible entries, in display order
+    fn visible_paths(&self) -> Vec<PathBuf> {
+        match &self.filtered_indices {
+            Some(indices) => indices
+                .iter()
+                .filter_map(|&i| self.entries.get(i))
+                .map(|e| e.path.clone())
+                .collect(),
+            None => self.entries.iter().map(|e| e.path.clone()).collect(),
+        }
+    }
+
+    fn restore_cursor(&mut self, old_paths: &[PathBuf], cursor: usize, scroll: usize) {
+        let (cursor, scroll) = sync_selection(old_paths, &self.visible_paths(), cursor, scroll);
+        self.cursor_index = cursor;
+        self.scroll = scroll.min(u16::MAX as usize) as u16;
+        self.selected_files
+            .retain(|path| self.entries.iter().any(|e| &e.path == path));
+    }
+
     /// Navigate into a directory
     pub fn enter_directory(&mut self, path: PathBuf) {
         self.current_dir = path;
-        #[cfg(feature = "filesystem")]
         let _ = self.load_entries();
     }
 
     /// Navigate up to parent directory
     pub fn go_up(&mut self) {
-        if let Some(parent) = self.current_dir.parent() {
-            self.current_dir = parent.to_path_buf();
-            #[cfg(feature = "filesystem")]
+        let parent = match &self.source {
+            Some(source) => source.parent(&self.current_dir),
+            None => self.current_dir.parent().map(Path::to_path_buf),
+        };
+        if let Some(parent) = parent {
+            self.current_dir = parent;
             let _ = self.load_entries();
         }
     }
@@ -241,29 +561,114 @@ impl FileExplorerState {
 
     /// Get the currently selected entry
     pub fn current_entry(&self) -> Option<&FileEntry> {
+        self.visible_entry(self.cursor_index)
+    }
+
+    /// Get the entry shown at display index `index`
+    pub fn visible_entry(&self, index: usize) -> Option<&FileEntry> {
         if let Some(ref indices) = self.filtered_indices {
-            indices
-                .get(self.cursor_index)
-                .and_then(|&i| self.entries.get(i))
+            indices.get(index).and_then(|&i| self.entries.get(i))
         } else {
-            self.entries.get(self.cursor_index)
+            self.entries.get(index)
         }
     }
 
     /// Toggle selection of current file
+    ///
+    /// The entry becomes the anchor for range selections.
     pub fn toggle_selection(&mut self) {
         if let Some(entry) = self.current_entry() {
-            if entry.is_selectable() {
-                let path = entry.path.clone();
-                if self.selected_files.contains(&path) {
-                    self.selected_files.remove(&path);
-                } else {
-                    self.selected_files.insert(path);
-                }
+            let path = entry.path.clone();
+            if entry.is_selectable() && !self.selected_files.remove(&path) {
+                self.selected_files.insert(path.clone());
             }
+            self.selection_anchor = Some(path);
         }
     }
 
+    /// Select the files shown between display indices `from` and `to`
+    /// (inclusive, in either order), replacing the current selection
+    pub fn select_range(&mut self, from: usize, to: usize) {
+        let (start, end) = (from.min(to), from.max(to));
+        let paths: HashSet<PathBuf> = (start..=end)
+            .filter_map(|index| self.visible_entry(index))
+            .filter(|entry| entry.is_selectable())
+            .map(|entry| entry.path.clone())
+            .collect();
+        self.selected_files = paths;
+    }
+
+    /// Display index of the selection anchor, falling back to the cursor
+    ///
+    /// Sets the anchor to the cursor entry when it is unset or not shown.
+    fn anchor_index(&mut self) -> usize {
+        let found = self.selection_anchor.as_ref().and_then(|anchor| {
+            (0..self.visible_count())
+                .find(|&i| self.visible_entry(i).is_some_and(|e| &e.path == anchor))
+        });
+        match found {
+            Some(index) => index,
+            None => {
+                self.selection_anchor = self.current_entry().map(|e| e.path.clone());
+                self.cursor_index
+            }
+        }
+    }
+
+    /// Move the cursor up, selecting the range from the anchor to the cursor
+    pub fn extend_selection_up(&mut self) {
+        let anchor = self.anchor_index();
+        self.cursor_up();
+        self.select_range(anchor, self.cursor_index);
+    }
+
+    /// Move the cursor down, selecting the range from the anchor to the cursor
+    pub fn extend_selection_down(&mut self) {
+        let anchor = self.anchor_index();
+        self.cursor_down();
+        self.select_range(anchor, self.cursor_index);
+    }
+
+    /// Invert the selection of all files
+    pub fn invert_selection(&mut self) {
+        let inverted: HashSet<PathBuf> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.is_selectable() && !self.selected_files.contains(&entry.path))
+            .map(|entry| entry.path.clone())
+            .collect();
+        self.selected_files = inverted;
+    }
+
+    /// Total size of the selected files in bytes
+    pub fn selected_size(&self) -> u64 {
+        self.entries
+            .iter()
+            .filter(|entry| self.selected_files.contains(&entry.path))
+            .map(|entry| match entry.entry_type {
+                EntryType::File { size, .. } => size,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Summary of the selection for a footer or title, e.g. "5 items, 12.3 MB"
+    ///
+    /// Returns `None` when nothing is selected.
+    pub fn selection_summary(&self) -> Option<String> {
+        let count = self.selected_files.len();
+        if count == 0 {
+            return None;
+        }
+        let noun = if count == 1 { "item" } else { "items" };
+        Some(format!(
+            "{} {}, {}",
+            count,
+            noun,
+            format_size(self.selected_size())
+        ))
+    }
+
     /// Select all files
     pub fn select_all(&mut self) {
         for entry in &self.entries {
@@ -281,7 +686,6 @@ impl FileExplorerState {
     /// Toggle hidden files visibility
     pub fn toggle_hidden(&mut self) {
         self.show_hidden = !self.show_hidden;
-        #[cfg(feature = "filesystem")]
         let _ = self.load_entries();
     }
 
@@ -291,11 +695,16 @@ impl FileExplorerState {
         self.search_query.clear();
     }
 
-    /// Exit search mode
+    /// Exit search mode, restoring the full listing
+    ///
+    /// The cursor stays on the entry it was on.
     pub fn cancel_search(&mut self) {
+        let old_paths = self.visible_paths();
+        let (cursor, scroll) = (self.cursor_index, self.scroll as usize);
         self.mode = FileExplorerMode::Browse;
         self.search_query.clear();
         self.filtered_indices = None;
+        self.restore_cursor(&old_paths, cursor, scroll);
     }
 
     /// Update search filter
@@ -303,16 +712,17 @@ impl FileExplorerState {
         if self.search_query.is_empty() {
             self.filtered_indices = None;
         } else {
-            let query = self.search_query.to_lowercase();
+            let mode = self.search_match_mode;
             self.filtered_indices = Some(
                 self.entries
                     .iter()
                     .enumerate()
-                    .filter(|(_, e)| e.name.to_lowercase().contains(&query))
+                    .filter(|(_, e)| mode.find(&e.name, &self.search_query).is_some())
                     .map(|(i, _)| i)
                     .collect(),
             );
             self.cursor_index = 0;
+            self.scroll = 0;
         }
     }
 
@@ -350,11 +760,23 @@ pub struct FileExplorerStyle {
     /// Checkbox unchecked
     pub checkbox_unchecked: &'static str,
     /// Directory icon
-    pub dir_icon: &'static str,
+    pub dir_icon: String,
     /// Parent directory icon
     pub parent_icon: &'static str,
     /// Symlink icon
     pub symlink_icon: &'static str,
+    /// File icons by extension (e.g. `".rs"`; the leading dot is optional)
+    pub file_icons: HashMap<String, String>,
+    /// Icon for files with no entry in `file_icons`
+    pub default_file_icon: String,
+    /// Display width of the icon column; shorter icons are padded
+    pub icon_width: u16,
+    /// Style for listing errors
+    pub error_style: Style,
+    /// Style for the search bar
+    pub search_bar_style: Style,
+    /// Style patched onto the part of each name matching the search query
+    pub search_match_style: Style,
 }
 
 impl Default for FileExplorerStyle {
@@ -380,9 +802,17 @@ impl Default for FileExplorerStyle {
             size_style: Style::default().fg(Color::DarkGray),
             checkbox_checked: "[x]",
             checkbox_unchecked: "[ ]",
-            dir_icon: "[DIR]",
-            parent_icon: " .. ",
-            symlink_icon: "[LNK]",
+            dir_icon: "📁".to_string(),
+            parent_icon: "..",
+            symlink_icon: "🔗",
+            file_icons: HashMap::new(),
+            default_file_icon: "📄".to_string(),
+            icon_width: 2,
+            error_style: Style::default().fg(Color::Red),
+            search_bar_style: Style::default().fg(Color::Yellow),
+            search_match_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::UNDERLINED),
         }
     }
 }
@@ -411,14 +841,117 @@ impl From<&crate::theme::Theme> for FileExplorerStyle {
             size_style: Style::default().fg(Color::DarkGray),
             checkbox_checked: "[x]",
             checkbox_unchecked: "[ ]",
-            dir_icon: "[DIR]",
-            parent_icon: " .. ",
-            symlink_icon: "[LNK]",
+            dir_icon: "📁".to_string(),
+            parent_icon: "..",
+            symlink_icon: "🔗",
+            file_icons: HashMap::new(),
+            default_file_icon: "📄".to_string(),
+            icon_width: 2,
+            error_style: Style::default().fg(p.error),
+            search_bar_style: Style::default().fg(p.warning),
+            search_match_style: Style::default()
+                .fg(p.warning)
+                .add_modifier(Modifier::UNDERLINED),
         }
     }
 }
 
+/// Build a file icon map from `(extensions, icon)` groups
+fn icon_map(groups: &[(&[&str], &str)]) -> HashMap<String, String> {
+    groups
+        .iter()
+        .flat_map(|(extensions, icon)| {
+            extensions
+                .iter()
+                .map(move |ext| (format!(".{}", ext), icon.to_string()))
+        })
+        .collect()
+}
+
 impl FileExplorerStyle {
+    /// Create a style with Nerd Font icons for common file types
+    ///
+    /// Requires a terminal font patched with Nerd Font glyphs.
+    pub fn with_nerd_fonts() -> Self {
+        Self {
+            dir_icon: "\u{f07b}".to_string(),
+            parent_icon: "\u{f062}",
+            symlink_icon: "\u{f0c1}",
+            file_icons: icon_map(&[
+                (&["rs"], "\u{e7a8}"),
+                (&["py"], "\u{e73c}"),
+                (&["js", "jsx", "mjs"], "\u{e74e}"),
+                (&["ts", "tsx"], "\u{e628}"),
+                (&["json"], "\u{e60b}"),
+                (&["toml", "yaml", "yml", "ini", "cfg"], "\u{e615}"),
+                (&["md", "rst"], "\u{e73e}"),
+                (&["txt", "log"], "\u{f15c}"),
+                (&["sh", "bash", "zsh", "fish"], "\u{f489}"),
+                (&["html", "htm"], "\u{e736}"),
+                (&["css", "scss"], "\u{e749}"),
+                (&["go"], "\u{e626}"),
+                (&["c", "h"], "\u{e61e}"),
+                (&["cpp", "cc", "hpp"], "\u{e61d}"),
+                (&["java"], "\u{e738}"),
+                (&["lock"], "\u{f023}"),
+                (&["png", "jpg", "jpeg", "gif", "svg"], "\u{f1c5}"),
+                (&["zip", "tar", "gz", "xz", "7z"], "\u{f410}"),
+            ]),
+            default_file_icon: "\u{f15b}".to_string(),
+            icon_width: 2,
+            ..Default::default()
+        }
+    }
+
+    /// Create a style with emoji icons for common file types
+    ///
+    /// For terminals without Nerd Fonts.
+    pub fn with_emoji() -> Self {
+        Self {
+            file_icons: icon_map(&[
+                (&["rs"], "🦀"),
+                (&["py"], "🐍"),
+                (&["js", "jsx", "mjs", "ts", "tsx"], "📜"),
+                (&["json"], "📋"),
+                (&["toml", "yaml", "yml", "ini", "cfg"], "🔧"),
+                (&["md", "rst", "txt", "log"], "📝"),
+                (&["sh", "bash", "zsh", "fish"], "💻"),
+                (&["html", "htm"], "🌐"),
+                (&["css", "scss"], "🎨"),
+                (&["lock"], "🔒"),
+                (&["png", "jpg", "jpeg", "gif", "svg"], "🎨"),
+                (&["zip", "tar", "gz", "xz", "7z"], "📦"),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    /// Set the icon for a file extension
+    pub fn file_icon(mut self, extension: impl Into<String>, icon: impl Into<String>) -> Self {
+        self.file_icons.insert(extension.into(), icon.into());
+        self
+    }
+
+    /// Set the icon column width
+    pub fn icon_width(mut self, width: u16) -> Self {
+        self.icon_width = width;
+        self
+    }
+
+    /// Get the icon for a file extension
+    ///
+    /// Looks the extension up with and without a leading dot, then in lower
+    /// case, before falling back to the default file icon.
+    pub fn icon_for_extension(&self, ext: Option<&str>) -> &str {
+        let lookup = |ext: &str| {
+            self.file_icons
+                .get(&format!(".{}", ext))
+                .or_else(|| self.file_icons.get(ext))
+        };
+        ext.and_then(|ext| lookup(ext).or_else(|| lookup(&ext.to_lowercase())))
+            .map_or(self.default_file_icon.as_str(), String::as_str)
+    }
+
     /// Get color for a file extension
     pub fn color_for_extension(&self, ext: Option<&str>) -> Color {
         if let Some(ext) = ext {
@@ -504,7 +1037,7 @@ impl<'a> FileExplorer<'a> {
 
             let (icon, name_style) = match &entry.entry_type {
                 EntryType::Directory => (
-                    self.style.dir_icon,
+                    self.style.dir_icon.as_str(),
                     if is_cursor {
                         self.style.cursor_style
                     } else {
@@ -522,7 +1055,7 @@ impl<'a> FileExplorer<'a> {
                 EntryType::File { extension, .. } => {
                     let color = self.style.color_for_extension(extension.as_deref());
                     (
-                        "     ",
+                        self.style.icon_for_extension(extension.as_deref()),
                         if is_cursor {
                             self.style.cursor_style
                         } else {
@@ -545,34 +1078,61 @@ impl<'a> FileExplorer<'a> {
                 _ => String::new(),
             };
 
+            // Pad the icon so names line up across rows
+            let icon_width = self.style.icon_width as usize;
+            let icon = format!(
+                "{}{}",
+                icon,
+                " ".repeat(icon_width.saturating_sub(icon.width()))
+            );
+
             // Calculate name width
-            let name_width = inner.width.saturating_sub(22) as usize;
+            let name_width = inner.width.saturating_sub(17 + self.style.icon_width) as usize;
             let display_name = if entry.name.len() > name_width {
                 format!("{}...", &entry.name[..name_width.saturating_sub(3)])
             } else {
                 entry.name.clone()
             };
 
-            lines.push(Line::from(vec![
+            let mut spans = vec![
                 Span::styled(cursor.to_string(), style),
                 Span::styled(" ", style),
                 Span::styled(checkbox.to_string(), style),
                 Span::styled(" ", style),
-                Span::styled(icon.to_string(), style),
+                Span::styled(icon, style),
                 Span::styled(" ", style),
-                Span::styled(
-                    format!("{:<width$}", display_name, width = name_width),
-                    name_style,
-                ),
-                Span::styled(
-                    format!("{:>10}", size_str),
-                    if is_cursor {
-                        self.style.cursor_style
-                    } else {
-                        self.style.size_style
-                    },
-                ),
-            ]));
+            ];
+
+            // Name, with the search match highlighted
+            let name = format!("{:<width$}", display_name, width = name_width);
+            let matched = (!self.state.search_query.is_empty())
+                .then(|| {
+                    self.state
+                        .search_match_mode
+                        .find(&display_name, &self.state.search_query)
+                })
+                .flatten();
+            match matched {
+                Some(range) => {
+                    spans.push(Span::styled(name[..range.start].to_string(), name_style));
+                    spans.push(Span::styled(
+                        name[range.clone()].to_string(),
+                        name_style.patch(self.style.search_match_style),
+                    ));
+                    spans.push(Span::styled(name[range.end..].to_string(), name_style));
+                }
+                None => spans.push(Span::styled(name, name_style)),
+            }
+
+            spans.push(Span::styled(
+                format!("{:>10}", size_str),
+                if is_cursor {
+                    self.style.cursor_style
+                } else {
+                    self.style.size_style
+                },
+            ));
+            lines.push(Line::from(spans));
         }
 
         lines
@@ -581,25 +1141,12 @@ impl<'a> FileExplorer<'a> {
 
 impl Widget for FileExplorer<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        // Main layout
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Min(1),    // File list
-                Constraint::Length(3), // Footer
-            ])
-            .split(area);
-
-        // Title with path and selection count
-        let selected_count = self.state.selected_files.len();
-        let title = if selected_count > 0 {
-            format!(
-                " {} ({} selected) ",
-                self.state.current_dir.display(),
-                selected_count
-            )
-        } else {
-            format!(" {} ", self.state.current_dir.display())
+        let chunks = split_layout(area);
+
+        // Title with path and selection summary
+        let title = match self.state.selection_summary() {
+            Some(summary) => format!(" {} ({}) ", self.state.current_dir.display(), summary),
+            None => format!(" {} ", self.state.current_dir.display()),
         };
 
         let block = Block::default()
@@ -607,16 +1154,39 @@ impl Widget for FileExplorer<'_> {
             .border_style(self.style.border_style)
             .title(title);
 
-        let inner = block.inner(chunks[0]);
+        let mut inner = block.inner(chunks[0]);
         block.render(chunks[0], buf);
 
+        // Listing error
+        if let Some(ref error) = self.state.error
+            && inner.height > 0
+        {
+            let error_line = Line::from(Span::styled(
+                format!(" ⚠ {}", error),
+                self.style.error_style,
+            ));
+            Paragraph::new(error_line).render(Rect { height: 1, ..inner }, buf);
+            inner.y += 1;
+            inner.height -= 1;
+        }
+
         // File list
         let lines = self.build_lines(inner);
         let paragraph = Paragraph::new(lines);
         paragraph.render(inner, buf);
 
-        // Footer
-        let footer = build_footer(self.state.mode);
+        // Footer, led by the search bar while searching
+        let mut footer = build_footer(self.state.mode);
+        if self.state.mode == FileExplorerMode::Search {
+            let bar = self.style.search_bar_style;
+            let search_line = Line::from(vec![
+                Span::styled("Search: ", bar.add_modifier(Modifier::BOLD)),
+                Span::styled(self.state.search_query.clone(), bar),
+                Span::styled("_", bar.add_modifier(Modifier::SLOW_BLINK)),
+            ])
+            .alignment(Alignment::Left);
+            footer.insert(0, search_line);
+        }
         let footer_block = Block::default()
             .borders(Borders::TOP)
             .border_style(Style::default().fg(Color::DarkGray));
@@ -627,6 +1197,168 @@ impl Widget for FileExplorer<'_> {
     }
 }
 
+/// Split the widget area into the file list and the footer
+fn split_layout(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // File list
+            Constraint::Length(3), // Footer
+        ])
+        .split(area)
+}
+
+/// Handle the cursor keys in browse mode
+///
+/// Up/Down and the [paging keys](crate::state::PageMove) move the cursor
+/// and keep it within a viewport of `viewport_height` rows; pages are
+/// [`FileExplorerState::page_size`] rows. Shift+Up/Down are left to
+/// [`handle_file_explorer_selection_key`].
+///
+/// Returns `true` if the key was handled.
+pub fn handle_file_explorer_nav_key(
+    key: &KeyEvent,
+    state: &mut FileExplorerState,
+    viewport_height: usize,
+) -> bool {
+    if state.mode != FileExplorerMode::Browse || key.modifiers.contains(KeyModifiers::SHIFT) {
+        return false;
+    }
+    if let Some(page_move) = PageMove::from_key(key) {
+        state.cursor_index = page_move.apply(
+            state.cursor_index,
+            state.visible_count(),
+            viewport_height,
+            state.page_size,
+        );
+    } else {
+        match key.code {
+            KeyCode::Up => state.cursor_up(),
+            KeyCode::Down => state.cursor_down(),
+            _ => return false,
+        }
+    }
+    state.ensure_visible(viewport_height);
+    true
+}
+
+/// Handle the multi-selection keys in browse mode
+///
+/// Shift+Up/Down move the cursor and select the range from the anchor to
+/// it; `*` inverts the selection. Returns
+/// [`FileExplorerAction::SelectionChanged`] when the selection changed.
+pub fn handle_file_explorer_selection_key(
+    key: &KeyEvent,
+    state: &mut FileExplorerState,
+) -> Option<FileExplorerAction> {
+    if state.mode != FileExplorerMode::Browse {
+        return None;
+    }
+    let before = state.selected_files.clone();
+    let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+    match key.code {
+        KeyCode::Up if shift => state.extend_selection_up(),
+        KeyCode::Down if shift => state.extend_selection_down(),
+        KeyCode::Char('*') => state.invert_selection(),
+        _ => return None,
+    }
+    selection_changed(state, before)
+}
+
+/// Handle a left click on the file list rendered in `area`
+///
+/// A plain click moves the cursor and sets the range anchor, Shift+Click
+/// selects from the anchor to the clicked entry, and Ctrl+Click toggles the
+/// clicked entry without clearing others. Returns
+/// [`FileExplorerAction::SelectionChanged`] when the selection changed.
+pub fn handle_file_explorer_mouse(
+    mouse: &MouseEvent,
+    state: &mut FileExplorerState,
+    area: Rect,
+) -> Option<FileExplorerAction> {
+    if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+        return None;
+    }
+    let mut list = Block::default()
+        .borders(Borders::ALL)
+        .inner(split_layout(area)[0]);
+    if state.error.is_some() && list.height > 0 {
+        list.y += 1;
+        list.height -= 1;
+    }
+    if !list.contains((mouse.column, mouse.row).into()) {
+        return None;
+    }
+    let index = state.scroll as usize + (mouse.row - list.y) as usize;
+    let path = state.visible_entry(index)?.path.clone();
+
+    let before = state.selected_files.clone();
+    if mouse.modifiers.contains(KeyModifiers::SHIFT) {
+        let anchor = state.anchor_index();
+        state.cursor_index = index;
+        state.select_range(anchor, index);
+    } else if mouse.modifiers.contains(KeyModifiers::CONTROL) {
+        state.cursor_index = index;
+        state.toggle_selection();
+    } else {
+        state.cursor_index = index;
+        state.selection_anchor = Some(path);
+    }
+    selection_changed(state, before)
+}
+
+/// Handle typing-to-search keys
+///
+/// In browse mode Ctrl+F starts a search. While searching, typed characters
+/// and Backspace edit the query and refilter the current directory,
+/// Up/Down move through the matches, Enter picks the highlighted entry
+/// ([`FileExplorerAction::EntrySelected`]) and Esc restores the full
+/// listing. Both Enter and Esc leave the cursor on the entry it was on.
+pub fn handle_file_explorer_search_key(
+    key: &KeyEvent,
+    state: &mut FileExplorerState,
+) -> EventResult<FileExplorerAction> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    if state.mode == FileExplorerMode::Browse {
+        if ctrl && matches!(key.code, KeyCode::Char('f' | 'F')) {
+            state.start_search();
+            return EventResult::Consumed;
+        }
+        return EventResult::NotHandled;
+    }
+
+    match key.code {
+        KeyCode::Esc => state.cancel_search(),
+        KeyCode::Enter => {
+            let Some(path) = state.current_entry().map(|e| e.path.clone()) else {
+                return EventResult::Consumed;
+            };
+            state.cancel_search();
+            return EventResult::Action(FileExplorerAction::EntrySelected(path));
+        }
+        KeyCode::Up => state.cursor_up(),
+        KeyCode::Down => state.cursor_down(),
+        KeyCode::Backspace => {
+            state.search_query.pop();
+            state.update_filter();
+        }
+        KeyCode::Char(c) if !ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+            state.search_query.push(c);
+            state.update_filter();
+        }
+        _ => return EventResult::NotHandled,
+    }
+    EventResult::Consumed
+}
+
+fn selection_changed(
+    state: &FileExplorerState,
+    before: HashSet<PathBuf>,
+) -> Option<FileExplorerAction> {
+    (state.selected_files != before)
+        .then(|| FileExplorerAction::SelectionChanged(state.selected_files.clone()))
+}
+
 /// Build footer lines based on current mode
 fn build_footer(mode: FileExplorerMode) -> Vec<Line<'static>> {
     match mode {
@@ -648,6 +1380,8 @@ fn build_footer(mode: FileExplorerMode) -> Vec<Line<'static>> {
                 Span::raw(":All "),
                 Span::styled("n", Style::default().fg(Color::Green)),
                 Span::raw(":None "),
+                Span::styled("*", Style::default().fg(Color::Green)),
+                Span::raw(":Invert "),
                 Span::styled("Esc", Style::default().fg(Color::Green)),
                 Span::raw(":Close"),
             ]),
@@ -1038,4 +1772,437 @@ mod tests {
         explorer.render(Rect::new(0, 0, 60, 20), &mut buf);
         // Should not panic
     }
+
+    #[test]
+    fn test_style_icon_for_extension() {
+        let style = FileExplorerStyle::default()
+            .file_icon(".rs", "R")
+            .file_icon("md", "M");
+        assert_eq!(style.icon_for_extension(Some("rs")), "R");
+        assert_eq!(style.icon_for_extension(Some("RS")), "R");
+        assert_eq!(style.icon_for_extension(Some("md")), "M");
+        assert_eq!(style.icon_for_extension(Some("zip")), "📄");
+        assert_eq!(style.icon_for_extension(None), "📄");
+
+        let nerd = FileExplorerStyle::with_nerd_fonts();
+        assert_eq!(nerd.icon_for_extension(Some("rs")), "\u{e7a8}");
+        assert_eq!(nerd.icon_for_extension(Some("unknown")), "\u{f15b}");
+        assert_eq!(
+            FileExplorerStyle::with_emoji().icon_for_extension(Some("json")),
+            "📋"
+        );
+    }
+
+    #[test]
+    fn test_icon_column_aligns_names() {
+        let mut state = FileExplorerState::new(PathBuf::from("/tmp"));
+        state.entries = vec![
+            FileEntry::directory("dir", PathBuf::from("/tmp/dir")),
+            FileEntry::file("main.rs", PathBuf::from("/tmp/main.rs")),
+            FileEntry::file("notes", PathBuf::from("/tmp/notes")),
+        ];
+        let style = FileExplorerStyle::with_emoji()
+            .file_icon(".rs", "R")
+            .icon_width(3);
+
+        let area = Rect::new(0, 0, 40, 8);
+        let mut buf = Buffer::empty(area);
+        FileExplorer::new(&state)
+            .style(style)
+            .render(area, &mut buf);
+
+        // Border, cursor, space, checkbox, space, icon column, space
+        let name_x = 1 + 6 + 3 + 1;
+        assert_eq!(buf[(7, 1)].symbol(), "📁");
+        assert_eq!(buf[(name_x, 1)].symbol(), "d");
+        assert_eq!(buf[(7, 2)].symbol(), "R");
+        assert_eq!(buf[(name_x, 2)].symbol(), "m");
+        assert_eq!(buf[(7, 3)].symbol(), "📄");
+        assert_eq!(buf[(name_x, 3)].symbol(), "n");
+    }
+
+    /// In-memory source keyed by directory path; "/" is the root
+    #[derive(Debug, Default)]
+    struct MemSource {
+        dirs: std::collections::HashMap<PathBuf, Vec<FileEntry>>,
+    }
+
+    impl FileSource for MemSource {
+        fn list(&self, path: &Path) -> io::Result<Vec<FileEntry>> {
+            self.dirs.get(path).cloned().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("{} not found", path.display()),
+                )
+            })
+        }
+    }
+
+    fn mem_source() -> MemSource {
+        let mut source = MemSource::default();
+        source.dirs.insert(
+            PathBuf::from("/"),
+            vec![
+                FileEntry::file("b.txt", PathBuf::from("/b.txt")).size(5),
+                FileEntry::directory("docs", PathBuf::from("/docs")),
+                FileEntry::file(".hidden", PathBuf::from("/.hidden")),
+                FileEntry::file("A.rs", PathBuf::from("/A.rs")),
+            ],
+        );
+        source.dirs.insert(PathBuf::from("/docs"), vec![]);
+        source
+    }
+
+    #[test]
+    fn test_file_entry_metadata_builders() {
+        let modified = SystemTime::UNIX_EPOCH;
+        let entry = FileEntry::file("a.rs", PathBuf::from("/a.rs"))
+            .size(42)
+            .modified(modified)
+            .permissions(0o644);
+        assert_eq!(
+            entry.entry_type,
+            EntryType::File {
+                extension: Some("rs".into()),
+                size: 42
+            }
+        );
+        assert_eq!(entry.modified, Some(modified));
+        assert_eq!(entry.permissions, Some(0o644));
+
+        let link =
+            FileEntry::file("l", PathBuf::from("/l")).symlink_target(PathBuf::from("/target"));
+        assert_eq!(
+            link.entry_type,
+            EntryType::Symlink {
+                target: Some(PathBuf::from("/target"))
+            }
+        );
+
+        // Size is ignored for non-files
+        let dir = FileEntry::directory("d", PathBuf::from("/d")).size(10);
+        assert_eq!(dir.entry_type, EntryType::Directory);
+    }
+
+    #[test]
+    fn test_virtual_source_listing_and_navigation() {
+        let mut state = FileExplorerState::with_source(PathBuf::from("/"), mem_source());
+        state.load_entries().unwrap();
+
+        // Root has no parent entry; dirs first, hidden skipped
+        let names: Vec<&str> = state.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["docs", "A.rs", "b.txt"]);
+
+        state.enter_directory(PathBuf::from("/docs"));
+        let names: Vec<&str> = state.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec![".."]);
+
+        state.go_up();
+        assert_eq!(state.current_dir, PathBuf::from("/"));
+        state.toggle_hidden();
+        assert_eq!(state.entries.len(), 4);
+    }
+
+    #[test]
+    fn test_source_error_renders_inline() {
+        let mut state = FileExplorerState::with_source(PathBuf::from("/"), mem_source());
+        state.enter_directory(PathBuf::from("/missing"));
+        assert_eq!(state.error.as_deref(), Some("/missing not found"));
+        // The parent entry stays so the user can navigate back
+        assert_eq!(state.entries.len(), 1);
+        assert_eq!(state.entries[0].entry_type, EntryType::ParentDir);
+
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        FileExplorer::new(&state).render(area, &mut buf);
+        let row: String = (1..39).map(|x| buf[(x, 1)].symbol()).collect();
+        assert!(row.contains("/missing not found"));
+        assert_eq!(buf[(3, 1)].fg, Color::Red);
+
+        state.go_up();
+        assert!(state.error.is_none());
+    }
+
+    #[test]
+    #[cfg(not(feature = "filesystem"))]
+    fn test_without_source_entries_are_kept() {
+        let mut state = FileExplorerState::new(PathBuf::from("/tmp"));
+        assert!(state.source().is_none());
+        state.entries = vec![FileEntry::file("a", PathBuf::from("/tmp/a"))];
+        state.load_entries().unwrap();
+        assert_eq!(state.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_sync_entries_keeps_cursor_and_selection() {
+        let entry = |name: &str| FileEntry::file(name, PathBuf::from("/tmp").join(name));
+        let mut state = FileExplorerState::new(PathBuf::from("/tmp"));
+        state.entries = vec![entry("b"), entry("c"), entry("d")];
+        state.select_all();
+        state.cursor_index = 1;
+
+        state.sync_entries(vec![entry("a"), entry("b"), entry("c")]);
+        assert_eq!(state.current_entry().unwrap().name, "c");
+        assert_eq!(state.cursor_index, 2);
+        // "d" no longer exists
+        assert_eq!(state.selected_files.len(), 2);
+        assert!(!state.selected_files.contains(Path::new("/tmp/d")));
+
+        // The search filter is reapplied
+        state.search_query = "c".into();
+        state.update_filter();
+        state.sync_entries(vec![entry("c"), entry("cc")]);
+        assert_eq!(state.visible_count(), 2);
+        assert_eq!(state.current_entry().unwrap().name, "c");
+    }
+
+    fn multi_select_state() -> FileExplorerState {
+        let file =
+            |name: &str, size| FileEntry::file(name, PathBuf::from("/tmp").join(name)).size(size);
+        let mut state = FileExplorerState::new(PathBuf::from("/tmp"));
+        state.entries = vec![
+            FileEntry::directory("dir", PathBuf::from("/tmp/dir")),
+            file("a", 1024),
+            file("b", 2048),
+            file("c", 512),
+            file("d", 100),
+        ];
+        state
+    }
+
+    fn selected(state: &FileExplorerState) -> Vec<String> {
+        let mut names: Vec<String> = state
+            .selected_files
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_shift_arrows_extend_from_anchor() {
+        let mut state = multi_select_state();
+        let shift = |code| KeyEvent::new(code, KeyModifiers::SHIFT);
+        state.cursor_index = 2;
+
+        let action = handle_file_explorer_selection_key(&shift(KeyCode::Down), &mut state);
+        assert_eq!(selected(&state), ["b", "c"]);
+        assert_eq!(
+            action,
+            Some(FileExplorerAction::SelectionChanged(
+                state.selected_files.clone()
+            ))
+        );
+        handle_file_explorer_selection_key(&shift(KeyCode::Down), &mut state);
+        assert_eq!(selected(&state), ["b", "c", "d"]);
+
+        // Moving back past the anchor flips the range; directories are skipped
+        for _ in 0..4 {
+            handle_file_explorer_selection_key(&shift(KeyCode::Up), &mut state);
+        }
+        assert_eq!(state.cursor_index, 0);
+        assert_eq!(selected(&state), ["a", "b"]);
+
+        // At the top the selection does not change
+        assert_eq!(
+            handle_file_explorer_selection_key(&shift(KeyCode::Up), &mut state),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invert_and_summary() {
+        let mut state = multi_select_state();
+        assert_eq!(state.selection_summary(), None);
+        state.cursor_index = 1;
+        state.toggle_selection();
+        assert_eq!(state.selection_summary().as_deref(), Some("1 item, 1.0 KB"));
+
+        let star = KeyEvent::from(KeyCode::Char('*'));
+        assert!(handle_file_explorer_selection_key(&star, &mut state).is_some());
+        assert_eq!(selected(&state), ["b", "c", "d"]);
+        assert_eq!(state.selected_size(), 2048 + 512 + 100);
+        assert_eq!(
+            state.selection_summary().as_deref(),
+            Some("3 items, 2.6 KB")
+        );
+    }
+
+    #[test]
+    fn test_selection_survives_reordering() {
+        let mut state = multi_select_state();
+        state.select_range(1, 2);
+        let mut entries = state.entries.clone();
+        entries.reverse();
+        state.sync_entries(entries);
+        assert_eq!(selected(&state), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_mouse_range_and_toggle() {
+        let mut state = multi_select_state();
+        let area = Rect::new(0, 0, 40, 12);
+        // List rows start inside the border
+        let click = |row: u16, modifiers| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 5,
+            row: row + 1,
+            modifiers,
+        };
+
+        // A plain click sets the anchor without selecting
+        assert_eq!(
+            handle_file_explorer_mouse(&click(1, KeyModifiers::NONE), &mut state, area),
+            None
+        );
+        assert_eq!(state.cursor_index, 1);
+
+        let action = handle_file_explorer_mouse(&click(3, KeyModifiers::SHIFT), &mut state, area);
+        assert!(action.is_some());
+        assert_eq!(selected(&state), ["a", "b", "c"]);
+        assert_eq!(state.cursor_index, 3);
+
+        // Ctrl+Click toggles one entry and keeps the rest
+        handle_file_explorer_mouse(&click(2, KeyModifiers::CONTROL), &mut state, area);
+        assert_eq!(selected(&state), ["a", "c"]);
+        handle_file_explorer_mouse(&click(4, KeyModifiers::CONTROL), &mut state, area);
+        assert_eq!(selected(&state), ["a", "c", "d"]);
+
+        // Shift+Click extends from the last toggled entry
+        handle_file_explorer_mouse(&click(3, KeyModifiers::SHIFT), &mut state, area);
+        assert_eq!(selected(&state), ["c", "d"]);
+
+        // Clicks past the last entry or on the footer are ignored
+        assert_eq!(
+            handle_file_explorer_mouse(&click(7, KeyModifiers::SHIFT), &mut state, area),
+            None
+        );
+        assert_eq!(state.cursor_index, 3);
+    }
+
+    #[test]
+    fn test_search_match_modes() {
+        let contains = SearchMatchMode::Contains;
+        assert_eq!(contains.find("Cargo.toml", "TOML"), Some(6..10));
+        assert_eq!(contains.find("Cargo.toml", "x"), None);
+        assert_eq!(
+            SearchMatchMode::Prefix.find("Cargo.toml", "car"),
+            Some(0..3)
+        );
+        assert_eq!(SearchMatchMode::Prefix.find("Cargo.toml", "toml"), None);
+        assert_eq!(contains.find("Über.md", "über"), Some(0..5));
+    }
+
+    #[test]
+    fn test_search_keys() {
+        let mut state = multi_select_state();
+        let key = |code| KeyEvent::from(code);
+        let ctrl_f = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL);
+
+        assert_eq!(
+            handle_file_explorer_search_key(&key(KeyCode::Char('b')), &mut state),
+            EventResult::NotHandled
+        );
+        assert_eq!(
+            handle_file_explorer_search_key(&ctrl_f, &mut state),
+            EventResult::Consumed
+        );
+        assert_eq!(state.mode, FileExplorerMode::Search);
+
+        // "d" matches "dir" and "d"
+        handle_file_explorer_search_key(&key(KeyCode::Char('D')), &mut state);
+        assert_eq!(state.visible_count(), 2);
+        handle_file_explorer_search_key(&key(KeyCode::Down), &mut state);
+        assert_eq!(state.current_entry().unwrap().name, "d");
+
+        assert_eq!(
+            handle_file_explorer_search_key(&key(KeyCode::Enter), &mut state),
+            EventResult::Action(FileExplorerAction::EntrySelected(PathBuf::from("/tmp/d")))
+        );
+        assert_eq!(state.mode, FileExplorerMode::Browse);
+        assert_eq!(state.visible_count(), 5);
+        assert_eq!(state.current_entry().unwrap().name, "d");
+    }
+
+    #[test]
+    fn test_search_escape_restores_listing() {
+        let mut state = multi_select_state().with_search_match_mode(SearchMatchMode::Prefix);
+        state.start_search();
+        for c in "b".chars() {
+            handle_file_explorer_search_key(&KeyEvent::from(KeyCode::Char(c)), &mut state);
+        }
+        assert_eq!(state.visible_count(), 1);
+        handle_file_explorer_search_key(&KeyEvent::from(KeyCode::Backspace), &mut state);
+        assert_eq!(state.visible_count(), 5);
+        handle_file_explorer_search_key(&KeyEvent::from(KeyCode::Char('c')), &mut state);
+
+        handle_file_explorer_search_key(&KeyEvent::from(KeyCode::Esc), &mut state);
+        assert_eq!(state.mode, FileExplorerMode::Browse);
+        assert!(state.search_query.is_empty());
+        assert_eq!(state.visible_count(), 5);
+        assert_eq!(state.current_entry().unwrap().name, "c");
+    }
+
+    #[test]
+    fn test_render_search_bar_and_match() {
+        let mut state = multi_select_state();
+        state
+            .entries
+            .push(FileEntry::file("notes.md", PathBuf::from("/tmp/notes.md")));
+        state.start_search();
+        state.search_query = "tes".into();
+        state.update_filter();
+
+        let area = Rect::new(0, 0, 40, 8);
+        let mut buf = Buffer::empty(area);
+        let style = FileExplorerStyle::default();
+        FileExplorer::new(&state)
+            .style(style.clone())
+            .render(area, &mut buf);
+
+        let row = |y: u16| (0..40).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert!(row(6).starts_with("Search: tes_"));
+        let name_x = (0..40).find(|&x| buf[(x, 1)].symbol() == "n").unwrap();
+        // "no" keeps the name style, "tes" gets the match style
+        assert!(!buf[(name_x + 1, 1)].modifier.contains(Modifier::UNDERLINED));
+        assert!(buf[(name_x + 2, 1)].modifier.contains(Modifier::UNDERLINED));
+        assert!(buf[(name_x + 4, 1)].modifier.contains(Modifier::UNDERLINED));
+        assert!(!buf[(name_x + 5, 1)].modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_nav_keys_at_boundaries() {
+        let mut state =
+            FileExplorerState::new(PathBuf::from("/tmp")).with_page_size(PageSize::Fixed(4));
+        state.entries = (0..10)
+            .map(|i| {
+                FileEntry::new(
+                    format!("f{i}"),
+                    PathBuf::from(format!("/tmp/f{i}")),
+                    EntryType::File {
+                        extension: None,
+                        size: 0,
+                    },
+                )
+            })
+            .collect();
+        let press = |state: &mut FileExplorerState, code| {
+            handle_file_explorer_nav_key(&KeyEvent::from(code), state, 3)
+        };
+
+        assert!(press(&mut state, KeyCode::PageUp));
+        assert_eq!((state.cursor_index, state.scroll), (0, 0));
+        press(&mut state, KeyCode::PageDown);
+        assert_eq!((state.cursor_index, state.scroll), (4, 2));
+        press(&mut state, KeyCode::End);
+        press(&mut state, KeyCode::PageDown);
+        assert_eq!((state.cursor_index, state.scroll), (9, 7));
+        press(&mut state, KeyCode::PageUp);
+        assert_eq!((state.cursor_index, state.scroll), (5, 5));
+
+        // Shift+Down extends the selection instead
+        let shift_down = KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT);
+        assert!(!handle_file_explorer_nav_key(&shift_down, &mut state, 3));
+    }
 }
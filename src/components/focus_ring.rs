@@ -0,0 +1,281 @@
+//! Focus ring - consistent focus indication for bordered and borderless widgets
+//!
+//! [`FocusRing`] resolves the border style for a widget's focused, unfocused,
+//! and disabled states. Bordered widgets apply it to their [`Block`];
+//! borderless widgets can paint a 1-cell outline around themselves instead.
+//! Built-in widgets use it so focus looks the same everywhere, and custom
+//! widgets can use [`apply_focus`] to match them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::widgets::{Block, Borders};
+//! use ratatui_interact::components::{FocusRing, apply_focus};
+//! use ratatui_interact::theme::Theme;
+//!
+//! let theme = Theme::default();
+//! let block = apply_focus(Block::default().borders(Borders::ALL), true, &theme);
+//!
+//! // Or with explicit colors
+//! let ring = FocusRing::from(&theme);
+//! let block = ring.block(Block::default().borders(Borders::ALL), false, true);
+//! ```
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols::border,
+    widgets::Block,
+};
+
+use crate::theme::Theme;
+
+/// Border colors for each focus state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusRing {
+    /// Border color when focused.
+    pub focused: Color,
+    /// Border color when unfocused.
+    pub unfocused: Color,
+    /// Border color when disabled.
+    pub disabled: Color,
+    /// Modifier added to the border when focused.
+    pub focused_modifier: Modifier,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            focused: Color::Yellow,
+            unfocused: Color::Gray,
+            disabled: Color::DarkGray,
+            focused_modifier: Modifier::empty(),
+        }
+    }
+}
+
+impl From<&Theme> for FocusRing {
+    fn from(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            focused: p.border_focused,
+            unfocused: p.border,
+            disabled: p.border_disabled,
+            focused_modifier: Modifier::empty(),
+        }
+    }
+}
+
+impl FocusRing {
+    /// Create a focus ring with the given focused and unfocused colors.
+    pub fn new(focused: Color, unfocused: Color) -> Self {
+        Self {
+            focused,
+            unfocused,
+            ..Default::default()
+        }
+    }
+
+    /// Set the disabled color.
+    pub fn disabled(mut self, color: Color) -> Self {
+        self.disabled = color;
+        self
+    }
+
+    /// Set the modifier added when focused.
+    pub fn focused_modifier(mut self, modifier: Modifier) -> Self {
+        self.focused_modifier = modifier;
+        self
+    }
+
+    /// Border color for the given state.
+    pub fn color(&self, focused: bool, enabled: bool) -> Color {
+        if !enabled {
+            self.disabled
+        } else if focused {
+            self.focused
+        } else {
+            self.unfocused
+        }
+    }
+
+    /// Border style for the given state.
+    pub fn style(&self, focused: bool, enabled: bool) -> Style {
+        let style = Style::default().fg(self.color(focused, enabled));
+        if focused && enabled {
+            style.add_modifier(self.focused_modifier)
+        } else {
+            style
+        }
+    }
+
+    /// Apply the border style for the given state to `block`.
+    pub fn block<'a>(&self, block: Block<'a>, focused: bool, enabled: bool) -> Block<'a> {
+        block.border_style(self.style(focused, enabled))
+    }
+
+    /// Restyle the outermost cells of `area`, for widgets that drew their own border.
+    pub fn paint_border(&self, area: Rect, buf: &mut Buffer, focused: bool, enabled: bool) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let style = self.style(focused, enabled);
+        let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+        for x in area.left()..area.right() {
+            buf[(x, area.y)].set_style(style);
+            buf[(x, bottom)].set_style(style);
+        }
+        for y in area.top()..area.bottom() {
+            buf[(area.x, y)].set_style(style);
+            buf[(right, y)].set_style(style);
+        }
+    }
+
+    /// Draw a 1-cell outline just outside `area` when focused.
+    ///
+    /// For borderless widgets. Sides that would fall outside the buffer are
+    /// skipped, so the widget itself is never drawn over.
+    pub fn render_outline(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        if !focused || area.is_empty() {
+            return;
+        }
+        let style = self.style(true, true);
+        let bounds = buf.area;
+        let has_top = area.y > bounds.y;
+        let has_bottom = area.bottom() < bounds.bottom();
+        let has_left = area.x > bounds.x;
+        let has_right = area.right() < bounds.right();
+        let (left, right) = (area.x.wrapping_sub(1), area.right());
+        let (top, bottom) = (area.y.wrapping_sub(1), area.bottom());
+
+        let mut put = |x: u16, y: u16, symbol: &str| {
+            buf[(x, y)].set_symbol(symbol).set_style(style);
+        };
+
+        for x in area.left()..area.right() {
+            if has_top {
+                put(x, top, border::PLAIN.horizontal_top);
+            }
+            if has_bottom {
+                put(x, bottom, border::PLAIN.horizontal_bottom);
+            }
+        }
+        for y in area.top()..area.bottom() {
+            if has_left {
+                put(left, y, border::PLAIN.vertical_left);
+            }
+            if has_right {
+                put(right, y, border::PLAIN.vertical_right);
+            }
+        }
+        if has_top && has_left {
+            put(left, top, border::PLAIN.top_left);
+        }
+        if has_top && has_right {
+            put(right, top, border::PLAIN.top_right);
+        }
+        if has_bottom && has_left {
+            put(left, bottom, border::PLAIN.bottom_left);
+        }
+        if has_bottom && has_right {
+            put(right, bottom, border::PLAIN.bottom_right);
+        }
+    }
+}
+
+/// Style `block`'s border for the focus state using the theme's focus colors.
+pub fn apply_focus<'a>(block: Block<'a>, focused: bool, theme: &Theme) -> Block<'a> {
+    FocusRing::from(theme).block(block, focused, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::{Borders, Widget};
+
+    #[test]
+    fn test_color_by_state() {
+        let ring = FocusRing::new(Color::Yellow, Color::Gray).disabled(Color::Red);
+        assert_eq!(ring.color(true, true), Color::Yellow);
+        assert_eq!(ring.color(false, true), Color::Gray);
+        assert_eq!(ring.color(true, false), Color::Red);
+    }
+
+    #[test]
+    fn test_focused_modifier_only_when_focused() {
+        let ring = FocusRing::default().focused_modifier(Modifier::BOLD);
+        assert!(ring.style(true, true).add_modifier.contains(Modifier::BOLD));
+        assert!(
+            !ring
+                .style(false, true)
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+        assert!(
+            !ring
+                .style(true, false)
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn test_apply_focus_uses_theme() {
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        apply_focus(Block::default().borders(Borders::ALL), true, &theme).render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, theme.palette.border_focused);
+
+        let mut buf = Buffer::empty(area);
+        apply_focus(Block::default().borders(Borders::ALL), false, &theme).render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, theme.palette.border);
+    }
+
+    #[test]
+    fn test_render_outline() {
+        let ring = FocusRing::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        ring.render_outline(Rect::new(1, 1, 3, 1), &mut buf, true);
+        let mut expected = Buffer::with_lines(["┌───┐", "│   │", "└───┘"]);
+        let yellow = Style::default().fg(Color::Yellow);
+        expected.set_style(Rect::new(0, 0, 5, 1), yellow);
+        expected.set_style(Rect::new(0, 2, 5, 1), yellow);
+        expected.set_style(Rect::new(0, 1, 1, 1), yellow);
+        expected.set_style(Rect::new(4, 1, 1, 1), yellow);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_outline_clips_to_buffer() {
+        let ring = FocusRing::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        ring.render_outline(Rect::new(0, 0, 3, 1), &mut buf, true);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+        assert_eq!(buf[(0, 1)].symbol(), "─");
+        assert_eq!(buf[(3, 0)].symbol(), "│");
+        assert_eq!(buf[(3, 1)].symbol(), "┘");
+    }
+
+    #[test]
+    fn test_render_outline_unfocused_draws_nothing() {
+        let ring = FocusRing::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        ring.render_outline(Rect::new(1, 1, 3, 1), &mut buf, false);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 5, 3)));
+    }
+
+    #[test]
+    fn test_paint_border() {
+        let ring = FocusRing::default();
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        ring.paint_border(area, &mut buf, true, true);
+        assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(3, 2)].fg, Color::Yellow);
+        assert_eq!(buf[(0, 1)].fg, Color::Yellow);
+        assert_eq!(buf[(1, 1)].fg, Color::Reset);
+    }
+}
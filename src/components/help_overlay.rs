@@ -0,0 +1,563 @@
+//! Searchable help overlay generated from registered key hints
+//!
+//! Components describe their bindings via `describe_keys()` on their state
+//! types, and the app registers the active ones into a [`KeyHintRegistry`]
+//! each frame. The [`HelpOverlay`] then renders them with the same
+//! search/filter UI as [`HotkeyDialog`], without a hand-written
+//! [`HotkeyProvider`].
+//!
+//! Bindings are grouped by scope. The registry tracks which scopes are
+//! focused, so the overlay shows only relevant bindings by default, with
+//! "Global" and "All" categories for the rest.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{
+//!     AccordionState, HelpOverlayState, HelpScope, HotkeyProvider, KeyHintRegistry,
+//!     handle_help_overlay_key,
+//! };
+//! use crossterm::event::{KeyCode, KeyEvent};
+//!
+//! // Each frame: register the bindings of the visible components
+//! let mut registry = KeyHintRegistry::new();
+//! registry.register_global("q", "Quit");
+//! registry.register_all("Sidebar", AccordionState::describe_keys());
+//! registry.set_focus("Sidebar");
+//!
+//! assert!(!registry.entries_for_category(HelpScope::Focused).is_empty());
+//!
+//! // F1 or ? opens the overlay
+//! let mut help = HelpOverlayState::new();
+//! assert!(handle_help_overlay_key(&mut help, &KeyEvent::from(KeyCode::F(1))));
+//! assert!(help.is_visible());
+//! ```
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use ratatui::{Frame, layout::Rect};
+
+use crate::events::KeyMatcher;
+use crate::state::OverlayManager;
+
+use super::hotkey_dialog::{
+    HotkeyCategory, HotkeyDialog, HotkeyDialogAction, HotkeyDialogState, HotkeyDialogStyle,
+    HotkeyEntryData, HotkeyFocus, HotkeyProvider, handle_hotkey_dialog_key,
+    handle_hotkey_dialog_mouse,
+};
+
+/// A single registered key binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyHint {
+    /// Scope the binding belongs to (e.g. a panel or component name)
+    pub scope: String,
+    /// Key chord (e.g. "Ctrl+S")
+    pub chord: String,
+    /// What the binding does
+    pub description: String,
+    /// Whether the binding is active everywhere
+    pub global: bool,
+}
+
+/// Registry of the key bindings active in the current frame.
+///
+/// Clear it at the start of each frame and register the bindings of every
+/// visible component, then mark the focused scope.
+#[derive(Debug, Clone, Default)]
+pub struct KeyHintRegistry {
+    hints: Vec<KeyHint>,
+    focused_scopes: Vec<String>,
+}
+
+/// Scope name used for global bindings.
+const GLOBAL_SCOPE: &str = "Global";
+
+impl KeyHintRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove all hints and focus information.
+    pub fn clear(&mut self) {
+        self.hints.clear();
+        self.focused_scopes.clear();
+    }
+
+    /// Register a binding in a scope.
+    ///
+    /// Registering the same chord twice in a scope keeps the first entry.
+    pub fn register(
+        &mut self,
+        scope: impl Into<String>,
+        chord: impl Into<String>,
+        description: impl Into<String>,
+    ) {
+        self.push(scope.into(), chord.into(), description.into(), false);
+    }
+
+    /// Register a binding that is active everywhere.
+    pub fn register_global(&mut self, chord: impl Into<String>, description: impl Into<String>) {
+        self.push(
+            GLOBAL_SCOPE.to_string(),
+            chord.into(),
+            description.into(),
+            true,
+        );
+    }
+
+    /// Register a [`KeyMatcher`] binding in a scope, using its chord text.
+    pub fn register_matcher(
+        &mut self,
+        scope: impl Into<String>,
+        matcher: &KeyMatcher,
+        description: impl Into<String>,
+    ) {
+        self.register(scope, matcher.to_string(), description);
+    }
+
+    /// Register a list of `(chord, description)` pairs, such as the output
+    /// of a component's `describe_keys()`.
+    pub fn register_all(&mut self, scope: impl Into<String>, hints: &[(&str, &str)]) {
+        let scope = scope.into();
+        for (chord, description) in hints {
+            self.register(scope.clone(), *chord, *description);
+        }
+    }
+
+    fn push(&mut self, scope: String, chord: String, description: String, global: bool) {
+        if self
+            .hints
+            .iter()
+            .any(|h| h.scope == scope && h.chord == chord)
+        {
+            return;
+        }
+        self.hints.push(KeyHint {
+            scope,
+            chord,
+            description,
+            global,
+        });
+    }
+
+    /// Set the focused scope, replacing any previous focus.
+    pub fn set_focus(&mut self, scope: impl Into<String>) {
+        self.focused_scopes.clear();
+        self.focused_scopes.push(scope.into());
+    }
+
+    /// Add a focused scope (e.g. a container and its focused child).
+    pub fn add_focus(&mut self, scope: impl Into<String>) {
+        let scope = scope.into();
+        if !self.focused_scopes.contains(&scope) {
+            self.focused_scopes.push(scope);
+        }
+    }
+
+    /// Check if a scope is focused.
+    pub fn is_focused(&self, scope: &str) -> bool {
+        self.focused_scopes.iter().any(|s| s == scope)
+    }
+
+    /// All registered hints, in registration order.
+    pub fn hints(&self) -> &[KeyHint] {
+        &self.hints
+    }
+
+    /// Number of registered hints.
+    pub fn len(&self) -> usize {
+        self.hints.len()
+    }
+
+    /// Check if no hints are registered.
+    pub fn is_empty(&self) -> bool {
+        self.hints.is_empty()
+    }
+
+    /// Scope names in order of first registration.
+    pub fn scopes(&self) -> Vec<&str> {
+        let mut scopes: Vec<&str> = Vec::new();
+        for hint in &self.hints {
+            if !scopes.contains(&hint.scope.as_str()) {
+                scopes.push(&hint.scope);
+            }
+        }
+        scopes
+    }
+
+    /// Hints grouped by scope, in scope order.
+    fn grouped(&self) -> impl Iterator<Item = &KeyHint> {
+        self.scopes()
+            .into_iter()
+            .flat_map(move |scope| self.hints.iter().filter(move |h| h.scope == scope))
+    }
+
+    fn to_entry(hint: &KeyHint, with_scope: bool) -> HotkeyEntryData {
+        let action = if with_scope && !hint.global {
+            format!("{}: {}", hint.scope, hint.description)
+        } else {
+            hint.description.clone()
+        };
+        HotkeyEntryData::new(hint.chord.clone(), action, hint.scope.clone())
+            .with_global(hint.global)
+            .fixed()
+    }
+}
+
+/// Categories shown in the help overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HelpScope {
+    /// Bindings of the focused scopes
+    #[default]
+    Focused,
+    /// Bindings active everywhere
+    Global,
+    /// Every registered binding, grouped by scope
+    All,
+}
+
+impl HotkeyCategory for HelpScope {
+    fn all() -> &'static [Self] {
+        &[Self::Focused, Self::Global, Self::All]
+    }
+
+    fn display_name(&self) -> &str {
+        match self {
+            Self::Focused => "Focused",
+            Self::Global => "Global",
+            Self::All => "All",
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            Self::Focused => Self::Global,
+            Self::Global => Self::All,
+            Self::All => Self::Focused,
+        }
+    }
+
+    fn prev(&self) -> Self {
+        match self {
+            Self::Focused => Self::All,
+            Self::Global => Self::Focused,
+            Self::All => Self::Global,
+        }
+    }
+}
+
+impl HotkeyProvider for KeyHintRegistry {
+    type Category = HelpScope;
+
+    fn entries_for_category(&self, category: HelpScope) -> Vec<HotkeyEntryData> {
+        match category {
+            HelpScope::Focused => {
+                // Without focus information, show every non-global binding
+                let any_focus = !self.focused_scopes.is_empty();
+                self.grouped()
+                    .filter(|h| !h.global && (!any_focus || self.is_focused(&h.scope)))
+                    .map(|h| Self::to_entry(h, self.focused_scopes.len() != 1))
+                    .collect()
+            }
+            HelpScope::Global => self
+                .hints
+                .iter()
+                .filter(|h| h.global)
+                .map(|h| Self::to_entry(h, false))
+                .collect(),
+            HelpScope::All => self.grouped().map(|h| Self::to_entry(h, true)).collect(),
+        }
+    }
+
+    fn search(&self, query: &str) -> Vec<(HelpScope, HotkeyEntryData)> {
+        let query = query.to_lowercase();
+        self.grouped()
+            .filter(|h| {
+                h.chord.to_lowercase().contains(&query)
+                    || h.description.to_lowercase().contains(&query)
+                    || h.scope.to_lowercase().contains(&query)
+            })
+            .map(|h| (HelpScope::All, Self::to_entry(h, true)))
+            .collect()
+    }
+}
+
+/// State for the help overlay.
+#[derive(Debug, Clone, Default)]
+pub struct HelpOverlayState {
+    /// Whether the overlay is visible
+    pub visible: bool,
+    /// State of the underlying hotkey dialog
+    pub dialog: HotkeyDialogState<HelpScope>,
+}
+
+impl HelpOverlayState {
+    /// Create a hidden help overlay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the overlay, starting on the focused bindings.
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.dialog = HotkeyDialogState::new();
+    }
+
+    /// Hide the overlay.
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Toggle the overlay.
+    pub fn toggle(&mut self) {
+        if self.visible {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Check if the overlay is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+/// Help overlay widget backed by a [`KeyHintRegistry`].
+pub struct HelpOverlay<'a> {
+    registry: &'a KeyHintRegistry,
+    style: HotkeyDialogStyle,
+}
+
+impl<'a> HelpOverlay<'a> {
+    /// Create a help overlay for the given registry.
+    pub fn new(registry: &'a KeyHintRegistry) -> Self {
+        Self {
+            registry,
+            style: HotkeyDialogStyle::default().title(" Help "),
+        }
+    }
+
+    /// Set the dialog style.
+    pub fn style(mut self, style: HotkeyDialogStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme to derive the style.
+    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
+        self.style(HotkeyDialogStyle::from(theme).title(" Help "))
+    }
+
+    /// Render the overlay if it is visible.
+    pub fn render(&self, frame: &mut Frame, area: Rect, state: &mut HelpOverlayState) {
+        if !state.visible {
+            return;
+        }
+        HotkeyDialog::new(&mut state.dialog, self.registry, &self.style).render(frame, area);
+    }
+
+    /// Render the overlay, if visible, into an [`OverlayManager`] on the
+    /// [`OverlayLayer::Dialog`](crate::state::OverlayLayer::Dialog) layer.
+    pub fn render_into_overlays(
+        &self,
+        screen: Rect,
+        state: &mut HelpOverlayState,
+        overlays: &mut OverlayManager<'_>,
+    ) {
+        if !state.visible {
+            return;
+        }
+        HotkeyDialog::new(&mut state.dialog, self.registry, &self.style)
+            .render_into_overlays(screen, overlays);
+    }
+}
+
+/// Check if a key toggles the help overlay (F1 or `?`).
+pub fn is_help_key(key: &KeyEvent) -> bool {
+    match key.code {
+        KeyCode::F(1) => true,
+        KeyCode::Char('?') => !key.modifiers.contains(KeyModifiers::CONTROL),
+        _ => false,
+    }
+}
+
+/// Handle keyboard events for the help overlay.
+///
+/// Opens the overlay on F1 / `?`. While visible, the overlay is modal and
+/// consumes every key. Returns `true` if the key was consumed.
+pub fn handle_help_overlay_key(state: &mut HelpOverlayState, key: &KeyEvent) -> bool {
+    if !state.visible {
+        if is_help_key(key) {
+            state.open();
+            return true;
+        }
+        return false;
+    }
+
+    // `?` is typed into the search field when it has focus
+    let typing = state.dialog.focus == HotkeyFocus::SearchInput;
+    if key.code == KeyCode::F(1) || (is_help_key(key) && !typing) {
+        state.close();
+        return true;
+    }
+
+    if handle_hotkey_dialog_key(&mut state.dialog, *key) == HotkeyDialogAction::Close {
+        state.close();
+    }
+    true
+}
+
+/// Handle mouse events for the help overlay.
+///
+/// Returns `true` if the event was consumed (always, while visible).
+pub fn handle_help_overlay_mouse(state: &mut HelpOverlayState, mouse: &MouseEvent) -> bool {
+    if !state.visible {
+        return false;
+    }
+    handle_hotkey_dialog_mouse(&mut state.dialog, *mouse);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn registry() -> KeyHintRegistry {
+        let mut registry = KeyHintRegistry::new();
+        registry.register_global("q", "Quit");
+        registry.register("Editor", "Ctrl+S", "Save");
+        registry.register("Editor", "Ctrl+S", "Duplicate is ignored");
+        registry.register_all("Sidebar", &[("j", "Down"), ("k", "Up")]);
+        registry
+    }
+
+    #[test]
+    fn test_registry_scopes_and_dedup() {
+        let registry = registry();
+        assert_eq!(registry.len(), 4);
+        assert_eq!(registry.scopes(), vec!["Global", "Editor", "Sidebar"]);
+    }
+
+    #[test]
+    fn test_register_matcher_uses_chord_text() {
+        let mut registry = registry();
+        registry.register_matcher("Editor", &KeyMatcher::ctrl('s'), "Duplicate is ignored");
+        registry.register_matcher("Editor", &"ctrl+enter|ctrl+space".parse().unwrap(), "Run");
+        assert_eq!(registry.len(), 5);
+        assert_eq!(registry.hints()[4].chord, "Ctrl+Enter / Ctrl+Space");
+    }
+
+    #[test]
+    fn test_focused_category_follows_focus() {
+        let mut registry = registry();
+
+        // No focus: every non-global binding
+        assert_eq!(registry.entries_for_category(HelpScope::Focused).len(), 3);
+
+        registry.set_focus("Sidebar");
+        let focused = registry.entries_for_category(HelpScope::Focused);
+        assert_eq!(focused.len(), 2);
+        assert_eq!(focused[0].action, "Down");
+
+        registry.add_focus("Editor");
+        assert_eq!(registry.entries_for_category(HelpScope::Focused).len(), 3);
+
+        let global = registry.entries_for_category(HelpScope::Global);
+        assert_eq!(global.len(), 1);
+        assert!(global[0].is_global);
+
+        let all = registry.entries_for_category(HelpScope::All);
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[1].action, "Editor: Save");
+    }
+
+    #[test]
+    fn test_search() {
+        let registry = registry();
+        let results = registry.search("sidebar");
+        assert_eq!(results.len(), 2);
+        assert_eq!(registry.search("ctrl+s").len(), 1);
+        assert!(registry.search("nothing").is_empty());
+    }
+
+    #[test]
+    fn test_toggle_keys() {
+        let mut state = HelpOverlayState::new();
+        assert!(!handle_help_overlay_key(
+            &mut state,
+            &KeyEvent::from(KeyCode::Char('x'))
+        ));
+
+        assert!(handle_help_overlay_key(
+            &mut state,
+            &KeyEvent::from(KeyCode::Char('?'))
+        ));
+        assert!(state.is_visible());
+
+        // Modal while open
+        assert!(handle_help_overlay_key(
+            &mut state,
+            &KeyEvent::from(KeyCode::Down)
+        ));
+        assert!(state.is_visible());
+
+        assert!(handle_help_overlay_key(
+            &mut state,
+            &KeyEvent::from(KeyCode::F(1))
+        ));
+        assert!(!state.is_visible());
+
+        state.open();
+        handle_help_overlay_key(&mut state, &KeyEvent::from(KeyCode::Esc));
+        assert!(!state.is_visible());
+    }
+
+    #[test]
+    fn test_question_mark_typed_in_search() {
+        let mut state = HelpOverlayState::new();
+        state.open();
+        state.dialog.focus = HotkeyFocus::SearchInput;
+        handle_help_overlay_key(&mut state, &KeyEvent::from(KeyCode::Char('?')));
+        assert!(state.is_visible());
+        assert_eq!(state.dialog.search_query, "?");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut registry = registry();
+        registry.set_focus("Editor");
+        let mut state = HelpOverlayState::new();
+        let overlay = HelpOverlay::new(&registry);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+
+        // Hidden: nothing drawn
+        terminal
+            .draw(|f| overlay.render(f, f.area(), &mut state))
+            .unwrap();
+        assert!(
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .all(|c| c.symbol() == " ")
+        );
+
+        state.open();
+        terminal
+            .draw(|f| overlay.render(f, f.area(), &mut state))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("Help"));
+        assert!(text.contains("Save"));
+    }
+}
@@ -7,6 +7,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent,
 
 use super::state::{HotkeyDialogState, HotkeyFocus};
 use super::traits::HotkeyCategory;
+use crate::events::CloseRequest;
 
 /// Result of handling a hotkey dialog event.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +16,8 @@ pub enum HotkeyDialogAction {
     None,
     /// Close the dialog
     Close,
+    /// Esc cleared the search query instead of closing the dialog
+    SearchCleared,
     /// Entry was selected (for potential future features like rebinding)
     EntrySelected {
         /// The key combination of the selected entry
@@ -30,10 +33,25 @@ pub enum HotkeyDialogAction {
     ScrollDown(usize),
 }
 
+/// For the result of handling Esc: clearing the search consumes the key, and
+/// `Close` asks the owner to close the dialog.
+impl From<HotkeyDialogAction> for CloseRequest {
+    fn from(action: HotkeyDialogAction) -> Self {
+        match action {
+            HotkeyDialogAction::None => CloseRequest::Propagate,
+            HotkeyDialogAction::Close => CloseRequest::Close,
+            _ => CloseRequest::Consumed,
+        }
+    }
+}
+
 /// Handle a keyboard event for the hotkey dialog.
 ///
 /// Returns a `HotkeyDialogAction` indicating what action should be taken.
 ///
+/// Esc first clears a non-empty search query
+/// ([`HotkeyDialogAction::SearchCleared`]) and otherwise closes the dialog.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -62,7 +80,7 @@ pub fn handle_hotkey_dialog_key<C: HotkeyCategory>(
         // If in search input with text, first clear search, then close
         if state.focus == HotkeyFocus::SearchInput && !state.search_query.is_empty() {
             state.clear_search();
-            return HotkeyDialogAction::None;
+            return HotkeyDialogAction::SearchCleared;
         }
         return HotkeyDialogAction::Close;
     }
@@ -83,6 +101,21 @@ pub fn handle_hotkey_dialog_key<C: HotkeyCategory>(
         return HotkeyDialogAction::None;
     }
 
+    // Ctrl+PgUp/PgDn switch categories from any focus area
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        match key.code {
+            KeyCode::PageUp => {
+                state.prev_category();
+                return HotkeyDialogAction::None;
+            }
+            KeyCode::PageDown => {
+                state.next_category();
+                return HotkeyDialogAction::None;
+            }
+            _ => {}
+        }
+    }
+
     // Handle focus-specific keys
     match state.focus {
         HotkeyFocus::SearchInput => handle_search_input_key(state, key),
@@ -133,13 +166,14 @@ fn handle_category_list_key<C: HotkeyCategory>(
     key: KeyEvent,
 ) -> HotkeyDialogAction {
     match key.code {
-        KeyCode::Up => {
+        // Up/Down for the sidebar, Left/Right for the tab strip
+        KeyCode::Up | KeyCode::Left => {
             state.prev_category();
         }
-        KeyCode::Down => {
+        KeyCode::Down | KeyCode::Right => {
             state.next_category();
         }
-        KeyCode::Enter | KeyCode::Right => {
+        KeyCode::Enter => {
             // Select category and move to hotkey list
             state.focus = HotkeyFocus::HotkeyList;
         }
@@ -188,6 +222,9 @@ fn handle_hotkey_list_key<C: HotkeyCategory>(
 ///
 /// Returns a `HotkeyDialogAction` indicating what action should be taken.
 ///
+/// The wheel scrolls the pane under the cursor: the category list when it
+/// is over it, the hotkey list otherwise.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -209,13 +246,24 @@ pub fn handle_hotkey_dialog_mouse<C: HotkeyCategory>(
     state: &mut HotkeyDialogState<C>,
     mouse: MouseEvent,
 ) -> HotkeyDialogAction {
+    let over_categories = state
+        .category_list_area
+        .is_some_and(|area| area.contains((mouse.column, mouse.row).into()));
     match mouse.kind {
         MouseEventKind::ScrollUp => {
-            state.scroll_hotkeys_up(3);
+            if over_categories {
+                state.scroll_categories_up(3);
+            } else {
+                state.scroll_hotkeys_up(3);
+            }
             HotkeyDialogAction::ScrollUp(3)
         }
         MouseEventKind::ScrollDown => {
-            state.scroll_hotkeys_down(3);
+            if over_categories {
+                state.scroll_categories_down(3);
+            } else {
+                state.scroll_hotkeys_down(3);
+            }
             HotkeyDialogAction::ScrollDown(3)
         }
         MouseEventKind::Down(MouseButton::Left) => {
@@ -301,7 +349,8 @@ mod tests {
         state.insert_char('a');
 
         let action = handle_hotkey_dialog_key(&mut state, key_event(KeyCode::Esc));
-        assert_eq!(action, HotkeyDialogAction::None);
+        assert_eq!(action, HotkeyDialogAction::SearchCleared);
+        assert_eq!(CloseRequest::from(action), CloseRequest::Consumed);
         assert!(state.search_query.is_empty());
 
         // Second escape should close
@@ -336,6 +385,36 @@ mod tests {
         assert_eq!(state.selected_category, TestCategory::First);
     }
 
+    #[test]
+    fn test_left_right_switch_categories() {
+        let mut state: HotkeyDialogState<TestCategory> = HotkeyDialogState::new();
+        state.focus = HotkeyFocus::CategoryList;
+
+        handle_hotkey_dialog_key(&mut state, key_event(KeyCode::Right));
+        assert_eq!(state.selected_category, TestCategory::Second);
+        assert_eq!(state.focus, HotkeyFocus::CategoryList);
+
+        handle_hotkey_dialog_key(&mut state, key_event(KeyCode::Left));
+        assert_eq!(state.selected_category, TestCategory::First);
+
+        handle_hotkey_dialog_key(&mut state, key_event(KeyCode::Enter));
+        assert_eq!(state.focus, HotkeyFocus::HotkeyList);
+    }
+
+    #[test]
+    fn test_ctrl_page_keys_switch_categories_from_hotkey_list() {
+        let mut state: HotkeyDialogState<TestCategory> = HotkeyDialogState::new();
+        state.focus = HotkeyFocus::HotkeyList;
+
+        let ctrl = |code| KeyEvent::new(code, KeyModifiers::CONTROL);
+        handle_hotkey_dialog_key(&mut state, ctrl(KeyCode::PageDown));
+        assert_eq!(state.selected_category, TestCategory::Second);
+        assert_eq!(state.focus, HotkeyFocus::HotkeyList);
+
+        handle_hotkey_dialog_key(&mut state, ctrl(KeyCode::PageUp));
+        assert_eq!(state.selected_category, TestCategory::First);
+    }
+
     #[test]
     fn test_search_input() {
         let mut state: HotkeyDialogState<TestCategory> = HotkeyDialogState::new();
@@ -354,4 +433,37 @@ mod tests {
         handle_hotkey_dialog_key(&mut state, key_event(KeyCode::Backspace));
         assert_eq!(state.search_query, "a");
     }
+
+    #[test]
+    fn test_mouse_scroll_routes_to_pane_under_cursor() {
+        use ratatui::layout::Rect;
+
+        let mut state: HotkeyDialogState<TestCategory> = HotkeyDialogState::new();
+        state.update_entry_count(20);
+        state.category_list_area = Some(Rect::new(0, 0, 10, 1));
+        state.hotkey_list_area = Some(Rect::new(10, 0, 30, 5));
+        let wheel = |kind, column| MouseEvent {
+            kind,
+            column,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        // Over the hotkey list: only the hotkeys scroll, up to the last page
+        for _ in 0..10 {
+            handle_hotkey_dialog_mouse(&mut state, wheel(MouseEventKind::ScrollDown, 20));
+        }
+        assert_eq!(state.hotkey_scroll, 15);
+        assert_eq!(state.category_scroll, 0);
+
+        // Over the category list: only the categories scroll
+        let action = handle_hotkey_dialog_mouse(&mut state, wheel(MouseEventKind::ScrollDown, 2));
+        assert_eq!(action, HotkeyDialogAction::ScrollDown(3));
+        assert_eq!(state.category_scroll, 1);
+        assert_eq!(state.hotkey_scroll, 15);
+
+        handle_hotkey_dialog_mouse(&mut state, wheel(MouseEventKind::ScrollUp, 20));
+        assert_eq!(state.hotkey_scroll, 12);
+        assert_eq!(state.category_scroll, 1);
+    }
 }
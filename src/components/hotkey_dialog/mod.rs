@@ -74,7 +74,9 @@
 //! # Features
 //!
 //! - **Search filtering**: Type to filter hotkeys across all categories
-//! - **Category navigation**: Arrow keys to navigate between categories
+//! - **Category navigation**: Arrow keys (or Ctrl+PgUp/PgDn from anywhere) to
+//!   switch categories, listed in a sidebar or tab strip with per-category
+//!   counts
 //! - **Mouse support**: Click to select categories and hotkeys
 //! - **Scrolling**: Page up/down and mouse scroll for long lists
 //! - **Customizable styling**: Colors, sizes, and text can be customized
@@ -91,6 +93,6 @@ pub use handlers::{
     is_navigation_key,
 };
 pub use state::{CategoryClickRegion, HotkeyClickRegion, HotkeyDialogState, HotkeyFocus};
-pub use style::HotkeyDialogStyle;
+pub use style::{HotkeyCategoryLayout, HotkeyDialogStyle};
 pub use traits::{HotkeyCategory, HotkeyEntryData, HotkeyProvider};
 pub use widget::{HotkeyDialog, render_hotkey_dialog};
@@ -64,9 +64,12 @@ pub struct HotkeyDialogState<C: HotkeyCategory> {
     pub search_cursor_pos: usize,
     /// Currently selected category
     pub selected_category: C,
-    /// Scroll offset for category list (if needed)
+    /// Category search results are limited to, set by selecting a category
+    /// while searching
+    pub search_scope: Option<C>,
+    /// Scroll offset for the category list, independent of the hotkey list
     pub category_scroll: usize,
-    /// Scroll offset for hotkey list
+    /// Scroll offset for the hotkey list, independent of the category list
     pub hotkey_scroll: usize,
     /// Selected hotkey index within current view
     pub selected_hotkey_idx: usize,
@@ -76,6 +79,11 @@ pub struct HotkeyDialogState<C: HotkeyCategory> {
     pub category_click_regions: Vec<CategoryClickRegion<C>>,
     /// Click regions for hotkeys (populated during render)
     pub hotkey_click_regions: Vec<HotkeyClickRegion>,
+    /// Inner area of the category list (set during render), used to route
+    /// mouse scrolling
+    pub category_list_area: Option<Rect>,
+    /// Inner area of the hotkey list (set during render)
+    pub hotkey_list_area: Option<Rect>,
     /// Cached current entries count (updated during render)
     cached_entry_count: usize,
 }
@@ -93,28 +101,71 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
             search_query: String::new(),
             search_cursor_pos: 0,
             selected_category: C::default(),
+            search_scope: None,
             category_scroll: 0,
             hotkey_scroll: 0,
             selected_hotkey_idx: 0,
             focus: HotkeyFocus::CategoryList,
             category_click_regions: Vec::new(),
             hotkey_click_regions: Vec::new(),
+            category_list_area: None,
+            hotkey_list_area: None,
             cached_entry_count: 0,
         }
     }
 
     /// Move to next category.
     pub fn next_category(&mut self) {
-        self.selected_category = self.selected_category.next();
-        self.hotkey_scroll = 0;
-        self.selected_hotkey_idx = 0;
+        self.select_category(self.selected_category.next());
     }
 
     /// Move to previous category.
     pub fn prev_category(&mut self) {
-        self.selected_category = self.selected_category.prev();
+        self.select_category(self.selected_category.prev());
+    }
+
+    /// Select a category, scoping the search results to it while searching.
+    pub fn select_category(&mut self, category: C) {
+        self.selected_category = category;
+        if self.is_searching() {
+            self.search_scope = Some(category);
+        }
         self.hotkey_scroll = 0;
         self.selected_hotkey_idx = 0;
+        if let Some(area) = self.category_list_area {
+            self.ensure_category_visible(area.height as usize);
+        }
+    }
+
+    /// Whether `category` is the highlighted one: the selected category, or
+    /// the search scope while searching.
+    pub fn is_category_active(&self, category: C) -> bool {
+        if self.is_searching() {
+            self.search_scope == Some(category)
+        } else {
+            self.selected_category == category
+        }
+    }
+
+    /// Number of hotkeys per category, in [`HotkeyCategory::all`] order.
+    ///
+    /// While searching these are match counts, ignoring the search scope.
+    pub fn category_counts<P: HotkeyProvider<Category = C>>(
+        &self,
+        provider: &P,
+    ) -> Vec<(C, usize)> {
+        if self.is_searching() {
+            let results = provider.search(&self.search_query);
+            C::all()
+                .iter()
+                .map(|&c| (c, results.iter().filter(|(rc, _)| *rc == c).count()))
+                .collect()
+        } else {
+            C::all()
+                .iter()
+                .map(|&c| (c, provider.entries_for_category(c).len()))
+                .collect()
+        }
     }
 
     /// Move to next hotkey in list.
@@ -123,6 +174,7 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
             self.selected_hotkey_idx =
                 (self.selected_hotkey_idx + 1).min(self.cached_entry_count - 1);
         }
+        self.follow_hotkey_selection();
     }
 
     /// Move to previous hotkey in list.
@@ -130,6 +182,16 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
         if self.selected_hotkey_idx > 0 {
             self.selected_hotkey_idx -= 1;
         }
+        self.follow_hotkey_selection();
+    }
+
+    /// Scroll the hotkey list to the selection after keyboard navigation.
+    fn follow_hotkey_selection(&mut self) {
+        if let Some(area) = self.hotkey_list_area
+            && area.height > 0
+        {
+            self.ensure_hotkey_visible(area.height as usize);
+        }
     }
 
     /// Move hotkey selection by a page (10 items).
@@ -148,7 +210,7 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
 
     /// Scroll hotkey list down.
     pub fn scroll_hotkeys_down(&mut self, amount: usize) {
-        let max_scroll = self.cached_entry_count.saturating_sub(1);
+        let max_scroll = max_scroll(self.cached_entry_count, self.hotkey_list_area);
         self.hotkey_scroll = (self.hotkey_scroll + amount).min(max_scroll);
     }
 
@@ -157,6 +219,17 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
         self.hotkey_scroll = self.hotkey_scroll.saturating_sub(amount);
     }
 
+    /// Scroll category list down, leaving the hotkey list as it is.
+    pub fn scroll_categories_down(&mut self, amount: usize) {
+        let max_scroll = max_scroll(C::all().len(), self.category_list_area);
+        self.category_scroll = (self.category_scroll + amount).min(max_scroll);
+    }
+
+    /// Scroll category list up, leaving the hotkey list as it is.
+    pub fn scroll_categories_up(&mut self, amount: usize) {
+        self.category_scroll = self.category_scroll.saturating_sub(amount);
+    }
+
     /// Move to next focus area.
     pub fn focus_next(&mut self) {
         self.focus = self.focus.next();
@@ -178,8 +251,7 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
         provider: &P,
     ) -> Vec<HotkeyEntryData> {
         if self.is_searching() {
-            provider
-                .search(&self.search_query)
+            self.get_search_results(provider)
                 .into_iter()
                 .map(|(_, entry)| entry)
                 .collect()
@@ -188,7 +260,7 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
         }
     }
 
-    /// Get search results using the provider.
+    /// Get search results using the provider, limited to the search scope.
     pub fn get_search_results<P: HotkeyProvider<Category = C>>(
         &self,
         provider: &P,
@@ -196,7 +268,11 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
         if self.search_query.is_empty() {
             return vec![];
         }
-        provider.search(&self.search_query)
+        let mut results = provider.search(&self.search_query);
+        if let Some(scope) = self.search_scope {
+            results.retain(|(c, _)| *c == scope);
+        }
+        results
     }
 
     /// Get the selected entry using the provider.
@@ -234,6 +310,7 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
             self.search_query
                 .replace_range(byte_pos..byte_pos + c.len_utf8(), "");
             self.selected_hotkey_idx = 0;
+            self.drop_scope_if_not_searching();
             return true;
         }
         false
@@ -247,6 +324,7 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
                 self.search_query
                     .replace_range(byte_pos..byte_pos + c.len_utf8(), "");
                 self.selected_hotkey_idx = 0;
+                self.drop_scope_if_not_searching();
                 return true;
             }
         }
@@ -278,14 +356,22 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
         self.search_cursor_pos = self.search_query.chars().count();
     }
 
-    /// Clear search query.
+    /// Clear search query and scope.
     pub fn clear_search(&mut self) {
         self.search_query.clear();
+        self.search_scope = None;
         self.search_cursor_pos = 0;
         self.hotkey_scroll = 0;
         self.selected_hotkey_idx = 0;
     }
 
+    /// The search scope only applies while there is a query.
+    fn drop_scope_if_not_searching(&mut self) {
+        if !self.is_searching() {
+            self.search_scope = None;
+        }
+    }
+
     /// Convert character index to byte index.
     fn char_to_byte_index(&self, char_idx: usize) -> usize {
         self.search_query
@@ -335,9 +421,7 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
                 && row >= region.area.y
                 && row < region.area.y + region.area.height
             {
-                self.selected_category = region.category;
-                self.hotkey_scroll = 0;
-                self.selected_hotkey_idx = 0;
+                self.select_category(region.category);
                 self.focus = HotkeyFocus::CategoryList;
                 return true;
             }
@@ -367,6 +451,27 @@ impl<C: HotkeyCategory> HotkeyDialogState<C> {
             self.hotkey_scroll = self.selected_hotkey_idx - visible_height + 1;
         }
     }
+
+    /// Ensure the selected category is visible in the category list.
+    pub fn ensure_category_visible(&mut self, visible_height: usize) {
+        let Some(idx) = C::all().iter().position(|c| *c == self.selected_category) else {
+            return;
+        };
+        if idx < self.category_scroll {
+            self.category_scroll = idx;
+        } else if visible_height > 0 && idx >= self.category_scroll + visible_height {
+            self.category_scroll = idx - visible_height + 1;
+        }
+    }
+}
+
+/// Largest scroll offset for `count` rows: the last page fills the pane
+/// once its area is known.
+fn max_scroll(count: usize, area: Option<Rect>) -> usize {
+    match area {
+        Some(area) => count.saturating_sub(area.height as usize),
+        None => count.saturating_sub(1),
+    }
 }
 
 #[cfg(test)]
@@ -486,4 +591,88 @@ mod tests {
         state.clear_search();
         assert!(!state.is_searching());
     }
+
+    struct TestProvider;
+
+    impl HotkeyProvider for TestProvider {
+        type Category = TestCategory;
+
+        fn entries_for_category(&self, category: TestCategory) -> Vec<HotkeyEntryData> {
+            match category {
+                TestCategory::First => vec![
+                    HotkeyEntryData::global("Ctrl+S", "Save file"),
+                    HotkeyEntryData::global("Ctrl+Q", "Quit"),
+                    HotkeyEntryData::global("Ctrl+O", "Open file"),
+                ],
+                TestCategory::Second => vec![HotkeyEntryData::global("Ctrl+D", "Delete file")],
+            }
+        }
+
+        fn search(&self, query: &str) -> Vec<(TestCategory, HotkeyEntryData)> {
+            TestCategory::all()
+                .iter()
+                .flat_map(|&c| {
+                    self.entries_for_category(c)
+                        .into_iter()
+                        .filter(|e| e.action.contains(query))
+                        .map(move |e| (c, e))
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_category_counts_while_searching() {
+        let mut state: HotkeyDialogState<TestCategory> = HotkeyDialogState::new();
+        assert_eq!(
+            state.category_counts(&TestProvider),
+            vec![(TestCategory::First, 3), (TestCategory::Second, 1)]
+        );
+
+        for c in "file".chars() {
+            state.insert_char(c);
+        }
+        assert_eq!(
+            state.category_counts(&TestProvider),
+            vec![(TestCategory::First, 2), (TestCategory::Second, 1)]
+        );
+        assert_eq!(state.get_current_entries(&TestProvider).len(), 3);
+    }
+
+    #[test]
+    fn test_selecting_category_while_searching_scopes_results() {
+        let mut state: HotkeyDialogState<TestCategory> = HotkeyDialogState::new();
+
+        // Not searching: no scope
+        state.select_category(TestCategory::Second);
+        assert_eq!(state.search_scope, None);
+        assert!(state.is_category_active(TestCategory::Second));
+
+        for c in "file".chars() {
+            state.insert_char(c);
+        }
+        assert!(!state.is_category_active(TestCategory::Second));
+
+        state.add_category_click_region(Rect::new(0, 0, 10, 1), TestCategory::First);
+        assert!(state.handle_click(3, 0));
+        assert_eq!(state.search_scope, Some(TestCategory::First));
+        assert!(state.is_category_active(TestCategory::First));
+        let entries = state.get_current_entries(&TestProvider);
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.action != "Delete file"));
+
+        // Counts ignore the scope
+        assert_eq!(
+            state.category_counts(&TestProvider)[1],
+            (TestCategory::Second, 1)
+        );
+
+        // Emptying the query drops the scope
+        for _ in 0..4 {
+            state.delete_char_backward();
+        }
+        assert_eq!(state.search_scope, None);
+        state.insert_char('f');
+        assert_eq!(state.get_current_entries(&TestProvider).len(), 3);
+    }
 }
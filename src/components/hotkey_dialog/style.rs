@@ -4,6 +4,16 @@
 
 use ratatui::style::{Color, Modifier, Style};
 
+/// Where the hotkey dialog lists its categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HotkeyCategoryLayout {
+    /// A sidebar to the left of the hotkey list
+    #[default]
+    Sidebar,
+    /// A one-row tab strip above the hotkey list
+    Tabs,
+}
+
 /// Style configuration for the hotkey dialog.
 #[derive(Debug, Clone)]
 pub struct HotkeyDialogStyle {
@@ -47,6 +57,11 @@ pub struct HotkeyDialogStyle {
     pub min_height: u16,
     /// Category list width percentage (0-100)
     pub category_width_percent: u16,
+    /// How the categories are listed
+    pub category_layout: HotkeyCategoryLayout,
+    /// Minimum content width for listing categories; narrower dialogs only
+    /// show the current category in the hotkey list header
+    pub category_min_width: u16,
     /// Search bar height
     pub search_height: u16,
     /// Footer height
@@ -57,6 +72,8 @@ pub struct HotkeyDialogStyle {
     pub locked_indicator: String,
     /// Search placeholder text
     pub search_placeholder: String,
+    /// Style for the category and hotkey pane scrollbars
+    pub scrollbar_style: Style,
 }
 
 impl Default for HotkeyDialogStyle {
@@ -82,11 +99,14 @@ impl Default for HotkeyDialogStyle {
             min_width: 70,
             min_height: 25,
             category_width_percent: 28,
+            category_layout: HotkeyCategoryLayout::Sidebar,
+            category_min_width: 60,
             search_height: 3,
             footer_height: 2,
             global_indicator: "[G]".to_string(),
             locked_indicator: "L".to_string(),
             search_placeholder: "Type to filter hotkeys...".to_string(),
+            scrollbar_style: Style::default(),
         }
     }
 }
@@ -115,11 +135,14 @@ impl From<&crate::theme::Theme> for HotkeyDialogStyle {
             min_width: 70,
             min_height: 25,
             category_width_percent: 28,
+            category_layout: HotkeyCategoryLayout::Sidebar,
+            category_min_width: 60,
             search_height: 3,
             footer_height: 2,
             global_indicator: "[G]".to_string(),
             locked_indicator: "L".to_string(),
             search_placeholder: "Type to filter hotkeys...".to_string(),
+            scrollbar_style: Style::default().fg(p.text_dim),
         }
     }
 }
@@ -170,12 +193,30 @@ impl HotkeyDialogStyle {
         self
     }
 
+    /// Set how the categories are listed.
+    pub fn category_layout(mut self, layout: HotkeyCategoryLayout) -> Self {
+        self.category_layout = layout;
+        self
+    }
+
+    /// Set the content width below which categories are not listed.
+    pub fn category_min_width(mut self, width: u16) -> Self {
+        self.category_min_width = width;
+        self
+    }
+
     /// Set the search placeholder text.
     pub fn search_placeholder(mut self, text: impl Into<String>) -> Self {
         self.search_placeholder = text.into();
         self
     }
 
+    /// Set the style for the pane scrollbars.
+    pub fn scrollbar_style(mut self, style: Style) -> Self {
+        self.scrollbar_style = style;
+        self
+    }
+
     /// Get the style for a focused border.
     pub fn focused_border_style(&self) -> Style {
         Style::default().fg(self.border_focused)
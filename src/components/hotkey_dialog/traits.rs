@@ -3,6 +3,10 @@
 //! This module provides traits that allow applications to implement their own
 //! hotkey categories and content while using the generic dialog widget.
 
+use crossterm::event::KeyEvent;
+
+use crate::events::KeyMatcher;
+
 /// A category for organizing hotkeys.
 ///
 /// Implement this trait on your application's category enum to provide
@@ -70,11 +74,15 @@ pub trait HotkeyCategory: Clone + Copy + PartialEq + Default + 'static {
 /// Generic representation of a hotkey entry.
 ///
 /// This struct holds the display data for a single hotkey, independent
-/// of any application-specific context types.
+/// of any application-specific context types. Entries built with
+/// [`HotkeyEntryData::bound`] also carry the [`KeyMatcher`] they describe,
+/// so the dialog text and key dispatch share one definition.
 #[derive(Debug, Clone)]
 pub struct HotkeyEntryData {
     /// The keyboard shortcut (e.g., "Ctrl+C", "F1")
     pub key_combination: String,
+    /// The binding, if the entry was built from one
+    pub matcher: Option<KeyMatcher>,
     /// Description of what the hotkey does
     pub action: String,
     /// Context description (e.g., "Global", "Normal Mode")
@@ -94,6 +102,7 @@ impl HotkeyEntryData {
     ) -> Self {
         Self {
             key_combination: key_combination.into(),
+            matcher: None,
             action: action.into(),
             context: context.into(),
             is_global: false,
@@ -101,10 +110,23 @@ impl HotkeyEntryData {
         }
     }
 
+    /// Create an entry for a binding, displayed as the matcher's chord text.
+    pub fn bound(
+        matcher: KeyMatcher,
+        action: impl Into<String>,
+        context: impl Into<String>,
+    ) -> Self {
+        Self {
+            matcher: Some(matcher.clone()),
+            ..Self::new(matcher.to_string(), action, context)
+        }
+    }
+
     /// Create a global hotkey entry.
     pub fn global(key_combination: impl Into<String>, action: impl Into<String>) -> Self {
         Self {
             key_combination: key_combination.into(),
+            matcher: None,
             action: action.into(),
             context: "Global".to_string(),
             is_global: true,
@@ -123,6 +145,13 @@ impl HotkeyEntryData {
         self.is_global = is_global;
         self
     }
+
+    /// Check if a key event triggers this entry's binding.
+    ///
+    /// Always false for entries without a [`KeyMatcher`].
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        self.matcher.as_ref().is_some_and(|m| m.matches(key))
+    }
 }
 
 /// Provider of hotkey entries for the dialog.
@@ -238,5 +267,18 @@ mod tests {
 
         let fixed = HotkeyEntryData::new("Ctrl+C", "Quit", "Global").fixed();
         assert!(!fixed.is_customizable);
+        assert!(fixed.matcher.is_none());
+    }
+
+    #[test]
+    fn test_bound_entry() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let matcher: KeyMatcher = "ctrl+s|f2".parse().unwrap();
+        let entry = HotkeyEntryData::bound(matcher, "Save file", "Normal");
+        assert_eq!(entry.key_combination, "Ctrl+S / F2");
+        assert!(entry.matches(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)));
+        assert!(entry.matches(&KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE)));
+        assert!(!entry.matches(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)));
     }
 }
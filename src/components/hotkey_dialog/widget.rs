@@ -4,15 +4,22 @@
 
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    widgets::{
+        Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        StatefulWidget, Widget,
+    },
 };
 
+use unicode_width::UnicodeWidthStr;
+
 use super::state::{HotkeyDialogState, HotkeyFocus};
-use super::style::HotkeyDialogStyle;
+use super::style::{HotkeyCategoryLayout, HotkeyDialogStyle};
 use super::traits::{HotkeyCategory, HotkeyEntryData, HotkeyProvider};
+use crate::state::{OverlayLayer, OverlayManager};
 
 /// A hotkey configuration dialog widget.
 ///
@@ -58,16 +65,28 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
     }
 
     /// Render the dialog to the frame.
-    pub fn render(mut self, frame: &mut Frame, _area: Rect) {
+    pub fn render(self, frame: &mut Frame, _area: Rect) {
         let screen = frame.area();
+        self.render_to_buffer(screen, frame.buffer_mut());
+    }
+
+    /// Render the dialog into an [`OverlayManager`] on the
+    /// [`OverlayLayer::Dialog`] layer, centered in `screen`.
+    pub fn render_into_overlays(self, screen: Rect, overlays: &mut OverlayManager<'_>) {
+        overlays.capture(OverlayLayer::Dialog, screen, |buf| {
+            self.render_to_buffer(screen, buf)
+        });
+    }
 
+    /// Render the dialog using Buffer, centered in `screen`.
+    pub fn render_to_buffer(mut self, screen: Rect, buf: &mut Buffer) {
         // Calculate modal dimensions
         let (x, y, modal_width, modal_height) =
             self.style.calculate_modal_area(screen.width, screen.height);
         let modal_area = Rect::new(x, y, modal_width, modal_height);
 
         // Clear background
-        frame.render_widget(Clear, modal_area);
+        Clear.render(modal_area, buf);
 
         // Outer border with title
         let border_color = ratatui::style::Color::Cyan;
@@ -78,7 +97,7 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
             .title_style(self.style.title_style());
 
         let inner = block.inner(modal_area);
-        frame.render_widget(block, modal_area);
+        block.render(modal_area, buf);
 
         // Clear click regions before rendering
         self.state.clear_click_regions();
@@ -94,24 +113,49 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
             .split(inner);
 
         // Render components
-        self.render_search_bar(frame, main_chunks[0]);
-
-        // Split main content: Categories | Hotkeys
-        let content_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(self.style.category_width_percent),
-                Constraint::Percentage(100 - self.style.category_width_percent),
-            ])
-            .split(main_chunks[1]);
+        self.render_search_bar(buf, main_chunks[0]);
 
-        self.render_category_list(frame, content_chunks[0]);
-        self.render_hotkey_list(frame, content_chunks[1]);
-        self.render_footer(frame, main_chunks[2]);
+        let content = main_chunks[1];
+        if content.width < self.style.category_min_width
+            || self.style.category_layout != HotkeyCategoryLayout::Sidebar
+        {
+            self.state.category_list_area = None;
+        }
+        if content.width < self.style.category_min_width {
+            // Too narrow for a category list: the hotkey list header names
+            // the current category
+            self.render_hotkey_list(buf, content, true);
+        } else {
+            match self.style.category_layout {
+                HotkeyCategoryLayout::Sidebar => {
+                    // Split main content: Categories | Hotkeys
+                    let content_chunks = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([
+                            Constraint::Percentage(self.style.category_width_percent),
+                            Constraint::Percentage(100 - self.style.category_width_percent),
+                        ])
+                        .split(content);
+
+                    self.render_category_list(buf, content_chunks[0]);
+                    self.render_hotkey_list(buf, content_chunks[1], false);
+                }
+                HotkeyCategoryLayout::Tabs => {
+                    let content_chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Length(3), Constraint::Min(1)])
+                        .split(content);
+
+                    self.render_category_tabs(buf, content_chunks[0]);
+                    self.render_hotkey_list(buf, content_chunks[1], false);
+                }
+            }
+        }
+        self.render_footer(buf, main_chunks[2]);
     }
 
     /// Render the search bar.
-    fn render_search_bar(&mut self, frame: &mut Frame, area: Rect) {
+    fn render_search_bar(&mut self, buf: &mut Buffer, area: Rect) {
         let is_focused = self.state.focus == HotkeyFocus::SearchInput;
         let border_style = if is_focused {
             self.style.focused_border_style()
@@ -125,7 +169,7 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
             .title(" Search ");
 
         let inner = block.inner(area);
-        frame.render_widget(block, area);
+        block.render(area, buf);
 
         // Build search text with cursor
         let text = if self.state.search_query.is_empty() && !is_focused {
@@ -149,37 +193,52 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
         };
 
         let paragraph = Paragraph::new(text);
-        frame.render_widget(paragraph, inner);
+        paragraph.render(inner, buf);
     }
 
-    /// Render the category list.
-    fn render_category_list(&mut self, frame: &mut Frame, area: Rect) {
-        let is_focused = self.state.focus == HotkeyFocus::CategoryList;
-        let border_style = if is_focused {
+    /// Block around the category list or tab strip.
+    fn category_block(&self) -> Block<'static> {
+        let border_style = if self.state.focus == HotkeyFocus::CategoryList {
             self.style.focused_border_style()
         } else {
             self.style.unfocused_border_style()
         };
-
-        let block = Block::default()
+        Block::default()
             .borders(Borders::ALL)
             .border_style(border_style)
-            .title(" Categories ");
+            .title(" Categories ")
+    }
 
+    /// Render the category list.
+    fn render_category_list(&mut self, buf: &mut Buffer, area: Rect) {
+        let block = self.category_block();
         let inner = block.inner(area);
-        frame.render_widget(block, area);
+        block.render(area, buf);
+
+        // Follow the selection when the list first appears; afterwards it
+        // scrolls on its own
+        let visible_height = inner.height as usize;
+        if self.state.category_list_area.is_none() {
+            self.state.ensure_category_visible(visible_height);
+        }
+        self.state.category_list_area = Some(inner);
+
+        let counts = self.state.category_counts(self.provider);
+        let total = counts.len();
+        let scroll = self
+            .state
+            .category_scroll
+            .min(total.saturating_sub(visible_height));
+        self.state.category_scroll = scroll;
 
-        let categories = C::all();
         let mut lines = Vec::new();
 
-        for (idx, category) in categories.iter().enumerate() {
-            let is_selected =
-                *category == self.state.selected_category && !self.state.is_searching();
+        for (idx, (category, count)) in counts.into_iter().enumerate() {
+            let is_selected = self.state.is_category_active(category);
 
             let prefix = if is_selected { "> " } else { "  " };
             let icon = category.icon();
-            let name = category.display_name();
-            let count = self.provider.entries_for_category(*category).len();
+            let name = category.display_name().to_string();
 
             let style = if is_selected {
                 self.style.selected_style()
@@ -201,22 +260,122 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
             ]);
             lines.push(line);
 
-            // Register click region
-            let row_y = inner.y + idx as u16;
-            if row_y < inner.y + inner.height {
-                self.state.add_category_click_region(
-                    Rect::new(inner.x, row_y, inner.width, 1),
-                    *category,
-                );
+            // Register click region (only for visible categories)
+            if idx >= scroll && idx - scroll < visible_height {
+                let row_y = inner.y + (idx - scroll) as u16;
+                self.state
+                    .add_category_click_region(Rect::new(inner.x, row_y, inner.width, 1), category);
             }
         }
 
-        let paragraph = Paragraph::new(lines);
-        frame.render_widget(paragraph, inner);
+        let paragraph = Paragraph::new(lines).scroll((scroll as u16, 0));
+        paragraph.render(inner, buf);
+
+        self.render_scrollbar(buf, area, total, scroll, visible_height);
+    }
+
+    /// Render a vertical scrollbar on the right border of `area` when
+    /// `total` rows do not fit.
+    fn render_scrollbar(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        total: usize,
+        scroll: usize,
+        visible_height: usize,
+    ) {
+        if total <= visible_height {
+            return;
+        }
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("^"))
+            .end_symbol(Some("v"))
+            .style(self.style.scrollbar_style);
+
+        let mut scrollbar_state = ScrollbarState::new(total)
+            .position(scroll)
+            .viewport_content_length(visible_height);
+
+        let scrollbar_area = Rect::new(
+            area.x + area.width - 1,
+            area.y + 1,
+            1,
+            area.height.saturating_sub(2),
+        );
+
+        StatefulWidget::render(scrollbar, scrollbar_area, buf, &mut scrollbar_state);
+    }
+
+    /// Render the categories as a one-row tab strip.
+    ///
+    /// Tabs scroll so the active one stays visible.
+    fn render_category_tabs(&mut self, buf: &mut Buffer, area: Rect) {
+        let block = self.category_block();
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let tabs: Vec<(C, String)> = self
+            .state
+            .category_counts(self.provider)
+            .into_iter()
+            .map(|(category, count)| {
+                let icon = category.icon();
+                let icon = if icon.is_empty() {
+                    String::new()
+                } else {
+                    format!("{icon} ")
+                };
+                (
+                    category,
+                    format!(" {icon}{} ({count}) ", category.display_name()),
+                )
+            })
+            .collect();
+
+        // Scroll so the active tab (plus its separator) fits
+        let widths: Vec<u16> = tabs.iter().map(|(_, l)| l.width() as u16 + 1).collect();
+        let active = tabs
+            .iter()
+            .position(|(c, _)| self.state.is_category_active(*c))
+            .unwrap_or(0);
+        let mut first = 0;
+        while first < active && widths[first..=active].iter().sum::<u16>() > inner.width {
+            first += 1;
+        }
+
+        let right = inner.x + inner.width;
+        let mut x = inner.x;
+        for (idx, (category, label)) in tabs.iter().enumerate().skip(first) {
+            if x >= right {
+                break;
+            }
+            if idx > first {
+                buf.set_string(x, inner.y, "│", self.style.dim_style());
+                x += 1;
+            }
+            let style = if self.state.is_category_active(*category) {
+                self.style.selected_style()
+            } else {
+                self.style.text_style()
+            };
+            let (end, _) =
+                buf.set_stringn(x, inner.y, label, right.saturating_sub(x) as usize, style);
+            if end > x {
+                self.state
+                    .add_category_click_region(Rect::new(x, inner.y, end - x, 1), *category);
+            }
+            x = end;
+        }
     }
 
     /// Render the hotkey list.
-    fn render_hotkey_list(&mut self, frame: &mut Frame, area: Rect) {
+    ///
+    /// `header_only` is set when no category list is shown, so the title
+    /// also gives the category's position.
+    fn render_hotkey_list(&mut self, buf: &mut Buffer, area: Rect, header_only: bool) {
         let is_focused = self.state.focus == HotkeyFocus::HotkeyList;
         let border_style = if is_focused {
             self.style.focused_border_style()
@@ -227,7 +386,23 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
         // Title shows category name or "Search Results"
         let title = if self.state.is_searching() {
             let count = self.state.get_search_results(self.provider).len();
-            format!(" Search Results ({}) ", count)
+            match self.state.search_scope {
+                Some(scope) => format!(" Search Results in {} ({}) ", scope.display_name(), count),
+                None => format!(" Search Results ({}) ", count),
+            }
+        } else if header_only {
+            let all = C::all();
+            let position = all
+                .iter()
+                .position(|c| *c == self.state.selected_category)
+                .map_or(0, |i| i + 1);
+            format!(
+                " {} {} ({}/{}) ",
+                self.state.selected_category.icon(),
+                self.state.selected_category.display_name(),
+                position,
+                all.len()
+            )
         } else {
             format!(
                 " {} {} ",
@@ -242,7 +417,12 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
             .title(title);
 
         let inner = block.inner(area);
-        frame.render_widget(block, area);
+        block.render(area, buf);
+
+        // Follow the selection when the list first appears; afterwards
+        // keyboard navigation keeps it visible and the wheel scrolls freely
+        let first_render = self.state.hotkey_list_area.is_none();
+        self.state.hotkey_list_area = Some(inner);
 
         // Get entries to display
         let entries = self.state.get_current_entries(self.provider);
@@ -261,7 +441,7 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
                 msg,
                 self.style.placeholder_style(),
             )));
-            frame.render_widget(paragraph, inner);
+            paragraph.render(inner, buf);
             return;
         }
 
@@ -276,8 +456,9 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
         // Visible height for scrolling
         let visible_height = inner.height as usize;
 
-        // Ensure selected hotkey is visible
-        self.state.ensure_hotkey_visible(visible_height);
+        if first_render {
+            self.state.ensure_hotkey_visible(visible_height);
+        }
 
         // Build lines with proper formatting
         let lines =
@@ -290,27 +471,9 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
             .min(total_entries.saturating_sub(1));
 
         let paragraph = Paragraph::new(lines).scroll((scroll as u16, 0));
-        frame.render_widget(paragraph, inner);
-
-        // Render scrollbar if needed
-        if total_entries > visible_height {
-            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .begin_symbol(Some("^"))
-                .end_symbol(Some("v"));
-
-            let mut scrollbar_state = ScrollbarState::new(total_entries)
-                .position(scroll)
-                .viewport_content_length(visible_height);
-
-            let scrollbar_area = Rect::new(
-                area.x + area.width - 1,
-                area.y + 1,
-                1,
-                area.height.saturating_sub(2),
-            );
-
-            frame.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
-        }
+        paragraph.render(inner, buf);
+
+        self.render_scrollbar(buf, area, total_entries, scroll, visible_height);
     }
 
     /// Build the lines for the hotkey list.
@@ -389,7 +552,7 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
     }
 
     /// Render the footer with key hints and legend.
-    fn render_footer(&self, frame: &mut Frame, area: Rect) {
+    fn render_footer(&self, buf: &mut Buffer, area: Rect) {
         let hints = match self.state.focus {
             HotkeyFocus::SearchInput => vec![
                 ("Esc", "Clear/Close"),
@@ -397,12 +560,12 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
                 ("Type", "Filter"),
             ],
             HotkeyFocus::CategoryList => {
-                vec![("Up/Dn", "Navigate"), ("Tab", "Hotkeys"), ("Esc", "Close")]
+                vec![("Arrows", "Category"), ("Tab", "Hotkeys"), ("Esc", "Close")]
             }
             HotkeyFocus::HotkeyList => vec![
                 ("Up/Dn", "Navigate"),
                 ("PgUp/Dn", "Page"),
-                ("Tab", "Search"),
+                ("^PgUp/Dn", "Category"),
                 ("Esc", "Close"),
             ],
         };
@@ -442,7 +605,7 @@ impl<'a, C: HotkeyCategory, P: HotkeyProvider<Category = C>> HotkeyDialog<'a, C,
                 .border_style(self.style.unfocused_border_style()),
         );
 
-        frame.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
 }
 
@@ -458,3 +621,152 @@ pub fn render_hotkey_dialog<C: HotkeyCategory, P: HotkeyProvider<Category = C>>(
     let dialog = HotkeyDialog::new(state, provider, style);
     dialog.render(frame, frame.area());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+    enum TestCategory {
+        #[default]
+        Files,
+        Edit,
+        View,
+    }
+
+    impl HotkeyCategory for TestCategory {
+        fn all() -> &'static [Self] {
+            &[Self::Files, Self::Edit, Self::View]
+        }
+
+        fn display_name(&self) -> &str {
+            match self {
+                Self::Files => "Files",
+                Self::Edit => "Edit",
+                Self::View => "View",
+            }
+        }
+
+        fn next(&self) -> Self {
+            match self {
+                Self::Files => Self::Edit,
+                Self::Edit => Self::View,
+                Self::View => Self::Files,
+            }
+        }
+
+        fn prev(&self) -> Self {
+            match self {
+                Self::Files => Self::View,
+                Self::Edit => Self::Files,
+                Self::View => Self::Edit,
+            }
+        }
+    }
+
+    struct TestProvider;
+
+    impl HotkeyProvider for TestProvider {
+        type Category = TestCategory;
+
+        fn entries_for_category(&self, category: TestCategory) -> Vec<HotkeyEntryData> {
+            match category {
+                TestCategory::Files => vec![
+                    HotkeyEntryData::global("Ctrl+S", "Save"),
+                    HotkeyEntryData::global("Ctrl+O", "Open"),
+                ],
+                TestCategory::Edit => vec![HotkeyEntryData::global("Ctrl+Z", "Undo save")],
+                TestCategory::View => vec![],
+            }
+        }
+
+        fn search(&self, query: &str) -> Vec<(TestCategory, HotkeyEntryData)> {
+            let query = query.to_lowercase();
+            TestCategory::all()
+                .iter()
+                .flat_map(|&c| {
+                    self.entries_for_category(c)
+                        .into_iter()
+                        .filter(|e| e.action.to_lowercase().contains(&query))
+                        .map(move |e| (c, e))
+                })
+                .collect()
+        }
+    }
+
+    fn render(
+        state: &mut HotkeyDialogState<TestCategory>,
+        style: &HotkeyDialogStyle,
+        width: u16,
+    ) -> Vec<String> {
+        let screen = Rect::new(0, 0, width, 30);
+        let mut buf = Buffer::empty(screen);
+        HotkeyDialog::new(state, &TestProvider, style).render_to_buffer(screen, &mut buf);
+        (0..screen.height)
+            .map(|y| (0..width).map(|x| buf[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_sidebar_shows_match_counts_while_searching() {
+        let mut state = HotkeyDialogState::new();
+        let style = HotkeyDialogStyle::default();
+
+        let rows = render(&mut state, &style, 100).join("\n");
+        assert!(rows.contains("Files (2)"));
+        assert!(rows.contains("Edit (1)"));
+        assert_eq!(state.category_click_regions.len(), 3);
+
+        for c in "save".chars() {
+            state.insert_char(c);
+        }
+        let rows = render(&mut state, &style, 100).join("\n");
+        assert!(rows.contains("Files (1)"));
+        assert!(rows.contains("Edit (1)"));
+        assert!(rows.contains("View (0)"));
+        assert!(rows.contains("Search Results (2)"));
+
+        // Clicking a category scopes the results
+        let edit = state.category_click_regions[1].area;
+        assert!(state.handle_click(edit.x, edit.y));
+        let rows = render(&mut state, &style, 100).join("\n");
+        assert!(rows.contains("Search Results in Edit (1)"));
+        assert!(rows.contains("> "));
+    }
+
+    #[test]
+    fn test_tab_strip_layout() {
+        let mut state = HotkeyDialogState::new();
+        let style = HotkeyDialogStyle::default().category_layout(HotkeyCategoryLayout::Tabs);
+
+        let rows = render(&mut state, &style, 100);
+        let strip = rows
+            .iter()
+            .find(|r| r.contains("Files (2)"))
+            .expect("tab strip row");
+        assert!(strip.contains(" Files (2) │ Edit (1) │ View (0) "));
+
+        // Each tab is clickable on the strip row
+        assert_eq!(state.category_click_regions.len(), 3);
+        let view = state.category_click_regions[2].clone();
+        assert_eq!(view.category, TestCategory::View);
+        assert_eq!(
+            view.area.y, state.category_click_regions[0].area.y,
+            "tabs share one row"
+        );
+        assert!(state.handle_click(view.area.x, view.area.y));
+        assert_eq!(state.selected_category, TestCategory::View);
+    }
+
+    #[test]
+    fn test_narrow_dialog_falls_back_to_header() {
+        let mut state = HotkeyDialogState::new();
+        state.next_category();
+        let style = HotkeyDialogStyle::default().min_size(40, 20);
+
+        let rows = render(&mut state, &style, 50).join("\n");
+        assert!(state.category_click_regions.is_empty());
+        assert!(!rows.contains("Categories"));
+        assert!(rows.contains("Edit (2/3)"));
+    }
+}
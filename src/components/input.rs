@@ -23,21 +23,191 @@
 //! assert_eq!(state.text, "Hello !");
 //! ```
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use crate::traits::{ClickRegion, FocusId};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::focus_ring::FocusRing;
+use crate::traits::{ClickRegion, FocusId, FocusableWidget};
 
 /// Actions an input can emit.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputAction {
     /// Focus the input.
     Focus,
+    /// Increase the numeric value by one step.
+    Increment,
+    /// Decrease the numeric value by one step.
+    Decrement,
+    /// The numeric text was valid and has been normalized.
+    Commit,
+    /// The numeric text could not be parsed.
+    Invalid,
+    /// The last edit was undone.
+    Undo,
+    /// The undone edit was restored.
+    Redo,
+}
+
+/// How an input interprets its text.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMode {
+    /// Free-form text (default).
+    #[default]
+    Text,
+    /// A number with spinner arrows, clamped to `[min, max]`.
+    Numeric {
+        /// Lowest allowed value.
+        min: Option<f64>,
+        /// Highest allowed value.
+        max: Option<f64>,
+        /// Amount added or removed by the arrows and Up/Down keys.
+        step: f64,
+        /// Digits shown after the decimal point.
+        decimal_places: u8,
+    },
+}
+
+impl InputMode {
+    /// Whether this is numeric mode.
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, InputMode::Numeric { .. })
+    }
+}
+
+/// Characters a slot of an [`InputMask`] accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaskSlot {
+    /// An ASCII digit (`_` or `9` in a template).
+    Digit,
+    /// An ASCII hex digit (`H` in a template).
+    Hex,
+    /// A letter (`A` in a template).
+    Alpha,
+    /// Any printable character (`*` in a template).
+    Any,
+}
+
+impl MaskSlot {
+    /// Whether the slot accepts `c`.
+    pub fn accepts(&self, c: char) -> bool {
+        match self {
+            MaskSlot::Digit => c.is_ascii_digit(),
+            MaskSlot::Hex => c.is_ascii_hexdigit(),
+            MaskSlot::Alpha => c.is_alphabetic(),
+            MaskSlot::Any => !c.is_control(),
+        }
+    }
+}
+
+/// One position of an [`InputMask`] template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MaskPart {
+    Literal(char),
+    Slot(MaskSlot),
+}
+
+/// A format template such as `"(___) ___-____"` for structured input.
+///
+/// Template characters `_` and `9` are digit slots, `H` hex slots, `A`
+/// letter slots, and `*` slots for any character; `\` makes the next
+/// character a literal. Everything else is a literal that the cursor skips.
+///
+/// A masked [`InputState`] keeps only the characters typed into slots in
+/// [`text`](InputState::text), filled from the first slot without gaps.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::components::InputMask;
+///
+/// let mask = InputMask::new("HH:HH:HH", '_');
+/// assert_eq!(mask.slot_count(), 6);
+/// assert_eq!(mask.fill("0a:1B:zz".chars()), "0a1B");
+/// assert_eq!(mask.format("0a1B"), "0a:1B:__");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMask {
+    parts: Vec<MaskPart>,
+    placeholder: char,
+}
+
+impl InputMask {
+    /// Parse a template; unfilled slots render as `placeholder`.
+    pub fn new(template: &str, placeholder: char) -> Self {
+        let mut parts = Vec::new();
+        let mut chars = template.chars();
+        while let Some(c) = chars.next() {
+            parts.push(match c {
+                '\\' => MaskPart::Literal(chars.next().unwrap_or('\\')),
+                '_' | '9' => MaskPart::Slot(MaskSlot::Digit),
+                'H' => MaskPart::Slot(MaskSlot::Hex),
+                'A' => MaskPart::Slot(MaskSlot::Alpha),
+                '*' => MaskPart::Slot(MaskSlot::Any),
+                c => MaskPart::Literal(c),
+            });
+        }
+        Self { parts, placeholder }
+    }
+
+    /// The character shown in unfilled slots.
+    pub fn placeholder(&self) -> char {
+        self.placeholder
+    }
+
+    /// Number of slots in the template.
+    pub fn slot_count(&self) -> usize {
+        self.slots().count()
+    }
+
+    /// Character classes of the slots, in order.
+    pub fn slots(&self) -> impl Iterator<Item = MaskSlot> + '_ {
+        self.parts.iter().filter_map(|part| match part {
+            MaskPart::Slot(slot) => Some(*slot),
+            MaskPart::Literal(_) => None,
+        })
+    }
+
+    /// Distribute `chars` across the slots in order.
+    ///
+    /// Characters the next slot does not accept, and any beyond the last
+    /// slot, are dropped. Returns the filled slot characters.
+    pub fn fill(&self, chars: impl IntoIterator<Item = char>) -> String {
+        let mut slots = self.slots().peekable();
+        let mut raw = String::new();
+        for c in chars {
+            let Some(slot) = slots.peek() else {
+                break;
+            };
+            if slot.accepts(c) {
+                raw.push(c);
+                slots.next();
+            }
+        }
+        raw
+    }
+
+    /// The template with `raw` filled into its slots and the placeholder in
+    /// the rest.
+    pub fn format(&self, raw: &str) -> String {
+        let mut raw = raw.chars();
+        self.parts
+            .iter()
+            .map(|part| match part {
+                MaskPart::Literal(c) => *c,
+                MaskPart::Slot(_) => raw.next().unwrap_or(self.placeholder),
+            })
+            .collect()
+    }
 }
 
 /// State for an input field.
@@ -45,14 +215,34 @@ pub enum InputAction {
 pub struct InputState {
     /// The text content.
     pub text: String,
-    /// Cursor position (character index).
+    /// Cursor position (grapheme cluster index).
     pub cursor_pos: usize,
     /// Whether the input has focus.
     pub focused: bool,
+    /// Focus ID matched by [`FocusManager::sync_from_vec`](crate::state::FocusManager::sync_from_vec).
+    pub focus_id: FocusId,
     /// Whether the input is enabled.
     pub enabled: bool,
-    /// Horizontal scroll offset for long text.
+    /// Horizontal scroll offset for long text (first grapheme cluster shown).
+    ///
+    /// Rendering scrolls further right when needed to keep the cursor visible.
     pub scroll_offset: usize,
+    /// Whether an IME composition is in progress.
+    pub composing: bool,
+    /// Uncommitted IME composition text, shown at the cursor.
+    pub composition_text: String,
+    /// How the text is interpreted.
+    pub mode: InputMode,
+    /// Text and cursor before the last word delete, line delete, or paste.
+    pub last_state: Option<(String, usize)>,
+    /// Text and cursor before the last [`undo_once`](Self::undo_once).
+    pub redo_state: Option<(String, usize)>,
+    /// Format template; when set, `text` holds only the slot characters and
+    /// `cursor_pos` is a slot index.
+    pub mask: Option<InputMask>,
+    /// Text as of construction, [`set_text`](Self::set_text), or
+    /// [`mark_clean`](Self::mark_clean), compared by [`is_dirty`](Self::is_dirty).
+    pub clean_text: String,
 }
 
 impl Default for InputState {
@@ -61,54 +251,212 @@ impl Default for InputState {
             text: String::new(),
             cursor_pos: 0,
             focused: false,
+            focus_id: FocusId::default(),
             enabled: true,
             scroll_offset: 0,
+            composing: false,
+            composition_text: String::new(),
+            mode: InputMode::Text,
+            last_state: None,
+            redo_state: None,
+            mask: None,
+            clean_text: String::new(),
         }
     }
 }
 
+impl FocusableWidget for InputState {
+    fn focus_id(&self) -> FocusId {
+        self.focus_id
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
 impl InputState {
     /// Create a new input state with initial text.
     ///
     /// Cursor is positioned at the end of the text.
     pub fn new(text: impl Into<String>) -> Self {
         let text = text.into();
-        let cursor_pos = text.chars().count();
+        let cursor_pos = text.graphemes(true).count();
         Self {
+            clean_text: text.clone(),
             text,
             cursor_pos,
-            focused: false,
-            enabled: true,
-            scroll_offset: 0,
+            ..Default::default()
         }
     }
 
+    /// Set the focus ID used by [`FocusableWidget`].
+    pub fn with_focus_id(mut self, id: FocusId) -> Self {
+        self.focus_id = id;
+        self
+    }
+
     /// Create an empty input state.
     pub fn empty() -> Self {
         Self::default()
     }
 
+    /// Set the input mode.
+    pub fn with_mode(mut self, mode: InputMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Restrict input to a format template such as `"__/__/____"`.
+    ///
+    /// See [`InputMask`] for the template syntax. The current text is
+    /// redistributed across the slots and becomes the clean baseline.
+    pub fn with_input_mask(mut self, template: &str, placeholder: char) -> Self {
+        self.mask = Some(InputMask::new(template, placeholder));
+        self.remask();
+        self.cursor_pos = self.grapheme_count();
+        self.mark_clean();
+        self
+    }
+
+    /// The characters typed into the mask's slots, or the whole text
+    /// without a mask.
+    pub fn raw_value(&self) -> &str {
+        &self.text
+    }
+
+    /// The text formatted by the mask, with the placeholder in unfilled
+    /// slots, or the whole text without a mask.
+    pub fn formatted_value(&self) -> String {
+        match &self.mask {
+            Some(mask) => mask.format(&self.text),
+            None => self.text.clone(),
+        }
+    }
+
+    /// Whether every slot of the mask is filled; always `true` without a mask.
+    pub fn is_mask_complete(&self) -> bool {
+        self.mask
+            .as_ref()
+            .is_none_or(|mask| self.text.chars().count() == mask.slot_count())
+    }
+
+    /// Redistribute the text across the mask's slots, keeping the cursor
+    /// after the same characters.
+    fn remask(&mut self) {
+        let Some(mask) = &self.mask else {
+            return;
+        };
+        let head = mask.fill(self.text_before_cursor().chars());
+        let text = mask.fill(head.chars().chain(self.text_after_cursor().chars()));
+        self.cursor_pos = head.graphemes(true).count();
+        self.text = text;
+    }
+
+    /// Parse the text as a number.
+    ///
+    /// Returns `None` if the text is not a finite number.
+    pub fn numeric_value(&self) -> Option<f64> {
+        self.text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+    }
+
+    /// Increase the value by one step (numeric mode only).
+    ///
+    /// Returns `true` if the text changed.
+    pub fn increment(&mut self) -> bool {
+        self.step_value(1.0)
+    }
+
+    /// Decrease the value by one step (numeric mode only).
+    ///
+    /// Returns `true` if the text changed.
+    pub fn decrement(&mut self) -> bool {
+        self.step_value(-1.0)
+    }
+
+    /// Validate typed numeric text, clamping and reformatting it.
+    ///
+    /// Returns `false` if the text is not a number; it is left unchanged so
+    /// the user can correct it. Always `true` in text mode.
+    pub fn commit_numeric(&mut self) -> bool {
+        if !self.mode.is_numeric() {
+            return true;
+        }
+        match self.numeric_value() {
+            Some(value) => {
+                self.set_numeric(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn step_value(&mut self, direction: f64) -> bool {
+        let InputMode::Numeric { step, .. } = self.mode else {
+            return false;
+        };
+        if !self.enabled {
+            return false;
+        }
+        let value = self.numeric_value().map_or(0.0, |v| v + direction * step);
+        let before = self.text.clone();
+        self.set_numeric(value);
+        self.text != before
+    }
+
+    fn set_numeric(&mut self, value: f64) {
+        let InputMode::Numeric {
+            min,
+            max,
+            decimal_places,
+            ..
+        } = self.mode
+        else {
+            return;
+        };
+        let value = max.map_or(value, |max| value.min(max));
+        let value = min.map_or(value, |min| value.max(min));
+        self.replace_text(format!("{:.*}", decimal_places as usize, value));
+    }
+
     /// Insert a character at cursor position.
+    ///
+    /// With a mask, the character is dropped if the slot at the cursor does
+    /// not accept it or every slot is filled.
     pub fn insert_char(&mut self, c: char) {
-        if !self.enabled {
+        if !self.enabled || (self.mask.is_some() && self.is_mask_complete()) {
             return;
         }
-        let byte_pos = self.char_to_byte_index(self.cursor_pos);
+        let byte_pos = self.grapheme_to_byte_index(self.cursor_pos);
         self.text.insert(byte_pos, c);
-        self.cursor_pos += 1;
+        // A combining mark or joiner can merge into the previous cluster
+        self.cursor_pos = self.byte_to_grapheme_index(byte_pos + c.len_utf8());
+        self.remask();
     }
 
     /// Insert a string at cursor position.
+    ///
+    /// With a mask, the characters are distributed across the slots from the
+    /// cursor, dropping those the slots do not accept.
     pub fn insert_str(&mut self, s: &str) {
         if !self.enabled {
             return;
         }
-        let byte_pos = self.char_to_byte_index(self.cursor_pos);
+        let byte_pos = self.grapheme_to_byte_index(self.cursor_pos);
         self.text.insert_str(byte_pos, s);
-        self.cursor_pos += s.chars().count();
+        self.cursor_pos = self.byte_to_grapheme_index(byte_pos + s.len());
+        self.remask();
     }
 
-    /// Delete character before cursor (backspace).
+    /// Delete the grapheme cluster before cursor (backspace).
     ///
     /// Returns `true` if a character was deleted.
     pub fn delete_char_backward(&mut self) -> bool {
@@ -117,16 +465,12 @@ impl InputState {
         }
 
         self.cursor_pos -= 1;
-        let byte_pos = self.char_to_byte_index(self.cursor_pos);
-        if let Some(c) = self.text[byte_pos..].chars().next() {
-            self.text
-                .replace_range(byte_pos..byte_pos + c.len_utf8(), "");
-            return true;
-        }
-        false
+        let deleted = self.remove_grapheme(self.cursor_pos);
+        self.remask();
+        deleted
     }
 
-    /// Delete character at cursor (delete key).
+    /// Delete the grapheme cluster at cursor (delete key).
     ///
     /// Returns `true` if a character was deleted.
     pub fn delete_char_forward(&mut self) -> bool {
@@ -134,31 +478,27 @@ impl InputState {
             return false;
         }
 
-        let byte_pos = self.char_to_byte_index(self.cursor_pos);
-        if byte_pos < self.text.len() {
-            if let Some(c) = self.text[byte_pos..].chars().next() {
-                self.text
-                    .replace_range(byte_pos..byte_pos + c.len_utf8(), "");
-                return true;
-            }
-        }
-        false
+        let deleted = self.remove_grapheme(self.cursor_pos);
+        self.remask();
+        deleted
     }
 
     /// Delete word before cursor.
     ///
-    /// Returns `true` if any characters were deleted.
+    /// Returns `true` if any characters were deleted. Can be undone with
+    /// [`undo_once`](Self::undo_once).
     pub fn delete_word_backward(&mut self) -> bool {
         if !self.enabled || self.cursor_pos == 0 {
             return false;
         }
 
+        self.snapshot();
         let start_pos = self.cursor_pos;
 
         // Skip trailing whitespace
         while self.cursor_pos > 0 {
-            let prev_char = self.char_at(self.cursor_pos - 1);
-            if prev_char.map(|c| c.is_whitespace()).unwrap_or(false) {
+            let prev = self.grapheme_at(self.cursor_pos - 1);
+            if prev.is_some_and(is_whitespace) {
                 self.cursor_pos -= 1;
             } else {
                 break;
@@ -167,8 +507,8 @@ impl InputState {
 
         // Delete word characters
         while self.cursor_pos > 0 {
-            let prev_char = self.char_at(self.cursor_pos - 1);
-            if prev_char.map(|c| !c.is_whitespace()).unwrap_or(false) {
+            let prev = self.grapheme_at(self.cursor_pos - 1);
+            if prev.is_some_and(|g| !is_whitespace(g)) {
                 self.delete_char_backward();
             } else {
                 break;
@@ -178,16 +518,78 @@ impl InputState {
         start_pos != self.cursor_pos
     }
 
-    /// Move cursor left by one character.
+    /// Delete from the cursor to the end of the text.
+    ///
+    /// Returns `true` if any characters were deleted. Can be undone with
+    /// [`undo_once`](Self::undo_once).
+    pub fn delete_to_line_end(&mut self) -> bool {
+        let byte_pos = self.grapheme_to_byte_index(self.cursor_pos);
+        if !self.enabled || byte_pos == self.text.len() {
+            return false;
+        }
+        self.snapshot();
+        self.text.truncate(byte_pos);
+        true
+    }
+
+    /// Insert pasted text at the cursor.
+    ///
+    /// Same as [`insert_str`](Self::insert_str), but can be undone with
+    /// [`undo_once`](Self::undo_once).
+    pub fn paste(&mut self, s: &str) {
+        if !self.enabled || s.is_empty() {
+            return;
+        }
+        self.snapshot();
+        self.insert_str(s);
+    }
+
+    /// Restore the text and cursor from before the last word delete, line
+    /// delete, or paste.
+    ///
+    /// Only one step is kept. Returns `true` if there was something to undo.
+    pub fn undo_once(&mut self) -> bool {
+        let Some(previous) = self.last_state.take() else {
+            return false;
+        };
+        self.redo_state = Some(self.swap_state(previous));
+        true
+    }
+
+    /// Reapply the edit reverted by [`undo_once`](Self::undo_once).
+    ///
+    /// Returns `true` if there was something to redo.
+    pub fn redo_once(&mut self) -> bool {
+        let Some(next) = self.redo_state.take() else {
+            return false;
+        };
+        self.last_state = Some(self.swap_state(next));
+        true
+    }
+
+    /// Record the current text and cursor for [`undo_once`](Self::undo_once).
+    fn snapshot(&mut self) {
+        self.last_state = Some((self.text.clone(), self.cursor_pos));
+        self.redo_state = None;
+    }
+
+    /// Replace the text and cursor, returning the old ones.
+    fn swap_state(&mut self, (text, cursor_pos): (String, usize)) -> (String, usize) {
+        let old = (std::mem::replace(&mut self.text, text), self.cursor_pos);
+        self.cursor_pos = cursor_pos.min(self.grapheme_count());
+        old
+    }
+
+    /// Move cursor left by one grapheme cluster.
     pub fn move_left(&mut self) {
         if self.cursor_pos > 0 {
             self.cursor_pos -= 1;
         }
     }
 
-    /// Move cursor right by one character.
+    /// Move cursor right by one grapheme cluster.
     pub fn move_right(&mut self) {
-        let max = self.text.chars().count();
+        let max = self.grapheme_count();
         if self.cursor_pos < max {
             self.cursor_pos += 1;
         }
@@ -200,7 +602,7 @@ impl InputState {
 
     /// Move cursor to the end of the text.
     pub fn move_end(&mut self) {
-        self.cursor_pos = self.text.chars().count();
+        self.cursor_pos = self.grapheme_count();
     }
 
     /// Move cursor left by one word.
@@ -211,8 +613,8 @@ impl InputState {
 
         // Skip whitespace
         while self.cursor_pos > 0 {
-            if let Some(c) = self.char_at(self.cursor_pos - 1) {
-                if c.is_whitespace() {
+            if let Some(g) = self.grapheme_at(self.cursor_pos - 1) {
+                if is_whitespace(g) {
                     self.cursor_pos -= 1;
                 } else {
                     break;
@@ -224,8 +626,8 @@ impl InputState {
 
         // Skip word characters
         while self.cursor_pos > 0 {
-            if let Some(c) = self.char_at(self.cursor_pos - 1) {
-                if !c.is_whitespace() {
+            if let Some(g) = self.grapheme_at(self.cursor_pos - 1) {
+                if !is_whitespace(g) {
                     self.cursor_pos -= 1;
                 } else {
                     break;
@@ -238,15 +640,15 @@ impl InputState {
 
     /// Move cursor right by one word.
     pub fn move_word_right(&mut self) {
-        let max = self.text.chars().count();
+        let max = self.grapheme_count();
         if self.cursor_pos >= max {
             return;
         }
 
         // Skip current word
         while self.cursor_pos < max {
-            if let Some(c) = self.char_at(self.cursor_pos) {
-                if !c.is_whitespace() {
+            if let Some(g) = self.grapheme_at(self.cursor_pos) {
+                if !is_whitespace(g) {
                     self.cursor_pos += 1;
                 } else {
                     break;
@@ -258,8 +660,8 @@ impl InputState {
 
         // Skip whitespace
         while self.cursor_pos < max {
-            if let Some(c) = self.char_at(self.cursor_pos) {
-                if c.is_whitespace() {
+            if let Some(g) = self.grapheme_at(self.cursor_pos) {
+                if is_whitespace(g) {
                     self.cursor_pos += 1;
                 } else {
                     break;
@@ -275,51 +677,184 @@ impl InputState {
         self.text.clear();
         self.cursor_pos = 0;
         self.scroll_offset = 0;
+        self.cancel_compose();
+    }
+
+    /// Start an IME composition at the cursor.
+    ///
+    /// Crossterm does not report IME events yet, so callers drive the
+    /// composition lifecycle themselves.
+    pub fn begin_compose(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        self.composing = true;
+        self.composition_text.clear();
+    }
+
+    /// Replace the in-progress composition text.
+    ///
+    /// Starts a composition if none is active.
+    pub fn update_compose(&mut self, text: impl Into<String>) {
+        if !self.enabled {
+            return;
+        }
+        self.composing = true;
+        self.composition_text = text.into();
+    }
+
+    /// Insert the composition text at the cursor and end the composition.
+    ///
+    /// Returns `true` if any text was inserted.
+    pub fn commit_compose(&mut self) -> bool {
+        if !self.composing {
+            return false;
+        }
+        let text = std::mem::take(&mut self.composition_text);
+        self.composing = false;
+        self.insert_str(&text);
+        !text.is_empty()
+    }
+
+    /// Discard the composition text and end the composition.
+    pub fn cancel_compose(&mut self) {
+        self.composing = false;
+        self.composition_text.clear();
     }
 
     /// Set the text content.
     ///
-    /// Cursor is moved to the end.
+    /// Cursor is moved to the end. With a mask, either the raw or the
+    /// formatted text can be given; it is distributed across the slots.
     pub fn set_text(&mut self, text: impl Into<String>) {
-        self.text = text.into();
-        self.cursor_pos = self.text.chars().count();
+        self.replace_text(text.into());
+        self.mark_clean();
+    }
+
+    /// Replace the text as [`set_text`](Self::set_text) does, but as an
+    /// edit that leaves the clean baseline alone.
+    fn replace_text(&mut self, text: String) {
+        self.text = text;
+        self.cursor_pos = self.grapheme_count();
         self.scroll_offset = 0;
+        self.remask();
+    }
+
+    /// Check if the text differs from the last clean text.
+    pub fn is_dirty(&self) -> bool {
+        self.text != self.clean_text
+    }
+
+    /// Treat the current text as saved, e.g. after a successful submit.
+    pub fn mark_clean(&mut self) {
+        self.clean_text.clone_from(&self.text);
     }
 
-    /// Get the character at a given index.
-    fn char_at(&self, index: usize) -> Option<char> {
-        self.text.chars().nth(index)
+    /// Get the grapheme cluster at a given index.
+    fn grapheme_at(&self, index: usize) -> Option<&str> {
+        self.text.graphemes(true).nth(index)
     }
 
-    /// Convert character index to byte index.
-    fn char_to_byte_index(&self, char_idx: usize) -> usize {
+    /// Remove the grapheme cluster at a given index.
+    fn remove_grapheme(&mut self, index: usize) -> bool {
+        let Some((byte_pos, g)) = self.text.grapheme_indices(true).nth(index) else {
+            return false;
+        };
+        let end = byte_pos + g.len();
+        self.text.replace_range(byte_pos..end, "");
+        true
+    }
+
+    /// Convert grapheme cluster index to byte index.
+    fn grapheme_to_byte_index(&self, grapheme_idx: usize) -> usize {
         self.text
-            .char_indices()
-            .nth(char_idx)
+            .grapheme_indices(true)
+            .nth(grapheme_idx)
             .map(|(i, _)| i)
             .unwrap_or(self.text.len())
     }
 
+    /// Index of the grapheme cluster boundary at or after a byte index.
+    fn byte_to_grapheme_index(&self, byte_idx: usize) -> usize {
+        self.text
+            .grapheme_indices(true)
+            .take_while(|(i, _)| *i < byte_idx)
+            .count()
+    }
+
     /// Get text before cursor.
     pub fn text_before_cursor(&self) -> &str {
-        let byte_pos = self.char_to_byte_index(self.cursor_pos);
+        let byte_pos = self.grapheme_to_byte_index(self.cursor_pos);
         &self.text[..byte_pos]
     }
 
     /// Get text after cursor.
     pub fn text_after_cursor(&self) -> &str {
-        let byte_pos = self.char_to_byte_index(self.cursor_pos);
+        let byte_pos = self.grapheme_to_byte_index(self.cursor_pos);
         &self.text[byte_pos..]
     }
 
+    /// First grapheme cluster shown in `width` display cells.
+    ///
+    /// Starts at [`scroll_offset`](Self::scroll_offset) and scrolls right by
+    /// whole clusters until the text before the cursor, any IME composition,
+    /// and the cursor fit, so wide characters are never split at the edge.
+    fn visible_start(&self, width: usize) -> usize {
+        let widths: Vec<usize> = self
+            .text
+            .graphemes(true)
+            .map(UnicodeWidthStr::width)
+            .collect();
+        let cursor = self.cursor_pos.min(widths.len());
+        let mut start = self.scroll_offset.min(cursor);
+
+        let mut used: usize = widths[start..cursor].iter().sum();
+        if self.composing {
+            used += self.composition_text.width();
+        }
+        if self.focused {
+            used += 1;
+        }
+        while used > width && start < cursor {
+            used -= widths[start];
+            start += 1;
+        }
+        start
+    }
+
+    /// Check if text from grapheme cluster `start` on, plus any IME
+    /// composition and the cursor, is wider than `width` display cells.
+    fn overflows_from(&self, start: usize, width: usize) -> bool {
+        let mut used: usize = self
+            .text
+            .graphemes(true)
+            .skip(start)
+            .map(UnicodeWidthStr::width)
+            .sum();
+        if self.composing {
+            used += self.composition_text.width();
+        }
+        if self.focused {
+            used += 1;
+        }
+        used > width
+    }
+
     /// Check if the input is empty.
     pub fn is_empty(&self) -> bool {
         self.text.is_empty()
     }
 
-    /// Get the length of the text in characters.
+    /// Get the length of the text in grapheme clusters.
+    ///
+    /// Same as [`grapheme_count`](Self::grapheme_count).
     pub fn len(&self) -> usize {
-        self.text.chars().count()
+        self.grapheme_count()
+    }
+
+    /// Get the number of grapheme clusters, the unit the cursor moves by.
+    pub fn grapheme_count(&self) -> usize {
+        self.text.graphemes(true).count()
     }
 
     /// Get a reference to the text content.
@@ -328,6 +863,11 @@ impl InputState {
     }
 }
 
+/// Whether a grapheme cluster is whitespace, for word movement.
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
 /// Configuration for input appearance.
 #[derive(Debug, Clone)]
 pub struct InputStyle {
@@ -343,6 +883,12 @@ pub struct InputStyle {
     pub cursor_fg: Color,
     /// Placeholder text color.
     pub placeholder_fg: Color,
+    /// Style for uncommitted IME composition text.
+    pub composing_style: Style,
+    /// Style for the numeric spinner arrows.
+    pub spinner_arrow_style: Style,
+    /// Style for the `◀`/`▶` markers shown when text is scrolled out of view.
+    pub scroll_indicator_style: Style,
 }
 
 impl Default for InputStyle {
@@ -354,11 +900,21 @@ impl Default for InputStyle {
             text_fg: Color::White,
             cursor_fg: Color::Yellow,
             placeholder_fg: Color::DarkGray,
+            composing_style: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::UNDERLINED),
+            spinner_arrow_style: Style::default().fg(Color::Cyan),
+            scroll_indicator_style: Style::default().fg(Color::DarkGray),
         }
     }
 }
 
 impl InputStyle {
+    /// Focus ring built from the border colors.
+    pub fn focus_ring(&self) -> FocusRing {
+        FocusRing::new(self.focused_border, self.unfocused_border).disabled(self.disabled_border)
+    }
+
     /// Set the focused border color.
     pub fn focused_border(mut self, color: Color) -> Self {
         self.focused_border = color;
@@ -388,6 +944,24 @@ impl InputStyle {
         self.placeholder_fg = color;
         self
     }
+
+    /// Set the IME composition text style.
+    pub fn composing_style(mut self, style: Style) -> Self {
+        self.composing_style = style;
+        self
+    }
+
+    /// Set the numeric spinner arrow style.
+    pub fn spinner_arrow_style(mut self, style: Style) -> Self {
+        self.spinner_arrow_style = style;
+        self
+    }
+
+    /// Set the scroll indicator style.
+    pub fn scroll_indicator_style(mut self, style: Style) -> Self {
+        self.scroll_indicator_style = style;
+        self
+    }
 }
 
 impl From<&crate::theme::Theme> for InputStyle {
@@ -400,10 +974,23 @@ impl From<&crate::theme::Theme> for InputStyle {
             text_fg: p.text,
             cursor_fg: p.primary,
             placeholder_fg: p.text_placeholder,
+            composing_style: Style::default()
+                .fg(p.text)
+                .add_modifier(Modifier::UNDERLINED),
+            spinner_arrow_style: Style::default().fg(p.primary),
+            scroll_indicator_style: Style::default().fg(p.text_dim),
         }
     }
 }
 
+/// Result of rendering an input.
+pub struct InputRender {
+    /// Click region for focus handling.
+    pub click_region: ClickRegion<InputAction>,
+    /// Spinner arrow regions in numeric mode; register these before `click_region`.
+    pub arrow_regions: Vec<ClickRegion<InputAction>>,
+}
+
 /// Input widget.
 ///
 /// A text input field with cursor, label, and placeholder support.
@@ -439,7 +1026,9 @@ impl<'a> Input<'a> {
         self
     }
 
-    /// Set the placeholder text (shown when empty).
+    /// Set the placeholder text, shown in `placeholder_fg` while the input is
+    /// empty and unfocused. It is display-only and never part of
+    /// [`InputState::text`].
     pub fn placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
         self
@@ -469,19 +1058,35 @@ impl<'a> Input<'a> {
     }
 
     /// Render the input and return the click region.
+    ///
+    /// In numeric mode the spinner arrows are drawn but their click regions
+    /// are not returned; use [`render_with_arrows`](Self::render_with_arrows)
+    /// to get them.
     pub fn render_stateful(self, frame: &mut Frame, area: Rect) -> ClickRegion<InputAction> {
-        let border_color = if !self.state.enabled {
-            self.style.disabled_border
-        } else if self.state.focused {
-            self.style.focused_border
-        } else {
-            self.style.unfocused_border
-        };
+        self.render_with_arrows(frame, area).click_region
+    }
+
+    /// Render the input and return its click region plus any spinner arrow regions.
+    pub fn render_with_arrows(self, frame: &mut Frame, area: Rect) -> InputRender {
+        self.render_with_arrows_to_buffer(area, frame.buffer_mut())
+    }
 
+    /// Render the input using Buffer (Widget-style rendering).
+    ///
+    /// This is useful when you need to render without a Frame reference.
+    pub fn render_to_buffer(self, area: Rect, buf: &mut Buffer) -> ClickRegion<InputAction> {
+        self.render_with_arrows_to_buffer(area, buf).click_region
+    }
+
+    /// Render the input using Buffer and return its click region plus any
+    /// spinner arrow regions.
+    pub fn render_with_arrows_to_buffer(self, area: Rect, buf: &mut Buffer) -> InputRender {
         let block = if self.with_border {
-            let mut block = Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(border_color));
+            let mut block = self.style.focus_ring().block(
+                Block::default().borders(Borders::ALL),
+                self.state.focused,
+                self.state.enabled,
+            );
             if let Some(label) = self.label {
                 block = block.title(format!(" {} ", label));
             }
@@ -490,15 +1095,39 @@ impl<'a> Input<'a> {
             None
         };
 
-        let inner_area = if let Some(ref b) = block {
+        let mut inner_area = if let Some(ref b) = block {
             b.inner(area)
         } else {
             area
         };
 
+        // Reserve " ▲▼" on the right edge in numeric mode
+        let mut arrow_regions = Vec::new();
+        if self.state.mode.is_numeric() && inner_area.width >= 4 && inner_area.height > 0 {
+            let x = inner_area.right() - 2;
+            let up = Rect::new(x, inner_area.y, 1, 1);
+            let down = Rect::new(x + 1, inner_area.y, 1, 1);
+            let arrow_style = if self.state.enabled {
+                self.style.spinner_arrow_style
+            } else {
+                Style::default().fg(self.style.disabled_border)
+            };
+            Paragraph::new(Line::from(Span::styled("▲▼", arrow_style)))
+                .render(Rect::new(x, inner_area.y, 2, 1), buf);
+            arrow_regions.push(ClickRegion::new(up, InputAction::Increment));
+            arrow_regions.push(ClickRegion::new(down, InputAction::Decrement));
+            inner_area.width -= 3;
+        }
+
         // Build display text with cursor indicator
-        let display_line = if self.state.text.is_empty() {
-            if let Some(placeholder) = self.placeholder {
+        let composing = self.state.composing && !self.state.composition_text.is_empty();
+        let mut scroll_markers = (false, false);
+        let display_line = if let Some(mask) = &self.state.mask {
+            self.masked_line(mask)
+        } else if self.state.text.is_empty() && !composing {
+            if let Some(placeholder) = self.placeholder
+                && !self.state.focused
+            {
                 Line::from(Span::styled(
                     placeholder,
                     Style::default().fg(self.style.placeholder_fg),
@@ -510,7 +1139,12 @@ impl<'a> Input<'a> {
                 Line::from("")
             }
         } else {
-            let before = self.state.text_before_cursor();
+            // Scroll so the cursor stays visible
+            let width = inner_area.width as usize;
+            let start = self.state.visible_start(width);
+            scroll_markers = (start > 0, self.state.overflows_from(start, width));
+            let start_byte = self.state.grapheme_to_byte_index(start);
+            let before = &self.state.text_before_cursor()[start_byte..];
             let after = self.state.text_after_cursor();
 
             let mut spans = vec![Span::styled(
@@ -518,6 +1152,13 @@ impl<'a> Input<'a> {
                 Style::default().fg(self.style.text_fg),
             )];
 
+            if composing {
+                spans.push(Span::styled(
+                    self.state.composition_text.clone(),
+                    self.style.composing_style,
+                ));
+            }
+
             if self.state.focused {
                 spans.push(Span::styled("│", Style::default().fg(self.style.cursor_fg)));
             }
@@ -533,12 +1174,135 @@ impl<'a> Input<'a> {
         let paragraph = Paragraph::new(display_line);
 
         if let Some(block) = block {
-            frame.render_widget(block, area);
+            block.render(area, buf);
+        }
+        paragraph.render(inner_area, buf);
+        self.render_scroll_markers(scroll_markers, inner_area, buf);
+
+        InputRender {
+            click_region: ClickRegion::new(area, InputAction::Focus),
+            arrow_regions,
         }
-        frame.render_widget(paragraph, inner_area);
+    }
+}
+
+impl Input<'_> {
+    /// Overlay `◀` on the first and `▶` on the last visible cell when text
+    /// is hidden on that side, blanking any wide character they cut.
+    fn render_scroll_markers(&self, (left, right): (bool, bool), area: Rect, buf: &mut Buffer) {
+        if area.width < 2 || area.height == 0 {
+            return;
+        }
+        let style = self.style.scroll_indicator_style;
+        if left {
+            let x = area.x;
+            if buf[(x, area.y)].symbol().width() > 1 {
+                buf[(x + 1, area.y)].set_symbol(" ");
+            }
+            buf[(x, area.y)].set_symbol("◀").set_style(style);
+        }
+        if right {
+            let x = area.right() - 1;
+            if buf[(x - 1, area.y)].symbol().width() > 1 {
+                buf[(x - 1, area.y)].set_symbol(" ");
+            }
+            buf[(x, area.y)].set_symbol("▶").set_style(style);
+        }
+    }
+
+    /// The mask template with the typed characters filled in, unfilled
+    /// slots in the placeholder color, and the cursor before its slot.
+    fn masked_line(&self, mask: &InputMask) -> Line<'static> {
+        let text_style = Style::default().fg(self.style.text_fg);
+        let placeholder_style = Style::default().fg(self.style.placeholder_fg);
+        let mut raw = self.state.text.chars();
+        let mut slot = 0;
+        let mut spans = Vec::new();
+        for part in &mask.parts {
+            match part {
+                MaskPart::Literal(c) => spans.push(Span::styled(c.to_string(), text_style)),
+                MaskPart::Slot(_) => {
+                    if self.state.focused && slot == self.state.cursor_pos {
+                        spans.push(Span::styled("│", Style::default().fg(self.style.cursor_fg)));
+                    }
+                    spans.push(match raw.next() {
+                        Some(c) => Span::styled(c.to_string(), text_style),
+                        None => Span::styled(mask.placeholder.to_string(), placeholder_style),
+                    });
+                    slot += 1;
+                }
+            }
+        }
+        if self.state.focused && slot == self.state.cursor_pos {
+            spans.push(Span::styled("│", Style::default().fg(self.style.cursor_fg)));
+        }
+        Line::from(spans)
+    }
+}
+
+/// Handle Up/Down and Enter for an input in numeric mode.
+///
+/// Up/Down step the value; Enter validates typed text with
+/// [`InputState::commit_numeric`]. Other keys, and every key in text mode,
+/// return `None` so the caller's text editing handles them.
+pub fn handle_numeric_input_key(key: &KeyEvent, state: &mut InputState) -> Option<InputAction> {
+    if !state.mode.is_numeric() || !state.enabled {
+        return None;
+    }
+    match key.code {
+        KeyCode::Up => {
+            state.increment();
+            Some(InputAction::Increment)
+        }
+        KeyCode::Down => {
+            state.decrement();
+            Some(InputAction::Decrement)
+        }
+        KeyCode::Enter => Some(if state.commit_numeric() {
+            InputAction::Commit
+        } else {
+            InputAction::Invalid
+        }),
+        _ => None,
+    }
+}
 
-        ClickRegion::new(area, InputAction::Focus)
+/// Handle Ctrl+Z / Ctrl+Y for an input's single-level undo.
+///
+/// Calls [`InputState::undo_once`] or [`InputState::redo_once`] and returns
+/// [`InputAction::Undo`] / [`InputAction::Redo`] when they changed the text.
+/// Other keys return `None` so the caller's text editing handles them.
+pub fn handle_input_undo_key(key: &KeyEvent, state: &mut InputState) -> Option<InputAction> {
+    if !state.enabled || !key.modifiers.contains(KeyModifiers::CONTROL) {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('z') => state.undo_once().then_some(InputAction::Undo),
+        KeyCode::Char('y') => state.redo_once().then_some(InputAction::Redo),
+        _ => None,
+    }
+}
+
+/// Handle clicks on the spinner arrows returned by [`Input::render_with_arrows`].
+///
+/// Returns the arrow action after applying it to `state`.
+pub fn handle_numeric_input_mouse(
+    mouse: &MouseEvent,
+    state: &mut InputState,
+    arrow_regions: &[ClickRegion<InputAction>],
+) -> Option<InputAction> {
+    if mouse.kind != MouseEventKind::Down(MouseButton::Left) || !state.enabled {
+        return None;
     }
+    let region = arrow_regions
+        .iter()
+        .find(|r| r.contains(mouse.column, mouse.row))?;
+    match region.data {
+        InputAction::Increment => state.increment(),
+        InputAction::Decrement => state.decrement(),
+        _ => return None,
+    };
+    Some(region.data.clone())
 }
 
 #[cfg(test)]
@@ -585,6 +1349,59 @@ mod tests {
         assert_eq!(state.text, "Hello World");
     }
 
+    #[test]
+    fn test_undo_redo_once() {
+        let mut state = InputState::new("hello big world");
+        assert!(!state.undo_once());
+
+        assert!(state.delete_word_backward());
+        assert_eq!(state.text, "hello big ");
+        assert!(state.undo_once());
+        assert_eq!(state.text, "hello big world");
+        assert_eq!(state.cursor_pos, 15);
+        // Only one level is kept
+        assert!(!state.undo_once());
+
+        assert!(state.redo_once());
+        assert_eq!(state.text, "hello big ");
+        assert!(!state.redo_once());
+
+        state.cursor_pos = 5;
+        assert!(state.delete_to_line_end());
+        assert_eq!(state.text, "hello");
+        assert!(!state.delete_to_line_end());
+        state.paste(", there");
+        assert_eq!(state.text, "hello, there");
+        assert!(state.undo_once());
+        assert_eq!(state.text, "hello");
+
+        // A new edit clears the redo step
+        state.paste("!");
+        assert!(!state.redo_once());
+    }
+
+    #[test]
+    fn test_handle_input_undo_key() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let mut state = InputState::new("one two");
+        assert_eq!(handle_input_undo_key(&ctrl('z'), &mut state), None);
+
+        state.delete_word_backward();
+        assert_eq!(
+            handle_input_undo_key(&ctrl('z'), &mut state),
+            Some(InputAction::Undo)
+        );
+        assert_eq!(state.text, "one two");
+        assert_eq!(
+            handle_input_undo_key(&ctrl('y'), &mut state),
+            Some(InputAction::Redo)
+        );
+        assert_eq!(state.text, "one ");
+
+        let plain_z = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE);
+        assert_eq!(handle_input_undo_key(&plain_z, &mut state), None);
+    }
+
     #[test]
     fn test_delete_char_backward() {
         let mut state = InputState::new("Hello");
@@ -701,13 +1518,69 @@ mod tests {
 
     #[test]
     fn test_emoji_handling() {
-        let mut state = InputState::new("Hi 👋");
-        assert_eq!(state.len(), 4); // "H", "i", " ", "👋"
+        let mut state = InputState::new("Hi 👨‍👩‍👧");
+        assert_eq!(state.len(), 4); // "H", "i", " ", "👨‍👩‍👧"
+        assert_eq!(state.cursor_pos, 4);
 
         state.delete_char_backward();
         assert_eq!(state.text, "Hi ");
     }
 
+    #[test]
+    fn test_grapheme_cursor_movement() {
+        let family = "👨‍👩‍👧";
+        let mut state = InputState::new(format!("a{family}b"));
+        assert_eq!(state.grapheme_count(), 3);
+
+        state.move_left();
+        assert_eq!(state.text_before_cursor(), format!("a{family}"));
+        state.move_left();
+        assert_eq!(state.text_before_cursor(), "a");
+        assert_eq!(state.text_after_cursor(), format!("{family}b"));
+
+        assert!(state.delete_char_forward());
+        assert_eq!(state.text, "ab");
+
+        state.insert_str("🇯🇵");
+        assert_eq!(state.cursor_pos, 2);
+        state.move_right();
+        assert_eq!(state.cursor_pos, 3);
+        state.move_right();
+        assert_eq!(state.cursor_pos, 3);
+    }
+
+    #[test]
+    fn test_combining_mark_joins_previous_grapheme() {
+        let mut state = InputState::new("e");
+        state.insert_char('\u{301}');
+        assert_eq!(state.text, "e\u{301}");
+        assert_eq!(state.cursor_pos, 1);
+        assert_eq!(state.len(), 1);
+
+        state.insert_char('x');
+        assert_eq!(state.cursor_pos, 2);
+        state.move_left();
+        assert!(state.delete_char_backward());
+        assert_eq!(state.text, "x");
+    }
+
+    #[test]
+    fn test_word_movement_over_graphemes() {
+        let mut state = InputState::new("👍🏽👍🏽 ok");
+        state.move_home();
+        state.move_word_right();
+        assert_eq!(state.cursor_pos, 3);
+        state.move_end();
+        state.move_word_left();
+        assert_eq!(state.cursor_pos, 3);
+
+        state.move_end();
+        state.move_word_left();
+        assert!(state.delete_word_backward());
+        assert_eq!(state.text, " ok");
+        assert_eq!(state.cursor_pos, 0);
+    }
+
     #[test]
     fn test_disabled_input() {
         let mut state = InputState::new("Hello");
@@ -740,4 +1613,410 @@ mod tests {
         assert_eq!(style.focused_border, Color::Cyan);
         assert_eq!(style.text_fg, Color::Green);
     }
+
+    #[test]
+    fn test_compose_commit() {
+        let mut state = InputState::new("ab");
+        state.cursor_pos = 1;
+        state.begin_compose();
+        assert!(state.composing);
+
+        state.update_compose("n");
+        state.update_compose("に");
+        assert_eq!(state.text, "ab"); // Not committed yet
+
+        assert!(state.commit_compose());
+        assert_eq!(state.text, "aにb");
+        assert_eq!(state.cursor_pos, 2);
+        assert!(!state.composing);
+        assert!(state.composition_text.is_empty());
+    }
+
+    #[test]
+    fn test_compose_cancel() {
+        let mut state = InputState::new("ab");
+        state.update_compose("你");
+        assert!(state.composing);
+
+        state.cancel_compose();
+        assert!(!state.composing);
+        assert!(state.composition_text.is_empty());
+        assert!(!state.commit_compose());
+        assert_eq!(state.text, "ab");
+    }
+
+    #[test]
+    fn test_render_composition_at_cursor() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut state = InputState::new("ab");
+        state.cursor_pos = 1;
+        state.update_compose("xy");
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        terminal
+            .draw(|f| {
+                Input::new(&state)
+                    .with_border(false)
+                    .render_stateful(f, f.area());
+            })
+            .unwrap();
+
+        let buf = terminal.backend().buffer();
+        assert_eq!(buf[(0, 0)].symbol(), "a");
+        assert_eq!(buf[(1, 0)].symbol(), "x");
+        assert!(buf[(1, 0)].modifier.contains(Modifier::UNDERLINED));
+        assert_eq!(buf[(2, 0)].symbol(), "y");
+        assert_eq!(buf[(3, 0)].symbol(), "b");
+        assert!(!buf[(3, 0)].modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_render_focus_ring() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let style = InputStyle::default();
+        for (focused, color) in [
+            (true, style.focused_border),
+            (false, style.unfocused_border),
+        ] {
+            let mut state = InputState::new("hi");
+            state.focused = focused;
+            let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+            terminal
+                .draw(|f| {
+                    Input::new(&state).render_stateful(f, f.area());
+                })
+                .unwrap();
+
+            let buf = terminal.backend().buffer();
+            assert_eq!(buf[(0, 0)].symbol(), "┌");
+            assert_eq!(buf[(0, 0)].fg, color);
+            assert_eq!(buf[(7, 2)].fg, color);
+        }
+    }
+
+    fn numeric(text: &str) -> InputState {
+        InputState::new(text).with_mode(InputMode::Numeric {
+            min: Some(0.0),
+            max: Some(10.0),
+            step: 2.5,
+            decimal_places: 1,
+        })
+    }
+
+    #[test]
+    fn test_numeric_value() {
+        assert_eq!(InputState::new(" 4.5 ").numeric_value(), Some(4.5));
+        assert_eq!(InputState::new("abc").numeric_value(), None);
+        assert_eq!(InputState::new("inf").numeric_value(), None);
+    }
+
+    #[test]
+    fn test_increment_decrement_clamp() {
+        let mut state = numeric("8");
+        assert!(state.increment());
+        assert_eq!(state.text, "10.0");
+        assert!(!state.increment());
+        assert_eq!(state.text, "10.0");
+
+        let mut state = numeric("1");
+        assert!(state.decrement());
+        assert_eq!(state.text, "0.0");
+        assert!(!state.decrement());
+
+        // Text mode ignores stepping
+        let mut state = InputState::new("5");
+        assert!(!state.increment());
+        assert_eq!(state.text, "5");
+    }
+
+    #[test]
+    fn test_commit_numeric() {
+        let mut state = numeric("42");
+        assert!(state.commit_numeric());
+        assert_eq!(state.text, "10.0");
+
+        let mut state = numeric("4x");
+        assert!(!state.commit_numeric());
+        assert_eq!(state.text, "4x");
+    }
+
+    #[test]
+    fn test_handle_numeric_input_key() {
+        use crossterm::event::KeyModifiers;
+
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut state = numeric("5");
+        assert_eq!(
+            handle_numeric_input_key(&key(KeyCode::Up), &mut state),
+            Some(InputAction::Increment)
+        );
+        assert_eq!(state.text, "7.5");
+        assert_eq!(
+            handle_numeric_input_key(&key(KeyCode::Down), &mut state),
+            Some(InputAction::Decrement)
+        );
+        assert_eq!(state.text, "5.0");
+        assert_eq!(
+            handle_numeric_input_key(&key(KeyCode::Char('1')), &mut state),
+            None
+        );
+
+        state.set_text("oops");
+        assert_eq!(
+            handle_numeric_input_key(&key(KeyCode::Enter), &mut state),
+            Some(InputAction::Invalid)
+        );
+
+        let mut text = InputState::new("5");
+        assert_eq!(handle_numeric_input_key(&key(KeyCode::Up), &mut text), None);
+    }
+
+    #[test]
+    fn test_render_numeric_arrows() {
+        use crossterm::event::KeyModifiers;
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut state = numeric("5");
+        let mut terminal = Terminal::new(TestBackend::new(12, 3)).unwrap();
+        let mut render = None;
+        terminal
+            .draw(|f| render = Some(Input::new(&state).render_with_arrows(f, f.area())))
+            .unwrap();
+        let render = render.unwrap();
+
+        let buf = terminal.backend().buffer();
+        assert_eq!(buf[(9, 1)].symbol(), "▲");
+        assert_eq!(buf[(10, 1)].symbol(), "▼");
+        assert_eq!(render.arrow_regions.len(), 2);
+        assert_eq!(render.arrow_regions[0].area, Rect::new(9, 1, 1, 1));
+
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(
+            handle_numeric_input_mouse(&click(9), &mut state, &render.arrow_regions),
+            Some(InputAction::Increment)
+        );
+        assert_eq!(state.text, "7.5");
+        assert_eq!(
+            handle_numeric_input_mouse(&click(10), &mut state, &render.arrow_regions),
+            Some(InputAction::Decrement)
+        );
+        assert_eq!(state.text, "5.0");
+        assert_eq!(
+            handle_numeric_input_mouse(&click(3), &mut state, &render.arrow_regions),
+            None
+        );
+    }
+
+    #[test]
+    fn test_render_to_buffer() {
+        let mut state = InputState::new("hi");
+        state.focused = true;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let area = Rect::new(2, 1, 6, 3);
+
+        let region = Input::new(&state).render_to_buffer(area, &mut buf);
+
+        assert_eq!(region.area, area);
+        let row = |y: u16| -> String { (0..10).map(|x| buf[(x, y)].symbol()).collect() };
+        assert_eq!(row(1), "  ┌────┐  ");
+        assert_eq!(row(2), "  │hi│ │  ");
+        assert_eq!(row(3), "  └────┘  ");
+    }
+
+    #[test]
+    fn test_render_scrolls_wide_text_to_cursor() {
+        let mut state = InputState::new("a日本語です");
+        state.focused = true;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let render = |state: &InputState, buf: &mut Buffer| {
+            buf.reset();
+            Input::new(state)
+                .with_border(false)
+                .render_to_buffer(buf.area, buf);
+        };
+
+        // Cursor at the end: whole wide characters scroll out on the left,
+        // and the scroll marker covers the first visible one
+        render(&state, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "◀");
+        assert_eq!(buf[(2, 0)].symbol(), "す");
+        assert_eq!(buf[(4, 0)].symbol(), "│");
+
+        // Cursor after the first wide character sits at display column 3
+        state.cursor_pos = 2;
+        render(&state, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "a");
+        assert_eq!(buf[(1, 0)].symbol(), "日");
+        assert_eq!(buf[(3, 0)].symbol(), "│");
+        // "本" is cut by the right marker, so it is blanked
+        assert_eq!(buf[(4, 0)].symbol(), " ");
+        assert_eq!(buf[(5, 0)].symbol(), "▶");
+    }
+
+    #[test]
+    fn test_render_scroll_indicators() {
+        let mut state = InputState::new("abcdefghij");
+        state.focused = true;
+        let style = InputStyle::default().scroll_indicator_style(Style::default().fg(Color::Red));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let render = |state: &InputState, buf: &mut Buffer| -> String {
+            buf.reset();
+            Input::new(state)
+                .style(style.clone())
+                .with_border(false)
+                .render_to_buffer(buf.area, buf);
+            (0..6).map(|x| buf[(x, 0)].symbol()).collect()
+        };
+
+        // Markers overlay the edge characters rather than shifting the text
+        assert_eq!(render(&state, &mut buf), "◀ghij│");
+        state.cursor_pos = 0;
+        assert_eq!(render(&state, &mut buf), "│abcd▶");
+        state.cursor_pos = 5;
+        state.scroll_offset = 2;
+        assert_eq!(render(&state, &mut buf), "◀de│f▶");
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+
+        // Text that fits shows no markers
+        state.set_text("abc");
+        assert_eq!(render(&state, &mut buf), "abc│  ");
+    }
+
+    #[test]
+    fn test_input_mask_typing_and_backspace() {
+        let mut state = InputState::empty().with_input_mask("(___) ___-____", '_');
+        for c in "555x1234".chars() {
+            state.insert_char(c);
+        }
+        assert_eq!(state.raw_value(), "5551234");
+        assert_eq!(state.formatted_value(), "(555) 123-4___");
+        assert!(!state.is_mask_complete());
+
+        // Backspace clears the previous slot; later slots shift left
+        state.cursor_pos = 3;
+        assert!(state.delete_char_backward());
+        assert_eq!(state.formatted_value(), "(551) 234-____");
+        assert_eq!(state.cursor_pos, 2);
+
+        // The cursor cannot move past the first unfilled slot
+        state.move_end();
+        state.move_right();
+        assert_eq!(state.cursor_pos, 6);
+
+        for c in "7890".chars() {
+            state.insert_char(c);
+        }
+        assert!(state.is_mask_complete());
+        state.insert_char('1');
+        assert_eq!(state.formatted_value(), "(551) 234-7890");
+    }
+
+    #[test]
+    fn test_input_mask_slot_classes_and_paste() {
+        let mut state = InputState::empty().with_input_mask("HH:HH:HH", '_');
+        state.paste("0a:1G:b2:c3");
+        assert_eq!(state.raw_value(), "0a1b2c");
+        assert!(state.is_mask_complete());
+        assert!(state.undo_once());
+        assert_eq!(state.raw_value(), "");
+
+        let mut state = InputState::empty().with_input_mask("AA-99", '#');
+        state.set_text("ab-12");
+        assert_eq!(state.raw_value(), "ab12");
+        state.set_text("1a");
+        assert_eq!(state.formatted_value(), "a#-##");
+
+        let mask = InputMask::new(r"\9*", ' ');
+        assert_eq!(mask.slot_count(), 1);
+        assert_eq!(mask.format("x"), "9x");
+    }
+
+    #[test]
+    fn test_render_input_mask() {
+        let mut state = InputState::empty().with_input_mask("__/__", '_');
+        state.insert_str("123");
+        state.focused = true;
+        let style = InputStyle::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Input::new(&state)
+            .with_border(false)
+            .render_to_buffer(buf.area, &mut buf);
+
+        let row: String = (0..8).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "12/3│_  ");
+        assert_eq!(buf[(3, 0)].fg, style.text_fg);
+        assert_eq!(buf[(5, 0)].fg, style.placeholder_fg);
+    }
+
+    #[test]
+    fn test_dirty_tracking() {
+        let mut state = InputState::new("name");
+        assert!(!state.is_dirty());
+        state.insert_char('s');
+        assert!(state.is_dirty());
+        state.delete_char_backward();
+        assert!(!state.is_dirty());
+
+        state.insert_char('!');
+        state.mark_clean();
+        assert!(!state.is_dirty());
+        state.set_text("other");
+        assert!(!state.is_dirty());
+    }
+
+    #[test]
+    fn test_numeric_step_makes_dirty() {
+        let mut state = numeric("5.0");
+        assert!(!state.is_dirty());
+        assert!(state.increment());
+        assert!(state.is_dirty());
+        assert!(state.decrement());
+        assert!(!state.is_dirty(), "back to the saved value");
+    }
+
+    #[test]
+    fn test_input_mask_starts_clean() {
+        let state = InputState::new("(555) 123-4567").with_input_mask("(___) ___-____", '_');
+        assert_eq!(state.raw_value(), "5551234567");
+        assert!(!state.is_dirty());
+    }
+
+    #[test]
+    fn test_placeholder_only_when_empty_and_unfocused() {
+        let mut state = InputState::empty();
+        let style = InputStyle::default();
+        let render = |state: &InputState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            Input::new(state)
+                .with_border(false)
+                .placeholder("Name")
+                .render_to_buffer(buf.area, &mut buf);
+            buf
+        };
+
+        let buf = render(&state);
+        let row: String = (0..4).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "Name");
+        assert_eq!(buf[(0, 0)].fg, style.placeholder_fg);
+        assert_eq!(state.text(), "");
+        assert_eq!(state.cursor_pos, 0);
+
+        state.focused = true;
+        let buf = render(&state);
+        assert_eq!(buf[(0, 0)].symbol(), "│");
+        assert_eq!(buf[(1, 0)].symbol(), " ");
+
+        state.focused = false;
+        state.insert_char('x');
+        let buf = render(&state);
+        assert_eq!(buf[(0, 0)].symbol(), "x");
+        assert_eq!(buf[(0, 0)].fg, style.text_fg);
+    }
 }
@@ -0,0 +1,625 @@
+//! Interactive scrollbar helper
+//!
+//! Wraps ratatui's [`Scrollbar`] with hit-testing so the arrows, track, and
+//! thumb respond to the mouse. Clicking an arrow scrolls one line, clicking
+//! the track pages, and dragging the thumb scrolls proportionally.
+//!
+//! The thumb geometry is computed exactly as ratatui does, so the click
+//! regions line up with what is drawn.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::ScrollbarOrientation};
+//! use ratatui_interact::components::{InteractiveScrollbar, ScrollbarPart};
+//! use ratatui_interact::traits::ClickRegionRegistry;
+//!
+//! let area = Rect::new(0, 0, 20, 10);
+//! let scrollbar = InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 100, 0);
+//!
+//! let mut buf = Buffer::empty(area);
+//! let mut registry = ClickRegionRegistry::new();
+//! scrollbar.render_with_registry(area, &mut buf, &mut registry);
+//!
+//! // The up arrow sits in the top-right cell
+//! assert_eq!(registry.handle_click(19, 0), Some(&ScrollbarPart::BeginArrow));
+//! ```
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    symbols::scrollbar,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget},
+};
+
+use crate::capabilities::{ASCII_HORIZONTAL_SCROLLBAR, ASCII_VERTICAL_SCROLLBAR};
+
+use crate::traits::{ClickRegion, ClickRegionRegistry};
+
+/// Parts of a scrollbar that respond to clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollbarPart {
+    /// The arrow at the start (up or left).
+    BeginArrow,
+    /// Track between the start arrow and the thumb.
+    TrackBefore,
+    /// The thumb.
+    Thumb,
+    /// Track between the thumb and the end arrow.
+    TrackAfter,
+    /// The arrow at the end (down or right).
+    EndArrow,
+}
+
+/// Drag state for an interactive scrollbar.
+///
+/// Keep one per scrollbar in the owning component's state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollbarDragState {
+    /// Offset of the pointer from the thumb start while dragging.
+    grab_offset: Option<u16>,
+}
+
+impl ScrollbarDragState {
+    /// Create a new drag state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if the thumb is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.grab_offset.is_some()
+    }
+
+    /// Stop dragging.
+    pub fn end_drag(&mut self) {
+        self.grab_offset = None;
+    }
+}
+
+/// Rectangles of each scrollbar part, in screen coordinates.
+///
+/// Parts with zero length have an empty rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollbarLayout {
+    /// The start arrow, if arrows are shown.
+    pub begin_arrow: Option<Rect>,
+    /// Track before the thumb.
+    pub track_before: Rect,
+    /// The thumb.
+    pub thumb: Rect,
+    /// Track after the thumb.
+    pub track_after: Rect,
+    /// The end arrow, if arrows are shown.
+    pub end_arrow: Option<Rect>,
+}
+
+impl ScrollbarLayout {
+    /// Iterate over the non-empty parts with their rects.
+    pub fn parts(&self) -> impl Iterator<Item = (ScrollbarPart, Rect)> {
+        [
+            self.begin_arrow.map(|r| (ScrollbarPart::BeginArrow, r)),
+            Some((ScrollbarPart::TrackBefore, self.track_before)),
+            Some((ScrollbarPart::Thumb, self.thumb)),
+            Some((ScrollbarPart::TrackAfter, self.track_after)),
+            self.end_arrow.map(|r| (ScrollbarPart::EndArrow, r)),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|(_, r)| r.width > 0 && r.height > 0)
+    }
+}
+
+/// A ratatui [`Scrollbar`] with mouse hit-testing.
+///
+/// Build one from the component's scroll state each frame and again when
+/// handling mouse events; it holds no state of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InteractiveScrollbar {
+    orientation: ScrollbarOrientation,
+    content_length: usize,
+    position: usize,
+    viewport_length: usize,
+    arrows: bool,
+    symbols: Option<scrollbar::Set<'static>>,
+}
+
+impl InteractiveScrollbar {
+    /// Create a scrollbar for `content_length` items scrolled to `position`.
+    pub fn new(orientation: ScrollbarOrientation, content_length: usize, position: usize) -> Self {
+        Self {
+            orientation,
+            content_length,
+            position,
+            viewport_length: 0,
+            arrows: true,
+            symbols: None,
+        }
+    }
+
+    /// Set the number of items visible at once.
+    ///
+    /// Defaults to the track area length, as with [`ScrollbarState`].
+    pub fn viewport_length(mut self, viewport_length: usize) -> Self {
+        self.viewport_length = viewport_length;
+        self
+    }
+
+    /// Show or hide the arrows at each end (default: shown).
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.arrows = arrows;
+        self
+    }
+
+    /// Draw with `symbols` instead of ratatui's default double-line set.
+    pub fn symbols(mut self, symbols: scrollbar::Set<'static>) -> Self {
+        self.symbols = Some(symbols);
+        self
+    }
+
+    /// Draw with ASCII symbols for this orientation.
+    pub fn ascii(self) -> Self {
+        let symbols = if self.is_vertical() {
+            ASCII_VERTICAL_SCROLLBAR
+        } else {
+            ASCII_HORIZONTAL_SCROLLBAR
+        };
+        self.symbols(symbols)
+    }
+
+    /// Check if the scrollbar is vertical.
+    pub fn is_vertical(&self) -> bool {
+        self.orientation.is_vertical()
+    }
+
+    /// The ratatui scrollbar widget this helper renders.
+    pub fn widget(&self) -> Scrollbar<'static> {
+        let mut scrollbar = Scrollbar::new(self.orientation.clone());
+        if let Some(symbols) = &self.symbols {
+            scrollbar = scrollbar.symbols(symbols.clone());
+        }
+        if self.arrows {
+            scrollbar
+        } else {
+            scrollbar.begin_symbol(None).end_symbol(None)
+        }
+    }
+
+    /// The ratatui scrollbar state this helper renders.
+    pub fn scrollbar_state(&self) -> ScrollbarState {
+        ScrollbarState::new(self.content_length)
+            .position(self.position)
+            .viewport_content_length(self.viewport_length)
+    }
+
+    /// Render the scrollbar.
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        self.widget().render(area, buf, &mut self.scrollbar_state());
+    }
+
+    /// Render the scrollbar and register its parts for click handling.
+    pub fn render_with_registry(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<ScrollbarPart>,
+    ) {
+        self.render(area, buf);
+        self.register(area, registry);
+    }
+
+    /// Register the scrollbar parts for click handling.
+    pub fn register(&self, area: Rect, registry: &mut ClickRegionRegistry<ScrollbarPart>) {
+        for region in self.click_regions(area) {
+            registry.register(region.area, region.data);
+        }
+    }
+
+    /// Click regions of each scrollbar part.
+    pub fn click_regions(&self, area: Rect) -> Vec<ClickRegion<ScrollbarPart>> {
+        self.layout(area)
+            .map(|layout| {
+                layout
+                    .parts()
+                    .map(|(part, rect)| ClickRegion::new(rect, part))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Compute the part rectangles within `area`.
+    ///
+    /// `area` is the same area passed to [`render`](Self::render). Returns
+    /// `None` when nothing would be drawn.
+    pub fn layout(&self, area: Rect) -> Option<ScrollbarLayout> {
+        let bar = self.bar_area(area)?;
+        let arrow_len = u16::from(self.arrows);
+        let track_len = self.bar_length(bar).saturating_sub(arrow_len * 2);
+        if self.content_length == 0 || track_len == 0 {
+            return None;
+        }
+
+        let (before, thumb, after) = self.part_lengths(bar);
+        let mut offset = 0;
+        let mut segment = |len: u16| {
+            let rect = self.segment(bar, offset, len);
+            offset += len;
+            rect
+        };
+
+        let begin_arrow = self.arrows.then(|| segment(1));
+        let track_before = segment(before);
+        let thumb = segment(thumb);
+        let track_after = segment(after);
+        let end_arrow = self.arrows.then(|| segment(1));
+
+        Some(ScrollbarLayout {
+            begin_arrow,
+            track_before,
+            thumb,
+            track_after,
+            end_arrow,
+        })
+    }
+
+    /// The largest position the scrollbar can show.
+    pub fn max_position(&self) -> usize {
+        self.content_length.saturating_sub(1)
+    }
+
+    /// Handle a mouse event against previously registered regions.
+    ///
+    /// `regions` are the regions registered when this scrollbar was last
+    /// rendered. Returns the new scroll position if the event scrolled.
+    pub fn handle_mouse(
+        &self,
+        regions: &[ClickRegion<ScrollbarPart>],
+        drag: &mut ScrollbarDragState,
+        mouse: &MouseEvent,
+    ) -> Option<usize> {
+        let bar = regions.iter().map(|r| r.area).reduce(|a, b| a.union(b))?;
+        let layout = self.layout(bar)?;
+        let pointer = if self.is_vertical() {
+            mouse.row
+        } else {
+            mouse.column
+        };
+
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let part = regions
+                    .iter()
+                    .find(|r| r.contains(mouse.column, mouse.row))?
+                    .data;
+                let page = self.page_length(bar);
+                let position = match part {
+                    ScrollbarPart::BeginArrow => self.position.saturating_sub(1),
+                    ScrollbarPart::EndArrow => self.position + 1,
+                    ScrollbarPart::TrackBefore => self.position.saturating_sub(page),
+                    ScrollbarPart::TrackAfter => self.position + page,
+                    ScrollbarPart::Thumb => {
+                        drag.grab_offset =
+                            Some(pointer.saturating_sub(self.start_of(layout.thumb)));
+                        return None;
+                    }
+                };
+                Some(position.min(self.max_position()))
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let grab = drag.grab_offset?;
+                let track_start = layout
+                    .begin_arrow
+                    .map_or(self.start_of(bar), |r| self.start_of(r) + 1);
+                let thumb_start = pointer.saturating_sub(grab).saturating_sub(track_start);
+                Some(self.position_for_thumb_start(bar, thumb_start as usize))
+            }
+            MouseEventKind::Up(MouseButton::Left) if drag.is_dragging() => {
+                drag.end_drag();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Column or row of the bar within `area`, as ratatui picks it.
+    fn bar_area(&self, area: Rect) -> Option<Rect> {
+        match self.orientation {
+            ScrollbarOrientation::VerticalLeft => area.columns().next(),
+            ScrollbarOrientation::VerticalRight => area.columns().next_back(),
+            ScrollbarOrientation::HorizontalTop => area.rows().next(),
+            ScrollbarOrientation::HorizontalBottom => area.rows().next_back(),
+        }
+    }
+
+    fn bar_length(&self, bar: Rect) -> u16 {
+        if self.is_vertical() {
+            bar.height
+        } else {
+            bar.width
+        }
+    }
+
+    fn start_of(&self, rect: Rect) -> u16 {
+        if self.is_vertical() { rect.y } else { rect.x }
+    }
+
+    fn segment(&self, bar: Rect, offset: u16, len: u16) -> Rect {
+        if self.is_vertical() {
+            Rect::new(bar.x, bar.y + offset, bar.width, len)
+        } else {
+            Rect::new(bar.x + offset, bar.y, len, bar.height)
+        }
+    }
+
+    fn track_length(&self, bar: Rect) -> usize {
+        let arrows_len = if self.arrows { 2 } else { 0 };
+        self.bar_length(bar).saturating_sub(arrows_len) as usize
+    }
+
+    /// Items visible at once, falling back to the bar length like ratatui.
+    fn page_length(&self, bar: Rect) -> usize {
+        if self.viewport_length != 0 {
+            self.viewport_length
+        } else {
+            self.bar_length(bar) as usize
+        }
+    }
+
+    /// Track before, thumb, and track after lengths, matching ratatui.
+    fn part_lengths(&self, bar: Rect) -> (u16, u16, u16) {
+        let track_length = self.track_length(bar);
+        if track_length == 0 {
+            return (0, 0, 0);
+        }
+
+        let viewport_length = self.page_length(bar);
+        let max_position = self.max_position();
+        let start_position = self.position.min(max_position);
+        let max_viewport_position = max_position.saturating_add(viewport_length);
+        if max_viewport_position == 0 {
+            return (0, track_length as u16, 0);
+        }
+
+        let thumb_length = rounding_divide(
+            viewport_length.saturating_mul(track_length),
+            max_viewport_position,
+        )
+        .clamp(1, track_length);
+        let thumb_start = rounding_divide(
+            start_position.saturating_mul(track_length),
+            max_viewport_position,
+        )
+        .min(track_length.saturating_sub(thumb_length));
+        let track_end = track_length.saturating_sub(thumb_start + thumb_length);
+
+        (thumb_start as u16, thumb_length as u16, track_end as u16)
+    }
+
+    /// Inverse of the thumb start calculation.
+    fn position_for_thumb_start(&self, bar: Rect, thumb_start: usize) -> usize {
+        let track_length = self.track_length(bar);
+        if track_length == 0 {
+            return 0;
+        }
+        let max_viewport_position = self.max_position().saturating_add(self.page_length(bar));
+        rounding_divide(
+            thumb_start.saturating_mul(max_viewport_position),
+            track_length,
+        )
+        .min(self.max_position())
+    }
+}
+
+/// Integer division rounding to nearest, as ratatui's scrollbar does.
+fn rounding_divide(numerator: usize, denominator: usize) -> usize {
+    (numerator + denominator / 2) / denominator
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    /// Collect the rendered symbols of a vertical bar at column `x`.
+    fn rendered_column(buf: &Buffer, x: u16, height: u16) -> Vec<String> {
+        (0..height)
+            .map(|y| buf[(x, y)].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_layout_matches_rendered_thumb() {
+        let area = Rect::new(0, 0, 5, 20);
+        for position in [0, 10, 37, 80, 99] {
+            let scrollbar =
+                InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 100, position);
+            let mut buf = Buffer::empty(area);
+            scrollbar.render(area, &mut buf);
+
+            let layout = scrollbar.layout(area).unwrap();
+            let symbols = rendered_column(&buf, 4, 20);
+            for y in 0..20 {
+                let in_thumb = y >= layout.thumb.y && y < layout.thumb.bottom();
+                assert_eq!(
+                    symbols[y as usize] == "█",
+                    in_thumb,
+                    "position {position}, row {y}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_layout_parts_fill_bar() {
+        let area = Rect::new(2, 3, 30, 1);
+        let scrollbar = InteractiveScrollbar::new(ScrollbarOrientation::HorizontalBottom, 50, 20);
+        let layout = scrollbar.layout(area).unwrap();
+
+        assert_eq!(layout.begin_arrow, Some(Rect::new(2, 3, 1, 1)));
+        assert_eq!(layout.end_arrow, Some(Rect::new(31, 3, 1, 1)));
+        let total: u16 = layout.parts().map(|(_, r)| r.width).sum();
+        assert_eq!(total, 30);
+        assert_eq!(layout.thumb.y, 3);
+    }
+
+    #[test]
+    fn test_layout_none_when_empty() {
+        let scrollbar = InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 0, 0);
+        assert!(scrollbar.layout(Rect::new(0, 0, 10, 10)).is_none());
+        assert!(scrollbar.click_regions(Rect::new(0, 0, 10, 10)).is_empty());
+    }
+
+    #[test]
+    fn test_arrow_and_track_clicks() {
+        let area = Rect::new(0, 0, 10, 12);
+        let scrollbar = InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 100, 50)
+            .viewport_length(10);
+        let regions = scrollbar.click_regions(area);
+        let layout = scrollbar.layout(area).unwrap();
+        let mut drag = ScrollbarDragState::new();
+        let down = MouseEventKind::Down(MouseButton::Left);
+
+        assert_eq!(
+            scrollbar.handle_mouse(&regions, &mut drag, &mouse(down, 9, 0)),
+            Some(49)
+        );
+        assert_eq!(
+            scrollbar.handle_mouse(&regions, &mut drag, &mouse(down, 9, 11)),
+            Some(51)
+        );
+        assert_eq!(
+            scrollbar.handle_mouse(&regions, &mut drag, &mouse(down, 9, layout.track_before.y)),
+            Some(40)
+        );
+        assert_eq!(
+            scrollbar.handle_mouse(&regions, &mut drag, &mouse(down, 9, layout.track_after.y)),
+            Some(60)
+        );
+        // Outside the bar
+        assert_eq!(
+            scrollbar.handle_mouse(&regions, &mut drag, &mouse(down, 3, 5)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_track_click_clamps() {
+        let area = Rect::new(0, 0, 1, 12);
+        let scrollbar = InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 30, 25);
+        let regions = scrollbar.click_regions(area);
+        let layout = scrollbar.layout(area).unwrap();
+        let mut drag = ScrollbarDragState::new();
+        let click = mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            0,
+            layout.track_after.y,
+        );
+
+        assert_eq!(
+            scrollbar.handle_mouse(&regions, &mut drag, &click),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_thumb_drag_scrolls_proportionally() {
+        let area = Rect::new(0, 0, 1, 22);
+        let mut position = 0;
+        let mut drag = ScrollbarDragState::new();
+
+        let scrollbar = InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 100, 0);
+        let regions = scrollbar.click_regions(area);
+        let thumb = scrollbar.layout(area).unwrap().thumb;
+        let grab = mouse(MouseEventKind::Down(MouseButton::Left), 0, thumb.y);
+        assert_eq!(scrollbar.handle_mouse(&regions, &mut drag, &grab), None);
+        assert!(drag.is_dragging());
+
+        // Drag the thumb halfway down the track and check it follows the pointer
+        let drag_row = thumb.y + 10;
+        let moved = mouse(MouseEventKind::Drag(MouseButton::Left), 0, drag_row);
+        if let Some(new) = scrollbar.handle_mouse(&regions, &mut drag, &moved) {
+            position = new;
+        }
+        assert!(position > 0);
+        let after = InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 100, position);
+        assert_eq!(after.layout(area).unwrap().thumb.y, drag_row);
+
+        // Dragging past the end clamps
+        let moved = mouse(MouseEventKind::Drag(MouseButton::Left), 0, 200);
+        assert_eq!(
+            scrollbar.handle_mouse(&regions, &mut drag, &moved),
+            Some(99)
+        );
+
+        let release = mouse(MouseEventKind::Up(MouseButton::Left), 0, 200);
+        scrollbar.handle_mouse(&regions, &mut drag, &release);
+        assert!(!drag.is_dragging());
+        assert_eq!(scrollbar.handle_mouse(&regions, &mut drag, &moved), None);
+    }
+
+    #[test]
+    fn test_horizontal_drag() {
+        let area = Rect::new(5, 0, 40, 3);
+        let scrollbar =
+            InteractiveScrollbar::new(ScrollbarOrientation::HorizontalBottom, 200, 0).arrows(false);
+        let regions = scrollbar.click_regions(area);
+        let thumb = scrollbar.layout(area).unwrap().thumb;
+        assert_eq!(thumb.y, 2);
+        assert_eq!(thumb.x, 5);
+
+        let mut drag = ScrollbarDragState::new();
+        let grab = mouse(MouseEventKind::Down(MouseButton::Left), thumb.x, 2);
+        scrollbar.handle_mouse(&regions, &mut drag, &grab);
+        let moved = mouse(MouseEventKind::Drag(MouseButton::Left), thumb.x + 20, 2);
+        let position = scrollbar.handle_mouse(&regions, &mut drag, &moved).unwrap();
+
+        let after =
+            InteractiveScrollbar::new(ScrollbarOrientation::HorizontalBottom, 200, position)
+                .arrows(false);
+        assert_eq!(after.layout(area).unwrap().thumb.x, thumb.x + 20);
+    }
+
+    #[test]
+    fn test_render_with_registry() {
+        let area = Rect::new(0, 0, 20, 10);
+        let scrollbar = InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 100, 0);
+        let mut buf = Buffer::empty(area);
+        let mut registry = ClickRegionRegistry::new();
+        scrollbar.render_with_registry(area, &mut buf, &mut registry);
+
+        assert_eq!(
+            registry.handle_click(19, 0),
+            Some(&ScrollbarPart::BeginArrow)
+        );
+        assert_eq!(registry.handle_click(19, 1), Some(&ScrollbarPart::Thumb));
+        assert_eq!(registry.handle_click(19, 9), Some(&ScrollbarPart::EndArrow));
+        assert_eq!(registry.handle_click(18, 5), None);
+    }
+
+    #[test]
+    fn test_ascii_symbols() {
+        let area = Rect::new(0, 0, 5, 6);
+        let scrollbar =
+            InteractiveScrollbar::new(ScrollbarOrientation::VerticalRight, 100, 0).ascii();
+        let mut buf = Buffer::empty(area);
+        scrollbar.render(area, &mut buf);
+        assert_eq!(rendered_column(&buf, 4, 6), ["^", "#", "|", "|", "|", "v"]);
+
+        let scrollbar =
+            InteractiveScrollbar::new(ScrollbarOrientation::HorizontalBottom, 100, 0).ascii();
+        let mut buf = Buffer::empty(area);
+        scrollbar.render(area, &mut buf);
+        assert!(buf.content().iter().all(|cell| cell.symbol().is_ascii()));
+        assert_eq!(buf[(0, 5)].symbol(), "<");
+    }
+}
@@ -0,0 +1,1071 @@
+//! Key-value editor
+//!
+//! A two-column editable grid for inspector-style panels such as HTTP
+//! headers, environment variables, or arbitrary metadata.
+//!
+//! - Enter on a cell starts inline editing, Enter again commits and Esc cancels
+//! - Tab / Shift+Tab move key → value → next row
+//! - Insert or Ctrl+N adds a row, Delete removes the selected row
+//! - F4 opens the selected value in a multi-line editor popup
+//! - Duplicate keys (and anything rejected by a custom validator) are flagged
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{
+//!     KeyValueEditorAction, KeyValueEditorState, handle_key_value_editor_key,
+//! };
+//! use crossterm::event::{KeyCode, KeyEvent};
+//!
+//! let mut state = KeyValueEditorState::new([("Accept", "*/*"), ("Host", "example.com")]);
+//!
+//! // Edit the value of the first row
+//! state.select_next_cell();
+//! handle_key_value_editor_key(&mut state, &KeyEvent::from(KeyCode::Enter));
+//! handle_key_value_editor_key(&mut state, &KeyEvent::from(KeyCode::Backspace));
+//! handle_key_value_editor_key(&mut state, &KeyEvent::from(KeyCode::Backspace));
+//! let action = handle_key_value_editor_key(&mut state, &KeyEvent::from(KeyCode::Enter));
+//!
+//! assert_eq!(
+//!     action,
+//!     Some(KeyValueEditorAction::Changed {
+//!         key: "Accept".into(),
+//!         old: "*/*".into(),
+//!         new: "*".into(),
+//!     })
+//! );
+//! ```
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::input::{Input, InputState, InputStyle};
+use super::textarea::{TextArea, TextAreaState, TextAreaStyle};
+use crate::traits::ClickRegionRegistry;
+use crate::utils::display::{pad_to_width, truncate_middle};
+
+/// Custom validator: receives `(key, value)` and returns an error message.
+pub type KeyValueValidateFn = Box<dyn Fn(&str, &str) -> Option<String>>;
+
+/// A single key-value row.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KeyValueEntry {
+    /// The key
+    pub key: String,
+    /// The value
+    pub value: String,
+}
+
+impl KeyValueEntry {
+    /// Create a new entry.
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Column of a key-value row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum KeyValueColumn {
+    /// The key column
+    #[default]
+    Key,
+    /// The value column
+    Value,
+}
+
+/// A cell in the grid, used for click regions.
+pub type KeyValueCell = (usize, KeyValueColumn);
+
+/// Granular changes emitted by the editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyValueEditorAction {
+    /// A new (empty) row was added
+    Added {
+        /// Index of the new row
+        index: usize,
+    },
+    /// A row was removed
+    Removed {
+        /// Index the row had
+        index: usize,
+        /// Key of the removed row
+        key: String,
+        /// Value of the removed row
+        value: String,
+    },
+    /// A value was changed
+    Changed {
+        /// Key of the changed row
+        key: String,
+        /// Previous value
+        old: String,
+        /// New value
+        new: String,
+    },
+    /// A key was renamed
+    KeyRenamed {
+        /// Previous key
+        old: String,
+        /// New key
+        new: String,
+    },
+}
+
+/// State for the key-value editor.
+#[derive(Debug, Clone, Default)]
+pub struct KeyValueEditorState {
+    /// Rows
+    pub entries: Vec<KeyValueEntry>,
+    /// Selected row
+    pub selected_row: usize,
+    /// Selected column
+    pub selected_column: KeyValueColumn,
+    /// Scroll offset in rows
+    pub scroll: usize,
+    /// Inline editor for the selected cell, if editing
+    pub editing: Option<InputState>,
+    /// Multi-line editor for the selected value, if open
+    pub expanded: Option<TextAreaState>,
+    /// Whether editing is disabled
+    pub read_only: bool,
+    /// Whether the editor has focus
+    pub focused: bool,
+}
+
+impl KeyValueEditorState {
+    /// Create a new state from `(key, value)` pairs.
+    pub fn new<K, V>(entries: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|(k, v)| KeyValueEntry::new(k, v))
+                .collect(),
+            focused: true,
+            ..Default::default()
+        }
+    }
+
+    /// Set read-only mode.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Get the rows as `(key, value)` pairs.
+    pub fn pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|e| (e.key.as_str(), e.value.as_str()))
+    }
+
+    /// Get the selected row.
+    pub fn selected_entry(&self) -> Option<&KeyValueEntry> {
+        self.entries.get(self.selected_row)
+    }
+
+    /// Check if a cell is being edited (inline or expanded).
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some() || self.expanded.is_some()
+    }
+
+    /// Check if the key at `index` appears in more than one row.
+    pub fn is_duplicate_key(&self, index: usize) -> bool {
+        let Some(entry) = self.entries.get(index) else {
+            return false;
+        };
+        self.entries
+            .iter()
+            .enumerate()
+            .any(|(i, e)| i != index && e.key == entry.key)
+    }
+
+    /// Indices of all rows whose key is duplicated.
+    pub fn duplicate_rows(&self) -> Vec<usize> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for entry in &self.entries {
+            *counts.entry(entry.key.as_str()).or_default() += 1;
+        }
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| counts.get(e.key.as_str()).copied().unwrap_or(0) > 1)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Select the previous row.
+    pub fn select_prev(&mut self) {
+        self.selected_row = self.selected_row.saturating_sub(1);
+    }
+
+    /// Select the next row.
+    pub fn select_next(&mut self) {
+        if self.selected_row + 1 < self.entries.len() {
+            self.selected_row += 1;
+        }
+    }
+
+    /// Move to the next cell (key → value → next row's key).
+    pub fn select_next_cell(&mut self) {
+        match self.selected_column {
+            KeyValueColumn::Key => self.selected_column = KeyValueColumn::Value,
+            KeyValueColumn::Value => {
+                if self.selected_row + 1 < self.entries.len() {
+                    self.selected_row += 1;
+                    self.selected_column = KeyValueColumn::Key;
+                }
+            }
+        }
+    }
+
+    /// Move to the previous cell (value → key → previous row's value).
+    pub fn select_prev_cell(&mut self) {
+        match self.selected_column {
+            KeyValueColumn::Value => self.selected_column = KeyValueColumn::Key,
+            KeyValueColumn::Key => {
+                if self.selected_row > 0 {
+                    self.selected_row -= 1;
+                    self.selected_column = KeyValueColumn::Value;
+                }
+            }
+        }
+    }
+
+    /// Append an empty row, select its key cell, and return its index.
+    pub fn add_row(&mut self) -> Option<usize> {
+        if self.read_only {
+            return None;
+        }
+        self.entries.push(KeyValueEntry::default());
+        self.selected_row = self.entries.len() - 1;
+        self.selected_column = KeyValueColumn::Key;
+        Some(self.selected_row)
+    }
+
+    /// Remove the selected row.
+    pub fn remove_selected(&mut self) -> Option<KeyValueEditorAction> {
+        if self.read_only || self.selected_row >= self.entries.len() {
+            return None;
+        }
+        let index = self.selected_row;
+        let entry = self.entries.remove(index);
+        if self.selected_row >= self.entries.len() {
+            self.selected_row = self.entries.len().saturating_sub(1);
+        }
+        Some(KeyValueEditorAction::Removed {
+            index,
+            key: entry.key,
+            value: entry.value,
+        })
+    }
+
+    fn selected_text(&self) -> Option<&str> {
+        let entry = self.entries.get(self.selected_row)?;
+        Some(match self.selected_column {
+            KeyValueColumn::Key => &entry.key,
+            KeyValueColumn::Value => &entry.value,
+        })
+    }
+
+    /// Start inline editing of the selected cell.
+    pub fn begin_edit(&mut self) -> bool {
+        if self.read_only {
+            return false;
+        }
+        let Some(text) = self.selected_text() else {
+            return false;
+        };
+        let mut input = InputState::new(text);
+        input.focused = true;
+        self.editing = Some(input);
+        true
+    }
+
+    /// Discard the inline edit.
+    pub fn cancel_edit(&mut self) {
+        self.editing = None;
+    }
+
+    /// Apply the inline edit to the selected cell.
+    ///
+    /// Returns the resulting change, or `None` if nothing changed.
+    pub fn commit_edit(&mut self) -> Option<KeyValueEditorAction> {
+        let input = self.editing.take()?;
+        self.apply(self.selected_column, input.text)
+    }
+
+    /// Open the selected value in the multi-line editor.
+    pub fn open_expanded(&mut self) -> bool {
+        if self.read_only {
+            return false;
+        }
+        let Some(entry) = self.entries.get(self.selected_row) else {
+            return false;
+        };
+        let mut textarea = TextAreaState::new(entry.value.clone());
+        textarea.focused = true;
+        self.selected_column = KeyValueColumn::Value;
+        self.editing = None;
+        self.expanded = Some(textarea);
+        true
+    }
+
+    /// Discard the multi-line edit.
+    pub fn cancel_expanded(&mut self) {
+        self.expanded = None;
+    }
+
+    /// Apply the multi-line edit to the selected value.
+    pub fn commit_expanded(&mut self) -> Option<KeyValueEditorAction> {
+        let textarea = self.expanded.take()?;
+        self.apply(KeyValueColumn::Value, textarea.text())
+    }
+
+    fn apply(&mut self, column: KeyValueColumn, text: String) -> Option<KeyValueEditorAction> {
+        let entry = self.entries.get_mut(self.selected_row)?;
+        match column {
+            KeyValueColumn::Key if entry.key != text => {
+                let old = std::mem::replace(&mut entry.key, text);
+                Some(KeyValueEditorAction::KeyRenamed {
+                    old,
+                    new: entry.key.clone(),
+                })
+            }
+            KeyValueColumn::Value if entry.value != text => {
+                let old = std::mem::replace(&mut entry.value, text);
+                Some(KeyValueEditorAction::Changed {
+                    key: entry.key.clone(),
+                    old,
+                    new: entry.value.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Ensure the selected row is visible in a viewport of `rows` rows.
+    pub fn ensure_visible(&mut self, rows: usize) {
+        if rows == 0 {
+            return;
+        }
+        if self.selected_row < self.scroll {
+            self.scroll = self.selected_row;
+        } else if self.selected_row >= self.scroll + rows {
+            self.scroll = self.selected_row + 1 - rows;
+        }
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_key_value_editor_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑/↓", "Previous/next row"),
+            ("Tab/Shift+Tab", "Next/previous cell"),
+            ("Enter", "Edit cell"),
+            ("F4", "Edit value in expanded editor"),
+            ("Ins/Ctrl+N", "Add row"),
+            ("Del", "Remove row"),
+            ("Esc", "Cancel edit"),
+        ]
+    }
+}
+
+/// Style for the key-value editor.
+#[derive(Debug, Clone)]
+pub struct KeyValueEditorStyle {
+    /// Border style when focused
+    pub focused_border: Style,
+    /// Border style when not focused
+    pub unfocused_border: Style,
+    /// Column header style
+    pub header_style: Style,
+    /// Key cell style
+    pub key_style: Style,
+    /// Value cell style
+    pub value_style: Style,
+    /// Background of the selected row
+    pub selected_row_style: Style,
+    /// Style of the selected cell
+    pub selected_cell_style: Style,
+    /// Style for rows that fail validation
+    pub error_style: Style,
+    /// Column separator style
+    pub separator_style: Style,
+    /// Footer hint style
+    pub hint_style: Style,
+    /// Key column width as a percentage of the inner width
+    pub key_width_percent: u16,
+    /// Column separator
+    pub separator: &'static str,
+}
+
+impl Default for KeyValueEditorStyle {
+    fn default() -> Self {
+        Self {
+            focused_border: Style::default().fg(Color::Yellow),
+            unfocused_border: Style::default().fg(Color::Gray),
+            header_style: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            key_style: Style::default().fg(Color::White),
+            value_style: Style::default().fg(Color::Gray),
+            selected_row_style: Style::default().bg(Color::Rgb(40, 40, 40)),
+            selected_cell_style: Style::default().fg(Color::Black).bg(Color::Yellow),
+            error_style: Style::default().fg(Color::Red),
+            separator_style: Style::default().fg(Color::DarkGray),
+            hint_style: Style::default().fg(Color::DarkGray),
+            key_width_percent: 35,
+            separator: " │ ",
+        }
+    }
+}
+
+impl From<&crate::theme::Theme> for KeyValueEditorStyle {
+    fn from(theme: &crate::theme::Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            focused_border: Style::default().fg(p.border_focused),
+            unfocused_border: Style::default().fg(p.border),
+            header_style: Style::default()
+                .fg(p.secondary)
+                .add_modifier(Modifier::BOLD),
+            key_style: Style::default().fg(p.text),
+            value_style: Style::default().fg(p.text_dim),
+            selected_row_style: Style::default().bg(p.surface_raised),
+            selected_cell_style: Style::default().fg(p.highlight_fg).bg(p.highlight_bg),
+            error_style: Style::default().fg(p.error),
+            separator_style: Style::default().fg(p.separator),
+            hint_style: Style::default().fg(p.text_muted),
+            ..Default::default()
+        }
+    }
+}
+
+/// Key-value editor widget.
+pub struct KeyValueEditor<'a> {
+    title: Option<&'a str>,
+    key_header: &'a str,
+    value_header: &'a str,
+    style: KeyValueEditorStyle,
+    validator: Option<KeyValueValidateFn>,
+}
+
+impl Default for KeyValueEditor<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> KeyValueEditor<'a> {
+    /// Create a new key-value editor.
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            key_header: "Key",
+            value_header: "Value",
+            style: KeyValueEditorStyle::default(),
+            validator: None,
+        }
+    }
+
+    /// Set the block title.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Set the column headers.
+    pub fn headers(mut self, key: &'a str, value: &'a str) -> Self {
+        self.key_header = key;
+        self.value_header = value;
+        self
+    }
+
+    /// Set the style.
+    pub fn style(mut self, style: KeyValueEditorStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme to derive the style.
+    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
+        self.style(KeyValueEditorStyle::from(theme))
+    }
+
+    /// Set a custom validator in addition to the duplicate-key check.
+    pub fn validator(mut self, validate: impl Fn(&str, &str) -> Option<String> + 'static) -> Self {
+        self.validator = Some(Box::new(validate));
+        self
+    }
+
+    /// Validation error for a row, if any.
+    pub fn row_error(&self, state: &KeyValueEditorState, index: usize) -> Option<String> {
+        let entry = state.entries.get(index)?;
+        if state.is_duplicate_key(index) {
+            return Some(format!("Duplicate key '{}'", entry.key));
+        }
+        self.validator
+            .as_ref()
+            .and_then(|validate| validate(&entry.key, &entry.value))
+    }
+
+    /// Number of rows visible in the given area.
+    pub fn visible_rows(area: Rect) -> usize {
+        // Borders, header, and footer
+        area.height.saturating_sub(4) as usize
+    }
+
+    /// Render the editor and return click regions for each visible cell.
+    pub fn render_stateful(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        state: &mut KeyValueEditorState,
+    ) -> Vec<(Rect, KeyValueCell)> {
+        let mut regions = Vec::new();
+
+        let border_style = if state.focused {
+            self.style.focused_border
+        } else {
+            self.style.unfocused_border
+        };
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        if let Some(title) = self.title {
+            block = block.title(format!(" {} ", title));
+        }
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if inner.height < 2 || inner.width < 5 {
+            return regions;
+        }
+
+        let sep_width = self.style.separator.chars().count() as u16;
+        let key_width = (inner.width.saturating_sub(sep_width) as u32
+            * self.style.key_width_percent.min(100) as u32
+            / 100) as u16;
+        let value_x = inner.x + key_width + sep_width;
+        let value_width = inner.width.saturating_sub(key_width + sep_width);
+
+        // Header
+        let header = Line::from(vec![
+            Span::styled(
+                pad_to_width(self.key_header, key_width as usize),
+                self.style.header_style,
+            ),
+            Span::styled(self.style.separator, self.style.separator_style),
+            Span::styled(self.value_header, self.style.header_style),
+        ]);
+        frame.render_widget(
+            Paragraph::new(header),
+            Rect::new(inner.x, inner.y, inner.width, 1),
+        );
+
+        let rows_height = inner.height.saturating_sub(2);
+        state.ensure_visible(rows_height as usize);
+
+        let duplicates = state.duplicate_rows();
+        for (row, index) in (state.scroll..state.entries.len())
+            .take(rows_height as usize)
+            .enumerate()
+        {
+            let y = inner.y + 1 + row as u16;
+            let entry = &state.entries[index];
+            let is_selected_row = index == state.selected_row;
+            let has_error = duplicates.contains(&index)
+                || self
+                    .validator
+                    .as_ref()
+                    .is_some_and(|validate| validate(&entry.key, &entry.value).is_some());
+
+            let cell_style = |column: KeyValueColumn, base: Style| {
+                let mut style = base;
+                if has_error && column == KeyValueColumn::Key {
+                    style = style.patch(self.style.error_style);
+                }
+                if is_selected_row {
+                    style = style.patch(self.style.selected_row_style);
+                    if state.selected_column == column && state.focused {
+                        style = style.patch(self.style.selected_cell_style);
+                    }
+                }
+                style
+            };
+
+            let key_text = pad_to_width(
+                &truncate_middle(&entry.key, key_width as usize),
+                key_width as usize,
+            );
+            let value_text = pad_to_width(
+                &truncate_middle(&entry.value, value_width as usize),
+                value_width as usize,
+            );
+            let mut sep_style = self.style.separator_style;
+            if is_selected_row {
+                sep_style = sep_style.patch(self.style.selected_row_style);
+            }
+            let line = Line::from(vec![
+                Span::styled(
+                    key_text,
+                    cell_style(KeyValueColumn::Key, self.style.key_style),
+                ),
+                Span::styled(self.style.separator, sep_style),
+                Span::styled(
+                    value_text,
+                    cell_style(KeyValueColumn::Value, self.style.value_style),
+                ),
+            ]);
+            frame.render_widget(Paragraph::new(line), Rect::new(inner.x, y, inner.width, 1));
+
+            let key_area = Rect::new(inner.x, y, key_width, 1);
+            let value_area = Rect::new(value_x, y, value_width, 1);
+            regions.push((key_area, (index, KeyValueColumn::Key)));
+            regions.push((value_area, (index, KeyValueColumn::Value)));
+
+            // Inline editor over the selected cell
+            if is_selected_row && let Some(input) = &state.editing {
+                let cell_area = match state.selected_column {
+                    KeyValueColumn::Key => key_area,
+                    KeyValueColumn::Value => value_area,
+                };
+                frame.render_widget(Clear, cell_area);
+                Input::new(input)
+                    .with_border(false)
+                    .style(InputStyle::default())
+                    .render_stateful(frame, cell_area);
+            }
+        }
+
+        // Footer: validation error for the selected row, or key hints
+        let footer_area = Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1);
+        let footer = if let Some(error) = self.row_error(state, state.selected_row) {
+            Span::styled(error, self.style.error_style)
+        } else if state.read_only {
+            Span::styled("↑↓: navigate  Tab: next cell", self.style.hint_style)
+        } else if state.editing.is_some() {
+            Span::styled("Enter: save  Esc: cancel", self.style.hint_style)
+        } else {
+            Span::styled(
+                "Enter: edit  F4: expand  Ins: add  Del: remove",
+                self.style.hint_style,
+            )
+        };
+        frame.render_widget(Paragraph::new(footer), footer_area);
+
+        // Expanded multi-line editor
+        if let Some(textarea) = state.expanded.as_mut() {
+            let popup = expanded_area(area);
+            frame.render_widget(Clear, popup);
+            TextArea::new()
+                .label("Edit value (F4/Ctrl+S: save, Esc: cancel)")
+                .style(TextAreaStyle::default())
+                .render_stateful(frame, popup, textarea);
+        }
+
+        regions
+    }
+
+    /// Render and register cell click regions.
+    pub fn render_with_registry(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        state: &mut KeyValueEditorState,
+        registry: &mut ClickRegionRegistry<KeyValueCell>,
+    ) {
+        for (rect, cell) in self.render_stateful(frame, area, state) {
+            registry.register(rect, cell);
+        }
+    }
+}
+
+/// Area of the expanded value editor within the editor area.
+fn expanded_area(area: Rect) -> Rect {
+    let width = (area.width * 4 / 5).max(20).min(area.width);
+    let height = (area.height * 3 / 4).max(5).min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+fn handle_input_key(input: &mut InputState, key: &KeyEvent) {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char(c) if !ctrl => input.insert_char(c),
+        KeyCode::Backspace => {
+            input.delete_char_backward();
+        }
+        KeyCode::Delete => {
+            input.delete_char_forward();
+        }
+        KeyCode::Left if ctrl => input.move_word_left(),
+        KeyCode::Right if ctrl => input.move_word_right(),
+        KeyCode::Left => input.move_left(),
+        KeyCode::Right => input.move_right(),
+        KeyCode::Home => input.move_home(),
+        KeyCode::End => input.move_end(),
+        _ => {}
+    }
+}
+
+fn handle_textarea_key(textarea: &mut TextAreaState, key: &KeyEvent) {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char(c) if !ctrl => textarea.insert_char(c),
+        KeyCode::Enter => textarea.insert_newline(),
+        KeyCode::Tab => textarea.insert_tab(),
+        KeyCode::Backspace => {
+            textarea.delete_char_backward();
+        }
+        KeyCode::Delete => {
+            textarea.delete_char_forward();
+        }
+        KeyCode::Left => textarea.move_left(),
+        KeyCode::Right => textarea.move_right(),
+        KeyCode::Up => textarea.move_up(),
+        KeyCode::Down => textarea.move_down(),
+        KeyCode::Home => textarea.move_line_start(),
+        KeyCode::End => textarea.move_line_end(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events for the key-value editor.
+///
+/// Returns an action when the data changed.
+pub fn handle_key_value_editor_key(
+    state: &mut KeyValueEditorState,
+    key: &KeyEvent,
+) -> Option<KeyValueEditorAction> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    // Expanded multi-line editor
+    if let Some(textarea) = state.expanded.as_mut() {
+        match key.code {
+            KeyCode::Esc => state.cancel_expanded(),
+            KeyCode::F(4) => return state.commit_expanded(),
+            KeyCode::Char('s') if ctrl => return state.commit_expanded(),
+            _ => handle_textarea_key(textarea, key),
+        }
+        return None;
+    }
+
+    // Inline editor
+    if let Some(input) = state.editing.as_mut() {
+        match key.code {
+            KeyCode::Esc => state.cancel_edit(),
+            KeyCode::Enter => return state.commit_edit(),
+            KeyCode::Tab | KeyCode::BackTab => {
+                let action = state.commit_edit();
+                if key.code == KeyCode::Tab {
+                    state.select_next_cell();
+                } else {
+                    state.select_prev_cell();
+                }
+                return action;
+            }
+            _ => handle_input_key(input, key),
+        }
+        return None;
+    }
+
+    match key.code {
+        KeyCode::Up => state.select_prev(),
+        KeyCode::Down => state.select_next(),
+        KeyCode::Left => state.selected_column = KeyValueColumn::Key,
+        KeyCode::Right => state.selected_column = KeyValueColumn::Value,
+        KeyCode::Tab => state.select_next_cell(),
+        KeyCode::BackTab => state.select_prev_cell(),
+        KeyCode::Home => state.selected_row = 0,
+        KeyCode::End => state.selected_row = state.entries.len().saturating_sub(1),
+        KeyCode::Enter => {
+            state.begin_edit();
+        }
+        KeyCode::F(4) => {
+            state.open_expanded();
+        }
+        KeyCode::Insert => return add_and_edit(state),
+        KeyCode::Char('n') if ctrl => return add_and_edit(state),
+        KeyCode::Delete => return state.remove_selected(),
+        _ => {}
+    }
+    None
+}
+
+fn add_and_edit(state: &mut KeyValueEditorState) -> Option<KeyValueEditorAction> {
+    let index = state.add_row()?;
+    state.begin_edit();
+    Some(KeyValueEditorAction::Added { index })
+}
+
+/// Handle mouse events for the key-value editor.
+///
+/// Clicking a cell selects it; clicking the already selected cell starts
+/// editing it. The scroll wheel moves the selection.
+pub fn handle_key_value_editor_mouse(
+    state: &mut KeyValueEditorState,
+    registry: &ClickRegionRegistry<KeyValueCell>,
+    mouse: &MouseEvent,
+) -> Option<KeyValueEditorAction> {
+    if state.expanded.is_some() {
+        return None;
+    }
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let &(row, column) = registry.handle_click(mouse.column, mouse.row)?;
+            let action = state.commit_edit();
+            let already_selected = state.selected_row == row && state.selected_column == column;
+            state.selected_row = row;
+            state.selected_column = column;
+            state.focused = true;
+            if already_selected && action.is_none() {
+                state.begin_edit();
+            }
+            action
+        }
+        MouseEventKind::ScrollUp => {
+            state.select_prev();
+            None
+        }
+        MouseEventKind::ScrollDown => {
+            state.select_next();
+            None
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn sample() -> KeyValueEditorState {
+        KeyValueEditorState::new([("HOME", "/root"), ("PATH", "/usr/bin")])
+    }
+
+    #[test]
+    fn test_tab_navigation() {
+        let mut state = sample();
+        assert_eq!(
+            (state.selected_row, state.selected_column),
+            (0, KeyValueColumn::Key)
+        );
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(
+            (state.selected_row, state.selected_column),
+            (0, KeyValueColumn::Value)
+        );
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(
+            (state.selected_row, state.selected_column),
+            (1, KeyValueColumn::Key)
+        );
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::BackTab));
+        assert_eq!(
+            (state.selected_row, state.selected_column),
+            (0, KeyValueColumn::Value)
+        );
+    }
+
+    #[test]
+    fn test_inline_edit_key_and_value() {
+        let mut state = sample();
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Enter));
+        assert!(state.is_editing());
+        for c in "_DIR".chars() {
+            handle_key_value_editor_key(&mut state, &key(KeyCode::Char(c)));
+        }
+        // Tab commits and moves to the value cell
+        let action = handle_key_value_editor_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(
+            action,
+            Some(KeyValueEditorAction::KeyRenamed {
+                old: "HOME".into(),
+                new: "HOME_DIR".into(),
+            })
+        );
+        assert_eq!(state.selected_column, KeyValueColumn::Value);
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Enter));
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Char('/')));
+        let action = handle_key_value_editor_key(&mut state, &key(KeyCode::Enter));
+        assert_eq!(
+            action,
+            Some(KeyValueEditorAction::Changed {
+                key: "HOME_DIR".into(),
+                old: "/root".into(),
+                new: "/root/".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_cancel_and_unchanged_edit() {
+        let mut state = sample();
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Enter));
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Char('x')));
+        assert_eq!(
+            handle_key_value_editor_key(&mut state, &key(KeyCode::Esc)),
+            None
+        );
+        assert_eq!(state.entries[0].key, "HOME");
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Enter));
+        assert_eq!(
+            handle_key_value_editor_key(&mut state, &key(KeyCode::Enter)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_rows() {
+        let mut state = sample();
+        let action = handle_key_value_editor_key(&mut state, &key(KeyCode::Insert));
+        assert_eq!(action, Some(KeyValueEditorAction::Added { index: 2 }));
+        assert!(state.editing.is_some());
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Esc));
+
+        let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+        assert_eq!(
+            handle_key_value_editor_key(&mut state, &ctrl_n),
+            Some(KeyValueEditorAction::Added { index: 3 })
+        );
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Esc));
+
+        state.selected_row = 0;
+        let action = handle_key_value_editor_key(&mut state, &key(KeyCode::Delete));
+        assert_eq!(
+            action,
+            Some(KeyValueEditorAction::Removed {
+                index: 0,
+                key: "HOME".into(),
+                value: "/root".into(),
+            })
+        );
+        assert_eq!(state.entries.len(), 3);
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        let state = KeyValueEditorState::new([("a", "1"), ("b", "2"), ("a", "3")]);
+        assert_eq!(state.duplicate_rows(), vec![0, 2]);
+        assert!(state.is_duplicate_key(2));
+        assert!(!state.is_duplicate_key(1));
+
+        let editor = KeyValueEditor::new().validator(|key, _| {
+            key.contains(' ')
+                .then(|| "Keys cannot contain spaces".to_string())
+        });
+        assert_eq!(
+            editor.row_error(&state, 0),
+            Some("Duplicate key 'a'".to_string())
+        );
+        assert_eq!(editor.row_error(&state, 1), None);
+
+        let state = KeyValueEditorState::new([("bad key", "1")]);
+        assert_eq!(
+            editor.row_error(&state, 0),
+            Some("Keys cannot contain spaces".to_string())
+        );
+    }
+
+    #[test]
+    fn test_expanded_editor() {
+        let mut state = sample();
+        handle_key_value_editor_key(&mut state, &key(KeyCode::F(4)));
+        assert!(state.expanded.is_some());
+        assert_eq!(state.selected_column, KeyValueColumn::Value);
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::End));
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Enter));
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Char('x')));
+        let action = handle_key_value_editor_key(&mut state, &key(KeyCode::F(4)));
+        assert_eq!(
+            action,
+            Some(KeyValueEditorAction::Changed {
+                key: "HOME".into(),
+                old: "/root".into(),
+                new: "/root\nx".into(),
+            })
+        );
+        assert!(state.expanded.is_none());
+    }
+
+    #[test]
+    fn test_read_only() {
+        let mut state = sample().read_only(true);
+        assert_eq!(
+            handle_key_value_editor_key(&mut state, &key(KeyCode::Insert)),
+            None
+        );
+        assert_eq!(
+            handle_key_value_editor_key(&mut state, &key(KeyCode::Delete)),
+            None
+        );
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Enter));
+        handle_key_value_editor_key(&mut state, &key(KeyCode::F(4)));
+        assert!(!state.is_editing());
+
+        handle_key_value_editor_key(&mut state, &key(KeyCode::Down));
+        assert_eq!(state.selected_row, 1);
+    }
+
+    #[test]
+    fn test_render_truncates_and_registers_cells() {
+        let mut state = KeyValueEditorState::new([("URL", "https://example.com/a/very/long/path")]);
+        let editor = KeyValueEditor::new().title("Headers");
+        let mut registry = ClickRegionRegistry::new();
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+        terminal
+            .draw(|f| editor.render_with_registry(f, f.area(), &mut state, &mut registry))
+            .unwrap();
+
+        assert_eq!(registry.len(), 2);
+        let buf = terminal.backend().buffer();
+        let row: String = (0..40).map(|x| buf[(x, 2)].symbol().to_string()).collect();
+        assert!(row.contains("URL"));
+        assert!(row.contains("..."));
+        assert!(row.contains("path"));
+
+        // Clicking the selected key cell starts editing it
+        let key_cell = registry.regions()[0].area;
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: key_cell.x,
+            row: key_cell.y,
+            modifiers: KeyModifiers::NONE,
+        };
+        handle_key_value_editor_mouse(&mut state, &registry, &click);
+        assert!(state.editing.is_some());
+    }
+}
@@ -28,14 +28,24 @@
 //!     });
 //! ```
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+    widgets::{Block, Borders, Paragraph, ScrollbarOrientation, Widget, Wrap},
 };
 
+use super::interactive_scrollbar::{InteractiveScrollbar, ScrollbarDragState, ScrollbarPart};
+use super::option_details::{
+    OptionDetails, OptionDetailsFn, OptionRowStyles, description_row, highlight_spans, option_row,
+};
+use super::spinner::load_status_spans;
+use crate::state::{Loadable, PageMove, PageSize, sync_selection};
+use crate::traits::ClickRegionRegistry;
+use crate::utils::display::spaces;
+
 /// State for the list picker widget
 #[derive(Debug, Clone, Default)]
 pub struct ListPickerState {
@@ -45,15 +55,135 @@ pub struct ListPickerState {
     pub scroll: u16,
     /// Total number of items
     pub total_items: usize,
+    /// Scrollbar thumb drag state
+    pub scrollbar_drag: ScrollbarDragState,
+    /// Whether printable keys jump to the next item starting with that character
+    pub jump_to_char: bool,
+    /// Character of the last jump, for cycling through matches
+    pub last_jump_char: Option<char>,
+    /// Index matched by the last jump
+    pub last_jump_index: usize,
+    /// Rows taken by each item (2 when items show a description line)
+    pub item_height: usize,
+    /// Loading phase of the items; a spinner or the load error replaces
+    /// them while loading or after a failure
+    pub load: Loadable<()>,
+    /// Item groups, sorted by start index; the header of the group holding
+    /// the top visible item stays pinned above the items
+    pub groups: Vec<ListGroup>,
+    /// How far PageUp/PageDown move the selection
+    pub page_size: PageSize,
+}
+
+/// A run of consecutive items sharing a header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListGroup {
+    /// Header text
+    pub label: String,
+    /// Index of the first item in the group
+    pub start: usize,
+}
+
+impl ListGroup {
+    /// Create a group starting at item `start`
+    pub fn new(label: impl Into<String>, start: usize) -> Self {
+        Self {
+            label: label.into(),
+            start,
+        }
+    }
+}
+
+/// Scroll position of a [`ListPickerState`], for [`ScrollMemory`](crate::state::ScrollMemory)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListPickerSnapshot {
+    /// Selected index
+    pub selected_index: usize,
+    /// Scroll offset
+    pub scroll: u16,
 }
 
 impl ListPickerState {
+    /// Capture the selection and scroll offset
+    pub fn snapshot(&self) -> ListPickerSnapshot {
+        ListPickerSnapshot {
+            selected_index: self.selected_index,
+            scroll: self.scroll,
+        }
+    }
+
+    /// Restore a [`snapshot`](Self::snapshot), clamped to the current items
+    pub fn apply_snapshot(&mut self, snapshot: &ListPickerSnapshot) {
+        let last = self.total_items.saturating_sub(1);
+        self.selected_index = snapshot.selected_index.min(last);
+        self.scroll = snapshot
+            .scroll
+            .min(self.selected_index.min(u16::MAX as usize) as u16);
+    }
+
     /// Create a new list picker state with the given number of items
     pub fn new(total_items: usize) -> Self {
         Self {
             selected_index: 0,
             scroll: 0,
             total_items,
+            scrollbar_drag: ScrollbarDragState::default(),
+            jump_to_char: false,
+            last_jump_char: None,
+            last_jump_index: 0,
+            item_height: 1,
+            load: Loadable::NotLoaded,
+            groups: Vec::new(),
+            page_size: PageSize::Full,
+        }
+    }
+
+    /// Set how far PageUp/PageDown move the selection
+    pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Set the item groups
+    pub fn with_groups(mut self, groups: Vec<ListGroup>) -> Self {
+        self.set_groups(groups);
+        self
+    }
+
+    /// Replace the item groups, sorting them by start index
+    pub fn set_groups(&mut self, mut groups: Vec<ListGroup>) {
+        groups.sort_by_key(|group| group.start);
+        self.groups = groups;
+    }
+
+    /// The group containing the item at `index`
+    pub fn group_at(&self, index: usize) -> Option<&ListGroup> {
+        let after = self.groups.partition_point(|group| group.start <= index);
+        after.checked_sub(1).map(|i| &self.groups[i])
+    }
+
+    /// The group of the top visible item, whose header is pinned
+    pub fn current_group(&self) -> Option<&ListGroup> {
+        self.group_at(self.scroll as usize)
+    }
+
+    /// Set the rows taken by each item
+    ///
+    /// Use 2 when the picker shows item descriptions on their own line, so
+    /// scrolling and paging count items rather than rows.
+    pub fn with_item_height(mut self, rows: usize) -> Self {
+        self.item_height = rows;
+        self
+    }
+
+    /// Number of whole items that fit in `viewport_height` rows
+    ///
+    /// With [`groups`](Self::groups), one row goes to the pinned header.
+    pub fn items_per_viewport(&self, viewport_height: usize) -> usize {
+        let header = usize::from(!self.groups.is_empty());
+        match viewport_height {
+            0 => 0,
+            rows => (rows.saturating_sub(header) / self.item_height.max(1)).max(1),
         }
     }
 
@@ -90,8 +220,9 @@ impl ListPickerState {
         }
     }
 
-    /// Ensure selected item is visible in viewport
+    /// Ensure selected item is visible in a viewport of `viewport_height` rows
     pub fn ensure_visible(&mut self, viewport_height: usize) {
+        let viewport_height = self.items_per_viewport(viewport_height);
         if viewport_height == 0 {
             return;
         }
@@ -103,6 +234,22 @@ impl ListPickerState {
         }
     }
 
+    /// Start loading the items at the caller's `tick`
+    pub fn set_loading(&mut self, tick: u64) {
+        self.load.set_loading(tick);
+    }
+
+    /// Finish loading with `total` items
+    pub fn set_loaded(&mut self, total: usize) {
+        self.load.set_loaded(());
+        self.set_total(total);
+    }
+
+    /// Mark loading the items as failed
+    pub fn set_failed(&mut self, message: impl Into<String>) {
+        self.load.set_failed(message);
+    }
+
     /// Update total items count
     pub fn set_total(&mut self, total: usize) {
         self.total_items = total;
@@ -110,6 +257,75 @@ impl ListPickerState {
             self.selected_index = total - 1;
         }
     }
+
+    /// Update the items, keeping the selection on the same item
+    ///
+    /// `old_keys` and `new_keys` identify the items before and after a data
+    /// refresh, e.g. process IDs. The selection follows its key and stays on
+    /// the same viewport row when possible; if the key is gone it falls back
+    /// to the nearest index. See [`sync_selection`].
+    pub fn sync_items<K: PartialEq>(&mut self, old_keys: &[K], new_keys: &[K]) {
+        let (selected, scroll) = sync_selection(
+            old_keys,
+            new_keys,
+            self.selected_index,
+            self.scroll as usize,
+        );
+        self.total_items = new_keys.len();
+        self.selected_index = selected;
+        self.scroll = scroll.min(u16::MAX as usize) as u16;
+    }
+
+    /// Select the first item whose text starts with `c` (case-insensitive)
+    ///
+    /// Pressing the same character again selects the next match, wrapping
+    /// around. Items are matched by their `Display` text. Returns `true` if
+    /// a match was found.
+    pub fn jump_to_char<T: std::fmt::Display>(&mut self, c: char, items: &[T]) -> bool {
+        let count = items.len().min(self.total_items);
+        if count == 0 {
+            return false;
+        }
+
+        let repeated = self.last_jump_char.is_some_and(|last| chars_match(last, c));
+        let start = if repeated {
+            (self.last_jump_index + 1) % count
+        } else {
+            0
+        };
+
+        let found = (0..count).map(|i| (start + i) % count).find(|&idx| {
+            items[idx]
+                .to_string()
+                .chars()
+                .next()
+                .is_some_and(|first| chars_match(first, c))
+        });
+
+        self.last_jump_char = Some(c);
+        match found {
+            Some(idx) => {
+                self.selected_index = idx;
+                self.last_jump_index = idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_list_picker_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑/↓", "Move selection"),
+            ("Home/End", "First/last item"),
+            ("PgUp/PgDn", "Page"),
+            ("a-z", "Jump to item (when enabled)"),
+        ]
+    }
+}
+
+fn chars_match(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
 }
 
 /// Style configuration for list picker
@@ -129,6 +345,16 @@ pub struct ListPickerStyle {
     pub indicator_empty: &'static str,
     /// Whether to show borders
     pub bordered: bool,
+    /// Style for item descriptions
+    pub description_style: Style,
+    /// Style for right-aligned item hints
+    pub hint_style: Style,
+    /// Style patched onto text matching the search query
+    pub match_style: Style,
+    /// Style for the load error shown when loading the items failed
+    pub error_style: Style,
+    /// Style for the pinned group header row
+    pub group_header_style: Style,
 }
 
 impl Default for ListPickerStyle {
@@ -143,6 +369,15 @@ impl Default for ListPickerStyle {
             indicator: "▶ ",
             indicator_empty: "  ",
             bordered: true,
+            description_style: Style::default().fg(Color::DarkGray),
+            hint_style: Style::default().fg(Color::DarkGray),
+            match_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::UNDERLINED),
+            error_style: Style::default().fg(Color::Red),
+            group_header_style: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
         }
     }
 }
@@ -158,6 +393,15 @@ impl From<&crate::theme::Theme> for ListPickerStyle {
             indicator: "▶ ",
             indicator_empty: "  ",
             bordered: true,
+            description_style: Style::default().fg(p.text_dim),
+            hint_style: Style::default().fg(p.text_muted),
+            match_style: Style::default()
+                .fg(p.warning)
+                .add_modifier(Modifier::UNDERLINED),
+            error_style: Style::default().fg(p.error),
+            group_header_style: Style::default()
+                .fg(p.text_muted)
+                .add_modifier(Modifier::BOLD),
         }
     }
 }
@@ -192,6 +436,36 @@ impl ListPickerStyle {
         self.bordered = bordered;
         self
     }
+
+    /// Set the style for item descriptions
+    pub fn description_style(mut self, style: Style) -> Self {
+        self.description_style = style;
+        self
+    }
+
+    /// Set the style for item hints
+    pub fn hint_style(mut self, style: Style) -> Self {
+        self.hint_style = style;
+        self
+    }
+
+    /// Set the style for text matching the search query
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Set the style for load errors
+    pub fn error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
+
+    /// Set the style for the pinned group header
+    pub fn group_header_style(mut self, style: Style) -> Self {
+        self.group_header_style = style;
+        self
+    }
 }
 
 /// Default render function type
@@ -207,7 +481,12 @@ where
     style: ListPickerStyle,
     title: Option<&'a str>,
     footer: Option<Vec<Line<'static>>>,
+    show_scrollbar: bool,
     render_fn: F,
+    details: Option<OptionDetailsFn<'a, T>>,
+    search: &'a str,
+    tick: u64,
+    loading_message: &'a str,
 }
 
 impl<'a, T: std::fmt::Display> ListPicker<'a, T, DefaultRenderFn<T>> {
@@ -219,7 +498,12 @@ impl<'a, T: std::fmt::Display> ListPicker<'a, T, DefaultRenderFn<T>> {
             style: ListPickerStyle::default(),
             title: None,
             footer: None,
+            show_scrollbar: false,
             render_fn: |item, _idx, _selected| vec![Line::from(item.to_string())],
+            details: None,
+            search: "",
+            tick: 0,
+            loading_message: "Loading...",
         }
     }
 }
@@ -242,10 +526,43 @@ where
             style: self.style,
             title: self.title,
             footer: self.footer,
+            show_scrollbar: self.show_scrollbar,
             render_fn,
+            details: self.details,
+            search: self.search,
+            tick: self.tick,
+            loading_message: self.loading_message,
         }
     }
 
+    /// Set a function providing each item's description and hint
+    ///
+    /// Descriptions render on a second, dimmer line when
+    /// `state.item_height` is 2 or more, and are appended to the first line
+    /// otherwise. Hints are right-aligned and dropped when space is short.
+    pub fn item_details(mut self, details: impl Fn(&T) -> OptionDetails + 'a) -> Self {
+        self.details = Some(Box::new(details));
+        self
+    }
+
+    /// Highlight occurrences of `query` in item text and descriptions
+    pub fn search(mut self, query: &'a str) -> Self {
+        self.search = query;
+        self
+    }
+
+    /// Set the caller's tick counter, which animates the loading spinner
+    pub fn tick(mut self, tick: u64) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Set the message shown beside the spinner while items load
+    pub fn loading_message(mut self, message: &'a str) -> Self {
+        self.loading_message = message;
+        self
+    }
+
     /// Set the title
     pub fn title(mut self, title: &'a str) -> Self {
         self.title = Some(title);
@@ -269,14 +586,103 @@ where
         self.style(ListPickerStyle::from(theme))
     }
 
+    /// Show a scrollbar beside the items when they overflow
+    ///
+    /// The scrollbar is sized from `state.total_items`.
+    pub fn show_scrollbar(mut self, show: bool) -> Self {
+        self.show_scrollbar = show;
+        self
+    }
+
+    /// Render and register the scrollbar parts for click handling
+    ///
+    /// Pass the registry to [`handle_list_picker_scrollbar_mouse`].
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<ScrollbarPart>,
+    ) {
+        registry.clear();
+        self.render_impl(area, buf, Some(registry));
+    }
+
+    fn render_impl(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: Option<&mut ClickRegionRegistry<ScrollbarPart>>,
+    ) {
+        let block = if self.style.bordered {
+            Some(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(self.style.border_style),
+            )
+        } else {
+            None
+        };
+
+        let inner = if let Some(ref block) = block {
+            block.inner(area)
+        } else {
+            area
+        };
+
+        if let Some(block) = block {
+            block.render(area, buf);
+        }
+
+        // Centered spinner or load error instead of the items
+        if let Some(spans) = load_status_spans(
+            &self.state.load,
+            self.tick,
+            self.loading_message,
+            self.style.description_style,
+            self.style.error_style,
+        ) {
+            let row = Rect {
+                y: inner.y + inner.height / 2,
+                height: inner.height.min(1),
+                ..inner
+            };
+            Paragraph::new(Line::from(spans))
+                .alignment(Alignment::Center)
+                .render(row, buf);
+            return;
+        }
+
+        let lines = self.build_lines(inner.width, inner.height);
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+        paragraph.render(inner, buf);
+
+        if self.show_scrollbar {
+            let header_lines = if self.title.is_some() { 2 } else { 0 };
+            let footer_lines = self.footer.as_ref().map_or(0, |f| f.len() as u16);
+            let items_area = Rect {
+                y: inner.y + header_lines,
+                height: inner.height.saturating_sub(header_lines + footer_lines),
+                ..inner
+            };
+            let viewport = self.state.items_per_viewport(items_area.height as usize);
+            if self.state.total_items > viewport {
+                let scrollbar = list_picker_scrollbar(self.state, items_area.height as usize);
+                match registry {
+                    Some(registry) => scrollbar.render_with_registry(items_area, buf, registry),
+                    None => scrollbar.render(items_area, buf),
+                }
+            }
+        }
+    }
+
     /// Build the lines for rendering
-    fn build_lines(&self, _area: Rect, inner_height: u16) -> Vec<Line<'static>> {
+    fn build_lines(&self, inner_width: u16, inner_height: u16) -> Vec<Line<'a>> {
         let mut lines = Vec::new();
 
         // Header
         if let Some(title) = self.title {
             lines.push(Line::from(vec![Span::styled(
-                title.to_string(),
+                title,
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
@@ -287,7 +693,11 @@ where
         // Calculate available height for items
         let header_lines = if self.title.is_some() { 2 } else { 0 };
         let footer_lines = self.footer.as_ref().map(|f| f.len()).unwrap_or(0);
-        let available_height = inner_height as usize - header_lines - footer_lines;
+        let mut available_height = inner_height as usize - header_lines - footer_lines;
+        let item_height = self.state.item_height.max(1);
+        let two_row = item_height >= 2 && available_height >= 2;
+        let width = inner_width as usize;
+        let indent = self.style.indicator.chars().count();
 
         // Items
         if self.items.is_empty() {
@@ -297,12 +707,25 @@ where
             )]));
         } else {
             let scroll = self.state.scroll as usize;
+
+            // Pinned header of the group the top item belongs to
+            if let Some(group) = self.state.current_group() {
+                let label = format!("{:<width$}", group.label, width = width);
+                lines.push(Line::from(Span::styled(
+                    label,
+                    self.style.group_header_style,
+                )));
+                available_height = available_height.saturating_sub(1);
+            }
+
+            // Reused for lines made of several spans
+            let mut text = String::new();
             for (idx, item) in self
                 .items
                 .iter()
                 .enumerate()
                 .skip(scroll)
-                .take(available_height)
+                .take(available_height.div_ceil(item_height))
             {
                 let is_selected = idx == self.state.selected_index;
                 let indicator = if is_selected {
@@ -317,28 +740,73 @@ where
                     self.style.normal_style
                 };
 
+                let details = self.details.as_ref().map(|details| details(item));
+                let row_styles = OptionRowStyles {
+                    label: item_style,
+                    description: self.style.description_style,
+                    hint: self.style.hint_style,
+                    matched: self.style.match_style,
+                };
+
                 let item_lines = (self.render_fn)(item, idx, is_selected);
                 for (line_idx, line) in item_lines.into_iter().enumerate() {
                     let mut spans = Vec::new();
 
                     // Only show indicator on first line of item
                     if line_idx == 0 {
-                        spans.push(Span::styled(
-                            indicator.to_string(),
-                            self.style.indicator_style,
-                        ));
+                        spans.push(Span::styled(indicator, self.style.indicator_style));
                     } else {
                         // Indent continuation lines
-                        spans.push(Span::raw(" ".repeat(self.style.indicator.len())));
+                        spans.push(Span::raw(spaces(self.style.indicator.len())));
+                    }
+
+                    // Without details or a search, restyle the spans in place
+                    let has_details = details.is_some() && line_idx == 0;
+                    if !has_details && self.search.is_empty() {
+                        spans.extend(
+                            line.spans
+                                .into_iter()
+                                .map(|span| Span::styled(span.content, item_style)),
+                        );
+                        lines.push(Line::from(spans));
+                        continue;
                     }
 
                     // Add the line content with appropriate style
-                    for span in line.spans {
-                        spans.push(Span::styled(span.content.to_string(), item_style));
+                    text.clear();
+                    text.extend(line.spans.iter().map(|s| s.content.as_ref()));
+                    match &details {
+                        Some(details) if line_idx == 0 => spans.extend(option_row(
+                            &text,
+                            details.description.as_deref().filter(|_| !two_row),
+                            details.hint.as_deref(),
+                            width.saturating_sub(indent),
+                            self.search,
+                            row_styles,
+                        )),
+                        _ => spans.extend(highlight_spans(
+                            &text,
+                            self.search,
+                            item_style,
+                            self.style.match_style,
+                        )),
                     }
 
                     lines.push(Line::from(spans));
                 }
+
+                if two_row
+                    && let Some(description) =
+                        details.as_ref().and_then(|d| d.description.as_deref())
+                {
+                    lines.push(description_row(
+                        description,
+                        indent,
+                        width,
+                        self.search,
+                        row_styles,
+                    ));
+                }
             }
         }
 
@@ -358,29 +826,100 @@ where
     F: Fn(&T, usize, bool) -> Vec<Line<'static>>,
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let block = if self.style.bordered {
-            Some(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(self.style.border_style),
-            )
-        } else {
-            None
-        };
-
-        let inner = if let Some(ref block) = block {
-            block.inner(area)
-        } else {
-            area
-        };
+        self.render_impl(area, buf, None);
+    }
+}
 
-        if let Some(block) = block {
-            block.render(area, buf);
+/// Handle keyboard input for a list picker
+///
+/// Arrows and the [paging keys](crate::state::PageMove) move the selection
+/// and keep it within a viewport of `viewport_height` rows; pages are
+/// [`ListPickerState::page_size`] items. When [`ListPickerState::jump_to_char`]
+/// is enabled, other printable characters jump to the next matching item.
+///
+/// Keys are ignored while the items are loading or failed to load; pass
+/// them to [`handle_loadable_retry_key`](crate::state::handle_loadable_retry_key)
+/// with `state.load` to offer a retry.
+///
+/// Returns `true` if the key was handled.
+pub fn handle_list_picker_key<T: std::fmt::Display>(
+    key: &KeyEvent,
+    state: &mut ListPickerState,
+    items: &[T],
+    viewport_height: usize,
+) -> bool {
+    if state.load.is_pending() {
+        return false;
+    }
+    if let Some(page_move) = PageMove::from_key(key) {
+        state.selected_index = page_move.apply(
+            state.selected_index,
+            state.total_items,
+            state.items_per_viewport(viewport_height),
+            state.page_size,
+        );
+        state.ensure_visible(viewport_height);
+        return true;
+    }
+    match key.code {
+        KeyCode::Up => state.select_prev(),
+        KeyCode::Down => state.select_next(),
+        KeyCode::Char(c)
+            if state.jump_to_char
+                && !c.is_control()
+                && !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            if !state.jump_to_char(c, items) {
+                return false;
+            }
         }
+        _ => return false,
+    }
+    state.ensure_visible(viewport_height);
+    true
+}
 
-        let lines = self.build_lines(area, inner.height);
-        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
-        paragraph.render(inner, buf);
+fn list_picker_scrollbar(state: &ListPickerState, viewport_rows: usize) -> InteractiveScrollbar {
+    let scrollbar = InteractiveScrollbar::new(
+        ScrollbarOrientation::VerticalRight,
+        state.total_items,
+        state.scroll as usize,
+    );
+    if state.item_height > 1 {
+        scrollbar.viewport_length(state.items_per_viewport(viewport_rows))
+    } else {
+        scrollbar
+    }
+}
+
+/// Handle mouse input on the list picker scrollbar
+///
+/// Clicking the arrows scrolls one item, clicking the track pages, and
+/// dragging the thumb scrolls proportionally. The selection is left
+/// unchanged. `registry` must come from [`ListPicker::render_with_registry`].
+///
+/// Returns `true` if the event was consumed.
+pub fn handle_list_picker_scrollbar_mouse(
+    state: &mut ListPickerState,
+    registry: &ClickRegionRegistry<ScrollbarPart>,
+    mouse: &MouseEvent,
+) -> bool {
+    let was_dragging = state.scrollbar_drag.is_dragging();
+    let viewport_rows = registry
+        .regions()
+        .iter()
+        .map(|r| r.area)
+        .reduce(|a, b| a.union(b))
+        .map_or(0, |bar| bar.height as usize);
+    let scrollbar = list_picker_scrollbar(state, viewport_rows);
+    match scrollbar.handle_mouse(registry.regions(), &mut state.scrollbar_drag, mouse) {
+        Some(position) => {
+            state.scroll = position.min(u16::MAX as usize) as u16;
+            true
+        }
+        None => was_dragging || state.scrollbar_drag.is_dragging(),
     }
 }
 
@@ -533,6 +1072,34 @@ mod tests {
         picker.render(Rect::new(0, 0, 40, 10), &mut buf);
     }
 
+    #[test]
+    fn test_multi_span_lines_render_like_joined_text() {
+        let items = vec!["Alpha", "Beta"];
+        let state = ListPickerState::new(items.len());
+        let area = Rect::new(0, 0, 30, 6);
+
+        for query in ["", "ta"] {
+            let mut split = Buffer::empty(area);
+            ListPicker::new(&items, &state)
+                .search(query)
+                .render_item(|item, idx, _| {
+                    vec![Line::from(vec![
+                        Span::raw(format!("{}. ", idx + 1)),
+                        Span::raw(item.to_string()),
+                    ])]
+                })
+                .render(area, &mut split);
+
+            let mut joined = Buffer::empty(area);
+            ListPicker::new(&items, &state)
+                .search(query)
+                .render_item(|item, idx, _| vec![Line::from(format!("{}. {}", idx + 1, item))])
+                .render(area, &mut joined);
+
+            assert_eq!(split, joined);
+        }
+    }
+
     #[test]
     fn test_list_picker_styles() {
         let arrow = ListPickerStyle::arrow();
@@ -566,4 +1133,347 @@ mod tests {
         let footer = key_hints_footer(&[]);
         assert_eq!(footer.len(), 2); // Empty line + spans line
     }
+
+    #[test]
+    fn test_list_picker_scrollbar_track_pages() {
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let items: Vec<String> = (0..40).map(|i| format!("Item {i}")).collect();
+        let mut state = ListPickerState::new(items.len());
+        let area = Rect::new(0, 0, 30, 12);
+        let mut registry = ClickRegionRegistry::new();
+
+        // No scrollbar unless enabled
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&items, &state).render_with_registry(area, &mut buf, &mut registry);
+        assert!(registry.is_empty());
+
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&items, &state)
+            .show_scrollbar(true)
+            .render_with_registry(area, &mut buf, &mut registry);
+        let track = registry
+            .regions()
+            .iter()
+            .find(|r| r.data == ScrollbarPart::TrackAfter)
+            .unwrap()
+            .area;
+
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: track.x,
+            row: track.y,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert!(handle_list_picker_scrollbar_mouse(
+            &mut state, &registry, &click
+        ));
+        assert_eq!(state.scroll, 10); // One page of the 10-row items area
+        assert_eq!(state.selected_index, 0);
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_jump_to_char_cycles() {
+        let items = ["apple", "Banana", "avocado", "cherry", "Apricot"];
+        let mut state = ListPickerState::new(items.len());
+
+        assert!(state.jump_to_char('a', &items));
+        assert_eq!(state.selected_index, 0);
+        assert!(state.jump_to_char('a', &items));
+        assert_eq!(state.selected_index, 2);
+        assert!(state.jump_to_char('A', &items));
+        assert_eq!(state.selected_index, 4);
+        // Wraps around
+        assert!(state.jump_to_char('a', &items));
+        assert_eq!(state.selected_index, 0);
+
+        // A different character starts from the top
+        assert!(state.jump_to_char('b', &items));
+        assert_eq!(state.selected_index, 1);
+        assert_eq!(state.last_jump_char, Some('b'));
+        assert_eq!(state.last_jump_index, 1);
+
+        assert!(!state.jump_to_char('z', &items));
+        assert_eq!(state.selected_index, 1);
+    }
+
+    #[test]
+    fn test_handle_key_routes_chars_when_enabled() {
+        let items: Vec<String> = (0..20)
+            .map(|i| format!("{}item", (b'a' + i) as char))
+            .collect();
+        let mut state = ListPickerState::new(items.len());
+
+        // Disabled by default
+        assert!(!handle_list_picker_key(
+            &key(KeyCode::Char('p')),
+            &mut state,
+            &items,
+            5
+        ));
+        assert_eq!(state.selected_index, 0);
+
+        state.jump_to_char = true;
+        assert!(handle_list_picker_key(
+            &key(KeyCode::Char('p')),
+            &mut state,
+            &items,
+            5
+        ));
+        assert_eq!(state.selected_index, 15);
+        assert_eq!(state.scroll, 11);
+
+        // Modified characters are left for the app
+        let ctrl_a = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        assert!(!handle_list_picker_key(&ctrl_a, &mut state, &items, 5));
+
+        assert!(handle_list_picker_key(
+            &key(KeyCode::Home),
+            &mut state,
+            &items,
+            5
+        ));
+        assert_eq!(state.selected_index, 0);
+        assert_eq!(state.scroll, 0);
+        assert!(handle_list_picker_key(
+            &key(KeyCode::PageDown),
+            &mut state,
+            &items,
+            5
+        ));
+        assert_eq!(state.selected_index, 5);
+    }
+
+    fn buffer_rows(buf: &Buffer) -> Vec<String> {
+        let area = buf.area;
+        (area.y..area.bottom())
+            .map(|y| {
+                (area.x..area.right())
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_item_details_rows() {
+        let items = ["Stable", "Nightly"];
+        let details = |item: &&str| match *item {
+            "Stable" => OptionDetails::new().description("Tested").hint("v1"),
+            _ => OptionDetails::new().description("Every night"),
+        };
+        let style = ListPickerStyle::default().bordered(false);
+        let area = Rect::new(0, 0, 20, 4);
+
+        // Single-row items append the description
+        let state = ListPickerState::new(items.len());
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&items, &state)
+            .style(style.clone())
+            .item_details(details)
+            .render(area, &mut buf);
+        let rows = buffer_rows(&buf);
+        assert_eq!(rows[0], "▶ Stable · Tested v1");
+        assert_eq!(rows[1].trim_end(), "  Nightly · Every...");
+
+        // Two-row items put the description on its own line
+        let state = ListPickerState::new(items.len()).with_item_height(2);
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&items, &state)
+            .style(style)
+            .item_details(details)
+            .render(area, &mut buf);
+        let rows = buffer_rows(&buf);
+        assert_eq!(rows[0], "▶ Stable          v1");
+        assert_eq!(rows[1].trim_end(), "  Tested");
+        assert_eq!(rows[2].trim_end(), "  Nightly");
+        assert_eq!(rows[3].trim_end(), "  Every night");
+    }
+
+    #[test]
+    fn test_search_highlights_descriptions() {
+        let items = ["Stable"];
+        let state = ListPickerState::new(items.len()).with_item_height(2);
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&items, &state)
+            .style(ListPickerStyle::default().bordered(false))
+            .item_details(|_| OptionDetails::new().description("Tested builds"))
+            .search("test")
+            .render(area, &mut buf);
+
+        let underlined = |x: u16, y: u16| buf[(x, y)].modifier.contains(Modifier::UNDERLINED);
+        assert!(underlined(2, 1)); // "T" of "Tested"
+        assert!(underlined(5, 1));
+        assert!(!underlined(6, 1));
+        assert!(!underlined(2, 0));
+    }
+
+    #[test]
+    fn test_two_row_items_scroll_by_item() {
+        let mut state = ListPickerState::new(20).with_item_height(2);
+        assert_eq!(state.items_per_viewport(10), 5);
+
+        state.selected_index = 7;
+        state.ensure_visible(10);
+        assert_eq!(state.scroll, 3);
+
+        assert!(handle_list_picker_key(
+            &key(KeyCode::PageDown),
+            &mut state,
+            &[""; 20],
+            10
+        ));
+        assert_eq!(state.selected_index, 12);
+        assert_eq!(state.scroll, 8);
+    }
+
+    #[test]
+    fn test_sync_items_keeps_selection() {
+        let old = [10, 20, 30, 40, 50];
+        let mut state = ListPickerState::new(old.len());
+        state.selected_index = 3;
+        state.scroll = 2;
+
+        // Insertion above keeps the item on the same viewport row
+        let new = [5, 10, 20, 30, 40, 50];
+        state.sync_items(&old, &new);
+        assert_eq!(state.total_items, 6);
+        assert_eq!((state.selected_index, state.scroll), (4, 3));
+
+        // Removing the selected item falls back to the nearest index
+        let old = new;
+        let new = [5, 10, 20, 30, 50];
+        state.sync_items(&old, &new);
+        assert_eq!((state.selected_index, state.scroll), (4, 3));
+        assert_eq!(new[state.selected_index], 50);
+
+        // A complete reorder follows the key
+        let old = new;
+        let new = [50, 30, 20, 10, 5];
+        state.sync_items(&old, &new);
+        assert_eq!(new[state.selected_index], 50);
+        assert_eq!(state.scroll, 0);
+    }
+
+    #[test]
+    fn test_loading_and_failed_status() {
+        let items = ["Stable", "Nightly"];
+        let style = ListPickerStyle::default().bordered(false);
+        let area = Rect::new(0, 0, 24, 3);
+        let mut state = ListPickerState::new(0);
+
+        state.set_loading(0);
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&[] as &[&str], &state)
+            .style(style.clone())
+            .tick(0)
+            .render(area, &mut buf);
+        assert_eq!(buffer_rows(&buf)[1].trim(), "⠋ Loading...");
+        assert!(!handle_list_picker_key(
+            &KeyEvent::from(KeyCode::Down),
+            &mut state,
+            &items,
+            5
+        ));
+
+        state.set_failed("offline");
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&[] as &[&str], &state)
+            .style(style.clone())
+            .render(area, &mut buf);
+        assert_eq!(buffer_rows(&buf)[1].trim(), "✗ offline [r] Retry");
+        assert_eq!(
+            crate::state::handle_loadable_retry_key(
+                &KeyEvent::from(KeyCode::Char('r')),
+                &state.load
+            ),
+            Some(crate::state::LoadableAction::RetryRequested)
+        );
+
+        state.set_loaded(items.len());
+        let mut buf = Buffer::empty(area);
+        ListPicker::new(&items, &state)
+            .style(style)
+            .render(area, &mut buf);
+        assert_eq!(buffer_rows(&buf)[0].trim_end(), "▶ Stable");
+    }
+
+    #[test]
+    fn test_group_header_pinned_while_scrolling() {
+        let items: Vec<String> = ["Ant", "Ape", "Bat", "Bee", "Boa", "Cat"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut state = ListPickerState::new(items.len()).with_groups(vec![
+            ListGroup::new("C", 5),
+            ListGroup::new("A", 0),
+            ListGroup::new("B", 2),
+        ]);
+        assert_eq!(state.group_at(4).map(|g| g.label.as_str()), Some("B"));
+        // One of the three rows holds the header
+        assert_eq!(state.items_per_viewport(3), 2);
+
+        let area = Rect::new(0, 0, 10, 3);
+        let render = |state: &ListPickerState| {
+            let mut buf = Buffer::empty(area);
+            ListPicker::new(&items, state)
+                .style(ListPickerStyle::default().bordered(false))
+                .render(area, &mut buf);
+            buf
+        };
+
+        // Scrolled into the middle of "B", its header stays on top
+        state.select(3);
+        state.scroll = 3;
+        let buf = render(&state);
+        assert_eq!(
+            buffer_rows(&buf),
+            vec!["B         ", "▶ Bee     ", "  Boa     "]
+        );
+        assert_eq!(buf[(0, 0)].fg, Color::Cyan);
+
+        // Moving down past the boundary pins the next group
+        for _ in 0..2 {
+            handle_list_picker_key(&key(KeyCode::Down), &mut state, &items, 3);
+        }
+        assert_eq!(state.scroll, 4);
+        assert_eq!(state.current_group().map(|g| g.label.as_str()), Some("B"));
+        state.scroll = 5;
+        assert_eq!(buffer_rows(&render(&state))[0], "C         ");
+    }
+
+    #[test]
+    fn test_paging_keys_at_boundaries() {
+        let items: Vec<String> = (0..20).map(|i| format!("Item {i}")).collect();
+        let mut state = ListPickerState::new(items.len()).with_page_size(PageSize::Half);
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        // At the top, paging up stays put
+        assert!(handle_list_picker_key(
+            &key(KeyCode::PageUp),
+            &mut state,
+            &items,
+            6
+        ));
+        assert_eq!((state.selected_index, state.scroll), (0, 0));
+
+        // Half pages of a 6-row viewport
+        handle_list_picker_key(&key(KeyCode::PageDown), &mut state, &items, 6);
+        assert_eq!(state.selected_index, 3);
+        handle_list_picker_key(&ctrl('d'), &mut state, &items, 6);
+        assert_eq!(state.selected_index, 6);
+        assert_eq!(state.scroll, 1);
+
+        // At the bottom, paging down stays on the last item
+        handle_list_picker_key(&key(KeyCode::End), &mut state, &items, 6);
+        handle_list_picker_key(&ctrl('d'), &mut state, &items, 6);
+        assert_eq!((state.selected_index, state.scroll), (19, 14));
+        handle_list_picker_key(&ctrl('u'), &mut state, &items, 6);
+        assert_eq!((state.selected_index, state.scroll), (16, 14));
+    }
 }
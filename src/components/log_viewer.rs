@@ -24,17 +24,69 @@
 //!     .title("Application Log")
 //!     .show_line_numbers(true);
 //! ```
+//!
+//! # Columns mode
+//!
+//! Structured logs can be shown as aligned timestamp, level, source, and
+//! message columns instead of flat strings:
+//!
+//! ```rust
+//! use ratatui_interact::components::{LogColumn, LogLevel, LogRecord, LogViewerState};
+//!
+//! let mut state = LogViewerState::empty();
+//! state.set_records(vec![
+//!     LogRecord::new("Listening on :8080")
+//!         .timestamp("12:00:01")
+//!         .level(LogLevel::Info)
+//!         .source("server"),
+//! ]);
+//!
+//! // Or split raw lines with a closure
+//! state.set_content_split(["12:00:02 WARN db Slow query"], |line| {
+//!     let mut parts = line.splitn(4, ' ');
+//!     let timestamp = parts.next().unwrap_or_default();
+//!     let level = parts.next().and_then(LogLevel::parse);
+//!     let source = parts.next().unwrap_or_default();
+//!     let mut record = LogRecord::new(parts.next().unwrap_or_default())
+//!         .timestamp(timestamp)
+//!         .source(source);
+//!     record.level = level;
+//!     record
+//! });
+//! state.toggle_column(LogColumn::Source);
+//! assert!(!state.columns.is_visible(LogColumn::Source));
+//! ```
+
+use std::collections::BTreeSet;
 
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{
-        Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget,
-        Widget,
-    },
+    widgets::{Block, Borders, Paragraph, ScrollbarOrientation, Widget},
 };
+use regex::Regex;
+
+use super::interactive_scrollbar::{InteractiveScrollbar, ScrollbarDragState, ScrollbarPart};
+use super::text_selection::{
+    SelectionRow, TextSelection, TextSelectionAction, handle_text_selection_key,
+    render_selection_overlay,
+};
+use crate::state::{PageMove, PageSize, ScrollMemory};
+use crate::traits::ClickRegionRegistry;
+use crate::utils::MouseCaptureCoordinator;
+use crate::utils::display::{pad_to_width, spaces, truncate_middle, truncate_to_width};
+
+/// Scroll position of a [`LogViewerState`], for [`ScrollMemory`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LogViewerSnapshot {
+    /// Vertical scroll position
+    pub scroll_y: usize,
+    /// Horizontal scroll position
+    pub scroll_x: usize,
+}
 
 /// State for the log viewer widget
 #[derive(Debug, Clone)]
@@ -51,6 +103,38 @@ pub struct LogViewerState {
     pub visible_width: usize,
     /// Search state
     pub search: SearchState,
+    /// Scrollbar thumb drag state
+    pub scrollbar_drag: ScrollbarDragState,
+    /// Maximum number of lines kept (None = unlimited)
+    pub max_lines: Option<usize>,
+    /// Total number of lines dropped from the front since creation
+    pub dropped_lines: u64,
+    /// Keyboard copy mode cursor and selection
+    pub selection: TextSelection,
+    /// Highlight rules, lowest priority first
+    pub highlight_rules: Vec<HighlightRule>,
+    /// Bookmarked line indices
+    pub bookmarks: BTreeSet<usize>,
+    /// Key of the content set with [`set_content_keyed`](Self::set_content_keyed)
+    pub content_key: Option<String>,
+    /// Scroll positions of previously shown content, by key
+    pub scroll_memory: ScrollMemory<String, LogViewerSnapshot>,
+    /// Structured records behind `content` in columns mode, `None` in plain mode
+    pub records: Option<Vec<LogRecord>>,
+    /// Column layout used in columns mode
+    pub columns: LogColumns,
+    /// How far PageUp/PageDown scroll
+    pub page_size: PageSize,
+}
+
+/// Result of a log viewer buffer operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogViewerAction {
+    /// A trim finished, removing this many lines
+    TrimComplete {
+        /// Number of lines removed
+        removed: usize,
+    },
 }
 
 /// Search state for log viewer
@@ -64,6 +148,225 @@ pub struct SearchState {
     pub matches: Vec<usize>,
     /// Current match index
     pub current_match: usize,
+    /// In columns mode, search every column instead of only the message
+    pub all_columns: bool,
+}
+
+/// Highlights every occurrence of a pattern, like `grep --color`
+///
+/// The style is patched over the line's base style. Where rules overlap, the
+/// one added last wins.
+#[derive(Debug, Clone)]
+pub struct HighlightRule {
+    pattern: String,
+    is_regex: bool,
+    matcher: Regex,
+    /// Style for matching text
+    pub style: Style,
+}
+
+impl HighlightRule {
+    /// Create a rule matching `pattern` literally, or as a regex if `is_regex`
+    ///
+    /// Fails if `is_regex` is set and `pattern` isn't a valid regex.
+    pub fn new(
+        pattern: impl Into<String>,
+        style: Style,
+        is_regex: bool,
+    ) -> Result<Self, regex::Error> {
+        let pattern = pattern.into();
+        let matcher = if is_regex {
+            Regex::new(&pattern)?
+        } else {
+            Regex::new(&regex::escape(&pattern))?
+        };
+        Ok(Self {
+            pattern,
+            is_regex,
+            matcher,
+            style,
+        })
+    }
+
+    /// The pattern as given
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Whether the pattern is a regex rather than literal text
+    pub fn is_regex(&self) -> bool {
+        self.is_regex
+    }
+
+    /// Byte ranges of the matches in `line`
+    pub fn find_iter<'l>(&'l self, line: &'l str) -> impl Iterator<Item = (usize, usize)> + 'l {
+        self.matcher.find_iter(line).map(|m| (m.start(), m.end()))
+    }
+}
+
+/// Severity of a [`LogRecord`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Width of the level column
+    pub const WIDTH: usize = 5;
+
+    /// Parse a level name such as `ERROR`, `warning`, or `[info]`, ignoring case
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim().trim_start_matches('[').trim_end_matches(']');
+        match name.to_ascii_lowercase().as_str() {
+            "error" | "err" | "fatal" => Some(Self::Error),
+            "warn" | "warning" => Some(Self::Warn),
+            "info" => Some(Self::Info),
+            "debug" => Some(Self::Debug),
+            "trace" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+
+    /// Upper-case name shown in the level column
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        }
+    }
+}
+
+/// One structured log line, shown as columns
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogRecord {
+    /// Timestamp, displayed as given
+    pub timestamp: String,
+    /// Severity; lines without one leave the level column blank
+    pub level: Option<LogLevel>,
+    /// Logger, module, or host the line came from
+    pub source: String,
+    /// The log message
+    pub message: String,
+}
+
+impl LogRecord {
+    /// Create a record with only a message
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the timestamp
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.timestamp = timestamp.into();
+        self
+    }
+
+    /// Set the level
+    pub fn level(mut self, level: LogLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Set the source
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Every column joined by spaces, as searched with [`SearchState::all_columns`]
+    fn column_text(&self) -> String {
+        let level = self.level.map(LogLevel::label).unwrap_or_default();
+        format!(
+            "{} {} {} {}",
+            self.timestamp, level, self.source, self.message
+        )
+    }
+}
+
+/// A column of the columns mode that can be hidden
+///
+/// The message column is always shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogColumn {
+    Timestamp,
+    Level,
+    Source,
+}
+
+/// Column layout for the columns mode
+///
+/// Columns are fixed-width and separated by a space; the message takes the
+/// rest of the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogColumns {
+    /// Show the timestamp column
+    pub show_timestamp: bool,
+    /// Show the level column
+    pub show_level: bool,
+    /// Show the source column
+    pub show_source: bool,
+    /// Timestamp column width; longer timestamps are truncated
+    pub timestamp_width: usize,
+    /// Source column width; longer sources are truncated in the middle
+    pub source_width: usize,
+    /// Wrap long messages onto continuation rows instead of scrolling them
+    pub wrap_message: bool,
+}
+
+impl Default for LogColumns {
+    fn default() -> Self {
+        Self {
+            show_timestamp: true,
+            show_level: true,
+            show_source: true,
+            timestamp_width: 19,
+            source_width: 12,
+            wrap_message: false,
+        }
+    }
+}
+
+impl LogColumns {
+    /// Check if a column is shown
+    pub fn is_visible(&self, column: LogColumn) -> bool {
+        match column {
+            LogColumn::Timestamp => self.show_timestamp,
+            LogColumn::Level => self.show_level,
+            LogColumn::Source => self.show_source,
+        }
+    }
+
+    /// Show or hide a column
+    pub fn set_visible(&mut self, column: LogColumn, visible: bool) {
+        match column {
+            LogColumn::Timestamp => self.show_timestamp = visible,
+            LogColumn::Level => self.show_level = visible,
+            LogColumn::Source => self.show_source = visible,
+        }
+    }
+
+    /// Width of the shown columns before the message, separators included
+    pub fn prefix_width(&self) -> usize {
+        [
+            (self.show_timestamp, self.timestamp_width),
+            (self.show_level, LogLevel::WIDTH),
+            (self.show_source, self.source_width),
+        ]
+        .iter()
+        .filter(|(shown, _)| *shown)
+        .map(|(_, width)| width + 1)
+        .sum()
+    }
 }
 
 impl LogViewerState {
@@ -76,25 +379,273 @@ impl LogViewerState {
             visible_height: 0,
             visible_width: 0,
             search: SearchState::default(),
+            scrollbar_drag: ScrollbarDragState::default(),
+            max_lines: None,
+            dropped_lines: 0,
+            selection: TextSelection::new(),
+            highlight_rules: Vec::new(),
+            bookmarks: BTreeSet::new(),
+            content_key: None,
+            scroll_memory: ScrollMemory::default(),
+            records: None,
+            columns: LogColumns::default(),
+            page_size: PageSize::default(),
         }
     }
 
+    /// Set how far PageUp/PageDown scroll
+    pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
     /// Create an empty log viewer state
     pub fn empty() -> Self {
         Self::new(Vec::new())
     }
 
+    /// Create an empty log viewer state that keeps at most `max` lines
+    ///
+    /// Once full, appending drops the oldest line.
+    pub fn new_bounded(max: usize) -> Self {
+        Self {
+            max_lines: Some(max),
+            ..Self::empty()
+        }
+    }
+
     /// Set content
+    ///
+    /// Keeps only the last [`max_lines`](Self::max_lines) lines if set.
+    /// Switches back to plain mode from columns mode.
     pub fn set_content(&mut self, content: Vec<String>) {
+        self.records = None;
+        self.replace_lines(content);
+    }
+
+    /// Switch to columns mode showing `records`
+    ///
+    /// Each record's message becomes its line of [`content`](Self::content),
+    /// so copy mode and plain searches work on messages.
+    pub fn set_records(&mut self, records: Vec<LogRecord>) {
+        let content = records.iter().map(|r| r.message.clone()).collect();
+        self.records = Some(records);
+        self.replace_lines(content);
+    }
+
+    /// Switch to columns mode, splitting raw lines into records with `split`
+    pub fn set_content_split<S: AsRef<str>>(
+        &mut self,
+        lines: impl IntoIterator<Item = S>,
+        mut split: impl FnMut(&str) -> LogRecord,
+    ) {
+        let records = lines.into_iter().map(|l| split(l.as_ref())).collect();
+        self.set_records(records);
+    }
+
+    /// Check if lines are shown as columns
+    pub fn is_columns_mode(&self) -> bool {
+        self.records.is_some()
+    }
+
+    /// The record behind a line in columns mode
+    pub fn record(&self, line: usize) -> Option<&LogRecord> {
+        self.records.as_ref()?.get(line)
+    }
+
+    /// Show or hide a column, returning whether it is now shown
+    pub fn toggle_column(&mut self, column: LogColumn) -> bool {
+        let visible = !self.columns.is_visible(column);
+        self.columns.set_visible(column, visible);
+        visible
+    }
+
+    /// Switch searches between the message column and all columns
+    pub fn toggle_search_scope(&mut self) {
+        self.search.all_columns = !self.search.all_columns;
+        self.update_search();
+    }
+
+    /// Replace the lines, resetting the view
+    fn replace_lines(&mut self, content: Vec<String>) {
+        self.content_key = None;
         self.content = content;
         self.scroll_y = 0;
         self.scroll_x = 0;
         self.search.matches.clear();
+        self.selection.exit();
+        self.bookmarks.clear();
+        self.evict_overflow();
+    }
+
+    /// Set content identified by `key`, remembering the scroll position
+    ///
+    /// The scroll position of the content being replaced is saved under its
+    /// own key, if it had one, and the position last saved for `key` is
+    /// restored, clamped to the new content.
+    pub fn set_content_keyed(&mut self, key: impl Into<String>, content: Vec<String>) {
+        let key = key.into();
+        if let Some(previous) = self.content_key.take() {
+            self.scroll_memory.save(previous, self.snapshot());
+        }
+        self.set_content(content);
+        if let Some(snapshot) = self.scroll_memory.restore(&key).copied() {
+            self.apply_snapshot(&snapshot);
+        }
+        self.content_key = Some(key);
+    }
+
+    /// Capture the scroll position
+    pub fn snapshot(&self) -> LogViewerSnapshot {
+        LogViewerSnapshot {
+            scroll_y: self.scroll_y,
+            scroll_x: self.scroll_x,
+        }
+    }
+
+    /// Restore a [`snapshot`](Self::snapshot), clamped to the current content
+    pub fn apply_snapshot(&mut self, snapshot: &LogViewerSnapshot) {
+        self.scroll_y = snapshot.scroll_y.min(self.content.len().saturating_sub(1));
+        self.scroll_x = snapshot.scroll_x;
     }
 
     /// Append a line to content
+    ///
+    /// Drops the oldest lines beyond [`max_lines`](Self::max_lines), keeping
+    /// the viewport on the same lines.
     pub fn append(&mut self, line: String) {
+        if let Some(records) = self.records.as_mut() {
+            records.push(LogRecord::new(line.clone()));
+        }
         self.content.push(line);
+        self.evict_overflow();
+    }
+
+    /// Append a record, switching to columns mode if needed
+    ///
+    /// Lines already shown in plain mode become message-only records.
+    pub fn append_record(&mut self, record: LogRecord) {
+        let records = self
+            .records
+            .get_or_insert_with(|| self.content.iter().cloned().map(LogRecord::new).collect());
+        self.content.push(record.message.clone());
+        records.push(record);
+        self.evict_overflow();
+    }
+
+    /// Remove lines from the front until within `max_lines`
+    fn evict_overflow(&mut self) {
+        let Some(max) = self.max_lines else {
+            return;
+        };
+        let excess = self.content.len().saturating_sub(max);
+        let removed = self.retain_lines(|i| i >= excess);
+        self.dropped_lines += removed as u64;
+    }
+
+    /// Toggle the bookmark on a line
+    ///
+    /// Returns whether the line is now bookmarked.
+    pub fn toggle_bookmark(&mut self, line: usize) -> bool {
+        if self.bookmarks.remove(&line) {
+            false
+        } else {
+            self.bookmarks.insert(line);
+            true
+        }
+    }
+
+    /// Check if a line is bookmarked
+    pub fn is_bookmarked(&self, line: usize) -> bool {
+        self.bookmarks.contains(&line)
+    }
+
+    /// Remove every line further than `keep_context` lines from a bookmark
+    ///
+    /// With no bookmarks this removes everything. The viewport stays on the
+    /// same lines where they survive.
+    pub fn trim_to_bookmarks(&mut self, keep_context: usize) -> LogViewerAction {
+        let mut keep = vec![false; self.content.len()];
+        for &b in &self.bookmarks {
+            let end = b
+                .saturating_add(keep_context)
+                .saturating_add(1)
+                .min(keep.len());
+            if let Some(range) = keep.get_mut(b.saturating_sub(keep_context)..end) {
+                range.fill(true);
+            }
+        }
+        let removed = self.retain_lines(|i| keep[i]);
+        LogViewerAction::TrimComplete { removed }
+    }
+
+    /// Keep only the last `n` lines
+    pub fn trim_to_last_n(&mut self, n: usize) -> LogViewerAction {
+        let excess = self.content.len().saturating_sub(n);
+        let removed = self.retain_lines(|i| i >= excess);
+        LogViewerAction::TrimComplete { removed }
+    }
+
+    /// Keep only the lines `keep` accepts
+    ///
+    /// The scroll position, bookmarks, copy mode selection, and search
+    /// matches move with their lines. Positions on a removed line move to the
+    /// next remaining one. Returns the number of lines removed.
+    fn retain_lines(&mut self, keep: impl Fn(usize) -> bool) -> usize {
+        let mask: Vec<bool> = (0..self.content.len()).map(keep).collect();
+        let removed = mask.iter().filter(|&&k| !k).count();
+        if removed == 0 {
+            return 0;
+        }
+
+        // new_index[i]: number of kept lines before line i
+        let mut new_index = Vec::with_capacity(mask.len() + 1);
+        let mut kept = 0;
+        for &k in &mask {
+            new_index.push(kept);
+            kept += usize::from(k);
+        }
+        new_index.push(kept);
+        let remap = |i: usize| new_index[i.min(mask.len())];
+        let is_kept = |i: usize| mask.get(i).copied().unwrap_or(false);
+
+        let mut lines = mask.iter();
+        self.content.retain(|_| *lines.next().unwrap_or(&false));
+        if let Some(records) = self.records.as_mut() {
+            let mut lines = mask.iter();
+            records.retain(|_| *lines.next().unwrap_or(&false));
+        }
+        self.scroll_y = remap(self.scroll_y);
+
+        self.bookmarks = self
+            .bookmarks
+            .iter()
+            .filter(|&&b| is_kept(b))
+            .map(|&b| remap(b))
+            .collect();
+
+        // Keep the selection on the same text
+        let last = kept.saturating_sub(1);
+        let selection = &mut self.selection;
+        selection.cursor.0 = remap(selection.cursor.0).min(last);
+        if let Some(anchor) = selection.anchor.as_mut() {
+            anchor.0 = remap(anchor.0).min(last);
+        }
+
+        // Shift search matches, forgetting the removed ones
+        let search = &mut self.search;
+        let current = search.current_match.min(search.matches.len());
+        let kept_before_current = search.matches[..current]
+            .iter()
+            .filter(|&&m| is_kept(m))
+            .count();
+        search.matches.retain(|&m| is_kept(m));
+        for m in &mut search.matches {
+            *m = remap(*m);
+        }
+        search.current_match = kept_before_current.min(search.matches.len().saturating_sub(1));
+
+        removed
     }
 
     /// Scroll up by one line
@@ -109,15 +660,35 @@ impl LogViewerState {
         }
     }
 
-    /// Scroll up by one page
+    /// Scroll up by one page, sized by [`page_size`](Self::page_size)
     pub fn page_up(&mut self) {
-        self.scroll_y = self.scroll_y.saturating_sub(self.visible_height);
+        self.apply_page_move(PageMove::PageUp);
     }
 
-    /// Scroll down by one page
+    /// Scroll down by one page, sized by [`page_size`](Self::page_size)
     pub fn page_down(&mut self) {
+        self.apply_page_move(PageMove::PageDown);
+    }
+
+    /// Scroll up by half the viewport
+    pub fn half_page_up(&mut self) {
+        self.apply_page_move(PageMove::HalfPageUp);
+    }
+
+    /// Scroll down by half the viewport
+    pub fn half_page_down(&mut self) {
+        self.apply_page_move(PageMove::HalfPageDown);
+    }
+
+    /// Move the scroll offset, clamped so the last page stays full
+    fn apply_page_move(&mut self, page_move: PageMove) {
         let max_scroll = self.content.len().saturating_sub(self.visible_height);
-        self.scroll_y = (self.scroll_y + self.visible_height).min(max_scroll);
+        self.scroll_y = page_move.apply(
+            self.scroll_y,
+            max_scroll + 1,
+            self.visible_height,
+            self.page_size,
+        );
     }
 
     /// Scroll left
@@ -145,6 +716,40 @@ impl LogViewerState {
         self.scroll_y = line.min(self.content.len().saturating_sub(1));
     }
 
+    /// Enter keyboard copy mode with the cursor on the top visible line
+    pub fn enter_copy_mode(&mut self) {
+        self.selection.enter(self.scroll_y);
+    }
+
+    /// Scroll so the copy mode cursor is visible
+    fn scroll_to_selection(&mut self) {
+        self.selection.scroll_into_view(
+            &mut self.scroll_y,
+            &mut self.scroll_x,
+            self.visible_height,
+            self.visible_width,
+        );
+    }
+
+    /// Add a highlight rule with priority over the existing ones
+    ///
+    /// Fails if `is_regex` is set and `pattern` isn't a valid regex.
+    pub fn add_highlight(
+        &mut self,
+        pattern: impl Into<String>,
+        style: Style,
+        is_regex: bool,
+    ) -> Result<(), regex::Error> {
+        self.highlight_rules
+            .push(HighlightRule::new(pattern, style, is_regex)?);
+        Ok(())
+    }
+
+    /// Remove all highlight rules
+    pub fn clear_highlights(&mut self) {
+        self.highlight_rules.clear();
+    }
+
     /// Start search mode
     pub fn start_search(&mut self) {
         self.search.active = true;
@@ -159,6 +764,9 @@ impl LogViewerState {
     }
 
     /// Update search with new query
+    ///
+    /// In columns mode only messages are searched unless
+    /// [`SearchState::all_columns`] is set.
     pub fn update_search(&mut self) {
         self.search.matches.clear();
         self.search.current_match = 0;
@@ -168,8 +776,13 @@ impl LogViewerState {
         }
 
         let query = self.search.query.to_lowercase();
+        let records = self.records.as_ref().filter(|_| self.search.all_columns);
         for (idx, line) in self.content.iter().enumerate() {
-            if line.to_lowercase().contains(&query) {
+            let found = match records.and_then(|r| r.get(idx)) {
+                Some(record) => record.column_text().to_lowercase().contains(&query),
+                None => line.to_lowercase().contains(&query),
+            };
+            if found {
                 self.search.matches.push(idx);
             }
         }
@@ -222,6 +835,18 @@ pub struct LogViewerStyle {
     pub show_line_numbers: bool,
     /// Line number width
     pub line_number_width: usize,
+    /// Status bar style once lines have been dropped due to `max_lines`
+    pub max_lines_warning_style: Style,
+    /// Copy mode selection highlight
+    pub selection_style: Style,
+    /// Copy mode cursor
+    pub selection_cursor_style: Style,
+    /// Gutter marker style for bookmarked lines
+    pub bookmark_style: Style,
+    /// Timestamp column style in columns mode
+    pub timestamp_style: Style,
+    /// Source column style in columns mode
+    pub source_style: Style,
 }
 
 /// Colors for different log levels
@@ -248,6 +873,19 @@ impl Default for LogLevelColors {
     }
 }
 
+impl LogLevelColors {
+    /// Color for a level
+    pub fn color(&self, level: LogLevel) -> Color {
+        match level {
+            LogLevel::Error => self.error,
+            LogLevel::Warn => self.warn,
+            LogLevel::Info => self.info,
+            LogLevel::Debug => self.debug,
+            LogLevel::Trace => self.trace,
+        }
+    }
+}
+
 impl From<&crate::theme::Theme> for LogLevelColors {
     fn from(theme: &crate::theme::Theme) -> Self {
         let p = &theme.palette;
@@ -275,6 +913,12 @@ impl Default for LogViewerStyle {
             level_colors: LogLevelColors::default(),
             show_line_numbers: true,
             line_number_width: 6,
+            max_lines_warning_style: Style::default().bg(Color::Yellow).fg(Color::Black),
+            selection_style: Style::default().bg(Color::Blue).fg(Color::White),
+            selection_cursor_style: Style::default().bg(Color::White).fg(Color::Black),
+            bookmark_style: Style::default().fg(Color::Cyan),
+            timestamp_style: Style::default().fg(Color::DarkGray),
+            source_style: Style::default().fg(Color::Magenta),
         }
     }
 }
@@ -291,6 +935,12 @@ impl From<&crate::theme::Theme> for LogViewerStyle {
             level_colors: LogLevelColors::from(theme),
             show_line_numbers: true,
             line_number_width: 6,
+            max_lines_warning_style: Style::default().bg(p.warning).fg(p.highlight_fg),
+            selection_style: Style::default().bg(p.highlight_bg).fg(p.highlight_fg),
+            selection_cursor_style: Style::default().bg(p.text).fg(p.bg),
+            bookmark_style: Style::default().fg(p.secondary),
+            timestamp_style: Style::default().fg(p.text_disabled),
+            source_style: Style::default().fg(p.secondary),
         }
     }
 }
@@ -330,6 +980,8 @@ pub struct LogViewer<'a> {
     state: &'a LogViewerState,
     style: LogViewerStyle,
     title: Option<&'a str>,
+    copy_mode: bool,
+    coordinator: Option<&'a mut MouseCaptureCoordinator>,
 }
 
 impl<'a> LogViewer<'a> {
@@ -339,6 +991,8 @@ impl<'a> LogViewer<'a> {
             state,
             style: LogViewerStyle::default(),
             title: None,
+            copy_mode: false,
+            coordinator: None,
         }
     }
 
@@ -365,16 +1019,69 @@ impl<'a> LogViewer<'a> {
         self
     }
 
+    /// Enable or disable copy mode
+    ///
+    /// In copy mode the content area is registered as a passthrough region
+    /// with the [`mouse_coordinator`](Self::mouse_coordinator), so the
+    /// terminal's native text selection works there.
+    pub fn copy_mode(mut self, copy_mode: bool) -> Self {
+        self.copy_mode = copy_mode;
+        self
+    }
+
+    /// Set the mouse capture coordinator used by copy mode
+    pub fn mouse_coordinator(mut self, coordinator: &'a mut MouseCaptureCoordinator) -> Self {
+        self.coordinator = Some(coordinator);
+        self
+    }
+
+    /// Width of the line number gutter, marker included
+    fn gutter_width(&self) -> usize {
+        if self.style.show_line_numbers {
+            self.style.line_number_width + 1
+        } else {
+            0
+        }
+    }
+
+    /// Line number and bookmark marker spans for a line
+    fn gutter_spans(&self, line_idx: usize) -> Vec<Span<'static>> {
+        if !self.style.show_line_numbers {
+            return Vec::new();
+        }
+        let line_num = format!(
+            "{:>width$}",
+            line_idx + 1,
+            width = self.style.line_number_width
+        );
+        let marker = if self.state.is_bookmarked(line_idx) {
+            Span::styled("▸", self.style.bookmark_style)
+        } else {
+            Span::styled(" ", self.style.line_number_style)
+        };
+        vec![Span::styled(line_num, self.style.line_number_style), marker]
+    }
+
+    /// Search highlight for a line, if it is a match
+    fn match_style(&self, line_idx: usize) -> Option<Style> {
+        let search = &self.state.search;
+        if search.matches.get(search.current_match) == Some(&line_idx) {
+            Some(self.style.current_match_style)
+        } else if search.matches.contains(&line_idx) {
+            Some(self.style.match_style)
+        } else {
+            None
+        }
+    }
+
     /// Build content lines
     fn build_lines(&self, inner: Rect) -> Vec<Line<'static>> {
+        if let Some(records) = &self.state.records {
+            return self.build_column_lines(inner, records);
+        }
+
         let visible_height = inner.height as usize;
-        let visible_width = if self.style.show_line_numbers {
-            inner
-                .width
-                .saturating_sub(self.style.line_number_width as u16 + 1) as usize
-        } else {
-            inner.width as usize
-        };
+        let visible_width = (inner.width as usize).saturating_sub(self.gutter_width());
 
         let start_line = self.state.scroll_y;
         let end_line = (start_line + visible_height).min(self.state.content.len());
@@ -383,57 +1090,172 @@ impl<'a> LogViewer<'a> {
 
         for line_idx in start_line..end_line {
             let line = &self.state.content[line_idx];
+            let content_style = self
+                .match_style(line_idx)
+                .unwrap_or_else(|| self.style.style_for_line(line));
 
-            // Check if this line is a search match
-            let is_match = self.state.search.matches.contains(&line_idx);
-            let is_current_match = self
-                .state
-                .search
-                .matches
-                .get(self.state.search.current_match)
-                == Some(&line_idx);
-
-            // Apply horizontal scroll
-            let chars: Vec<char> = line.chars().collect();
-            let display_line: String = chars
-                .iter()
-                .skip(self.state.scroll_x)
-                .take(visible_width)
-                .collect();
-
-            // Determine content style
-            let content_style = if is_current_match {
-                self.style.current_match_style
-            } else if is_match {
-                self.style.match_style
-            } else {
-                self.style.style_for_line(line)
-            };
+            // Line number, with a marker in the gap for bookmarks
+            let mut spans = self.gutter_spans(line_idx);
 
-            let mut spans = Vec::new();
+            // Content, with horizontal scroll applied
+            spans.extend(self.content_spans(
+                line,
+                content_style,
+                self.state.scroll_x,
+                visible_width,
+            ));
 
-            // Line number
-            if self.style.show_line_numbers {
-                let line_num = format!(
-                    "{:>width$} ",
-                    line_idx + 1,
-                    width = self.style.line_number_width
-                );
-                spans.push(Span::styled(line_num, self.style.line_number_style));
-            }
+            lines.push(Line::from(spans));
+        }
+
+        lines
+    }
 
-            // Content
-            spans.push(Span::styled(display_line, content_style));
+    /// Build content lines in columns mode
+    ///
+    /// Horizontal scrolling only moves the message column. Wrapped messages
+    /// continue on rows indented past the other columns.
+    fn build_column_lines(&self, inner: Rect, records: &[LogRecord]) -> Vec<Line<'static>> {
+        let visible_height = inner.height as usize;
+        let columns = &self.state.columns;
+        let indent = self.gutter_width() + columns.prefix_width();
+        let message_width = (inner.width as usize).saturating_sub(indent);
 
-            lines.push(Line::from(spans));
+        let mut lines = Vec::new();
+        for (line_idx, record) in records.iter().enumerate().skip(self.state.scroll_y) {
+            if lines.len() >= visible_height {
+                break;
+            }
+            let mut spans = self.gutter_spans(line_idx);
+
+            if columns.show_timestamp {
+                let width = columns.timestamp_width;
+                let timestamp = truncate_to_width(&record.timestamp, width);
+                spans.push(Span::styled(
+                    pad_to_width(&timestamp, width + 1),
+                    self.style.timestamp_style,
+                ));
+            }
+            if columns.show_level {
+                let (label, style) = match record.level {
+                    Some(level) => (
+                        level.label(),
+                        Style::default().fg(self.style.level_colors.color(level)),
+                    ),
+                    None => ("", self.style.content_style),
+                };
+                spans.push(Span::styled(
+                    pad_to_width(label, LogLevel::WIDTH + 1),
+                    style,
+                ));
+            }
+            if columns.show_source {
+                let width = columns.source_width;
+                let source = truncate_middle(&record.source, width);
+                spans.push(Span::styled(
+                    pad_to_width(&source, width + 1),
+                    self.style.source_style,
+                ));
+            }
+
+            let message = &record.message;
+            let base = self
+                .match_style(line_idx)
+                .unwrap_or(self.style.content_style);
+            if columns.wrap_message && message_width > 0 {
+                let rows = message.chars().count().div_ceil(message_width).max(1);
+                spans.extend(self.content_spans(message, base, 0, message_width));
+                lines.push(Line::from(spans));
+                for row in 1..rows {
+                    let mut spans = vec![Span::raw(spaces(indent))];
+                    spans.extend(self.content_spans(
+                        message,
+                        base,
+                        row * message_width,
+                        message_width,
+                    ));
+                    lines.push(Line::from(spans));
+                }
+            } else {
+                spans.extend(self.content_spans(message, base, self.state.scroll_x, message_width));
+                lines.push(Line::from(spans));
+            }
         }
 
+        lines.truncate(visible_height);
         lines
     }
 }
 
+impl LogViewer<'_> {
+    /// Split `visible_width` chars of a line, starting at char `skip`, into
+    /// spans styled by the highlight rules
+    fn content_spans(
+        &self,
+        line: &str,
+        base: Style,
+        skip: usize,
+        visible_width: usize,
+    ) -> Vec<Span<'static>> {
+        let visible = || line.chars().skip(skip).take(visible_width);
+        if self.state.highlight_rules.is_empty() {
+            return vec![Span::styled(visible().collect::<String>(), base)];
+        }
+
+        // Style per char; later rules overwrite earlier ones
+        let byte_offsets: Vec<usize> = line.char_indices().map(|(b, _)| b).collect();
+        let char_index = |byte: usize| byte_offsets.partition_point(|&b| b < byte);
+        let mut styles = vec![base; byte_offsets.len()];
+        for rule in &self.state.highlight_rules {
+            let style = base.patch(rule.style);
+            for (start, end) in rule.find_iter(line) {
+                styles[char_index(start)..char_index(end)].fill(style);
+            }
+        }
+
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let mut run = String::new();
+        let mut run_style = base;
+        for (c, &style) in visible().zip(styles.iter().skip(skip)) {
+            if style != run_style && !run.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut run), run_style));
+            }
+            run_style = style;
+            run.push(c);
+        }
+        if !run.is_empty() || spans.is_empty() {
+            spans.push(Span::styled(run, run_style));
+        }
+        spans
+    }
+}
+
 impl Widget for LogViewer<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_impl(area, buf, None);
+    }
+}
+
+impl LogViewer<'_> {
+    /// Render and register the scrollbar parts for click handling
+    ///
+    /// Pass the registry to [`handle_log_viewer_scrollbar_mouse`].
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<ScrollbarPart>,
+    ) {
+        registry.clear();
+        self.render_impl(area, buf, Some(registry));
+    }
+
+    fn render_impl(
+        mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: Option<&mut ClickRegionRegistry<ScrollbarPart>>,
+    ) {
         // Layout: content + status bar + optional search bar
         let constraints = if self.state.search.active {
             vec![
@@ -460,21 +1282,63 @@ impl Widget for LogViewer<'_> {
         let inner = block.inner(chunks[0]);
         block.render(chunks[0], buf);
 
+        if self.copy_mode
+            && let Some(coordinator) = self.coordinator.as_deref_mut()
+        {
+            coordinator.register_passthrough(inner);
+        }
+
         // Content
         let lines = self.build_lines(inner);
         let para = Paragraph::new(lines);
         para.render(inner, buf);
 
+        // Copy mode selection, over the message column in columns mode
+        let columns = self.state.is_columns_mode().then_some(&self.state.columns);
+        let prefix = columns.map_or(0, LogColumns::prefix_width);
+        let gutter = ((self.gutter_width() + prefix) as u16).min(inner.width);
+        let message_width = (inner.width - gutter) as usize;
+        let wrap = columns.is_some_and(|c| c.wrap_message) && message_width > 0;
+        let mut rows = Vec::new();
+        let mut y = inner.y;
+        for line in self.state.scroll_y..self.state.content.len() {
+            if y >= inner.bottom() {
+                break;
+            }
+            rows.push(SelectionRow {
+                line,
+                y,
+                x: inner.x + gutter,
+                width: inner.width - gutter,
+            });
+            let height = if wrap {
+                let chars = self.state.content[line].chars().count();
+                chars.div_ceil(message_width).max(1)
+            } else {
+                1
+            };
+            y = y.saturating_add(height as u16);
+        }
+        render_selection_overlay(
+            &self.state.selection,
+            rows,
+            self.state.scroll_x,
+            self.style.selection_style,
+            self.style.selection_cursor_style,
+            buf,
+        );
+
         // Scrollbar
         if self.state.content.len() > inner.height as usize {
-            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
-            let mut scrollbar_state =
-                ScrollbarState::new(self.state.content.len()).position(self.state.scroll_y);
-            scrollbar.render(inner, buf, &mut scrollbar_state);
+            let scrollbar = log_viewer_scrollbar(self.state);
+            match registry {
+                Some(registry) => scrollbar.render_with_registry(inner, buf, registry),
+                None => scrollbar.render(inner, buf),
+            }
         }
 
         // Status bar
-        render_status_bar(self.state, chunks[1], buf);
+        render_status_bar(self.state, &self.style, chunks[1], buf);
 
         // Search bar
         if self.state.search.active && chunks.len() > 2 {
@@ -483,7 +1347,87 @@ impl Widget for LogViewer<'_> {
     }
 }
 
-fn render_status_bar(state: &LogViewerState, area: Rect, buf: &mut Buffer) {
+fn log_viewer_scrollbar(state: &LogViewerState) -> InteractiveScrollbar {
+    InteractiveScrollbar::new(
+        ScrollbarOrientation::VerticalRight,
+        state.content.len(),
+        state.scroll_y,
+    )
+}
+
+/// Handle mouse input on the log viewer scrollbar
+///
+/// Clicking the arrows scrolls one line, clicking the track pages, and
+/// dragging the thumb scrolls proportionally. `registry` must come from
+/// [`LogViewer::render_with_registry`].
+///
+/// Returns `true` if the event was consumed.
+pub fn handle_log_viewer_scrollbar_mouse(
+    state: &mut LogViewerState,
+    registry: &ClickRegionRegistry<ScrollbarPart>,
+    mouse: &MouseEvent,
+) -> bool {
+    let was_dragging = state.scrollbar_drag.is_dragging();
+    let scrollbar = log_viewer_scrollbar(state);
+    match scrollbar.handle_mouse(registry.regions(), &mut state.scrollbar_drag, mouse) {
+        Some(position) => {
+            state.scroll_y = position;
+            true
+        }
+        None => was_dragging || state.scrollbar_drag.is_dragging(),
+    }
+}
+
+/// Handle scrolling keys
+///
+/// Up/Down scroll one line and the [`PageMove`] keys (Home/End,
+/// PageUp/PageDown, Ctrl+U/Ctrl+D) scroll by the amounts described in
+/// [`crate::state::PageMove`], measured against `state.visible_height`.
+/// Returns `false` for other keys, and while search input or copy mode is
+/// active so those handlers see the keys first.
+pub fn handle_log_viewer_scroll_key(key: &KeyEvent, state: &mut LogViewerState) -> bool {
+    if state.search.active || state.selection.active {
+        return false;
+    }
+    if let Some(page_move) = PageMove::from_key(key) {
+        state.apply_page_move(page_move);
+        return true;
+    }
+    match key.code {
+        KeyCode::Up => state.scroll_up(),
+        KeyCode::Down => state.scroll_down(),
+        _ => return false,
+    }
+    true
+}
+
+/// Handle keyboard copy mode keys
+///
+/// `c` enters copy mode. While it is active, the keys from
+/// [`TextSelection::describe_keys`] move the cursor, select, and copy, and the
+/// view scrolls to keep the cursor visible. Other keys return `None` so the
+/// caller's own scrolling and search bindings still apply.
+pub fn handle_log_viewer_copy_key(
+    state: &mut LogViewerState,
+    key: &KeyEvent,
+) -> Option<TextSelectionAction> {
+    if state.search.active {
+        return None;
+    }
+    if !state.selection.active {
+        if key.code == KeyCode::Char('c') && key.modifiers.is_empty() {
+            state.enter_copy_mode();
+            return Some(TextSelectionAction::Entered);
+        }
+        return None;
+    }
+
+    let action = handle_text_selection_key(&mut state.selection, key, &state.content)?;
+    state.scroll_to_selection();
+    Some(action)
+}
+
+fn render_status_bar(state: &LogViewerState, style: &LogViewerStyle, area: Rect, buf: &mut Buffer) {
     let total_lines = state.content.len();
     let current_line = state.scroll_y + 1;
     let percent = if total_lines > 0 {
@@ -510,31 +1454,64 @@ fn render_status_bar(state: &LogViewerState, area: Rect, buf: &mut Buffer) {
         String::new()
     };
 
-    let status = Line::from(vec![
-        Span::styled(" ↑↓", Style::default().fg(Color::Yellow)),
-        Span::raw(": scroll | "),
-        Span::styled("/", Style::default().fg(Color::Yellow)),
-        Span::raw(": search | "),
-        Span::styled("n/N", Style::default().fg(Color::Yellow)),
-        Span::raw(": next/prev | "),
-        Span::styled("g/G", Style::default().fg(Color::Yellow)),
-        Span::raw(": top/bottom | "),
-        Span::raw(format!(
-            "Line {}/{} ({}%){}{}",
-            current_line, total_lines, percent, h_scroll_info, search_info
-        )),
-    ]);
-
-    let para = Paragraph::new(status).style(Style::default().bg(Color::DarkGray));
+    let dropped_info = if state.dropped_lines > 0 {
+        format!(" | Dropped: {}", state.dropped_lines)
+    } else {
+        String::new()
+    };
+
+    let position = Span::raw(format!(
+        "Line {}/{} ({}%){}{}{}",
+        current_line, total_lines, percent, h_scroll_info, search_info, dropped_info
+    ));
+    let status = match state.selection.status_label() {
+        Some(label) => Line::from(vec![
+            Span::styled(format!(" {} ", label), style.selection_style),
+            Span::styled(" v", Style::default().fg(Color::Yellow)),
+            Span::raw(": lines | "),
+            Span::styled("^V", Style::default().fg(Color::Yellow)),
+            Span::raw(": block | "),
+            Span::styled("y", Style::default().fg(Color::Yellow)),
+            Span::raw(": copy | "),
+            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::raw(": exit | "),
+            position,
+        ]),
+        None => Line::from(vec![
+            Span::styled(" ↑↓", Style::default().fg(Color::Yellow)),
+            Span::raw(": scroll | "),
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw(": search | "),
+            Span::styled("n/N", Style::default().fg(Color::Yellow)),
+            Span::raw(": next/prev | "),
+            Span::styled("g/G", Style::default().fg(Color::Yellow)),
+            Span::raw(": top/bottom | "),
+            position,
+        ]),
+    };
+
+    let bar_style = if state.dropped_lines > 0 {
+        style.max_lines_warning_style
+    } else {
+        Style::default().bg(Color::DarkGray)
+    };
+    let para = Paragraph::new(status).style(bar_style);
     para.render(area, buf);
 }
 
 fn render_search_bar(state: &LogViewerState, area: Rect, buf: &mut Buffer) {
-    let search_line = Line::from(vec![
+    let mut spans = vec![
         Span::styled(" Search: ", Style::default().fg(Color::Yellow)),
         Span::raw(state.search.query.clone()),
         Span::styled("▌", Style::default().fg(Color::White)),
-    ]);
+    ];
+    if state.is_columns_mode() && state.search.all_columns {
+        spans.push(Span::styled(
+            "  [all columns]",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    let search_line = Line::from(spans);
 
     let para = Paragraph::new(search_line).style(Style::default().bg(Color::Rgb(40, 40, 60)));
     para.render(area, buf);
@@ -630,6 +1607,164 @@ mod tests {
         assert_eq!(state.scroll_y, 49);
     }
 
+    #[test]
+    fn test_bounded_append_evicts_oldest() {
+        let mut state = LogViewerState::new_bounded(3);
+        for i in 0..5 {
+            state.append(format!("Line {}", i));
+        }
+        assert_eq!(state.content, vec!["Line 2", "Line 3", "Line 4"]);
+        assert_eq!(state.dropped_lines, 2);
+
+        // Unbounded by default
+        let mut state = LogViewerState::empty();
+        for i in 0..5 {
+            state.append(format!("Line {}", i));
+        }
+        assert_eq!(state.content.len(), 5);
+        assert_eq!(state.dropped_lines, 0);
+    }
+
+    #[test]
+    fn test_bounded_eviction_keeps_viewport_and_matches() {
+        let content: Vec<String> = (0..5).map(|i| format!("Line {}", i)).collect();
+        let mut state = LogViewerState::new(content);
+        state.max_lines = Some(5);
+        state.scroll_y = 3;
+        state.search.query = "line".into();
+        state.update_search();
+        state.scroll_y = 3;
+        state.search.current_match = 3;
+
+        state.append("Line 5".into());
+        state.append("Line 6".into());
+
+        // Still looking at "Line 3"
+        assert_eq!(state.scroll_y, 1);
+        assert_eq!(state.content[state.scroll_y], "Line 3");
+        // Matches for evicted lines are gone and the rest shifted
+        assert_eq!(state.search.matches, vec![0, 1, 2]);
+        assert_eq!(state.search.current_match, 1);
+    }
+
+    #[test]
+    fn test_trim_to_bookmarks() {
+        let content: Vec<String> = (0..20).map(|i| format!("Line {}", i)).collect();
+        let mut state = LogViewerState::new(content);
+        state.toggle_bookmark(5);
+        state.toggle_bookmark(15);
+        state.toggle_bookmark(19);
+        state.search.query = "Line 1".into();
+        state.update_search();
+        state.scroll_y = 14;
+        state.search.current_match = state.search.matches.iter().position(|&m| m == 16).unwrap();
+
+        let action = state.trim_to_bookmarks(1);
+
+        assert_eq!(
+            state.content,
+            [
+                "Line 4", "Line 5", "Line 6", "Line 14", "Line 15", "Line 16", "Line 18", "Line 19"
+            ]
+        );
+        assert_eq!(action, LogViewerAction::TrimComplete { removed: 12 });
+        assert_eq!(
+            state.bookmarks.iter().copied().collect::<Vec<_>>(),
+            vec![1, 4, 7]
+        );
+        let marked: Vec<&str> = state
+            .bookmarks
+            .iter()
+            .map(|&b| state.content[b].as_str())
+            .collect();
+        assert_eq!(marked, ["Line 5", "Line 15", "Line 19"]);
+
+        // Viewport stays on "Line 14"
+        assert_eq!(state.content[state.scroll_y], "Line 14");
+        // "Line 1*" matches that survived, current match still "Line 16"
+        assert_eq!(state.search.matches, vec![3, 4, 5, 6, 7]);
+        assert_eq!(
+            state.content[state.search.matches[state.search.current_match]],
+            "Line 16"
+        );
+        // Trimming doesn't count as max_lines eviction
+        assert_eq!(state.dropped_lines, 0);
+    }
+
+    #[test]
+    fn test_trim_scroll_on_removed_line_moves_to_next_kept() {
+        let content: Vec<String> = (0..10).map(|i| format!("Line {}", i)).collect();
+        let mut state = LogViewerState::new(content);
+        state.toggle_bookmark(8);
+        state.scroll_y = 3;
+
+        state.trim_to_bookmarks(0);
+        assert_eq!(state.content, ["Line 8"]);
+        assert_eq!(state.scroll_y, 0);
+
+        // Nothing left to keep without bookmarks
+        state.bookmarks.clear();
+        assert_eq!(
+            state.trim_to_bookmarks(5),
+            LogViewerAction::TrimComplete { removed: 1 }
+        );
+        assert!(state.content.is_empty());
+    }
+
+    #[test]
+    fn test_trim_to_last_n() {
+        let content: Vec<String> = (0..10).map(|i| format!("Line {}", i)).collect();
+        let mut state = LogViewerState::new(content);
+        state.toggle_bookmark(2);
+        state.toggle_bookmark(8);
+        state.scroll_y = 7;
+
+        assert_eq!(
+            state.trim_to_last_n(4),
+            LogViewerAction::TrimComplete { removed: 6 }
+        );
+        assert_eq!(state.content, ["Line 6", "Line 7", "Line 8", "Line 9"]);
+        assert_eq!(state.scroll_y, 1);
+        assert_eq!(state.bookmarks.iter().copied().collect::<Vec<_>>(), vec![2]);
+
+        assert_eq!(
+            state.trim_to_last_n(10),
+            LogViewerAction::TrimComplete { removed: 0 }
+        );
+    }
+
+    #[test]
+    fn test_bookmark_gutter_marker() {
+        let mut state = LogViewerState::new(vec!["a".into(), "b".into()]);
+        assert!(state.toggle_bookmark(1));
+        assert!(state.is_bookmarked(1));
+
+        let area = Rect::new(0, 0, 30, 5);
+        let mut buf = Buffer::empty(area);
+        LogViewer::new(&state).render(area, &mut buf);
+        let row = |y: u16| (0..30).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert!(row(1).contains("     1 a"));
+        assert!(row(2).contains("     2▸b"));
+
+        assert!(!state.toggle_bookmark(1));
+        assert!(!state.is_bookmarked(1));
+    }
+
+    #[test]
+    fn test_dropped_lines_highlight_status_bar() {
+        let mut state = LogViewerState::new_bounded(2);
+        state.set_content((0..4).map(|i| format!("Line {}", i)).collect());
+        assert_eq!(state.content, vec!["Line 2", "Line 3"]);
+        assert_eq!(state.dropped_lines, 2);
+
+        let area = Rect::new(0, 0, 120, 6);
+        let mut buf = Buffer::empty(area);
+        LogViewer::new(&state).render(area, &mut buf);
+        let status: String = (0..120).map(|x| buf[(x, 5)].symbol()).collect();
+        assert!(status.contains("Dropped: 2"));
+        assert_eq!(buf[(0, 5)].bg, Color::Yellow);
+    }
+
     #[test]
     fn test_set_content() {
         let mut state = LogViewerState::new(vec!["Old content".into()]);
@@ -793,6 +1928,60 @@ mod tests {
         // Just verify it doesn't panic
     }
 
+    #[test]
+    fn test_highlight_rules() {
+        let mut state = LogViewerState::new(vec!["[ERROR] 10.0.0.1 failed".into()]);
+        assert!(state.add_highlight("(", Style::default(), true).is_err());
+        // Literal patterns aren't regexes
+        state
+            .add_highlight("[ERROR]", Style::default().bg(Color::Red), false)
+            .unwrap();
+        state
+            .add_highlight(r"\d+(\.\d+){3}", Style::default().bg(Color::Blue), true)
+            .unwrap();
+        // Overlaps the IP address; added last, so it wins
+        state
+            .add_highlight("1 fail", Style::default().bg(Color::Green), false)
+            .unwrap();
+        assert_eq!(state.highlight_rules.len(), 3);
+        assert!(state.highlight_rules[1].is_regex());
+
+        let style = LogViewerStyle {
+            show_line_numbers: false,
+            ..Default::default()
+        };
+        let render = |state: &LogViewerState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 40, 5));
+            LogViewer::new(state)
+                .style(style.clone())
+                .render(buf.area, &mut buf);
+            buf
+        };
+
+        let buf = render(&state);
+        let bg = |x: u16| buf[(x + 1, 1)].bg;
+        assert_eq!(bg(0), Color::Red);
+        assert_eq!(bg(6), Color::Red);
+        assert_eq!(bg(7), Color::Reset);
+        assert_eq!(bg(8), Color::Blue);
+        assert_eq!(bg(14), Color::Blue);
+        assert_eq!(bg(15), Color::Green);
+        assert_eq!(bg(20), Color::Green);
+        assert_eq!(bg(21), Color::Reset);
+        // The base line color shows through
+        assert_eq!(buf[(9, 1)].fg, style.level_colors.error);
+
+        // Highlights follow horizontal scroll
+        state.scroll_x = 8;
+        let buf = render(&state);
+        assert_eq!(buf[(1, 1)].symbol(), "1");
+        assert_eq!(buf[(1, 1)].bg, Color::Blue);
+
+        state.clear_highlights();
+        let buf = render(&state);
+        assert_eq!(buf[(1, 1)].bg, Color::Reset);
+    }
+
     #[test]
     fn test_log_viewer_show_line_numbers() {
         let content = vec!["Line 1".into()];
@@ -802,4 +1991,316 @@ mod tests {
         let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
         viewer.render(Rect::new(0, 0, 40, 10), &mut buf);
     }
+
+    #[test]
+    fn test_log_viewer_copy_mode_registers_passthrough() {
+        let state = LogViewerState::new(vec!["Line 1".into()]);
+        let mut coordinator = MouseCaptureCoordinator::new(true);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+        LogViewer::new(&state)
+            .mouse_coordinator(&mut coordinator)
+            .render(Rect::new(0, 0, 40, 10), &mut buf);
+        assert!(coordinator.regions().is_empty());
+
+        LogViewer::new(&state)
+            .copy_mode(true)
+            .mouse_coordinator(&mut coordinator)
+            .render(Rect::new(0, 0, 40, 10), &mut buf);
+        // Content block minus borders, above the status bar
+        assert_eq!(coordinator.regions(), &[Rect::new(1, 1, 38, 7)]);
+    }
+
+    #[test]
+    fn test_log_viewer_scrollbar_click_and_drag() {
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let content: Vec<String> = (0..100).map(|i| format!("line {i}")).collect();
+        let mut state = LogViewerState::new(content);
+        let area = Rect::new(0, 0, 40, 12);
+        let mut registry = ClickRegionRegistry::new();
+        let mut buf = Buffer::empty(area);
+        LogViewer::new(&state).render_with_registry(area, &mut buf, &mut registry);
+        assert!(!registry.is_empty());
+
+        let event = |kind, row| MouseEvent {
+            kind,
+            column: 38,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        // Down arrow sits just above the bottom border
+        assert!(handle_log_viewer_scrollbar_mouse(
+            &mut state,
+            &registry,
+            &event(MouseEventKind::Down(MouseButton::Left), 9)
+        ));
+        assert_eq!(state.scroll_y, 1);
+
+        // Grab the thumb and drag it down
+        let mut buf = Buffer::empty(area);
+        LogViewer::new(&state).render_with_registry(area, &mut buf, &mut registry);
+        let thumb = registry
+            .regions()
+            .iter()
+            .find(|r| r.data == ScrollbarPart::Thumb)
+            .unwrap()
+            .area;
+        assert!(handle_log_viewer_scrollbar_mouse(
+            &mut state,
+            &registry,
+            &event(MouseEventKind::Down(MouseButton::Left), thumb.y)
+        ));
+        assert!(handle_log_viewer_scrollbar_mouse(
+            &mut state,
+            &registry,
+            &event(MouseEventKind::Drag(MouseButton::Left), thumb.y + 4)
+        ));
+        assert!(state.scroll_y > 1);
+        assert!(handle_log_viewer_scrollbar_mouse(
+            &mut state,
+            &registry,
+            &event(MouseEventKind::Up(MouseButton::Left), thumb.y + 4)
+        ));
+        assert!(!state.scrollbar_drag.is_dragging());
+    }
+
+    #[test]
+    fn test_copy_mode_selection_renders_and_scrolls() {
+        use crate::components::SelectionMode;
+        use crossterm::event::KeyModifiers;
+
+        let content: Vec<String> = (0..20).map(|i| format!("Line {}", i)).collect();
+        let mut state = LogViewerState::new(content);
+        state.visible_height = 4;
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(
+            handle_log_viewer_copy_key(&mut state, &key('c')),
+            Some(TextSelectionAction::Entered)
+        );
+        handle_log_viewer_copy_key(&mut state, &key('v'));
+        for _ in 0..4 {
+            handle_log_viewer_copy_key(&mut state, &key('j'));
+        }
+        assert_eq!(state.selection.mode, SelectionMode::Line);
+        assert_eq!(state.selection.line_range(), (0, 4));
+        // The view followed the cursor
+        assert_eq!(state.scroll_y, 1);
+        // Non copy mode keys fall through
+        assert_eq!(handle_log_viewer_copy_key(&mut state, &key('/')), None);
+
+        let area = Rect::new(0, 0, 60, 7);
+        let mut buf = Buffer::empty(area);
+        LogViewer::new(&state)
+            .show_line_numbers(false)
+            .render(area, &mut buf);
+        let style = LogViewerStyle::default();
+        // Rows show lines 1..=4; lines 1-3 are selected, line 4 has the cursor
+        assert_eq!(buf[(1, 1)].bg, style.selection_style.bg.unwrap());
+        assert_eq!(buf[(30, 3)].bg, style.selection_style.bg.unwrap());
+        assert_eq!(buf[(1, 4)].bg, style.selection_cursor_style.bg.unwrap());
+        assert_eq!(buf[(2, 4)].bg, style.selection_style.bg.unwrap());
+        let status: String = (0..60).map(|x| buf[(x, 6)].symbol()).collect();
+        assert!(status.starts_with(" V-LINE "));
+
+        // Selection stays on the same lines after scrolling
+        state.scroll_down();
+        assert_eq!(state.selection.line_range(), (0, 4));
+    }
+
+    #[test]
+    fn test_set_content_keyed_restores_scroll() {
+        let lines = |n: usize| (0..n).map(|i| format!("line {i}")).collect::<Vec<_>>();
+        let mut state = LogViewerState::empty();
+        state.set_content_keyed("build", lines(100));
+        state.scroll_y = 60;
+        state.scroll_x = 3;
+
+        state.set_content_keyed("test", lines(10));
+        assert_eq!(state.scroll_y, 0);
+        state.scroll_y = 5;
+
+        state.set_content_keyed("build", lines(100));
+        assert_eq!((state.scroll_y, state.scroll_x), (60, 3));
+
+        // The test log was saved on the way out and shrank since
+        state.set_content_keyed("test", lines(3));
+        assert_eq!(state.scroll_y, 2);
+    }
+
+    fn render_rows(viewer: LogViewer, width: u16, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        viewer.render(area, &mut buf);
+        (0..height)
+            .map(|y| (0..width).map(|x| buf[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    fn records() -> Vec<LogRecord> {
+        vec![
+            LogRecord::new("Listening on :8080")
+                .timestamp("12:00:01")
+                .level(LogLevel::Info)
+                .source("server"),
+            LogRecord::new("Slow query on users")
+                .timestamp("12:00:02")
+                .level(LogLevel::Warn)
+                .source("database.connection.pool"),
+        ]
+    }
+
+    #[test]
+    fn test_log_level_parse() {
+        assert_eq!(LogLevel::parse("ERROR"), Some(LogLevel::Error));
+        assert_eq!(LogLevel::parse("[warning]"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::parse(" Info "), Some(LogLevel::Info));
+        assert_eq!(LogLevel::parse("verbose"), None);
+        assert_eq!(LogLevel::Debug.label(), "DEBUG");
+    }
+
+    #[test]
+    fn test_columns_mode_render() {
+        let mut state = LogViewerState::empty();
+        state.set_records(records());
+        state.columns.timestamp_width = 8;
+        state.columns.source_width = 10;
+        assert!(state.is_columns_mode());
+        assert_eq!(state.content[1], "Slow query on users");
+
+        let style = LogViewerStyle::default();
+        let viewer = LogViewer::new(&state).show_line_numbers(false);
+        let rows = render_rows(viewer, 60, 5);
+        assert!(rows[1].starts_with("│12:00:01 INFO  server     Listening on :8080  "));
+        assert!(rows[2].starts_with("│12:00:02 WARN  data...ool Slow query"));
+
+        // The level column is colored by level, the message is not
+        let area = Rect::new(0, 0, 60, 5);
+        let mut buf = Buffer::empty(area);
+        LogViewer::new(&state)
+            .show_line_numbers(false)
+            .render(area, &mut buf);
+        assert_eq!(buf[(10, 2)].fg, style.level_colors.warn);
+        assert_eq!(buf[(28, 2)].fg, Color::White);
+
+        // Hidden columns give their space to the message
+        assert!(!state.toggle_column(LogColumn::Source));
+        assert!(!state.toggle_column(LogColumn::Timestamp));
+        let rows = render_rows(LogViewer::new(&state).show_line_numbers(false), 60, 5);
+        assert!(rows[2].starts_with("│WARN  Slow query"));
+    }
+
+    #[test]
+    fn test_columns_split_and_append() {
+        let mut state = LogViewerState::new_bounded(2);
+        state.set_content_split(["09:00 ERROR api Timeout", "not structured"], |line| {
+            let parts: Vec<&str> = line.splitn(4, ' ').collect();
+            match parts.as_slice() {
+                [ts, level, source, message] => {
+                    let mut record = LogRecord::new(*message).timestamp(*ts).source(*source);
+                    record.level = LogLevel::parse(level);
+                    record
+                }
+                _ => LogRecord::new(line),
+            }
+        });
+        assert_eq!(state.record(0).unwrap().level, Some(LogLevel::Error));
+        assert_eq!(state.record(1).unwrap().message, "not structured");
+
+        // Appending keeps records and content in step, evicting both
+        state.append_record(LogRecord::new("Retrying").level(LogLevel::Info));
+        state.append("plain line".into());
+        assert_eq!(state.content, vec!["Retrying", "plain line"]);
+        assert_eq!(state.record(1), Some(&LogRecord::new("plain line")));
+
+        // Plain content leaves columns mode
+        state.set_content(vec!["a".into()]);
+        assert!(!state.is_columns_mode());
+    }
+
+    #[test]
+    fn test_columns_search_scope() {
+        let mut state = LogViewerState::empty();
+        state.set_records(records());
+        state.search.query = "database".into();
+        state.update_search();
+        assert!(state.search.matches.is_empty());
+
+        state.toggle_search_scope();
+        assert_eq!(state.search.matches, vec![1]);
+
+        state.search.query = "warn".into();
+        state.update_search();
+        assert_eq!(state.search.matches, vec![1]);
+    }
+
+    #[test]
+    fn test_columns_scroll_and_wrap_message_only() {
+        let mut state = LogViewerState::empty();
+        state.set_records(records());
+        state.columns.show_source = false;
+        state.scroll_x = 5;
+        let rows = render_rows(LogViewer::new(&state).show_line_numbers(false), 40, 6);
+        assert_eq!(rows[1], "│12:00:01            INFO  ning on :808│");
+
+        state.columns.show_timestamp = false;
+        state.columns.wrap_message = true;
+        let rows = render_rows(LogViewer::new(&state).show_line_numbers(false), 20, 8);
+        // 18 columns inside the border, 12 of them for the message
+        assert_eq!(rows[1], "│INFO  Listening on│");
+        assert_eq!(rows[2], "│       :8080      │");
+        assert_eq!(rows[3], "│WARN  Slow query o│");
+        assert_eq!(rows[4], "│      n users     │");
+    }
+
+    #[test]
+    fn test_scroll_keys_at_boundaries() {
+        use crossterm::event::KeyModifiers;
+        let lines = (0..50).map(|i| format!("line {i}")).collect();
+        let mut state = LogViewerState::new(lines).with_page_size(PageSize::Fixed(15));
+        state.visible_height = 10;
+        let key = KeyEvent::from;
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        assert!(handle_log_viewer_scroll_key(
+            &key(KeyCode::PageUp),
+            &mut state
+        ));
+        assert_eq!(state.scroll_y, 0);
+        assert!(handle_log_viewer_scroll_key(
+            &key(KeyCode::PageDown),
+            &mut state
+        ));
+        assert_eq!(state.scroll_y, 15);
+        assert!(handle_log_viewer_scroll_key(&ctrl('d'), &mut state));
+        assert_eq!(state.scroll_y, 20);
+        assert!(handle_log_viewer_scroll_key(&key(KeyCode::End), &mut state));
+        assert_eq!(state.scroll_y, 40);
+        // The last page stays full
+        assert!(handle_log_viewer_scroll_key(
+            &key(KeyCode::PageDown),
+            &mut state
+        ));
+        assert_eq!(state.scroll_y, 40);
+        assert!(handle_log_viewer_scroll_key(&ctrl('u'), &mut state));
+        assert_eq!(state.scroll_y, 35);
+        assert!(handle_log_viewer_scroll_key(
+            &key(KeyCode::Home),
+            &mut state
+        ));
+        assert_eq!(state.scroll_y, 0);
+        assert!(!handle_log_viewer_scroll_key(
+            &key(KeyCode::Char('x')),
+            &mut state
+        ));
+
+        // Copy mode gets the keys first
+        state.enter_copy_mode();
+        assert!(!handle_log_viewer_scroll_key(
+            &key(KeyCode::End),
+            &mut state
+        ));
+    }
 }
@@ -24,6 +24,8 @@
 //!     .style(style);
 //! ```
 
+use std::sync::Arc;
+
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -54,8 +56,11 @@ pub enum MarqueeMode {
     Static,
 }
 
+/// Callback invoked when a marquee completes a full cycle
+pub type MarqueeCycleCallback = Arc<dyn Fn() + Send + Sync>;
+
 /// State for tracking marquee animation
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct MarqueeState {
     /// Current scroll offset (in display columns)
     pub offset: usize,
@@ -63,6 +68,25 @@ pub struct MarqueeState {
     pub direction: ScrollDir,
     /// Counter for edge pause
     pub paused_ticks: usize,
+    /// Number of full cycles completed (wrap-arounds or full bounces)
+    pub cycle_count: u64,
+    /// Stop animating after this many cycles (None = run forever)
+    pub max_cycles: Option<u64>,
+    /// Callback invoked each time a cycle completes
+    pub on_cycle_complete: Option<MarqueeCycleCallback>,
+}
+
+impl std::fmt::Debug for MarqueeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarqueeState")
+            .field("offset", &self.offset)
+            .field("direction", &self.direction)
+            .field("paused_ticks", &self.paused_ticks)
+            .field("cycle_count", &self.cycle_count)
+            .field("max_cycles", &self.max_cycles)
+            .field("on_cycle_complete", &self.on_cycle_complete.is_some())
+            .finish()
+    }
 }
 
 impl MarqueeState {
@@ -71,11 +95,38 @@ impl MarqueeState {
         Self::default()
     }
 
+    /// Set a callback invoked each time the content completes a full cycle
+    pub fn with_on_cycle(mut self, cb: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_cycle_complete = Some(Arc::new(cb));
+        self
+    }
+
+    /// Stop the animation after `n` completed cycles
+    pub fn stop_after_cycles(&mut self, n: u64) {
+        self.max_cycles = Some(n);
+    }
+
+    /// Check if the cycle limit has been reached
+    pub fn is_finished(&self) -> bool {
+        self.max_cycles.is_some_and(|max| self.cycle_count >= max)
+    }
+
     /// Reset the state to initial position
+    ///
+    /// Clears the cycle count but keeps the callback and cycle limit.
     pub fn reset(&mut self) {
         self.offset = 0;
         self.direction = ScrollDir::Left;
         self.paused_ticks = 0;
+        self.cycle_count = 0;
+    }
+
+    /// Record a completed cycle and notify the callback
+    fn complete_cycle(&mut self) {
+        self.cycle_count += 1;
+        if let Some(cb) = &self.on_cycle_complete {
+            cb();
+        }
     }
 
     /// Advance the animation by one tick
@@ -91,6 +142,11 @@ impl MarqueeState {
             return;
         }
 
+        // Stop once the cycle limit is reached
+        if self.is_finished() {
+            return;
+        }
+
         // Handle edge pause
         if self.paused_ticks > 0 {
             self.paused_ticks -= 1;
@@ -103,7 +159,11 @@ impl MarqueeState {
                 // "text + separator + text"
                 // and scroll through it, wrapping around
                 let total_width = text_width + style.separator.width();
-                self.offset = (self.offset + style.scroll_speed) % total_width;
+                let next = self.offset + style.scroll_speed;
+                self.offset = next % total_width;
+                if next >= total_width {
+                    self.complete_cycle();
+                }
             }
             MarqueeMode::Bounce => {
                 // Calculate the maximum offset (how far we can scroll)
@@ -125,6 +185,8 @@ impl MarqueeState {
                             self.offset = 0;
                             self.direction = ScrollDir::Left;
                             self.paused_ticks = style.pause_at_edge;
+                            // Back at the start: one full bounce completed
+                            self.complete_cycle();
                         } else {
                             self.offset = self.offset.saturating_sub(style.scroll_speed);
                         }
@@ -612,6 +674,90 @@ mod tests {
         assert_eq!(state.offset, 0); // Moved back (saturating)
     }
 
+    #[test]
+    fn test_marquee_cycle_count_continuous() {
+        let mut state = MarqueeState::new();
+        let style = MarqueeStyle::default()
+            .mode(MarqueeMode::Continuous)
+            .separator("   ")
+            .scroll_speed(4);
+
+        // Text width 17 + separator 3 = cycle of 20 columns
+        for _ in 0..4 {
+            state.tick(17, 10, &style);
+        }
+        assert_eq!(state.offset, 16);
+        assert_eq!(state.cycle_count, 0);
+
+        state.tick(17, 10, &style);
+        assert_eq!(state.offset, 0);
+        assert_eq!(state.cycle_count, 1);
+
+        for _ in 0..5 {
+            state.tick(17, 10, &style);
+        }
+        assert_eq!(state.cycle_count, 2);
+    }
+
+    #[test]
+    fn test_marquee_cycle_count_bounce() {
+        let mut state = MarqueeState::new();
+        let style = MarqueeStyle::default()
+            .mode(MarqueeMode::Bounce)
+            .scroll_speed(5)
+            .pause_at_edge(0);
+
+        // max_offset = 10: two ticks out, two ticks back
+        state.tick(20, 10, &style);
+        state.tick(20, 10, &style);
+        assert_eq!(state.cycle_count, 0);
+
+        state.tick(20, 10, &style);
+        assert_eq!(state.cycle_count, 0);
+        state.tick(20, 10, &style);
+        assert_eq!(state.offset, 0);
+        assert_eq!(state.cycle_count, 1);
+    }
+
+    #[test]
+    fn test_marquee_on_cycle_callback() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let mut state = MarqueeState::new().with_on_cycle(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let style = MarqueeStyle::default().separator("").scroll_speed(5);
+
+        // Text width 10, cycle of 10 columns: wraps every 2 ticks
+        for _ in 0..6 {
+            state.tick(10, 5, &style);
+        }
+        assert_eq!(state.cycle_count, 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_marquee_stop_after_cycles() {
+        let mut state = MarqueeState::new();
+        state.stop_after_cycles(1);
+        let style = MarqueeStyle::default().separator("").scroll_speed(5);
+
+        state.tick(10, 5, &style);
+        state.tick(10, 5, &style);
+        assert_eq!(state.cycle_count, 1);
+        assert!(state.is_finished());
+
+        // Further ticks are no-ops
+        state.tick(10, 5, &style);
+        assert_eq!(state.offset, 0);
+        assert_eq!(state.cycle_count, 1);
+
+        state.reset();
+        assert!(!state.is_finished());
+    }
+
     #[test]
     fn test_marquee_style_default() {
         let style = MarqueeStyle::default();
@@ -41,23 +41,31 @@
 use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
+    symbols::border,
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
-use crate::traits::ClickRegion;
+use crate::capabilities::{ASCII_BORDER, Capabilities};
+use crate::events::CloseRequest;
+use crate::state::{OverlayLayer, OverlayManager};
+use crate::traits::{ClickRegion, MenuAction, PopupOwner};
+use crate::utils::display::{display_width, spaces};
 
 /// Actions a menu bar can emit.
+///
+/// `A` is the item action type; see [`MenuAction`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum MenuBarAction {
+pub enum TypedMenuBarAction<A> {
     /// A menu was opened (menu index).
     MenuOpen(usize),
     /// The active menu was closed.
     MenuClose,
-    /// An action item was selected (item ID).
-    ItemSelect(String),
+    /// An action item was selected (item action).
+    ItemSelect(A),
     /// Highlight changed (menu index, optional item index within dropdown).
     HighlightChange(usize, Option<usize>),
     /// A submenu was opened (parent menu index, parent item index).
@@ -66,19 +74,36 @@ pub enum MenuBarAction {
     SubmenuClose,
 }
 
+/// Menu bar actions for items with string IDs.
+pub type MenuBarAction = TypedMenuBarAction<String>;
+
+/// For the result of handling Esc: closing a dropdown or submenu consumes the key.
+impl<A> From<Option<TypedMenuBarAction<A>>> for CloseRequest {
+    fn from(action: Option<TypedMenuBarAction<A>>) -> Self {
+        match action {
+            Some(_) => CloseRequest::Consumed,
+            None => CloseRequest::Propagate,
+        }
+    }
+}
+
 /// A single item in a menu dropdown.
+///
+/// `A` is the action the item triggers; see [`MenuAction`].
 #[derive(Debug, Clone)]
-pub enum MenuBarItem {
+pub enum TypedMenuBarItem<A> {
     /// A clickable action item.
     Action {
-        /// Unique identifier for this action.
-        id: String,
+        /// Action emitted when this item is selected.
+        id: A,
         /// Display label.
         label: String,
         /// Optional keyboard shortcut display.
         shortcut: Option<String>,
         /// Whether the item is enabled.
         enabled: bool,
+        /// One-line description shown in a status line while highlighted.
+        hint: Option<String>,
     },
     /// A visual separator line.
     Separator,
@@ -87,20 +112,33 @@ pub enum MenuBarItem {
         /// Display label.
         label: String,
         /// Child menu items.
-        items: Vec<MenuBarItem>,
+        items: Vec<TypedMenuBarItem<A>>,
         /// Whether the submenu is enabled.
         enabled: bool,
+        /// One-line description shown in a status line while highlighted.
+        hint: Option<String>,
     },
 }
 
+/// A menu bar item with a string ID.
+pub type MenuBarItem = TypedMenuBarItem<String>;
+
 impl MenuBarItem {
-    /// Create a new action item.
+    /// Create a new action item with a string ID.
     pub fn action(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::from_action(id.into(), label)
+    }
+}
+
+impl<A> TypedMenuBarItem<A> {
+    /// Create a new action item that emits `action` when selected.
+    pub fn from_action(action: A, label: impl Into<String>) -> Self {
         Self::Action {
-            id: id.into(),
+            id: action,
             label: label.into(),
             shortcut: None,
             enabled: true,
+            hint: None,
         }
     }
 
@@ -110,11 +148,12 @@ impl MenuBarItem {
     }
 
     /// Create a submenu.
-    pub fn submenu(label: impl Into<String>, items: Vec<MenuBarItem>) -> Self {
+    pub fn submenu(label: impl Into<String>, items: Vec<TypedMenuBarItem<A>>) -> Self {
         Self::Submenu {
             label: label.into(),
             items,
             enabled: true,
+            hint: None,
         }
     }
 
@@ -126,6 +165,16 @@ impl MenuBarItem {
         self
     }
 
+    /// Add a status-line hint to this item.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        match &mut self {
+            Self::Action { hint: h, .. } => *h = Some(hint.into()),
+            Self::Submenu { hint: h, .. } => *h = Some(hint.into()),
+            Self::Separator => {}
+        }
+        self
+    }
+
     /// Set whether this item is enabled.
     pub fn enabled(mut self, enabled: bool) -> Self {
         match &mut self {
@@ -150,8 +199,8 @@ impl MenuBarItem {
         matches!(self, Self::Submenu { .. })
     }
 
-    /// Get the ID if this is an action item.
-    pub fn id(&self) -> Option<&str> {
+    /// Get the action if this is an action item.
+    pub fn get_action(&self) -> Option<&A> {
         if let Self::Action { id, .. } = self {
             Some(id)
         } else {
@@ -177,6 +226,15 @@ impl MenuBarItem {
         }
     }
 
+    /// Get the status-line hint for this item.
+    pub fn get_hint(&self) -> Option<&str> {
+        match self {
+            Self::Action { hint, .. } => hint.as_deref(),
+            Self::Submenu { hint, .. } => hint.as_deref(),
+            Self::Separator => None,
+        }
+    }
+
     /// Check if this item is enabled.
     pub fn is_enabled(&self) -> bool {
         match self {
@@ -187,7 +245,7 @@ impl MenuBarItem {
     }
 
     /// Get submenu items if this is a submenu.
-    pub fn submenu_items(&self) -> Option<&[MenuBarItem]> {
+    pub fn submenu_items(&self) -> Option<&[TypedMenuBarItem<A>]> {
         if let Self::Submenu { items, .. } = self {
             Some(items)
         } else {
@@ -196,18 +254,30 @@ impl MenuBarItem {
     }
 }
 
+impl<A: MenuAction> TypedMenuBarItem<A> {
+    /// Get the action ID if this is an action item.
+    pub fn id(&self) -> Option<&str> {
+        self.get_action().map(MenuAction::id)
+    }
+}
+
 /// A top-level menu in the menu bar.
+///
+/// `A` is the action its items trigger; see [`MenuAction`].
 #[derive(Debug, Clone)]
-pub struct Menu {
+pub struct TypedMenu<A> {
     /// Display label for the menu.
     pub label: String,
     /// Items in this menu's dropdown.
-    pub items: Vec<MenuBarItem>,
+    pub items: Vec<TypedMenuBarItem<A>>,
     /// Whether this menu is enabled.
     pub enabled: bool,
 }
 
-impl Menu {
+/// A menu whose items have string IDs.
+pub type Menu = TypedMenu<String>;
+
+impl<A> TypedMenu<A> {
     /// Create a new menu with a label.
     pub fn new(label: impl Into<String>) -> Self {
         Self {
@@ -218,7 +288,7 @@ impl Menu {
     }
 
     /// Set the items for this menu.
-    pub fn items(mut self, items: Vec<MenuBarItem>) -> Self {
+    pub fn items(mut self, items: Vec<TypedMenuBarItem<A>>) -> Self {
         self.items = items;
         self
     }
@@ -230,6 +300,18 @@ impl Menu {
     }
 }
 
+/// Snapshot of a menu bar's focus, from [`MenuBarState::serialize`].
+///
+/// Enable the `serde` feature to persist snapshots across sessions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MenuBarStateSave {
+    /// Index of the active menu.
+    pub active_menu: usize,
+    /// Whether the menu bar had focus.
+    pub focused: bool,
+}
+
 /// State for a menu bar.
 #[derive(Debug, Clone)]
 pub struct MenuBarState {
@@ -257,6 +339,14 @@ impl Default for MenuBarState {
     }
 }
 
+impl PopupOwner for MenuBarState {
+    fn on_focus_lost(&mut self) -> bool {
+        let was_open = self.is_open;
+        self.close_menu();
+        was_open
+    }
+}
+
 impl MenuBarState {
     /// Create a new menu bar state.
     pub fn new() -> Self {
@@ -272,6 +362,26 @@ impl MenuBarState {
         }
     }
 
+    /// Take a snapshot of the active menu and focus.
+    pub fn serialize(&self) -> MenuBarStateSave {
+        MenuBarStateSave {
+            active_menu: self.active_menu,
+            focused: self.focused,
+        }
+    }
+
+    /// Restore the active menu and focus from a snapshot, closing any open menu.
+    pub fn restore(&mut self, save: &MenuBarStateSave) {
+        self.close_menu();
+        self.active_menu = save.active_menu;
+        self.focused = save.focused;
+    }
+
+    /// Check whether the active menu and focus match a snapshot.
+    pub fn eq_snapshot(&self, other: &MenuBarStateSave) -> bool {
+        self.serialize() == *other
+    }
+
     /// Open the menu at the given index.
     pub fn open_menu(&mut self, index: usize) {
         self.is_open = true;
@@ -329,7 +439,7 @@ impl MenuBarState {
     }
 
     /// Move highlight to the next item in the dropdown.
-    pub fn next_item(&mut self, items: &[MenuBarItem]) {
+    pub fn next_item<A>(&mut self, items: &[TypedMenuBarItem<A>]) {
         if items.is_empty() {
             return;
         }
@@ -355,7 +465,7 @@ impl MenuBarState {
     }
 
     /// Move highlight to the previous item in the dropdown.
-    pub fn prev_item(&mut self, items: &[MenuBarItem]) {
+    pub fn prev_item<A>(&mut self, items: &[TypedMenuBarItem<A>]) {
         if items.is_empty() {
             return;
         }
@@ -381,7 +491,7 @@ impl MenuBarState {
     }
 
     /// Move to first selectable item.
-    pub fn highlight_first(&mut self, items: &[MenuBarItem]) {
+    pub fn highlight_first<A>(&mut self, items: &[TypedMenuBarItem<A>]) {
         for (i, item) in items.iter().enumerate() {
             if item.is_selectable() {
                 self.highlighted_item = Some(i);
@@ -392,7 +502,7 @@ impl MenuBarState {
     }
 
     /// Move to last selectable item.
-    pub fn highlight_last(&mut self, items: &[MenuBarItem]) {
+    pub fn highlight_last<A>(&mut self, items: &[TypedMenuBarItem<A>]) {
         for (i, item) in items.iter().enumerate().rev() {
             if item.is_selectable() {
                 self.highlighted_item = Some(i);
@@ -401,6 +511,48 @@ impl MenuBarState {
         }
     }
 
+    /// Move the highlight down by `page_size` items, scrolling by a page.
+    ///
+    /// Stops at the last selectable item.
+    pub fn page_down_items<A>(&mut self, items: &[TypedMenuBarItem<A>], page_size: usize) {
+        let Some(last) = items.len().checked_sub(1) else {
+            return;
+        };
+        let current = self.highlighted_item.unwrap_or(0);
+        let target = (current + page_size).min(last);
+        let found = (target..=last)
+            .find(|&i| items[i].is_selectable())
+            .or_else(|| (current..target).rev().find(|&i| items[i].is_selectable()));
+        if let Some(idx) = found {
+            self.highlighted_item = Some(idx);
+        }
+
+        let max_scroll = items.len().saturating_sub(page_size);
+        self.scroll_offset = (self.scroll_offset as usize + page_size).min(max_scroll) as u16;
+        self.ensure_visible(page_size);
+    }
+
+    /// Move the highlight up by `page_size` items, scrolling by a page.
+    ///
+    /// Stops at the first selectable item.
+    pub fn page_up_items<A>(&mut self, items: &[TypedMenuBarItem<A>], page_size: usize) {
+        if items.is_empty() {
+            return;
+        }
+        let current = self.highlighted_item.unwrap_or(0).min(items.len() - 1);
+        let target = current.saturating_sub(page_size);
+        let found = (0..=target)
+            .rev()
+            .find(|&i| items[i].is_selectable())
+            .or_else(|| (target + 1..=current).find(|&i| items[i].is_selectable()));
+        if let Some(idx) = found {
+            self.highlighted_item = Some(idx);
+        }
+
+        self.scroll_offset = (self.scroll_offset as usize).saturating_sub(page_size) as u16;
+        self.ensure_visible(page_size);
+    }
+
     /// Select an item by index.
     pub fn select_item(&mut self, index: usize) {
         self.highlighted_item = Some(index);
@@ -427,8 +579,27 @@ impl MenuBarState {
         self.active_submenu.is_some()
     }
 
+    /// Hint of the highlighted item, for display in a status line.
+    ///
+    /// Looks into the open submenu when one of its items is highlighted.
+    /// Returns `None` when the menu is closed, nothing is highlighted, or
+    /// the highlighted item has no hint.
+    pub fn current_hint<'m, A>(&self, menus: &'m [TypedMenu<A>]) -> Option<&'m str> {
+        if !self.is_open {
+            return None;
+        }
+        let index = self.highlighted_item?;
+        let item = menus.get(self.active_menu)?.items.get(index)?;
+        match (self.active_submenu, self.submenu_highlighted) {
+            (Some(submenu), Some(sub_index)) if submenu == index => {
+                item.submenu_items()?.get(sub_index)?.get_hint()
+            }
+            _ => item.get_hint(),
+        }
+    }
+
     /// Move to next item in submenu.
-    pub fn next_submenu_item(&mut self, items: &[MenuBarItem]) {
+    pub fn next_submenu_item<A>(&mut self, items: &[TypedMenuBarItem<A>]) {
         if items.is_empty() {
             return;
         }
@@ -452,7 +623,7 @@ impl MenuBarState {
     }
 
     /// Move to previous item in submenu.
-    pub fn prev_submenu_item(&mut self, items: &[MenuBarItem]) {
+    pub fn prev_submenu_item<A>(&mut self, items: &[TypedMenuBarItem<A>]) {
         if items.is_empty() {
             return;
         }
@@ -489,6 +660,18 @@ impl MenuBarState {
             }
         }
     }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_menu_bar_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("←/→", "Previous/next menu"),
+            ("↑/↓", "Move highlight"),
+            ("Enter/Space", "Open menu or select item"),
+            ("Home/End", "First/last item"),
+            ("PgUp/PgDn", "Scroll a page of items"),
+            ("Esc", "Close menu"),
+        ]
+    }
 }
 
 /// Style configuration for menu bar.
@@ -530,6 +713,8 @@ pub struct MenuBarStyle {
     pub submenu_indicator: &'static str,
     /// Separator character.
     pub separator_char: char,
+    /// Dropdown border characters.
+    pub border_set: border::Set<'static>,
 }
 
 impl Default for MenuBarStyle {
@@ -553,6 +738,7 @@ impl Default for MenuBarStyle {
             dropdown_padding: 1,
             submenu_indicator: "▶",
             separator_char: '─',
+            border_set: border::PLAIN,
         }
     }
 }
@@ -579,6 +765,7 @@ impl From<&crate::theme::Theme> for MenuBarStyle {
             dropdown_padding: 1,
             submenu_indicator: "▶",
             separator_char: '─',
+            border_set: border::PLAIN,
         }
     }
 }
@@ -622,6 +809,42 @@ impl MenuBarStyle {
         }
     }
 
+    /// Create an ASCII-only style: `>` submenu indicator, `-` separators,
+    /// and `+-|` dropdown borders.
+    pub fn ascii() -> Self {
+        Self {
+            submenu_indicator: ">",
+            separator_char: '-',
+            border_set: ASCII_BORDER,
+            ..Default::default()
+        }
+    }
+
+    /// The default style adapted to the terminal: [`ascii`](Self::ascii)
+    /// glyphs without Unicode, palette colors without truecolor.
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        let style = if caps.unicode {
+            Self::default()
+        } else {
+            Self::ascii()
+        };
+        Self {
+            bar_bg: caps.color(style.bar_bg),
+            bar_fg: caps.color(style.bar_fg),
+            bar_highlight_bg: caps.color(style.bar_highlight_bg),
+            bar_highlight_fg: caps.color(style.bar_highlight_fg),
+            dropdown_bg: caps.color(style.dropdown_bg),
+            dropdown_border: caps.color(style.dropdown_border),
+            item_fg: caps.color(style.item_fg),
+            item_highlight_bg: caps.color(style.item_highlight_bg),
+            item_highlight_fg: caps.color(style.item_highlight_fg),
+            shortcut_fg: caps.color(style.shortcut_fg),
+            disabled_fg: caps.color(style.disabled_fg),
+            separator_fg: caps.color(style.separator_fg),
+            ..style
+        }
+    }
+
     /// Set bar colors.
     pub fn bar_colors(mut self, fg: Color, bg: Color) -> Self {
         self.bar_fg = fg;
@@ -683,16 +906,20 @@ pub enum MenuBarClickTarget {
 
 /// Menu bar widget.
 ///
-/// A horizontal menu bar with dropdown menus.
-pub struct MenuBar<'a> {
-    menus: &'a [Menu],
+/// A horizontal menu bar with dropdown menus. `A` is the action its items
+/// trigger; see [`MenuAction`].
+pub struct TypedMenuBar<'a, A> {
+    menus: &'a [TypedMenu<A>],
     state: &'a MenuBarState,
     style: MenuBarStyle,
 }
 
-impl<'a> MenuBar<'a> {
+/// A menu bar whose items have string IDs.
+pub type MenuBar<'a> = TypedMenuBar<'a, String>;
+
+impl<'a, A> TypedMenuBar<'a, A> {
     /// Create a new menu bar.
-    pub fn new(menus: &'a [Menu], state: &'a MenuBarState) -> Self {
+    pub fn new(menus: &'a [TypedMenu<A>], state: &'a MenuBarState) -> Self {
         Self {
             menus,
             state,
@@ -712,26 +939,27 @@ impl<'a> MenuBar<'a> {
     }
 
     /// Calculate the required width for a dropdown.
-    fn calculate_dropdown_width(&self, items: &[MenuBarItem]) -> u16 {
+    fn calculate_dropdown_width(&self, items: &[TypedMenuBarItem<A>]) -> u16 {
         let mut max_label_width = 0u16;
         let mut max_shortcut_width = 0u16;
 
         for item in items {
             match item {
-                MenuBarItem::Action {
+                TypedMenuBarItem::Action {
                     label, shortcut, ..
                 } => {
-                    max_label_width = max_label_width.max(label.chars().count() as u16);
+                    max_label_width = max_label_width.max(display_width(label) as u16);
                     if let Some(s) = shortcut {
-                        max_shortcut_width = max_shortcut_width.max(s.chars().count() as u16);
+                        max_shortcut_width = max_shortcut_width.max(display_width(s) as u16);
                     }
                 }
-                MenuBarItem::Submenu { label, .. } => {
-                    // +2 for submenu indicator
-                    let label_width = label.chars().count() as u16 + 2;
+                TypedMenuBarItem::Submenu { label, .. } => {
+                    // Gap plus the submenu indicator
+                    let indicator_width = 1 + display_width(self.style.submenu_indicator);
+                    let label_width = (display_width(label) + indicator_width) as u16;
                     max_label_width = max_label_width.max(label_width);
                 }
-                MenuBarItem::Separator => {}
+                TypedMenuBarItem::Separator => {}
             }
         }
 
@@ -759,7 +987,7 @@ impl<'a> MenuBar<'a> {
         &self,
         menu_x: u16,
         bar_bottom: u16,
-        items: &[MenuBarItem],
+        items: &[TypedMenuBarItem<A>],
         screen: Rect,
     ) -> Rect {
         let width = self.calculate_dropdown_width(items);
@@ -789,6 +1017,20 @@ impl<'a> MenuBar<'a> {
         &self,
         frame: &mut Frame,
         area: Rect,
+    ) -> (Rect, Option<Rect>, Vec<ClickRegion<MenuBarClickTarget>>) {
+        let screen = frame.area();
+        self.render_to_buffer(area, screen, frame.buffer_mut())
+    }
+
+    /// Render the menu bar using Buffer (Widget-style rendering).
+    ///
+    /// `screen` bounds the dropdown and submenu placement. Returns the same
+    /// tuple as [`render_stateful`](Self::render_stateful).
+    pub fn render_to_buffer(
+        &self,
+        area: Rect,
+        screen: Rect,
+        buf: &mut Buffer,
     ) -> (Rect, Option<Rect>, Vec<ClickRegion<MenuBarClickTarget>>) {
         let mut regions = Vec::new();
 
@@ -796,22 +1038,63 @@ impl<'a> MenuBar<'a> {
             return (Rect::default(), None, regions);
         }
 
+        let (bar_area, menu_positions) = self.render_bar(area, buf, &mut regions);
+        let dropdown_area =
+            self.render_dropdown(bar_area, &menu_positions, screen, buf, &mut regions);
+
+        (bar_area, dropdown_area, regions)
+    }
+
+    /// Render the menu bar into `buf` and its open dropdown into an
+    /// [`OverlayManager`] on the [`OverlayLayer::Dropdown`] layer.
+    ///
+    /// Returns the same tuple as [`render_stateful`](Self::render_stateful).
+    pub fn render_into_overlays(
+        &self,
+        area: Rect,
+        screen: Rect,
+        buf: &mut Buffer,
+        overlays: &mut OverlayManager<'_>,
+    ) -> (Rect, Option<Rect>, Vec<ClickRegion<MenuBarClickTarget>>) {
+        let mut regions = Vec::new();
+
+        if area.height == 0 || self.menus.is_empty() {
+            return (Rect::default(), None, regions);
+        }
+
+        let (bar_area, menu_positions) = self.render_bar(area, buf, &mut regions);
+        let dropdown_area = if self.state.is_open {
+            overlays.capture(OverlayLayer::Dropdown, screen, |buf| {
+                self.render_dropdown(bar_area, &menu_positions, screen, buf, &mut regions)
+            })
+        } else {
+            None
+        };
+
+        (bar_area, dropdown_area, regions)
+    }
+
+    /// Render the bar row, returning its area and each menu label's (x, width).
+    fn render_bar(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        regions: &mut Vec<ClickRegion<MenuBarClickTarget>>,
+    ) -> (Rect, Vec<(u16, u16)>) {
         // Render the bar (1 row high)
         let bar_area = Rect::new(area.x, area.y, area.width, 1);
 
         // Fill bar background
         let bar_style = Style::default().bg(self.style.bar_bg);
-        let bar_line = " ".repeat(bar_area.width as usize);
-        let bar_para = Paragraph::new(Span::styled(bar_line, bar_style));
-        frame.render_widget(bar_para, bar_area);
+        let bar_para = Paragraph::new(Span::styled(spaces(bar_area.width as usize), bar_style));
+        bar_para.render(bar_area, buf);
 
         // Render menu labels
         let mut x = bar_area.x;
         let mut menu_positions: Vec<(u16, u16)> = Vec::new(); // (x, width) for each menu
 
         for (idx, menu) in self.menus.iter().enumerate() {
-            let label = format!(" {} ", menu.label);
-            let label_width = label.chars().count() as u16;
+            let label_width = display_width(&menu.label) as u16 + 2;
 
             let is_active = self.state.focused && idx == self.state.active_menu;
             let is_open = self.state.is_open && idx == self.state.active_menu;
@@ -827,8 +1110,12 @@ impl<'a> MenuBar<'a> {
             let style = Style::default().fg(fg).bg(bg);
             let label_area = Rect::new(x, bar_area.y, label_width, 1);
 
-            let para = Paragraph::new(Span::styled(label.clone(), style));
-            frame.render_widget(para, label_area);
+            let para = Paragraph::new(Line::from(vec![
+                Span::styled(" ", style),
+                Span::styled(menu.label.as_str(), style),
+                Span::styled(" ", style),
+            ]));
+            para.render(label_area, buf);
 
             menu_positions.push((x, label_width));
 
@@ -843,25 +1130,36 @@ impl<'a> MenuBar<'a> {
             x += label_width + self.style.menu_padding;
         }
 
-        // Render dropdown if a menu is open
-        let dropdown_area = if self.state.is_open {
+        (bar_area, menu_positions)
+    }
+
+    /// Render the open dropdown (and submenu), returning the dropdown area.
+    fn render_dropdown(
+        &self,
+        bar_area: Rect,
+        menu_positions: &[(u16, u16)],
+        screen: Rect,
+        buf: &mut Buffer,
+        regions: &mut Vec<ClickRegion<MenuBarClickTarget>>,
+    ) -> Option<Rect> {
+        if self.state.is_open {
             if let Some(menu) = self.menus.get(self.state.active_menu) {
                 if let Some(&(menu_x, _)) = menu_positions.get(self.state.active_menu) {
-                    let screen = frame.area();
                     let dropdown_area =
                         self.calculate_dropdown_area(menu_x, bar_area.y + 1, &menu.items, screen);
 
                     // Clear background (overlay)
-                    frame.render_widget(Clear, dropdown_area);
+                    Clear.render(dropdown_area, buf);
 
                     // Render border
                     let block = Block::default()
                         .borders(Borders::ALL)
+                        .border_set(self.style.border_set)
                         .border_style(Style::default().fg(self.style.dropdown_border))
                         .style(Style::default().bg(self.style.dropdown_bg));
 
                     let inner = block.inner(dropdown_area);
-                    frame.render_widget(block, dropdown_area);
+                    block.render(dropdown_area, buf);
 
                     // Render items
                     let visible_count = inner.height as usize;
@@ -881,11 +1179,11 @@ impl<'a> MenuBar<'a> {
                         let is_highlighted = self.state.highlighted_item == Some(item_idx);
 
                         self.render_menu_item(
-                            frame,
+                            buf,
                             item,
                             item_area,
                             is_highlighted,
-                            &mut regions,
+                            regions,
                             item_idx,
                             false,
                         );
@@ -893,7 +1191,7 @@ impl<'a> MenuBar<'a> {
 
                     // Render submenu if open
                     if let Some(submenu_idx) = self.state.active_submenu {
-                        if let Some(MenuBarItem::Submenu { items, .. }) =
+                        if let Some(TypedMenuBarItem::Submenu { items, .. }) =
                             menu.items.get(submenu_idx)
                         {
                             let submenu_x = dropdown_area.x + dropdown_area.width;
@@ -904,8 +1202,6 @@ impl<'a> MenuBar<'a> {
                             let submenu_width = self.calculate_dropdown_width(items);
                             let submenu_height = self.calculate_dropdown_height(items.len());
 
-                            let screen = frame.area();
-
                             // Adjust submenu position to stay on screen
                             let final_x = if submenu_x + submenu_width <= screen.x + screen.width {
                                 submenu_x
@@ -921,15 +1217,16 @@ impl<'a> MenuBar<'a> {
                             );
 
                             // Clear and render submenu
-                            frame.render_widget(Clear, submenu_area);
+                            Clear.render(submenu_area, buf);
 
                             let block = Block::default()
                                 .borders(Borders::ALL)
+                                .border_set(self.style.border_set)
                                 .border_style(Style::default().fg(self.style.dropdown_border))
                                 .style(Style::default().bg(self.style.dropdown_bg));
 
                             let sub_inner = block.inner(submenu_area);
-                            frame.render_widget(block, submenu_area);
+                            block.render(submenu_area, buf);
 
                             let sub_visible = sub_inner.height as usize;
                             let sub_scroll = self.state.submenu_scroll_offset as usize;
@@ -948,11 +1245,11 @@ impl<'a> MenuBar<'a> {
                                     self.state.submenu_highlighted == Some(item_idx);
 
                                 self.render_menu_item(
-                                    frame,
+                                    buf,
                                     item,
                                     item_area,
                                     is_highlighted,
-                                    &mut regions,
+                                    regions,
                                     item_idx,
                                     true,
                                 );
@@ -969,17 +1266,15 @@ impl<'a> MenuBar<'a> {
             }
         } else {
             None
-        };
-
-        (bar_area, dropdown_area, regions)
+        }
     }
 
     /// Render a single menu item.
     #[allow(clippy::too_many_arguments)]
     fn render_menu_item(
         &self,
-        frame: &mut Frame,
-        item: &MenuBarItem,
+        buf: &mut Buffer,
+        item: &TypedMenuBarItem<A>,
         item_area: Rect,
         is_highlighted: bool,
         regions: &mut Vec<ClickRegion<MenuBarClickTarget>>,
@@ -987,7 +1282,7 @@ impl<'a> MenuBar<'a> {
         is_submenu: bool,
     ) {
         match item {
-            MenuBarItem::Separator => {
+            TypedMenuBarItem::Separator => {
                 let sep_line: String =
                     std::iter::repeat_n(self.style.separator_char, item_area.width as usize)
                         .collect();
@@ -997,13 +1292,14 @@ impl<'a> MenuBar<'a> {
                         .fg(self.style.separator_fg)
                         .bg(self.style.dropdown_bg),
                 ));
-                frame.render_widget(para, item_area);
+                para.render(item_area, buf);
             }
-            MenuBarItem::Action {
+            TypedMenuBarItem::Action {
                 label,
                 shortcut,
                 enabled,
                 id,
+                ..
             } => {
                 let (fg, bg) = if !enabled {
                     (self.style.disabled_fg, self.style.dropdown_bg)
@@ -1022,42 +1318,37 @@ impl<'a> MenuBar<'a> {
                     })
                     .bg(bg);
 
+                let padding = spaces(self.style.dropdown_padding as usize);
                 let mut spans = Vec::new();
 
                 // Padding
-                spans.push(Span::styled(
-                    " ".repeat(self.style.dropdown_padding as usize),
-                    style,
-                ));
+                spans.push(Span::styled(padding.clone(), style));
 
                 // Label
-                spans.push(Span::styled(label.clone(), style));
+                spans.push(Span::styled(label.as_str(), style));
 
                 // Fill space before shortcut
-                let current_len: usize = spans.iter().map(|s| s.content.chars().count()).sum();
-                let shortcut_len = shortcut.as_ref().map(|s| s.chars().count()).unwrap_or(0);
+                let current_len: usize = spans.iter().map(Span::width).sum();
+                let shortcut_len = shortcut.as_deref().map_or(0, display_width);
                 let fill_len = (item_area.width as usize)
                     .saturating_sub(current_len)
                     .saturating_sub(shortcut_len)
                     .saturating_sub(self.style.dropdown_padding as usize);
 
                 if fill_len > 0 {
-                    spans.push(Span::styled(" ".repeat(fill_len), style));
+                    spans.push(Span::styled(spaces(fill_len), style));
                 }
 
                 // Shortcut
                 if let Some(sc) = shortcut {
-                    spans.push(Span::styled(sc.clone(), shortcut_style));
+                    spans.push(Span::styled(sc.as_str(), shortcut_style));
                 }
 
                 // Right padding
-                spans.push(Span::styled(
-                    " ".repeat(self.style.dropdown_padding as usize),
-                    style,
-                ));
+                spans.push(Span::styled(padding, style));
 
                 let para = Paragraph::new(Line::from(spans));
-                frame.render_widget(para, item_area);
+                para.render(item_area, buf);
 
                 // Register click region
                 if *enabled {
@@ -1072,7 +1363,7 @@ impl<'a> MenuBar<'a> {
                 // Silence unused variable warning
                 let _ = id;
             }
-            MenuBarItem::Submenu { label, enabled, .. } => {
+            TypedMenuBarItem::Submenu { label, enabled, .. } => {
                 let (fg, bg) = if !enabled {
                     (self.style.disabled_fg, self.style.dropdown_bg)
                 } else if is_highlighted {
@@ -1083,39 +1374,34 @@ impl<'a> MenuBar<'a> {
 
                 let style = Style::default().fg(fg).bg(bg);
 
+                let padding = spaces(self.style.dropdown_padding as usize);
                 let mut spans = Vec::new();
 
                 // Padding
-                spans.push(Span::styled(
-                    " ".repeat(self.style.dropdown_padding as usize),
-                    style,
-                ));
+                spans.push(Span::styled(padding.clone(), style));
 
                 // Label
-                spans.push(Span::styled(label.clone(), style));
+                spans.push(Span::styled(label.as_str(), style));
 
                 // Fill and submenu indicator
-                let current_len: usize = spans.iter().map(|s| s.content.chars().count()).sum();
-                let indicator_len = self.style.submenu_indicator.chars().count();
+                let current_len: usize = spans.iter().map(Span::width).sum();
+                let indicator_len = display_width(self.style.submenu_indicator);
                 let fill_len = (item_area.width as usize)
                     .saturating_sub(current_len)
                     .saturating_sub(indicator_len)
                     .saturating_sub(self.style.dropdown_padding as usize);
 
                 if fill_len > 0 {
-                    spans.push(Span::styled(" ".repeat(fill_len), style));
+                    spans.push(Span::styled(spaces(fill_len), style));
                 }
 
                 spans.push(Span::styled(self.style.submenu_indicator, style));
 
                 // Right padding
-                spans.push(Span::styled(
-                    " ".repeat(self.style.dropdown_padding as usize),
-                    style,
-                ));
+                spans.push(Span::styled(padding, style));
 
                 let para = Paragraph::new(Line::from(spans));
-                frame.render_widget(para, item_area);
+                para.render(item_area, buf);
 
                 // Register click region (only for parent dropdown, not for nested submenus)
                 if *enabled && !is_submenu {
@@ -1138,15 +1424,36 @@ impl<'a> MenuBar<'a> {
 /// - `Left/Right` - Navigate between menus
 /// - `Up/Down` - Navigate within dropdown (opens menu if closed)
 /// - `Enter/Space` - Select item or toggle menu
-/// - `Escape` - Close menu
+/// - `Escape` - Close the open submenu, or the dropdown (left unhandled while
+///   no dropdown is open, so it can propagate; see [`CloseRequest`])
 /// - `Home` - Jump to first item
 /// - `End` - Jump to last item
+/// - `PageUp/PageDown` - Move a page of items in the dropdown
+///
+/// Scrolls as if the dropdown used the default [`MenuBarStyle`]; use
+/// [`handle_menu_bar_key_with_style`] when rendering with a custom
+/// [`dropdown_max_height`](MenuBarStyle::dropdown_max_height).
+pub fn handle_menu_bar_key<A: MenuAction>(
+    key: &KeyEvent,
+    state: &mut MenuBarState,
+    menus: &[TypedMenu<A>],
+) -> Option<TypedMenuBarAction<A>> {
+    handle_menu_bar_key_with_style(key, state, menus, &MenuBarStyle::default())
+}
+
+/// Handle keyboard events for menu bar rendered with `style`.
+///
+/// Same as [`handle_menu_bar_key`], but scrolling and PageUp/PageDown move
+/// by the style's [`dropdown_max_height`](MenuBarStyle::dropdown_max_height),
+/// the number of items the dropdown shows.
 #[allow(clippy::collapsible_match)]
-pub fn handle_menu_bar_key(
+pub fn handle_menu_bar_key_with_style<A: MenuAction>(
     key: &KeyEvent,
     state: &mut MenuBarState,
-    menus: &[Menu],
-) -> Option<MenuBarAction> {
+    menus: &[TypedMenu<A>],
+    style: &MenuBarStyle,
+) -> Option<TypedMenuBarAction<A>> {
+    let page_size = usize::from(style.dropdown_max_height);
     if menus.is_empty() {
         return None;
     }
@@ -1155,22 +1462,22 @@ pub fn handle_menu_bar_key(
     if state.has_open_submenu() {
         if let Some(menu) = menus.get(state.active_menu) {
             if let Some(submenu_idx) = state.active_submenu {
-                if let Some(MenuBarItem::Submenu { items, .. }) = menu.items.get(submenu_idx) {
+                if let Some(TypedMenuBarItem::Submenu { items, .. }) = menu.items.get(submenu_idx) {
                     match key.code {
                         KeyCode::Esc | KeyCode::Left => {
                             state.close_submenu();
-                            return Some(MenuBarAction::SubmenuClose);
+                            return Some(TypedMenuBarAction::SubmenuClose);
                         }
                         KeyCode::Up => {
                             state.prev_submenu_item(items);
-                            return Some(MenuBarAction::HighlightChange(
+                            return Some(TypedMenuBarAction::HighlightChange(
                                 state.active_menu,
                                 state.submenu_highlighted,
                             ));
                         }
                         KeyCode::Down => {
                             state.next_submenu_item(items);
-                            return Some(MenuBarAction::HighlightChange(
+                            return Some(TypedMenuBarAction::HighlightChange(
                                 state.active_menu,
                                 state.submenu_highlighted,
                             ));
@@ -1178,11 +1485,11 @@ pub fn handle_menu_bar_key(
                         KeyCode::Enter | KeyCode::Char(' ') => {
                             if let Some(idx) = state.submenu_highlighted {
                                 if let Some(item) = items.get(idx) {
-                                    if let MenuBarItem::Action { id, enabled, .. } = item {
+                                    if let TypedMenuBarItem::Action { id, enabled, .. } = item {
                                         if *enabled {
                                             let action_id = id.clone();
                                             state.close_menu();
-                                            return Some(MenuBarAction::ItemSelect(action_id));
+                                            return Some(TypedMenuBarAction::ItemSelect(action_id));
                                         }
                                     }
                                 }
@@ -1199,7 +1506,7 @@ pub fn handle_menu_bar_key(
     match key.code {
         KeyCode::Left => {
             state.prev_menu(menus.len());
-            Some(MenuBarAction::HighlightChange(state.active_menu, None))
+            Some(TypedMenuBarAction::HighlightChange(state.active_menu, None))
         }
         KeyCode::Right => {
             // If on a submenu item, open it
@@ -1209,21 +1516,24 @@ pub fn handle_menu_bar_key(
                         if let Some(item) = menu.items.get(idx) {
                             if item.has_submenu() && item.is_enabled() {
                                 state.open_submenu();
-                                return Some(MenuBarAction::SubmenuOpen(state.active_menu, idx));
+                                return Some(TypedMenuBarAction::SubmenuOpen(
+                                    state.active_menu,
+                                    idx,
+                                ));
                             }
                         }
                     }
                 }
             }
             state.next_menu(menus.len());
-            Some(MenuBarAction::HighlightChange(state.active_menu, None))
+            Some(TypedMenuBarAction::HighlightChange(state.active_menu, None))
         }
         KeyCode::Down => {
             if state.is_open {
                 if let Some(menu) = menus.get(state.active_menu) {
                     state.next_item(&menu.items);
-                    state.ensure_visible(8);
-                    Some(MenuBarAction::HighlightChange(
+                    state.ensure_visible(page_size);
+                    Some(TypedMenuBarAction::HighlightChange(
                         state.active_menu,
                         state.highlighted_item,
                     ))
@@ -1235,15 +1545,15 @@ pub fn handle_menu_bar_key(
                 if let Some(menu) = menus.get(state.active_menu) {
                     state.highlight_first(&menu.items);
                 }
-                Some(MenuBarAction::MenuOpen(state.active_menu))
+                Some(TypedMenuBarAction::MenuOpen(state.active_menu))
             }
         }
         KeyCode::Up => {
             if state.is_open {
                 if let Some(menu) = menus.get(state.active_menu) {
                     state.prev_item(&menu.items);
-                    state.ensure_visible(8);
-                    Some(MenuBarAction::HighlightChange(
+                    state.ensure_visible(page_size);
+                    Some(TypedMenuBarAction::HighlightChange(
                         state.active_menu,
                         state.highlighted_item,
                     ))
@@ -1260,14 +1570,14 @@ pub fn handle_menu_bar_key(
                     if let Some(idx) = state.highlighted_item {
                         if let Some(item) = menu.items.get(idx) {
                             match item {
-                                MenuBarItem::Action { id, enabled, .. } if *enabled => {
+                                TypedMenuBarItem::Action { id, enabled, .. } if *enabled => {
                                     let action_id = id.clone();
                                     state.close_menu();
-                                    return Some(MenuBarAction::ItemSelect(action_id));
+                                    return Some(TypedMenuBarAction::ItemSelect(action_id));
                                 }
-                                MenuBarItem::Submenu { enabled, .. } if *enabled => {
+                                TypedMenuBarItem::Submenu { enabled, .. } if *enabled => {
                                     state.open_submenu();
-                                    return Some(MenuBarAction::SubmenuOpen(
+                                    return Some(TypedMenuBarAction::SubmenuOpen(
                                         state.active_menu,
                                         idx,
                                     ));
@@ -1283,13 +1593,13 @@ pub fn handle_menu_bar_key(
                 if let Some(menu) = menus.get(state.active_menu) {
                     state.highlight_first(&menu.items);
                 }
-                Some(MenuBarAction::MenuOpen(state.active_menu))
+                Some(TypedMenuBarAction::MenuOpen(state.active_menu))
             }
         }
         KeyCode::Esc => {
             if state.is_open {
                 state.close_menu();
-                Some(MenuBarAction::MenuClose)
+                Some(TypedMenuBarAction::MenuClose)
             } else {
                 None
             }
@@ -1298,7 +1608,7 @@ pub fn handle_menu_bar_key(
             if state.is_open {
                 if let Some(menu) = menus.get(state.active_menu) {
                     state.highlight_first(&menu.items);
-                    Some(MenuBarAction::HighlightChange(
+                    Some(TypedMenuBarAction::HighlightChange(
                         state.active_menu,
                         state.highlighted_item,
                     ))
@@ -1307,7 +1617,7 @@ pub fn handle_menu_bar_key(
                 }
             } else {
                 state.active_menu = 0;
-                Some(MenuBarAction::HighlightChange(0, None))
+                Some(TypedMenuBarAction::HighlightChange(0, None))
             }
         }
         KeyCode::End => {
@@ -1315,7 +1625,7 @@ pub fn handle_menu_bar_key(
                 if let Some(menu) = menus.get(state.active_menu) {
                     state.highlight_last(&menu.items);
                     state.ensure_visible(menu.items.len());
-                    Some(MenuBarAction::HighlightChange(
+                    Some(TypedMenuBarAction::HighlightChange(
                         state.active_menu,
                         state.highlighted_item,
                     ))
@@ -1324,9 +1634,21 @@ pub fn handle_menu_bar_key(
                 }
             } else {
                 state.active_menu = menus.len().saturating_sub(1);
-                Some(MenuBarAction::HighlightChange(state.active_menu, None))
+                Some(TypedMenuBarAction::HighlightChange(state.active_menu, None))
             }
         }
+        KeyCode::PageDown | KeyCode::PageUp if state.is_open => {
+            let menu = menus.get(state.active_menu)?;
+            if key.code == KeyCode::PageDown {
+                state.page_down_items(&menu.items, page_size);
+            } else {
+                state.page_up_items(&menu.items, page_size);
+            }
+            Some(TypedMenuBarAction::HighlightChange(
+                state.active_menu,
+                state.highlighted_item,
+            ))
+        }
         _ => None,
     }
 }
@@ -1344,14 +1666,14 @@ pub fn handle_menu_bar_key(
 /// * `click_regions` - Click regions from `render_stateful`
 /// * `menus` - The menu definitions
 #[allow(clippy::collapsible_match)]
-pub fn handle_menu_bar_mouse(
+pub fn handle_menu_bar_mouse<A: MenuAction>(
     mouse: &MouseEvent,
     state: &mut MenuBarState,
     bar_area: Rect,
     dropdown_area: Option<Rect>,
     click_regions: &[ClickRegion<MenuBarClickTarget>],
-    menus: &[Menu],
-) -> Option<MenuBarAction> {
+    menus: &[TypedMenu<A>],
+) -> Option<TypedMenuBarAction<A>> {
     let col = mouse.column;
     let row = mouse.row;
 
@@ -1367,24 +1689,26 @@ pub fn handle_menu_bar_mouse(
                                 if let Some(menu) = menus.get(*idx) {
                                     state.highlight_first(&menu.items);
                                 }
-                                return Some(MenuBarAction::MenuOpen(*idx));
+                                return Some(TypedMenuBarAction::MenuOpen(*idx));
                             } else {
-                                return Some(MenuBarAction::MenuClose);
+                                return Some(TypedMenuBarAction::MenuClose);
                             }
                         }
                         MenuBarClickTarget::DropdownItem(idx) => {
                             if let Some(menu) = menus.get(state.active_menu) {
                                 if let Some(item) = menu.items.get(*idx) {
                                     match item {
-                                        MenuBarItem::Action { id, enabled, .. } if *enabled => {
+                                        TypedMenuBarItem::Action { id, enabled, .. }
+                                            if *enabled =>
+                                        {
                                             let action_id = id.clone();
                                             state.close_menu();
-                                            return Some(MenuBarAction::ItemSelect(action_id));
+                                            return Some(TypedMenuBarAction::ItemSelect(action_id));
                                         }
-                                        MenuBarItem::Submenu { enabled, .. } if *enabled => {
+                                        TypedMenuBarItem::Submenu { enabled, .. } if *enabled => {
                                             state.highlighted_item = Some(*idx);
                                             state.open_submenu();
-                                            return Some(MenuBarAction::SubmenuOpen(
+                                            return Some(TypedMenuBarAction::SubmenuOpen(
                                                 state.active_menu,
                                                 *idx,
                                             ));
@@ -1397,15 +1721,18 @@ pub fn handle_menu_bar_mouse(
                         MenuBarClickTarget::SubmenuItem(idx) => {
                             if let Some(menu) = menus.get(state.active_menu) {
                                 if let Some(submenu_idx) = state.active_submenu {
-                                    if let Some(MenuBarItem::Submenu { items, .. }) =
+                                    if let Some(TypedMenuBarItem::Submenu { items, .. }) =
                                         menu.items.get(submenu_idx)
                                     {
                                         if let Some(item) = items.get(*idx) {
-                                            if let MenuBarItem::Action { id, enabled, .. } = item {
+                                            if let TypedMenuBarItem::Action {
+                                                id, enabled, ..
+                                            } = item
+                                            {
                                                 if *enabled {
                                                     let action_id = id.clone();
                                                     state.close_menu();
-                                                    return Some(MenuBarAction::ItemSelect(
+                                                    return Some(TypedMenuBarAction::ItemSelect(
                                                         action_id,
                                                     ));
                                                 }
@@ -1427,7 +1754,7 @@ pub fn handle_menu_bar_mouse(
 
             if state.is_open && !in_bar && !in_dropdown {
                 state.close_menu();
-                return Some(MenuBarAction::MenuClose);
+                return Some(TypedMenuBarAction::MenuClose);
             }
 
             None
@@ -1444,7 +1771,7 @@ pub fn handle_menu_bar_mouse(
                                 if let Some(menu) = menus.get(*idx) {
                                     state.highlight_first(&menu.items);
                                 }
-                                return Some(MenuBarAction::MenuOpen(*idx));
+                                return Some(TypedMenuBarAction::MenuOpen(*idx));
                             }
                         }
                         MenuBarClickTarget::DropdownItem(idx) => {
@@ -1456,7 +1783,7 @@ pub fn handle_menu_bar_mouse(
                                 {
                                     state.close_submenu();
                                 }
-                                return Some(MenuBarAction::HighlightChange(
+                                return Some(TypedMenuBarAction::HighlightChange(
                                     state.active_menu,
                                     Some(*idx),
                                 ));
@@ -1465,7 +1792,7 @@ pub fn handle_menu_bar_mouse(
                         MenuBarClickTarget::SubmenuItem(idx) => {
                             if state.submenu_highlighted != Some(*idx) {
                                 state.submenu_highlighted = Some(*idx);
-                                return Some(MenuBarAction::HighlightChange(
+                                return Some(TypedMenuBarAction::HighlightChange(
                                     state.active_menu,
                                     Some(*idx),
                                 ));
@@ -1593,6 +1920,35 @@ mod tests {
         assert_eq!(state.active_menu, 1);
     }
 
+    #[test]
+    fn test_menu_bar_state_save_restore() {
+        let mut state = MenuBarState::new();
+        state.focused = true;
+        state.open_menu(2);
+        state.highlighted_item = Some(1);
+
+        let save = state.serialize();
+        assert_eq!(
+            save,
+            MenuBarStateSave {
+                active_menu: 2,
+                focused: true
+            }
+        );
+        assert!(state.eq_snapshot(&save));
+
+        let mut restored = MenuBarState::new();
+        assert!(!restored.eq_snapshot(&save));
+        restored.open_menu(0);
+        restored.restore(&save);
+        assert!(restored.eq_snapshot(&save));
+        assert!(!restored.is_open);
+        assert_eq!(restored.highlighted_item, None);
+
+        state.next_menu(4);
+        assert!(!state.eq_snapshot(&save));
+    }
+
     #[test]
     fn test_menu_bar_state_navigation() {
         let mut state = MenuBarState::new();
@@ -1757,6 +2113,40 @@ mod tests {
         assert!(!state.is_open);
     }
 
+    #[test]
+    fn test_typed_actions() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum FileAction {
+            New,
+            Open,
+        }
+
+        impl MenuAction for FileAction {
+            fn id(&self) -> &str {
+                match self {
+                    FileAction::New => "new",
+                    FileAction::Open => "open",
+                }
+            }
+        }
+
+        let menus = vec![TypedMenu::new("File").items(vec![
+            TypedMenuBarItem::from_action(FileAction::New, "New"),
+            TypedMenuBarItem::separator(),
+            TypedMenuBarItem::from_action(FileAction::Open, "Open"),
+        ])];
+        assert_eq!(menus[0].items[2].id(), Some("open"));
+
+        let mut state = MenuBarState::new();
+        state.open_menu(0);
+        state.highlighted_item = Some(2);
+        let action = handle_menu_bar_key(&KeyEvent::from(KeyCode::Enter), &mut state, &menus);
+        assert_eq!(
+            action,
+            Some(TypedMenuBarAction::ItemSelect(FileAction::Open))
+        );
+    }
+
     #[test]
     fn test_handle_key_enter_opens_submenu() {
         let mut state = MenuBarState::new();
@@ -1857,6 +2247,47 @@ mod tests {
         assert!(state.scroll_offset <= 3);
     }
 
+    #[test]
+    fn test_handle_key_page_down_up() {
+        let items: Vec<MenuBarItem> = (0..30)
+            .map(|i| MenuBarItem::action(format!("item{}", i), format!("Item {}", i)))
+            .collect();
+        let menus = vec![Menu::new("Long").items(items)];
+        let style = MenuBarStyle::default().dropdown_max_height(10);
+        let mut state = MenuBarState::new();
+        state.open_menu(0);
+        state.highlight_first(&menus[0].items);
+
+        let mut press = |code| {
+            handle_menu_bar_key_with_style(&KeyEvent::from(code), &mut state, &menus, &style);
+            (state.highlighted_item, state.scroll_offset)
+        };
+        assert_eq!(press(KeyCode::PageDown), (Some(10), 10));
+        assert_eq!(press(KeyCode::PageDown), (Some(20), 20));
+        assert_eq!(press(KeyCode::PageDown), (Some(29), 20));
+        assert_eq!(press(KeyCode::PageUp), (Some(19), 10));
+        assert_eq!(press(KeyCode::PageUp), (Some(9), 0));
+        assert_eq!(press(KeyCode::PageUp), (Some(0), 0));
+    }
+
+    #[test]
+    fn test_page_items_skip_unselectable() {
+        let items = vec![
+            MenuBarItem::action("a", "A"),
+            MenuBarItem::action("b", "B"),
+            MenuBarItem::separator(),
+            MenuBarItem::action("c", "C").enabled(false),
+        ];
+        let mut state = MenuBarState::new();
+        state.highlighted_item = Some(0);
+
+        state.page_down_items(&items, 2);
+        assert_eq!(state.highlighted_item, Some(1));
+
+        state.page_up_items(&items, 5);
+        assert_eq!(state.highlighted_item, Some(0));
+    }
+
     #[test]
     fn test_menu_bar_state_highlight_first_last() {
         let mut state = MenuBarState::new();
@@ -1897,4 +2328,210 @@ mod tests {
         state.prev_submenu_item(&items);
         assert!(state.submenu_highlighted.is_some());
     }
+
+    #[test]
+    fn test_current_hint() {
+        let menus = vec![Menu::new("File").items(vec![
+            MenuBarItem::action("new", "New").hint("Create a new file"),
+            MenuBarItem::separator(),
+            MenuBarItem::submenu(
+                "Recent",
+                vec![MenuBarItem::action("project", "Project").hint("Open the last project")],
+            )
+            .hint("Open a recent file"),
+        ])];
+        let mut state = MenuBarState::new();
+        assert_eq!(state.current_hint(&menus), None);
+
+        state.open_menu(0);
+        state.highlighted_item = Some(0);
+        assert_eq!(state.current_hint(&menus), Some("Create a new file"));
+
+        // Separators clear the hint
+        state.highlighted_item = Some(1);
+        assert_eq!(state.current_hint(&menus), None);
+
+        state.highlighted_item = Some(2);
+        assert_eq!(state.current_hint(&menus), Some("Open a recent file"));
+        state.open_submenu();
+        assert_eq!(state.current_hint(&menus), Some("Open a recent file"));
+        state.submenu_highlighted = Some(0);
+        assert_eq!(state.current_hint(&menus), Some("Open the last project"));
+
+        state.close_menu();
+        assert_eq!(state.current_hint(&menus), None);
+    }
+
+    #[test]
+    fn test_render_bar_and_dropdown_rows() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let menus = vec![
+            Menu::new("File").items(vec![
+                MenuBarItem::action("new", "New").shortcut("Ctrl+N"),
+                MenuBarItem::submenu("Recent", vec![MenuBarItem::action("a", "a.rs")]),
+            ]),
+            Menu::new("Edit"),
+        ];
+        let mut state = MenuBarState::new();
+        state.open_menu(0);
+
+        let mut terminal = Terminal::new(TestBackend::new(30, 6)).unwrap();
+        terminal
+            .draw(|frame| {
+                MenuBar::new(&menus, &state).render_stateful(frame, frame.area());
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let row = |y: u16| (0..30).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(0), " File    Edit                 ");
+        assert_eq!(row(2), "│ New       Ctrl+N │          ");
+        assert_eq!(row(3), "│ Recent         ▶ │          ");
+    }
+
+    #[test]
+    fn test_render_to_buffer_with_submenu() {
+        let menus = vec![Menu::new("File").items(vec![
+            MenuBarItem::action("new", "New"),
+            MenuBarItem::submenu("Recent", vec![MenuBarItem::action("a", "a.rs")]),
+        ])];
+        let mut state = MenuBarState::new();
+        state.open_menu(0);
+        state.select_item(1);
+        state.open_submenu();
+
+        let screen = Rect::new(0, 0, 30, 6);
+        let mut buf = Buffer::empty(screen);
+        for y in 1..6 {
+            buf.set_string(0, y, "x".repeat(30), Style::default());
+        }
+        let (bar, dropdown, regions) =
+            MenuBar::new(&menus, &state).render_to_buffer(screen, screen, &mut buf);
+
+        assert_eq!(bar, Rect::new(0, 0, 30, 1));
+        assert!(dropdown.is_some());
+        assert!(
+            regions
+                .iter()
+                .any(|r| r.data == MenuBarClickTarget::SubmenuItem(0))
+        );
+        let row = |y: u16| (0..30).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(2), "│ New         │xxxxxxxxxxxxxxx");
+        assert_eq!(row(3), "│ Recent    ▶ │┌─────────────┐");
+        assert_eq!(row(4), "└─────────────┘│ a.rs        │");
+        assert_eq!(row(5), "xxxxxxxxxxxxxxx└─────────────┘");
+    }
+
+    #[test]
+    fn test_render_into_overlays() {
+        use crate::state::OverlayManager;
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let menus = vec![Menu::new("File").items(vec![
+            MenuBarItem::action("new", "New"),
+            MenuBarItem::submenu("Recent", vec![MenuBarItem::action("a", "a.rs")]),
+        ])];
+        let mut state = MenuBarState::new();
+        state.open_menu(0);
+        state.select_item(1);
+        state.open_submenu();
+
+        let mut terminal = Terminal::new(TestBackend::new(30, 6)).unwrap();
+        terminal
+            .draw(|frame| {
+                let screen = frame.area();
+                let mut overlays = OverlayManager::new();
+                let (bar, dropdown, regions) = MenuBar::new(&menus, &state).render_into_overlays(
+                    screen,
+                    screen,
+                    frame.buffer_mut(),
+                    &mut overlays,
+                );
+                assert_eq!(bar, Rect::new(0, 0, 30, 1));
+                assert!(overlays.covers(dropdown.unwrap()));
+                assert!(!overlays.covers(bar));
+                assert!(
+                    regions
+                        .iter()
+                        .any(|r| r.data == MenuBarClickTarget::SubmenuItem(0))
+                );
+
+                // Background drawn after registering still ends up underneath
+                for y in 1..6 {
+                    frame
+                        .buffer_mut()
+                        .set_string(0, y, "x".repeat(30), Style::default());
+                }
+                overlays.render(frame);
+            })
+            .unwrap();
+
+        let buf = terminal.backend().buffer();
+        let row = |y: u16| (0..30).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert!(row(0).starts_with(" File "));
+        assert_eq!(row(2), "│ New         │xxxxxxxxxxxxxxx");
+        assert_eq!(row(3), "│ Recent    ▶ │┌─────────────┐");
+        assert_eq!(row(5), "xxxxxxxxxxxxxxx└─────────────┘");
+    }
+
+    #[test]
+    fn test_ascii_render() {
+        let menus = vec![Menu::new("File").items(vec![
+            MenuBarItem::action("new", "New"),
+            MenuBarItem::separator(),
+            MenuBarItem::submenu("Recent", vec![MenuBarItem::action("a", "a.rs")]),
+        ])];
+        let mut state = MenuBarState::new();
+        state.open_menu(0);
+        state.select_item(2);
+        state.open_submenu();
+
+        let screen = Rect::new(0, 0, 30, 7);
+        let mut buf = Buffer::empty(screen);
+        MenuBar::new(&menus, &state)
+            .style(MenuBarStyle::from_capabilities(&Capabilities::basic()))
+            .render_to_buffer(screen, screen, &mut buf);
+
+        assert!(buf.content().iter().all(|c| c.symbol().is_ascii()));
+        let row = |y: u16| (0..15).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(1), "+-------------+");
+        assert_eq!(row(3), "|-------------|");
+        assert_eq!(row(4), "| Recent    > |");
+    }
+
+    #[test]
+    fn test_render_dropdown_wide_labels() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let menus = vec![Menu::new("ファイル").items(vec![
+            MenuBarItem::action("open", "開く").shortcut("Ctrl+O"),
+            MenuBarItem::action("save", "Save").shortcut("Ctrl+S"),
+            MenuBarItem::submenu("最近", vec![MenuBarItem::action("a", "a.rs")]),
+        ])];
+        let mut state = MenuBarState::new();
+        state.open_menu(0);
+
+        let mut terminal = Terminal::new(TestBackend::new(30, 6)).unwrap();
+        terminal
+            .draw(|frame| {
+                MenuBar::new(&menus, &state).render_stateful(frame, frame.area());
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        // The dropdown border closes at the same column on every row
+        let right = (0..30)
+            .rev()
+            .find(|&x| buf[(x, 2)].symbol() == "│")
+            .unwrap();
+        for y in 2..5 {
+            assert_eq!(buf[(right, y)].symbol(), "│");
+            assert_eq!(buf[(right - 2, y)].symbol().chars().count(), 1);
+        }
+        assert_eq!(buf[(right - 2, 2)].symbol(), "O");
+        assert_eq!(buf[(right - 2, 3)].symbol(), "S");
+        assert_eq!(buf[(right - 2, 4)].symbol(), "▶");
+        // "ファイル" is 8 cells wide, plus a space on each side
+        assert_eq!(buf[(9, 0)].symbol(), " ");
+        assert_eq!(buf[(7, 0)].symbol(), "ル");
+    }
 }
@@ -8,19 +8,23 @@
 //! - [`CheckBox`] - Toggleable checkbox with label
 //! - [`Input`] - Text input field with cursor
 //! - [`TextArea`] - Multi-line text input with cursor and scrolling
+//! - [`KeyValueEditor`] - Two-column editable grid for headers, env vars, and metadata
 //! - [`Button`] - Various button styles
 //! - [`Select`] - Dropdown select box with popup options
 //! - [`ContextMenu`] - Right-click popup menu with actions and submenus
 //! - [`MenuBar`] - Horizontal menu bar with dropdown menus (File, Edit, View, Help style)
 //! - [`PopupDialog`] - Container for popup dialogs
+//! - [`HelpOverlay`] - Searchable help generated from registered key hints
 //!
 //! ## Display Components
 //! - [`AnimatedText`] - Animated text with color effects (pulse, wave, rainbow)
 //! - [`ParagraphExt`] - Extended paragraph with word-wrapping and scrolling
 //! - [`Toast`] - Toast notifications with auto-dismiss
+//! - [`NotificationCenter`] - Persistent notification history with unread badge
 //! - [`Progress`] - Progress bar with label and percentage
 //! - [`MarqueeText`] - Scrolling text for long content in limited space
 //! - [`Spinner`] - Animated loading/processing indicator with multiple styles
+//! - [`BusyOverlay`] - Input-blocking dimmed overlay with a spinner and message
 //!
 //! ## Navigation Components
 //! - [`ListPicker`] - Scrollable list with selection
@@ -35,31 +39,42 @@
 //!
 //! ## Utility Components
 //! - [`MousePointer`] - Visual indicator at mouse cursor position
+//! - [`InteractiveScrollbar`] - Scrollbar with clickable arrows, track, and draggable thumb
+//! - [`FocusRing`] - Consistent focus border or outline for any widget
 //!
 //! ## Dialog Components
 //! - [`HotkeyDialog`] - Hotkey configuration dialog with search and categories
+//! - [`Wizard`] - Multi-page flow with progress header and Back/Next/Finish
 //!
 //! ## Viewer Components
 //! - [`LogViewer`] - Scrollable log viewer with search
 //! - [`DiffViewer`] - Diff viewer with unified and side-by-side modes
 //! - [`StepDisplay`] - Multi-step progress display
+//! - [`TextSelection`] - Keyboard copy mode with linewise and block selection for viewers
 
 pub mod accordion;
 pub mod animated_text;
 pub mod breadcrumb;
+pub mod busy_overlay;
 pub mod button;
 pub mod checkbox;
 pub mod container;
 pub mod context_menu;
 pub mod diff_viewer;
 pub mod file_explorer;
+pub mod focus_ring;
+pub mod help_overlay;
 pub mod hotkey_dialog;
 pub mod input;
+pub mod interactive_scrollbar;
+pub mod key_value_editor;
 pub mod list_picker;
 pub mod log_viewer;
 pub mod marquee;
 pub mod menu_bar;
 pub mod mouse_pointer;
+pub mod notification_center;
+pub mod option_details;
 pub mod paragraph_ext;
 pub mod progress;
 pub mod scrollable_content;
@@ -68,13 +83,16 @@ pub mod spinner;
 pub mod split_pane;
 pub mod step_display;
 pub mod tab_view;
+pub mod text_selection;
 pub mod textarea;
 pub mod toast;
 pub mod toast_stack;
 pub mod tree_view;
+pub mod wizard;
 
 pub use accordion::{
-    Accordion, AccordionMode, AccordionState, AccordionStyle, calculate_height as accordion_height,
+    Accordion, AccordionLayout, AccordionMode, AccordionState, AccordionStyle,
+    calculate_height as accordion_height, calculate_layout as accordion_layout,
     handle_accordion_key, handle_accordion_mouse,
 };
 pub use animated_text::{
@@ -82,70 +100,142 @@ pub use animated_text::{
 };
 pub use breadcrumb::{
     Breadcrumb, BreadcrumbAction, BreadcrumbItem, BreadcrumbState, BreadcrumbStyle,
-    get_hovered_index as breadcrumb_hovered_index, handle_breadcrumb_key, handle_breadcrumb_mouse,
+    BreadcrumbTransition, get_hovered_index as breadcrumb_hovered_index, handle_breadcrumb_key,
+    handle_breadcrumb_key_with_style, handle_breadcrumb_mouse, handle_breadcrumb_mouse_with_style,
+};
+pub use busy_overlay::{
+    BusyOverlay, BusyOverlayAction, BusyOverlayState, BusyOverlayStyle, handle_busy_overlay_key,
+    handle_busy_overlay_mouse,
+};
+pub use button::{
+    Button, ButtonAction, ButtonGroup, ButtonGroupAction, ButtonGroupState, ButtonSize,
+    ButtonState, ButtonStyle, ButtonVariant, IconPosition, ToggleGroup, ToggleGroupAction,
+    ToggleGroupState, handle_button_group_key, handle_button_group_mouse, handle_button_key,
+    handle_button_mouse, handle_toggle_group_key, handle_toggle_group_mouse, render_button_tooltip,
+};
+pub use checkbox::{CheckBox, CheckBoxAction, CheckBoxState, CheckBoxStyle, handle_checkbox_key};
+pub use container::{
+    DialogAction, DialogConfig, DialogDirtyFn, DialogFocusTarget, DialogMeasureFn,
+    DialogPopupCloserFn, DialogState, EscapeAction, PopupDialog,
 };
-pub use button::{Button, ButtonAction, ButtonState, ButtonStyle, ButtonVariant};
-pub use checkbox::{CheckBox, CheckBoxAction, CheckBoxState, CheckBoxStyle};
-pub use container::{DialogConfig, DialogFocusTarget, DialogState, PopupDialog};
 pub use context_menu::{
-    ContextMenu, ContextMenuAction, ContextMenuItem, ContextMenuState, ContextMenuStyle,
-    calculate_menu_height, handle_context_menu_key, handle_context_menu_mouse,
-    is_context_menu_trigger,
+    ContextMenu, ContextMenuAction, ContextMenuItem, ContextMenuProvider, ContextMenuState,
+    ContextMenuStyle, TypedContextMenu, TypedContextMenuAction, TypedContextMenuItem,
+    calculate_menu_height, calculate_menu_height_for_items, handle_context_menu_key,
+    handle_context_menu_key_with_provider, handle_context_menu_mouse, is_context_menu_trigger,
 };
 pub use diff_viewer::{
-    DiffData, DiffHunk, DiffLine, DiffLineType, DiffViewMode, DiffViewer, DiffViewerAction,
-    DiffViewerState, DiffViewerStyle, handle_diff_viewer_key, handle_diff_viewer_mouse,
+    DiffData, DiffHunk, DiffLine, DiffLineType, DiffSide, DiffViewMode, DiffViewer,
+    DiffViewerAction, DiffViewerSnapshot, DiffViewerState, DiffViewerStyle, DiffViewerTheme,
+    format_hunk_as_patch, handle_diff_viewer_key, handle_diff_viewer_mouse,
+    handle_diff_viewer_scrollbar_mouse,
+};
+#[cfg(feature = "filesystem")]
+pub use file_explorer::LocalFs;
+pub use file_explorer::{
+    EntryType, FileEntry, FileExplorer, FileExplorerAction, FileExplorerState, FileExplorerStyle,
+    FileSource, SearchMatchMode, handle_file_explorer_mouse, handle_file_explorer_nav_key,
+    handle_file_explorer_search_key, handle_file_explorer_selection_key,
+};
+pub use focus_ring::{FocusRing, apply_focus};
+pub use help_overlay::{
+    HelpOverlay, HelpOverlayState, HelpScope, KeyHint, KeyHintRegistry, handle_help_overlay_key,
+    handle_help_overlay_mouse, is_help_key,
 };
-pub use file_explorer::{EntryType, FileEntry, FileExplorer, FileExplorerState, FileExplorerStyle};
 pub use hotkey_dialog::{
-    CategoryClickRegion, HotkeyCategory, HotkeyClickRegion, HotkeyDialog, HotkeyDialogAction,
-    HotkeyDialogState, HotkeyDialogStyle, HotkeyEntryData, HotkeyFocus, HotkeyProvider,
-    handle_hotkey_dialog_key, handle_hotkey_dialog_mouse, is_close_key as hotkey_is_close_key,
-    is_navigation_key as hotkey_is_navigation_key, render_hotkey_dialog,
-};
-pub use input::{Input, InputAction, InputState, InputStyle};
-pub use list_picker::{ListPicker, ListPickerState, ListPickerStyle, key_hints_footer};
-pub use log_viewer::{LogViewer, LogViewerState, LogViewerStyle, SearchState};
+    CategoryClickRegion, HotkeyCategory, HotkeyCategoryLayout, HotkeyClickRegion, HotkeyDialog,
+    HotkeyDialogAction, HotkeyDialogState, HotkeyDialogStyle, HotkeyEntryData, HotkeyFocus,
+    HotkeyProvider, handle_hotkey_dialog_key, handle_hotkey_dialog_mouse,
+    is_close_key as hotkey_is_close_key, is_navigation_key as hotkey_is_navigation_key,
+    render_hotkey_dialog,
+};
+pub use input::{
+    Input, InputAction, InputMask, InputMode, InputRender, InputState, InputStyle, MaskSlot,
+    handle_input_undo_key, handle_numeric_input_key, handle_numeric_input_mouse,
+};
+pub use interactive_scrollbar::{
+    InteractiveScrollbar, ScrollbarDragState, ScrollbarLayout, ScrollbarPart,
+};
+pub use key_value_editor::{
+    KeyValueCell, KeyValueColumn, KeyValueEditor, KeyValueEditorAction, KeyValueEditorState,
+    KeyValueEditorStyle, KeyValueEntry, KeyValueValidateFn, handle_key_value_editor_key,
+    handle_key_value_editor_mouse,
+};
+pub use list_picker::{
+    ListGroup, ListPicker, ListPickerSnapshot, ListPickerState, ListPickerStyle,
+    handle_list_picker_key, handle_list_picker_scrollbar_mouse, key_hints_footer,
+};
+pub use log_viewer::{
+    HighlightRule, LogColumn, LogColumns, LogLevel, LogRecord, LogViewer, LogViewerAction,
+    LogViewerSnapshot, LogViewerState, LogViewerStyle, SearchState, handle_log_viewer_copy_key,
+    handle_log_viewer_scroll_key, handle_log_viewer_scrollbar_mouse,
+};
 pub use marquee::{
-    MarqueeMode, MarqueeState, MarqueeStyle, MarqueeText, ScrollDir, bounce_marquee,
-    continuous_marquee,
+    MarqueeCycleCallback, MarqueeMode, MarqueeState, MarqueeStyle, MarqueeText, ScrollDir,
+    bounce_marquee, continuous_marquee,
 };
 pub use menu_bar::{
-    Menu, MenuBar, MenuBarAction, MenuBarClickTarget, MenuBarItem, MenuBarState, MenuBarStyle,
+    Menu, MenuBar, MenuBarAction, MenuBarClickTarget, MenuBarItem, MenuBarState, MenuBarStateSave,
+    MenuBarStyle, TypedMenu, TypedMenuBar, TypedMenuBarAction, TypedMenuBarItem,
     calculate_dropdown_height as menu_bar_dropdown_height, calculate_menu_bar_height,
-    handle_menu_bar_key, handle_menu_bar_mouse,
+    handle_menu_bar_key, handle_menu_bar_key_with_style, handle_menu_bar_mouse,
 };
 pub use mouse_pointer::{MousePointer, MousePointerState, MousePointerStyle};
-pub use paragraph_ext::ParagraphExt;
-pub use progress::{Progress, ProgressStyle};
+pub use notification_center::{
+    Notification, NotificationCenter, NotificationCenterAction, NotificationCenterState,
+    NotificationCenterStyle, NotificationId, handle_notification_center_key,
+    handle_notification_center_mouse, severity_icon,
+};
+pub use option_details::{OptionDetails, OptionDetailsFn, filter_option_indices};
+pub use paragraph_ext::{
+    ParagraphAction, ParagraphContent, ParagraphExt, ParagraphExtState, handle_paragraph_ext_key,
+    handle_paragraph_ext_mouse,
+};
+pub use progress::{
+    MiniProgress, MiniProgressKind, Progress, ProgressHistory, ProgressSparkline, ProgressState,
+    ProgressStyle,
+};
 pub use scrollable_content::{
     ScrollableContent, ScrollableContentAction, ScrollableContentState, ScrollableContentStyle,
     handle_scrollable_content_key, handle_scrollable_content_mouse,
 };
 pub use select::{
-    Select, SelectAction, SelectState, SelectStyle, calculate_dropdown_height, handle_select_key,
+    Select, SelectAction, SelectOption, SelectState, SelectStyle, calculate_dropdown_height,
+    calculate_dropdown_height_for_options, calculate_dropdown_height_for_rows, handle_select_key,
     handle_select_mouse,
 };
 pub use spinner::{LabelPosition, Spinner, SpinnerFrames, SpinnerState, SpinnerStyle};
 pub use split_pane::{
-    Orientation, SplitPane, SplitPaneAction, SplitPaneState, SplitPaneStyle, handle_split_pane_key,
-    handle_split_pane_mouse,
+    Orientation, SplitPane, SplitPaneAction, SplitPaneFocus, SplitPaneKeyRoute, SplitPaneKeys,
+    SplitPaneState, SplitPaneStyle, handle_split_pane_double_click, handle_split_pane_focus_key,
+    handle_split_pane_key, handle_split_pane_mouse,
 };
 pub use step_display::{
-    Step, StepDisplay, StepDisplayState, StepDisplayStyle, StepStatus, SubStep,
-    calculate_height as step_display_height,
+    Step, StepDisplay, StepDisplayLayout, StepDisplayState, StepDisplayStyle, StepStatus, SubStep,
+    calculate_height as step_display_height, handle_step_display_key, handle_step_display_mouse,
 };
 pub use tab_view::{
     Tab, TabPosition, TabView, TabViewAction, TabViewState, TabViewStyle, handle_tab_view_key,
     handle_tab_view_mouse,
 };
+pub use text_selection::{
+    SelectionMode, SelectionRow, TextSelection, TextSelectionAction, handle_text_selection_key,
+    render_selection_overlay,
+};
 pub use textarea::{
-    CursorMode, ScrollMode, TabConfig, TextArea, TextAreaAction, TextAreaRender, TextAreaState,
-    TextAreaStyle, WrapMode,
+    CursorMode, ScrollMode, TabConfig, TextArea, TextAreaAction, TextAreaRender, TextAreaSnapshot,
+    TextAreaState, TextAreaStyle, WrapMode, handle_textarea_mouse, handle_textarea_scrollbar_mouse,
+};
+pub use toast::{
+    ScreenFlash, Toast, ToastAttention, ToastPulse, ToastPulseTarget, ToastState, ToastStyle,
 };
-pub use toast::{Toast, ToastState, ToastStyle};
 pub use toast_stack::{
     ToastDismissPolicy, ToastId, ToastItem, ToastOrder, ToastPlacement, ToastStack,
     ToastStackLayout, ToastStackState,
 };
-pub use tree_view::{FlatNode, TreeNode, TreeStyle, TreeView, TreeViewState, get_selected_id};
+pub use tree_view::{
+    ClipboardOp, FlatNode, TreeClipboard, TreeNode, TreeStyle, TreeView, TreeViewAction,
+    TreeViewSnapshot, TreeViewState, get_selected_id, handle_tree_view_clipboard_key,
+    handle_tree_view_nav_key, handle_tree_view_retry_key, handle_tree_view_scrollbar_mouse,
+};
+pub use wizard::{Wizard, WizardAction, WizardFocus, WizardPage, WizardState, WizardStyle};
@@ -0,0 +1,793 @@
+//! Notification center
+//!
+//! A persistent history of notifications with an unread badge count and a
+//! popup panel listing past entries. Toasts vanish after a few seconds; the
+//! notification center keeps them around so users can look back at them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{
+//!     NotificationCenter, NotificationCenterState, ToastStyle,
+//! };
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+//!
+//! let mut state = NotificationCenterState::new();
+//! state.push(ToastStyle::Error, "Build failed", "3 errors in main.rs", 1_000);
+//! state.push(ToastStyle::Success, "Saved", "config.toml", 2_000);
+//! assert_eq!(state.unread_count(), 2);
+//!
+//! // Opening the panel marks everything as read
+//! state.open();
+//! assert_eq!(state.unread_count(), 0);
+//!
+//! let screen = Rect::new(0, 0, 80, 24);
+//! let mut buf = Buffer::empty(screen);
+//! let center = NotificationCenter::new(&state).now_ms(5_000);
+//! let area = center.popup_area(screen);
+//! center.render(area, &mut buf);
+//! ```
+
+use std::collections::VecDeque;
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::toast::ToastStyle;
+use super::toast_stack::ToastItem;
+use crate::state::{OverlayLayer, OverlayManager};
+use crate::traits::ClickRegionRegistry;
+use crate::utils::display::{format_relative, pad_to_width, truncate_to_width};
+
+/// Identifier for a notification entry.
+pub type NotificationId = u64;
+
+/// A single notification history entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// Unique id
+    pub id: NotificationId,
+    /// Severity (reuses the toast styles)
+    pub severity: ToastStyle,
+    /// Short title
+    pub title: String,
+    /// Longer body text
+    pub body: String,
+    /// Time the notification was recorded, in milliseconds
+    pub timestamp_ms: i64,
+    /// Whether the user has seen this entry
+    pub read: bool,
+}
+
+/// Actions emitted by the notification center panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCenterAction {
+    /// An entry was activated; the app can jump to its context
+    Select(NotificationId),
+    /// An entry was dismissed
+    Dismiss(NotificationId),
+    /// All entries were cleared
+    ClearAll,
+    /// The panel was closed
+    Close,
+}
+
+/// State for the notification center.
+#[derive(Debug, Clone)]
+pub struct NotificationCenterState {
+    entries: VecDeque<Notification>,
+    next_id: NotificationId,
+    capacity: usize,
+    /// Whether the popup panel is open
+    pub open: bool,
+    /// Selected entry index (0 = newest)
+    pub selected_index: usize,
+    /// Scroll offset in entries
+    pub scroll: usize,
+}
+
+impl Default for NotificationCenterState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationCenterState {
+    /// Create an empty notification center keeping up to 100 entries.
+    pub fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            next_id: 1,
+            capacity: 100,
+            open: false,
+            selected_index: 0,
+            scroll: 0,
+        }
+    }
+
+    /// Set the maximum number of entries kept in history.
+    ///
+    /// When the cap is exceeded, the oldest entries are evicted.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.enforce_capacity();
+    }
+
+    /// Record a notification and return its id.
+    pub fn push(
+        &mut self,
+        severity: ToastStyle,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        timestamp_ms: i64,
+    ) -> NotificationId {
+        let id = self.next_id;
+        self.next_id = self.next_id.saturating_add(1);
+
+        self.entries.push_back(Notification {
+            id,
+            severity,
+            title: title.into(),
+            body: body.into(),
+            timestamp_ms,
+            // Entries that arrive while the panel is open are seen immediately
+            read: self.open,
+        });
+        self.enforce_capacity();
+        id
+    }
+
+    /// Record a toast from a [`ToastStackState`](super::ToastStackState).
+    ///
+    /// The toast message becomes the title; auto-styled toasts get their
+    /// severity detected from the message.
+    pub fn record_toast(&mut self, toast: &ToastItem) -> NotificationId {
+        let severity = if toast.auto_style {
+            ToastStyle::from_message(&toast.message)
+        } else {
+            toast.style
+        };
+        self.push(severity, toast.message.clone(), "", toast.created_at_ms)
+    }
+
+    /// Number of entries in history.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the history is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of entries not yet seen.
+    pub fn unread_count(&self) -> usize {
+        self.entries.iter().filter(|n| !n.read).count()
+    }
+
+    /// Iterate over entries, newest first.
+    pub fn entries(&self) -> impl Iterator<Item = &Notification> {
+        self.entries.iter().rev()
+    }
+
+    /// Get an entry by id.
+    pub fn get(&self, id: NotificationId) -> Option<&Notification> {
+        self.entries.iter().find(|n| n.id == id)
+    }
+
+    /// Open the panel and mark all entries as read.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.selected_index = 0;
+        self.scroll = 0;
+        self.mark_all_read();
+    }
+
+    /// Close the panel.
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Toggle the panel.
+    pub fn toggle(&mut self) {
+        if self.open {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Check if the panel is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Mark every entry as read.
+    pub fn mark_all_read(&mut self) {
+        for entry in &mut self.entries {
+            entry.read = true;
+        }
+    }
+
+    /// Remove an entry.
+    pub fn dismiss(&mut self, id: NotificationId) -> bool {
+        if let Some(pos) = self.entries.iter().position(|n| n.id == id) {
+            self.entries.remove(pos);
+            self.clamp_selection();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Remove all entries.
+    pub fn clear_all(&mut self) {
+        self.entries.clear();
+        self.selected_index = 0;
+        self.scroll = 0;
+    }
+
+    /// Get the selected entry.
+    pub fn selected(&self) -> Option<&Notification> {
+        self.entries().nth(self.selected_index)
+    }
+
+    /// Select the next (older) entry.
+    pub fn select_next(&mut self) {
+        if self.selected_index + 1 < self.entries.len() {
+            self.selected_index += 1;
+        }
+    }
+
+    /// Select the previous (newer) entry.
+    pub fn select_prev(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    /// Select the newest entry.
+    pub fn select_first(&mut self) {
+        self.selected_index = 0;
+    }
+
+    /// Select the oldest entry.
+    pub fn select_last(&mut self) {
+        self.selected_index = self.entries.len().saturating_sub(1);
+    }
+
+    /// Ensure the selected entry is visible in a viewport of `rows` entries.
+    pub fn ensure_visible(&mut self, rows: usize) {
+        if rows == 0 {
+            return;
+        }
+        if self.selected_index < self.scroll {
+            self.scroll = self.selected_index;
+        } else if self.selected_index >= self.scroll + rows {
+            self.scroll = self.selected_index + 1 - rows;
+        }
+    }
+
+    fn clamp_selection(&mut self) {
+        if self.selected_index >= self.entries.len() {
+            self.selected_index = self.entries.len().saturating_sub(1);
+        }
+        self.scroll = self.scroll.min(self.selected_index);
+    }
+
+    fn enforce_capacity(&mut self) {
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+        self.clamp_selection();
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_notification_center_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑↓/jk", "Move selection"),
+            ("Home/End", "Newest/oldest"),
+            ("Enter", "Open notification"),
+            ("d/Del", "Dismiss"),
+            ("C", "Clear all"),
+            ("Esc", "Close"),
+        ]
+    }
+}
+
+/// Style for the notification center panel.
+#[derive(Debug, Clone)]
+pub struct NotificationCenterStyle {
+    /// Panel border style
+    pub border_style: Style,
+    /// Style for entry titles
+    pub title_style: Style,
+    /// Style for entry bodies
+    pub body_style: Style,
+    /// Style for relative timestamps
+    pub time_style: Style,
+    /// Style for the selected entry
+    pub selected_style: Style,
+    /// Style for the empty-state message
+    pub empty_style: Style,
+    /// Style for the key hint footer
+    pub hint_style: Style,
+    /// Panel width in columns
+    pub width: u16,
+    /// Maximum panel height in rows
+    pub max_height: u16,
+    /// Message shown when there are no entries
+    pub empty_message: &'static str,
+}
+
+impl Default for NotificationCenterStyle {
+    fn default() -> Self {
+        Self {
+            border_style: Style::default().fg(Color::Cyan),
+            title_style: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+            body_style: Style::default().fg(Color::Gray),
+            time_style: Style::default().fg(Color::DarkGray),
+            selected_style: Style::default().bg(Color::DarkGray),
+            empty_style: Style::default().fg(Color::DarkGray),
+            hint_style: Style::default().fg(Color::DarkGray),
+            width: 50,
+            max_height: 20,
+            empty_message: "No notifications",
+        }
+    }
+}
+
+impl From<&crate::theme::Theme> for NotificationCenterStyle {
+    fn from(theme: &crate::theme::Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            border_style: Style::default().fg(p.border_accent),
+            title_style: Style::default().fg(p.text).add_modifier(Modifier::BOLD),
+            body_style: Style::default().fg(p.text_dim),
+            time_style: Style::default().fg(p.text_muted),
+            selected_style: Style::default().bg(p.surface_raised),
+            empty_style: Style::default().fg(p.text_disabled),
+            hint_style: Style::default().fg(p.text_muted),
+            ..Default::default()
+        }
+    }
+}
+
+/// Icon for a notification severity.
+pub fn severity_icon(severity: ToastStyle) -> &'static str {
+    match severity {
+        ToastStyle::Info => "ℹ",
+        ToastStyle::Success => "✓",
+        ToastStyle::Warning => "⚠",
+        ToastStyle::Error => "✗",
+    }
+}
+
+/// Notification center popup panel.
+pub struct NotificationCenter<'a> {
+    state: &'a NotificationCenterState,
+    style: NotificationCenterStyle,
+    now_ms: i64,
+}
+
+impl<'a> NotificationCenter<'a> {
+    /// Create a new panel for the given state.
+    pub fn new(state: &'a NotificationCenterState) -> Self {
+        Self {
+            state,
+            style: NotificationCenterStyle::default(),
+            now_ms: 0,
+        }
+    }
+
+    /// Set the current time used for relative timestamps.
+    pub fn now_ms(mut self, now_ms: i64) -> Self {
+        self.now_ms = now_ms;
+        self
+    }
+
+    /// Set the style.
+    pub fn style(mut self, style: NotificationCenterStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme to derive the style.
+    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
+        self.style(NotificationCenterStyle::from(theme))
+    }
+
+    /// Calculate the panel area anchored to the top-right of `screen`.
+    pub fn popup_area(&self, screen: Rect) -> Rect {
+        let width = self.style.width.min(screen.width);
+        // Two rows per entry plus borders and the hint line
+        let wanted = (self.state.len().max(1) as u16)
+            .saturating_mul(2)
+            .saturating_add(3);
+        let height = wanted.min(self.style.max_height).min(screen.height);
+        Rect::new(
+            screen.x + screen.width.saturating_sub(width),
+            screen.y + 1.min(screen.height.saturating_sub(height)),
+            width,
+            height,
+        )
+    }
+
+    /// Number of entries that fit in the given panel area.
+    pub fn visible_rows(area: Rect) -> usize {
+        // Borders + hint line, two rows per entry
+        (area.height.saturating_sub(3) / 2) as usize
+    }
+
+    /// Render the panel and return click regions for each visible entry.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer) -> Vec<(Rect, NotificationId)> {
+        let mut regions = Vec::new();
+        if area.width < 3 || area.height < 3 {
+            return regions;
+        }
+
+        Clear.render(area, buf);
+
+        let unread = self.state.unread_count();
+        let title = if unread > 0 {
+            format!(" Notifications ({} unread) ", unread)
+        } else {
+            format!(" Notifications ({}) ", self.state.len())
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.style.border_style)
+            .title(title);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.height == 0 {
+            return regions;
+        }
+
+        let list_height = inner.height.saturating_sub(1);
+        let hint_area = Rect::new(inner.x, inner.y + list_height, inner.width, 1);
+        Paragraph::new(Span::styled(
+            "Enter: open  d: dismiss  C: clear all  Esc: close",
+            self.style.hint_style,
+        ))
+        .render(hint_area, buf);
+
+        if self.state.is_empty() {
+            Paragraph::new(Span::styled(
+                self.style.empty_message,
+                self.style.empty_style,
+            ))
+            .render(Rect::new(inner.x, inner.y, inner.width, 1), buf);
+            return regions;
+        }
+
+        let width = inner.width as usize;
+        let rows = (list_height / 2) as usize;
+        for (row, (idx, entry)) in self
+            .state
+            .entries()
+            .enumerate()
+            .skip(self.state.scroll)
+            .take(rows)
+            .enumerate()
+        {
+            let y = inner.y + (row as u16) * 2;
+            let entry_area = Rect::new(inner.x, y, inner.width, 2);
+            let is_selected = idx == self.state.selected_index;
+
+            let icon = format!("{} ", severity_icon(entry.severity));
+            let time = format!(
+                " {}",
+                format_relative(self.now_ms.saturating_sub(entry.timestamp_ms))
+            );
+            let title_width = width.saturating_sub(icon.width() + time.width());
+            let title = pad_to_width(&truncate_to_width(&entry.title, title_width), title_width);
+            let body = pad_to_width(
+                &truncate_to_width(&entry.body, width.saturating_sub(2)),
+                width.saturating_sub(2),
+            );
+
+            let lines = vec![
+                Line::from(vec![
+                    Span::styled(icon, Style::default().fg(entry.severity.border_color())),
+                    Span::styled(title, self.style.title_style),
+                    Span::styled(time, self.style.time_style),
+                ]),
+                Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(body, self.style.body_style),
+                ]),
+            ];
+
+            let mut paragraph = Paragraph::new(lines);
+            if is_selected {
+                paragraph = paragraph.style(self.style.selected_style);
+            }
+            paragraph.render(entry_area, buf);
+            regions.push((entry_area, entry.id));
+        }
+
+        regions
+    }
+
+    /// Render the panel into an [`OverlayManager`] on the
+    /// [`OverlayLayer::Dialog`] layer.
+    ///
+    /// Returns the same click regions as [`render_stateful`](Self::render_stateful).
+    pub fn render_into_overlays(
+        self,
+        area: Rect,
+        overlays: &mut OverlayManager<'_>,
+    ) -> Vec<(Rect, NotificationId)> {
+        overlays.capture(OverlayLayer::Dialog, area, |buf| {
+            self.render_stateful(area, buf)
+        })
+    }
+
+    /// Render and register entry click regions.
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<NotificationId>,
+    ) {
+        for (rect, id) in self.render_stateful(area, buf) {
+            registry.register(rect, id);
+        }
+    }
+}
+
+impl Widget for NotificationCenter<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let _ = self.render_stateful(area, buf);
+    }
+}
+
+/// Handle keyboard events for an open notification center.
+///
+/// `visible_rows` is the number of entries visible in the panel (see
+/// [`NotificationCenter::visible_rows`]). Returns `None` if the panel is
+/// closed or the key was not handled.
+pub fn handle_notification_center_key(
+    state: &mut NotificationCenterState,
+    key: &KeyEvent,
+    visible_rows: usize,
+) -> Option<NotificationCenterAction> {
+    if !state.open {
+        return None;
+    }
+
+    match key.code {
+        KeyCode::Esc => {
+            state.close();
+            Some(NotificationCenterAction::Close)
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            state.select_prev();
+            state.ensure_visible(visible_rows);
+            None
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            state.select_next();
+            state.ensure_visible(visible_rows);
+            None
+        }
+        KeyCode::Home => {
+            state.select_first();
+            state.ensure_visible(visible_rows);
+            None
+        }
+        KeyCode::End => {
+            state.select_last();
+            state.ensure_visible(visible_rows);
+            None
+        }
+        KeyCode::Enter => state
+            .selected()
+            .map(|entry| NotificationCenterAction::Select(entry.id)),
+        KeyCode::Delete | KeyCode::Char('d') => {
+            let id = state.selected()?.id;
+            state.dismiss(id);
+            state.ensure_visible(visible_rows);
+            Some(NotificationCenterAction::Dismiss(id))
+        }
+        KeyCode::Char('C') => {
+            state.clear_all();
+            Some(NotificationCenterAction::ClearAll)
+        }
+        _ => None,
+    }
+}
+
+/// Handle mouse events for an open notification center.
+///
+/// Clicking an entry selects it and emits `Select`; the scroll wheel moves
+/// the selection.
+pub fn handle_notification_center_mouse(
+    state: &mut NotificationCenterState,
+    registry: &ClickRegionRegistry<NotificationId>,
+    mouse: &MouseEvent,
+    visible_rows: usize,
+) -> Option<NotificationCenterAction> {
+    if !state.open {
+        return None;
+    }
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let id = *registry.handle_click(mouse.column, mouse.row)?;
+            let idx = state.entries().position(|n| n.id == id);
+            if let Some(idx) = idx {
+                state.selected_index = idx;
+            }
+            Some(NotificationCenterAction::Select(id))
+        }
+        MouseEventKind::ScrollUp => {
+            state.select_prev();
+            state.ensure_visible(visible_rows);
+            None
+        }
+        MouseEventKind::ScrollDown => {
+            state.select_next();
+            state.ensure_visible(visible_rows);
+            None
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::ToastStackState;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn sample() -> NotificationCenterState {
+        let mut state = NotificationCenterState::new();
+        state.push(ToastStyle::Info, "First", "", 1_000);
+        state.push(ToastStyle::Warning, "Second", "", 2_000);
+        state.push(ToastStyle::Error, "Third", "details", 3_000);
+        state
+    }
+
+    #[test]
+    fn test_push_and_unread() {
+        let mut state = sample();
+        assert_eq!(state.len(), 3);
+        assert_eq!(state.unread_count(), 3);
+
+        state.open();
+        assert_eq!(state.unread_count(), 0);
+
+        // Arrives while open: already read
+        state.push(ToastStyle::Info, "Fourth", "", 4_000);
+        assert_eq!(state.unread_count(), 0);
+
+        state.close();
+        state.push(ToastStyle::Info, "Fifth", "", 5_000);
+        assert_eq!(state.unread_count(), 1);
+    }
+
+    #[test]
+    fn test_capacity_evicts_oldest() {
+        let mut state = sample();
+        state.set_capacity(2);
+        let titles: Vec<_> = state.entries().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, vec!["Third", "Second"]);
+    }
+
+    #[test]
+    fn test_record_toast() {
+        let mut toasts = ToastStackState::new();
+        toasts.push_manual("Error: disk full");
+        let item = toasts.items().next().unwrap().clone();
+
+        let mut state = NotificationCenterState::new();
+        let id = state.record_toast(&item);
+        let entry = state.get(id).unwrap();
+        assert_eq!(entry.severity, ToastStyle::Error);
+        assert_eq!(entry.title, "Error: disk full");
+    }
+
+    #[test]
+    fn test_keyboard_select_dismiss_clear() {
+        let mut state = sample();
+        assert_eq!(
+            handle_notification_center_key(&mut state, &key(KeyCode::Down), 5),
+            None
+        );
+
+        state.open();
+        let newest = state.selected().unwrap().id;
+        assert_eq!(
+            handle_notification_center_key(&mut state, &key(KeyCode::Enter), 5),
+            Some(NotificationCenterAction::Select(newest))
+        );
+
+        handle_notification_center_key(&mut state, &key(KeyCode::Down), 5);
+        assert_eq!(state.selected().unwrap().title, "Second");
+
+        let second = state.selected().unwrap().id;
+        assert_eq!(
+            handle_notification_center_key(&mut state, &key(KeyCode::Char('d')), 5),
+            Some(NotificationCenterAction::Dismiss(second))
+        );
+        assert_eq!(state.len(), 2);
+        assert_eq!(state.selected().unwrap().title, "First");
+
+        assert_eq!(
+            handle_notification_center_key(&mut state, &key(KeyCode::Char('C')), 5),
+            Some(NotificationCenterAction::ClearAll)
+        );
+        assert!(state.is_empty());
+
+        assert_eq!(
+            handle_notification_center_key(&mut state, &key(KeyCode::Esc), 5),
+            Some(NotificationCenterAction::Close)
+        );
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn test_scrolling_keeps_selection_visible() {
+        let mut state = NotificationCenterState::new();
+        for i in 0..10 {
+            state.push(ToastStyle::Info, format!("N{}", i), "", i);
+        }
+        state.open();
+        for _ in 0..5 {
+            handle_notification_center_key(&mut state, &key(KeyCode::Down), 3);
+        }
+        assert_eq!(state.selected_index, 5);
+        assert_eq!(state.scroll, 3);
+
+        handle_notification_center_key(&mut state, &key(KeyCode::Home), 3);
+        assert_eq!(state.scroll, 0);
+    }
+
+    #[test]
+    fn test_render_and_click() {
+        let mut state = sample();
+        state.open();
+
+        let screen = Rect::new(0, 0, 80, 24);
+        let mut buf = Buffer::empty(screen);
+        let center = NotificationCenter::new(&state).now_ms(63_000);
+        let area = center.popup_area(screen);
+        let mut registry = ClickRegionRegistry::new();
+        center.render_with_registry(area, &mut buf, &mut registry);
+        assert_eq!(registry.len(), 3);
+
+        let first_row: String = (area.x..area.right())
+            .map(|x| buf[(x, area.y + 1)].symbol().to_string())
+            .collect();
+        assert!(first_row.contains("Third"));
+        assert!(first_row.contains("1m ago"));
+
+        let second = registry.regions()[1].area;
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: second.x,
+            row: second.y,
+            modifiers: KeyModifiers::NONE,
+        };
+        let id = state.entries().nth(1).unwrap().id;
+        assert_eq!(
+            handle_notification_center_mouse(&mut state, &registry, &click, 5),
+            Some(NotificationCenterAction::Select(id))
+        );
+        assert_eq!(state.selected_index, 1);
+    }
+}
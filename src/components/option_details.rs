@@ -0,0 +1,291 @@
+//! Option details - descriptions and hints for list-style options
+//!
+//! [`Select`](super::Select) dropdowns and [`ListPicker`](super::ListPicker)
+//! rows can show a secondary description line and a right-aligned hint
+//! (such as "default", a shortcut, or a version) for each option.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{OptionDetails, filter_option_indices};
+//!
+//! let options = vec!["stable", "nightly"];
+//! let details = |opt: &&str| match *opt {
+//!     "stable" => OptionDetails::new().description("Tested releases").hint("default"),
+//!     _ => OptionDetails::new().description("Built every night"),
+//! };
+//!
+//! assert_eq!(filter_option_indices(&options, |o| o.to_string(), details, "night"), vec![1]);
+//! assert_eq!(filter_option_indices(&options, |o| o.to_string(), details, "tested"), vec![0]);
+//! ```
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::utils::display::truncate_to_width;
+
+/// Secondary text shown alongside an option's label.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptionDetails {
+    /// Description shown on a second line, or appended when space is short.
+    pub description: Option<String>,
+    /// Short right-aligned hint.
+    pub hint: Option<String>,
+}
+
+impl OptionDetails {
+    /// Create empty details.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the hint.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Check whether `label` or the description contains `query` (case-insensitive).
+    ///
+    /// An empty query matches everything.
+    pub fn matches(&self, label: &str, query: &str) -> bool {
+        query.is_empty()
+            || !find_matches(label, query).is_empty()
+            || self
+                .description
+                .as_deref()
+                .is_some_and(|desc| !find_matches(desc, query).is_empty())
+    }
+}
+
+/// Function mapping an option to its details.
+pub type OptionDetailsFn<'a, T> = Box<dyn Fn(&T) -> OptionDetails + 'a>;
+
+/// Indices of the options whose label or description matches `query`.
+pub fn filter_option_indices<T>(
+    options: &[T],
+    label: impl Fn(&T) -> String,
+    details: impl Fn(&T) -> OptionDetails,
+    query: &str,
+) -> Vec<usize> {
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, opt)| details(opt).matches(&label(opt), query))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Styles used when laying out an option row.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OptionRowStyles {
+    pub label: Style,
+    pub description: Style,
+    pub hint: Style,
+    pub matched: Style,
+}
+
+/// Char ranges of case-insensitive occurrences of `query` in `text`.
+pub(crate) fn find_matches(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let text: Vec<char> = text.chars().map(fold).collect();
+    let query: Vec<char> = query.chars().map(fold).collect();
+    if query.is_empty() || query.len() > text.len() {
+        return Vec::new();
+    }
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start + query.len() <= text.len() {
+        if text[start..start + query.len()] == query[..] {
+            ranges.push((start, start + query.len()));
+            start += query.len();
+        } else {
+            start += 1;
+        }
+    }
+    ranges
+}
+
+/// Split `text` into spans, styling occurrences of `query` with `matched`.
+pub(crate) fn highlight_spans(
+    text: &str,
+    query: &str,
+    base: Style,
+    matched: Style,
+) -> Vec<Span<'static>> {
+    let ranges = find_matches(text, query);
+    if ranges.is_empty() {
+        return vec![Span::styled(text.to_string(), base)];
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for (start, end) in ranges {
+        if start > pos {
+            spans.push(Span::styled(
+                chars[pos..start].iter().collect::<String>(),
+                base,
+            ));
+        }
+        spans.push(Span::styled(
+            chars[start..end].iter().collect::<String>(),
+            base.patch(matched),
+        ));
+        pos = end;
+    }
+    if pos < chars.len() {
+        spans.push(Span::styled(chars[pos..].iter().collect::<String>(), base));
+    }
+    spans
+}
+
+/// Lay out the first row of an option within `width` columns.
+///
+/// Space is given up in order: the inline description is truncated, then
+/// the hint is dropped, and only then is the label truncated.
+pub(crate) fn option_row(
+    label: &str,
+    inline_description: Option<&str>,
+    hint: Option<&str>,
+    width: usize,
+    query: &str,
+    styles: OptionRowStyles,
+) -> Vec<Span<'static>> {
+    let label_width = label.chars().count();
+    let hint_width = hint.map_or(0, |h| h.chars().count() + 1);
+
+    let (label_text, show_hint) = if label_width + hint_width <= width {
+        (label.to_string(), hint.is_some())
+    } else if label_width <= width {
+        (label.to_string(), false)
+    } else {
+        (truncate_to_width(label, width), false)
+    };
+
+    let mut spans = highlight_spans(&label_text, query, styles.label, styles.matched);
+    let mut used = label_text.chars().count();
+    let reserved = if show_hint { hint_width } else { 0 };
+
+    if let Some(desc) = inline_description {
+        let room = width.saturating_sub(used + reserved);
+        // Need room for the separator plus a few characters to be useful
+        if room > 4 {
+            let text = truncate_to_width(desc, room - 3);
+            used += 3 + text.chars().count();
+            spans.push(Span::styled(" · ", styles.description));
+            spans.extend(highlight_spans(
+                &text,
+                query,
+                styles.description,
+                styles.matched,
+            ));
+        }
+    }
+
+    if let Some(hint) = hint.filter(|_| show_hint) {
+        let gap = width.saturating_sub(used + hint_width - 1);
+        spans.push(Span::raw(" ".repeat(gap)));
+        spans.push(Span::styled(hint.to_string(), styles.hint));
+    }
+
+    spans
+}
+
+/// Lay out the description row of an option, indented by `indent` columns.
+pub(crate) fn description_row(
+    description: &str,
+    indent: usize,
+    width: usize,
+    query: &str,
+    styles: OptionRowStyles,
+) -> Line<'static> {
+    let text = truncate_to_width(description, width.saturating_sub(indent));
+    let mut spans = vec![Span::raw(" ".repeat(indent))];
+    spans.extend(highlight_spans(
+        &text,
+        query,
+        styles.description,
+        styles.matched,
+    ));
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Modifier};
+
+    fn styles() -> OptionRowStyles {
+        OptionRowStyles {
+            label: Style::default(),
+            description: Style::default().fg(Color::DarkGray),
+            hint: Style::default().fg(Color::Gray),
+            matched: Style::default().add_modifier(Modifier::UNDERLINED),
+        }
+    }
+
+    fn text(spans: &[Span]) -> String {
+        spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_matches_label_and_description() {
+        let details = OptionDetails::new().description("Fast Builds");
+        assert!(details.matches("Release", "rel"));
+        assert!(details.matches("Release", "FAST"));
+        assert!(!details.matches("Release", "debug"));
+        assert!(details.matches("Release", ""));
+    }
+
+    #[test]
+    fn test_highlight_spans_case_insensitive() {
+        let spans = highlight_spans("Nightly night", "NIGHT", Style::default(), styles().matched);
+        assert_eq!(text(&spans), "Nightly night");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].content, "Night");
+        assert_eq!(spans[2].content, "night");
+        assert!(spans[2].style.add_modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_option_row_right_aligns_hint() {
+        let spans = option_row("Stable", None, Some("default"), 20, "", styles());
+        assert_eq!(text(&spans), "Stable       default");
+    }
+
+    #[test]
+    fn test_option_row_drops_hint_before_truncating_label() {
+        let spans = option_row("Stable", None, Some("default"), 10, "", styles());
+        assert_eq!(text(&spans), "Stable");
+
+        let spans = option_row("Stable channel", None, Some("default"), 10, "", styles());
+        assert_eq!(text(&spans), "Stable ...");
+    }
+
+    #[test]
+    fn test_option_row_inline_description() {
+        let spans = option_row("Stable", Some("Tested"), Some("v1"), 30, "", styles());
+        assert_eq!(text(&spans), "Stable · Tested             v1");
+
+        // Description is truncated before the hint is dropped
+        let spans = option_row(
+            "Stable",
+            Some("Tested releases"),
+            Some("v1"),
+            18,
+            "",
+            styles(),
+        );
+        assert_eq!(text(&spans), "Stable · Tes... v1");
+    }
+}
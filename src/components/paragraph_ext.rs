@@ -26,8 +26,414 @@
 //! let mut buf = Buffer::empty(area);
 //! widget.render(area, &mut buf);
 //! ```
+//!
+//! # Anchors
+//!
+//! Build content with inline anchors to make parts of the text clickable.
+//! [`ParagraphExt::render_stateful`] returns one click region per visual line
+//! an anchor occupies, and Tab/Enter cycle and activate anchors by keyboard.
+//!
+//! ```rust
+//! use ratatui_interact::components::{ParagraphAction, ParagraphExt, ParagraphExtState};
+//! use ratatui::{buffer::Buffer, layout::Rect};
+//!
+//! let widget = ParagraphExt::text("Build failed. ")
+//!     .anchor("details", "See details")
+//!     .text(" for the full log.")
+//!     .build();
+//!
+//! let area = Rect::new(0, 0, 40, 3);
+//! let mut buf = Buffer::empty(area);
+//! let mut state = ParagraphExtState::new();
+//! let regions = widget.render_stateful(area, &mut buf, &mut state);
+//!
+//! assert_eq!(regions.len(), 1);
+//! assert_eq!(regions[0].data, ParagraphAction::AnchorActivated("details".into()));
+//! ```
+//!
+//! # Navigation
+//!
+//! With [`ParagraphExt::render_stateful`], scrolling lives in
+//! [`ParagraphExtState`] and counts wrapped lines. [`ParagraphExt::headings`]
+//! enables `n`/`p` heading jumps, `/` opens a search bar, and
+//! [`ParagraphExtState::scroll_percent`] reports the position for status bars.
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use super::log_viewer::SearchState;
+use crate::traits::{ClickRegion, EventResult};
+
+/// Actions emitted by an anchored paragraph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParagraphAction {
+    /// An anchor was clicked or activated with Enter.
+    AnchorActivated(String),
+}
+
+/// State for keyboard navigation between anchors, scrolling, headings and search.
+#[derive(Debug, Clone, Default)]
+pub struct ParagraphExtState {
+    /// Whether the paragraph has focus.
+    pub focused: bool,
+    /// Index of the highlighted anchor.
+    pub highlighted: Option<usize>,
+    /// Anchor ids in content order (set during render).
+    pub anchor_ids: Vec<String>,
+    /// Scroll offset in wrapped lines (used by [`ParagraphExt::render_stateful`]).
+    pub scroll: usize,
+    /// Search state.
+    pub search: SearchState,
+    /// Logical line and start character of each wrapped line (set during render).
+    rows: Vec<(usize, usize)>,
+    /// Plain text of each logical line (set during render).
+    line_text: Vec<String>,
+    /// First wrapped line of each heading (set during render).
+    heading_rows: Vec<usize>,
+    /// Number of wrapped lines visible at once (set during render).
+    viewport_height: usize,
+    /// Area the paragraph was last rendered to.
+    area: Rect,
+}
+
+impl ParagraphExtState {
+    /// Create a new paragraph state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Id of the highlighted anchor.
+    pub fn highlighted_id(&self) -> Option<&str> {
+        self.highlighted
+            .and_then(|idx| self.anchor_ids.get(idx))
+            .map(String::as_str)
+    }
+
+    /// Highlight the next anchor, wrapping to the first.
+    pub fn next_anchor(&mut self) {
+        let count = self.anchor_ids.len();
+        if count > 0 {
+            self.highlighted = Some(self.highlighted.map_or(0, |idx| (idx + 1) % count));
+        }
+    }
+
+    /// Highlight the previous anchor, wrapping to the last.
+    pub fn prev_anchor(&mut self) {
+        let count = self.anchor_ids.len();
+        if count > 0 {
+            self.highlighted = Some(
+                self.highlighted
+                    .map_or(count - 1, |idx| (idx + count - 1) % count),
+            );
+        }
+    }
+
+    /// Total number of wrapped lines as of the last render.
+    pub fn total_lines(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Largest valid scroll offset.
+    pub fn max_scroll(&self) -> usize {
+        self.rows.len().saturating_sub(self.viewport_height)
+    }
+
+    /// Scroll position as a percentage (0-100), for status bars.
+    ///
+    /// Returns 100 when all content fits in the viewport.
+    pub fn scroll_percent(&self) -> u16 {
+        let max = self.max_scroll();
+        (self.scroll.min(max) * 100)
+            .checked_div(max)
+            .map_or(100, |percent| percent as u16)
+    }
+
+    /// Scroll up by `lines` wrapped lines.
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Scroll down by `lines` wrapped lines.
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = (self.scroll + lines).min(self.max_scroll());
+    }
+
+    /// Scroll up by one page.
+    pub fn page_up(&mut self) {
+        self.scroll_up(self.viewport_height.max(1));
+    }
+
+    /// Scroll down by one page.
+    pub fn page_down(&mut self) {
+        self.scroll_down(self.viewport_height.max(1));
+    }
+
+    /// Go to top.
+    pub fn go_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Go to bottom.
+    pub fn go_to_bottom(&mut self) {
+        self.scroll = self.max_scroll();
+    }
+
+    /// Number of headings as of the last render.
+    pub fn heading_count(&self) -> usize {
+        self.heading_rows.len()
+    }
+
+    /// Index of the last heading at or above the top of the viewport.
+    pub fn current_heading(&self) -> Option<usize> {
+        self.heading_rows
+            .iter()
+            .rposition(|&row| row <= self.scroll)
+    }
+
+    /// Scroll so heading `idx` is at the top. Returns false if out of range.
+    pub fn go_to_heading(&mut self, idx: usize) -> bool {
+        match self.heading_rows.get(idx) {
+            Some(&row) => {
+                self.scroll = row.min(self.max_scroll());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Jump to the first heading below the top of the viewport.
+    pub fn next_heading(&mut self) -> bool {
+        match self.heading_rows.iter().position(|&row| row > self.scroll) {
+            Some(idx) if self.scroll < self.max_scroll() => self.go_to_heading(idx),
+            _ => false,
+        }
+    }
+
+    /// Jump to the last heading above the top of the viewport.
+    pub fn prev_heading(&mut self) -> bool {
+        match self.heading_rows.iter().rposition(|&row| row < self.scroll) {
+            Some(idx) => self.go_to_heading(idx),
+            None => false,
+        }
+    }
+
+    /// Start search mode
+    pub fn start_search(&mut self) {
+        self.search.active = true;
+        self.search.query.clear();
+        self.search.matches.clear();
+        self.search.current_match = 0;
+    }
+
+    /// Cancel search mode
+    pub fn cancel_search(&mut self) {
+        self.search.active = false;
+    }
 
-use ratatui::{buffer::Buffer, layout::Rect, style::Style, text::Line, widgets::Widget};
+    /// Update search with new query, jumping to the first match.
+    ///
+    /// Matching is case-insensitive against the text rendered last.
+    pub fn update_search(&mut self) {
+        self.search.matches.clear();
+        self.search.current_match = 0;
+
+        let query: Vec<char> = self.search.query.chars().collect();
+        if query.is_empty() {
+            return;
+        }
+
+        for (idx, line) in self.line_text.iter().enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            if !find_occurrences(&chars, &query).is_empty() {
+                self.search.matches.push(idx);
+            }
+        }
+
+        self.scroll_to_current_match();
+    }
+
+    /// Go to next search match
+    pub fn next_match(&mut self) {
+        if self.search.matches.is_empty() {
+            return;
+        }
+        self.search.current_match = (self.search.current_match + 1) % self.search.matches.len();
+        self.scroll_to_current_match();
+    }
+
+    /// Go to previous search match
+    pub fn prev_match(&mut self) {
+        if self.search.matches.is_empty() {
+            return;
+        }
+        if self.search.current_match == 0 {
+            self.search.current_match = self.search.matches.len() - 1;
+        } else {
+            self.search.current_match -= 1;
+        }
+        self.scroll_to_current_match();
+    }
+
+    /// Scroll to the wrapped line holding the first occurrence in the current match.
+    fn scroll_to_current_match(&mut self) {
+        let Some(&line) = self.search.matches.get(self.search.current_match) else {
+            return;
+        };
+        let query: Vec<char> = self.search.query.chars().collect();
+        let chars: Vec<char> = self.line_text[line].chars().collect();
+        let offset = find_occurrences(&chars, &query)
+            .first()
+            .copied()
+            .unwrap_or(0);
+        if let Some(row) = self
+            .rows
+            .iter()
+            .rposition(|&(l, start)| l == line && start <= offset)
+        {
+            self.scroll = row.min(self.max_scroll());
+        }
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_paragraph_ext_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("Tab/Shift+Tab", "Next/previous link"),
+            ("Enter", "Open link"),
+            ("Up/Down", "Scroll"),
+            ("PgUp/PgDn", "Scroll page"),
+            ("Home/End", "Top/bottom"),
+            ("n/p", "Next/previous heading"),
+            ("/", "Search"),
+            ("n/N", "Next/previous match"),
+        ]
+    }
+}
+
+/// Start offsets of case-insensitive occurrences of `query` in `text`.
+fn find_occurrences(text: &[char], query: &[char]) -> Vec<usize> {
+    if query.is_empty() || query.len() > text.len() {
+        return Vec::new();
+    }
+    (0..=text.len() - query.len())
+        .filter(|&start| {
+            text[start..start + query.len()]
+                .iter()
+                .zip(query)
+                .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+        })
+        .collect()
+}
+
+/// An anchor's character range within one logical line.
+#[derive(Debug, Clone)]
+struct Anchor {
+    id: String,
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+/// A wrapped visual line and where it starts in its logical line.
+struct WrappedLine {
+    line: usize,
+    start: usize,
+    chars: Vec<(char, Style)>,
+}
+
+/// Builder for paragraph content with inline anchors.
+///
+/// Created by [`ParagraphExt::text`]; finish with [`build`](Self::build).
+#[derive(Debug, Clone)]
+pub struct ParagraphContent {
+    lines: Vec<Line<'static>>,
+    anchors: Vec<Anchor>,
+}
+
+impl Default for ParagraphContent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParagraphContent {
+    /// Create empty content.
+    pub fn new() -> Self {
+        Self {
+            lines: vec![Line::default()],
+            anchors: Vec::new(),
+        }
+    }
+
+    /// Append plain text. Newlines start a new line.
+    pub fn text(self, text: impl AsRef<str>) -> Self {
+        self.styled(text, Style::default())
+    }
+
+    /// Append styled text. Newlines start a new line.
+    pub fn styled(mut self, text: impl AsRef<str>, style: Style) -> Self {
+        for (idx, part) in text.as_ref().split('\n').enumerate() {
+            if idx > 0 {
+                self.lines.push(Line::default());
+            }
+            if !part.is_empty() {
+                self.current_line()
+                    .spans
+                    .push(Span::styled(part.to_string(), style));
+            }
+        }
+        self
+    }
+
+    /// Append an anchor labelled `label` that activates `id`.
+    pub fn anchor(mut self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        let label = label.into();
+        let line = self.lines.len() - 1;
+        let start = self
+            .current_line()
+            .spans
+            .iter()
+            .map(|span| span.content.chars().count())
+            .sum::<usize>();
+        let end = start + label.chars().count();
+        self.current_line().spans.push(Span::raw(label));
+        self.anchors.push(Anchor {
+            id: id.into(),
+            line,
+            start,
+            end,
+        });
+        self
+    }
+
+    /// Start a new line.
+    pub fn newline(mut self) -> Self {
+        self.lines.push(Line::default());
+        self
+    }
+
+    /// Build the paragraph widget.
+    pub fn build(self) -> ParagraphExt<'static> {
+        self.into()
+    }
+
+    fn current_line(&mut self) -> &mut Line<'static> {
+        self.lines.last_mut().expect("content always has a line")
+    }
+}
+
+impl From<ParagraphContent> for ParagraphExt<'static> {
+    fn from(content: ParagraphContent) -> Self {
+        let mut paragraph = ParagraphExt::new(content.lines);
+        paragraph.anchors = content.anchors;
+        paragraph
+    }
+}
 
 /// Extended paragraph widget with word-wrapping and scrolling.
 ///
@@ -36,10 +442,18 @@ use ratatui::{buffer::Buffer, layout::Rect, style::Style, text::Line, widgets::W
 /// - Provides fine-grained control over word wrapping
 /// - Preserves per-character styling through wrapping
 /// - Supports vertical scrolling
+/// - Supports clickable inline anchors (see [`ParagraphExt::text`])
+/// - Supports heading navigation and search via [`ParagraphExt::render_stateful`]
 pub struct ParagraphExt<'a> {
     lines: Vec<Line<'a>>,
     scroll: u16,
     width: Option<u16>,
+    anchors: Vec<Anchor>,
+    headings: Vec<usize>,
+    link_style: Style,
+    highlighted_link_style: Style,
+    match_style: Style,
+    current_match_style: Style,
 }
 
 impl<'a> ParagraphExt<'a> {
@@ -49,9 +463,27 @@ impl<'a> ParagraphExt<'a> {
             lines,
             scroll: 0,
             width: None,
+            anchors: Vec::new(),
+            headings: Vec::new(),
+            link_style: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::UNDERLINED),
+            highlighted_link_style: Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::UNDERLINED),
+            match_style: Style::default()
+                .bg(Color::Rgb(60, 60, 30))
+                .fg(Color::Yellow),
+            current_match_style: Style::default().bg(Color::Yellow).fg(Color::Black),
         }
     }
 
+    /// Start building content with inline anchors.
+    pub fn text(text: impl AsRef<str>) -> ParagraphContent {
+        ParagraphContent::new().text(text)
+    }
+
     /// Set the vertical scroll offset (number of wrapped lines to skip).
     pub fn scroll(mut self, scroll: u16) -> Self {
         self.scroll = scroll;
@@ -66,10 +498,50 @@ impl<'a> ParagraphExt<'a> {
         self
     }
 
+    /// Set the style for anchors.
+    pub fn link_style(mut self, style: Style) -> Self {
+        self.link_style = style;
+        self
+    }
+
+    /// Set the style for the highlighted anchor while focused.
+    pub fn highlighted_link_style(mut self, style: Style) -> Self {
+        self.highlighted_link_style = style;
+        self
+    }
+
+    /// Set the logical line indices of headings for heading navigation.
+    ///
+    /// Use the indices from [`render_markdown_with_headings`](crate::utils::render_markdown_with_headings)
+    /// or any caller-provided list of line anchors.
+    pub fn headings(mut self, headings: Vec<usize>) -> Self {
+        self.headings = headings;
+        self
+    }
+
+    /// Set the style for search matches.
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Set the style for the current search match.
+    pub fn current_match_style(mut self, style: Style) -> Self {
+        self.current_match_style = style;
+        self
+    }
+
     /// Word-wrap lines and return wrapped line content.
     ///
-    /// Each wrapped line is a vector of (char, Style) tuples.
-    fn wrap_lines(&self, width: u16) -> Vec<Vec<(char, Style)>> {
+    /// Anchor text is styled with the link style, or the highlighted link
+    /// style for the anchor at index `highlighted`. Occurrences of the
+    /// `search` query on matching lines are styled on top.
+    fn wrap_lines(
+        &self,
+        width: u16,
+        highlighted: Option<usize>,
+        search: Option<&SearchState>,
+    ) -> Vec<WrappedLine> {
         let width = width as usize;
         if width == 0 {
             return vec![];
@@ -77,7 +549,7 @@ impl<'a> ParagraphExt<'a> {
 
         let mut wrapped = Vec::new();
 
-        for line in &self.lines {
+        for (line_idx, line) in self.lines.iter().enumerate() {
             // Flatten spans to chars with styles
             let mut chars: Vec<(char, Style)> = Vec::new();
             for span in &line.spans {
@@ -86,8 +558,44 @@ impl<'a> ParagraphExt<'a> {
                 }
             }
 
+            for (anchor_idx, anchor) in self.anchors.iter().enumerate() {
+                if anchor.line != line_idx {
+                    continue;
+                }
+                let link_style = if highlighted == Some(anchor_idx) {
+                    self.highlighted_link_style
+                } else {
+                    self.link_style
+                };
+                let end = anchor.end.min(chars.len());
+                for (_, style) in chars.iter_mut().take(end).skip(anchor.start) {
+                    *style = style.patch(link_style);
+                }
+            }
+
+            if let Some(search) = search
+                && let Some(match_idx) = search.matches.iter().position(|&l| l == line_idx)
+            {
+                let match_style = if match_idx == search.current_match {
+                    self.current_match_style
+                } else {
+                    self.match_style
+                };
+                let query: Vec<char> = search.query.chars().collect();
+                let text: Vec<char> = chars.iter().map(|(ch, _)| *ch).collect();
+                for start in find_occurrences(&text, &query) {
+                    for (_, style) in &mut chars[start..start + query.len()] {
+                        *style = style.patch(match_style);
+                    }
+                }
+            }
+
             if chars.is_empty() {
-                wrapped.push(vec![]);
+                wrapped.push(WrappedLine {
+                    line: line_idx,
+                    start: 0,
+                    chars: vec![],
+                });
                 continue;
             }
 
@@ -96,7 +604,11 @@ impl<'a> ParagraphExt<'a> {
             while start < chars.len() {
                 let remaining = chars.len() - start;
                 if remaining <= width {
-                    wrapped.push(chars[start..].to_vec());
+                    wrapped.push(WrappedLine {
+                        line: line_idx,
+                        start,
+                        chars: chars[start..].to_vec(),
+                    });
                     break;
                 }
 
@@ -111,7 +623,11 @@ impl<'a> ParagraphExt<'a> {
                     }
                 }
 
-                wrapped.push(chars[start..break_at].to_vec());
+                wrapped.push(WrappedLine {
+                    line: line_idx,
+                    start,
+                    chars: chars[start..break_at].to_vec(),
+                });
                 start = break_at;
 
                 // Skip leading spaces on continuation
@@ -128,41 +644,247 @@ impl<'a> ParagraphExt<'a> {
     ///
     /// This is useful for calculating scroll bounds.
     pub fn line_count(&self, width: u16) -> usize {
-        self.wrap_lines(width).len()
+        self.wrap_lines(width, None, None).len()
+    }
+
+    /// Render with anchor highlighting and return a click region per
+    /// visible anchor segment.
+    ///
+    /// An anchor wrapped across visual lines produces one region per line.
+    /// Updates `state.anchor_ids` and clamps the highlighted anchor. Scrolls
+    /// by `state.scroll` (the [`scroll`](Self::scroll) builder is ignored),
+    /// highlights search matches and shows a search bar on the last row
+    /// while searching.
+    pub fn render_stateful(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ParagraphExtState,
+    ) -> Vec<ClickRegion<ParagraphAction>> {
+        state.anchor_ids = self.anchors.iter().map(|a| a.id.clone()).collect();
+        if state
+            .highlighted
+            .is_some_and(|idx| idx >= self.anchors.len())
+        {
+            state.highlighted = None;
+        }
+
+        let highlighted = if state.focused {
+            state.highlighted
+        } else {
+            None
+        };
+        let width = self.width.unwrap_or(area.width);
+        let wrapped = self.wrap_lines(width, highlighted, Some(&state.search));
+
+        let (content_area, search_area) = if state.search.active && area.height > 1 {
+            (
+                Rect::new(area.x, area.y, area.width, area.height - 1),
+                Some(Rect::new(area.x, area.y + area.height - 1, area.width, 1)),
+            )
+        } else {
+            (area, None)
+        };
+
+        state.area = area;
+        state.viewport_height = content_area.height as usize;
+        state.rows = wrapped.iter().map(|w| (w.line, w.start)).collect();
+        state.line_text = self
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        state.heading_rows = self
+            .headings
+            .iter()
+            .filter_map(|&line| state.rows.iter().position(|&(l, _)| l == line))
+            .collect();
+        state.scroll = state.scroll.min(state.max_scroll());
+
+        render_wrapped(&wrapped, state.scroll, content_area, buf);
+        if let Some(search_area) = search_area {
+            render_search_bar(&state.search, search_area, buf);
+        }
+
+        let area = content_area;
+        let mut regions = Vec::new();
+        for anchor in &self.anchors {
+            let visible = wrapped.iter().skip(state.scroll).take(area.height as usize);
+            for (row, wrapped_line) in visible.enumerate() {
+                if wrapped_line.line != anchor.line {
+                    continue;
+                }
+                let line_end = wrapped_line.start + wrapped_line.chars.len();
+                let start = anchor.start.max(wrapped_line.start);
+                let end = anchor.end.min(line_end);
+                let col_start = (start - wrapped_line.start).min(area.width as usize);
+                let col_end = end
+                    .saturating_sub(wrapped_line.start)
+                    .min(area.width as usize);
+                if start >= end || col_start >= col_end {
+                    continue;
+                }
+                regions.push(ClickRegion::new(
+                    Rect::new(
+                        area.x + col_start as u16,
+                        area.y + row as u16,
+                        (col_end - col_start) as u16,
+                        1,
+                    ),
+                    ParagraphAction::AnchorActivated(anchor.id.clone()),
+                ));
+            }
+        }
+        regions
     }
 }
 
 impl Widget for ParagraphExt<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let width = self.width.unwrap_or(area.width);
-        let wrapped = self.wrap_lines(width);
-        let scroll = self.scroll as usize;
+        let wrapped = self.wrap_lines(width, None, None);
+        render_wrapped(&wrapped, self.scroll as usize, area, buf);
+    }
+}
 
-        // Clear area first
-        for y in area.y..area.y + area.height {
-            for x in area.x..area.x + area.width {
-                buf[(x, y)].reset();
-            }
+fn render_wrapped(wrapped: &[WrappedLine], scroll: usize, area: Rect, buf: &mut Buffer) {
+    // Clear area first
+    for y in area.y..area.y + area.height {
+        for x in area.x..area.x + area.width {
+            buf[(x, y)].reset();
         }
+    }
+
+    let visible = wrapped.iter().skip(scroll).take(area.height as usize);
 
-        let visible = wrapped.iter().skip(scroll).take(area.height as usize);
+    for (row, wrapped_line) in visible.enumerate() {
+        let y = area.y + row as u16;
+        if y >= area.y + area.height {
+            break;
+        }
 
-        for (row, line_chars) in visible.enumerate() {
-            let y = area.y + row as u16;
-            if y >= area.y + area.height {
+        // Only write actual content characters (no trailing spaces)
+        for (col, (ch, style)) in wrapped_line.chars.iter().enumerate() {
+            let x = area.x + col as u16;
+            if x >= area.x + area.width {
                 break;
             }
+            buf[(x, y)].set_char(*ch).set_style(*style);
+        }
+    }
+}
 
-            // Only write actual content characters (no trailing spaces)
-            for (col, (ch, style)) in line_chars.iter().enumerate() {
-                let x = area.x + col as u16;
-                if x >= area.x + area.width {
-                    break;
-                }
-                buf[(x, y)].set_char(*ch).set_style(*style);
+fn render_search_bar(search: &SearchState, area: Rect, buf: &mut Buffer) {
+    let search_line = Line::from(vec![
+        Span::styled(" Search: ", Style::default().fg(Color::Yellow)),
+        Span::raw(search.query.clone()),
+        Span::styled("▌", Style::default().fg(Color::White)),
+    ]);
+
+    let para = Paragraph::new(search_line).style(Style::default().bg(Color::Rgb(40, 40, 60)));
+    para.render(area, buf);
+}
+
+/// Handle keyboard input for a paragraph rendered with
+/// [`ParagraphExt::render_stateful`].
+///
+/// When focused, Tab and Shift+Tab cycle the highlighted anchor and Enter
+/// activates it. Arrow keys, PageUp/PageDown and Home/End scroll by wrapped
+/// lines, `/` starts a search, `n`/`N` cycle search matches, and `n`/`p`
+/// jump between headings when there are no matches.
+pub fn handle_paragraph_ext_key(
+    key: &KeyEvent,
+    state: &mut ParagraphExtState,
+) -> EventResult<ParagraphAction> {
+    if !state.focused {
+        return EventResult::NotHandled;
+    }
+
+    if state.search.active {
+        match key.code {
+            KeyCode::Esc => state.cancel_search(),
+            KeyCode::Enter => state.search.active = false,
+            KeyCode::Backspace => {
+                state.search.query.pop();
+                state.update_search();
             }
+            KeyCode::Char(c) => {
+                state.search.query.push(c);
+                state.update_search();
+            }
+            _ => return EventResult::NotHandled,
+        }
+        return EventResult::Consumed;
+    }
+
+    let has_anchors = !state.anchor_ids.is_empty();
+    let has_matches = !state.search.matches.is_empty();
+    match key.code {
+        KeyCode::Tab if has_anchors => state.next_anchor(),
+        KeyCode::BackTab if has_anchors => state.prev_anchor(),
+        KeyCode::Enter if has_anchors => {
+            return match state.highlighted_id() {
+                Some(id) => EventResult::Action(ParagraphAction::AnchorActivated(id.to_string())),
+                None => EventResult::NotHandled,
+            };
+        }
+        KeyCode::Up => state.scroll_up(1),
+        KeyCode::Down => state.scroll_down(1),
+        KeyCode::PageUp => state.page_up(),
+        KeyCode::PageDown => state.page_down(),
+        KeyCode::Home => state.go_to_top(),
+        KeyCode::End => state.go_to_bottom(),
+        KeyCode::Char('/') => state.start_search(),
+        KeyCode::Char('n') if has_matches => state.next_match(),
+        KeyCode::Char('N') if has_matches => state.prev_match(),
+        KeyCode::Char('n') if state.heading_count() > 0 => {
+            state.next_heading();
+        }
+        KeyCode::Char('p') if state.heading_count() > 0 => {
+            state.prev_heading();
+        }
+        _ => return EventResult::NotHandled,
+    }
+    EventResult::Consumed
+}
+
+/// Handle mouse input for a paragraph rendered with
+/// [`ParagraphExt::render_stateful`].
+///
+/// `regions` must come from [`ParagraphExt::render_stateful`]. A left click
+/// on an anchor highlights and activates it; the mouse wheel over the
+/// paragraph scrolls by three wrapped lines.
+pub fn handle_paragraph_ext_mouse(
+    state: &mut ParagraphExtState,
+    regions: &[ClickRegion<ParagraphAction>],
+    mouse: &MouseEvent,
+) -> Option<ParagraphAction> {
+    let in_area = state.area.contains((mouse.column, mouse.row).into());
+    match mouse.kind {
+        MouseEventKind::ScrollUp if in_area => {
+            state.scroll_up(3);
+            return None;
+        }
+        MouseEventKind::ScrollDown if in_area => {
+            state.scroll_down(3);
+            return None;
         }
+        MouseEventKind::Down(MouseButton::Left) => {}
+        _ => return None,
     }
+    let action = regions
+        .iter()
+        .find(|r| r.contains(mouse.column, mouse.row))?
+        .data
+        .clone();
+    let ParagraphAction::AnchorActivated(id) = &action;
+    state.highlighted = state.anchor_ids.iter().position(|a| a == id);
+    Some(action)
 }
 
 #[cfg(test)]
@@ -261,4 +983,292 @@ mod tests {
         let count = widget.line_count(20);
         assert_eq!(count, 3);
     }
+
+    #[test]
+    fn test_content_builder() {
+        let widget = ParagraphExt::text("Line one\nSee ")
+            .anchor("a", "here")
+            .newline()
+            .text("done")
+            .build();
+        assert_eq!(widget.lines.len(), 3);
+        assert_eq!(widget.anchors.len(), 1);
+        assert_eq!(widget.anchors[0].line, 1);
+        assert_eq!(widget.anchors[0].start, 4);
+        assert_eq!(widget.anchors[0].end, 8);
+    }
+
+    #[test]
+    fn test_anchor_region_and_link_style() {
+        let widget = ParagraphExt::text("Go ")
+            .anchor("next", "next")
+            .text(" now")
+            .build();
+        let area = Rect::new(2, 1, 20, 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+        let mut state = ParagraphExtState::new();
+        let regions = widget.render_stateful(area, &mut buf, &mut state);
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].area, Rect::new(5, 1, 4, 1));
+        assert_eq!(state.anchor_ids, vec!["next".to_string()]);
+        assert_eq!(buf[(5, 1)].fg, Color::Cyan);
+        assert!(buf[(5, 1)].modifier.contains(Modifier::UNDERLINED));
+        assert_ne!(buf[(4, 1)].fg, Color::Cyan);
+    }
+
+    #[test]
+    fn test_wrapped_anchor_produces_two_regions() {
+        let widget = ParagraphExt::text("Click ")
+            .anchor("docs", "the documentation link")
+            .build();
+        // "Click the " | "documentation" | "link"
+        let area = Rect::new(0, 0, 13, 5);
+        let mut buf = Buffer::empty(area);
+        let mut state = ParagraphExtState::new();
+        let regions = widget.render_stateful(area, &mut buf, &mut state);
+
+        let rects: Vec<Rect> = regions.iter().map(|r| r.area).collect();
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(6, 0, 4, 1),
+                Rect::new(0, 1, 13, 1),
+                Rect::new(0, 2, 4, 1),
+            ]
+        );
+        assert!(
+            regions
+                .iter()
+                .all(|r| r.data == ParagraphAction::AnchorActivated("docs".into()))
+        );
+    }
+
+    #[test]
+    fn test_anchor_regions_follow_scroll() {
+        let widget = ParagraphExt::text("zero\none\ntwo\n")
+            .anchor("x", "link")
+            .build();
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ParagraphExtState {
+            scroll: 1,
+            ..Default::default()
+        };
+        let regions = widget.render_stateful(area, &mut buf, &mut state);
+        assert_eq!(regions[0].area, Rect::new(0, 2, 4, 1));
+
+        let widget = ParagraphExt::text("one\n").anchor("x", "link").build();
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        let regions = widget.render_stateful(area, &mut buf, &mut state);
+        assert_eq!(regions[0].area, Rect::new(0, 0, 4, 1));
+
+        // Scrolled out of view
+        state.scroll = 0;
+        let widget = ParagraphExt::text("one\n").anchor("x", "link").build();
+        let regions = widget.render_stateful(area, &mut buf, &mut state);
+        assert!(regions.is_empty());
+    }
+
+    #[test]
+    fn test_keyboard_cycles_and_activates() {
+        let widget = ParagraphExt::text("")
+            .anchor("a", "A")
+            .text(" ")
+            .anchor("b", "B")
+            .build();
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        let mut state = ParagraphExtState::new();
+        widget.render_stateful(area, &mut buf, &mut state);
+
+        let tab = KeyEvent::from(KeyCode::Tab);
+        let enter = KeyEvent::from(KeyCode::Enter);
+
+        // Ignored while unfocused
+        assert_eq!(
+            handle_paragraph_ext_key(&tab, &mut state),
+            EventResult::NotHandled
+        );
+
+        state.focused = true;
+        assert_eq!(
+            handle_paragraph_ext_key(&tab, &mut state),
+            EventResult::Consumed
+        );
+        assert_eq!(state.highlighted_id(), Some("a"));
+        assert_eq!(
+            handle_paragraph_ext_key(&tab, &mut state),
+            EventResult::Consumed
+        );
+        assert_eq!(
+            handle_paragraph_ext_key(&enter, &mut state),
+            EventResult::Action(ParagraphAction::AnchorActivated("b".into()))
+        );
+        // Wraps around
+        handle_paragraph_ext_key(&tab, &mut state);
+        assert_eq!(state.highlighted_id(), Some("a"));
+        handle_paragraph_ext_key(&KeyEvent::from(KeyCode::BackTab), &mut state);
+        assert_eq!(state.highlighted_id(), Some("b"));
+    }
+
+    #[test]
+    fn test_mouse_click_activates_anchor() {
+        use crossterm::event::KeyModifiers;
+
+        let widget = ParagraphExt::text("x ")
+            .anchor("a", "A")
+            .text(" ")
+            .anchor("b", "B")
+            .build();
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        let mut state = ParagraphExtState::new();
+        let regions = widget.render_stateful(area, &mut buf, &mut state);
+
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 4,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(
+            handle_paragraph_ext_mouse(&mut state, &regions, &click),
+            Some(ParagraphAction::AnchorActivated("b".into()))
+        );
+        assert_eq!(state.highlighted, Some(1));
+
+        let miss = MouseEvent { column: 0, ..click };
+        assert_eq!(
+            handle_paragraph_ext_mouse(&mut state, &regions, &miss),
+            None
+        );
+    }
+
+    fn render_lines(widget: ParagraphExt<'_>, area: Rect, state: &mut ParagraphExtState) -> Buffer {
+        let mut buf = Buffer::empty(area);
+        widget.render_stateful(area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn test_scroll_uses_wrapped_lines() {
+        // Each source line wraps into two visual lines at width 6
+        let lines: Vec<Line> = (0..5)
+            .map(|i| Line::from(format!("aaaa bbbb{}", i)))
+            .collect();
+        let area = Rect::new(0, 0, 6, 4);
+        let mut state = ParagraphExtState::new();
+        render_lines(ParagraphExt::new(lines.clone()), area, &mut state);
+
+        assert_eq!(state.total_lines(), 10);
+        assert_eq!(state.max_scroll(), 6);
+        assert_eq!(state.scroll_percent(), 0);
+
+        state.page_down();
+        assert_eq!(state.scroll, 4);
+        state.page_down();
+        assert_eq!(state.scroll, 6);
+        assert_eq!(state.scroll_percent(), 100);
+        state.go_to_top();
+        state.scroll_down(3);
+        assert_eq!(state.scroll_percent(), 50);
+
+        state.focused = true;
+        handle_paragraph_ext_key(&KeyEvent::from(KeyCode::End), &mut state);
+        assert_eq!(state.scroll, 6);
+        let buf = render_lines(ParagraphExt::new(lines), area, &mut state);
+        assert_eq!(buf[(4, 3)].symbol(), "4");
+    }
+
+    #[test]
+    fn test_heading_navigation() {
+        let lines: Vec<Line> = (0..10).map(|i| Line::from(format!("line {}", i))).collect();
+        let widget = ParagraphExt::new(lines).headings(vec![0, 4, 7]);
+        let area = Rect::new(0, 0, 10, 3);
+        let mut state = ParagraphExtState::new();
+        state.focused = true;
+        render_lines(widget, area, &mut state);
+
+        let n = KeyEvent::from(KeyCode::Char('n'));
+        let p = KeyEvent::from(KeyCode::Char('p'));
+        assert_eq!(state.heading_count(), 3);
+        assert_eq!(state.current_heading(), Some(0));
+        handle_paragraph_ext_key(&n, &mut state);
+        assert_eq!(state.scroll, 4);
+        assert_eq!(state.current_heading(), Some(1));
+        handle_paragraph_ext_key(&n, &mut state);
+        assert_eq!(state.scroll, 7);
+        handle_paragraph_ext_key(&p, &mut state);
+        assert_eq!(state.scroll, 4);
+
+        assert!(state.go_to_heading(2));
+        assert_eq!(state.scroll, 7);
+        assert!(!state.go_to_heading(3));
+    }
+
+    #[test]
+    fn test_search_highlights_across_wrapped_lines() {
+        let lines = vec![
+            Line::from("nothing here"),
+            Line::from("alpha beta Gamma"),
+            Line::from("gamma again"),
+        ];
+        let area = Rect::new(0, 0, 11, 3);
+        let mut state = ParagraphExtState::new();
+        state.focused = true;
+        render_lines(ParagraphExt::new(lines.clone()), area, &mut state);
+
+        handle_paragraph_ext_key(&KeyEvent::from(KeyCode::Char('/')), &mut state);
+        for c in "gamma".chars() {
+            handle_paragraph_ext_key(&KeyEvent::from(KeyCode::Char(c)), &mut state);
+        }
+        assert_eq!(state.search.matches, vec![1, 2]);
+        // "Gamma" wraps onto the line after "alpha beta"
+        assert_eq!(state.scroll, 2);
+
+        // Search bar takes the last row
+        let buf = render_lines(ParagraphExt::new(lines.clone()), area, &mut state);
+        assert_eq!(buf[(0, 1)].symbol(), "G");
+        assert_eq!(buf[(0, 1)].bg, Color::Yellow);
+        assert_ne!(buf[(0, 0)].bg, Color::Yellow);
+        assert_eq!(buf[(1, 2)].symbol(), "S");
+
+        handle_paragraph_ext_key(&KeyEvent::from(KeyCode::Enter), &mut state);
+        assert!(!state.search.active);
+        handle_paragraph_ext_key(&KeyEvent::from(KeyCode::Char('n')), &mut state);
+        assert_eq!(state.search.current_match, 1);
+        let buf = render_lines(ParagraphExt::new(lines), area, &mut state);
+        assert_eq!(buf[(0, 1)].bg, Color::Rgb(60, 60, 30));
+        assert_eq!(buf[(0, 2)].symbol(), "g");
+        assert_eq!(buf[(0, 2)].bg, Color::Yellow);
+        assert_ne!(buf[(6, 2)].bg, Color::Yellow);
+    }
+
+    #[test]
+    fn test_mouse_wheel_scrolls_within_area() {
+        use crossterm::event::KeyModifiers;
+
+        let lines: Vec<Line> = (0..10).map(|i| Line::from(format!("line {}", i))).collect();
+        let area = Rect::new(0, 0, 10, 4);
+        let mut state = ParagraphExtState::new();
+        let regions =
+            ParagraphExt::new(lines).render_stateful(area, &mut Buffer::empty(area), &mut state);
+
+        let wheel = MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 1,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        };
+        handle_paragraph_ext_mouse(&mut state, &regions, &wheel);
+        assert_eq!(state.scroll, 3);
+        handle_paragraph_ext_mouse(&mut state, &regions, &wheel);
+        assert_eq!(state.scroll, 6);
+
+        let outside = MouseEvent { row: 9, ..wheel };
+        handle_paragraph_ext_mouse(&mut state, &regions, &outside);
+        assert_eq!(state.scroll, 6);
+    }
 }
@@ -5,7 +5,7 @@
 //! # Example
 //!
 //! ```rust
-//! use ratatui_interact::components::{Progress, ProgressStyle};
+//! use ratatui_interact::components::{Progress, ProgressState, ProgressStyle};
 //! use ratatui::layout::Rect;
 //! use ratatui::buffer::Buffer;
 //! use ratatui::widgets::Widget;
@@ -22,16 +22,30 @@
 //! // Custom style
 //! let progress = Progress::new(0.25)
 //!     .style(ProgressStyle::warning());
+//!
+//! // Streaming: 40% played, 70% buffered
+//! let mut state = ProgressState::new(0.4);
+//! state.set_buffered(0.7);
+//! let progress = Progress::from_state(&state).with_secondary(true);
+//!
+//! // Compact status bar segment: "▮▮▮▯▯ 62%"
+//! let mini = Progress::new(0.62).mini(5);
 //! ```
 
+use std::collections::VecDeque;
+
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
+    symbols::{block, border},
     text::Span,
     widgets::{Block, Borders, Gauge, Widget},
 };
 
+use crate::capabilities::{ASCII_BORDER, Capabilities};
+use crate::traits::ClickRegionRegistry;
+
 /// Style configuration for progress bars
 #[derive(Debug, Clone)]
 pub struct ProgressStyle {
@@ -43,8 +57,33 @@ pub struct ProgressStyle {
     pub label_style: Style,
     /// Whether to show borders
     pub bordered: bool,
+    /// Border characters
+    pub border_set: border::Set<'static>,
+    /// Character for the filled portion
+    pub fill_char: char,
+    /// Character for the buffered span between the progress and secondary progress
+    pub secondary_fill_char: char,
+    /// Style for the buffered span
+    pub secondary_fill_style: Style,
+    /// Cell glyphs of a [`MiniProgress`] block bar, from empty to full
+    pub mini_levels: &'static [&'static str],
+    /// Cell glyphs of a [`MiniProgress`] braille bar, from empty to full
+    pub micro_levels: &'static [&'static str],
+    /// Glyphs of a [`ProgressSparkline`], from lowest to highest value
+    pub spark_levels: &'static [&'static str],
 }
 
+/// Block bar cells: empty, full
+const MINI_LEVELS: &[&str] = &["▯", "▮"];
+/// Braille bar cells: empty, left column, both columns
+const MICRO_LEVELS: &[&str] = &["⠀", "⡇", "⣿"];
+/// Sparkline bars, lowest to highest
+const SPARK_LEVELS: &[&str] = &["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+/// ASCII bar cells: empty, full
+const ASCII_MINI_LEVELS: &[&str] = &["-", "#"];
+/// ASCII sparkline bars, lowest to highest
+const ASCII_SPARK_LEVELS: &[&str] = &["_", ".", "-", "=", "#"];
+
 impl Default for ProgressStyle {
     fn default() -> Self {
         Self {
@@ -54,6 +93,13 @@ impl Default for ProgressStyle {
                 .fg(Color::White)
                 .add_modifier(Modifier::BOLD),
             bordered: true,
+            border_set: border::PLAIN,
+            fill_char: '█',
+            secondary_fill_char: '▒',
+            secondary_fill_style: Style::default().fg(Color::Gray).bg(Color::DarkGray),
+            mini_levels: MINI_LEVELS,
+            micro_levels: MICRO_LEVELS,
+            spark_levels: SPARK_LEVELS,
         }
     }
 }
@@ -66,6 +112,13 @@ impl From<&crate::theme::Theme> for ProgressStyle {
             unfilled_color: p.text_disabled,
             label_style: Style::default().fg(p.text).add_modifier(Modifier::BOLD),
             bordered: true,
+            border_set: border::PLAIN,
+            fill_char: '█',
+            secondary_fill_char: '▒',
+            secondary_fill_style: Style::default().fg(p.text_dim).bg(p.text_disabled),
+            mini_levels: MINI_LEVELS,
+            micro_levels: MICRO_LEVELS,
+            spark_levels: SPARK_LEVELS,
         }
     }
 }
@@ -109,11 +162,99 @@ impl ProgressStyle {
         }
     }
 
+    /// ASCII-only style: `#` fill, `-` buffered span, and `+-|` borders;
+    /// mini bars use `#`/`-` cells and sparklines `_.-=#`
+    pub fn ascii() -> Self {
+        Self {
+            border_set: ASCII_BORDER,
+            fill_char: '#',
+            secondary_fill_char: '-',
+            mini_levels: ASCII_MINI_LEVELS,
+            micro_levels: ASCII_MINI_LEVELS,
+            spark_levels: ASCII_SPARK_LEVELS,
+            ..Default::default()
+        }
+    }
+
+    /// The default style adapted to the terminal: [`ascii`](Self::ascii)
+    /// glyphs without Unicode, palette colors without truecolor
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        let style = if caps.unicode {
+            Self::default()
+        } else {
+            Self::ascii()
+        };
+        Self {
+            filled_color: caps.color(style.filled_color),
+            unfilled_color: caps.color(style.unfilled_color),
+            label_style: caps.style(style.label_style),
+            secondary_fill_style: caps.style(style.secondary_fill_style),
+            ..style
+        }
+    }
+
+    /// Set the border characters
+    pub fn border_set(mut self, border_set: border::Set<'static>) -> Self {
+        self.border_set = border_set;
+        self
+    }
+
+    /// Set the character for the filled portion
+    pub fn fill_char(mut self, fill_char: char) -> Self {
+        self.fill_char = fill_char;
+        self
+    }
+
     /// Set whether to show borders
     pub fn bordered(mut self, bordered: bool) -> Self {
         self.bordered = bordered;
         self
     }
+
+    /// Set the character and style for the buffered span
+    pub fn secondary_fill(mut self, fill_char: char, style: Style) -> Self {
+        self.secondary_fill_char = fill_char;
+        self.secondary_fill_style = style;
+        self
+    }
+}
+
+/// Progress values for a bar that also shows buffered progress
+///
+/// `secondary_progress` is data that is buffered but not yet processed,
+/// like a video player's buffer indicator. It never falls below `progress`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProgressState {
+    /// Primary progress (0.0 to 1.0)
+    pub progress: f32,
+    /// Buffered progress (`progress` to 1.0)
+    pub secondary_progress: f32,
+}
+
+impl ProgressState {
+    /// Create a state with the given progress and nothing buffered beyond it
+    pub fn new(progress: f32) -> Self {
+        let mut state = Self::default();
+        state.set_progress(progress);
+        state
+    }
+
+    /// Set the primary progress, raising the buffered progress to match if needed
+    pub fn set_progress(&mut self, fraction: f32) {
+        self.progress = fraction.clamp(0.0, 1.0);
+        self.secondary_progress = self.secondary_progress.max(self.progress);
+    }
+
+    /// Set the buffered progress, clamped to at least the primary progress
+    ///
+    /// Non-finite values are ignored.
+    pub fn set_buffered(&mut self, fraction: f32) {
+        if !fraction.is_finite() {
+            return;
+        }
+        let lo = self.progress.clamp(0.0, 1.0);
+        self.secondary_progress = fraction.max(lo).min(1.0);
+    }
 }
 
 /// A progress bar widget with label and step counter support.
@@ -127,6 +268,10 @@ pub struct Progress<'a> {
     label: Option<&'a str>,
     /// Optional step counter (current, total)
     steps: Option<(usize, usize)>,
+    /// Buffered progress value (ratio to 1.0)
+    secondary: f64,
+    /// Whether to render the buffered span
+    show_secondary: bool,
     /// Style configuration
     style: ProgressStyle,
 }
@@ -138,6 +283,8 @@ impl<'a> Progress<'a> {
             ratio: ratio.clamp(0.0, 1.0),
             label: None,
             steps: None,
+            secondary: 0.0,
+            show_secondary: false,
             style: ProgressStyle::default(),
         }
     }
@@ -152,6 +299,29 @@ impl<'a> Progress<'a> {
         Self::new(ratio).steps(current, total)
     }
 
+    /// Create a progress bar from a [`ProgressState`]
+    ///
+    /// Enable [`with_secondary`](Self::with_secondary) to show the buffered span.
+    pub fn from_state(state: &ProgressState) -> Self {
+        Self::new(f64::from(state.progress)).buffered(f64::from(state.secondary_progress))
+    }
+
+    /// Set the buffered progress (clamped to at least the primary progress)
+    ///
+    /// Non-finite values are ignored.
+    pub fn buffered(mut self, ratio: f64) -> Self {
+        if ratio.is_finite() {
+            self.secondary = ratio.max(self.ratio.clamp(0.0, 1.0)).min(1.0);
+        }
+        self
+    }
+
+    /// Set whether to render the buffered span
+    pub fn with_secondary(mut self, show: bool) -> Self {
+        self.show_secondary = show;
+        self
+    }
+
     /// Set the label text
     pub fn label(mut self, label: &'a str) -> Self {
         self.label = Some(label);
@@ -175,6 +345,44 @@ impl<'a> Progress<'a> {
         self.style(ProgressStyle::from(theme))
     }
 
+    /// Turn this bar into a single-row [`MiniProgress`] of `width` cells
+    /// plus the percentage, keeping its ratio and style
+    pub fn mini(self, width: u16) -> MiniProgress {
+        MiniProgress {
+            ratio: self.ratio,
+            width,
+            kind: MiniProgressKind::Blocks,
+            show_percent: true,
+            style: self.style,
+        }
+    }
+
+    /// Fill the cells between the primary and buffered ends, leaving the label intact
+    fn render_secondary(&self, gauge_area: Rect, buf: &mut Buffer, percent: u16, label_width: u16) {
+        // Same rounding as the gauge's own fill
+        let width = f64::from(gauge_area.width);
+        let start = gauge_area.left() + (width * f64::from(percent) / 100.0).round() as u16;
+        let end = gauge_area.left() + (width * self.secondary).round() as u16;
+
+        let label_width = label_width.min(gauge_area.width);
+        let label_col = gauge_area.left() + (gauge_area.width - label_width) / 2;
+        let label_row = gauge_area.top() + gauge_area.height / 2;
+        let label_cols = label_col..label_col + label_width;
+
+        let mut fill = [0u8; 4];
+        let fill = self.style.secondary_fill_char.encode_utf8(&mut fill);
+        for y in gauge_area.top()..gauge_area.bottom() {
+            for x in start..end {
+                if y == label_row && label_cols.contains(&x) {
+                    continue;
+                }
+                buf[(x, y)]
+                    .set_symbol(fill)
+                    .set_style(self.style.secondary_fill_style);
+            }
+        }
+    }
+
     /// Build the label string
     fn build_label(&self) -> String {
         let percent = (self.ratio * 100.0) as u16;
@@ -200,6 +408,8 @@ impl Widget for Progress<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let label = self.build_label();
         let label_span = Span::styled(label, self.style.label_style);
+        let label_width = label_span.width() as u16;
+        let percent = (self.ratio * 100.0) as u16;
 
         let mut gauge = Gauge::default()
             .gauge_style(
@@ -207,14 +417,276 @@ impl Widget for Progress<'_> {
                     .fg(self.style.filled_color)
                     .bg(self.style.unfilled_color),
             )
-            .percent((self.ratio * 100.0) as u16)
+            .percent(percent)
             .label(label_span);
 
-        if self.style.bordered {
-            gauge = gauge.block(Block::default().borders(Borders::ALL));
-        }
+        let gauge_area = if self.style.bordered {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_set(self.style.border_set);
+            let inner = block.inner(area);
+            gauge = gauge.block(block);
+            inner
+        } else {
+            area
+        };
 
         gauge.render(area, buf);
+
+        if self.style.fill_char != '█' {
+            let mut fill = [0u8; 4];
+            let fill = self.style.fill_char.encode_utf8(&mut fill);
+            for pos in gauge_area.positions() {
+                if buf[pos].symbol() == block::FULL {
+                    buf[pos].set_symbol(fill);
+                }
+            }
+        }
+
+        if self.show_secondary && !gauge_area.is_empty() {
+            self.render_secondary(gauge_area, buf, percent, label_width);
+        }
+    }
+}
+
+/// Glyphs used by a [`MiniProgress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MiniProgressKind {
+    /// One step per cell ([`ProgressStyle::mini_levels`]), e.g. `▮▮▮▯▯`
+    #[default]
+    Blocks,
+    /// Braille cells with two steps each ([`ProgressStyle::micro_levels`])
+    Braille,
+}
+
+/// Compact single-row progress bar for status bar segments, e.g. `▮▮▮▯▯ 62%`
+///
+/// Created with [`Progress::mini`]. The percentage is rounded down like
+/// [`Progress`]'s label, so unfinished work never shows `100%`.
+#[derive(Debug, Clone)]
+pub struct MiniProgress {
+    ratio: f64,
+    width: u16,
+    kind: MiniProgressKind,
+    show_percent: bool,
+    style: ProgressStyle,
+}
+
+impl MiniProgress {
+    /// Use braille cells for twice the resolution
+    pub fn braille(mut self) -> Self {
+        self.kind = MiniProgressKind::Braille;
+        self
+    }
+
+    /// Set the glyphs
+    pub fn kind(mut self, kind: MiniProgressKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set whether to show the percentage after the bar
+    pub fn show_percent(mut self, show: bool) -> Self {
+        self.show_percent = show;
+        self
+    }
+
+    /// Cells needed to render the bar and the percentage
+    pub fn width(&self) -> u16 {
+        self.width + self.percent_label().map_or(0, |label| label.len() as u16)
+    }
+
+    fn percent_label(&self) -> Option<String> {
+        self.show_percent
+            .then(|| format!(" {}%", (self.ratio * 100.0) as u16))
+    }
+
+    /// Render and register the rendered area with `data` as its click payload
+    pub fn render_with_registry<T: Clone>(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<T>,
+        data: T,
+    ) {
+        let width = self.width().min(area.width);
+        self.render(area, buf);
+        registry.register(Rect::new(area.x, area.y, width, area.height.min(1)), data);
+    }
+}
+
+impl Widget for MiniProgress {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let levels = match self.kind {
+            MiniProgressKind::Blocks => self.style.mini_levels,
+            MiniProgressKind::Braille => self.style.micro_levels,
+        };
+        let steps = levels.len().saturating_sub(1).max(1);
+        let bar_width = self.width.min(area.width) as usize;
+        let filled = filled_units(self.ratio, bar_width * steps);
+
+        for i in 0..bar_width {
+            let cell = filled.saturating_sub(i * steps).min(steps);
+            let color = if cell > 0 {
+                self.style.filled_color
+            } else {
+                self.style.unfilled_color
+            };
+            buf[(area.x + i as u16, area.y)]
+                .set_symbol(levels.get(cell).copied().unwrap_or_default())
+                .set_style(Style::default().fg(color));
+        }
+
+        if let Some(label) = self.percent_label() {
+            let x = area.x + bar_width as u16;
+            let room = area.right().saturating_sub(x) as usize;
+            buf.set_stringn(x, area.y, label, room, self.style.label_style);
+        }
+    }
+}
+
+/// Steps out of `units` to fill for `ratio`, rounded to nearest
+///
+/// With two or more steps, unfinished work never renders as empty or full.
+fn filled_units(ratio: f64, units: usize) -> usize {
+    let filled = (ratio * units as f64).round() as usize;
+    if units >= 2 && ratio > 0.0 && ratio < 1.0 {
+        filled.clamp(1, units - 1)
+    } else {
+        filled.min(units)
+    }
+}
+
+/// The last values of a progress measure, for a [`ProgressSparkline`]
+///
+/// Keeps at most `capacity` values, dropping the oldest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressHistory {
+    values: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl Default for ProgressHistory {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+impl ProgressHistory {
+    /// Create an empty history keeping at most `capacity` values (at least one)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Record a value (clamped to 0.0..=1.0), dropping the oldest when full
+    pub fn push(&mut self, value: f64) {
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value.clamp(0.0, 1.0));
+    }
+
+    /// Values from oldest to newest
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = f64> + ExactSizeIterator + '_ {
+        self.values.iter().copied()
+    }
+
+    /// The newest value
+    pub fn latest(&self) -> Option<f64> {
+        self.values.back().copied()
+    }
+
+    /// Maximum number of values kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of values recorded
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if no values are recorded
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Forget all values
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+/// Spark-style bars of a [`ProgressHistory`], newest at the right edge
+#[derive(Debug, Clone)]
+pub struct ProgressSparkline<'a> {
+    history: &'a ProgressHistory,
+    style: ProgressStyle,
+}
+
+impl<'a> ProgressSparkline<'a> {
+    /// Create a sparkline of `history`
+    pub fn new(history: &'a ProgressHistory) -> Self {
+        Self {
+            history,
+            style: ProgressStyle::default(),
+        }
+    }
+
+    /// Set the style
+    pub fn style(mut self, style: ProgressStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme to derive the style
+    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
+        self.style(ProgressStyle::from(theme))
+    }
+
+    /// Render and register the rendered area with `data` as its click payload
+    pub fn render_with_registry<T: Clone>(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<T>,
+        data: T,
+    ) {
+        registry.register(
+            Rect::new(area.x, area.y, area.width, area.height.min(1)),
+            data,
+        );
+        self.render(area, buf);
+    }
+}
+
+impl Widget for ProgressSparkline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let levels = self.style.spark_levels;
+        if area.is_empty() || levels.is_empty() {
+            return;
+        }
+        let count = self.history.len().min(area.width as usize);
+        let x = area.right() - count as u16;
+        let style = Style::default().fg(self.style.filled_color);
+        let top = (levels.len() - 1) as f64;
+        for (i, value) in self
+            .history
+            .values()
+            .skip(self.history.len() - count)
+            .enumerate()
+        {
+            let level = (value * top).round() as usize;
+            buf[(x + i as u16, area.y)]
+                .set_symbol(levels[level])
+                .set_style(style);
+        }
     }
 }
 
@@ -256,6 +728,68 @@ mod tests {
         assert_eq!(p.build_label(), "Processing - 5/10 steps (50%)");
     }
 
+    #[test]
+    fn test_progress_state_buffered_never_below_progress() {
+        let mut state = ProgressState::new(0.4);
+        assert_eq!(state.secondary_progress, 0.4);
+
+        state.set_buffered(0.7);
+        assert_eq!(state.secondary_progress, 0.7);
+        state.set_buffered(0.2);
+        assert_eq!(state.secondary_progress, 0.4);
+
+        state.set_buffered(0.7);
+        state.set_progress(0.9);
+        assert_eq!(state.secondary_progress, 0.9);
+    }
+
+    #[test]
+    fn test_buffered_out_of_range_progress_does_not_panic() {
+        let mut state = ProgressState::new(0.4);
+        state.progress = 1.5;
+        state.set_buffered(0.5);
+        assert_eq!(state.secondary_progress, 1.0);
+
+        state.progress = f32::NAN;
+        state.set_buffered(0.5);
+        assert_eq!(state.secondary_progress, 0.5);
+        state.set_buffered(f32::NAN);
+        state.set_buffered(f32::INFINITY);
+        assert_eq!(state.secondary_progress, 0.5);
+
+        let p = Progress::new(f64::NAN).buffered(0.3);
+        assert_eq!(p.secondary, 0.3);
+        let p = Progress::new(0.5).buffered(f64::NAN);
+        assert_eq!(p.secondary, 0.0);
+    }
+
+    #[test]
+    fn test_render_secondary_fill() {
+        let mut state = ProgressState::new(0.2);
+        state.set_buffered(0.6);
+        let style = ProgressStyle::default().bordered(false);
+        let area = Rect::new(0, 0, 20, 3);
+
+        let mut buf = Buffer::empty(area);
+        Progress::from_state(&state)
+            .style(style.clone())
+            .with_secondary(true)
+            .render(area, &mut buf);
+
+        let row = |y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol()).collect() };
+        assert_eq!(row(0), "████▒▒▒▒▒▒▒▒        ");
+        // The label row keeps the label over the buffered span
+        assert_eq!(row(1), "████▒▒▒▒20%▒        ");
+        assert_eq!(buf[(5, 0)].fg, style.secondary_fill_style.fg.unwrap());
+
+        // Hidden unless enabled
+        let mut buf = Buffer::empty(area);
+        Progress::from_state(&state)
+            .style(style)
+            .render(area, &mut buf);
+        assert!(!(0..20).any(|x| buf[(x, 0)].symbol() == "▒"));
+    }
+
     #[test]
     fn test_progress_render() {
         let mut buf = Buffer::empty(Rect::new(0, 0, 40, 3));
@@ -263,4 +797,115 @@ mod tests {
         progress.render(Rect::new(0, 0, 40, 3), &mut buf);
         // Just verify it doesn't panic
     }
+
+    #[test]
+    fn test_ascii_render() {
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        Progress::new(0.5)
+            .buffered(0.75)
+            .with_secondary(true)
+            .style(ProgressStyle::from_capabilities(&Capabilities::basic()))
+            .render(area, &mut buf);
+
+        assert!(buf.content().iter().all(|c| c.symbol().is_ascii()));
+        let row = |y: u16| (0..20).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(0), "+------------------+");
+        assert_eq!(row(1), "|#######50%----    |");
+    }
+
+    fn render_row(widget: impl Widget, width: u16) -> String {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        (0..width).map(|x| buf[(x, 0)].symbol()).collect()
+    }
+
+    #[test]
+    fn test_mini_render() {
+        let mini = Progress::new(0.62).mini(5);
+        assert_eq!(mini.width(), 9);
+        assert_eq!(render_row(mini, 9), "▮▮▮▯▯ 62%");
+
+        // The percentage is cut off when the area is too narrow
+        let mini = Progress::new(0.62).mini(5);
+        assert_eq!(render_row(mini, 7), "▮▮▮▯▯ 6");
+    }
+
+    #[test]
+    fn test_mini_rounding_tiny_widths() {
+        let bar = |ratio: f64, width: u16| {
+            render_row(Progress::new(ratio).mini(width).show_percent(false), width)
+        };
+        assert_eq!(bar(0.4, 1), "▯");
+        assert_eq!(bar(0.5, 1), "▮");
+        // Unfinished work is never empty or full once there are two steps
+        assert_eq!(bar(0.1, 2), "▮▯");
+        assert_eq!(bar(0.99, 2), "▮▯");
+        assert_eq!(bar(1.0, 2), "▮▮");
+        assert_eq!(bar(0.0, 3), "▯▯▯");
+        assert_eq!(bar(0.5, 3), "▮▮▯");
+        assert_eq!(bar(0.01, 3), "▮▯▯");
+        assert_eq!(bar(0.98, 3), "▮▮▯");
+    }
+
+    #[test]
+    fn test_mini_braille_half_cells() {
+        let bar = |ratio: f64, width: u16| {
+            render_row(
+                Progress::new(ratio)
+                    .mini(width)
+                    .braille()
+                    .show_percent(false),
+                width,
+            )
+        };
+        assert_eq!(bar(0.5, 3), "⣿⡇⠀");
+        assert_eq!(bar(0.5, 1), "⡇");
+        assert_eq!(bar(0.01, 1), "⡇");
+        assert_eq!(bar(1.0, 2), "⣿⣿");
+    }
+
+    #[test]
+    fn test_mini_ascii_and_registry() {
+        let area = Rect::new(2, 0, 12, 1);
+        let mut buf = Buffer::empty(area);
+        let mut registry = ClickRegionRegistry::new();
+        Progress::new(0.5)
+            .style(ProgressStyle::from_capabilities(&Capabilities::basic()))
+            .mini(4)
+            .render_with_registry(area, &mut buf, &mut registry, "upload");
+        let row: String = (2..14).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "##-- 50%    ");
+        assert_eq!(registry.handle_click(3, 0), Some(&"upload"));
+        assert_eq!(registry.handle_click(10, 0), None);
+    }
+
+    #[test]
+    fn test_history_ring() {
+        let mut history = ProgressHistory::new(3);
+        assert!(history.is_empty());
+        for value in [0.1, 0.2, 1.5, -1.0] {
+            history.push(value);
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.values().collect::<Vec<_>>(), vec![0.2, 1.0, 0.0]);
+        assert_eq!(history.latest(), Some(0.0));
+        history.clear();
+        assert_eq!(history.latest(), None);
+    }
+
+    #[test]
+    fn test_sparkline_render() {
+        let mut history = ProgressHistory::new(8);
+        for value in [0.0, 0.5, 1.0] {
+            history.push(value);
+        }
+        // Newest values sit at the right edge
+        assert_eq!(render_row(ProgressSparkline::new(&history), 5), "  ▁▅█");
+        assert_eq!(render_row(ProgressSparkline::new(&history), 2), "▅█");
+
+        let ascii = ProgressSparkline::new(&history).style(ProgressStyle::ascii());
+        assert_eq!(render_row(ascii, 3), "_-#");
+    }
 }
@@ -236,6 +236,17 @@ impl ScrollableContentState {
     pub fn content_as_string(&self) -> String {
         self.lines.join("\n")
     }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_scrollable_content_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑/k", "Scroll up"),
+            ("↓/j", "Scroll down"),
+            ("PgUp/PgDn", "Page"),
+            ("Home/End", "Top/bottom"),
+            ("F10/Enter", "Toggle fullscreen"),
+        ]
+    }
 }
 
 impl Default for ScrollableContentState {
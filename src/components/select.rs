@@ -22,8 +22,26 @@
 //!
 //! // Render and handle events (see handle_select_key, handle_select_mouse)
 //! ```
+//!
+//! Options with subtitles and separators use [`SelectOption`]:
+//!
+//! ```rust
+//! use ratatui_interact::components::{Select, SelectOption, SelectState};
+//!
+//! let options = vec![
+//!     SelectOption::new("Stable").with_subtitle("Tested releases"),
+//!     SelectOption::new("Beta").with_subtitle("Upcoming release"),
+//!     SelectOption::separator(),
+//!     SelectOption::new("Nightly").with_subtitle("Built every night"),
+//! ];
+//!
+//! let mut state = SelectState::default();
+//! state.set_options(&options);
+//!
+//! let select = Select::from_options(&options, &state).label("Channel");
+//! ```
 
-use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
     buffer::Buffer,
@@ -33,7 +51,16 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
-use crate::traits::{ClickRegion, FocusId};
+use super::focus_ring::FocusRing;
+use super::option_details::{
+    OptionDetails, OptionDetailsFn, OptionRowStyles, description_row, option_row,
+};
+use super::spinner::load_status_spans;
+use crate::events::CloseRequest;
+use crate::state::{
+    Loadable, OverlayLayer, OverlayManager, PageMove, PageSize, handle_loadable_retry_key,
+};
+use crate::traits::{ClickRegion, FocusId, FocusableWidget, PopupOwner};
 
 /// Actions a select component can emit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -46,6 +73,69 @@ pub enum SelectAction {
     Close,
     /// An option was selected (index).
     Select(usize),
+    /// Type-ahead moved the selection of the closed select (index).
+    Changed(usize),
+    /// Loading the options failed and the user asked to retry.
+    RetryRequested,
+}
+
+/// For the result of handling Esc: closing the dropdown consumes the key.
+impl From<Option<SelectAction>> for CloseRequest {
+    fn from(action: Option<SelectAction>) -> Self {
+        match action {
+            Some(_) => CloseRequest::Consumed,
+            None => CloseRequest::Propagate,
+        }
+    }
+}
+
+/// An option with an optional subtitle, or a separator line.
+///
+/// Used with [`Select::from_options`] and [`SelectState::set_options`].
+/// Displays as its label.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectOption {
+    /// Primary label, also shown in the closed select.
+    pub label: String,
+    /// Secondary line shown below the label in the dropdown.
+    pub subtitle: Option<String>,
+    /// Whether this is a non-selectable divider line.
+    pub separator: bool,
+}
+
+impl SelectOption {
+    /// Create an option with a label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a separator.
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Default::default()
+        }
+    }
+
+    /// Set the subtitle.
+    pub fn with_subtitle(mut self, text: &str) -> Self {
+        self.subtitle = Some(text.to_string());
+        self
+    }
+
+    /// Check if this option is a separator.
+    pub fn is_separator(&self) -> bool {
+        self.separator
+    }
+}
+
+impl std::fmt::Display for SelectOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label)
+    }
 }
 
 /// State for a select component.
@@ -57,6 +147,8 @@ pub struct SelectState {
     pub is_open: bool,
     /// Whether the select has focus.
     pub focused: bool,
+    /// Focus ID matched by [`FocusManager::sync_from_vec`](crate::state::FocusManager::sync_from_vec).
+    pub focus_id: FocusId,
     /// Whether the select is enabled.
     pub enabled: bool,
     /// Highlighted index in dropdown (for keyboard navigation).
@@ -65,22 +157,74 @@ pub struct SelectState {
     pub scroll_offset: u16,
     /// Total number of options.
     pub total_options: usize,
+    /// Option labels matched by keyboard type-ahead.
+    pub option_labels: Vec<String>,
+    /// Type-ahead prefix typed so far.
+    pub type_ahead: String,
+    /// Ticks left before the type-ahead prefix resets.
+    pub type_ahead_ticks: u32,
+    /// Ticks without typing after which the type-ahead prefix resets.
+    pub type_ahead_timeout: u32,
+    /// Indices of separator options, skipped by navigation and selection.
+    pub separators: Vec<usize>,
+    /// Loading phase of the options; the select cannot open while they are
+    /// loading or failed to load.
+    pub load: Loadable<()>,
+    /// How far PageUp/PageDown move the highlight.
+    pub page_size: PageSize,
+    /// Rows shown by the open dropdown; keep in sync with
+    /// [`SelectStyle::max_visible_options`].
+    pub visible_options: usize,
 }
 
+/// Default ticks before the type-ahead prefix resets.
+const DEFAULT_TYPE_AHEAD_TIMEOUT: u32 = 10;
+
 impl Default for SelectState {
     fn default() -> Self {
         Self {
             selected_index: None,
             is_open: false,
             focused: false,
+            focus_id: FocusId::default(),
             enabled: true,
             highlighted_index: 0,
             scroll_offset: 0,
             total_options: 0,
+            option_labels: Vec::new(),
+            type_ahead: String::new(),
+            type_ahead_ticks: 0,
+            type_ahead_timeout: DEFAULT_TYPE_AHEAD_TIMEOUT,
+            separators: Vec::new(),
+            load: Loadable::NotLoaded,
+            page_size: PageSize::default(),
+            visible_options: 8,
         }
     }
 }
 
+impl FocusableWidget for SelectState {
+    fn focus_id(&self) -> FocusId {
+        self.focus_id
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl PopupOwner for SelectState {
+    fn on_focus_lost(&mut self) -> bool {
+        let was_open = self.is_open;
+        self.close();
+        was_open
+    }
+}
+
 impl SelectState {
     /// Create a new select state with given number of options.
     pub fn new(total_options: usize) -> Self {
@@ -90,6 +234,24 @@ impl SelectState {
         }
     }
 
+    /// Set the focus ID used by [`FocusableWidget`].
+    pub fn with_focus_id(mut self, id: FocusId) -> Self {
+        self.focus_id = id;
+        self
+    }
+
+    /// Set how far PageUp/PageDown move the highlight.
+    pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Set the number of rows shown by the open dropdown.
+    pub fn with_visible_options(mut self, visible_options: usize) -> Self {
+        self.visible_options = visible_options;
+        self
+    }
+
     /// Create with a pre-selected index.
     pub fn with_selected(total_options: usize, selected: usize) -> Self {
         let mut state = Self::new(total_options);
@@ -100,14 +262,37 @@ impl SelectState {
         state
     }
 
+    /// Start loading the options at the caller's `tick`, closing the dropdown.
+    pub fn set_loading(&mut self, tick: u64) {
+        self.load.set_loading(tick);
+        self.close();
+    }
+
+    /// Finish loading with `total` options.
+    pub fn set_loaded(&mut self, total: usize) {
+        self.load.set_loaded(());
+        self.set_total(total);
+    }
+
+    /// Mark loading the options as failed, closing the dropdown.
+    pub fn set_failed(&mut self, message: impl Into<String>) {
+        self.load.set_failed(message);
+        self.close();
+    }
+
     /// Open the dropdown.
+    ///
+    /// Does nothing while the options are loading or failed to load.
     pub fn open(&mut self) {
-        if self.enabled {
+        if self.enabled && !self.load.is_pending() {
             self.is_open = true;
             // Start highlight at selected item if any
             if let Some(idx) = self.selected_index {
                 self.highlighted_index = idx;
             }
+            if self.is_separator(self.highlighted_index) {
+                self.highlight_next();
+            }
         }
     }
 
@@ -125,36 +310,73 @@ impl SelectState {
         }
     }
 
-    /// Move highlight up.
+    /// Move highlight up, skipping separators.
     pub fn highlight_prev(&mut self) {
-        if self.highlighted_index > 0 {
-            self.highlighted_index -= 1;
+        if let Some(idx) = (0..self.highlighted_index)
+            .rev()
+            .find(|&i| !self.is_separator(i))
+        {
+            self.highlighted_index = idx;
         }
     }
 
-    /// Move highlight down.
+    /// Move highlight down, skipping separators.
     pub fn highlight_next(&mut self) {
-        if self.highlighted_index + 1 < self.total_options {
-            self.highlighted_index += 1;
+        if let Some(idx) =
+            (self.highlighted_index + 1..self.total_options).find(|&i| !self.is_separator(i))
+        {
+            self.highlighted_index = idx;
         }
     }
 
     /// Move highlight to first option.
     pub fn highlight_first(&mut self) {
-        self.highlighted_index = 0;
+        self.highlighted_index = (0..self.total_options)
+            .find(|&i| !self.is_separator(i))
+            .unwrap_or(0);
         self.scroll_offset = 0;
     }
 
     /// Move highlight to last option.
     pub fn highlight_last(&mut self) {
-        if self.total_options > 0 {
-            self.highlighted_index = self.total_options - 1;
+        if let Some(idx) = (0..self.total_options)
+            .rev()
+            .find(|&i| !self.is_separator(i))
+        {
+            self.highlighted_index = idx;
+        }
+    }
+
+    /// Move the highlight by a [`PageMove`], skipping separators.
+    ///
+    /// A separator at the target is skipped in the direction of the move,
+    /// falling back to the other direction at the end of the list.
+    pub fn highlight_page(&mut self, page_move: PageMove) {
+        let target = page_move.apply(
+            self.highlighted_index,
+            self.total_options,
+            self.visible_options,
+            self.page_size,
+        );
+        let mut forward = (target..self.total_options).filter(|&i| !self.is_separator(i));
+        let mut backward = (0..=target).rev().filter(|&i| !self.is_separator(i));
+        let found = if page_move.is_forward() {
+            forward.next().or_else(|| backward.next())
+        } else {
+            backward.next().or_else(|| forward.next())
+        };
+        if let Some(idx) = found {
+            self.highlighted_index = idx;
+        }
+        if page_move == PageMove::First {
+            self.scroll_offset = 0;
         }
+        self.ensure_visible(self.visible_options);
     }
 
     /// Select the currently highlighted option and close.
     pub fn select_highlighted(&mut self) {
-        if self.total_options > 0 {
+        if self.total_options > 0 && !self.is_separator(self.highlighted_index) {
             self.selected_index = Some(self.highlighted_index);
         }
         self.close();
@@ -162,13 +384,18 @@ impl SelectState {
 
     /// Select a specific index.
     pub fn select(&mut self, index: usize) {
-        if index < self.total_options {
+        if index < self.total_options && !self.is_separator(index) {
             self.selected_index = Some(index);
             self.highlighted_index = index;
         }
         self.close();
     }
 
+    /// Check if the option at `index` is a separator.
+    pub fn is_separator(&self, index: usize) -> bool {
+        self.separators.contains(&index)
+    }
+
     /// Clear the selection.
     pub fn clear_selection(&mut self) {
         self.selected_index = None;
@@ -187,6 +414,82 @@ impl SelectState {
         }
     }
 
+    /// Set the option labels used by keyboard type-ahead.
+    ///
+    /// Also updates the option count.
+    pub fn set_option_labels<S: Into<String>>(&mut self, labels: impl IntoIterator<Item = S>) {
+        self.option_labels = labels.into_iter().map(Into::into).collect();
+        self.set_total(self.option_labels.len());
+    }
+
+    /// Set up the state for a list of [`SelectOption`]s.
+    ///
+    /// Records the separators and sets the type-ahead labels and option count.
+    pub fn set_options(&mut self, options: &[SelectOption]) {
+        self.separators = options
+            .iter()
+            .enumerate()
+            .filter(|(_, opt)| opt.separator)
+            .map(|(i, _)| i)
+            .collect();
+        self.set_option_labels(options.iter().map(|opt| opt.label.as_str()));
+        if self.is_separator(self.highlighted_index) {
+            self.highlight_next();
+        }
+    }
+
+    /// Advance the type-ahead timeout; call once per app tick.
+    pub fn tick(&mut self) {
+        if self.type_ahead_ticks > 0 {
+            self.type_ahead_ticks -= 1;
+            if self.type_ahead_ticks == 0 {
+                self.type_ahead.clear();
+            }
+        }
+    }
+
+    /// Jump to the option matching the typed prefix.
+    ///
+    /// Repeating the same letter cycles through the options starting with
+    /// it; typing different letters before the timeout narrows the prefix.
+    /// Moves the highlight, and the selection too while closed. Returns the
+    /// matched index.
+    pub fn type_ahead(&mut self, c: char) -> Option<usize> {
+        self.type_ahead.extend(c.to_lowercase());
+        self.type_ahead_ticks = self.type_ahead_timeout;
+
+        let current = if self.is_open {
+            Some(self.highlighted_index)
+        } else {
+            self.selected_index
+        };
+        // A repeated letter cycles, so the search starts after the current option
+        let mut chars = self.type_ahead.chars();
+        let first = chars.next()?;
+        let cycling = chars.all(|ch| ch == first);
+        let prefix = if cycling {
+            first.to_string()
+        } else {
+            self.type_ahead.clone()
+        };
+        let start = match current {
+            Some(idx) if cycling => idx + 1,
+            Some(idx) => idx,
+            None => 0,
+        };
+
+        let count = self.option_labels.len();
+        let found = (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&idx| self.option_labels[idx].to_lowercase().starts_with(&prefix))?;
+
+        self.highlighted_index = found;
+        if !self.is_open {
+            self.selected_index = Some(found);
+        }
+        Some(found)
+    }
+
     /// Ensure highlighted item is visible in viewport.
     pub fn ensure_visible(&mut self, viewport_height: usize) {
         if viewport_height == 0 {
@@ -208,6 +511,20 @@ impl SelectState {
     pub fn has_selection(&self) -> bool {
         self.selected_index.is_some()
     }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_select_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("Enter/Space/↓", "Open dropdown"),
+            ("↑/↓", "Move highlight"),
+            ("Home/End", "First/last option"),
+            ("PgUp/PgDn", "Page"),
+            ("Ctrl+U/Ctrl+D", "Half page"),
+            ("Enter/Space", "Select option"),
+            ("Esc", "Close dropdown"),
+            ("a-z", "Jump to option by label"),
+        ]
+    }
 }
 
 /// Style configuration for select component.
@@ -237,6 +554,18 @@ pub struct SelectStyle {
     pub dropdown_border: Color,
     /// Max visible options in dropdown.
     pub max_visible_options: u16,
+    /// Style for option descriptions.
+    pub description_style: Style,
+    /// Style for right-aligned option hints.
+    pub hint_style: Style,
+    /// Style patched onto text matching the search query.
+    pub match_style: Style,
+    /// Style for option subtitles.
+    pub subtitle_style: Style,
+    /// Columns a subtitle is indented past its label.
+    pub subtitle_indent: u16,
+    /// Style for the load error shown when loading the options failed.
+    pub error_style: Style,
 }
 
 impl Default for SelectStyle {
@@ -257,6 +586,14 @@ impl Default for SelectStyle {
             unselected_indicator: "  ",
             dropdown_border: Color::Cyan,
             max_visible_options: 8,
+            description_style: Style::default().fg(Color::DarkGray),
+            hint_style: Style::default().fg(Color::DarkGray),
+            match_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::UNDERLINED),
+            subtitle_style: Style::default().fg(Color::Gray),
+            subtitle_indent: 2,
+            error_style: Style::default().fg(Color::Red),
         }
     }
 }
@@ -280,11 +617,24 @@ impl From<&crate::theme::Theme> for SelectStyle {
             unselected_indicator: "  ",
             dropdown_border: p.border_accent,
             max_visible_options: 8,
+            description_style: Style::default().fg(p.text_dim),
+            hint_style: Style::default().fg(p.text_muted),
+            match_style: Style::default()
+                .fg(p.warning)
+                .add_modifier(Modifier::UNDERLINED),
+            subtitle_style: Style::default().fg(p.text_dim),
+            subtitle_indent: 2,
+            error_style: Style::default().fg(p.error),
         }
     }
 }
 
 impl SelectStyle {
+    /// Focus ring built from the border colors.
+    pub fn focus_ring(&self) -> FocusRing {
+        FocusRing::new(self.focused_border, self.unfocused_border).disabled(self.disabled_border)
+    }
+
     /// Minimal style without heavy highlighting.
     pub fn minimal() -> Self {
         Self {
@@ -342,8 +692,50 @@ impl SelectStyle {
         self.highlight_style = style;
         self
     }
+
+    /// Set the style for option descriptions.
+    pub fn description_style(mut self, style: Style) -> Self {
+        self.description_style = style;
+        self
+    }
+
+    /// Set the style for option hints.
+    pub fn hint_style(mut self, style: Style) -> Self {
+        self.hint_style = style;
+        self
+    }
+
+    /// Set the style for text matching the search query.
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Set the style for option subtitles.
+    pub fn subtitle_style(mut self, style: Style) -> Self {
+        self.subtitle_style = style;
+        self
+    }
+
+    /// Set how far subtitles are indented past their labels.
+    pub fn subtitle_indent(mut self, indent: u16) -> Self {
+        self.subtitle_indent = indent;
+        self
+    }
+
+    /// Set the style for load errors.
+    pub fn error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
 }
 
+/// Function mapping an option to its subtitle.
+type SubtitleFn<'a, T> = Box<dyn Fn(&T) -> Option<String> + 'a>;
+
+/// Function checking whether an option is a separator.
+type SeparatorFn<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
 /// Default render function type for options.
 type DefaultRenderFn<T> = fn(&T) -> String;
 
@@ -362,6 +754,12 @@ where
     label: Option<&'a str>,
     render_option: F,
     focus_id: FocusId,
+    details: Option<OptionDetailsFn<'a, T>>,
+    subtitles: Option<SubtitleFn<'a, T>>,
+    separators: Option<SeparatorFn<'a, T>>,
+    search: &'a str,
+    tick: u64,
+    loading_message: &'a str,
 }
 
 impl<'a, T: std::fmt::Display> Select<'a, T, DefaultRenderFn<T>> {
@@ -375,10 +773,28 @@ impl<'a, T: std::fmt::Display> Select<'a, T, DefaultRenderFn<T>> {
             label: None,
             render_option: |opt| opt.to_string(),
             focus_id: FocusId::default(),
+            details: None,
+            subtitles: None,
+            separators: None,
+            search: "",
+            tick: 0,
+            loading_message: "Loading...",
         }
     }
 }
 
+impl<'a> Select<'a, SelectOption, DefaultRenderFn<SelectOption>> {
+    /// Create a select over [`SelectOption`]s, showing their subtitles and
+    /// separators.
+    ///
+    /// Pair with [`SelectState::set_options`] so navigation skips separators.
+    pub fn from_options(options: &'a [SelectOption], state: &'a SelectState) -> Self {
+        Self::new(options, state)
+            .option_subtitle(|opt| opt.subtitle.clone())
+            .option_separator(SelectOption::is_separator)
+    }
+}
+
 impl<'a, T, F> Select<'a, T, F>
 where
     F: Fn(&T) -> String,
@@ -396,15 +812,63 @@ where
             label: self.label,
             render_option: render_fn,
             focus_id: self.focus_id,
+            details: self.details,
+            subtitles: self.subtitles,
+            separators: self.separators,
+            search: self.search,
+            tick: self.tick,
+            loading_message: self.loading_message,
         }
     }
 
+    /// Set a function providing each option's subtitle.
+    ///
+    /// If any option has a subtitle, every option takes two rows so the
+    /// layout stays even.
+    pub fn option_subtitle(mut self, subtitle: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.subtitles = Some(Box::new(subtitle));
+        self
+    }
+
+    /// Set a function marking options as separator lines.
+    pub fn option_separator(mut self, separator: impl Fn(&T) -> bool + 'a) -> Self {
+        self.separators = Some(Box::new(separator));
+        self
+    }
+
+    /// Set a function providing each option's description and hint.
+    ///
+    /// Descriptions render on a second, dimmer line when the dropdown has
+    /// room for two rows per option, and are appended to the label otherwise.
+    pub fn option_details(mut self, details: impl Fn(&T) -> OptionDetails + 'a) -> Self {
+        self.details = Some(Box::new(details));
+        self
+    }
+
+    /// Highlight occurrences of `query` in option labels and descriptions.
+    pub fn search(mut self, query: &'a str) -> Self {
+        self.search = query;
+        self
+    }
+
     /// Set the placeholder text.
     pub fn placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = placeholder;
         self
     }
 
+    /// Set the caller's tick counter, which animates the loading spinner.
+    pub fn tick(mut self, tick: u64) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Set the message shown beside the spinner while options load.
+    pub fn loading_message(mut self, message: &'a str) -> Self {
+        self.loading_message = message;
+        self
+    }
+
     /// Set the label (border title).
     pub fn label(mut self, label: &'a str) -> Self {
         self.label = Some(label);
@@ -433,60 +897,7 @@ where
     /// This renders the compact closed state of the select box.
     /// Call `render_dropdown` separately when the dropdown is open.
     pub fn render_stateful(self, frame: &mut Frame, area: Rect) -> ClickRegion<SelectAction> {
-        let border_color = if !self.state.enabled {
-            self.style.disabled_border
-        } else if self.state.focused {
-            self.style.focused_border
-        } else {
-            self.style.unfocused_border
-        };
-
-        let mut block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(border_color));
-
-        if let Some(label) = self.label {
-            block = block.title(format!(" {} ", label));
-        }
-
-        let inner = block.inner(area);
-        frame.render_widget(block, area);
-
-        // Build display text
-        let display_text = if let Some(idx) = self.state.selected_index {
-            if idx < self.options.len() {
-                let text = (self.render_option)(&self.options[idx]);
-                Span::styled(text, Style::default().fg(self.style.text_fg))
-            } else {
-                Span::styled(
-                    self.placeholder,
-                    Style::default().fg(self.style.placeholder_fg),
-                )
-            }
-        } else {
-            Span::styled(
-                self.placeholder,
-                Style::default().fg(self.style.placeholder_fg),
-            )
-        };
-
-        // Add dropdown indicator on the right
-        let indicator_color = if self.state.focused {
-            self.style.focused_border
-        } else {
-            self.style.unfocused_border
-        };
-
-        let indicator = Span::styled(
-            format!(" {}", self.style.dropdown_indicator),
-            Style::default().fg(indicator_color),
-        );
-
-        let line = Line::from(vec![display_text, indicator]);
-        let paragraph = Paragraph::new(line);
-        frame.render_widget(paragraph, inner);
-
-        ClickRegion::new(area, SelectAction::Focus)
+        self.render_to_buffer(area, frame.buffer_mut())
     }
 
     /// Render the dropdown overlay.
@@ -497,28 +908,82 @@ where
     ///
     /// * `frame` - The frame to render to
     /// * `anchor` - The area of the closed select box (dropdown positions below this)
-    /// * `screen` - The full screen area (for bounds checking)
+    /// * `screen` - The screen area or [`SafeArea`](crate::state::SafeArea)
+    ///   the dropdown must stay within
     pub fn render_dropdown(
         &self,
         frame: &mut Frame,
         anchor: Rect,
-        screen: Rect,
+        screen: impl Into<Rect>,
+    ) -> Vec<ClickRegion<SelectAction>> {
+        self.render_dropdown_to_buffer(anchor, screen, frame.buffer_mut())
+    }
+
+    /// Render the dropdown into an [`OverlayManager`] on the
+    /// [`OverlayLayer::Dropdown`] layer.
+    ///
+    /// Same as [`render_dropdown`](Self::render_dropdown) otherwise.
+    pub fn render_dropdown_into_overlays(
+        &self,
+        anchor: Rect,
+        screen: impl Into<Rect>,
+        overlays: &mut OverlayManager<'_>,
     ) -> Vec<ClickRegion<SelectAction>> {
+        let screen = screen.into();
+        overlays.capture(OverlayLayer::Dropdown, screen, |buf| {
+            self.render_dropdown_to_buffer(anchor, screen, buf)
+        })
+    }
+
+    /// Render the dropdown overlay using Buffer.
+    ///
+    /// Same as [`render_dropdown`](Self::render_dropdown), for rendering
+    /// without a Frame reference.
+    pub fn render_dropdown_to_buffer(
+        &self,
+        anchor: Rect,
+        screen: impl Into<Rect>,
+        buf: &mut Buffer,
+    ) -> Vec<ClickRegion<SelectAction>> {
+        let screen = screen.into();
         let mut regions = Vec::new();
 
         if self.options.is_empty() {
             return regions;
         }
 
-        let visible_count = (self.options.len() as u16).min(self.style.max_visible_options);
-        let dropdown_height = visible_count + 2; // +2 for borders
+        let details: Vec<OptionDetails> = match &self.details {
+            Some(details) => self.options.iter().map(details).collect(),
+            None => vec![OptionDetails::default(); self.options.len()],
+        };
+        let subtitles: Vec<Option<String>> = match &self.subtitles {
+            Some(subtitles) => self.options.iter().map(subtitles).collect(),
+            None => vec![None; self.options.len()],
+        };
+        let has_subtitles = subtitles.iter().any(Option::is_some);
 
-        let dropdown_width = anchor.width;
+        let visible_count = (self.options.len() as u16).min(self.style.max_visible_options);
 
         // Position dropdown below the anchor, but flip up if not enough space
-        let space_below = screen.height.saturating_sub(anchor.y + anchor.height);
+        let space_below = screen.bottom().saturating_sub(anchor.bottom());
         let space_above = anchor.y.saturating_sub(screen.y);
 
+        // Subtitles always get their own row; descriptions only if every
+        // visible option fits
+        let two_row_height = calculate_dropdown_height_for_rows(
+            self.options.len(),
+            self.style.max_visible_options,
+            2,
+        );
+        let description_row_fits = !has_subtitles
+            && details.iter().any(|d| d.description.is_some())
+            && (space_below >= two_row_height || space_above >= two_row_height);
+        let two_row = has_subtitles || description_row_fits;
+        let rows_per_option: u16 = if two_row { 2 } else { 1 };
+        let dropdown_height = visible_count * rows_per_option + 2; // +2 for borders
+
+        let dropdown_width = anchor.width;
+
         let (dropdown_y, flip_up) = if space_below >= dropdown_height {
             (anchor.y + anchor.height, false)
         } else if space_above >= dropdown_height {
@@ -536,7 +1001,7 @@ where
         );
 
         // Clear background
-        frame.render_widget(Clear, dropdown_area);
+        Clear.render(dropdown_area, buf);
 
         // Render border
         let block = Block::default()
@@ -544,10 +1009,10 @@ where
             .border_style(Style::default().fg(self.style.dropdown_border));
 
         let inner = block.inner(dropdown_area);
-        frame.render_widget(block, dropdown_area);
+        block.render(dropdown_area, buf);
 
         // Render options
-        let actual_visible = inner.height as usize;
+        let actual_visible = (inner.height / rows_per_option) as usize;
         let scroll = self.state.scroll_offset as usize;
 
         for (i, option) in self
@@ -557,8 +1022,19 @@ where
             .skip(scroll)
             .take(actual_visible)
         {
-            let y = inner.y + (i - scroll) as u16;
-            let option_area = Rect::new(inner.x, y, inner.width, 1);
+            let y = inner.y + (i - scroll) as u16 * rows_per_option;
+            let option_area = Rect::new(inner.x, y, inner.width, rows_per_option);
+
+            if self.separators.as_ref().is_some_and(|sep| sep(option)) {
+                let line = "─".repeat(inner.width as usize);
+                buf.set_string(
+                    inner.x,
+                    y,
+                    line,
+                    Style::default().fg(self.style.dropdown_border),
+                );
+                continue;
+            }
 
             let is_highlighted = i == self.state.highlighted_index;
             let is_selected = self.state.selected_index == Some(i);
@@ -575,14 +1051,66 @@ where
                 self.style.unselected_indicator
             };
 
-            let text = format!("{}{}", prefix, (self.render_option)(option));
+            let row_styles = OptionRowStyles {
+                label: style,
+                description: if is_highlighted {
+                    style
+                } else {
+                    self.style.description_style
+                },
+                hint: if is_highlighted {
+                    style
+                } else {
+                    self.style.hint_style
+                },
+                matched: self.style.match_style,
+            };
 
-            // Truncate if too long
-            let max_width = inner.width as usize;
-            let display_text: String = text.chars().take(max_width).collect();
+            let detail = &details[i];
+            let indent = prefix.chars().count();
+            let width = inner.width as usize;
+            let mut spans = vec![Span::styled(prefix, style)];
+            spans.extend(option_row(
+                &(self.render_option)(option),
+                detail
+                    .description
+                    .as_deref()
+                    .filter(|_| !description_row_fits),
+                detail.hint.as_deref(),
+                width.saturating_sub(indent),
+                self.search,
+                row_styles,
+            ));
+
+            let mut lines = vec![Line::from(spans)];
+            if let Some(subtitle) = &subtitles[i] {
+                let subtitle_styles = OptionRowStyles {
+                    description: if is_highlighted {
+                        style
+                    } else {
+                        self.style.subtitle_style
+                    },
+                    ..row_styles
+                };
+                lines.push(description_row(
+                    subtitle,
+                    indent + self.style.subtitle_indent as usize,
+                    width,
+                    self.search,
+                    subtitle_styles,
+                ));
+            } else if description_row_fits && let Some(description) = &detail.description {
+                lines.push(description_row(
+                    description,
+                    indent,
+                    width,
+                    self.search,
+                    row_styles,
+                ));
+            }
 
-            let paragraph = Paragraph::new(Span::styled(display_text, style));
-            frame.render_widget(paragraph, option_area);
+            let paragraph = Paragraph::new(lines);
+            paragraph.render(option_area, buf);
 
             // Register click region for this option
             regions.push(ClickRegion::new(option_area, SelectAction::Select(i)));
@@ -595,17 +1123,12 @@ where
     ///
     /// This is useful when you need to render without a Frame reference.
     pub fn render_to_buffer(self, area: Rect, buf: &mut Buffer) -> ClickRegion<SelectAction> {
-        let border_color = if !self.state.enabled {
-            self.style.disabled_border
-        } else if self.state.focused {
-            self.style.focused_border
-        } else {
-            self.style.unfocused_border
-        };
-
-        let mut block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(border_color));
+        let ring = self.style.focus_ring();
+        let mut block = ring.block(
+            Block::default().borders(Borders::ALL),
+            self.state.focused,
+            self.state.enabled,
+        );
 
         if let Some(label) = self.label {
             block = block.title(format!(" {} ", label));
@@ -614,6 +1137,18 @@ where
         let inner = block.inner(area);
         block.render(area, buf);
 
+        // Spinner or load error instead of the value
+        if let Some(spans) = load_status_spans(
+            &self.state.load,
+            self.tick,
+            self.loading_message,
+            Style::default().fg(self.style.placeholder_fg),
+            self.style.error_style,
+        ) {
+            Paragraph::new(Line::from(spans)).render(inner, buf);
+            return ClickRegion::new(area, SelectAction::Focus);
+        }
+
         // Build display text
         let display_text = if let Some(idx) = self.state.selected_index {
             if idx < self.options.len() {
@@ -632,11 +1167,7 @@ where
             )
         };
 
-        let indicator_color = if self.state.focused {
-            self.style.focused_border
-        } else {
-            self.style.unfocused_border
-        };
+        let indicator_color = ring.color(self.state.focused, true);
 
         let indicator = Span::styled(
             format!(" {}", self.style.dropdown_indicator),
@@ -661,21 +1192,55 @@ where
 /// - `Enter`, `Space`, `Down` - Open dropdown
 ///
 /// When open:
-/// - `Esc` - Close without selection
+/// - `Esc` - Close without selection (left unhandled while closed, so it
+///   can propagate; see [`CloseRequest`])
 /// - `Enter`, `Space` - Select highlighted option
 /// - `Up` - Move highlight up
 /// - `Down` - Move highlight down
 /// - `Home` - Move to first option
 /// - `End` - Move to last option
-/// - `PageUp` - Move up by 5
-/// - `PageDown` - Move down by 5
+/// - `PageUp`, `PageDown` - Move by a page of
+///   [`SelectState::visible_options`], sized by [`SelectState::page_size`]
+/// - `Ctrl+U`, `Ctrl+D` - Move by half a page
+///
+/// While the options are loading, keys are ignored. When loading failed,
+/// `Enter`, `Space`, or `r` returns [`SelectAction::RetryRequested`].
 pub fn handle_select_key(key: &KeyEvent, state: &mut SelectState) -> Option<SelectAction> {
     if !state.enabled {
         return None;
     }
 
+    if state.load.is_pending() {
+        let retry = matches!(key.code, KeyCode::Char(' '))
+            || handle_loadable_retry_key(key, &state.load).is_some();
+        return (retry && state.load.is_failed()).then_some(SelectAction::RetryRequested);
+    }
+
+    // Type-ahead, when labels are set
+    if let KeyCode::Char(c) = key.code
+        && !c.is_whitespace()
+        && !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        && !state.option_labels.is_empty()
+    {
+        let previous = state.selected_index;
+        let found = state.type_ahead(c);
+        if state.is_open {
+            state.ensure_visible(state.visible_options);
+            return None;
+        }
+        return found
+            .filter(|&idx| previous != Some(idx))
+            .map(SelectAction::Changed);
+    }
+
     if state.is_open {
         // Dropdown is open - handle navigation
+        if let Some(page_move) = PageMove::from_key(key) {
+            state.highlight_page(page_move);
+            return None;
+        }
         match key.code {
             KeyCode::Esc => {
                 state.close();
@@ -688,35 +1253,12 @@ pub fn handle_select_key(key: &KeyEvent, state: &mut SelectState) -> Option<Sele
             }
             KeyCode::Up => {
                 state.highlight_prev();
-                state.ensure_visible(8); // Use default visible count
+                state.ensure_visible(state.visible_options);
                 None
             }
             KeyCode::Down => {
                 state.highlight_next();
-                state.ensure_visible(8);
-                None
-            }
-            KeyCode::Home => {
-                state.highlight_first();
-                None
-            }
-            KeyCode::End => {
-                state.highlight_last();
-                state.ensure_visible(8);
-                None
-            }
-            KeyCode::PageUp => {
-                for _ in 0..5 {
-                    state.highlight_prev();
-                }
-                state.ensure_visible(8);
-                None
-            }
-            KeyCode::PageDown => {
-                for _ in 0..5 {
-                    state.highlight_next();
-                }
-                state.ensure_visible(8);
+                state.ensure_visible(state.visible_options);
                 None
             }
             _ => None,
@@ -736,6 +1278,8 @@ pub fn handle_select_key(key: &KeyEvent, state: &mut SelectState) -> Option<Sele
 /// Handle mouse events for select component.
 ///
 /// Returns `Some(SelectAction)` if an action was triggered, `None` otherwise.
+/// Clicking the select box after loading failed returns
+/// [`SelectAction::RetryRequested`].
 ///
 /// # Arguments
 ///
@@ -788,6 +1332,11 @@ pub fn handle_select_mouse(
                 && row >= select_area.y
                 && row < select_area.y + select_area.height
             {
+                if state.load.is_failed() {
+                    return Some(SelectAction::RetryRequested);
+                } else if state.load.is_loading() {
+                    return None;
+                }
                 state.open();
                 return Some(SelectAction::Open);
             }
@@ -802,8 +1351,31 @@ pub fn handle_select_mouse(
 ///
 /// Useful for layout calculations.
 pub fn calculate_dropdown_height(option_count: usize, max_visible: u16) -> u16 {
+    calculate_dropdown_height_for_rows(option_count, max_visible, 1)
+}
+
+/// Calculate the dropdown height for a list of [`SelectOption`]s.
+///
+/// Uses two rows per option if any option has a subtitle.
+pub fn calculate_dropdown_height_for_options(options: &[SelectOption], max_visible: u16) -> u16 {
+    let rows = if options.iter().any(|opt| opt.subtitle.is_some()) {
+        2
+    } else {
+        1
+    };
+    calculate_dropdown_height_for_rows(options.len(), max_visible, rows)
+}
+
+/// Calculate the dropdown height when each option takes `rows_per_option` rows.
+///
+/// Use `2` for options rendered with a description line.
+pub fn calculate_dropdown_height_for_rows(
+    option_count: usize,
+    max_visible: u16,
+    rows_per_option: u16,
+) -> u16 {
     let visible = (option_count as u16).min(max_visible);
-    visible + 2 // +2 for borders
+    visible * rows_per_option + 2 // +2 for borders
 }
 
 #[cfg(test)]
@@ -887,6 +1459,42 @@ mod tests {
         assert_eq!(state.highlighted_index, 4);
     }
 
+    #[test]
+    fn test_navigation_skips_separators() {
+        let options = [
+            SelectOption::separator(),
+            SelectOption::new("One"),
+            SelectOption::separator(),
+            SelectOption::new("Two"),
+            SelectOption::separator(),
+        ];
+        let mut state = SelectState::default();
+        state.set_options(&options);
+        assert_eq!(state.separators, vec![0, 2, 4]);
+        assert_eq!(state.highlighted_index, 1);
+
+        state.highlight_next();
+        assert_eq!(state.highlighted_index, 3);
+        state.highlight_next();
+        assert_eq!(state.highlighted_index, 3);
+        state.highlight_prev();
+        assert_eq!(state.highlighted_index, 1);
+        state.highlight_prev();
+        assert_eq!(state.highlighted_index, 1);
+
+        state.highlight_last();
+        assert_eq!(state.highlighted_index, 3);
+        state.highlight_first();
+        assert_eq!(state.highlighted_index, 1);
+
+        // Separators can't be selected
+        state.select(2);
+        assert_eq!(state.selected_index, None);
+        state.highlighted_index = 2;
+        state.select_highlighted();
+        assert_eq!(state.selected_index, None);
+    }
+
     #[test]
     fn test_highlight_bounds() {
         let mut state = SelectState::new(3);
@@ -1050,6 +1658,86 @@ mod tests {
         assert!(!state.is_open);
     }
 
+    #[test]
+    fn test_type_ahead_closed() {
+        let mut state = SelectState::new(0);
+        state.set_option_labels(["Apple", "banana", "Blueberry", "Cherry", "Bluebell"]);
+        assert_eq!(state.total_options, 5);
+        let key = |c| KeyEvent::from(KeyCode::Char(c));
+
+        // Jumps without opening the dropdown
+        let action = handle_select_key(&key('b'), &mut state);
+        assert_eq!(action, Some(SelectAction::Changed(1)));
+        assert!(!state.is_open);
+
+        // Repeating the letter cycles, wrapping around
+        assert_eq!(
+            handle_select_key(&key('B'), &mut state),
+            Some(SelectAction::Changed(2))
+        );
+        assert_eq!(
+            handle_select_key(&key('b'), &mut state),
+            Some(SelectAction::Changed(4))
+        );
+        assert_eq!(
+            handle_select_key(&key('b'), &mut state),
+            Some(SelectAction::Changed(1))
+        );
+
+        // The prefix resets after the timeout
+        for _ in 0..DEFAULT_TYPE_AHEAD_TIMEOUT {
+            state.tick();
+        }
+        assert!(state.type_ahead.is_empty());
+
+        // Typing quickly narrows the prefix
+        handle_select_key(&key('b'), &mut state);
+        handle_select_key(&key('l'), &mut state);
+        assert_eq!(state.selected_index, Some(2));
+        assert_eq!(
+            handle_select_key(&key('u'), &mut state),
+            None,
+            "still on Blueberry"
+        );
+        for c in "ebel".chars() {
+            handle_select_key(&key(c), &mut state);
+        }
+        assert_eq!(state.selected_index, Some(4));
+
+        // No match leaves the selection alone
+        state.type_ahead.clear();
+        assert_eq!(handle_select_key(&key('z'), &mut state), None);
+        assert_eq!(state.selected_index, Some(4));
+
+        // Space still opens the dropdown
+        assert_eq!(
+            handle_select_key(&key(' '), &mut state),
+            Some(SelectAction::Open)
+        );
+    }
+
+    #[test]
+    fn test_type_ahead_open_moves_highlight() {
+        let mut state = SelectState::with_selected(0, 0);
+        state.set_option_labels(["a", "b", "c"]);
+        state.selected_index = Some(0);
+        state.open();
+
+        let action = handle_select_key(&KeyEvent::from(KeyCode::Char('c')), &mut state);
+        assert_eq!(action, None);
+        assert_eq!(state.highlighted_index, 2);
+        assert_eq!(state.selected_index, Some(0));
+        assert!(state.is_open);
+
+        // Without labels, letters do nothing
+        let mut state = SelectState::new(3);
+        assert_eq!(
+            handle_select_key(&KeyEvent::from(KeyCode::Char('c')), &mut state),
+            None
+        );
+        assert_eq!(state.selected_index, None);
+    }
+
     #[test]
     fn test_calculate_dropdown_height() {
         assert_eq!(calculate_dropdown_height(3, 8), 5); // 3 + 2
@@ -1066,4 +1754,237 @@ mod tests {
         assert!(!region.contains(9, 5));
         assert!(!region.contains(30, 5));
     }
+
+    #[test]
+    fn test_dropdown_space_below_offset_screen() {
+        use crate::state::SafeArea;
+
+        let options = ["A", "B", "C"];
+        let state = SelectState::new(options.len());
+        let select = Select::new(&options, &state);
+
+        // A 2-row header is reserved; 6 rows remain below the anchor, enough
+        // for all three options plus borders
+        let screen = Rect::new(0, 0, 20, 12);
+        let safe = SafeArea::new(screen).reserve_top(2);
+        let mut buf = Buffer::empty(screen);
+        let regions = select.render_dropdown_to_buffer(Rect::new(0, 3, 20, 3), safe, &mut buf);
+        assert_eq!(regions.len(), 3);
+        assert_eq!(regions[0].area.y, 7);
+    }
+
+    fn render_dropdown_rows(select: &Select<'_, &str>, screen_height: u16) -> Vec<String> {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut terminal = Terminal::new(TestBackend::new(30, screen_height)).unwrap();
+        terminal
+            .draw(|f| {
+                select.render_dropdown(f, Rect::new(0, 0, 30, 3), f.area());
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        (0..screen_height)
+            .map(|y| (0..30).map(|x| buf[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_dropdown_description_rows() {
+        let options = ["Stable", "Nightly"];
+        let state = SelectState::new(options.len());
+        let select = Select::new(&options, &state).option_details(|opt| match *opt {
+            "Stable" => OptionDetails::new().description("Tested").hint("default"),
+            _ => OptionDetails::new(),
+        });
+
+        // Room for two rows per option: description on its own line
+        let rows = render_dropdown_rows(&select, 12);
+        assert_eq!(rows[4].trim_end(), "│  Stable             default│");
+        assert_eq!(rows[5].trim_end(), "│  Tested                    │");
+        assert!(rows[6].starts_with("│  Nightly"));
+
+        // Too short: description is appended instead
+        let rows = render_dropdown_rows(&select, 7);
+        assert_eq!(rows[4].trim_end(), "│  Stable · Tested    default│");
+        assert!(rows[5].starts_with("│  Nightly"));
+    }
+
+    #[test]
+    fn test_dropdown_subtitles_and_separator() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let options = [
+            SelectOption::new("Stable").with_subtitle("Tested"),
+            SelectOption::separator(),
+            SelectOption::new("Nightly"),
+        ];
+        let mut state = SelectState::default();
+        state.set_options(&options);
+        state.selected_index = Some(2);
+        state.open();
+        let select = Select::from_options(&options, &state);
+
+        // Subtitles give every option two rows, separators included
+        let mut terminal = Terminal::new(TestBackend::new(20, 11)).unwrap();
+        terminal
+            .draw(|f| {
+                let regions = select.render_dropdown(f, Rect::new(0, 0, 20, 3), f.area());
+                assert_eq!(regions.len(), 2);
+                assert_eq!(regions[1].data, SelectAction::Select(2));
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol()).collect() };
+        assert_eq!(row(4), "│  Stable          │");
+        assert_eq!(row(5), "│    Tested        │");
+        assert_eq!(row(6), "│──────────────────│");
+        assert_eq!(row(8), "│✓ Nightly         │");
+        assert_eq!(
+            buf[(5, 5)].fg,
+            SelectStyle::default().subtitle_style.fg.unwrap()
+        );
+
+        // The closed select shows only the label
+        drop(select);
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        state.selected_index = Some(0);
+        Select::from_options(&options, &state).render_to_buffer(area, &mut buf);
+        let text: String = (1..19).map(|x| buf[(x, 1)].symbol()).collect();
+        assert_eq!(text.trim_end(), "Stable ▼");
+
+        assert_eq!(calculate_dropdown_height_for_options(&options, 8), 8);
+        assert_eq!(
+            calculate_dropdown_height_for_options(&options[1..], 8),
+            calculate_dropdown_height(2, 8)
+        );
+    }
+
+    #[test]
+    fn test_calculate_dropdown_height_for_rows() {
+        assert_eq!(calculate_dropdown_height_for_rows(3, 8, 2), 8);
+        assert_eq!(calculate_dropdown_height_for_rows(20, 8, 2), 18);
+        assert_eq!(
+            calculate_dropdown_height_for_rows(5, 8, 1),
+            calculate_dropdown_height(5, 8)
+        );
+    }
+
+    #[test]
+    fn test_render_focus_ring() {
+        let options = vec!["One", "Two"];
+        let style = SelectStyle::default();
+        for (focused, color) in [
+            (true, style.focused_border),
+            (false, style.unfocused_border),
+        ] {
+            let mut state = SelectState::new(options.len());
+            state.focused = focused;
+            let area = Rect::new(0, 0, 12, 3);
+            let mut buf = Buffer::empty(area);
+            Select::new(&options, &state).render_to_buffer(area, &mut buf);
+
+            assert_eq!(buf[(0, 0)].symbol(), "┌");
+            assert_eq!(buf[(0, 0)].fg, color);
+            assert_eq!(buf[(11, 2)].fg, color);
+        }
+
+        let mut state = SelectState::new(options.len());
+        state.focused = true;
+        state.enabled = false;
+        let area = Rect::new(0, 0, 12, 3);
+        let mut buf = Buffer::empty(area);
+        Select::new(&options, &state).render_to_buffer(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, style.disabled_border);
+    }
+
+    #[test]
+    fn test_render_dropdown_to_buffer() {
+        let options = ["One", "Two"];
+        let state = SelectState::new(options.len());
+        let screen = Rect::new(0, 0, 12, 8);
+        let mut buf = Buffer::empty(screen);
+        buf.set_string(0, 4, "xxxxxxxxxxxx", Style::default());
+
+        let regions = Select::new(&options, &state).render_dropdown_to_buffer(
+            Rect::new(0, 0, 12, 3),
+            screen,
+            &mut buf,
+        );
+
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[1].area, Rect::new(1, 5, 10, 1));
+        let row = |y: u16| -> String { (0..12).map(|x| buf[(x, y)].symbol()).collect() };
+        assert_eq!(row(3), "┌──────────┐");
+        assert_eq!(row(4), "│  One     │");
+        assert_eq!(row(6), "└──────────┘");
+    }
+
+    #[test]
+    fn test_loading_and_retry() {
+        let options = ["One", "Two"];
+        let area = Rect::new(0, 0, 24, 3);
+        let row = |buf: &Buffer| -> String { (0..24).map(|x| buf[(x, 1)].symbol()).collect() };
+        let mut state = SelectState::new(0);
+
+        state.set_loading(3);
+        let mut buf = Buffer::empty(area);
+        Select::new(&[] as &[&str], &state)
+            .tick(3)
+            .render_to_buffer(area, &mut buf);
+        assert!(row(&buf).contains("⠋ Loading..."));
+        let enter = KeyEvent::from(KeyCode::Enter);
+        assert_eq!(handle_select_key(&enter, &mut state), None);
+        state.open();
+        assert!(!state.is_open);
+
+        state.set_failed("timeout");
+        let mut buf = Buffer::empty(area);
+        Select::new(&[] as &[&str], &state).render_to_buffer(area, &mut buf);
+        assert!(row(&buf).contains("✗ timeout [r] Retry"));
+        assert_eq!(
+            handle_select_key(&KeyEvent::from(KeyCode::Char('r')), &mut state),
+            Some(SelectAction::RetryRequested)
+        );
+        assert!(!state.is_open);
+
+        state.set_loaded(options.len());
+        state.select(1);
+        let mut buf = Buffer::empty(area);
+        Select::new(&options, &state).render_to_buffer(area, &mut buf);
+        assert!(row(&buf).contains("Two"));
+        handle_select_key(&enter, &mut state);
+        assert!(state.is_open);
+    }
+
+    #[test]
+    fn test_paging_keys_at_boundaries() {
+        let mut state = SelectState::new(30)
+            .with_visible_options(6)
+            .with_page_size(PageSize::Fixed(4));
+        state.separators = vec![4, 29];
+        state.open();
+        let key = KeyEvent::from;
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        handle_select_key(&key(KeyCode::PageUp), &mut state);
+        assert_eq!(state.highlighted_index, 0);
+        // Lands on a separator, skipped forwards
+        handle_select_key(&key(KeyCode::PageDown), &mut state);
+        assert_eq!(state.highlighted_index, 5);
+        handle_select_key(&ctrl('d'), &mut state);
+        assert_eq!(state.highlighted_index, 8);
+        assert_eq!(state.scroll_offset, 3);
+        // Trailing separator falls back to the last option
+        handle_select_key(&key(KeyCode::End), &mut state);
+        assert_eq!(state.highlighted_index, 28);
+        handle_select_key(&key(KeyCode::PageDown), &mut state);
+        assert_eq!(state.highlighted_index, 28);
+        handle_select_key(&ctrl('u'), &mut state);
+        assert_eq!(state.highlighted_index, 25);
+        handle_select_key(&key(KeyCode::Home), &mut state);
+        assert_eq!(state.highlighted_index, 0);
+        assert_eq!(state.scroll_offset, 0);
+        assert!(state.is_open);
+    }
 }
@@ -25,8 +25,8 @@
 //!     .frames(SpinnerFrames::Braille)
 //!     .label("Processing");
 //!
-//! // In your event loop, advance the animation
-//! state.tick();
+//! // In your event loop, advance the animation once its interval has elapsed
+//! state.advance_if_ready();
 //! ```
 
 use std::time::{Duration, Instant};
@@ -35,10 +35,14 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
+    text::Span,
     widgets::Widget,
 };
 use unicode_width::UnicodeWidthStr;
 
+use crate::capabilities::Capabilities;
+use crate::state::Loadable;
+
 /// Predefined spinner frame sets
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SpinnerFrames {
@@ -90,6 +94,26 @@ impl SpinnerFrames {
         }
     }
 
+    /// Check if every frame is plain ASCII
+    pub fn is_ascii(&self) -> bool {
+        self.frames().iter().all(|frame| frame.is_ascii())
+    }
+
+    /// These frames, or [`Line`](Self::Line) if they need Unicode and the
+    /// terminal lacks it
+    pub fn for_capabilities(self, caps: &Capabilities) -> Self {
+        if caps.unicode || self.is_ascii() {
+            self
+        } else {
+            SpinnerFrames::Line
+        }
+    }
+
+    /// Create a spinner state for these frames with a custom tick interval
+    pub fn with_interval_ms(self, interval_ms: u64) -> SpinnerState {
+        SpinnerState::new_with_interval(self, Duration::from_millis(interval_ms))
+    }
+
     /// Get the recommended interval for this spinner style (in milliseconds)
     pub fn interval_ms(&self) -> u64 {
         match self {
@@ -115,9 +139,11 @@ pub struct SpinnerState {
     /// Current frame index
     pub frame: usize,
     /// Last tick time
-    last_tick: Option<Instant>,
-    /// Frame interval
-    interval: Duration,
+    pub last_tick: Option<Instant>,
+    /// Minimum time between frames
+    pub tick_interval: Duration,
+    /// Number of frames to cycle through
+    frame_count: usize,
     /// Whether the spinner is active
     pub active: bool,
 }
@@ -134,7 +160,8 @@ impl SpinnerState {
         Self {
             frame: 0,
             last_tick: None,
-            interval: Duration::from_millis(80),
+            tick_interval: Duration::from_millis(80),
+            frame_count: 10,
             active: true,
         }
     }
@@ -142,28 +169,51 @@ impl SpinnerState {
     /// Create a new spinner state with a specific interval
     pub fn with_interval(interval_ms: u64) -> Self {
         Self {
-            frame: 0,
-            last_tick: None,
-            interval: Duration::from_millis(interval_ms),
-            active: true,
+            tick_interval: Duration::from_millis(interval_ms),
+            ..Self::new()
         }
     }
 
     /// Create a new spinner state configured for specific frames
     pub fn for_frames(frames: SpinnerFrames) -> Self {
-        Self::with_interval(frames.interval_ms())
+        Self::new_with_interval(frames, Duration::from_millis(frames.interval_ms()))
+    }
+
+    /// Create a new spinner state for specific frames with a custom interval
+    pub fn new_with_interval(frames: SpinnerFrames, interval: Duration) -> Self {
+        Self {
+            tick_interval: interval,
+            frame_count: frames.frames().len(),
+            ..Self::new()
+        }
     }
 
     /// Set the frame interval
     pub fn set_interval(&mut self, interval_ms: u64) {
-        self.interval = Duration::from_millis(interval_ms);
+        self.tick_interval = Duration::from_millis(interval_ms);
+    }
+
+    /// Check if the tick interval has elapsed since the last tick
+    ///
+    /// Returns true if the spinner has never ticked.
+    pub fn should_tick(&self) -> bool {
+        self.last_tick
+            .is_none_or(|last| last.elapsed() >= self.tick_interval)
+    }
+
+    /// Advance the animation if the tick interval has elapsed
+    ///
+    /// Call this from the event loop on every iteration; it does nothing
+    /// when called too soon. Returns true if the frame changed.
+    pub fn advance_if_ready(&mut self) -> bool {
+        self.should_tick() && self.tick()
     }
 
     /// Advance to the next frame if enough time has passed
     ///
     /// Returns true if the frame changed
     pub fn tick(&mut self) -> bool {
-        self.tick_with_frames(10) // Default frame count
+        self.tick_with_frames(self.frame_count)
     }
 
     /// Advance to the next frame with a specific frame count
@@ -177,7 +227,7 @@ impl SpinnerState {
         let now = Instant::now();
 
         match self.last_tick {
-            Some(last) if now.duration_since(last) >= self.interval => {
+            Some(last) if now.duration_since(last) >= self.tick_interval => {
                 self.frame = (self.frame + 1) % frame_count;
                 self.last_tick = Some(now);
                 true
@@ -219,6 +269,33 @@ impl SpinnerState {
     }
 }
 
+/// Retry hint shown after a failed load; Enter or `r` retries
+pub(crate) const RETRY_HINT: &str = "[r] Retry";
+
+/// Status spans for pending [`Loadable`] data
+///
+/// A spinner frame and `message` while loading, the error and a retry hint
+/// after a failure, and `None` otherwise. `tick` is the caller's tick
+/// counter, which advances the spinner.
+pub(crate) fn load_status_spans<T>(
+    data: &Loadable<T>,
+    tick: u64,
+    message: &str,
+    text_style: Style,
+    error_style: Style,
+) -> Option<Vec<Span<'static>>> {
+    if let Some(ticks) = data.loading_ticks(tick) {
+        let frames = SpinnerFrames::Dots.frames();
+        let frame = frames[(ticks % frames.len() as u64) as usize];
+        return Some(vec![Span::styled(format!("{frame} {message}"), text_style)]);
+    }
+    let error = data.error()?;
+    Some(vec![
+        Span::styled(format!("✗ {error}"), error_style),
+        Span::styled(format!(" {RETRY_HINT}"), text_style),
+    ])
+}
+
 /// Label position relative to the spinner
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum LabelPosition {
@@ -282,6 +359,20 @@ impl SpinnerStyle {
         }
     }
 
+    /// Style with the ASCII [`Line`](SpinnerFrames::Line) frames
+    pub fn ascii() -> Self {
+        Self::new(SpinnerFrames::Line)
+    }
+
+    /// The default style, or [`ascii`](Self::ascii) without Unicode support
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        if caps.unicode {
+            Self::default()
+        } else {
+            Self::ascii()
+        }
+    }
+
     /// Set the spinner frames
     pub fn frames(mut self, frames: SpinnerFrames) -> Self {
         self.frames = frames;
@@ -506,7 +597,39 @@ mod tests {
     #[test]
     fn test_spinner_state_for_frames() {
         let state = SpinnerState::for_frames(SpinnerFrames::Braille);
-        assert_eq!(state.interval, Duration::from_millis(80));
+        assert_eq!(state.tick_interval, Duration::from_millis(80));
+    }
+
+    #[test]
+    fn test_spinner_state_new_with_interval() {
+        let state = SpinnerState::new_with_interval(SpinnerFrames::Line, Duration::from_millis(30));
+        assert_eq!(state.tick_interval, Duration::from_millis(30));
+
+        let state = SpinnerFrames::Moon.with_interval_ms(40);
+        assert_eq!(state.tick_interval, Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_spinner_advance_if_ready() {
+        let mut state =
+            SpinnerState::new_with_interval(SpinnerFrames::Line, Duration::from_secs(60));
+        assert!(state.should_tick());
+
+        // First call only records the tick time
+        assert!(!state.advance_if_ready());
+        assert!(state.last_tick.is_some());
+
+        // Called again too quickly: no advance
+        assert!(!state.should_tick());
+        assert!(!state.advance_if_ready());
+        assert_eq!(state.frame, 0);
+
+        // Once the interval has elapsed, the frame advances and wraps with the frame count
+        state.frame = 3;
+        state.last_tick = Some(Instant::now() - Duration::from_secs(61));
+        assert!(state.should_tick());
+        assert!(state.advance_if_ready());
+        assert_eq!(state.frame, 0);
     }
 
     #[test]
@@ -642,4 +765,30 @@ mod tests {
         spinner.render(Rect::new(0, 0, 0, 0), &mut buf);
         // Should not panic on empty area
     }
+
+    #[test]
+    fn test_ascii_fallback() {
+        let basic = Capabilities::basic();
+        assert!(!SpinnerFrames::Dots.is_ascii());
+        assert!(SpinnerFrames::Line.is_ascii());
+        assert_eq!(
+            SpinnerFrames::Dots.for_capabilities(&basic),
+            SpinnerFrames::Line
+        );
+        assert_eq!(
+            SpinnerFrames::Dots.for_capabilities(&Capabilities::full()),
+            SpinnerFrames::Dots
+        );
+
+        let mut state = SpinnerState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        for _ in 0..4 {
+            Spinner::new(&state)
+                .style(SpinnerStyle::from_capabilities(&basic))
+                .label("Loading")
+                .render(buf.area, &mut buf);
+            assert!(buf.content().iter().all(|c| c.symbol().is_ascii()));
+            state.frame += 1;
+        }
+    }
 }
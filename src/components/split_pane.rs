@@ -25,6 +25,57 @@
 //!     &mut registry,
 //! );
 //! ```
+//!
+//! # Pane focus
+//!
+//! [`SplitPaneState`] tracks which pane the user is in ([`SplitPaneFocus`]).
+//! [`handle_split_pane_focus_key`] switches panes (Ctrl+W then h/l by
+//! default, see [`SplitPaneKeys`]) and tells the app where every other key
+//! should go:
+//!
+//! ```rust
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use ratatui_interact::components::{
+//!     SplitPaneFocus, SplitPaneKeyRoute, SplitPaneKeys, SplitPaneState,
+//!     handle_split_pane_focus_key,
+//! };
+//! use ratatui_interact::state::FocusManager;
+//!
+//! let mut state = SplitPaneState::half();
+//! let keys = SplitPaneKeys::default();
+//!
+//! // One FocusManager per pane keeps Tab inside the pane the user is in
+//! let mut left = FocusManager::new();
+//! left.register_all([0, 1]);
+//! let mut right = FocusManager::new();
+//! right.register_all([10, 11]);
+//!
+//! let ctrl_w = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL);
+//! let l = KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE);
+//! let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+//!
+//! for key in [ctrl_w, l, tab] {
+//!     match handle_split_pane_focus_key(&mut state, &key, &keys) {
+//!         SplitPaneKeyRoute::Consumed => {}
+//!         SplitPaneKeyRoute::Forward(SplitPaneFocus::Divider) => {
+//!             // handle_split_pane_key(...) for resizing
+//!         }
+//!         SplitPaneKeyRoute::Forward(_) => {
+//!             if let Some(focus) = state.select_pane(&mut left, &mut right)
+//!                 && key.code == KeyCode::Tab
+//!             {
+//!                 focus.next();
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! assert_eq!(state.focused_pane(), SplitPaneFocus::Second);
+//! assert_eq!(right.current(), Some(&11));
+//! assert_eq!(left.current(), Some(&0));
+//! ```
+
+use std::time::{Duration, Instant};
 
 use ratatui::{
     buffer::Buffer,
@@ -32,6 +83,7 @@ use ratatui::{
     style::{Color, Modifier, Style},
 };
 
+use crate::events::KeyMatcher;
 use crate::traits::{ClickRegion, ClickRegionRegistry, FocusId, Focusable};
 
 /// Actions that can be triggered by mouse interaction with the split pane
@@ -43,6 +95,115 @@ pub enum SplitPaneAction {
     SecondPaneClick,
     /// Click/drag on the divider
     DividerDrag,
+    /// Double-click on the divider reset the split to equal panes
+    Reset,
+}
+
+/// The part of a split pane that has keyboard focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SplitPaneFocus {
+    /// The first pane (left or top)
+    #[default]
+    First,
+    /// The second pane (right or bottom)
+    Second,
+    /// The divider, for keyboard resizing
+    Divider,
+}
+
+/// Where a key should go after [`handle_split_pane_focus_key`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPaneKeyRoute {
+    /// The key switched panes (or started a pane switch)
+    Consumed,
+    /// Pass the key on to the focused part: the pane's own handler, or
+    /// [`handle_split_pane_key`] for the divider
+    Forward(SplitPaneFocus),
+}
+
+/// Key bindings for moving focus between panes
+///
+/// With a `prefix`, the pane keys only apply to the key right after it,
+/// like Vim's Ctrl+W window commands. Without one they match directly.
+#[derive(Debug, Clone)]
+pub struct SplitPaneKeys {
+    /// Chord that starts a pane switch (default: Ctrl+W)
+    pub prefix: Option<KeyMatcher>,
+    /// Focus the first pane (default: h, k, ←, ↑)
+    pub first: KeyMatcher,
+    /// Focus the second pane (default: l, j, →, ↓)
+    pub second: KeyMatcher,
+    /// Switch to the other pane (default: w, Ctrl+W)
+    pub cycle: Option<KeyMatcher>,
+    /// Focus the divider for resizing (default: r)
+    pub divider: Option<KeyMatcher>,
+}
+
+impl Default for SplitPaneKeys {
+    fn default() -> Self {
+        use crossterm::event::KeyCode;
+
+        let keys = |codes: [KeyCode; 4]| KeyMatcher::any_of(codes.map(KeyMatcher::key));
+        Self {
+            prefix: Some(KeyMatcher::ctrl('w')),
+            first: keys([
+                KeyCode::Char('h'),
+                KeyCode::Char('k'),
+                KeyCode::Left,
+                KeyCode::Up,
+            ]),
+            second: keys([
+                KeyCode::Char('l'),
+                KeyCode::Char('j'),
+                KeyCode::Right,
+                KeyCode::Down,
+            ]),
+            cycle: Some(KeyMatcher::key(KeyCode::Char('w')).or(KeyMatcher::ctrl('w'))),
+            divider: Some(KeyMatcher::key(KeyCode::Char('r'))),
+        }
+    }
+}
+
+impl SplitPaneKeys {
+    /// Bind a pair of keys that focus the first and second pane directly,
+    /// without a prefix
+    pub fn pair(first: KeyMatcher, second: KeyMatcher) -> Self {
+        Self {
+            prefix: None,
+            first,
+            second,
+            cycle: None,
+            divider: None,
+        }
+    }
+
+    /// Set the key that focuses the divider
+    pub fn divider(mut self, divider: KeyMatcher) -> Self {
+        self.divider = Some(divider);
+        self
+    }
+
+    /// The pane a key selects, if any
+    fn target(
+        &self,
+        key: &crossterm::event::KeyEvent,
+        current: SplitPaneFocus,
+    ) -> Option<SplitPaneFocus> {
+        if self.first.matches(key) {
+            Some(SplitPaneFocus::First)
+        } else if self.second.matches(key) {
+            Some(SplitPaneFocus::Second)
+        } else if self.cycle.as_ref().is_some_and(|m| m.matches(key)) {
+            Some(match current {
+                SplitPaneFocus::First => SplitPaneFocus::Second,
+                _ => SplitPaneFocus::First,
+            })
+        } else if self.divider.as_ref().is_some_and(|m| m.matches(key)) {
+            Some(SplitPaneFocus::Divider)
+        } else {
+            None
+        }
+    }
 }
 
 /// Orientation of the split pane
@@ -66,12 +227,21 @@ pub struct SplitPaneState {
     pub divider_focused: bool,
     /// Whether currently dragging the divider
     pub is_dragging: bool,
+    /// Pane focused when the divider isn't (First or Second)
+    pane: SplitPaneFocus,
+    /// Whether the pane switch prefix was just pressed
+    prefix_pending: bool,
     /// Starting position when drag began
     drag_start_pos: u16,
     /// Split percentage when drag began
     drag_start_percent: u16,
     /// Total size of the split area (cached from last render)
     total_size: u16,
+    /// Position and time of the last divider click, for double-click detection
+    pub last_click: Option<(u16, u16, Instant)>,
+    /// Whether [`resize_linked`](Self::resize_linked) keeps the first pane's
+    /// size when the divider before this split moves
+    pub linked_resize: bool,
     /// Focus ID for focus management
     pub focus_id: FocusId,
 }
@@ -84,9 +254,13 @@ impl SplitPaneState {
             focused: false,
             divider_focused: false,
             is_dragging: false,
+            pane: SplitPaneFocus::First,
+            prefix_pending: false,
             drag_start_pos: 0,
             drag_start_percent: 0,
             total_size: 0,
+            last_click: None,
+            linked_resize: false,
             focus_id: FocusId::default(),
         }
     }
@@ -96,6 +270,13 @@ impl SplitPaneState {
         Self::new(50)
     }
 
+    /// Set whether [`resize_linked`](Self::resize_linked) keeps the first
+    /// pane's size
+    pub fn with_linked_resize(mut self, linked: bool) -> Self {
+        self.linked_resize = linked;
+        self
+    }
+
     /// Start dragging the divider
     pub fn start_drag(&mut self, pos: u16) {
         self.is_dragging = true;
@@ -139,6 +320,50 @@ impl SplitPaneState {
         self.split_percent
     }
 
+    /// Get the size of the first pane as a fraction (0.0-1.0)
+    pub fn ratio(&self) -> f32 {
+        self.split_percent as f32 / 100.0
+    }
+
+    /// Set the size of the first pane as a fraction (0.0-1.0)
+    ///
+    /// Once the total size is known, the split snaps to the nearest whole
+    /// cell of the current total. Non-finite values are ignored.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        if !ratio.is_finite() {
+            return;
+        }
+        let ratio = ratio.clamp(0.0, 1.0);
+        let percent = if self.total_size > 0 {
+            let total = self.total_size as f32;
+            (ratio * total).round() * 100.0 / total
+        } else {
+            ratio * 100.0
+        };
+        self.split_percent = (percent.round() as u16).min(100);
+    }
+
+    /// Follow the divider before this split moving by `delta` cells
+    ///
+    /// For three panes nested as `A | (B | C)`, call this on the inner state
+    /// when the outer divider moves. `total` is the inner split's size before
+    /// the move; a positive `delta` moves the outer divider into it. With
+    /// [`linked_resize`](Self::linked_resize), B keeps its size (and so its
+    /// fraction of the whole layout) and C absorbs the change; otherwise the
+    /// ratio is kept and both panes scale.
+    pub fn resize_linked(&mut self, delta: i16, total: u16) {
+        let new_total = i32::from(total) - i32::from(delta);
+        if new_total <= 0 {
+            return;
+        }
+        if self.linked_resize && total > 0 {
+            let first = (self.ratio() * f32::from(total)).round();
+            let percent = (first * 100.0 / new_total as f32).round() as u16;
+            self.split_percent = percent.min(100);
+        }
+        self.total_size = new_total.min(i32::from(u16::MAX)) as u16;
+    }
+
     /// Check if currently dragging
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
@@ -148,6 +373,63 @@ impl SplitPaneState {
     pub fn set_total_size(&mut self, size: u16) {
         self.total_size = size;
     }
+
+    /// The focused part of the split pane
+    ///
+    /// Reports [`SplitPaneFocus::Divider`] while `divider_focused` is set,
+    /// and otherwise the pane focused last.
+    pub fn focused_pane(&self) -> SplitPaneFocus {
+        if self.divider_focused {
+            SplitPaneFocus::Divider
+        } else {
+            self.pane
+        }
+    }
+
+    /// Move focus to a pane or the divider
+    pub fn focus_pane(&mut self, focus: SplitPaneFocus) {
+        self.prefix_pending = false;
+        match focus {
+            SplitPaneFocus::Divider => self.divider_focused = true,
+            pane => {
+                self.pane = pane;
+                self.divider_focused = false;
+            }
+        }
+    }
+
+    /// Whether `pane` has focus within a focused split pane
+    ///
+    /// Use this to pick the border style of a pane's content.
+    pub fn is_pane_focused(&self, pane: SplitPaneFocus) -> bool {
+        self.focused && self.focused_pane() == pane
+    }
+
+    /// Whether the pane switch prefix is waiting for its second key
+    pub fn is_prefix_pending(&self) -> bool {
+        self.prefix_pending
+    }
+
+    /// Pick the value for the focused pane, or `None` while the divider is
+    /// focused
+    ///
+    /// Keeping one [`FocusManager`](crate::state::FocusManager) per pane and
+    /// forwarding keys to the selected one keeps Tab within that pane.
+    pub fn select_pane<'a, T>(&self, first: &'a mut T, second: &'a mut T) -> Option<&'a mut T> {
+        match self.focused_pane() {
+            SplitPaneFocus::First => Some(first),
+            SplitPaneFocus::Second => Some(second),
+            SplitPaneFocus::Divider => None,
+        }
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_split_pane_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("←/→ or ↑/↓", "Move divider"),
+            ("Home/End", "Minimum/maximum split"),
+        ]
+    }
 }
 
 impl Default for SplitPaneState {
@@ -169,6 +451,7 @@ impl Focusable for SplitPaneState {
         self.focused = focused;
         if !focused {
             self.divider_focused = false;
+            self.prefix_pending = false;
         }
     }
 
@@ -200,6 +483,13 @@ pub struct SplitPaneStyle {
     pub divider_size: u16,
     /// Show a grab indicator on the divider
     pub show_grab_indicator: bool,
+    /// Border style for a pane without focus (see [`SplitPane::pane_border_style`])
+    pub pane_border_style: Style,
+    /// Border style for the focused pane
+    pub pane_focused_border_style: Style,
+    /// Longest gap between two divider clicks that counts as a double-click,
+    /// in milliseconds (default: 300)
+    pub double_click_ms: u64,
 }
 
 impl Default for SplitPaneStyle {
@@ -212,6 +502,9 @@ impl Default for SplitPaneStyle {
             divider_char: None, // Auto-select based on orientation
             divider_size: 1,
             show_grab_indicator: true,
+            pane_border_style: Style::default().fg(Color::DarkGray),
+            pane_focused_border_style: Style::default().fg(Color::Yellow),
+            double_click_ms: 300,
         }
     }
 }
@@ -227,6 +520,9 @@ impl From<&crate::theme::Theme> for SplitPaneStyle {
             divider_char: None,
             divider_size: 1,
             show_grab_indicator: true,
+            pane_border_style: Style::default().fg(p.border),
+            pane_focused_border_style: Style::default().fg(p.border_focused),
+            double_click_ms: 300,
         }
     }
 }
@@ -242,6 +538,7 @@ impl SplitPaneStyle {
             divider_char: None,
             divider_size: 1,
             show_grab_indicator: false,
+            ..Default::default()
         }
     }
 
@@ -255,6 +552,7 @@ impl SplitPaneStyle {
             divider_char: None,
             divider_size: 1,
             show_grab_indicator: true,
+            ..Default::default()
         }
     }
 
@@ -269,6 +567,12 @@ impl SplitPaneStyle {
         self.divider_size = size.max(1);
         self
     }
+
+    /// Set the double-click threshold in milliseconds
+    pub fn double_click_ms(mut self, ms: u64) -> Self {
+        self.double_click_ms = ms;
+        self
+    }
 }
 
 /// A resizable split pane component
@@ -495,6 +799,17 @@ impl SplitPane {
         (first_area, divider_area, second_area)
     }
 
+    /// Border style for a pane's content block
+    ///
+    /// Returns the focused style when `pane` is the focused pane.
+    pub fn pane_border_style(&self, state: &SplitPaneState, pane: SplitPaneFocus) -> Style {
+        if state.is_pane_focused(pane) {
+            self.style.pane_focused_border_style
+        } else {
+            self.style.pane_border_style
+        }
+    }
+
     /// Get a simple click region for the divider
     pub fn divider_click_region(
         &self,
@@ -573,9 +888,46 @@ pub fn handle_split_pane_key(
     }
 }
 
+/// Handle pane switching keys and route everything else
+///
+/// Pane switch keys (see [`SplitPaneKeys`]) return
+/// [`SplitPaneKeyRoute::Consumed`]. So does any key right after the prefix,
+/// and Esc or Enter while the divider is focused, which return focus to the
+/// last pane. Other keys are forwarded to the focused part.
+pub fn handle_split_pane_focus_key(
+    state: &mut SplitPaneState,
+    key: &crossterm::event::KeyEvent,
+    keys: &SplitPaneKeys,
+) -> SplitPaneKeyRoute {
+    use crossterm::event::KeyCode;
+
+    if state.prefix_pending || keys.prefix.is_none() {
+        let after_prefix = std::mem::take(&mut state.prefix_pending);
+        if let Some(target) = keys.target(key, state.focused_pane()) {
+            state.focus_pane(target);
+            return SplitPaneKeyRoute::Consumed;
+        }
+        if after_prefix {
+            return SplitPaneKeyRoute::Consumed;
+        }
+    }
+
+    if keys.prefix.as_ref().is_some_and(|m| m.matches(key)) {
+        state.prefix_pending = true;
+        return SplitPaneKeyRoute::Consumed;
+    }
+
+    if state.divider_focused && matches!(key.code, KeyCode::Esc | KeyCode::Enter) {
+        state.focus_pane(state.pane);
+        return SplitPaneKeyRoute::Consumed;
+    }
+
+    SplitPaneKeyRoute::Forward(state.focused_pane())
+}
+
 /// Handle mouse input for split pane
 ///
-/// Returns the action triggered, if any
+/// Returns the action triggered, if any. Clicking a pane focuses it.
 pub fn handle_split_pane_mouse(
     state: &mut SplitPaneState,
     mouse: &crossterm::event::MouseEvent,
@@ -594,21 +946,20 @@ pub fn handle_split_pane_mouse(
     match mouse.kind {
         MouseEventKind::Down(MouseButton::Left) => {
             if let Some(&action) = registry.handle_click(mouse.column, mouse.row) {
-                if action == SplitPaneAction::DividerDrag {
-                    state.start_drag(pos);
+                match action {
+                    SplitPaneAction::DividerDrag => state.start_drag(pos),
+                    SplitPaneAction::FirstPaneClick => state.focus_pane(SplitPaneFocus::First),
+                    SplitPaneAction::SecondPaneClick => state.focus_pane(SplitPaneFocus::Second),
+                    SplitPaneAction::Reset => {}
                 }
                 return Some(action);
             }
         }
-        MouseEventKind::Up(MouseButton::Left) => {
-            if state.is_dragging {
-                state.end_drag();
-            }
+        MouseEventKind::Up(MouseButton::Left) if state.is_dragging => {
+            state.end_drag();
         }
-        MouseEventKind::Drag(MouseButton::Left) => {
-            if state.is_dragging {
-                state.update_drag(pos, min_percent, max_percent);
-            }
+        MouseEventKind::Drag(MouseButton::Left) if state.is_dragging => {
+            state.update_drag(pos, min_percent, max_percent);
         }
         _ => {}
     }
@@ -616,6 +967,47 @@ pub fn handle_split_pane_mouse(
     None
 }
 
+/// Handle a double-click on the divider
+///
+/// A second left click on the same divider cell within
+/// [`SplitPaneStyle::double_click_ms`] of the first resets the split to
+/// equal panes and returns [`SplitPaneAction::Reset`]. `area` is the area
+/// the split pane was rendered in and `now` the time of the event. Call
+/// this before [`handle_split_pane_mouse`] and skip that call on `Reset`.
+pub fn handle_split_pane_double_click(
+    mouse: &crossterm::event::MouseEvent,
+    state: &mut SplitPaneState,
+    split_pane: &SplitPane,
+    area: Rect,
+    now: Instant,
+) -> Option<SplitPaneAction> {
+    use crossterm::event::{MouseButton, MouseEventKind};
+
+    if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+        return None;
+    }
+
+    let (_, divider, _) = split_pane.calculate_areas(area, state.split_percent);
+    if !divider.contains((mouse.column, mouse.row).into()) {
+        state.last_click = None;
+        return None;
+    }
+
+    let threshold = Duration::from_millis(split_pane.style.double_click_ms);
+    let is_double = state.last_click.is_some_and(|(column, row, at)| {
+        (column, row) == (mouse.column, mouse.row) && now.saturating_duration_since(at) <= threshold
+    });
+    if is_double {
+        state.last_click = None;
+        state.end_drag();
+        state.set_split_percent(50);
+        return Some(SplitPaneAction::Reset);
+    }
+
+    state.last_click = Some((mouse.column, mouse.row, now));
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -672,6 +1064,51 @@ mod tests {
         assert!(state.split_percent <= 90);
     }
 
+    #[test]
+    fn test_ratio_round_trip() {
+        let mut state = SplitPaneState::new(25);
+        assert_eq!(state.ratio(), 0.25);
+
+        state.set_ratio(0.6);
+        assert_eq!(state.split_percent, 60);
+
+        state.set_ratio(1.5);
+        assert_eq!(state.split_percent, 100);
+        state.set_ratio(f32::NAN);
+        assert_eq!(state.split_percent, 100);
+
+        // Snaps to whole cells of the current total
+        state.set_total_size(8);
+        state.set_ratio(0.3);
+        assert_eq!(state.split_percent, 25);
+    }
+
+    #[test]
+    fn test_resize_linked() {
+        // Inner split B | C of A | (B | C), B at 50 of 100 cells
+        let mut state = SplitPaneState::new(50).with_linked_resize(true);
+        state.resize_linked(20, 100);
+        assert_eq!(state.split_percent, 63, "B stays ~50 of 80 cells");
+        assert_eq!(state.total_size, 80);
+
+        let mut state = SplitPaneState::new(50).with_linked_resize(true);
+        state.resize_linked(-20, 100);
+        assert_eq!(state.split_percent, 42, "B stays ~50 of 120 cells");
+
+        // B can't outgrow the shrunken split
+        let mut state = SplitPaneState::new(50).with_linked_resize(true);
+        state.resize_linked(60, 100);
+        assert_eq!(state.split_percent, 100);
+        state.resize_linked(40, 40);
+        assert_eq!(state.split_percent, 100, "collapsing entirely is ignored");
+
+        // Unlinked keeps the ratio
+        let mut state = SplitPaneState::new(50);
+        state.resize_linked(20, 100);
+        assert_eq!(state.split_percent, 50);
+        assert_eq!(state.total_size, 80);
+    }
+
     #[test]
     fn test_adjust_split() {
         let mut state = SplitPaneState::new(50);
@@ -717,4 +1154,233 @@ mod tests {
         state.set_focused(false);
         assert!(!state.divider_focused);
     }
+
+    fn key(code: crossterm::event::KeyCode) -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(code, crossterm::event::KeyModifiers::NONE)
+    }
+
+    fn ctrl_w() -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char('w'),
+            crossterm::event::KeyModifiers::CONTROL,
+        )
+    }
+
+    #[test]
+    fn test_focus_key_prefix_switches_panes() {
+        use crossterm::event::KeyCode;
+
+        let mut state = SplitPaneState::half();
+        let keys = SplitPaneKeys::default();
+        assert_eq!(state.focused_pane(), SplitPaneFocus::First);
+
+        // Plain keys go to the focused pane
+        let l = key(KeyCode::Char('l'));
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &l, &keys),
+            SplitPaneKeyRoute::Forward(SplitPaneFocus::First)
+        );
+
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &ctrl_w(), &keys),
+            SplitPaneKeyRoute::Consumed
+        );
+        assert!(state.is_prefix_pending());
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &l, &keys),
+            SplitPaneKeyRoute::Consumed
+        );
+        assert!(!state.is_prefix_pending());
+        assert_eq!(state.focused_pane(), SplitPaneFocus::Second);
+
+        // Ctrl+W twice cycles back
+        handle_split_pane_focus_key(&mut state, &ctrl_w(), &keys);
+        handle_split_pane_focus_key(&mut state, &ctrl_w(), &keys);
+        assert_eq!(state.focused_pane(), SplitPaneFocus::First);
+
+        // An unbound key after the prefix is swallowed
+        handle_split_pane_focus_key(&mut state, &ctrl_w(), &keys);
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &key(KeyCode::Char('x')), &keys),
+            SplitPaneKeyRoute::Consumed
+        );
+        assert_eq!(state.focused_pane(), SplitPaneFocus::First);
+    }
+
+    #[test]
+    fn test_focus_key_divider_routes_to_resize() {
+        use crossterm::event::KeyCode;
+
+        let mut state = SplitPaneState::half();
+        let keys = SplitPaneKeys::default();
+        state.focus_pane(SplitPaneFocus::Second);
+
+        handle_split_pane_focus_key(&mut state, &ctrl_w(), &keys);
+        handle_split_pane_focus_key(&mut state, &key(KeyCode::Char('r')), &keys);
+        assert!(state.divider_focused);
+
+        let left = key(KeyCode::Left);
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &left, &keys),
+            SplitPaneKeyRoute::Forward(SplitPaneFocus::Divider)
+        );
+        assert!(handle_split_pane_key(
+            &mut state,
+            &left,
+            Orientation::Horizontal,
+            5,
+            10,
+            90
+        ));
+        assert_eq!(state.split_percent, 45);
+
+        // Esc hands focus back to the pane used last
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &key(KeyCode::Esc), &keys),
+            SplitPaneKeyRoute::Consumed
+        );
+        assert_eq!(state.focused_pane(), SplitPaneFocus::Second);
+    }
+
+    #[test]
+    fn test_focus_key_direct_pair() {
+        let mut state = SplitPaneState::half();
+        let keys = SplitPaneKeys::pair(KeyMatcher::alt('h'), KeyMatcher::alt('l'));
+
+        let alt_l = crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char('l'),
+            crossterm::event::KeyModifiers::ALT,
+        );
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &alt_l, &keys),
+            SplitPaneKeyRoute::Consumed
+        );
+        assert_eq!(state.focused_pane(), SplitPaneFocus::Second);
+
+        // Ctrl+W is just another key without a prefix
+        assert_eq!(
+            handle_split_pane_focus_key(&mut state, &ctrl_w(), &keys),
+            SplitPaneKeyRoute::Forward(SplitPaneFocus::Second)
+        );
+    }
+
+    #[test]
+    fn test_click_focuses_pane() {
+        use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+        let split_pane = SplitPane::new();
+        let mut state = SplitPaneState::half();
+        state.divider_focused = true;
+        let mut registry = ClickRegionRegistry::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 5));
+        split_pane.render_with_content(
+            Rect::new(0, 0, 21, 5),
+            &mut buf,
+            &mut state,
+            |_, _| {},
+            |_, _| {},
+            &mut registry,
+        );
+
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 2,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        let action = handle_split_pane_mouse(
+            &mut state,
+            &click(15),
+            Orientation::Horizontal,
+            &registry,
+            10,
+            90,
+        );
+        assert_eq!(action, Some(SplitPaneAction::SecondPaneClick));
+        assert_eq!(state.focused_pane(), SplitPaneFocus::Second);
+
+        handle_split_pane_mouse(
+            &mut state,
+            &click(2),
+            Orientation::Horizontal,
+            &registry,
+            10,
+            90,
+        );
+        assert_eq!(state.focused_pane(), SplitPaneFocus::First);
+    }
+
+    #[test]
+    fn test_select_pane_and_border_style() {
+        let split_pane = SplitPane::new();
+        let mut state = SplitPaneState::half();
+        let (mut left, mut right) = (1, 2);
+
+        assert_eq!(state.select_pane(&mut left, &mut right), Some(&mut 1));
+        state.focus_pane(SplitPaneFocus::Second);
+        assert_eq!(state.select_pane(&mut left, &mut right), Some(&mut 2));
+        state.focus_pane(SplitPaneFocus::Divider);
+        assert_eq!(state.select_pane(&mut left, &mut right), None);
+
+        // Border cue only shows while the split pane itself is focused
+        state.focus_pane(SplitPaneFocus::First);
+        let style = SplitPaneStyle::default();
+        assert_eq!(
+            split_pane.pane_border_style(&state, SplitPaneFocus::First),
+            style.pane_border_style
+        );
+        state.set_focused(true);
+        assert_eq!(
+            split_pane.pane_border_style(&state, SplitPaneFocus::First),
+            style.pane_focused_border_style
+        );
+        assert_eq!(
+            split_pane.pane_border_style(&state, SplitPaneFocus::Second),
+            style.pane_border_style
+        );
+    }
+
+    #[test]
+    fn test_double_click_divider_resets_split() {
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+        let split_pane = SplitPane::new();
+        let area = Rect::new(0, 0, 41, 10);
+        let mut state = SplitPaneState::new(30);
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 3,
+            modifiers: KeyModifiers::NONE,
+        };
+        let (_, divider, _) = split_pane.calculate_areas(area, 30);
+        let start = Instant::now();
+
+        // A slow second click is just another single click
+        assert_eq!(
+            handle_split_pane_double_click(&click(divider.x), &mut state, &split_pane, area, start),
+            None
+        );
+        let late = start + Duration::from_millis(400);
+        assert_eq!(
+            handle_split_pane_double_click(&click(divider.x), &mut state, &split_pane, area, late),
+            None
+        );
+
+        // A quick one resets to equal panes
+        let quick = late + Duration::from_millis(200);
+        assert_eq!(
+            handle_split_pane_double_click(&click(divider.x), &mut state, &split_pane, area, quick),
+            Some(SplitPaneAction::Reset)
+        );
+        assert_eq!(state.split_percent, 50);
+        assert!(state.last_click.is_none());
+
+        // Clicks off the divider never count
+        handle_split_pane_double_click(&click(0), &mut state, &split_pane, area, quick);
+        assert_eq!(
+            handle_split_pane_double_click(&click(0), &mut state, &split_pane, area, quick),
+            None
+        );
+    }
 }
@@ -5,7 +5,9 @@
 //! # Example
 //!
 //! ```rust
-//! use ratatui_interact::components::{StepDisplay, StepDisplayState, Step, StepStatus};
+//! use ratatui_interact::components::{
+//!     Step, StepDisplay, StepDisplayLayout, StepDisplayState, StepStatus,
+//! };
 //!
 //! // Create steps
 //! let steps = vec![
@@ -22,8 +24,17 @@
 //! state.start_step(0);
 //! state.complete_step(0);
 //! state.start_step(1);
+//!
+//! // Live output shown under the running step
+//! state.push_output(1, "running 12 tests");
+//!
+//! // Single-row progress header
+//! let header = StepDisplay::new(&state).layout(StepDisplayLayout::Horizontal);
 //! ```
 
+use std::collections::VecDeque;
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -32,7 +43,14 @@ use ratatui::{
     widgets::{Paragraph, Widget},
 };
 
-use crate::utils::display::{pad_to_width, truncate_to_width};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::traits::ClickRegionRegistry;
+use crate::utils::ansi::parse_ansi_to_spans;
+use crate::utils::display::{clean_for_display, pad_to_width, truncate_to_width};
+
+/// Default number of lines kept in a step's live output tail
+pub const DEFAULT_TAIL_CAPACITY: usize = 3;
 
 /// Status of a step or sub-step
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -85,6 +103,16 @@ impl StepStatus {
     }
 }
 
+/// How a [`StepDisplay`] lays out its steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepDisplayLayout {
+    /// One step per row, with sub-steps, live tail, and output underneath
+    #[default]
+    Vertical,
+    /// All step headers on a single row, e.g. as a progress header
+    Horizontal,
+}
+
 /// A sub-step within a step
 #[derive(Debug, Clone)]
 pub struct SubStep {
@@ -92,6 +120,10 @@ pub struct SubStep {
     pub name: String,
     /// Current status
     pub status: StepStatus,
+    /// Number of retries attempted so far
+    pub retry_count: u32,
+    /// Maximum number of retries (None = no retry limit configured)
+    pub max_retries: Option<u32>,
 }
 
 impl SubStep {
@@ -100,8 +132,39 @@ impl SubStep {
         Self {
             name: name.into(),
             status: StepStatus::Pending,
+            retry_count: 0,
+            max_retries: None,
         }
     }
+
+    /// Set the maximum number of retries
+    pub fn with_max_retries(mut self, max: u32) -> Self {
+        self.max_retries = Some(max);
+        self
+    }
+
+    /// Get the retry indicator text, if any
+    pub fn retry_label(&self) -> Option<String> {
+        retry_label(self.status, self.retry_count, self.max_retries)
+    }
+}
+
+/// Build the retry indicator for a step or sub-step.
+///
+/// Running steps with at least one retry show `[Retry N/M]`; failed steps
+/// with a retry limit show `(Failed after N retries)` (`1 retry` for one).
+fn retry_label(status: StepStatus, retry_count: u32, max_retries: Option<u32>) -> Option<String> {
+    match status {
+        StepStatus::Running if retry_count > 0 => Some(match max_retries {
+            Some(max) => format!("[Retry {}/{}]", retry_count, max),
+            None => format!("[Retry {}]", retry_count),
+        }),
+        StepStatus::Failed if max_retries.is_some() => {
+            let noun = if retry_count == 1 { "retry" } else { "retries" };
+            Some(format!("(Failed after {} {})", retry_count, noun))
+        }
+        _ => None,
+    }
 }
 
 /// A step in the process
@@ -119,6 +182,14 @@ pub struct Step {
     pub output: Vec<String>,
     /// Output scroll position
     pub scroll: u16,
+    /// Number of retries attempted so far
+    pub retry_count: u32,
+    /// Maximum number of retries (None = no retry limit configured)
+    pub max_retries: Option<u32>,
+    /// Last lines of live output, shown under the step while it runs
+    pub tail: VecDeque<String>,
+    /// Maximum number of lines kept in `tail`
+    pub tail_capacity: usize,
 }
 
 impl Step {
@@ -131,9 +202,31 @@ impl Step {
             expanded: false,
             output: Vec::new(),
             scroll: 0,
+            retry_count: 0,
+            max_retries: None,
+            tail: VecDeque::new(),
+            tail_capacity: DEFAULT_TAIL_CAPACITY,
         }
     }
 
+    /// Set the maximum number of retries
+    pub fn with_max_retries(mut self, max: u32) -> Self {
+        self.max_retries = Some(max);
+        self
+    }
+
+    /// Get the retry indicator text, if any
+    pub fn retry_label(&self) -> Option<String> {
+        retry_label(self.status, self.retry_count, self.max_retries)
+    }
+
+    /// Set how many lines of live output to keep (0 disables the tail)
+    pub fn with_tail_capacity(mut self, capacity: usize) -> Self {
+        self.tail_capacity = capacity;
+        self.tail.truncate(capacity);
+        self
+    }
+
     /// Add sub-steps
     pub fn with_sub_steps(mut self, names: Vec<&str>) -> Self {
         self.sub_steps = names.into_iter().map(SubStep::new).collect();
@@ -150,6 +243,25 @@ impl Step {
         }
     }
 
+    /// Push a line of live output, dropping the oldest beyond `tail_capacity`
+    ///
+    /// The tail may contain ANSI escape codes. It is only drawn while the
+    /// step is running, but stays readable in `tail` afterwards.
+    pub fn push_output(&mut self, line: impl Into<String>) {
+        if self.tail_capacity == 0 {
+            return;
+        }
+        while self.tail.len() >= self.tail_capacity {
+            self.tail.pop_front();
+        }
+        self.tail.push_back(line.into());
+    }
+
+    /// Whether the live output tail is drawn under the step header
+    fn shows_tail(&self) -> bool {
+        self.status == StepStatus::Running && !self.tail.is_empty()
+    }
+
     /// Clear output
     pub fn clear_output(&mut self) {
         self.output.clear();
@@ -174,6 +286,8 @@ pub struct StepDisplayState {
     pub steps: Vec<Step>,
     /// Currently focused step index
     pub focused_step: Option<usize>,
+    /// Whether the display has keyboard focus, highlighting the focused step
+    pub keyboard_focused: bool,
     /// Console scroll position
     pub scroll: u16,
 }
@@ -184,6 +298,7 @@ impl StepDisplayState {
         Self {
             steps,
             focused_step: None,
+            keyboard_focused: false,
             scroll: 0,
         }
     }
@@ -231,6 +346,15 @@ impl StepDisplayState {
         }
     }
 
+    /// Mark a step as running again after a failure
+    pub fn set_retrying(&mut self, index: usize, retry_count: u32) {
+        if let Some(step) = self.steps.get_mut(index) {
+            step.status = StepStatus::Running;
+            step.retry_count = retry_count;
+            step.expanded = true;
+        }
+    }
+
     /// Skip a step
     pub fn skip_step(&mut self, index: usize) {
         if let Some(step) = self.steps.get_mut(index) {
@@ -263,6 +387,13 @@ impl StepDisplayState {
         }
     }
 
+    /// Push a line of live output to a step's tail
+    pub fn push_output(&mut self, step_index: usize, line: impl Into<String>) {
+        if let Some(step) = self.steps.get_mut(step_index) {
+            step.push_output(line);
+        }
+    }
+
     /// Toggle expansion of a step
     pub fn toggle_expanded(&mut self, index: usize) {
         if let Some(step) = self.steps.get_mut(index) {
@@ -270,6 +401,40 @@ impl StepDisplayState {
         }
     }
 
+    /// Focus the next step, or the first if none is focused
+    pub fn focus_next(&mut self) {
+        if self.steps.is_empty() {
+            return;
+        }
+        self.focused_step = Some(match self.focused_step {
+            Some(index) => (index + 1).min(self.steps.len() - 1),
+            None => 0,
+        });
+    }
+
+    /// Focus the previous step, or the first if none is focused
+    pub fn focus_prev(&mut self) {
+        if self.steps.is_empty() {
+            return;
+        }
+        self.focused_step = Some(self.focused_step.map_or(0, |index| index.saturating_sub(1)));
+    }
+
+    /// Toggle expansion of the focused step
+    pub fn toggle_focused(&mut self) {
+        if let Some(index) = self.focused_step {
+            self.toggle_expanded(index);
+        }
+    }
+
+    /// Key bindings handled by [`handle_step_display_key`]
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑/↓", "Focus previous/next step"),
+            ("Enter", "Expand/collapse step details"),
+        ]
+    }
+
     /// Scroll output for a step
     pub fn scroll_output(&mut self, index: usize, delta: i32) {
         if let Some(step) = self.steps.get_mut(index) {
@@ -289,6 +454,16 @@ pub struct StepDisplayStyle {
     pub unfocused_border: Color,
     /// Maximum visible output lines
     pub max_output_lines: usize,
+    /// Style for retry indicators
+    pub retry_style: Style,
+    /// Base style for the running step's live output tail (ANSI colors apply on top)
+    pub tail_style: Style,
+    /// Highlight of the focused step's header row while the display has keyboard focus
+    pub focused_step_style: Style,
+    /// Separator between steps in the horizontal layout
+    pub separator: &'static str,
+    /// Style for separators in the horizontal layout
+    pub separator_style: Style,
 }
 
 impl Default for StepDisplayStyle {
@@ -297,6 +472,11 @@ impl Default for StepDisplayStyle {
             focused_border: Color::Cyan,
             unfocused_border: Color::DarkGray,
             max_output_lines: 5,
+            retry_style: Style::default().fg(Color::Magenta),
+            tail_style: Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+            focused_step_style: Style::default().bg(Color::DarkGray),
+            separator: " ─ ",
+            separator_style: Style::default().fg(Color::DarkGray),
         }
     }
 }
@@ -308,6 +488,11 @@ impl From<&crate::theme::Theme> for StepDisplayStyle {
             focused_border: p.border_accent,
             unfocused_border: p.border_disabled,
             max_output_lines: 5,
+            retry_style: Style::default().fg(p.warning),
+            tail_style: Style::default().fg(p.text_dim).add_modifier(Modifier::DIM),
+            focused_step_style: Style::default().bg(p.surface_raised),
+            separator: " ─ ",
+            separator_style: Style::default().fg(p.separator),
         }
     }
 }
@@ -316,6 +501,7 @@ impl From<&crate::theme::Theme> for StepDisplayStyle {
 pub struct StepDisplay<'a> {
     state: &'a StepDisplayState,
     style: StepDisplayStyle,
+    layout: StepDisplayLayout,
 }
 
 impl<'a> StepDisplay<'a> {
@@ -324,9 +510,16 @@ impl<'a> StepDisplay<'a> {
         Self {
             state,
             style: StepDisplayStyle::default(),
+            layout: StepDisplayLayout::default(),
         }
     }
 
+    /// Set the layout
+    pub fn layout(mut self, layout: StepDisplayLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
     /// Set the style
     pub fn style(mut self, style: StepDisplayStyle) -> Self {
         self.style = style;
@@ -338,22 +531,16 @@ impl<'a> StepDisplay<'a> {
         self.style(StepDisplayStyle::from(theme))
     }
 
-    /// Build content lines
-    fn build_lines(&self, area: Rect) -> Vec<Line<'static>> {
+    /// Build content lines, and the line index of each step header
+    fn build_lines(&self, area: Rect) -> (Vec<Line<'static>>, Vec<usize>) {
         let mut lines = Vec::new();
+        let mut header_rows = Vec::with_capacity(self.state.steps.len());
         let full_width = area.width as usize;
 
         for (idx, step) in self.state.steps.iter().enumerate() {
             // Step header
             let icon_color = step.status.color();
-            let step_style = match step.status {
-                StepStatus::Running => Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-                StepStatus::Failed => Style::default().fg(Color::Red),
-                StepStatus::Completed => Style::default().fg(Color::Green),
-                _ => Style::default().fg(Color::White),
-            };
+            let step_style = step_style(step.status);
 
             let header_suffix = if !step.sub_steps.is_empty() {
                 let (completed, total) = step.sub_step_progress();
@@ -362,15 +549,36 @@ impl<'a> StepDisplay<'a> {
                 String::new()
             };
 
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("{} ", step.status.icon()),
-                    Style::default().fg(icon_color),
-                ),
-                Span::styled(format!("Step {}: ", idx + 1), step_style),
-                Span::styled(step.name.clone(), step_style),
+            let icon = format!("{} ", step.status.icon());
+            let prefix = format!("Step {}: ", idx + 1);
+            let retry = step.retry_label().map(|label| format!(" {}", label));
+
+            // Shrink the name so the suffix and retry indicator stay visible
+            let reserved = icon.width()
+                + prefix.width()
+                + header_suffix.width()
+                + retry.as_deref().map_or(0, UnicodeWidthStr::width);
+            let name = truncate_to_width(&step.name, full_width.saturating_sub(reserved));
+
+            let mut spans = vec![
+                Span::styled(icon, Style::default().fg(icon_color)),
+                Span::styled(prefix, step_style),
+                Span::styled(name, step_style),
                 Span::styled(header_suffix, Style::default().fg(Color::DarkGray)),
-            ]));
+            ];
+            if let Some(retry) = retry {
+                spans.push(Span::styled(retry, self.style.retry_style));
+            }
+            if self.state.keyboard_focused && self.state.focused_step == Some(idx) {
+                // Fill the row so the highlight spans the full width
+                let used: usize = spans.iter().map(|span| span.width()).sum();
+                spans.push(Span::raw(" ".repeat(full_width.saturating_sub(used))));
+                for span in &mut spans {
+                    span.style = span.style.patch(self.style.focused_step_style);
+                }
+            }
+            header_rows.push(lines.len());
+            lines.push(Line::from(spans));
 
             // Sub-steps (if running or expanded)
             if !step.sub_steps.is_empty() && (step.expanded || step.status == StepStatus::Running) {
@@ -384,14 +592,31 @@ impl<'a> StepDisplay<'a> {
                         _ => Style::default().fg(Color::White),
                     };
 
-                    lines.push(Line::from(vec![
+                    let icon = format!("{} ", sub.status.sub_icon());
+                    let retry = sub.retry_label().map(|label| format!(" {}", label));
+                    let reserved =
+                        4 + icon.width() + retry.as_deref().map_or(0, UnicodeWidthStr::width);
+                    let name = truncate_to_width(&sub.name, full_width.saturating_sub(reserved));
+
+                    let mut spans = vec![
                         Span::raw("    "),
-                        Span::styled(
-                            format!("{} ", sub.status.sub_icon()),
-                            Style::default().fg(sub_color),
-                        ),
-                        Span::styled(sub.name.clone(), sub_style),
-                    ]));
+                        Span::styled(icon, Style::default().fg(sub_color)),
+                        Span::styled(name, sub_style),
+                    ];
+                    if let Some(retry) = retry {
+                        spans.push(Span::styled(retry, self.style.retry_style));
+                    }
+                    lines.push(Line::from(spans));
+                }
+            }
+
+            // Live output tail (running step only)
+            if step.shows_tail() {
+                let width = full_width.saturating_sub(TAIL_INDENT.len());
+                for line in &step.tail {
+                    let mut spans = vec![Span::raw(TAIL_INDENT)];
+                    spans.extend(self.tail_spans(line, width));
+                    lines.push(Line::from(spans));
                 }
             }
 
@@ -451,18 +676,186 @@ impl<'a> StepDisplay<'a> {
             }
         }
 
-        lines
+        (lines, header_rows)
+    }
+
+    /// Build the horizontal layout's row, and the column offset and width
+    /// of each step
+    fn build_row(&self) -> (Line<'static>, Vec<(usize, usize)>) {
+        let mut spans = Vec::new();
+        let mut columns = Vec::with_capacity(self.state.steps.len());
+        let mut x = 0;
+
+        for (idx, step) in self.state.steps.iter().enumerate() {
+            if idx > 0 {
+                spans.push(Span::styled(
+                    self.style.separator,
+                    self.style.separator_style,
+                ));
+                x += self.style.separator.width();
+            }
+
+            let mut label = vec![
+                Span::styled(
+                    format!("{} ", step.status.icon()),
+                    Style::default().fg(step.status.color()),
+                ),
+                Span::styled(step.name.clone(), step_style(step.status)),
+            ];
+            if let Some(retry) = step.retry_label() {
+                label.push(Span::styled(format!(" {}", retry), self.style.retry_style));
+            }
+            if self.state.keyboard_focused && self.state.focused_step == Some(idx) {
+                for span in &mut label {
+                    span.style = span.style.patch(self.style.focused_step_style);
+                }
+            }
+
+            let width: usize = label.iter().map(|span| span.width()).sum();
+            columns.push((x, width));
+            x += width;
+            spans.extend(label);
+        }
+
+        (Line::from(spans), columns)
+    }
+
+    /// Parse a tail line's ANSI codes and clip it to `width` cells
+    fn tail_spans(&self, line: &str, width: usize) -> Vec<Span<'static>> {
+        let mut spans = Vec::new();
+        let mut remaining = width;
+
+        for span in parse_ansi_to_spans(line) {
+            let mut content = String::new();
+            for ch in clean_for_display(&span.content).chars() {
+                let ch_width = ch.width().unwrap_or(0);
+                if ch_width > remaining {
+                    remaining = 0;
+                    break;
+                }
+                remaining -= ch_width;
+                content.push(ch);
+            }
+            if !content.is_empty() {
+                spans.push(Span::styled(
+                    content,
+                    self.style.tail_style.patch(span.style),
+                ));
+            }
+            if remaining == 0 {
+                break;
+            }
+        }
+
+        spans
     }
 }
 
-impl Widget for StepDisplay<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let lines = self.build_lines(area);
+/// Indentation of live output tail lines
+const TAIL_INDENT: &str = "      ";
+
+/// Header style for a step with `status`
+fn step_style(status: StepStatus) -> Style {
+    match status {
+        StepStatus::Running => Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+        StepStatus::Failed => Style::default().fg(Color::Red),
+        StepStatus::Completed => Style::default().fg(Color::Green),
+        _ => Style::default().fg(Color::White),
+    }
+}
+
+impl StepDisplay<'_> {
+    /// Render and register each visible step header, carrying the step
+    /// index
+    ///
+    /// Pass the registry to [`handle_step_display_mouse`].
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<usize>,
+    ) {
+        if self.layout == StepDisplayLayout::Horizontal {
+            let (row, columns) = self.build_row();
+            Paragraph::new(row).render(area, buf);
+            for (index, (x, width)) in columns.into_iter().enumerate() {
+                if x < area.width as usize {
+                    let width = width.min(area.width as usize - x) as u16;
+                    registry.register(Rect::new(area.x + x as u16, area.y, width, 1), index);
+                }
+            }
+            return;
+        }
+
+        let header_rows = self.render_impl(area, buf);
+        let scroll = self.state.scroll as usize;
+        for (index, row) in header_rows.into_iter().enumerate() {
+            if let Some(y) = row.checked_sub(scroll)
+                && y < area.height as usize
+            {
+                registry.register(Rect::new(area.x, area.y + y as u16, area.width, 1), index);
+            }
+        }
+    }
+
+    fn render_impl(&self, area: Rect, buf: &mut Buffer) -> Vec<usize> {
+        let (lines, header_rows) = self.build_lines(area);
         let para = Paragraph::new(lines).scroll((self.state.scroll, 0));
         para.render(area, buf);
+        header_rows
     }
 }
 
+impl Widget for StepDisplay<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self.layout {
+            StepDisplayLayout::Vertical => {
+                self.render_impl(area, buf);
+            }
+            StepDisplayLayout::Horizontal => {
+                Paragraph::new(self.build_row().0).render(area, buf);
+            }
+        }
+    }
+}
+
+/// Handle keyboard input for the step display
+///
+/// Up/Down move `focused_step`; Enter expands or collapses the focused
+/// step's sub-steps and output. Returns `true` if the key was handled.
+pub fn handle_step_display_key(key: &KeyEvent, state: &mut StepDisplayState) -> bool {
+    match key.code {
+        KeyCode::Up => state.focus_prev(),
+        KeyCode::Down => state.focus_next(),
+        KeyCode::Enter if state.focused_step.is_some() => state.toggle_focused(),
+        _ => return false,
+    }
+    true
+}
+
+/// Handle mouse input for the step display
+///
+/// A left click on a step header registered by
+/// [`StepDisplay::render_with_registry`] focuses that step and toggles its
+/// expansion. Returns `true` if the click hit a step.
+pub fn handle_step_display_mouse(
+    mouse: &MouseEvent,
+    state: &mut StepDisplayState,
+    registry: &ClickRegionRegistry<usize>,
+) -> bool {
+    if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+        return false;
+    }
+    let Some(&index) = registry.handle_click(mouse.column, mouse.row) else {
+        return false;
+    };
+    state.focused_step = Some(index);
+    state.toggle_expanded(index);
+    true
+}
+
 /// Calculate total height needed for step display
 pub fn calculate_height(state: &StepDisplayState, style: &StepDisplayStyle) -> u16 {
     let mut height = 0u16;
@@ -475,6 +868,11 @@ pub fn calculate_height(state: &StepDisplayState, style: &StepDisplayStyle) -> u
             height += step.sub_steps.len() as u16;
         }
 
+        // Live output tail
+        if step.shows_tail() {
+            height += step.tail.len() as u16;
+        }
+
         // Output frame
         if step.expanded && !step.output.is_empty() {
             height += 2; // borders
@@ -730,6 +1128,73 @@ mod tests {
         state.complete_sub_step(10, 0);
     }
 
+    #[test]
+    fn test_retry_label() {
+        let mut step = Step::new("Deploy").with_max_retries(3);
+        assert_eq!(step.retry_label(), None);
+
+        step.status = StepStatus::Running;
+        assert_eq!(step.retry_label(), None);
+
+        step.retry_count = 2;
+        assert_eq!(step.retry_label().as_deref(), Some("[Retry 2/3]"));
+
+        step.max_retries = None;
+        assert_eq!(step.retry_label().as_deref(), Some("[Retry 2]"));
+
+        step.status = StepStatus::Failed;
+        assert_eq!(step.retry_label(), None);
+
+        step.max_retries = Some(3);
+        step.retry_count = 3;
+        assert_eq!(
+            step.retry_label().as_deref(),
+            Some("(Failed after 3 retries)")
+        );
+        step.retry_count = 1;
+        assert_eq!(
+            step.retry_label().as_deref(),
+            Some("(Failed after 1 retry)")
+        );
+
+        let mut sub = SubStep::new("Upload").with_max_retries(2);
+        sub.status = StepStatus::Running;
+        sub.retry_count = 1;
+        assert_eq!(sub.retry_label().as_deref(), Some("[Retry 1/2]"));
+    }
+
+    #[test]
+    fn test_state_set_retrying() {
+        let steps = vec![Step::new("Deploy").with_max_retries(3)];
+        let mut state = StepDisplayState::new(steps);
+
+        state.fail_step(0);
+        state.set_retrying(0, 1);
+        assert_eq!(state.steps[0].status, StepStatus::Running);
+        assert_eq!(state.steps[0].retry_count, 1);
+
+        // Invalid index should not panic
+        state.set_retrying(10, 1);
+    }
+
+    #[test]
+    fn test_render_retry_indicator_fits() {
+        let steps = vec![
+            Step::new("A very long step name that would overflow the header").with_max_retries(3),
+        ];
+        let mut state = StepDisplayState::new(steps);
+        state.set_retrying(0, 2);
+
+        let area = Rect::new(0, 0, 40, 1);
+        let mut buf = Buffer::empty(area);
+        StepDisplay::new(&state).render(area, &mut buf);
+
+        let row: String = (0..area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect();
+        assert!(row.contains("[Retry 2/3]"), "row was {row:?}");
+    }
+
     #[test]
     fn test_step_display_style_default() {
         let style = StepDisplayStyle::default();
@@ -757,6 +1222,60 @@ mod tests {
         assert!(height > 2); // Should include sub-steps
     }
 
+    #[test]
+    fn test_push_output_interleaved_keeps_tail() {
+        let steps = vec![Step::new("Build"), Step::new("Test").with_tail_capacity(2)];
+        let mut state = StepDisplayState::new(steps);
+        state.start_step(0);
+        state.start_step(1);
+
+        for i in 0..5 {
+            state.push_output(0, format!("build {}", i));
+            state.push_output(1, format!("test {}", i));
+        }
+        state.push_output(7, "ignored");
+
+        assert_eq!(state.steps[0].tail, ["build 2", "build 3", "build 4"]);
+        assert_eq!(state.steps[1].tail, ["test 3", "test 4"]);
+        // The tail is separate from the expandable output frame
+        assert!(state.steps[0].output.is_empty());
+
+        let mut step = Step::new("Quiet").with_tail_capacity(0);
+        step.push_output("dropped");
+        assert!(step.tail.is_empty());
+    }
+
+    #[test]
+    fn test_tail_rendered_under_running_step_only() {
+        let steps = vec![Step::new("Build"), Step::new("Test")];
+        let mut state = StepDisplayState::new(steps);
+        let style = StepDisplayStyle::default();
+        state.start_step(0);
+        state.push_output(0, "\x1b[32mCompiling\x1b[0m foo v0.1.0");
+        state.push_output(0, "Compiling bar v0.2.0");
+        assert_eq!(calculate_height(&state, &style), 4);
+
+        let area = Rect::new(0, 0, 24, 4);
+        let mut buf = Buffer::empty(area);
+        StepDisplay::new(&state).render(area, &mut buf);
+        let row = |buf: &Buffer, y: u16| -> String {
+            (0..area.width).map(|x| buf[(x, y)].symbol()).collect()
+        };
+        assert_eq!(row(&buf, 1), "      Compiling foo v0.1");
+        assert_eq!(row(&buf, 2), "      Compiling bar v0.2");
+        assert!(row(&buf, 3).contains("Step 2"));
+
+        // ANSI colors are kept; the base tail style dims everything
+        assert_eq!(buf[(6, 1)].fg, Color::Green);
+        assert_eq!(buf[(16, 1)].fg, Color::Gray);
+        assert!(buf[(6, 1)].modifier.contains(Modifier::DIM));
+
+        // The tail collapses once the step completes but stays in state
+        state.complete_step(0);
+        assert_eq!(calculate_height(&state, &style), 2);
+        assert_eq!(state.steps[0].tail.len(), 2);
+    }
+
     #[test]
     fn test_step_display_render() {
         let steps = vec![
@@ -770,4 +1289,104 @@ mod tests {
         display.render(Rect::new(0, 0, 60, 20), &mut buf);
         // Should not panic
     }
+
+    #[test]
+    fn test_handle_key_focus_and_toggle() {
+        let key = |code| KeyEvent::from(code);
+        let mut state = StepDisplayState::new(vec![Step::new("A"), Step::new("B")]);
+
+        // Enter does nothing until a step is focused
+        assert!(!handle_step_display_key(&key(KeyCode::Enter), &mut state));
+
+        assert!(handle_step_display_key(&key(KeyCode::Down), &mut state));
+        assert_eq!(state.focused_step, Some(0));
+        assert!(handle_step_display_key(&key(KeyCode::Down), &mut state));
+        assert!(handle_step_display_key(&key(KeyCode::Down), &mut state));
+        assert_eq!(state.focused_step, Some(1));
+
+        assert!(handle_step_display_key(&key(KeyCode::Enter), &mut state));
+        assert!(state.steps[1].expanded);
+        assert!(handle_step_display_key(&key(KeyCode::Up), &mut state));
+        assert_eq!(state.focused_step, Some(0));
+        assert!(!handle_step_display_key(
+            &key(KeyCode::Char('x')),
+            &mut state
+        ));
+    }
+
+    #[test]
+    fn test_click_focuses_and_toggles() {
+        let mut state = StepDisplayState::new(vec![
+            Step::new("Build").with_sub_steps(vec!["Compile", "Link"]),
+            Step::new("Test"),
+        ]);
+        state.toggle_expanded(0);
+
+        let area = Rect::new(0, 0, 30, 6);
+        let mut buf = Buffer::empty(area);
+        let mut registry = ClickRegionRegistry::new();
+        StepDisplay::new(&state).render_with_registry(area, &mut buf, &mut registry);
+        // Header rows sit above and below the two sub-steps
+        assert_eq!(registry.handle_click(5, 0), Some(&0));
+        assert_eq!(registry.handle_click(5, 3), Some(&1));
+        assert_eq!(registry.handle_click(5, 1), None);
+
+        let click = |row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 5,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        assert!(handle_step_display_mouse(&click(0), &mut state, &registry));
+        assert_eq!(state.focused_step, Some(0));
+        assert!(!state.steps[0].expanded);
+        assert!(!handle_step_display_mouse(&click(1), &mut state, &registry));
+    }
+
+    #[test]
+    fn test_focused_step_highlight() {
+        let mut state = StepDisplayState::new(vec![Step::new("A"), Step::new("B")]);
+        state.focused_step = Some(1);
+        let style = StepDisplayStyle::default();
+        let area = Rect::new(0, 0, 20, 2);
+
+        let mut buf = Buffer::empty(area);
+        StepDisplay::new(&state).render(area, &mut buf);
+        assert_eq!(buf[(19, 1)].bg, Color::Reset);
+
+        state.keyboard_focused = true;
+        let mut buf = Buffer::empty(area);
+        StepDisplay::new(&state).render(area, &mut buf);
+        assert_eq!(buf[(0, 1)].bg, style.focused_step_style.bg.unwrap());
+        assert_eq!(buf[(19, 1)].bg, style.focused_step_style.bg.unwrap());
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+    }
+
+    #[test]
+    fn test_horizontal_layout() {
+        let mut state = StepDisplayState::new(vec![
+            Step::new("Name").with_sub_steps(vec!["Hidden"]),
+            Step::new("Options"),
+        ]);
+        state.complete_step(0);
+        state.start_step(1);
+
+        let area = Rect::new(0, 0, 30, 2);
+        let mut buf = Buffer::empty(area);
+        let mut registry = ClickRegionRegistry::new();
+        StepDisplay::new(&state)
+            .layout(StepDisplayLayout::Horizontal)
+            .render_with_registry(area, &mut buf, &mut registry);
+
+        let row: String = (0..30).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row.trim_end(), "[✓] Name ─ [▶] Options");
+        assert_eq!(buf[(4, 0)].fg, Color::Green);
+        assert_eq!(buf[(15, 0)].fg, Color::Yellow);
+        // Sub-steps and details stay out of the single row
+        assert_eq!(buf[(0, 1)].symbol(), " ");
+
+        assert_eq!(registry.handle_click(2, 0), Some(&0));
+        assert_eq!(registry.handle_click(9, 0), None);
+        assert_eq!(registry.handle_click(12, 0), Some(&1));
+    }
 }
@@ -37,8 +37,11 @@
 //!     });
 //! ```
 
+use std::collections::HashMap;
+
 use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
 use ratatui::{
+    Frame,
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -85,6 +88,12 @@ pub struct Tab<'a> {
     pub badge: Option<&'a str>,
     /// Whether this tab is enabled
     pub enabled: bool,
+    /// Whether this tab is pinned (rendered first, with the pinned indicator)
+    pub pinned: bool,
+    /// Whether this tab has unsaved changes (rendered with the dirty indicator)
+    pub dirty: bool,
+    /// Whether this tab shows a close button (never shown on pinned tabs)
+    pub closeable: bool,
 }
 
 impl<'a> Tab<'a> {
@@ -95,6 +104,9 @@ impl<'a> Tab<'a> {
             icon: None,
             badge: None,
             enabled: true,
+            pinned: false,
+            dirty: false,
+            closeable: false,
         }
     }
 
@@ -116,6 +128,29 @@ impl<'a> Tab<'a> {
         self
     }
 
+    /// Set whether the tab is pinned
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Set whether the tab has unsaved changes
+    pub fn dirty(mut self, dirty: bool) -> Self {
+        self.dirty = dirty;
+        self
+    }
+
+    /// Set whether the tab shows a close button
+    pub fn closeable(mut self, closeable: bool) -> Self {
+        self.closeable = closeable;
+        self
+    }
+
+    /// Whether the close button is rendered: closeable and not pinned
+    pub fn shows_close_button(&self) -> bool {
+        self.closeable && !self.pinned
+    }
+
     /// Calculate the display width of this tab
     pub fn display_width(&self) -> usize {
         let mut width = self.label.width();
@@ -144,6 +179,12 @@ pub struct TabViewState {
     pub focus_id: FocusId,
     /// Whether this component has focus
     pub focused: bool,
+    /// Whether [`TabView::render_with_cache`] reuses rendered tab content
+    pub cache_enabled: bool,
+    /// Last rendered content of each tab, keyed by tab index
+    pub content_cache: HashMap<usize, Buffer>,
+    /// Tab whose content was rendered last
+    rendered_tab: Option<usize>,
 }
 
 impl TabViewState {
@@ -156,6 +197,9 @@ impl TabViewState {
             tab_bar_focused: true,
             focus_id: FocusId::default(),
             focused: false,
+            cache_enabled: false,
+            content_cache: HashMap::new(),
+            rendered_tab: None,
         }
     }
 
@@ -168,6 +212,9 @@ impl TabViewState {
             tab_bar_focused: true,
             focus_id,
             focused: false,
+            cache_enabled: false,
+            content_cache: HashMap::new(),
+            rendered_tab: None,
         }
     }
 
@@ -225,10 +272,138 @@ impl TabViewState {
     /// Update the total number of tabs
     pub fn set_total(&mut self, total: usize) {
         self.total_tabs = total;
+        self.content_cache.retain(|&idx, _| idx < total);
         if self.selected_index >= total && total > 0 {
             self.selected_index = total - 1;
         }
     }
+
+    /// Pin the tab at `idx`, moving it after the other pinned tabs.
+    ///
+    /// The selection follows the tab it pointed at. Returns the tab's new index.
+    pub fn pin_tab(&mut self, tabs: &mut [Tab<'_>], idx: usize) -> usize {
+        if idx >= tabs.len() || tabs[idx].pinned {
+            return idx;
+        }
+        let target = tabs.iter().filter(|t| t.pinned).count();
+        tabs[idx].pinned = true;
+        self.move_tab(tabs, idx, target)
+    }
+
+    /// Unpin the tab at `idx`, moving it before the other unpinned tabs.
+    ///
+    /// The selection follows the tab it pointed at. Returns the tab's new index.
+    pub fn unpin_tab(&mut self, tabs: &mut [Tab<'_>], idx: usize) -> usize {
+        if idx >= tabs.len() || !tabs[idx].pinned {
+            return idx;
+        }
+        tabs[idx].pinned = false;
+        let target = tabs.iter().filter(|t| t.pinned).count();
+        self.move_tab(tabs, idx, target)
+    }
+
+    /// Mark the tab at `idx` as having unsaved changes.
+    pub fn mark_dirty(&mut self, tabs: &mut [Tab<'_>], idx: usize) {
+        if let Some(tab) = tabs.get_mut(idx) {
+            tab.dirty = true;
+        }
+    }
+
+    /// Mark the tab at `idx` as saved.
+    pub fn mark_clean(&mut self, tabs: &mut [Tab<'_>], idx: usize) {
+        if let Some(tab) = tabs.get_mut(idx) {
+            tab.dirty = false;
+        }
+    }
+
+    /// Check if any tab has unsaved changes.
+    pub fn has_dirty_tabs(&self, tabs: &[Tab<'_>]) -> bool {
+        tabs.iter().any(|tab| tab.dirty)
+    }
+
+    /// Move a tab from `from` to `to`, keeping the selection on the same tab.
+    fn move_tab(&mut self, tabs: &mut [Tab<'_>], from: usize, to: usize) -> usize {
+        if from < to {
+            tabs[from..=to].rotate_left(1);
+        } else {
+            tabs[to..=from].rotate_right(1);
+        }
+        let selected = self.selected_index;
+        if selected == from {
+            self.selected_index = to;
+        } else if from < selected && selected <= to {
+            self.selected_index -= 1;
+        } else if to <= selected && selected < from {
+            self.selected_index += 1;
+        }
+        // Cached content is keyed by index
+        self.content_cache.clear();
+        to
+    }
+
+    /// Enable or disable content caching.
+    ///
+    /// Disabling drops all cached content.
+    pub fn set_cache_enabled(&mut self, enabled: bool) {
+        self.cache_enabled = enabled;
+        if !enabled {
+            self.content_cache.clear();
+            self.rendered_tab = None;
+        }
+    }
+
+    /// Drop the cached content of the tab at `idx`, so it is rendered again
+    /// the next time it is shown.
+    pub fn invalidate_tab(&mut self, idx: usize) {
+        self.content_cache.remove(&idx);
+    }
+
+    /// Render the selected tab's content into `area`, reusing its cached
+    /// content when switching back to it at the same size.
+    fn render_cached_content<R>(&mut self, area: Rect, buf: &mut Buffer, render_fn: R)
+    where
+        R: FnOnce(usize, Rect, &mut Buffer),
+    {
+        let idx = self.selected_index;
+        if !self.cache_enabled {
+            render_fn(idx, area, buf);
+            return;
+        }
+
+        let visible = area.intersection(buf.area);
+        let switched = self.rendered_tab.replace(idx) != Some(idx);
+        if switched
+            && let Some(cached) = self.content_cache.get(&idx)
+            && cached.area.as_size() == visible.as_size()
+        {
+            for pos in cached.area.positions() {
+                let dst = (
+                    visible.x + pos.x - cached.area.x,
+                    visible.y + pos.y - cached.area.y,
+                );
+                buf[dst] = cached[pos].clone();
+            }
+            return;
+        }
+
+        render_fn(idx, area, buf);
+        let mut snapshot = Buffer::empty(visible);
+        for pos in visible.positions() {
+            snapshot[pos] = buf[pos].clone();
+        }
+        self.content_cache.insert(idx, snapshot);
+    }
+
+    /// `(chord, description)` pairs for the keys handled by [`handle_tab_view_key`].
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("←/→ or ↑/↓", "Previous/next tab"),
+            ("Home/End", "First/last tab"),
+            ("1-9", "Jump to tab"),
+            ("Enter", "Focus content"),
+            ("Esc", "Back to tab bar"),
+        ]
+    }
 }
 
 impl Default for TabViewState {
@@ -264,6 +439,18 @@ pub struct TabViewStyle {
     pub focused_style: Style,
     /// Style for disabled tabs
     pub disabled_style: Style,
+    /// Style for pinned (unselected) tabs
+    pub pinned_tab_style: Style,
+    /// Indicator shown before pinned tab labels
+    pub pinned_indicator: &'static str,
+    /// Indicator shown after the labels of tabs with unsaved changes
+    pub dirty_indicator: &'static str,
+    /// Style for the dirty indicator
+    pub dirty_indicator_style: Style,
+    /// Close button shown on closeable tabs
+    pub close_button: &'static str,
+    /// Style for the close button
+    pub close_button_style: Style,
     /// Style for badge text
     pub badge_style: Style,
     /// Style for the content area border
@@ -303,6 +490,12 @@ impl Default for TabViewStyle {
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
             disabled_style: Style::default().fg(Color::DarkGray),
+            pinned_tab_style: Style::default().fg(Color::Cyan),
+            pinned_indicator: "📌",
+            dirty_indicator: "●",
+            dirty_indicator_style: Style::default().fg(Color::Yellow),
+            close_button: "×",
+            close_button_style: Style::default().fg(Color::DarkGray),
             badge_style: Style::default()
                 .fg(Color::Black)
                 .bg(Color::Red)
@@ -334,6 +527,12 @@ impl From<&crate::theme::Theme> for TabViewStyle {
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
             disabled_style: Style::default().fg(p.text_disabled),
+            pinned_tab_style: Style::default().fg(p.secondary),
+            pinned_indicator: "📌",
+            dirty_indicator: "●",
+            dirty_indicator_style: Style::default().fg(p.warning),
+            close_button: "×",
+            close_button_style: Style::default().fg(p.text_disabled),
             badge_style: Style::default()
                 .fg(p.highlight_fg)
                 .bg(p.error)
@@ -449,6 +648,23 @@ pub enum TabViewAction {
     ScrollPrev,
     /// Scroll to next tabs
     ScrollNext,
+    /// The close button of a saved tab was clicked
+    CloseRequested(usize),
+    /// The close button of a tab with unsaved changes was clicked
+    ///
+    /// Sent instead of [`CloseRequested`](Self::CloseRequested) so the app
+    /// can ask to save first; [`close_index`](Self::close_index) covers both.
+    DirtyCloseRequested(usize),
+}
+
+impl TabViewAction {
+    /// Index of the tab whose close button was clicked, dirty or not
+    pub fn close_index(&self) -> Option<usize> {
+        match self {
+            Self::CloseRequested(idx) | Self::DirtyCloseRequested(idx) => Some(*idx),
+            _ => None,
+        }
+    }
 }
 
 /// Default content renderer type
@@ -600,9 +816,9 @@ where
                 .saturating_sub(if show_next { 2 } else { 0 }),
         );
 
-        for (idx, tab) in self
-            .tabs
-            .iter()
+        for (pos, (idx, tab)) in self
+            .ordered_tabs()
+            .into_iter()
             .enumerate()
             .skip(visible_start)
             .take(visible_count)
@@ -612,26 +828,39 @@ where
 
             // Build tab text
             let mut text = String::new();
+            if tab.pinned {
+                text.push_str(self.style.pinned_indicator);
+                text.push(' ');
+            }
             if let Some(icon) = tab.icon {
                 text.push_str(icon);
                 text.push(' ');
             }
             text.push_str(tab.label);
+            let label_end = text.width();
+            if tab.dirty {
+                text.push(' ');
+                text.push_str(self.style.dirty_indicator);
+            }
 
             // Determine style
-            let style = self.get_tab_style(idx, tab.enabled);
+            let style = self.get_tab_style(idx, tab);
 
             // Render indicator if selected and enabled
-            let text_with_padding = if self.state.selected_index == idx && self.style.show_indicator
-            {
-                format!("{} {} ", self.style.indicator, text)
+            let prefix = if self.state.selected_index == idx && self.style.show_indicator {
+                format!("{} ", self.style.indicator)
             } else {
-                format!(" {} ", text)
+                " ".to_string()
             };
+            let text_with_padding = format!("{}{} ", prefix, text);
 
             // Render the text using unicode width for proper calculation
             let text_width = text_with_padding.width() as u16;
             buf.set_string(x, y, &text_with_padding, style);
+            if tab.dirty {
+                let dirty_x = x + (prefix.width() + label_end + 1) as u16;
+                self.style_dirty_indicator(dirty_x, y, area.right(), buf);
+            }
             x += text_width;
 
             // Render badge if present (included in click region)
@@ -649,8 +878,18 @@ where
                 click_regions.push((tab_area, TabViewAction::TabClick(idx)));
             }
 
+            // Close button, with its own click region
+            if tab.shows_close_button() {
+                let close_text = format!("{} ", self.style.close_button);
+                let close_width = close_text.width() as u16;
+                buf.set_string(x, y, &close_text, self.style.close_button_style);
+                let close_area = Rect::new(x, y, close_width, 1).intersection(area);
+                click_regions.push((close_area, Self::close_action(idx, tab)));
+                x += close_width;
+            }
+
             // Render divider (if not last visible) - not part of click region
-            if idx + 1 < visible_start + visible_count && idx + 1 < self.tabs.len() {
+            if pos + 1 < visible_start + visible_count && pos + 1 < self.tabs.len() {
                 let divider_width = self.style.divider.width() as u16;
                 buf.set_string(
                     x,
@@ -710,9 +949,8 @@ where
         let visible_count = (available_height as usize).min(self.tabs.len() - visible_start);
 
         for (idx, tab) in self
-            .tabs
-            .iter()
-            .enumerate()
+            .ordered_tabs()
+            .into_iter()
             .skip(visible_start)
             .take(visible_count)
         {
@@ -728,20 +966,35 @@ where
             } else {
                 text.push_str("  ");
             }
+            if tab.pinned {
+                text.push_str(self.style.pinned_indicator);
+                text.push(' ');
+            }
             if let Some(icon) = tab.icon {
                 text.push_str(icon);
                 text.push(' ');
             }
             text.push_str(tab.label);
+            let label_end = text.width();
+            if tab.dirty {
+                text.push(' ');
+                text.push_str(self.style.dirty_indicator);
+            }
 
             // Add badge
             if let Some(badge) = tab.badge {
                 text.push_str(&format!(" ({})", badge));
             }
 
-            // Truncate if too long
-            let max_len = width as usize;
-            let display_text = if text.chars().count() > max_len {
+            // Truncate if too long, leaving room for the close button
+            let close_width = if tab.shows_close_button() {
+                self.style.close_button.width() as u16 + 1
+            } else {
+                0
+            };
+            let max_len = width.saturating_sub(close_width) as usize;
+            let truncated = text.chars().count() > max_len;
+            let display_text = if truncated {
                 let truncated: String = text.chars().take(max_len - 1).collect();
                 format!("{}…", truncated)
             } else {
@@ -749,11 +1002,21 @@ where
             };
 
             // Determine style
-            let style = self.get_tab_style(idx, tab.enabled);
+            let style = self.get_tab_style(idx, tab);
 
-            let tab_area = Rect::new(x, y, width, 1);
+            let tab_area = Rect::new(x, y, width.saturating_sub(close_width), 1);
             buf.set_string(x, y, &display_text, style);
+            if tab.dirty && !truncated {
+                let dirty_x = x + (label_end + 1) as u16;
+                self.style_dirty_indicator(dirty_x, y, tab_area.right(), buf);
+            }
             click_regions.push((tab_area, TabViewAction::TabClick(idx)));
+            if close_width > 0 {
+                let close_area = Rect::new(tab_area.right(), y, close_width, 1);
+                let close_text = format!("{} ", self.style.close_button);
+                buf.set_string(close_area.x, y, &close_text, self.style.close_button_style);
+                click_regions.push((close_area, Self::close_action(idx, tab)));
+            }
 
             y += 1;
         }
@@ -775,9 +1038,49 @@ where
         }
     }
 
+    /// Tabs with their indices, pinned tabs first
+    fn ordered_tabs(&self) -> Vec<(usize, &'a Tab<'a>)> {
+        let (pinned, unpinned): (Vec<_>, Vec<_>) = self
+            .tabs
+            .iter()
+            .enumerate()
+            .partition(|(_, tab)| tab.pinned);
+        pinned.into_iter().chain(unpinned).collect()
+    }
+
+    /// Rendered width of a tab, including the pinned and dirty indicators
+    fn tab_width(&self, tab: &Tab<'_>) -> u16 {
+        let mut width = tab.display_width();
+        if tab.pinned {
+            width += self.style.pinned_indicator.width() + 1; // indicator + space
+        }
+        if tab.dirty {
+            width += self.style.dirty_indicator.width() + 1; // space + indicator
+        }
+        if tab.shows_close_button() {
+            width += self.style.close_button.width() + 1; // button + space
+        }
+        width as u16
+    }
+
+    /// Apply the dirty indicator style at `x`, stopping at `right`
+    fn style_dirty_indicator(&self, x: u16, y: u16, right: u16, buf: &mut Buffer) {
+        let width = (self.style.dirty_indicator.width() as u16).min(right.saturating_sub(x));
+        buf.set_style(Rect::new(x, y, width, 1), self.style.dirty_indicator_style);
+    }
+
+    /// Action of a tab's close button, flagging unsaved changes
+    fn close_action(idx: usize, tab: &Tab<'_>) -> TabViewAction {
+        if tab.dirty {
+            TabViewAction::DirtyCloseRequested(idx)
+        } else {
+            TabViewAction::CloseRequested(idx)
+        }
+    }
+
     /// Get the appropriate style for a tab
-    fn get_tab_style(&self, idx: usize, enabled: bool) -> Style {
-        if !enabled {
+    fn get_tab_style(&self, idx: usize, tab: &Tab<'_>) -> Style {
+        if !tab.enabled {
             self.style.disabled_style
         } else if idx == self.state.selected_index
             && self.state.focused
@@ -786,6 +1089,8 @@ where
             self.style.focused_style
         } else if idx == self.state.selected_index {
             self.style.selected_style
+        } else if tab.pinned {
+            self.style.pinned_tab_style
         } else {
             self.style.normal_style
         }
@@ -796,7 +1101,7 @@ where
         let total_width: u16 = self
             .tabs
             .iter()
-            .map(|t| t.display_width() as u16 + self.style.divider.width() as u16)
+            .map(|t| self.tab_width(t) + self.style.divider.width() as u16)
             .sum();
         total_width > available_width
     }
@@ -805,8 +1110,12 @@ where
     fn visible_tabs_horizontal(&self, available_width: u16) -> usize {
         let mut width = 0u16;
         let mut count = 0;
-        for tab in self.tabs.iter().skip(self.state.scroll_offset) {
-            let tab_width = tab.display_width() as u16 + self.style.divider.width() as u16;
+        for (_, tab) in self
+            .ordered_tabs()
+            .into_iter()
+            .skip(self.state.scroll_offset)
+        {
+            let tab_width = self.tab_width(tab) + self.style.divider.width() as u16;
             if width + tab_width > available_width {
                 break;
             }
@@ -816,9 +1125,9 @@ where
         count.max(1)
     }
 
-    /// Render content area
-    fn render_content(&self, area: Rect, buf: &mut Buffer) {
-        let inner = if self.style.bordered_content {
+    /// Render the content border, returning the area inside it
+    fn render_content_block(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        if self.style.bordered_content {
             let block = Block::default()
                 .borders(Borders::ALL)
                 .border_style(self.style.content_border_style);
@@ -827,8 +1136,12 @@ where
             inner
         } else {
             area
-        };
+        }
+    }
 
+    /// Render content area
+    fn render_content(&self, area: Rect, buf: &mut Buffer) {
+        let inner = self.render_content_block(area, buf);
         if let Some(ref renderer) = self.content_renderer {
             renderer(self.state.selected_index, inner, buf);
         }
@@ -861,6 +1174,39 @@ where
     }
 }
 
+impl TabView<'_> {
+    /// Render the tab view, caching tab content in `state`.
+    ///
+    /// With [`TabViewState::cache_enabled`] set, each tab's rendered content
+    /// is kept in [`TabViewState::content_cache`]. When switching back to a
+    /// tab whose content area has the same size, the cached content is drawn
+    /// instead of calling `render_fn`; the selected tab is otherwise rendered
+    /// as usual. Call [`TabViewState::invalidate_tab`] when a tab's content
+    /// changes while it is not shown.
+    ///
+    /// Returns the tab bar click regions.
+    pub fn render_with_cache<R>(
+        tabs: &[Tab<'_>],
+        style: TabViewStyle,
+        frame: &mut Frame,
+        area: Rect,
+        state: &mut TabViewState,
+        render_fn: R,
+    ) -> Vec<(Rect, TabViewAction)>
+    where
+        R: FnOnce(usize, Rect, &mut Buffer),
+    {
+        let buf = frame.buffer_mut();
+        let view = TabView::new(tabs, state).style(style);
+        let (tab_area, content_area) = view.calculate_layout(area);
+        let click_regions = view.render_tab_bar(tab_area, buf);
+        let inner = view.render_content_block(content_area, buf);
+
+        state.render_cached_content(inner, buf, render_fn);
+        click_regions
+    }
+}
+
 impl<'a, F> Widget for TabView<'a, F>
 where
     F: Fn(usize, Rect, &mut Buffer),
@@ -963,6 +1309,9 @@ pub fn handle_tab_view_mouse(
                     state.scroll_offset += 1;
                     return Some(*action);
                 }
+                TabViewAction::CloseRequested(_) | TabViewAction::DirtyCloseRequested(_) => {
+                    return Some(*action);
+                }
             }
         }
     }
@@ -1174,4 +1523,203 @@ mod tests {
         assert!(handle_tab_view_key(&mut state, &key, TabPosition::Top));
         assert!(state.tab_bar_focused);
     }
+
+    #[test]
+    fn test_pin_and_unpin_reorder() {
+        let mut tabs = vec![Tab::new("A"), Tab::new("B"), Tab::new("C")];
+        let mut state = TabViewState::new(tabs.len());
+        state.select(1);
+
+        assert_eq!(state.pin_tab(&mut tabs, 2), 0);
+        let labels: Vec<&str> = tabs.iter().map(|t| t.label).collect();
+        assert_eq!(labels, vec!["C", "A", "B"]);
+        assert!(tabs[0].pinned);
+        // Selection follows "B"
+        assert_eq!(state.selected_index, 2);
+
+        assert_eq!(state.pin_tab(&mut tabs, 2), 1);
+        let labels: Vec<&str> = tabs.iter().map(|t| t.label).collect();
+        assert_eq!(labels, vec!["C", "B", "A"]);
+        assert_eq!(state.selected_index, 1);
+
+        // Unpinning moves the tab to the front of the unpinned tabs
+        assert_eq!(state.unpin_tab(&mut tabs, 0), 1);
+        let labels: Vec<&str> = tabs.iter().map(|t| t.label).collect();
+        assert_eq!(labels, vec!["B", "C", "A"]);
+        assert!(!tabs[1].pinned);
+        assert_eq!(state.selected_index, 0);
+    }
+
+    #[test]
+    fn test_pinned_tabs_render_first() {
+        let tabs = vec![Tab::new("Doc"), Tab::new("Home").pinned(true)];
+        let mut state = TabViewState::new(tabs.len());
+        state.select(0);
+        let tab_view = TabView::new(&tabs, &state);
+
+        let area = Rect::new(0, 0, 40, 5);
+        let mut buf = Buffer::empty(area);
+        let regions = tab_view.render_stateful(area, &mut buf);
+
+        assert_eq!(regions[0].1, TabViewAction::TabClick(1));
+        assert_eq!(regions[1].1, TabViewAction::TabClick(0));
+        assert_eq!(buf[(1, 0)].symbol(), "📌");
+        assert_eq!(buf[(1, 0)].fg, Color::Cyan);
+    }
+
+    #[test]
+    fn test_render_with_cache_reuses_content_on_switch_back() {
+        use ratatui::{Terminal, backend::TestBackend};
+        use std::cell::Cell;
+
+        let tabs = vec![Tab::new("One"), Tab::new("Two")];
+        let mut state = TabViewState::new(tabs.len());
+        state.set_cache_enabled(true);
+        let mut terminal = Terminal::new(TestBackend::new(20, 5)).unwrap();
+        let calls = Cell::new(0);
+        let mut draw = |state: &mut TabViewState, text: &'static str| {
+            terminal
+                .draw(|frame| {
+                    let area = frame.area();
+                    TabView::render_with_cache(
+                        &tabs,
+                        TabViewStyle::default(),
+                        frame,
+                        area,
+                        state,
+                        |_, area, buf| {
+                            calls.set(calls.get() + 1);
+                            buf.set_string(area.x, area.y, text, Style::default());
+                        },
+                    );
+                })
+                .unwrap()
+                .buffer
+                .clone()
+        };
+
+        draw(&mut state, "first");
+        draw(&mut state, "again");
+        assert_eq!(calls.get(), 2, "the selected tab renders every frame");
+
+        state.select_next();
+        draw(&mut state, "second");
+        state.select_prev();
+        let buf = draw(&mut state, "changed");
+        assert_eq!(calls.get(), 3, "switching back draws from the cache");
+        let text: String = buf.content.iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("again"));
+        assert!(!text.contains("changed"));
+
+        state.select_next();
+        draw(&mut state, "second");
+        state.invalidate_tab(0);
+        state.select_prev();
+        draw(&mut state, "changed");
+        // Tab 1 came from the cache, tab 0 was rendered again
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn test_disabling_cache_evicts_entries() {
+        let mut state = TabViewState::new(3);
+        state.set_cache_enabled(true);
+        state
+            .content_cache
+            .insert(1, Buffer::empty(Rect::new(0, 0, 2, 2)));
+        state.set_total(1);
+        assert!(state.content_cache.is_empty());
+
+        state
+            .content_cache
+            .insert(0, Buffer::empty(Rect::new(0, 0, 2, 2)));
+        state.set_cache_enabled(false);
+        assert!(state.content_cache.is_empty());
+    }
+
+    #[test]
+    fn test_close_button_flags_dirty_tabs() {
+        let tabs = vec![
+            Tab::new("a").closeable(true).pinned(true),
+            Tab::new("b").closeable(true),
+            Tab::new("c").closeable(true).dirty(true),
+        ];
+        let state = TabViewState::new(tabs.len());
+        let area = Rect::new(0, 0, 40, 5);
+        let mut buf = Buffer::empty(area);
+        let regions = TabView::new(&tabs, &state).render_stateful(area, &mut buf);
+
+        let actions: Vec<_> = regions.iter().map(|(_, action)| *action).collect();
+        assert_eq!(
+            actions,
+            [
+                TabViewAction::TabClick(0),
+                TabViewAction::TabClick(1),
+                TabViewAction::CloseRequested(1),
+                TabViewAction::TabClick(2),
+                TabViewAction::DirtyCloseRequested(2),
+            ],
+            "pinned tabs have no close button"
+        );
+        let (close_area, _) = regions[4];
+        assert_eq!(buf[(close_area.x, 0)].symbol(), "×");
+
+        let mut registry = ClickRegionRegistry::new();
+        for (area, action) in regions {
+            registry.register(area, action);
+        }
+        let mut state = state;
+        let click = MouseEvent {
+            kind: crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left),
+            column: close_area.x,
+            row: 0,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        };
+        let action = handle_tab_view_mouse(&mut state, &registry, &click);
+        assert_eq!(action, Some(TabViewAction::DirtyCloseRequested(2)));
+        assert_eq!(action.and_then(|a| a.close_index()), Some(2));
+        assert_eq!(TabViewAction::TabClick(2).close_index(), None);
+
+        // Vertical tabs put the button at the right edge
+        let mut buf = Buffer::empty(area);
+        let regions = TabView::new(&tabs, &state)
+            .style(TabViewStyle::left().tab_width(12))
+            .render_stateful(area, &mut buf);
+        assert!(regions.contains(&(Rect::new(10, 1, 2, 1), TabViewAction::CloseRequested(1))));
+        assert_eq!(buf[(10, 1)].symbol(), "×");
+    }
+
+    #[test]
+    fn test_dirty_tabs() {
+        let mut tabs = vec![Tab::new("main.rs"), Tab::new("lib.rs")];
+        let mut state = TabViewState::new(tabs.len());
+        assert!(!state.has_dirty_tabs(&tabs));
+
+        state.mark_dirty(&mut tabs, 1);
+        assert!(tabs[1].dirty);
+        assert!(state.has_dirty_tabs(&tabs));
+
+        let area = Rect::new(0, 0, 40, 5);
+        let mut buf = Buffer::empty(area);
+        let regions = TabView::new(&tabs, &state).render_stateful(area, &mut buf);
+        // "▸ main.rs  │  lib.rs ● ": the dot follows the label
+        let row: String = (0..23).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "▸ main.rs  │  lib.rs ● ");
+        assert_eq!(buf[(21, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(15, 0)].fg, Color::White);
+        // The indicator is part of the tab's click region
+        assert_eq!(regions[1].0, Rect::new(13, 0, 10, 1));
+
+        let mut buf = Buffer::empty(area);
+        let style = TabViewStyle::left().tab_width(12);
+        TabView::new(&tabs, &state)
+            .style(style)
+            .render(area, &mut buf);
+        let row: String = (0..12).map(|x| buf[(x, 1)].symbol()).collect();
+        assert_eq!(row, "  lib.rs ●  ");
+        assert_eq!(buf[(9, 1)].fg, Color::Yellow);
+
+        state.mark_clean(&mut tabs, 1);
+        assert!(!state.has_dirty_tabs(&tabs));
+    }
 }
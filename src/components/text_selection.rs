@@ -0,0 +1,463 @@
+//! Text selection - keyboard-driven copy mode for read-only viewers
+//!
+//! While mouse capture is on, the terminal's own selection does not work.
+//! [`TextSelection`] gives viewers an application-level copy mode instead:
+//! a cursor moved with the arrow or vi keys, linewise (`v`) or rectangular
+//! block (`Ctrl+V`) selection, and `y` to copy through
+//! [`copy_to_clipboard`].
+//!
+//! Positions are `(line, column)` in content coordinates, so a selection
+//! stays on the same text while the view scrolls. Used by
+//! [`LogViewer`](super::LogViewer) and [`DiffViewer`](super::DiffViewer).
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{SelectionMode, TextSelection};
+//!
+//! let lines = ["id  name", "1   alpha", "2   beta"];
+//! let mut selection = TextSelection::new();
+//! selection.enter(1);
+//!
+//! // Select the first column of the two data rows
+//! selection.start(SelectionMode::Block);
+//! selection.move_cursor(1, 0, &lines);
+//! assert_eq!(selection.selected_text(&lines), "1\n2");
+//! ```
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{buffer::Buffer, style::Style};
+
+use crate::utils::{ClipboardResult, copy_to_clipboard};
+
+/// Shape of a selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Whole lines between the anchor and cursor.
+    #[default]
+    Line,
+    /// The rectangle spanned by the anchor and cursor.
+    Block,
+}
+
+/// Actions emitted while in copy mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextSelectionAction {
+    /// Copy mode was entered.
+    Entered,
+    /// Copy mode was left.
+    Exited,
+    /// The cursor moved or the selection changed.
+    Moved,
+    /// The selection was copied; copy mode has been left.
+    Copied(ClipboardResult),
+}
+
+/// Copy mode cursor and selection state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextSelection {
+    /// Whether copy mode is on.
+    pub active: bool,
+    /// Cursor position as `(line, column)`.
+    pub cursor: (usize, usize),
+    /// Where the selection started, if selecting.
+    pub anchor: Option<(usize, usize)>,
+    /// Shape of the current selection.
+    pub mode: SelectionMode,
+}
+
+impl TextSelection {
+    /// Create an inactive selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enter copy mode with the cursor at the start of `line`.
+    pub fn enter(&mut self, line: usize) {
+        self.active = true;
+        self.cursor = (line, 0);
+        self.anchor = None;
+    }
+
+    /// Leave copy mode, dropping any selection.
+    pub fn exit(&mut self) {
+        self.active = false;
+        self.anchor = None;
+    }
+
+    /// Start a selection of the given shape at the cursor.
+    ///
+    /// Starting the shape already in progress cancels the selection;
+    /// starting the other shape switches to it, keeping the anchor.
+    pub fn start(&mut self, mode: SelectionMode) {
+        match self.anchor {
+            Some(_) if self.mode == mode => self.anchor = None,
+            Some(_) => self.mode = mode,
+            None => {
+                self.anchor = Some(self.cursor);
+                self.mode = mode;
+            }
+        }
+    }
+
+    /// Check if a selection is in progress.
+    pub fn is_selecting(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    /// Inclusive range of selected lines (the cursor line when not selecting).
+    pub fn line_range(&self) -> (usize, usize) {
+        let anchor = self.anchor.unwrap_or(self.cursor).0;
+        (anchor.min(self.cursor.0), anchor.max(self.cursor.0))
+    }
+
+    /// Inclusive range of selected columns for a block selection.
+    pub fn column_range(&self) -> Option<(usize, usize)> {
+        let anchor = self.anchor.filter(|_| self.mode == SelectionMode::Block)?;
+        Some((anchor.1.min(self.cursor.1), anchor.1.max(self.cursor.1)))
+    }
+
+    /// Check if the cell at `(line, column)` is selected.
+    pub fn contains(&self, line: usize, column: usize) -> bool {
+        if !self.active || self.anchor.is_none() {
+            return false;
+        }
+        let (first, last) = self.line_range();
+        if line < first || line > last {
+            return false;
+        }
+        self.column_range()
+            .is_none_or(|(start, end)| column >= start && column <= end)
+    }
+
+    /// Move the cursor by whole lines and columns, clamped to `content`.
+    pub fn move_cursor<S: AsRef<str>>(&mut self, lines: isize, columns: isize, content: &[S]) {
+        if content.is_empty() {
+            self.cursor = (0, 0);
+            return;
+        }
+        let line = self
+            .cursor
+            .0
+            .saturating_add_signed(lines)
+            .min(content.len() - 1);
+        let column = self.cursor.1.saturating_add_signed(columns);
+        self.cursor = (line, column.min(last_column(content, line)));
+    }
+
+    /// Move the cursor to a line, keeping the column where possible.
+    pub fn move_to_line<S: AsRef<str>>(&mut self, line: usize, content: &[S]) {
+        let line = line.min(content.len().saturating_sub(1));
+        self.cursor = (line, self.cursor.1.min(last_column(content, line)));
+    }
+
+    /// Move the cursor to the first column of its line.
+    pub fn move_to_line_start(&mut self) {
+        self.cursor.1 = 0;
+    }
+
+    /// Move the cursor to the last column of its line.
+    pub fn move_to_line_end<S: AsRef<str>>(&mut self, content: &[S]) {
+        self.cursor.1 = last_column(content, self.cursor.0);
+    }
+
+    /// Text covered by the selection, or the cursor line when not selecting.
+    ///
+    /// Block selections take the same column range from every line, so
+    /// shorter lines contribute fewer (or no) characters.
+    pub fn selected_text<S: AsRef<str>>(&self, content: &[S]) -> String {
+        let (first, last) = self.line_range();
+        let lines = content
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(last + 1 - first)
+            .map(|(_, line)| line.as_ref());
+        match self.column_range() {
+            Some((start, end)) => lines
+                .map(|line| line.chars().skip(start).take(end + 1 - start).collect())
+                .collect::<Vec<String>>()
+                .join("\n"),
+            None => lines.collect::<Vec<_>>().join("\n"),
+        }
+    }
+
+    /// Adjust scroll offsets so the cursor is inside a `height` x `width` viewport.
+    ///
+    /// A zero dimension leaves that axis alone.
+    pub fn scroll_into_view(
+        &self,
+        scroll_y: &mut usize,
+        scroll_x: &mut usize,
+        height: usize,
+        width: usize,
+    ) {
+        let (line, column) = self.cursor;
+        if line < *scroll_y {
+            *scroll_y = line;
+        } else if height > 0 && line >= *scroll_y + height {
+            *scroll_y = line + 1 - height;
+        }
+        if column < *scroll_x {
+            *scroll_x = column;
+        } else if width > 0 && column >= *scroll_x + width {
+            *scroll_x = column + 1 - width;
+        }
+    }
+
+    /// Short mode label for a status bar, or `None` outside copy mode.
+    pub fn status_label(&self) -> Option<&'static str> {
+        match (self.active, self.anchor, self.mode) {
+            (false, _, _) => None,
+            (true, None, _) => Some("COPY"),
+            (true, Some(_), SelectionMode::Line) => Some("V-LINE"),
+            (true, Some(_), SelectionMode::Block) => Some("V-BLOCK"),
+        }
+    }
+
+    /// `(chord, description)` pairs for the keys handled in copy mode.
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑↓←→/hjkl", "Move cursor"),
+            ("0/$", "Line start/end"),
+            ("g/G", "First/last line"),
+            ("v", "Select lines"),
+            ("Ctrl+V", "Select block"),
+            ("y/Enter", "Copy selection"),
+            ("Esc/q", "Cancel selection or leave copy mode"),
+        ]
+    }
+}
+
+/// Last cursor column on `line` (0 for empty lines).
+fn last_column<S: AsRef<str>>(content: &[S], line: usize) -> usize {
+    content
+        .get(line)
+        .map_or(0, |l| l.as_ref().chars().count().saturating_sub(1))
+}
+
+/// Handle a key while copy mode is active.
+///
+/// Returns `None` when copy mode is off or the key is not a copy mode key,
+/// so the viewer can still handle it (e.g. to scroll).
+pub fn handle_text_selection_key<S: AsRef<str>>(
+    selection: &mut TextSelection,
+    key: &KeyEvent,
+    content: &[S],
+) -> Option<TextSelectionAction> {
+    if !selection.active {
+        return None;
+    }
+
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('v') if ctrl => selection.start(SelectionMode::Block),
+        KeyCode::Char('v') => selection.start(SelectionMode::Line),
+        KeyCode::Char('y') | KeyCode::Enter => {
+            let result = copy_to_clipboard(&selection.selected_text(content));
+            selection.exit();
+            return Some(TextSelectionAction::Copied(result));
+        }
+        KeyCode::Esc if selection.is_selecting() => selection.anchor = None,
+        KeyCode::Esc | KeyCode::Char('q') => {
+            selection.exit();
+            return Some(TextSelectionAction::Exited);
+        }
+        KeyCode::Up | KeyCode::Char('k') => selection.move_cursor(-1, 0, content),
+        KeyCode::Down | KeyCode::Char('j') => selection.move_cursor(1, 0, content),
+        KeyCode::Left | KeyCode::Char('h') => selection.move_cursor(0, -1, content),
+        KeyCode::Right | KeyCode::Char('l') => selection.move_cursor(0, 1, content),
+        KeyCode::Home | KeyCode::Char('0') => selection.move_to_line_start(),
+        KeyCode::End | KeyCode::Char('$') => selection.move_to_line_end(content),
+        KeyCode::Char('g') => selection.move_to_line(0, content),
+        KeyCode::Char('G') => selection.move_to_line(usize::MAX, content),
+        _ => return None,
+    }
+    Some(TextSelectionAction::Moved)
+}
+
+/// Where one content line is drawn on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionRow {
+    /// Content line index.
+    pub line: usize,
+    /// Screen row.
+    pub y: u16,
+    /// Screen column where the first visible content column is drawn.
+    pub x: u16,
+    /// Number of content columns visible.
+    pub width: u16,
+}
+
+/// Patch `style` onto selected cells and `cursor_style` onto the cursor.
+///
+/// `scroll_x` is the first visible content column. Linewise selections
+/// cover the full row width, including space past the end of the text.
+pub fn render_selection_overlay(
+    selection: &TextSelection,
+    rows: impl IntoIterator<Item = SelectionRow>,
+    scroll_x: usize,
+    style: Style,
+    cursor_style: Style,
+    buf: &mut Buffer,
+) {
+    if !selection.active {
+        return;
+    }
+    for row in rows {
+        for dx in 0..row.width {
+            let column = scroll_x + dx as usize;
+            let is_cursor = selection.cursor == (row.line, column);
+            let style = if is_cursor {
+                cursor_style
+            } else if selection.contains(row.line, column) {
+                style
+            } else {
+                continue;
+            };
+            if let Some(cell) = buf.cell_mut((row.x + dx, row.y)) {
+                cell.set_style(style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{
+        layout::Rect,
+        style::{Color, Modifier},
+    };
+
+    const LINES: [&str; 3] = ["alpha", "be", "gamma"];
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_linewise_selection() {
+        let mut selection = TextSelection::new();
+        selection.enter(0);
+        selection.start(SelectionMode::Line);
+        selection.move_cursor(1, 0, &LINES);
+        assert_eq!(selection.line_range(), (0, 1));
+        assert!(selection.contains(1, 40));
+        assert!(!selection.contains(2, 0));
+        assert_eq!(selection.selected_text(&LINES), "alpha\nbe");
+        assert_eq!(selection.status_label(), Some("V-LINE"));
+    }
+
+    #[test]
+    fn test_block_selection_skips_short_lines() {
+        let mut selection = TextSelection::new();
+        selection.enter(0);
+        selection.move_cursor(0, 2, &LINES);
+        selection.start(SelectionMode::Block);
+        selection.move_cursor(2, 1, &LINES);
+        assert_eq!(selection.column_range(), Some((2, 3)));
+        assert!(selection.contains(1, 2));
+        assert!(!selection.contains(1, 1));
+        assert_eq!(selection.selected_text(&LINES), "ph\n\nmm");
+    }
+
+    #[test]
+    fn test_cursor_clamps_to_content() {
+        let mut selection = TextSelection::new();
+        selection.enter(0);
+        selection.move_to_line_end(&LINES);
+        assert_eq!(selection.cursor, (0, 4));
+        selection.move_cursor(1, 0, &LINES);
+        assert_eq!(selection.cursor, (1, 1));
+        selection.move_cursor(10, -10, &LINES);
+        assert_eq!(selection.cursor, (2, 0));
+    }
+
+    #[test]
+    fn test_start_toggles_and_switches() {
+        let mut selection = TextSelection::new();
+        selection.enter(0);
+        selection.start(SelectionMode::Line);
+        selection.start(SelectionMode::Block);
+        assert_eq!(selection.mode, SelectionMode::Block);
+        assert!(selection.is_selecting());
+        selection.start(SelectionMode::Block);
+        assert!(!selection.is_selecting());
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut selection = TextSelection::new();
+        assert_eq!(
+            handle_text_selection_key(&mut selection, &key(KeyCode::Down), &LINES),
+            None
+        );
+
+        selection.enter(0);
+        let ctrl_v = KeyEvent::new(KeyCode::Char('v'), KeyModifiers::CONTROL);
+        handle_text_selection_key(&mut selection, &ctrl_v, &LINES);
+        assert_eq!(selection.status_label(), Some("V-BLOCK"));
+        handle_text_selection_key(&mut selection, &key(KeyCode::Char('j')), &LINES);
+        assert_eq!(selection.cursor, (1, 0));
+
+        // Esc drops the selection first, then leaves copy mode
+        handle_text_selection_key(&mut selection, &key(KeyCode::Esc), &LINES);
+        assert!(selection.active && !selection.is_selecting());
+        assert_eq!(
+            handle_text_selection_key(&mut selection, &key(KeyCode::Esc), &LINES),
+            Some(TextSelectionAction::Exited)
+        );
+        assert!(!selection.active);
+    }
+
+    #[test]
+    fn test_yank_leaves_copy_mode() {
+        let mut selection = TextSelection::new();
+        selection.enter(2);
+        let action = handle_text_selection_key(&mut selection, &key(KeyCode::Char('y')), &LINES);
+        assert!(matches!(action, Some(TextSelectionAction::Copied(_))));
+        assert!(!selection.active);
+    }
+
+    #[test]
+    fn test_render_overlay() {
+        let mut selection = TextSelection::new();
+        selection.enter(0);
+        selection.move_cursor(0, 1, &LINES);
+        selection.start(SelectionMode::Block);
+        selection.move_cursor(1, 1, &LINES);
+
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buf = Buffer::empty(area);
+        let rows = (0..2).map(|i| SelectionRow {
+            line: i as usize,
+            y: i,
+            x: 1,
+            width: 5,
+        });
+        let style = Style::default().bg(Color::Blue);
+        let cursor = Style::default().add_modifier(Modifier::REVERSED);
+        // Scrolled right by one, so content column 1 is drawn at x = 1
+        render_selection_overlay(&selection, rows, 1, style, cursor, &mut buf);
+
+        assert_eq!(buf[(1, 0)].bg, Color::Blue);
+        assert_eq!(buf[(2, 0)].bg, Color::Reset);
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+        assert!(buf[(1, 1)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_scroll_into_view() {
+        let mut selection = TextSelection::new();
+        selection.enter(12);
+        selection.cursor.1 = 30;
+        let (mut y, mut x) = (0, 0);
+        selection.scroll_into_view(&mut y, &mut x, 10, 20);
+        assert_eq!((y, x), (3, 11));
+
+        selection.cursor = (1, 2);
+        selection.scroll_into_view(&mut y, &mut x, 10, 20);
+        assert_eq!((y, x), (1, 2));
+    }
+}
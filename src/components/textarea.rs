@@ -25,15 +25,22 @@
 //!     .placeholder("Enter text...");
 //! ```
 
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, ScrollbarOrientation, Widget},
 };
 
-use crate::traits::{ClickRegion, FocusId};
+use unicode_width::UnicodeWidthChar;
+
+use super::focus_ring::FocusRing;
+use super::interactive_scrollbar::{InteractiveScrollbar, ScrollbarDragState, ScrollbarPart};
+use crate::traits::{ClickRegion, FocusId, FocusableWidget};
+use crate::utils::MouseCaptureCoordinator;
 
 /// Convert character index to byte index in a string.
 fn char_to_byte_index(s: &str, char_idx: usize) -> usize {
@@ -43,6 +50,12 @@ fn char_to_byte_index(s: &str, char_idx: usize) -> usize {
         .unwrap_or(s.len())
 }
 
+/// Split a line into its leading whitespace width (in characters) and the rest.
+fn split_indent(line: &str) -> (usize, &str) {
+    let body = line.trim_start();
+    (line[..line.len() - body.len()].chars().count(), body)
+}
+
 /// Get character at index in a string.
 fn char_at(s: &str, index: usize) -> Option<char> {
     s.chars().nth(index)
@@ -106,6 +119,22 @@ pub struct TextAreaRender {
     pub click_region: ClickRegion<TextAreaAction>,
     /// Screen position for terminal cursor (only set when `CursorMode::Terminal` + focused).
     pub cursor_position: Option<(u16, u16)>,
+    /// Scrollbar part regions (empty unless the scrollbar is shown).
+    pub scrollbar_regions: Vec<ClickRegion<ScrollbarPart>>,
+}
+
+/// Cursor and scroll position of a [`TextAreaState`], for
+/// [`ScrollMemory`](crate::state::ScrollMemory).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextAreaSnapshot {
+    /// Cursor line.
+    pub cursor_line: usize,
+    /// Cursor column (character index within line).
+    pub cursor_col: usize,
+    /// Vertical scroll offset.
+    pub scroll_y: usize,
+    /// Horizontal scroll offset, in display cells.
+    pub scroll_x: usize,
 }
 
 /// State for a multi-line text area.
@@ -119,16 +148,29 @@ pub struct TextAreaState {
     pub cursor_col: usize,
     /// Vertical scroll offset.
     pub scroll_y: usize,
-    /// Horizontal scroll offset (for no-wrap mode).
+    /// Horizontal scroll offset in display cells (for no-wrap mode).
     pub scroll_x: usize,
     /// Visible viewport height (set during render).
     pub visible_height: usize,
     /// Whether the textarea has focus.
     pub focused: bool,
+    /// Focus ID matched by [`FocusManager::sync_from_vec`](crate::state::FocusManager::sync_from_vec).
+    pub focus_id: FocusId,
     /// Whether the textarea is enabled.
     pub enabled: bool,
     /// Tab configuration.
     pub tab_config: TabConfig,
+    /// Scrollbar thumb drag state.
+    pub scrollbar_drag: ScrollbarDragState,
+    /// Line comment prefix used by [`toggle_line_comment`](Self::toggle_line_comment),
+    /// e.g. `"//"` for Rust or `"#"` for shell.
+    pub comment_prefix: Option<&'static str>,
+    /// Block comment markers used by
+    /// [`toggle_block_comment`](Self::toggle_block_comment), e.g. `("/*", "*/")`.
+    pub block_comment: Option<(&'static str, &'static str)>,
+    /// Lines as of construction, [`set_text`](Self::set_text), or
+    /// [`mark_clean`](Self::mark_clean), compared by [`is_dirty`](Self::is_dirty).
+    pub clean_lines: Vec<String>,
 }
 
 impl Default for TextAreaState {
@@ -141,12 +183,31 @@ impl Default for TextAreaState {
             scroll_x: 0,
             visible_height: 0,
             focused: false,
+            focus_id: FocusId::default(),
             enabled: true,
             tab_config: TabConfig::default(),
+            scrollbar_drag: ScrollbarDragState::default(),
+            comment_prefix: None,
+            block_comment: None,
+            clean_lines: vec![String::new()],
         }
     }
 }
 
+impl FocusableWidget for TextAreaState {
+    fn focus_id(&self) -> FocusId {
+        self.focus_id
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
 impl TextAreaState {
     /// Create a new textarea state with initial text.
     ///
@@ -166,6 +227,7 @@ impl TextAreaState {
         };
 
         Self {
+            clean_lines: lines.clone(),
             lines,
             cursor_line: 0,
             cursor_col: 0,
@@ -173,11 +235,21 @@ impl TextAreaState {
             scroll_x: 0,
             visible_height: 0,
             focused: false,
+            focus_id: FocusId::default(),
             enabled: true,
             tab_config: TabConfig::default(),
+            scrollbar_drag: ScrollbarDragState::default(),
+            comment_prefix: None,
+            block_comment: None,
         }
     }
 
+    /// Set the focus ID used by [`FocusableWidget`].
+    pub fn with_focus_id(mut self, id: FocusId) -> Self {
+        self.focus_id = id;
+        self
+    }
+
     /// Create an empty textarea state.
     pub fn empty() -> Self {
         Self::default()
@@ -189,6 +261,18 @@ impl TextAreaState {
         self
     }
 
+    /// Set the line comment prefix.
+    pub fn with_comment_prefix(mut self, prefix: &'static str) -> Self {
+        self.comment_prefix = Some(prefix);
+        self
+    }
+
+    /// Set the block comment markers.
+    pub fn with_block_comment(mut self, open: &'static str, close: &'static str) -> Self {
+        self.block_comment = Some((open, close));
+        self
+    }
+
     // ========================================================================
     // Character operations
     // ========================================================================
@@ -454,6 +538,77 @@ impl TextAreaState {
         self.lines[self.cursor_line] = line[..byte_pos].to_string();
     }
 
+    // ========================================================================
+    // Comment operations
+    // ========================================================================
+
+    /// Toggle a line comment on the current line (Ctrl+/).
+    ///
+    /// Inserts [`comment_prefix`](Self::comment_prefix) and a space after the
+    /// indentation, or removes them if the line is already commented.
+    /// Does nothing when no prefix is set.
+    pub fn toggle_line_comment(&mut self) {
+        let Some(prefix) = self.comment_prefix else {
+            return;
+        };
+        if !self.enabled {
+            return;
+        }
+
+        let (indent, body) = split_indent(&self.lines[self.cursor_line]);
+        if let Some(rest) = body.strip_prefix(prefix) {
+            let rest = rest.strip_prefix(' ').unwrap_or(rest);
+            let removed = body.chars().count() - rest.chars().count();
+            let rest = rest.to_string();
+            self.rewrite_line_body(indent, &rest, 0, removed);
+        } else {
+            let commented = format!("{prefix} {body}");
+            self.rewrite_line_body(indent, &commented, prefix.chars().count() + 1, 0);
+        }
+    }
+
+    /// Toggle a block comment around the current line.
+    ///
+    /// Wraps the text after the indentation in the
+    /// [`block_comment`](Self::block_comment) markers, or unwraps it if it is
+    /// already enclosed in them. Does nothing when no markers are set.
+    pub fn toggle_block_comment(&mut self) {
+        let Some((open, close)) = self.block_comment else {
+            return;
+        };
+        if !self.enabled {
+            return;
+        }
+
+        let (indent, body) = split_indent(&self.lines[self.cursor_line]);
+        let enclosed = body.len() >= open.len() + close.len()
+            && body.starts_with(open)
+            && body.ends_with(close);
+        if enclosed {
+            let inner = &body[open.len()..body.len() - close.len()];
+            let trimmed = inner.strip_prefix(' ').unwrap_or(inner);
+            let removed = open.chars().count() + inner.len() - trimmed.len();
+            let trimmed = trimmed.strip_suffix(' ').unwrap_or(trimmed).to_string();
+            self.rewrite_line_body(indent, &trimmed, 0, removed);
+        } else {
+            let wrapped = format!("{open} {body} {close}");
+            self.rewrite_line_body(indent, &wrapped, open.chars().count() + 1, 0);
+        }
+    }
+
+    /// Replace the current line after its first `indent` characters with
+    /// `body`, shifting a cursor past the indentation by `added` characters
+    /// right and `removed` characters left.
+    fn rewrite_line_body(&mut self, indent: usize, body: &str, added: usize, removed: usize) {
+        let line = &mut self.lines[self.cursor_line];
+        line.truncate(char_to_byte_index(line, indent));
+        line.push_str(body);
+        if self.cursor_col >= indent {
+            self.cursor_col = indent + (self.cursor_col - indent + added).saturating_sub(removed);
+        }
+        self.cursor_col = self.cursor_col.min(line.chars().count());
+    }
+
     // ========================================================================
     // Cursor movement - Horizontal
     // ========================================================================
@@ -657,6 +812,33 @@ impl TextAreaState {
         }
     }
 
+    /// Capture the cursor and scroll position.
+    pub fn snapshot(&self) -> TextAreaSnapshot {
+        TextAreaSnapshot {
+            cursor_line: self.cursor_line,
+            cursor_col: self.cursor_col,
+            scroll_y: self.scroll_y,
+            scroll_x: self.scroll_x,
+        }
+    }
+
+    /// Restore a [`snapshot`](Self::snapshot), clamped to the current text.
+    pub fn apply_snapshot(&mut self, snapshot: &TextAreaSnapshot) {
+        let last = self.lines.len().saturating_sub(1);
+        self.cursor_line = snapshot.cursor_line.min(last);
+        let line_len = self
+            .lines
+            .get(self.cursor_line)
+            .map_or(0, |line| line.chars().count());
+        self.cursor_col = snapshot.cursor_col.min(line_len);
+        self.scroll_y = snapshot.scroll_y.min(self.cursor_line);
+        let cursor_x = self
+            .lines
+            .get(self.cursor_line)
+            .map_or(0, |line| cells_before(line, self.cursor_col));
+        self.scroll_x = snapshot.scroll_x.min(cursor_x);
+    }
+
     /// Ensure cursor is visible (alias for scroll_to_cursor).
     pub fn ensure_cursor_visible(&mut self) {
         self.scroll_to_cursor();
@@ -711,6 +893,17 @@ impl TextAreaState {
         self.cursor_col = self.lines[self.cursor_line].chars().count();
         self.scroll_y = 0;
         self.scroll_x = 0;
+        self.mark_clean();
+    }
+
+    /// Check if the text differs from the last clean text.
+    pub fn is_dirty(&self) -> bool {
+        self.lines != self.clean_lines
+    }
+
+    /// Treat the current text as saved, e.g. after a successful submit.
+    pub fn mark_clean(&mut self) {
+        self.clean_lines.clone_from(&self.lines);
     }
 
     /// Clear all text.
@@ -727,25 +920,19 @@ impl TextAreaState {
         self.lines.len()
     }
 
-    /// Count visual lines when soft-wrapped at `content_width` characters.
+    /// Count visual lines when soft-wrapped at `content_width` display cells.
     ///
-    /// Each logical line takes `ceil(char_count / content_width)` visual rows (minimum 1).
-    /// Use this to size a container that renders with `WrapMode::Soft`.
-    /// If `content_width` is 0, falls back to logical line count.
+    /// Each logical line takes at least one visual row; wide characters
+    /// count as two cells and are never split across rows. Use this to size
+    /// a container that renders with `WrapMode::Soft`. If `content_width`
+    /// is 0, falls back to logical line count.
     pub fn visual_line_count(&self, content_width: usize) -> usize {
         if content_width == 0 {
             return self.lines.len();
         }
         self.lines
             .iter()
-            .map(|line| {
-                let char_count = line.chars().count();
-                if char_count == 0 {
-                    1
-                } else {
-                    (char_count + content_width - 1) / content_width
-                }
-            })
+            .map(|line| wrap_starts(line, content_width).len())
             .sum::<usize>()
             .max(1)
     }
@@ -847,6 +1034,11 @@ impl From<&crate::theme::Theme> for TextAreaStyle {
 }
 
 impl TextAreaStyle {
+    /// Focus ring built from the border colors.
+    pub fn focus_ring(&self) -> FocusRing {
+        FocusRing::new(self.focused_border, self.unfocused_border).disabled(self.disabled_border)
+    }
+
     /// Set the focused border color.
     pub fn focused_border(mut self, color: Color) -> Self {
         self.focused_border = color;
@@ -930,6 +1122,12 @@ pub struct TextArea<'a> {
     content_lines: Option<Vec<Line<'a>>>,
     /// Border color override (bypasses focus-based color logic).
     border_color_override: Option<Color>,
+    /// Whether the content area is a mouse capture passthrough region.
+    copy_mode: bool,
+    /// Coordinator that receives the passthrough region in copy mode.
+    coordinator: Option<&'a mut MouseCaptureCoordinator>,
+    /// Whether to show a scrollbar when lines overflow.
+    show_scrollbar: bool,
 }
 
 impl TextArea<'_> {
@@ -945,6 +1143,9 @@ impl TextArea<'_> {
             title: None,
             content_lines: None,
             border_color_override: None,
+            copy_mode: false,
+            coordinator: None,
+            show_scrollbar: false,
         }
     }
 }
@@ -1018,6 +1219,30 @@ impl<'a> TextArea<'a> {
         self
     }
 
+    /// Enable or disable copy mode.
+    ///
+    /// In copy mode the content area is registered as a passthrough region
+    /// with the [`mouse_coordinator`](Self::mouse_coordinator), so the
+    /// terminal's native text selection works there.
+    pub fn copy_mode(mut self, copy_mode: bool) -> Self {
+        self.copy_mode = copy_mode;
+        self
+    }
+
+    /// Set the mouse capture coordinator used by copy mode.
+    pub fn mouse_coordinator(mut self, coordinator: &'a mut MouseCaptureCoordinator) -> Self {
+        self.coordinator = Some(coordinator);
+        self
+    }
+
+    /// Show a scrollbar on the right edge when lines overflow.
+    ///
+    /// Its regions are returned in [`TextAreaRender::scrollbar_regions`].
+    pub fn show_scrollbar(mut self, show: bool) -> Self {
+        self.show_scrollbar = show;
+        self
+    }
+
     /// Render the textarea and return render result with click region and optional cursor position.
     pub fn render_stateful(
         self,
@@ -1025,20 +1250,27 @@ impl<'a> TextArea<'a> {
         area: Rect,
         state: &mut TextAreaState,
     ) -> TextAreaRender {
-        let border_color = if let Some(override_color) = self.border_color_override {
-            override_color
-        } else if !state.enabled {
-            self.style.disabled_border
-        } else if state.focused {
-            self.style.focused_border
-        } else {
-            self.style.unfocused_border
-        };
+        self.render_to_buffer(area, frame.buffer_mut(), state)
+    }
 
+    /// Render the textarea using Buffer (Widget-style rendering).
+    ///
+    /// This is useful when you need to render without a Frame reference.
+    pub fn render_to_buffer(
+        mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TextAreaState,
+    ) -> TextAreaRender {
         let block = if self.with_border {
-            let mut block = Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(border_color));
+            let mut block = Block::default().borders(Borders::ALL);
+            block = match self.border_color_override {
+                Some(color) => block.border_style(Style::default().fg(color)),
+                None => self
+                    .style
+                    .focus_ring()
+                    .block(block, state.focused, state.enabled),
+            };
             if let Some(title) = self.title {
                 block = block.title(title);
             } else if let Some(label) = self.label {
@@ -1055,6 +1287,12 @@ impl<'a> TextArea<'a> {
             area
         };
 
+        if self.copy_mode
+            && let Some(coordinator) = self.coordinator.as_deref_mut()
+        {
+            coordinator.register_passthrough(inner_area);
+        }
+
         // Update visible height in state
         state.visible_height = inner_area.height as usize;
 
@@ -1082,36 +1320,31 @@ impl<'a> TextArea<'a> {
                 let paragraph = Paragraph::new(display_line);
 
                 if let Some(block) = block {
-                    frame.render_widget(block, area);
+                    block.render(area, buf);
                 }
-                frame.render_widget(paragraph, inner_area);
+                paragraph.render(inner_area, buf);
                 return TextAreaRender {
                     click_region: ClickRegion::new(area, TextAreaAction::Focus),
                     cursor_position: None,
+                    scrollbar_regions: Vec::new(),
                 };
             }
         }
 
         let mut display_lines: Vec<Line> = Vec::new();
         let mut cursor_screen_pos: Option<(u16, u16)> = None;
+        // Cursor cell as (content column, row, width in cells)
+        let mut cursor_cell_pos: Option<(usize, u16, usize)> = None;
 
         if self.wrap_mode == WrapMode::Soft && content_width > 0 {
             // Build visual rows: (logical_line_idx, start_col_in_line)
             let mut visual_rows: Vec<(usize, usize)> = Vec::new();
             for (li, line) in state.lines.iter().enumerate() {
-                let char_count = line.chars().count();
-                if char_count == 0 {
-                    visual_rows.push((li, 0));
-                } else {
-                    let mut col = 0;
-                    loop {
-                        visual_rows.push((li, col));
-                        col += content_width;
-                        if col >= char_count {
-                            break;
-                        }
-                    }
-                }
+                visual_rows.extend(
+                    wrap_starts(line, content_width)
+                        .into_iter()
+                        .map(|col| (li, col)),
+                );
             }
 
             let total_visual_rows = visual_rows.len();
@@ -1121,33 +1354,29 @@ impl<'a> TextArea<'a> {
                 .iter()
                 .enumerate()
                 .rev()
-                .find(|(_, (li, vc))| {
-                    *li == state.cursor_line && state.cursor_col >= *vc
-                })
+                .find(|(_, (li, vc))| *li == state.cursor_line && state.cursor_col >= *vc)
                 .map(|(i, _)| i)
                 .unwrap_or(0);
 
             // Effective scroll in visual rows
-            let effective_scroll_vr =
-                if self.style.scroll_mode == ScrollMode::CenterTracking && state.visible_height > 0
-                {
-                    let half_height = state.visible_height / 2;
-                    if total_visual_rows <= state.visible_height
-                        || cursor_visual_row <= half_height
-                    {
-                        0
-                    } else if cursor_visual_row + half_height >= total_visual_rows {
-                        total_visual_rows.saturating_sub(state.visible_height)
-                    } else {
-                        cursor_visual_row.saturating_sub(half_height)
-                    }
+            let effective_scroll_vr = if self.style.scroll_mode == ScrollMode::CenterTracking
+                && state.visible_height > 0
+            {
+                let half_height = state.visible_height / 2;
+                if total_visual_rows <= state.visible_height || cursor_visual_row <= half_height {
+                    0
+                } else if cursor_visual_row + half_height >= total_visual_rows {
+                    total_visual_rows.saturating_sub(state.visible_height)
                 } else {
-                    // Convert logical scroll_y to visual row offset
-                    visual_rows
-                        .iter()
-                        .position(|(li, _)| *li >= state.scroll_y)
-                        .unwrap_or(0)
-                };
+                    cursor_visual_row.saturating_sub(half_height)
+                }
+            } else {
+                // Convert logical scroll_y to visual row offset
+                visual_rows
+                    .iter()
+                    .position(|(li, _)| *li >= state.scroll_y)
+                    .unwrap_or(0)
+            };
 
             let start_vr = effective_scroll_vr;
             let end_vr = (start_vr + state.visible_height).min(total_visual_rows);
@@ -1158,9 +1387,7 @@ impl<'a> TextArea<'a> {
                 let display_row = vr_offset as u16;
 
                 let line = &state.lines[line_idx];
-                let chars: Vec<char> = line.chars().collect();
-                let visible_chars: String =
-                    chars.iter().skip(start_col).take(content_width).collect();
+                let visible_chars = fit_chars(line, start_col, content_width);
 
                 let mut spans = Vec::new();
 
@@ -1193,62 +1420,32 @@ impl<'a> TextArea<'a> {
 
                 // Cursor is on this visual row if cursor_col falls in [start_col, next_start_col)
                 // or this is the last visual row for this logical line
-                let is_last_vr_for_line =
-                    vr_idx + 1 >= visual_rows.len() || visual_rows[vr_idx + 1].0 != line_idx;
+                let next_start = visual_rows
+                    .get(vr_idx + 1)
+                    .filter(|(li, _)| *li == line_idx)
+                    .map(|(_, col)| *col);
                 let cursor_on_this_vr = is_cursor_line
                     && state.cursor_col >= start_col
-                    && (is_last_vr_for_line || state.cursor_col < start_col + content_width);
+                    && next_start.is_none_or(|next| state.cursor_col < next);
 
                 if cursor_on_this_vr && state.focused {
-                    let cursor_visible_col = state.cursor_col - start_col;
-                    let visible_char_count = visible_chars.chars().count();
-
-                    if use_terminal_cursor {
-                        spans.push(Span::styled(visible_chars, line_style));
-                        let cx =
-                            inner_area.x + line_num_width as u16 + cursor_visible_col as u16;
-                        let cy = inner_area.y + display_row;
-                        if cx < inner_area.x + inner_area.width
-                            && cy < inner_area.y + inner_area.height
-                        {
-                            cursor_screen_pos = Some((cx, cy));
-                        }
-                    } else if cursor_visible_col <= visible_char_count {
-                        let before: String =
-                            visible_chars.chars().take(cursor_visible_col).collect();
-                        let cursor_char: String = visible_chars
-                            .chars()
-                            .skip(cursor_visible_col)
-                            .take(1)
-                            .collect();
-                        let after: String =
-                            visible_chars.chars().skip(cursor_visible_col + 1).collect();
-
-                        if !before.is_empty() {
-                            spans.push(Span::styled(before, line_style));
-                        }
-                        let cursor_style = Style::default()
-                            .fg(self.style.cursor_fg)
-                            .bg(self.style.text_fg);
-                        let cursor_display =
-                            if cursor_char.is_empty() { " " } else { &cursor_char };
-                        spans.push(Span::styled(cursor_display.to_string(), cursor_style));
-                        if !after.is_empty() {
-                            spans.push(Span::styled(after, line_style));
-                        }
-                    } else {
-                        spans.push(Span::styled(visible_chars, line_style));
-                    }
-                } else {
-                    spans.push(Span::styled(visible_chars, line_style));
+                    cursor_cell_pos = cursor_cell(
+                        line,
+                        cells_before(line, start_col),
+                        state.cursor_col,
+                        content_width,
+                    )
+                    .map(|(x, w)| (x, display_row, w));
                 }
+                spans.push(Span::styled(visible_chars, line_style));
 
                 display_lines.push(Line::from(spans));
             }
         } else {
-        // Calculate effective scroll offset
-        let effective_scroll_y =
-            if self.style.scroll_mode == ScrollMode::CenterTracking && state.visible_height > 0 {
+            // Calculate effective scroll offset
+            let effective_scroll_y = if self.style.scroll_mode == ScrollMode::CenterTracking
+                && state.visible_height > 0
+            {
                 // Center-tracking: keep cursor near vertical midpoint
                 let total_lines = state.lines.len();
                 let half_height = state.visible_height / 2;
@@ -1263,138 +1460,94 @@ impl<'a> TextArea<'a> {
                 state.scroll_y
             };
 
-        // Build visible lines
-        let start_line = effective_scroll_y;
-        let end_line = (start_line + state.visible_height).min(state.lines.len());
+            // Build visible lines
+            let start_line = effective_scroll_y;
+            let end_line = (start_line + state.visible_height).min(state.lines.len());
 
-        for line_idx in start_line..end_line {
-            let is_cursor_line = line_idx == state.cursor_line;
-            let display_row = (line_idx - start_line) as u16;
-
-            // Check if we have pre-styled content lines
-            if let Some(ref content) = self.content_lines {
-                if line_idx < content.len() {
-                    let mut spans = Vec::new();
-
-                    // Line number
-                    if self.style.show_line_numbers {
-                        let line_num = format!(
-                            "{:>width$} ",
-                            line_idx + 1,
-                            width = line_num_width.saturating_sub(2)
-                        );
-                        spans.push(Span::styled(
-                            line_num,
-                            Style::default().fg(self.style.line_number_fg),
-                        ));
-                    }
+            for line_idx in start_line..end_line {
+                let is_cursor_line = line_idx == state.cursor_line;
+                let display_row = (line_idx - start_line) as u16;
+
+                // Check if we have pre-styled content lines
+                if let Some(ref content) = self.content_lines {
+                    if line_idx < content.len() {
+                        let mut spans = Vec::new();
+
+                        // Line number
+                        if self.style.show_line_numbers {
+                            let line_num = format!(
+                                "{:>width$} ",
+                                line_idx + 1,
+                                width = line_num_width.saturating_sub(2)
+                            );
+                            spans.push(Span::styled(
+                                line_num,
+                                Style::default().fg(self.style.line_number_fg),
+                            ));
+                        }
 
-                    // Use pre-styled content
-                    spans.extend(content[line_idx].spans.iter().cloned());
-                    display_lines.push(Line::from(spans));
-
-                    // Calculate cursor position for terminal mode
-                    if is_cursor_line && state.focused && use_terminal_cursor {
-                        let cursor_visible_col = state.cursor_col.saturating_sub(state.scroll_x);
-                        let cx = inner_area.x + line_num_width as u16 + cursor_visible_col as u16;
-                        let cy = inner_area.y + display_row;
-                        if cx < inner_area.x + inner_area.width
-                            && cy < inner_area.y + inner_area.height
-                        {
-                            cursor_screen_pos = Some((cx, cy));
+                        // Use pre-styled content
+                        spans.extend(content[line_idx].spans.iter().cloned());
+                        display_lines.push(Line::from(spans));
+
+                        // Calculate cursor position for terminal mode
+                        if is_cursor_line && state.focused && use_terminal_cursor {
+                            cursor_cell_pos = cursor_cell(
+                                &state.lines[line_idx],
+                                state.scroll_x,
+                                state.cursor_col,
+                                content_width,
+                            )
+                            .map(|(x, w)| (x, display_row, w));
                         }
+                        continue;
                     }
-                    continue;
                 }
-            }
 
-            let line = &state.lines[line_idx];
+                let line = &state.lines[line_idx];
 
-            // Apply horizontal scroll
-            let chars: Vec<char> = line.chars().collect();
-            let visible_chars: String = chars
-                .iter()
-                .skip(state.scroll_x)
-                .take(content_width)
-                .collect();
+                // Apply horizontal scroll; a wide character cut by the left
+                // edge is replaced by padding
+                let (start, pad) = char_at_cell(line, state.scroll_x);
+                let mut visible_chars = " ".repeat(pad.min(content_width));
+                visible_chars.push_str(&fit_chars(line, start, content_width.saturating_sub(pad)));
 
-            let mut spans = Vec::new();
-
-            // Line number
-            if self.style.show_line_numbers {
-                let line_num = format!(
-                    "{:>width$} ",
-                    line_idx + 1,
-                    width = line_num_width.saturating_sub(2)
-                );
-                spans.push(Span::styled(
-                    line_num,
-                    Style::default().fg(self.style.line_number_fg),
-                ));
-            }
+                let mut spans = Vec::new();
 
-            // Determine line style
-            let line_style = if is_cursor_line {
-                if let Some(bg) = self.style.current_line_bg {
-                    Style::default().fg(self.style.text_fg).bg(bg)
-                } else {
-                    Style::default().fg(self.style.text_fg)
+                // Line number
+                if self.style.show_line_numbers {
+                    let line_num = format!(
+                        "{:>width$} ",
+                        line_idx + 1,
+                        width = line_num_width.saturating_sub(2)
+                    );
+                    spans.push(Span::styled(
+                        line_num,
+                        Style::default().fg(self.style.line_number_fg),
+                    ));
                 }
-            } else {
-                Style::default().fg(self.style.text_fg)
-            };
-
-            // Build content with cursor
-            if is_cursor_line && state.focused {
-                let cursor_visible_col = state.cursor_col.saturating_sub(state.scroll_x);
-                let visible_char_count = visible_chars.chars().count();
-
-                if use_terminal_cursor {
-                    // Terminal cursor mode: just render text, return screen position
-                    spans.push(Span::styled(visible_chars, line_style));
-                    let cx = inner_area.x + line_num_width as u16 + cursor_visible_col as u16;
-                    let cy = inner_area.y + display_row;
-                    if cx < inner_area.x + inner_area.width && cy < inner_area.y + inner_area.height
-                    {
-                        cursor_screen_pos = Some((cx, cy));
-                    }
-                } else if cursor_visible_col <= visible_char_count {
-                    // Block cursor mode: render inverted span
-                    let before: String = visible_chars.chars().take(cursor_visible_col).collect();
-                    let cursor_char: String = visible_chars
-                        .chars()
-                        .skip(cursor_visible_col)
-                        .take(1)
-                        .collect();
-                    let after: String =
-                        visible_chars.chars().skip(cursor_visible_col + 1).collect();
-
-                    if !before.is_empty() {
-                        spans.push(Span::styled(before, line_style));
-                    }
 
-                    let cursor_style = Style::default()
-                        .fg(self.style.cursor_fg)
-                        .bg(self.style.text_fg);
-                    let cursor_display = if cursor_char.is_empty() {
-                        " "
+                // Determine line style
+                let line_style = if is_cursor_line {
+                    if let Some(bg) = self.style.current_line_bg {
+                        Style::default().fg(self.style.text_fg).bg(bg)
                     } else {
-                        &cursor_char
-                    };
-                    spans.push(Span::styled(cursor_display.to_string(), cursor_style));
-
-                    if !after.is_empty() {
-                        spans.push(Span::styled(after, line_style));
+                        Style::default().fg(self.style.text_fg)
                     }
                 } else {
-                    spans.push(Span::styled(visible_chars, line_style));
+                    Style::default().fg(self.style.text_fg)
+                };
+
+                // Locate the cursor cell; it is drawn after the text
+                if is_cursor_line && state.focused {
+                    cursor_cell_pos =
+                        cursor_cell(line, state.scroll_x, state.cursor_col, content_width)
+                            .map(|(x, w)| (x, display_row, w));
                 }
-            } else {
                 spans.push(Span::styled(visible_chars, line_style));
-            }
 
-            display_lines.push(Line::from(spans));
-        }
+                display_lines.push(Line::from(spans));
+            }
         } // end else (WrapMode::None)
 
         // Handle case when there are no lines to display (but cursor is active)
@@ -1424,15 +1577,206 @@ impl<'a> TextArea<'a> {
         let paragraph = Paragraph::new(display_lines);
 
         if let Some(block) = block {
-            frame.render_widget(block, area);
+            block.render(area, buf);
+        }
+        paragraph.render(inner_area, buf);
+
+        // Terminal cursor goes at the cell; the block cursor inverts every
+        // cell of the character under it
+        if let Some((x, row, width)) = cursor_cell_pos
+            && row < inner_area.height
+        {
+            let cx = inner_area.x + (line_num_width + x) as u16;
+            let cy = inner_area.y + row;
+            if use_terminal_cursor {
+                cursor_screen_pos = Some((cx, cy));
+            } else {
+                let cursor_style = Style::default()
+                    .fg(self.style.cursor_fg)
+                    .bg(self.style.text_fg);
+                buf.set_style(Rect::new(cx, cy, width as u16, 1), cursor_style);
+            }
+        }
+
+        let mut scrollbar_regions = Vec::new();
+        if self.show_scrollbar && state.lines.len() > state.visible_height {
+            let scrollbar = textarea_scrollbar(state);
+            scrollbar.render(inner_area, buf);
+            scrollbar_regions = scrollbar.click_regions(inner_area);
         }
-        frame.render_widget(paragraph, inner_area);
 
         TextAreaRender {
             click_region: ClickRegion::new(area, TextAreaAction::Focus),
             cursor_position: cursor_screen_pos,
+            scrollbar_regions,
+        }
+    }
+}
+
+/// Scrollbar sized so the thumb reaches the end at the last scroll offset.
+fn textarea_scrollbar(state: &TextAreaState) -> InteractiveScrollbar {
+    let max_scroll = state.lines.len().saturating_sub(state.visible_height);
+    InteractiveScrollbar::new(
+        ScrollbarOrientation::VerticalRight,
+        max_scroll + 1,
+        state.scroll_y,
+    )
+    .viewport_length(state.visible_height)
+}
+
+/// Handle mouse input on the textarea scrollbar.
+///
+/// Clicking the arrows scrolls one line, clicking the track pages, and
+/// dragging the thumb scrolls proportionally. The cursor is left unchanged.
+/// `regions` must come from [`TextAreaRender::scrollbar_regions`].
+///
+/// Returns `true` if the event was consumed.
+pub fn handle_textarea_scrollbar_mouse(
+    state: &mut TextAreaState,
+    regions: &[ClickRegion<ScrollbarPart>],
+    mouse: &MouseEvent,
+) -> bool {
+    let was_dragging = state.scrollbar_drag.is_dragging();
+    let scrollbar = textarea_scrollbar(state);
+    match scrollbar.handle_mouse(regions, &mut state.scrollbar_drag, mouse) {
+        Some(position) => {
+            state.scroll_y = position;
+            true
+        }
+        None => was_dragging || state.scrollbar_drag.is_dragging(),
+    }
+}
+
+/// Handle mouse input on the textarea content.
+///
+/// A left click moves the cursor to the clicked character and returns
+/// [`TextAreaAction::Focus`]; clicks on the line-number gutter go to the
+/// start of the line and clicks past the end go to the end. The scroll
+/// wheel scrolls by one line. Columns are measured in display cells, so
+/// wide characters take two columns.
+///
+/// `inner_area` is the area inside the border, `scroll_x` the horizontal
+/// scroll in display cells, and `line_number_width` the gutter width (0 when
+/// line numbers are hidden). Positions assume [`WrapMode::None`].
+pub fn handle_textarea_mouse(
+    mouse: &MouseEvent,
+    state: &mut TextAreaState,
+    inner_area: Rect,
+    scroll_x: usize,
+    line_number_width: usize,
+) -> Option<TextAreaAction> {
+    if !state.enabled {
+        return None;
+    }
+
+    let inside = mouse.column >= inner_area.x
+        && mouse.column < inner_area.right()
+        && mouse.row >= inner_area.y
+        && mouse.row < inner_area.bottom();
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) if inside => {
+            let row = (mouse.row - inner_area.y) as usize + state.scroll_y;
+            state.cursor_line = row.min(state.lines.len().saturating_sub(1));
+
+            let click_x =
+                ((mouse.column - inner_area.x) as usize).saturating_sub(line_number_width);
+            state.cursor_col =
+                column_at_display_x(&state.lines[state.cursor_line], scroll_x, click_x);
+            Some(TextAreaAction::Focus)
+        }
+        MouseEventKind::ScrollUp if inside => {
+            state.scroll_up();
+            None
+        }
+        MouseEventKind::ScrollDown if inside => {
+            state.scroll_down();
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Display width of a character (0 for control characters).
+fn char_width(ch: char) -> usize {
+    UnicodeWidthChar::width(ch).unwrap_or(0)
+}
+
+/// Characters of `line` from the `start`-th that fit in `width` display cells.
+///
+/// A wide character that would straddle the right edge is left out.
+fn fit_chars(line: &str, start: usize, width: usize) -> String {
+    let mut used = 0;
+    line.chars()
+        .skip(start)
+        .take_while(|&ch| {
+            used += char_width(ch);
+            used <= width
+        })
+        .collect()
+}
+
+/// Character indices where the soft-wrapped rows of `line` start, wrapping
+/// at `width` display cells.
+fn wrap_starts(line: &str, width: usize) -> Vec<usize> {
+    let mut starts = vec![0];
+    let mut row_width = 0;
+    for (i, ch) in line.chars().enumerate() {
+        let w = char_width(ch);
+        if row_width > 0 && row_width + w > width {
+            starts.push(i);
+            row_width = 0;
+        }
+        row_width += w;
+    }
+    starts
+}
+
+/// Display width of the first `chars` characters of `line`.
+fn cells_before(line: &str, chars: usize) -> usize {
+    line.chars().take(chars).map(char_width).sum()
+}
+
+/// First character of `line` starting at or after display cell `cell`, and
+/// the cells between `cell` and that character (1 when a wide character
+/// straddles `cell`).
+fn char_at_cell(line: &str, cell: usize) -> (usize, usize) {
+    let mut start = 0;
+    for (i, ch) in line.chars().enumerate() {
+        if start >= cell {
+            return (i, start - cell);
+        }
+        start += char_width(ch);
+    }
+    (line.chars().count(), start.saturating_sub(cell))
+}
+
+/// Display column and width of the cursor cell on a row showing `line`
+/// scrolled `scroll_x` cells to the right.
+///
+/// The cell covers the whole character under the cursor (two cells for
+/// wide characters, one past the end of the line). Returns `None` if the
+/// cursor starts left of the viewport or its cell does not fit in `width`.
+fn cursor_cell(line: &str, scroll_x: usize, cursor: usize, width: usize) -> Option<(usize, usize)> {
+    let x = cells_before(line, cursor).checked_sub(scroll_x)?;
+    let w = line.chars().nth(cursor).map(char_width).unwrap_or(1).max(1);
+    (x + w <= width).then_some((x, w))
+}
+
+/// Character index in `line` under display column `x` of a row scrolled
+/// `scroll_x` cells to the right.
+fn column_at_display_x(line: &str, scroll_x: usize, x: usize) -> usize {
+    let target = scroll_x + x;
+    let mut col = 0;
+    let mut width = 0;
+    for ch in line.chars() {
+        width += char_width(ch);
+        if width > target {
+            break;
         }
+        col += 1;
     }
+    col
 }
 
 #[cfg(test)]
@@ -1684,6 +2028,58 @@ mod tests {
         assert_eq!(state.lines[0], "Hello");
     }
 
+    // ========================================================================
+    // Comment tests
+    // ========================================================================
+
+    #[test]
+    fn test_toggle_line_comment() {
+        let mut state =
+            TextAreaState::new("fn main() {\n    let x = 1;\n}").with_comment_prefix("//");
+        state.cursor_line = 1;
+        state.cursor_col = 8;
+
+        state.toggle_line_comment();
+        assert_eq!(state.lines[1], "    // let x = 1;");
+        assert_eq!(state.cursor_col, 11);
+
+        state.toggle_line_comment();
+        assert_eq!(state.lines[1], "    let x = 1;");
+        assert_eq!(state.cursor_col, 8);
+
+        // Without a space after the prefix
+        state.lines[1] = "    //let x = 1;".into();
+        state.toggle_line_comment();
+        assert_eq!(state.lines[1], "    let x = 1;");
+
+        // No prefix configured
+        state.comment_prefix = None;
+        state.toggle_line_comment();
+        assert_eq!(state.lines[1], "    let x = 1;");
+    }
+
+    #[test]
+    fn test_toggle_block_comment() {
+        let mut state = TextAreaState::new("  int x = 1;").with_block_comment("/*", "*/");
+        state.cursor_col = 2;
+
+        state.toggle_block_comment();
+        assert_eq!(state.lines[0], "  /* int x = 1; */");
+        assert_eq!(state.cursor_col, 5);
+
+        state.toggle_block_comment();
+        assert_eq!(state.lines[0], "  int x = 1;");
+        assert_eq!(state.cursor_col, 2);
+
+        // Empty line round-trips
+        state.lines[0].clear();
+        state.cursor_col = 0;
+        state.toggle_block_comment();
+        state.toggle_block_comment();
+        assert_eq!(state.lines[0], "");
+        assert_eq!(state.cursor_col, 0);
+    }
+
     // ========================================================================
     // Cursor movement tests
     // ========================================================================
@@ -2119,4 +2515,345 @@ mod tests {
         let textarea = TextArea::new().content_lines(lines);
         assert!(textarea.content_lines.is_some());
     }
+
+    #[test]
+    fn test_textarea_scrollbar_drag_reaches_end() {
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let text: Vec<String> = (0..50).map(|i| format!("line {i}")).collect();
+        let mut state = TextAreaState::new(text.join("\n"));
+        let mut terminal = Terminal::new(TestBackend::new(30, 12)).unwrap();
+
+        let mut render = |state: &mut TextAreaState, show: bool| {
+            let mut result = None;
+            terminal
+                .draw(|f| {
+                    result = Some(TextArea::new().show_scrollbar(show).render_stateful(
+                        f,
+                        f.area(),
+                        state,
+                    ))
+                })
+                .unwrap();
+            result.unwrap()
+        };
+
+        assert!(render(&mut state, false).scrollbar_regions.is_empty());
+        let regions = render(&mut state, true).scrollbar_regions;
+        let thumb = regions
+            .iter()
+            .find(|r| r.data == ScrollbarPart::Thumb)
+            .unwrap()
+            .area;
+
+        let event = |kind, row| MouseEvent {
+            kind,
+            column: thumb.x,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        handle_textarea_scrollbar_mouse(
+            &mut state,
+            &regions,
+            &event(MouseEventKind::Down(MouseButton::Left), thumb.y),
+        );
+        assert!(handle_textarea_scrollbar_mouse(
+            &mut state,
+            &regions,
+            &event(MouseEventKind::Drag(MouseButton::Left), 100),
+        ));
+        // Dragging to the bottom scrolls to the last full page
+        assert_eq!(state.scroll_y, 50 - state.visible_height);
+    }
+
+    fn mouse(kind: crossterm::event::MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_handle_textarea_mouse_click_positions_cursor() {
+        let mut state = TextAreaState::new("first\nsecond line\nthird");
+        let inner = Rect::new(1, 1, 20, 5);
+        let click = |col, row| mouse(MouseEventKind::Down(MouseButton::Left), col, row);
+
+        let action = handle_textarea_mouse(&click(4, 2), &mut state, inner, 0, 0);
+        assert_eq!(action, Some(TextAreaAction::Focus));
+        assert_eq!((state.cursor_line, state.cursor_col), (1, 3));
+
+        // Past the end of the line
+        handle_textarea_mouse(&click(15, 1), &mut state, inner, 0, 0);
+        assert_eq!((state.cursor_line, state.cursor_col), (0, 5));
+
+        // Below the last line clamps to it
+        handle_textarea_mouse(&click(2, 5), &mut state, inner, 0, 0);
+        assert_eq!((state.cursor_line, state.cursor_col), (2, 1));
+
+        // Gutter clicks go to the line start; scroll offsets are applied
+        state.scroll_y = 1;
+        handle_textarea_mouse(&click(2, 1), &mut state, inner, 0, 4);
+        assert_eq!((state.cursor_line, state.cursor_col), (1, 0));
+        handle_textarea_mouse(&click(7, 1), &mut state, inner, 3, 4);
+        assert_eq!((state.cursor_line, state.cursor_col), (1, 5));
+
+        // Outside the content area
+        assert!(handle_textarea_mouse(&click(30, 1), &mut state, inner, 0, 0).is_none());
+    }
+
+    #[test]
+    fn test_handle_textarea_mouse_wide_chars() {
+        let mut state = TextAreaState::new("你好ab");
+        let inner = Rect::new(0, 0, 20, 3);
+        let click = |col| mouse(MouseEventKind::Down(MouseButton::Left), col, 0);
+
+        for (x, expected) in [(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 3), (9, 4)] {
+            handle_textarea_mouse(&click(x), &mut state, inner, 0, 0);
+            assert_eq!(state.cursor_col, expected, "x = {x}");
+        }
+
+        // Horizontally scrolled past the first character (two cells)
+        handle_textarea_mouse(&click(2), &mut state, inner, 2, 0);
+        assert_eq!(state.cursor_col, 2);
+
+        // Scroll lands inside the first character: cell 1 belongs to '你'
+        handle_textarea_mouse(&click(0), &mut state, inner, 1, 0);
+        assert_eq!(state.cursor_col, 0);
+        handle_textarea_mouse(&click(1), &mut state, inner, 1, 0);
+        assert_eq!(state.cursor_col, 1);
+    }
+
+    #[test]
+    fn test_handle_textarea_mouse_scroll_wheel() {
+        let text: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        let mut state = TextAreaState::new(text.join("\n"));
+        state.visible_height = 3;
+        let inner = Rect::new(0, 0, 10, 3);
+
+        handle_textarea_mouse(
+            &mouse(MouseEventKind::ScrollDown, 1, 1),
+            &mut state,
+            inner,
+            0,
+            0,
+        );
+        assert_eq!(state.scroll_y, 1);
+        handle_textarea_mouse(
+            &mouse(MouseEventKind::ScrollUp, 1, 1),
+            &mut state,
+            inner,
+            0,
+            0,
+        );
+        assert_eq!(state.scroll_y, 0);
+    }
+
+    #[test]
+    fn test_render_focus_ring() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let style = TextAreaStyle::default();
+        for (focused, color) in [
+            (true, style.focused_border),
+            (false, style.unfocused_border),
+        ] {
+            let mut state = TextAreaState::new("hi");
+            state.focused = focused;
+            let mut terminal = Terminal::new(TestBackend::new(8, 4)).unwrap();
+            terminal
+                .draw(|f| {
+                    TextArea::new().render_stateful(f, f.area(), &mut state);
+                })
+                .unwrap();
+
+            let buf = terminal.backend().buffer();
+            assert_eq!(buf[(0, 0)].symbol(), "┌");
+            assert_eq!(buf[(0, 0)].fg, color);
+            assert_eq!(buf[(7, 3)].fg, color);
+        }
+
+        // An explicit override still wins
+        let mut state = TextAreaState::new("hi");
+        state.focused = true;
+        let mut terminal = Terminal::new(TestBackend::new(8, 4)).unwrap();
+        terminal
+            .draw(|f| {
+                TextArea::new()
+                    .border_color(Color::Red)
+                    .render_stateful(f, f.area(), &mut state);
+            })
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(0, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_render_to_buffer() {
+        let mut state = TextAreaState::new("one\ntwo\nthree");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+        let area = Rect::new(1, 1, 10, 4);
+
+        let render = TextArea::new().render_to_buffer(area, &mut buf, &mut state);
+
+        assert_eq!(render.click_region.area, area);
+        assert_eq!(state.visible_height, 2);
+        let row = |y: u16| -> String { (0..12).map(|x| buf[(x, y)].symbol()).collect() };
+        assert_eq!(row(1), " ┌────────┐ ");
+        assert_eq!(row(2), " │one     │ ");
+        assert_eq!(row(3), " │two     │ ");
+        assert_eq!(row(4), " └────────┘ ");
+    }
+
+    fn render_plain(
+        state: &mut TextAreaState,
+        width: u16,
+        style: TextAreaStyle,
+    ) -> (Buffer, TextAreaRender) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 3));
+        let render = TextArea::new()
+            .with_border(false)
+            .style(style)
+            .render_to_buffer(buf.area, &mut buf, state);
+        (buf, render)
+    }
+
+    #[test]
+    fn test_block_cursor_covers_wide_char() {
+        let mut state = TextAreaState::new("a你好b");
+        state.focused = true;
+        state.cursor_col = 2;
+        let style = TextAreaStyle::default();
+        let (buf, _) = render_plain(&mut state, 10, style.clone());
+
+        assert_eq!(buf[(3, 0)].symbol(), "好");
+        assert_eq!(buf[(3, 0)].bg, style.text_fg);
+        assert_eq!(buf[(4, 0)].bg, style.text_fg);
+        assert_ne!(buf[(2, 0)].bg, style.text_fg);
+        assert_ne!(buf[(5, 0)].bg, style.text_fg);
+
+        // Past the end of the line the cursor is a single cell
+        state.cursor_col = 4;
+        let (buf, _) = render_plain(&mut state, 10, style.clone());
+        assert_eq!(buf[(6, 0)].bg, style.text_fg);
+        assert_ne!(buf[(7, 0)].bg, style.text_fg);
+    }
+
+    #[test]
+    fn test_terminal_cursor_counts_display_cells() {
+        let mut state = TextAreaState::new("ab😀c");
+        state.focused = true;
+        state.cursor_col = 3;
+        let style = TextAreaStyle::default()
+            .cursor_mode(CursorMode::Terminal)
+            .show_line_numbers(true);
+        let (_, render) = render_plain(&mut state, 12, style);
+        // Three-cell line number gutter, then a, b, and the two-cell emoji
+        assert_eq!(render.cursor_position, Some((3 + 4, 0)));
+    }
+
+    #[test]
+    fn test_wide_char_never_straddles_right_edge() {
+        let mut state = TextAreaState::new("abcd你");
+        state.focused = true;
+        state.cursor_col = 4;
+        let style = TextAreaStyle::default().cursor_mode(CursorMode::Terminal);
+        let (buf, render) = render_plain(&mut state, 5, style);
+
+        assert_eq!(buf[(3, 0)].symbol(), "d");
+        assert_eq!(buf[(4, 0)].symbol(), " ");
+        assert_eq!(render.cursor_position, None);
+    }
+
+    #[test]
+    fn test_soft_wrap_by_display_cells() {
+        let mut state = TextAreaState::new("你好世界");
+        state.focused = true;
+        state.cursor_col = 2;
+        assert_eq!(state.visual_line_count(5), 2);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let render = TextArea::new()
+            .with_border(false)
+            .wrap_mode(WrapMode::Soft)
+            .style(TextAreaStyle::default().cursor_mode(CursorMode::Terminal))
+            .render_to_buffer(buf.area, &mut buf, &mut state);
+
+        assert_eq!(buf[(0, 0)].symbol(), "你");
+        assert_eq!(buf[(2, 0)].symbol(), "好");
+        assert_eq!(buf[(0, 1)].symbol(), "世");
+        assert_eq!(render.cursor_position, Some((0, 1)));
+    }
+
+    #[test]
+    fn test_apply_snapshot_clamps_to_text() {
+        let mut state = TextAreaState::new("first line\nsecond\nthird");
+        state.cursor_line = 2;
+        state.cursor_col = 4;
+        state.scroll_y = 1;
+        let snapshot = state.snapshot();
+
+        state.set_text("one\nab");
+        state.apply_snapshot(&snapshot);
+        assert_eq!((state.cursor_line, state.cursor_col), (1, 2));
+        assert_eq!(state.scroll_y, 1);
+    }
+
+    #[test]
+    fn test_dirty_tracking() {
+        let mut state = TextAreaState::new("one\ntwo");
+        assert!(!state.is_dirty());
+        state.insert_newline();
+        assert!(state.is_dirty());
+
+        state.mark_clean();
+        assert!(!state.is_dirty());
+        state.set_text("fresh");
+        assert!(!state.is_dirty());
+        state.clear();
+        assert!(state.is_dirty());
+    }
+
+    #[test]
+    fn test_horizontal_scroll_in_display_cells() {
+        let mut state = TextAreaState::new("你好ab");
+        state.focused = true;
+        state.cursor_col = 2;
+        state.scroll_x = 2;
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let render = TextArea::new()
+            .with_border(false)
+            .style(TextAreaStyle::default().cursor_mode(CursorMode::Terminal))
+            .render_to_buffer(buf.area, &mut buf, &mut state);
+
+        // Two cells scrolled: '你' is gone, '好' starts at column 0
+        assert_eq!(buf[(0, 0)].symbol(), "好");
+        assert_eq!(buf[(2, 0)].symbol(), "a");
+        // Cursor on 'a' sits two cells past the scroll offset
+        assert_eq!(render.cursor_position, Some((2, 0)));
+
+        // Scrolling into the middle of '好' pads its right half
+        state.scroll_x = 3;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let render = TextArea::new()
+            .with_border(false)
+            .style(TextAreaStyle::default().cursor_mode(CursorMode::Terminal))
+            .render_to_buffer(buf.area, &mut buf, &mut state);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+        assert_eq!(buf[(1, 0)].symbol(), "a");
+        assert_eq!(render.cursor_position, Some((1, 0)));
+    }
+
+    #[test]
+    fn test_apply_snapshot_clamps_scroll_to_cursor_cell() {
+        let mut state = TextAreaState::new("你好ab");
+        let mut snapshot = state.snapshot();
+        snapshot.cursor_col = 2;
+        snapshot.scroll_x = 10;
+        state.apply_snapshot(&snapshot);
+        assert_eq!(state.scroll_x, 4);
+    }
 }
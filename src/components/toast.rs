@@ -23,14 +23,42 @@
 //! // In your event loop, periodically clear expired toasts
 //! state.clear_if_expired();
 //! ```
+//!
+//! # Attention cues
+//!
+//! A [`ToastPulse`] alternates a toast's border (or background) between its
+//! normal style and an accent for the first few ticks after it appears, and
+//! a [`ScreenFlash`] briefly restyles the edges of the screen. Both are
+//! driven by the tick the app passes in, so they advance only as fast as
+//! the app's event loop:
+//!
+//! ```rust
+//! use ratatui_interact::components::{Toast, ToastPulse, ToastState, ToastStyle};
+//!
+//! let mut state = ToastState::new();
+//! state.set_tick(100);
+//! state.show("Build failed", 5000);
+//!
+//! // Later, each frame
+//! state.set_tick(103);
+//! let pulse = ToastPulse::new(12, 2);
+//! if let Some(message) = state.get_message() {
+//!     let toast = Toast::new(message)
+//!         .style(ToastStyle::Error)
+//!         .pulse(pulse, state.age_ticks());
+//!     // render toast...
+//! }
+//! ```
 
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
 };
 
+use crate::state::{OverlayLayer, OverlayManager};
+
 /// Style variants for toast notifications
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ToastStyle {
@@ -82,6 +110,213 @@ impl ToastStyle {
     }
 }
 
+/// Part of a toast restyled by a [`ToastPulse`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastPulseTarget {
+    /// The border
+    #[default]
+    Border,
+    /// The whole toast, border and message
+    Background,
+}
+
+/// Attention animation for a newly shown toast
+///
+/// For the first [`ticks`](Self::ticks) ticks after the toast appears, its
+/// [`target`](Self::target) alternates between the normal style and
+/// [`accent`](Self::accent) every [`interval`](Self::interval) ticks,
+/// starting with the accent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToastPulse {
+    /// Ticks after the toast appears during which it pulses
+    pub ticks: u64,
+    /// Ticks between switching styles
+    pub interval: u64,
+    /// Style patched onto the target while accented
+    pub accent: Style,
+    /// Part of the toast that pulses
+    pub target: ToastPulseTarget,
+}
+
+impl Default for ToastPulse {
+    fn default() -> Self {
+        Self::new(12, 2)
+    }
+}
+
+impl ToastPulse {
+    /// Pulse the border for `ticks` ticks, switching every `interval` ticks
+    pub fn new(ticks: u64, interval: u64) -> Self {
+        Self {
+            ticks,
+            interval,
+            accent: Style::default().add_modifier(Modifier::REVERSED),
+            target: ToastPulseTarget::Border,
+        }
+    }
+
+    /// Set the accent style
+    pub fn accent(mut self, accent: Style) -> Self {
+        self.accent = accent;
+        self
+    }
+
+    /// Set the part of the toast that pulses
+    pub fn target(mut self, target: ToastPulseTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Whether the accent is shown `age` ticks after the toast appeared
+    pub fn is_accent(&self, age: u64) -> bool {
+        age < self.ticks && (age / self.interval.max(1)) % 2 == 0
+    }
+}
+
+/// Which toasts pulse, by severity
+///
+/// No severity pulses by default; [`enabled`](Self::enabled) turns all
+/// pulses off at once without losing the per-severity settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToastAttention {
+    /// Whether any toast pulses
+    pub enabled: bool,
+    /// Pulse for [`ToastStyle::Info`] toasts
+    pub info: Option<ToastPulse>,
+    /// Pulse for [`ToastStyle::Success`] toasts
+    pub success: Option<ToastPulse>,
+    /// Pulse for [`ToastStyle::Warning`] toasts
+    pub warning: Option<ToastPulse>,
+    /// Pulse for [`ToastStyle::Error`] toasts
+    pub error: Option<ToastPulse>,
+}
+
+impl Default for ToastAttention {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            info: None,
+            success: None,
+            warning: None,
+            error: None,
+        }
+    }
+}
+
+impl ToastAttention {
+    /// No pulses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pulse error toasts with the default [`ToastPulse`]
+    pub fn errors() -> Self {
+        Self::new().with_pulse(ToastStyle::Error, Some(ToastPulse::default()))
+    }
+
+    /// Set the pulse for one severity, or `None` for no pulse
+    pub fn with_pulse(mut self, style: ToastStyle, pulse: Option<ToastPulse>) -> Self {
+        *match style {
+            ToastStyle::Info => &mut self.info,
+            ToastStyle::Success => &mut self.success,
+            ToastStyle::Warning => &mut self.warning,
+            ToastStyle::Error => &mut self.error,
+        } = pulse;
+        self
+    }
+
+    /// Turn all pulses on or off
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// The pulse for toasts of `style`, if enabled
+    pub fn pulse_for(&self, style: ToastStyle) -> Option<ToastPulse> {
+        if !self.enabled {
+            return None;
+        }
+        match style {
+            ToastStyle::Info => self.info,
+            ToastStyle::Success => self.success,
+            ToastStyle::Warning => self.warning,
+            ToastStyle::Error => self.error,
+        }
+    }
+}
+
+/// One-shot flash of the outermost rows and columns of the screen
+///
+/// [`trigger`](Self::trigger) it alongside an error toast and call
+/// [`render`](Self::render) last each frame; it restyles the edge cells
+/// for [`frames`](Self::frames) ticks and then does nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenFlash {
+    /// Ticks the flash lasts
+    pub frames: u64,
+    /// Style patched onto the edge cells
+    pub style: Style,
+    /// Tick at which the flash was triggered
+    started_tick: Option<u64>,
+}
+
+impl Default for ScreenFlash {
+    fn default() -> Self {
+        Self {
+            frames: 2,
+            style: Style::default().bg(Color::Red),
+            started_tick: None,
+        }
+    }
+}
+
+impl ScreenFlash {
+    /// A red flash lasting 2 ticks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how many ticks the flash lasts
+    pub fn with_frames(mut self, frames: u64) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Set the style patched onto the edge cells
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Start the flash at the caller's `tick`
+    pub fn trigger(&mut self, tick: u64) {
+        self.started_tick = Some(tick);
+    }
+
+    /// Whether the flash shows at `tick`
+    pub fn is_active(&self, tick: u64) -> bool {
+        self.started_tick
+            .is_some_and(|start| tick >= start && tick - start < self.frames)
+    }
+
+    /// Restyle the edges of `area` if the flash shows at `tick`
+    pub fn render(&self, area: Rect, buf: &mut Buffer, tick: u64) {
+        let area = area.intersection(buf.area);
+        if !self.is_active(tick) || area.is_empty() {
+            return;
+        }
+        let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+        for x in area.left()..area.right() {
+            buf[(x, area.top())].set_style(self.style);
+            buf[(x, bottom)].set_style(self.style);
+        }
+        for y in area.top()..area.bottom() {
+            buf[(area.left(), y)].set_style(self.style);
+            buf[(right, y)].set_style(self.style);
+        }
+    }
+}
+
 /// State for managing toast visibility and expiration
 #[derive(Debug, Clone, Default)]
 pub struct ToastState {
@@ -89,6 +324,10 @@ pub struct ToastState {
     message: Option<String>,
     /// Expiration time (epoch milliseconds)
     expires_at: Option<i64>,
+    /// Caller tick, set with [`set_tick`](Self::set_tick)
+    tick: u64,
+    /// Caller tick at which the current message was shown
+    shown_tick: u64,
 }
 
 impl ToastState {
@@ -102,6 +341,17 @@ impl ToastState {
         let now = Self::current_time_ms();
         self.message = Some(message.into());
         self.expires_at = Some(now + duration_ms);
+        self.shown_tick = self.tick;
+    }
+
+    /// Set the caller's tick counter, which drives [`ToastPulse`]s
+    pub fn set_tick(&mut self, tick: u64) {
+        self.tick = tick;
+    }
+
+    /// Ticks since the current message was shown
+    pub fn age_ticks(&self) -> u64 {
+        self.tick.saturating_sub(self.shown_tick)
     }
 
     /// Get the current message if the toast hasn't expired
@@ -157,6 +407,7 @@ pub struct Toast<'a> {
     max_width: u16,
     max_height: u16,
     top_offset: u16,
+    pulse: Option<(ToastPulse, u64)>,
 }
 
 impl<'a> Toast<'a> {
@@ -169,9 +420,18 @@ impl<'a> Toast<'a> {
             max_width: 80,
             max_height: 8,
             top_offset: 3,
+            pulse: None,
         }
     }
 
+    /// Pulse the toast, shown `age` ticks ago
+    ///
+    /// See [`ToastState::age_ticks`] and [`ToastPulse`].
+    pub fn pulse(mut self, pulse: ToastPulse, age: u64) -> Self {
+        self.pulse = Some((pulse, age));
+        self
+    }
+
     /// Set the toast style
     ///
     /// This disables auto-style detection.
@@ -206,7 +466,8 @@ impl<'a> Toast<'a> {
     }
 
     /// Calculate the toast area centered within the given area
-    pub fn calculate_area(&self, area: Rect) -> Rect {
+    pub fn calculate_area(&self, area: impl Into<Rect>) -> Rect {
+        let area = area.into();
         // Calculate toast dimensions
         let max_content_width = (area.width as usize)
             .saturating_sub(8)
@@ -232,7 +493,7 @@ impl<'a> Toast<'a> {
     /// Render the toast, clearing the area behind it
     ///
     /// This is the preferred method as it ensures the toast appears on top.
-    pub fn render_with_clear(self, area: Rect, buf: &mut Buffer) {
+    pub fn render_with_clear(self, area: impl Into<Rect>, buf: &mut Buffer) {
         let toast_area = self.calculate_area(area);
 
         // Clear the area behind the toast
@@ -242,6 +503,17 @@ impl<'a> Toast<'a> {
         self.render_in_area(toast_area, buf);
     }
 
+    /// Render the toast into an [`OverlayManager`] on the
+    /// [`OverlayLayer::Toast`] layer
+    ///
+    /// Positions the toast like [`render_with_clear`](Self::render_with_clear).
+    pub fn render_into_overlays(self, area: impl Into<Rect>, overlays: &mut OverlayManager<'_>) {
+        let area = area.into();
+        overlays.capture(OverlayLayer::Toast, area, |buf| {
+            self.render_with_clear(area, buf)
+        });
+    }
+
     /// Render the toast in a specific pre-calculated area
     fn render_in_area(self, area: Rect, buf: &mut Buffer) {
         let border_color = if self.auto_style {
@@ -250,10 +522,21 @@ impl<'a> Toast<'a> {
             self.style.border_color()
         };
 
+        let mut border_style = Style::default().fg(border_color);
+        let mut block_style = Style::default().bg(Color::Black);
+        if let Some((pulse, age)) = self.pulse
+            && pulse.is_accent(age)
+        {
+            match pulse.target {
+                ToastPulseTarget::Border => border_style = border_style.patch(pulse.accent),
+                ToastPulseTarget::Background => block_style = block_style.patch(pulse.accent),
+            }
+        }
+
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(border_color))
-            .style(Style::default().bg(Color::Black));
+            .border_style(border_style)
+            .style(block_style);
 
         let paragraph = Paragraph::new(self.message)
             .block(block)
@@ -467,4 +750,97 @@ mod tests {
         toast.render(area, &mut buf);
         // Should not panic
     }
+
+    #[test]
+    fn test_pulse_sequence() {
+        let pulse = ToastPulse::new(7, 2);
+        let accents: Vec<bool> = (0..9).map(|age| pulse.is_accent(age)).collect();
+        assert_eq!(
+            accents,
+            vec![true, true, false, false, true, true, false, false, false]
+        );
+        // A zero interval switches every tick
+        assert!(!ToastPulse::new(4, 0).is_accent(1));
+    }
+
+    #[test]
+    fn test_attention_per_severity() {
+        let attention = ToastAttention::errors();
+        assert!(attention.pulse_for(ToastStyle::Error).is_some());
+        assert!(attention.pulse_for(ToastStyle::Info).is_none());
+        assert!(
+            attention
+                .enabled(false)
+                .pulse_for(ToastStyle::Error)
+                .is_none()
+        );
+
+        let warning = ToastPulse::new(4, 1);
+        let attention = ToastAttention::new().with_pulse(ToastStyle::Warning, Some(warning));
+        assert_eq!(attention.pulse_for(ToastStyle::Warning), Some(warning));
+    }
+
+    #[test]
+    fn test_toast_state_age_ticks() {
+        let mut state = ToastState::new();
+        state.set_tick(10);
+        state.show("Failed", 100_000);
+        state.set_tick(13);
+        assert_eq!(state.age_ticks(), 3);
+        state.show("Failed again", 100_000);
+        assert_eq!(state.age_ticks(), 0);
+    }
+
+    #[test]
+    fn test_toast_render_pulse_toggles_border() {
+        let area = Rect::new(0, 0, 30, 3);
+        let border_at = |age, target| {
+            let pulse = ToastPulse::new(4, 1).target(target);
+            let mut buf = Buffer::empty(area);
+            Toast::new("Error")
+                .style(ToastStyle::Error)
+                .pulse(pulse, age)
+                .render(area, &mut buf);
+            (
+                buf[(0, 0)].modifier.contains(Modifier::REVERSED),
+                buf[(2, 1)].modifier.contains(Modifier::REVERSED),
+            )
+        };
+
+        let border = ToastPulseTarget::Border;
+        let borders: Vec<_> = (0..5).map(|age| border_at(age, border)).collect();
+        assert_eq!(
+            borders,
+            vec![
+                (true, false),
+                (false, false),
+                (true, false),
+                (false, false),
+                (false, false),
+            ]
+        );
+        assert_eq!(border_at(0, ToastPulseTarget::Background), (true, true));
+    }
+
+    #[test]
+    fn test_screen_flash() {
+        let area = Rect::new(0, 0, 6, 4);
+        let mut flash = ScreenFlash::new().with_frames(2);
+        assert!(!flash.is_active(0));
+
+        flash.trigger(5);
+        let flashed: Vec<bool> = (4..8).map(|tick| flash.is_active(tick)).collect();
+        assert_eq!(flashed, vec![false, true, true, false]);
+
+        let mut buf = Buffer::empty(area);
+        flash.render(area, &mut buf, 6);
+        assert_eq!(buf[(0, 0)].bg, Color::Red);
+        assert_eq!(buf[(5, 2)].bg, Color::Red);
+        assert_eq!(buf[(3, 3)].bg, Color::Red);
+        assert_eq!(buf[(2, 1)].bg, Color::Reset);
+
+        let mut buf = Buffer::empty(area);
+        flash.render(area, &mut buf, 7);
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+    }
 }
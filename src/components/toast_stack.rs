@@ -6,7 +6,8 @@ use ratatui::{
     widgets::{Clear, Widget},
 };
 
-use super::toast::{Toast, ToastStyle};
+use super::toast::{Toast, ToastAttention, ToastStyle};
+use crate::state::{OverlayLayer, OverlayManager};
 
 /// Identifier for a toast in a stack.
 pub type ToastId = u64;
@@ -42,6 +43,8 @@ pub struct ToastItem {
     pub created_at_ms: i64,
     pub expires_at_ms: Option<i64>,
     pub dismiss_policy: ToastDismissPolicy,
+    /// Caller tick at which the toast was pushed, see [`ToastStackState::set_tick`].
+    pub shown_tick: u64,
 }
 
 /// Placement of a toast stack within a render area.
@@ -104,6 +107,7 @@ pub struct ToastStackState {
     items: VecDeque<ToastItem>,
     next_id: ToastId,
     capacity: usize,
+    tick: u64,
 }
 
 impl Default for ToastStackState {
@@ -118,9 +122,26 @@ impl ToastStackState {
             items: VecDeque::new(),
             next_id: 1,
             capacity: 5,
+            tick: 0,
         }
     }
 
+    /// Set the caller's tick counter.
+    ///
+    /// Pushed toasts record it as their `shown_tick`, and attention pulses
+    /// (see [`ToastStack::attention`]) are timed from it.
+    pub fn set_tick(&mut self, tick: u64) {
+        self.tick = tick;
+    }
+
+    /// Ticks since the toast with `id` was pushed.
+    pub fn age_ticks(&self, id: ToastId) -> Option<u64> {
+        self.items
+            .iter()
+            .find(|t| t.id == id)
+            .map(|t| self.tick.saturating_sub(t.shown_tick))
+    }
+
     /// Set maximum number of toasts retained in the stack.
     ///
     /// When pushing beyond capacity, the oldest toasts are evicted.
@@ -180,6 +201,7 @@ impl ToastStackState {
             expires_at_ms: dismiss_policy.expires_at_ms(now),
             dismiss_policy,
             message: msg,
+            shown_tick: self.tick,
         };
 
         self.items.push_back(item);
@@ -187,7 +209,8 @@ impl ToastStackState {
         id
     }
 
-    /// Push a pre-built item. If `item.id == 0`, an id will be allocated.
+    /// Push a pre-built item. If `item.id == 0`, an id will be allocated, and if
+    /// `item.shown_tick == 0` it is set to the current tick.
     pub fn push(&mut self, mut item: ToastItem) -> ToastId {
         let now = current_time_ms();
         if item.id == 0 {
@@ -196,6 +219,9 @@ impl ToastStackState {
         if item.created_at_ms == 0 {
             item.created_at_ms = now;
         }
+        if item.shown_tick == 0 {
+            item.shown_tick = self.tick;
+        }
         if item.expires_at_ms.is_none() {
             item.expires_at_ms = item.dismiss_policy.expires_at_ms(item.created_at_ms);
         }
@@ -253,6 +279,7 @@ impl ToastStackState {
 pub struct ToastStack<'a> {
     pub state: &'a ToastStackState,
     pub layout: ToastStackLayout,
+    pub attention: ToastAttention,
 }
 
 impl<'a> ToastStack<'a> {
@@ -260,6 +287,7 @@ impl<'a> ToastStack<'a> {
         Self {
             state,
             layout: ToastStackLayout::default(),
+            attention: ToastAttention::default(),
         }
     }
 
@@ -268,13 +296,19 @@ impl<'a> ToastStack<'a> {
         self
     }
 
+    /// Pulse newly pushed toasts by severity, timed by [`ToastStackState::set_tick`].
+    pub fn attention(mut self, attention: ToastAttention) -> Self {
+        self.attention = attention;
+        self
+    }
+
     /// Compute rectangles for visible toasts using the current layout.
-    pub fn compute_rects(&self, area: Rect) -> Vec<(ToastId, Rect)> {
-        compute_toast_rects(area, self.state, self.layout)
+    pub fn compute_rects(&self, area: impl Into<Rect>) -> Vec<(ToastId, Rect)> {
+        compute_toast_rects(area.into(), self.state, self.layout)
     }
 
     /// Hit-test a cell position within the given area.
-    pub fn hit_test(&self, area: Rect, x: u16, y: u16) -> Option<ToastId> {
+    pub fn hit_test(&self, area: impl Into<Rect>, x: u16, y: u16) -> Option<ToastId> {
         self.compute_rects(area)
             .into_iter()
             .find(|(_, r)| {
@@ -286,12 +320,22 @@ impl<'a> ToastStack<'a> {
             .map(|(id, _)| id)
     }
 
-    pub fn render_with_clear(self, area: Rect, buf: &mut Buffer) {
+    pub fn render_with_clear(self, area: impl Into<Rect>, buf: &mut Buffer) {
+        let area = area.into();
         for (_, rect) in self.compute_rects(area) {
             Clear.render(rect, buf);
         }
         self.render(area, buf);
     }
+
+    /// Render the stack into an [`OverlayManager`] on the
+    /// [`OverlayLayer::Toast`] layer.
+    pub fn render_into_overlays(self, area: impl Into<Rect>, overlays: &mut OverlayManager<'_>) {
+        let area = area.into();
+        overlays.capture(OverlayLayer::Toast, area, |buf| {
+            self.render_with_clear(area, buf)
+        });
+    }
 }
 
 impl Widget for ToastStack<'_> {
@@ -310,6 +354,16 @@ impl Widget for ToastStack<'_> {
                     toast.style(item.style)
                 };
 
+                let severity = if item.auto_style {
+                    ToastStyle::from_message(&item.message)
+                } else {
+                    item.style
+                };
+                if let Some(pulse) = self.attention.pulse_for(severity) {
+                    let age = self.state.tick.saturating_sub(item.shown_tick);
+                    toast = toast.pulse(pulse, age);
+                }
+
                 toast.render(rect, buf);
             }
         }
@@ -477,4 +531,79 @@ mod tests {
         );
         assert_eq!(stack.hit_test(area, r.x.saturating_sub(1), r.y), None);
     }
+
+    #[test]
+    fn layout_bottom_placement_in_safe_area() {
+        use crate::state::SafeArea;
+
+        let mut s = ToastStackState::new();
+        s.set_capacity(10);
+        s.push_manual("A");
+        s.push_manual("B");
+
+        let stack = ToastStack::new(&s).layout(ToastStackLayout {
+            placement: ToastPlacement::BottomRight,
+            ..Default::default()
+        });
+        let screen = Rect::new(0, 0, 100, 40);
+        let safe = SafeArea::new(screen).reserve_bottom(3);
+
+        assert_eq!(
+            stack.compute_rects(SafeArea::new(screen)),
+            stack.compute_rects(screen)
+        );
+        let rects = stack.compute_rects(safe);
+        assert_eq!(rects.len(), 2);
+        for (_, r) in rects {
+            assert!(r.bottom() <= safe.area().bottom(), "{r:?}");
+        }
+    }
+
+    #[test]
+    fn attention_pulses_by_severity() {
+        use super::super::toast::ToastPulse;
+        use ratatui::style::Modifier;
+
+        let mut s = ToastStackState::new();
+        s.set_tick(20);
+        let error = s.push_manual("Error: disk full");
+        let info = s.push_manual("Hello");
+        s.set_tick(21);
+        assert_eq!(s.age_ticks(error), Some(1));
+
+        let area = Rect::new(0, 0, 100, 40);
+        let pulsed = |s: &ToastStackState, attention| {
+            let stack = ToastStack::new(s).attention(attention);
+            let rects = stack.compute_rects(area);
+            let mut buf = Buffer::empty(area);
+            stack.render(area, &mut buf);
+            [error, info].map(|id| {
+                let r = rects.iter().find(|(i, _)| *i == id).unwrap().1;
+                buf[(r.x, r.y)].modifier.contains(Modifier::REVERSED)
+            })
+        };
+
+        let attention =
+            ToastAttention::errors().with_pulse(ToastStyle::Error, Some(ToastPulse::new(4, 2)));
+        let sequence: Vec<_> = (20..26)
+            .map(|tick| {
+                s.set_tick(tick);
+                pulsed(&s, attention)
+            })
+            .collect();
+        assert_eq!(
+            sequence,
+            vec![
+                [true, false],
+                [true, false],
+                [false, false],
+                [false, false],
+                [false, false],
+                [false, false],
+            ]
+        );
+
+        s.set_tick(20);
+        assert_eq!(pulsed(&s, attention.enabled(false)), [false, false]);
+    }
 }
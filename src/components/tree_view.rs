@@ -33,16 +33,30 @@
 //!     });
 //! ```
 
-use std::collections::HashSet;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
 
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
+    symbols::scrollbar,
     text::{Line, Span},
-    widgets::{Paragraph, Widget, Wrap},
+    widgets::{Paragraph, ScrollbarOrientation, Widget},
 };
 
+use super::interactive_scrollbar::{InteractiveScrollbar, ScrollbarDragState, ScrollbarPart};
+use super::spinner::load_status_spans;
+use crate::capabilities::{ASCII_VERTICAL_SCROLLBAR, Capabilities};
+use crate::state::{
+    Loadable, LoadableAction, PageMove, PageSize, handle_loadable_retry_key, sync_selection,
+};
+use crate::traits::ClickRegionRegistry;
+use crate::utils::display::truncate_to_width;
+
 /// A node in the tree
 #[derive(Debug, Clone)]
 pub struct TreeNode<T> {
@@ -79,10 +93,122 @@ impl<T> TreeNode<T> {
     pub fn has_children(&self) -> bool {
         !self.children.is_empty()
     }
+
+    /// Comparator ordering siblings by ID, for [`TreeViewState::set_sort`]
+    pub const COMPARE_ID: fn(&Self, &Self) -> Ordering = |a, b| a.id.cmp(&b.id);
+
+    /// Comparator ordering siblings by ID, ignoring case
+    pub const COMPARE_ID_CASE_INSENSITIVE: fn(&Self, &Self) -> Ordering = |a, b| {
+        a.id.chars()
+            .flat_map(char::to_lowercase)
+            .cmp(b.id.chars().flat_map(char::to_lowercase))
+    };
 }
 
-/// State for the tree view widget
+/// Sibling comparator, erased so the state isn't generic over node data
+type TreeCompareFn = Arc<dyn Fn(&dyn Any, &dyn Any) -> Ordering + Send + Sync>;
+
+/// Sorted sibling order for a whole tree, keyed by parent ID
 #[derive(Debug, Clone, Default)]
+struct TreeSortOrder {
+    roots: Vec<usize>,
+    children: HashMap<String, Vec<usize>>,
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of sort order builds on this thread
+    static SORT_ORDER_BUILDS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+impl TreeSortOrder {
+    fn build<T: 'static>(nodes: &[TreeNode<T>], compare: &TreeCompareFn) -> Self {
+        #[cfg(test)]
+        SORT_ORDER_BUILDS.with(|builds| builds.set(builds.get() + 1));
+
+        let mut order = Self {
+            roots: sorted_indices(nodes, compare),
+            children: HashMap::new(),
+        };
+        let mut pending: Vec<&TreeNode<T>> = nodes.iter().collect();
+        while let Some(node) = pending.pop() {
+            if node.has_children() {
+                order
+                    .children
+                    .insert(node.id.clone(), sorted_indices(&node.children, compare));
+                pending.extend(&node.children);
+            }
+        }
+        order
+    }
+
+    fn siblings(&self, parent: Option<&str>) -> Option<&[usize]> {
+        match parent {
+            None => Some(&self.roots),
+            Some(id) => self.children.get(id).map(Vec::as_slice),
+        }
+    }
+}
+
+/// Indices of `nodes` in sorted order; the sort is stable, so equal
+/// siblings keep their insertion order
+fn sorted_indices<T: 'static>(nodes: &[TreeNode<T>], compare: &TreeCompareFn) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..nodes.len()).collect();
+    indices.sort_by(|&a, &b| compare(&nodes[a], &nodes[b]));
+    indices
+}
+
+/// Whether a node on the [`TreeClipboard`] moves or duplicates on paste
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardOp {
+    /// Move the node; it renders dimmed until pasted
+    Cut,
+    /// Duplicate the node
+    Copy,
+}
+
+/// A node held for pasting with [`TreeViewState::paste_node`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeClipboard {
+    /// ID of the cut or copied node
+    pub node_id: String,
+    /// Whether pasting moves or duplicates the node
+    pub operation: ClipboardOp,
+}
+
+/// Actions emitted by the tree view clipboard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeViewAction {
+    /// A node was cut to the clipboard
+    NodeCut(String),
+    /// A node was copied to the clipboard
+    NodeCopied(String),
+    /// Move or duplicate `source_id` as a child of `target_id`; the caller
+    /// performs the tree mutation
+    NodePasted {
+        /// Node on the clipboard
+        source_id: String,
+        /// New parent
+        target_id: String,
+        /// Whether to move or duplicate the source
+        operation: ClipboardOp,
+    },
+}
+
+/// Selection, scroll, and collapsed nodes of a [`TreeViewState`], for
+/// [`ScrollMemory`](crate::state::ScrollMemory)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TreeViewSnapshot {
+    /// Selected index in the visible list
+    pub selected_index: usize,
+    /// Scroll offset
+    pub scroll: u16,
+    /// Collapsed node IDs
+    pub collapsed: HashSet<String>,
+}
+
+/// State for the tree view widget
+#[derive(Clone, Default)]
 pub struct TreeViewState {
     /// Set of collapsed node IDs
     pub collapsed: HashSet<String>,
@@ -90,6 +216,34 @@ pub struct TreeViewState {
     pub selected_index: usize,
     /// Scroll offset
     pub scroll: u16,
+    /// Scrollbar thumb drag state
+    pub scrollbar_drag: ScrollbarDragState,
+    /// Sibling comparator set with [`set_sort`](Self::set_sort)
+    sort: Option<TreeCompareFn>,
+    /// Sibling order under `sort`, built on first use and cleared by
+    /// [`resort`](Self::resort)
+    sort_order: OnceLock<TreeSortOrder>,
+    /// Loading phase of lazily loaded children, by parent node ID
+    pub children_load: HashMap<String, Loadable<()>>,
+    /// Node held for pasting
+    pub clipboard: Option<TreeClipboard>,
+    /// How far PageUp/PageDown move the selection
+    pub page_size: PageSize,
+}
+
+impl std::fmt::Debug for TreeViewState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TreeViewState")
+            .field("collapsed", &self.collapsed)
+            .field("selected_index", &self.selected_index)
+            .field("scroll", &self.scroll)
+            .field("scrollbar_drag", &self.scrollbar_drag)
+            .field("sort", &self.sort.is_some())
+            .field("children_load", &self.children_load)
+            .field("clipboard", &self.clipboard)
+            .field("page_size", &self.page_size)
+            .finish()
+    }
 }
 
 impl TreeViewState {
@@ -98,6 +252,12 @@ impl TreeViewState {
         Self::default()
     }
 
+    /// Set how far PageUp/PageDown move the selection
+    pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
     /// Toggle the collapsed state of a node
     pub fn toggle_collapsed(&mut self, id: &str) {
         if self.collapsed.contains(id) {
@@ -122,6 +282,99 @@ impl TreeViewState {
         self.collapsed.remove(id);
     }
 
+    /// Start loading the children of node `id` at the caller's `tick`
+    pub fn set_loading(&mut self, id: &str, tick: u64) {
+        self.children_load
+            .entry(id.to_string())
+            .or_default()
+            .set_loading(tick);
+    }
+
+    /// Finish loading the children of node `id`
+    ///
+    /// Put the loaded children into the node before the next render.
+    pub fn set_loaded(&mut self, id: &str) {
+        self.children_load.remove(id);
+    }
+
+    /// Mark loading the children of node `id` as failed
+    pub fn set_failed(&mut self, id: &str, message: impl Into<String>) {
+        self.children_load
+            .entry(id.to_string())
+            .or_default()
+            .set_failed(message);
+    }
+
+    /// Loading phase of the children of node `id`, while loading or failed
+    pub fn children_load(&self, id: &str) -> Option<&Loadable<()>> {
+        self.children_load.get(id)
+    }
+
+    /// Capture the selection, scroll offset, and collapsed nodes
+    pub fn snapshot(&self) -> TreeViewSnapshot {
+        TreeViewSnapshot {
+            selected_index: self.selected_index,
+            scroll: self.scroll,
+            collapsed: self.collapsed.clone(),
+        }
+    }
+
+    /// Restore a [`snapshot`](Self::snapshot), clamped to the nodes visible
+    /// in `nodes` once its collapsed nodes are applied
+    pub fn apply_snapshot<T: std::fmt::Debug + 'static>(
+        &mut self,
+        snapshot: &TreeViewSnapshot,
+        nodes: &[TreeNode<T>],
+    ) {
+        self.collapsed = snapshot.collapsed.clone();
+        let last = TreeView::new(nodes, self).visible_count().saturating_sub(1);
+        self.selected_index = snapshot.selected_index.min(last);
+        self.scroll = snapshot
+            .scroll
+            .min(self.selected_index.min(u16::MAX as usize) as u16);
+    }
+
+    /// Put node `id` on the clipboard to be moved by the next paste
+    pub fn cut_node(&mut self, id: &str) {
+        self.clipboard = Some(TreeClipboard {
+            node_id: id.to_string(),
+            operation: ClipboardOp::Cut,
+        });
+    }
+
+    /// Put node `id` on the clipboard to be duplicated by each paste
+    pub fn copy_node(&mut self, id: &str) {
+        self.clipboard = Some(TreeClipboard {
+            node_id: id.to_string(),
+            operation: ClipboardOp::Copy,
+        });
+    }
+
+    /// Paste the clipboard node as a child of `target_id`
+    ///
+    /// Returns [`TreeViewAction::NodePasted`] for the caller to apply, or
+    /// `None` if the clipboard is empty. A cut node leaves the clipboard;
+    /// a copied node stays for further pastes.
+    pub fn paste_node(&mut self, target_id: &str) -> Option<TreeViewAction> {
+        let clipboard = self.clipboard.as_ref()?;
+        let action = TreeViewAction::NodePasted {
+            source_id: clipboard.node_id.clone(),
+            target_id: target_id.to_string(),
+            operation: clipboard.operation,
+        };
+        if clipboard.operation == ClipboardOp::Cut {
+            self.clipboard = None;
+        }
+        Some(action)
+    }
+
+    /// Check if node `id` is cut and waiting to be pasted
+    pub fn is_cut(&self, id: &str) -> bool {
+        self.clipboard
+            .as_ref()
+            .is_some_and(|c| c.operation == ClipboardOp::Cut && c.node_id == id)
+    }
+
     /// Move selection up
     pub fn select_prev(&mut self) {
         self.selected_index = self.selected_index.saturating_sub(1);
@@ -142,6 +395,121 @@ impl TreeViewState {
             self.scroll = (self.selected_index - viewport_height + 1) as u16;
         }
     }
+
+    /// Keep the selection on the same node after the tree is rebuilt
+    ///
+    /// Pass the nodes before and after the refresh. The selection follows
+    /// the selected node's ID and stays on the same viewport row when
+    /// possible; if the node is gone it falls back to the nearest visible
+    /// row. See [`sync_selection`].
+    ///
+    /// When sorting is on, the new tree is re-sorted.
+    pub fn sync_nodes<T: std::fmt::Debug + 'static>(
+        &mut self,
+        old_nodes: &[TreeNode<T>],
+        new_nodes: &[TreeNode<T>],
+    ) {
+        let old_ids = visible_ids(old_nodes, self);
+        self.sort_order = OnceLock::new();
+        let new_ids = visible_ids(new_nodes, self);
+        self.follow_selection(&old_ids, &new_ids);
+    }
+
+    /// Sort siblings with `compare` when flattening the tree
+    ///
+    /// The sort is stable, so siblings that compare equal keep their
+    /// insertion order. The order is computed once and cached until
+    /// [`resort`](Self::resort) or [`sync_nodes`](Self::sync_nodes); the
+    /// selection stays on the same node. A comparator for a different node
+    /// type than the rendered tree leaves the order unchanged.
+    ///
+    /// ```rust
+    /// use ratatui_interact::components::{TreeNode, TreeViewState};
+    ///
+    /// let nodes = vec![TreeNode::new("b", ()), TreeNode::new("A", ())];
+    /// let mut state = TreeViewState::new();
+    /// state.set_sort(&nodes, TreeNode::COMPARE_ID_CASE_INSENSITIVE);
+    /// ```
+    pub fn set_sort<T: std::fmt::Debug + 'static>(
+        &mut self,
+        nodes: &[TreeNode<T>],
+        compare: impl Fn(&TreeNode<T>, &TreeNode<T>) -> Ordering + Send + Sync + 'static,
+    ) {
+        let compare: TreeCompareFn =
+            Arc::new(
+                move |a: &dyn Any, b: &dyn Any| match (a.downcast_ref(), b.downcast_ref()) {
+                    (Some(a), Some(b)) => compare(a, b),
+                    _ => Ordering::Equal,
+                },
+            );
+        self.reorder(nodes, Some(compare));
+    }
+
+    /// Go back to insertion order, keeping the selection on the same node
+    pub fn clear_sort<T: std::fmt::Debug + 'static>(&mut self, nodes: &[TreeNode<T>]) {
+        self.reorder(nodes, None);
+    }
+
+    /// Re-sort after the nodes were modified in place
+    ///
+    /// The cached order goes stale when nodes are renamed or their data
+    /// changes; call this afterwards. Sibling lists whose length changed are
+    /// re-sorted on the fly until then. When replacing the tree, prefer
+    /// [`sync_nodes`](Self::sync_nodes) with the old and new nodes.
+    pub fn resort<T: std::fmt::Debug + 'static>(&mut self, nodes: &[TreeNode<T>]) {
+        let sort = self.sort.clone();
+        self.reorder(nodes, sort);
+    }
+
+    /// Whether a sort comparator is set
+    pub fn is_sorted(&self) -> bool {
+        self.sort.is_some()
+    }
+
+    /// Swap the comparator and rebuild the order, following the selected node
+    fn reorder<T: std::fmt::Debug + 'static>(
+        &mut self,
+        nodes: &[TreeNode<T>],
+        sort: Option<TreeCompareFn>,
+    ) {
+        let old_ids = visible_ids(nodes, self);
+        self.sort = sort;
+        self.sort_order = OnceLock::new();
+        let new_ids = visible_ids(nodes, self);
+        self.follow_selection(&old_ids, &new_ids);
+    }
+
+    fn follow_selection(&mut self, old_ids: &[String], new_ids: &[String]) {
+        let (selected, scroll) =
+            sync_selection(old_ids, new_ids, self.selected_index, self.scroll as usize);
+        self.selected_index = selected;
+        self.scroll = scroll.min(u16::MAX as usize) as u16;
+    }
+
+    /// Sibling order for the whole tree, if sorting is on
+    fn sort_order<T: 'static>(&self, nodes: &[TreeNode<T>]) -> Option<&TreeSortOrder> {
+        let compare = self.sort.as_ref()?;
+        Some(
+            self.sort_order
+                .get_or_init(|| TreeSortOrder::build(nodes, compare)),
+        )
+    }
+
+    /// Display order of `nodes`, the children of `parent` (`None` for roots)
+    fn sibling_order<T: 'static>(
+        &self,
+        nodes: &[TreeNode<T>],
+        parent: Option<&str>,
+        order: Option<&TreeSortOrder>,
+    ) -> Vec<usize> {
+        let Some(compare) = &self.sort else {
+            return (0..nodes.len()).collect();
+        };
+        match order.and_then(|order| order.siblings(parent)) {
+            Some(cached) if cached.len() == nodes.len() => cached.to_vec(),
+            _ => sorted_indices(nodes, compare),
+        }
+    }
 }
 
 /// Style configuration for tree view
@@ -171,6 +539,12 @@ pub struct TreeStyle {
     pub cursor_selected: &'static str,
     /// Selection cursor for non-selected items
     pub cursor_normal: &'static str,
+    /// Scrollbar symbols (ratatui's default set when `None`)
+    pub scrollbar_symbols: Option<scrollbar::Set<'static>>,
+    /// Style for errors from loading a node's children
+    pub error_style: Style,
+    /// Style patched onto the content of a cut node
+    pub cut_style: Style,
 }
 
 impl Default for TreeStyle {
@@ -190,6 +564,11 @@ impl Default for TreeStyle {
             connector_space: "    ",
             cursor_selected: "> ",
             cursor_normal: "  ",
+            scrollbar_symbols: None,
+            error_style: Style::default().fg(Color::Red),
+            cut_style: Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM),
         }
     }
 }
@@ -210,6 +589,9 @@ impl From<&crate::theme::Theme> for TreeStyle {
             connector_space: "    ",
             cursor_selected: "> ",
             cursor_normal: "  ",
+            scrollbar_symbols: None,
+            error_style: Style::default().fg(p.error),
+            cut_style: Style::default().fg(p.text_dim).add_modifier(Modifier::DIM),
         }
     }
 }
@@ -225,6 +607,42 @@ impl TreeStyle {
             ..Default::default()
         }
     }
+
+    /// Create a style using only ASCII characters, for terminals without
+    /// box-drawing glyphs
+    pub fn ascii() -> Self {
+        Self {
+            collapsed_icon: "+ ",
+            expanded_icon: "- ",
+            connector_branch: "|-- ",
+            connector_last: "`-- ",
+            connector_vertical: "|   ",
+            connector_space: "    ",
+            scrollbar_symbols: Some(ASCII_VERTICAL_SCROLLBAR),
+            ..Default::default()
+        }
+    }
+
+    /// The default style, or [`ascii`](Self::ascii) without Unicode support
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        if caps.unicode {
+            Self::default()
+        } else {
+            Self::ascii()
+        }
+    }
+
+    /// Create a lighter style with an indentation guide at every level
+    /// instead of branch connectors
+    pub fn guides() -> Self {
+        Self {
+            connector_branch: "│ ",
+            connector_last: "│ ",
+            connector_vertical: "│ ",
+            connector_space: "│ ",
+            ..Default::default()
+        }
+    }
 }
 
 /// Flattened node info for rendering
@@ -248,7 +666,10 @@ where
     nodes: &'a [TreeNode<T>],
     state: &'a TreeViewState,
     style: TreeStyle,
+    show_scrollbar: bool,
     render_fn: F,
+    tick: u64,
+    loading_message: &'a str,
 }
 
 impl<'a, T> TreeView<'a, T, fn(&TreeNode<T>, bool) -> String> {
@@ -261,13 +682,17 @@ impl<'a, T> TreeView<'a, T, fn(&TreeNode<T>, bool) -> String> {
             nodes,
             state,
             style: TreeStyle::default(),
+            show_scrollbar: false,
             render_fn: |node, _| format!("{:?}", node.id),
+            tick: 0,
+            loading_message: "Loading...",
         }
     }
 }
 
 impl<'a, T, F> TreeView<'a, T, F>
 where
+    T: 'static,
     F: Fn(&TreeNode<T>, bool) -> String,
 {
     /// Set the render function for items
@@ -279,7 +704,10 @@ where
             nodes: self.nodes,
             state: self.state,
             style: self.style,
+            show_scrollbar: self.show_scrollbar,
             render_fn,
+            tick: self.tick,
+            loading_message: self.loading_message,
         }
     }
 
@@ -294,23 +722,90 @@ where
         self.style(TreeStyle::from(theme))
     }
 
+    /// Show a scrollbar on the right edge when the visible nodes overflow
+    pub fn show_scrollbar(mut self, show: bool) -> Self {
+        self.show_scrollbar = show;
+        self
+    }
+
+    /// Set the caller's tick counter, which animates loading spinners
+    pub fn tick(mut self, tick: u64) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Set the message shown beside the spinner of nodes loading children
+    pub fn loading_message(mut self, message: &'a str) -> Self {
+        self.loading_message = message;
+        self
+    }
+
+    /// Render and register the scrollbar parts for click handling
+    ///
+    /// Pass the registry to [`handle_tree_view_scrollbar_mouse`].
+    pub fn render_with_registry(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: &mut ClickRegionRegistry<ScrollbarPart>,
+    ) {
+        registry.clear();
+        self.render_impl(area, buf, Some(registry));
+    }
+
+    fn render_impl(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        registry: Option<&mut ClickRegionRegistry<ScrollbarPart>>,
+    ) {
+        let total = self.visible_count();
+        let has_scrollbar = self.show_scrollbar && total > area.height as usize;
+        // Leave the scrollbar column free
+        let content_width = area.width.saturating_sub(has_scrollbar as u16);
+
+        let lines = self.build_lines(area, content_width);
+        let paragraph = Paragraph::new(lines);
+        paragraph.render(area, buf);
+
+        if has_scrollbar {
+            let mut scrollbar = tree_view_scrollbar(self.state, total);
+            if let Some(symbols) = &self.style.scrollbar_symbols {
+                scrollbar = scrollbar.symbols(symbols.clone());
+            }
+            match registry {
+                Some(registry) => scrollbar.render_with_registry(area, buf, registry),
+                None => scrollbar.render(area, buf),
+            }
+        }
+    }
+
     /// Flatten the tree into a list of visible nodes
     fn flatten_visible(&self) -> Vec<FlatNode<'a, T>> {
         let mut result = Vec::new();
-        self.flatten_nodes(self.nodes, 0, &mut result, &[]);
+        let order = self.state.sort_order(self.nodes);
+        self.flatten_nodes(self.nodes, None, order, 0, &mut result, &[]);
         result
     }
 
     fn flatten_nodes(
         &self,
         nodes: &'a [TreeNode<T>],
+        parent: Option<&str>,
+        order: Option<&TreeSortOrder>,
         depth: usize,
         result: &mut Vec<FlatNode<'a, T>>,
         parent_is_last: &[bool],
     ) {
         let count = nodes.len();
-        for (idx, node) in nodes.iter().enumerate() {
-            let is_last = idx == count - 1;
+        for (pos, idx) in self
+            .state
+            .sibling_order(nodes, parent, order)
+            .into_iter()
+            .enumerate()
+        {
+            let node = &nodes[idx];
+            let is_last = pos == count - 1;
             result.push(FlatNode {
                 node,
                 depth,
@@ -322,7 +817,14 @@ where
             if node.has_children() && !self.state.is_collapsed(&node.id) {
                 let mut new_parent_is_last = parent_is_last.to_vec();
                 new_parent_is_last.push(is_last);
-                self.flatten_nodes(&node.children, depth + 1, result, &new_parent_is_last);
+                self.flatten_nodes(
+                    &node.children,
+                    Some(&node.id),
+                    order,
+                    depth + 1,
+                    result,
+                    &new_parent_is_last,
+                );
             }
         }
     }
@@ -332,8 +834,9 @@ where
         self.flatten_visible().len()
     }
 
-    /// Build the lines for rendering
-    fn build_lines(&self, area: Rect) -> Vec<Line<'static>> {
+    /// Build the lines for rendering, truncating node content to fit after
+    /// the cursor, connectors, and icon
+    fn build_lines(&self, area: Rect, width: u16) -> Vec<Line<'static>> {
         let visible = self.flatten_visible();
         let mut lines = Vec::new();
 
@@ -390,8 +893,10 @@ where
                 ));
             }
 
-            // Expand/collapse icon (if has children)
-            if flat_node.node.has_children() {
+            let children_load = self.state.children_load(&flat_node.node.id);
+
+            // Expand/collapse icon (if has children, or they are loading)
+            if flat_node.node.has_children() || children_load.is_some() {
                 let icon = if self.state.is_collapsed(&flat_node.node.id) {
                     self.style.collapsed_icon
                 } else {
@@ -401,15 +906,34 @@ where
             }
 
             // Node content
-            let content = (self.render_fn)(flat_node.node, is_selected);
-            spans.push(Span::styled(
-                content,
-                if is_selected {
-                    self.style.selected_style
-                } else {
-                    self.style.normal_style
-                },
-            ));
+            let prefix_width: usize = spans.iter().map(|span| span.width()).sum();
+            let content = truncate_to_width(
+                &(self.render_fn)(flat_node.node, is_selected),
+                (width as usize).saturating_sub(prefix_width),
+            );
+            let mut content_style = if is_selected {
+                self.style.selected_style
+            } else {
+                self.style.normal_style
+            };
+            if self.state.is_cut(&flat_node.node.id) {
+                content_style = content_style.patch(self.style.cut_style);
+            }
+            spans.push(Span::styled(content, content_style));
+
+            // Spinner or load error after the content
+            if let Some(status) = children_load.and_then(|load| {
+                load_status_spans(
+                    load,
+                    self.tick,
+                    self.loading_message,
+                    self.style.connector_style,
+                    self.style.error_style,
+                )
+            }) {
+                spans.push(Span::raw("  "));
+                spans.extend(status);
+            }
 
             lines.push(Line::from(spans));
         }
@@ -420,17 +944,159 @@ where
 
 impl<'a, T, F> Widget for TreeView<'a, T, F>
 where
+    T: 'static,
     F: Fn(&TreeNode<T>, bool) -> String,
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let lines = self.build_lines(area);
-        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
-        paragraph.render(area, buf);
+        self.render_impl(area, buf, None);
+    }
+}
+
+fn tree_view_scrollbar(state: &TreeViewState, total_visible: usize) -> InteractiveScrollbar {
+    InteractiveScrollbar::new(
+        ScrollbarOrientation::VerticalRight,
+        total_visible,
+        state.scroll as usize,
+    )
+}
+
+/// Handle mouse input on the tree view scrollbar
+///
+/// Clicking the arrows scrolls one row, clicking the track pages, and
+/// dragging the thumb scrolls proportionally. The selection is left
+/// unchanged. `registry` must come from [`TreeView::render_with_registry`].
+///
+/// Returns `true` if the event was consumed.
+pub fn handle_tree_view_scrollbar_mouse<T: std::fmt::Debug + 'static>(
+    nodes: &[TreeNode<T>],
+    state: &mut TreeViewState,
+    registry: &ClickRegionRegistry<ScrollbarPart>,
+    mouse: &MouseEvent,
+) -> bool {
+    let total_visible = TreeView::new(nodes, state).visible_count();
+    let was_dragging = state.scrollbar_drag.is_dragging();
+    let scrollbar = tree_view_scrollbar(state, total_visible);
+    match scrollbar.handle_mouse(registry.regions(), &mut state.scrollbar_drag, mouse) {
+        Some(position) => {
+            state.scroll = position.min(u16::MAX as usize) as u16;
+            true
+        }
+        None => was_dragging || state.scrollbar_drag.is_dragging(),
+    }
+}
+
+/// Handle the navigation keys
+///
+/// Up/Down and the [paging keys](crate::state::PageMove) move the selection
+/// through the visible nodes and keep it within a viewport of
+/// `viewport_height` rows; pages are [`TreeViewState::page_size`] rows.
+/// Expanding and collapsing are left to the app.
+///
+/// Returns `true` if the key was handled.
+pub fn handle_tree_view_nav_key<T: std::fmt::Debug + 'static>(
+    key: &KeyEvent,
+    nodes: &[TreeNode<T>],
+    state: &mut TreeViewState,
+    viewport_height: usize,
+) -> bool {
+    let total_visible = TreeView::new(nodes, state).visible_count();
+    if let Some(page_move) = PageMove::from_key(key) {
+        state.selected_index = page_move.apply(
+            state.selected_index,
+            total_visible,
+            viewport_height,
+            state.page_size,
+        );
+    } else {
+        match key.code {
+            KeyCode::Up => state.select_prev(),
+            KeyCode::Down => state.select_next(total_visible),
+            _ => return false,
+        }
+    }
+    if viewport_height > 0 {
+        state.ensure_visible(viewport_height);
+    }
+    true
+}
+
+/// Handle the retry key for the selected node's children
+///
+/// Enter or `r` on a selected node whose children failed to load returns
+/// [`LoadableAction::RetryRequested`]; reload the children of
+/// [`get_selected_id`] and call [`TreeViewState::set_loading`].
+pub fn handle_tree_view_retry_key<T: std::fmt::Debug + 'static>(
+    key: &KeyEvent,
+    nodes: &[TreeNode<T>],
+    state: &TreeViewState,
+) -> Option<LoadableAction> {
+    let id = get_selected_id(nodes, state)?;
+    handle_loadable_retry_key(key, state.children_load(&id)?)
+}
+
+/// Handle Ctrl+X, Ctrl+C, and Ctrl+V on the selected node
+///
+/// Ctrl+X and Ctrl+C put the selected node on [`TreeViewState::clipboard`].
+/// Ctrl+V pastes it as a child of the selected node, returning
+/// [`TreeViewAction::NodePasted`] for the caller to apply; pasting a cut
+/// node into itself or one of its descendants is ignored.
+pub fn handle_tree_view_clipboard_key<T: std::fmt::Debug + 'static>(
+    key: &KeyEvent,
+    nodes: &[TreeNode<T>],
+    state: &mut TreeViewState,
+) -> Option<TreeViewAction> {
+    if !key.modifiers.contains(KeyModifiers::CONTROL) {
+        return None;
+    }
+    let id = get_selected_id(nodes, state)?;
+    match key.code {
+        KeyCode::Char('x') => {
+            state.cut_node(&id);
+            Some(TreeViewAction::NodeCut(id))
+        }
+        KeyCode::Char('c') => {
+            state.copy_node(&id);
+            Some(TreeViewAction::NodeCopied(id))
+        }
+        KeyCode::Char('v') => {
+            let clipboard = state.clipboard.as_ref()?;
+            if clipboard.operation == ClipboardOp::Cut
+                && find_node(nodes, &clipboard.node_id)
+                    .is_some_and(|source| find_node(std::slice::from_ref(source), &id).is_some())
+            {
+                return None;
+            }
+            state.paste_node(&id)
+        }
+        _ => None,
     }
 }
 
+/// The node with `id` in `nodes` or their descendants
+fn find_node<'a, T>(nodes: &'a [TreeNode<T>], id: &str) -> Option<&'a TreeNode<T>> {
+    nodes.iter().find_map(|node| {
+        if node.id == id {
+            Some(node)
+        } else {
+            find_node(&node.children, id)
+        }
+    })
+}
+
+/// IDs of the visible nodes, in display order
+fn visible_ids<T: std::fmt::Debug + 'static>(
+    nodes: &[TreeNode<T>],
+    state: &TreeViewState,
+) -> Vec<String> {
+    TreeView::new(nodes, state)
+        .flatten_visible()
+        .into_iter()
+        .map(|f| f.node.id.clone())
+        .collect()
+}
+
 /// Get the selected node ID from a tree view state and nodes
-pub fn get_selected_id<T: std::fmt::Debug>(
+pub fn get_selected_id<T: std::fmt::Debug + 'static>(
     nodes: &[TreeNode<T>],
     state: &TreeViewState,
 ) -> Option<String> {
@@ -803,4 +1469,416 @@ mod tests {
         assert_eq!(tree.visible_count(), 0);
         assert!(tree.flatten_visible().is_empty());
     }
+
+    fn render_rows(style: TreeStyle, width: u16) -> Vec<String> {
+        let item = |name: &str| TestItem { name: name.into() };
+        let nodes = vec![TreeNode::new("a", item("a")).with_children(vec![
+            TreeNode::new("b", item("b")).with_children(vec![
+                TreeNode::new("c", item("c")).with_children(vec![TreeNode::new("d", item("d"))]),
+                TreeNode::new("e", item("e")),
+            ]),
+            TreeNode::new("f", item("f with a long name")),
+        ])];
+        let state = TreeViewState::new();
+        let area = Rect::new(0, 0, width, 7);
+        let mut buf = Buffer::empty(area);
+        TreeView::new(&nodes, &state)
+            .style(style)
+            .render_item(|node, _| node.data.name.clone())
+            .render(area, &mut buf);
+        (0..area.height)
+            .map(|y| {
+                let row: String = (0..width).map(|x| buf[(x, y)].symbol()).collect();
+                row.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_render_connectors_snapshot() {
+        assert_eq!(
+            render_rows(TreeStyle::default(), 40),
+            [
+                "> ▼ a",
+                "      ├── ▼ b",
+                "      │   ├── ▼ c",
+                "      │   │   └── d",
+                "      │   └── e",
+                "      └── f with a long name",
+                "",
+            ]
+        );
+        assert_eq!(
+            render_rows(TreeStyle::ascii(), 40),
+            [
+                "> - a",
+                "      |-- - b",
+                "      |   |-- - c",
+                "      |   |   `-- d",
+                "      |   `-- e",
+                "      `-- f with a long name",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_guides_snapshot() {
+        assert_eq!(
+            render_rows(TreeStyle::guides(), 40),
+            [
+                "> ▼ a",
+                "  │ │ ▼ b",
+                "  │ │ │ ▼ c",
+                "  │ │ │ │ d",
+                "  │ │ │ e",
+                "  │ │ f with a long name",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_truncates_after_connectors() {
+        let rows = render_rows(TreeStyle::default(), 20);
+        assert_eq!(rows[5], "      └── f with ...");
+        // Nothing wraps onto extra rows
+        assert_eq!(rows[6], "");
+    }
+
+    #[test]
+    fn test_sync_nodes_keeps_selection() {
+        let old = create_test_tree();
+        let mut state = TreeViewState::new();
+        state.selected_index = 2; // "1.2"
+
+        let mut new = create_test_tree();
+        new.insert(
+            0,
+            TreeNode::new(
+                "0",
+                TestItem {
+                    name: "Root 0".into(),
+                },
+            ),
+        );
+        state.sync_nodes(&old, &new);
+        assert_eq!(get_selected_id(&new, &state), Some("1.2".into()));
+
+        // Collapsing hides the selected node, which falls back to the nearest row
+        let old = new;
+        state.collapse("1");
+        let new = old.clone();
+        state.sync_nodes(&old, &new);
+        assert_eq!(get_selected_id(&new, &state), Some("2".into()));
+    }
+
+    fn visible(nodes: &[TreeNode<TestItem>], state: &TreeViewState) -> Vec<String> {
+        visible_ids(nodes, state)
+    }
+
+    fn sort_test_tree() -> Vec<TreeNode<TestItem>> {
+        let item = |name: &str| TestItem { name: name.into() };
+        vec![
+            TreeNode::new("readme", item("file")),
+            TreeNode::new("src", item("dir")).with_children(vec![
+                TreeNode::new("src/main.rs", item("file")),
+                TreeNode::new("src/Lib.rs", item("file")),
+            ]),
+            TreeNode::new("Cargo.toml", item("file")),
+            TreeNode::new("docs", item("dir")),
+        ]
+    }
+
+    #[test]
+    fn test_sort_dirs_first_case_insensitive() {
+        let nodes = sort_test_tree();
+        let mut state = TreeViewState::new();
+        state.set_sort(&nodes, |a: &TreeNode<TestItem>, b: &TreeNode<TestItem>| {
+            (b.data.name == "dir")
+                .cmp(&(a.data.name == "dir"))
+                .then_with(|| TreeNode::COMPARE_ID_CASE_INSENSITIVE(a, b))
+        });
+        assert!(state.is_sorted());
+        assert_eq!(
+            visible(&nodes, &state),
+            [
+                "docs",
+                "src",
+                "src/Lib.rs",
+                "src/main.rs",
+                "Cargo.toml",
+                "readme"
+            ]
+        );
+
+        state.clear_sort(&nodes);
+        assert_eq!(visible(&nodes, &state)[0], "readme");
+    }
+
+    #[test]
+    fn test_sort_is_stable() {
+        let nodes = sort_test_tree();
+        let mut state = TreeViewState::new();
+        // Directories first, everything else keeps insertion order
+        state.set_sort(&nodes, |a: &TreeNode<TestItem>, b: &TreeNode<TestItem>| {
+            (b.data.name == "dir").cmp(&(a.data.name == "dir"))
+        });
+        assert_eq!(
+            visible(&nodes, &state),
+            [
+                "src",
+                "src/main.rs",
+                "src/Lib.rs",
+                "docs",
+                "readme",
+                "Cargo.toml"
+            ]
+        );
+
+        state.set_sort(&nodes, TreeNode::COMPARE_ID);
+        assert_eq!(visible(&nodes, &state)[..2], ["Cargo.toml", "docs"]);
+    }
+
+    #[test]
+    fn test_sort_keeps_selection_and_collapse_by_id() {
+        let nodes = sort_test_tree();
+        let mut state = TreeViewState::new();
+        state.collapse("src");
+        state.selected_index = 2; // "Cargo.toml"
+
+        state.set_sort(&nodes, TreeNode::COMPARE_ID_CASE_INSENSITIVE);
+        assert_eq!(get_selected_id(&nodes, &state), Some("Cargo.toml".into()));
+        assert_eq!(
+            visible(&nodes, &state),
+            ["Cargo.toml", "docs", "readme", "src"]
+        );
+        assert!(state.is_collapsed("src"));
+    }
+
+    #[test]
+    fn test_sort_order_is_cached_until_resort() {
+        let builds = || SORT_ORDER_BUILDS.with(|builds| builds.get());
+        let mut nodes = sort_test_tree();
+        let mut state = TreeViewState::new();
+        state.set_sort(&nodes, TreeNode::COMPARE_ID_CASE_INSENSITIVE);
+
+        let before = builds();
+        for _ in 0..3 {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+            TreeView::new(&nodes, &state).render(Rect::new(0, 0, 30, 10), &mut buf);
+        }
+        assert_eq!(builds(), before);
+
+        nodes[0].id = "Alpha".into();
+        state.resort(&nodes);
+        assert_eq!(visible(&nodes, &state)[0], "Alpha");
+        assert_eq!(builds(), before + 1);
+
+        // A new sibling is sorted in even before resort
+        nodes.push(TreeNode::new("build", TestItem { name: "dir".into() }));
+        assert_eq!(
+            visible(&nodes, &state)[..3],
+            ["Alpha", "build", "Cargo.toml"]
+        );
+    }
+
+    #[test]
+    fn test_ascii_render_with_scrollbar() {
+        let nodes: Vec<_> = (0..10)
+            .map(|i| {
+                TreeNode::new(
+                    i.to_string(),
+                    TestItem {
+                        name: "node".into(),
+                    },
+                )
+                .with_children(vec![TreeNode::new(
+                    format!("{i}.1"),
+                    TestItem {
+                        name: "leaf".into(),
+                    },
+                )])
+            })
+            .collect();
+        let mut state = TreeViewState::new();
+        state.expand("0");
+        let area = Rect::new(0, 0, 20, 4);
+        let mut buf = Buffer::empty(area);
+        TreeView::new(&nodes, &state)
+            .style(TreeStyle::from_capabilities(&Capabilities::basic()))
+            .show_scrollbar(true)
+            .render_item(|node, _| node.data.name.clone())
+            .render(area, &mut buf);
+
+        assert!(buf.content().iter().all(|c| c.symbol().is_ascii()));
+        let column: String = (0..4).map(|y| buf[(19, y)].symbol()).collect();
+        assert_eq!(column, "^#|v");
+    }
+
+    #[test]
+    fn test_children_loading_status() {
+        let nodes = vec![
+            TreeNode::new(
+                "remote",
+                TestItem {
+                    name: "remote".into(),
+                },
+            ),
+            TreeNode::new(
+                "local",
+                TestItem {
+                    name: "local".into(),
+                },
+            ),
+        ];
+        let mut state = TreeViewState::new();
+        let area = Rect::new(0, 0, 48, 2);
+        let render = |state: &TreeViewState| -> Vec<String> {
+            let mut buf = Buffer::empty(area);
+            TreeView::new(&nodes, state)
+                .render_item(|node, _| node.data.name.clone())
+                .tick(1)
+                .render(area, &mut buf);
+            (0..area.height)
+                .map(|y| {
+                    (0..area.width)
+                        .map(|x| buf[(x, y)].symbol())
+                        .collect::<String>()
+                })
+                .map(|row| row.trim_end().to_string())
+                .collect()
+        };
+
+        state.set_loading("remote", 0);
+        assert_eq!(render(&state)[0], "> ▼ remote  ⠙ Loading...");
+        assert_eq!(render(&state)[1], "  local");
+
+        state.set_failed("remote", "permission denied");
+        assert_eq!(
+            render(&state)[0],
+            "> ▼ remote  ✗ permission denied [r] Retry"
+        );
+        let r = KeyEvent::from(KeyCode::Char('r'));
+        assert_eq!(
+            handle_tree_view_retry_key(&r, &nodes, &state),
+            Some(LoadableAction::RetryRequested)
+        );
+        state.selected_index = 1;
+        assert_eq!(handle_tree_view_retry_key(&r, &nodes, &state), None);
+
+        state.set_loaded("remote");
+        assert_eq!(render(&state)[0], "  remote");
+    }
+
+    #[test]
+    fn test_clipboard_cut_copy_paste() {
+        let nodes = create_test_tree();
+        let mut state = TreeViewState::new();
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        assert_eq!(state.paste_node("2"), None);
+
+        // Cut "1" and try to paste it into its own child
+        assert_eq!(
+            handle_tree_view_clipboard_key(&ctrl('x'), &nodes, &mut state),
+            Some(TreeViewAction::NodeCut("1".into()))
+        );
+        assert!(state.is_cut("1"));
+        state.selected_index = 1;
+        assert_eq!(
+            handle_tree_view_clipboard_key(&ctrl('v'), &nodes, &mut state),
+            None
+        );
+
+        state.selected_index = 3;
+        assert_eq!(
+            handle_tree_view_clipboard_key(&ctrl('v'), &nodes, &mut state),
+            Some(TreeViewAction::NodePasted {
+                source_id: "1".into(),
+                target_id: "2".into(),
+                operation: ClipboardOp::Cut,
+            })
+        );
+        assert_eq!(state.clipboard, None);
+
+        // A copied node stays on the clipboard
+        state.copy_node("1.2");
+        assert!(!state.is_cut("1.2"));
+        for _ in 0..2 {
+            assert!(matches!(
+                state.paste_node("2"),
+                Some(TreeViewAction::NodePasted {
+                    operation: ClipboardOp::Copy,
+                    ..
+                })
+            ));
+        }
+        assert_eq!(
+            handle_tree_view_clipboard_key(&KeyEvent::from(KeyCode::Char('v')), &nodes, &mut state),
+            None
+        );
+    }
+
+    #[test]
+    fn test_render_cut_node_dimmed() {
+        let nodes = create_test_tree();
+        let mut state = TreeViewState::new();
+        state.cut_node("2");
+        let area = Rect::new(0, 0, 30, 4);
+        let mut buf = Buffer::empty(area);
+        TreeView::new(&nodes, &state)
+            .render_item(|node, _| node.data.name.clone())
+            .render(area, &mut buf);
+
+        let dim = |y: u16| buf[(4, y)].modifier.contains(Modifier::DIM);
+        assert!(dim(3));
+        assert!(!dim(0));
+        assert_eq!(buf[(4, 3)].fg, TreeStyle::default().cut_style.fg.unwrap());
+    }
+
+    #[test]
+    fn test_apply_snapshot_clamps_to_visible_nodes() {
+        let nodes = create_test_tree();
+        let mut state = TreeViewState::new();
+        state.selected_index = 3;
+        state.collapse("1");
+        let snapshot = state.snapshot();
+        assert!(snapshot.collapsed.contains("1"));
+
+        // Restoring the collapsed set leaves two visible nodes
+        let mut restored = TreeViewState::new();
+        restored.apply_snapshot(&snapshot, &nodes);
+        assert!(restored.is_collapsed("1"));
+        assert_eq!(restored.selected_index, 1);
+    }
+
+    #[test]
+    fn test_nav_keys_at_boundaries() {
+        // Four visible nodes in a two-row viewport
+        let nodes = create_test_tree();
+        let mut state = TreeViewState::new();
+        let press = |state: &mut TreeViewState, code| {
+            handle_tree_view_nav_key(&KeyEvent::from(code), &nodes, state, 2)
+        };
+
+        assert!(press(&mut state, KeyCode::PageUp));
+        assert_eq!((state.selected_index, state.scroll), (0, 0));
+        assert!(press(&mut state, KeyCode::PageDown));
+        assert_eq!((state.selected_index, state.scroll), (2, 1));
+        press(&mut state, KeyCode::PageDown);
+        press(&mut state, KeyCode::PageDown);
+        assert_eq!((state.selected_index, state.scroll), (3, 2));
+
+        press(&mut state, KeyCode::Home);
+        assert_eq!((state.selected_index, state.scroll), (0, 0));
+        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        handle_tree_view_nav_key(&ctrl_d, &nodes, &mut state, 2);
+        assert_eq!(state.selected_index, 1);
+
+        // Collapsing shrinks the range End reaches
+        state.collapse("1");
+        press(&mut state, KeyCode::End);
+        assert_eq!(state.selected_index, 1);
+        assert!(!press(&mut state, KeyCode::Enter));
+    }
 }
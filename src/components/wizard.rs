@@ -0,0 +1,776 @@
+//! Wizard component - Multi-page setup flows
+//!
+//! A page-by-page flow with a [`StepDisplay`] progress header, a
+//! [`ButtonGroup`] Back/Next/Finish footer, validation gating, and cancel
+//! confirmation. The wizard renders into any
+//! area, so it works fullscreen or as the content of a [`PopupDialog`].
+//!
+//! [`PopupDialog`]: crate::components::PopupDialog
+//! [`StepDisplay`]: crate::components::StepDisplay
+//! [`ButtonGroup`]: crate::components::ButtonGroup
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{Wizard, WizardAction, WizardPage, WizardState};
+//! use ratatui::widgets::Paragraph;
+//!
+//! #[derive(Default)]
+//! struct Setup {
+//!     name: String,
+//!     accepted: bool,
+//! }
+//!
+//! let wizard = Wizard::new()
+//!     .page(
+//!         WizardPage::new("name", "Name", |frame, area, data: &mut Setup| {
+//!             frame.render_widget(Paragraph::new(data.name.as_str()), area);
+//!         })
+//!         .validate(|data| !data.name.is_empty()),
+//!     )
+//!     .page(WizardPage::new("license", "License", |frame, area, _| {
+//!         frame.render_widget(Paragraph::new("Accept the license?"), area);
+//!     }))
+//!     .dirty_when(|data| !data.name.is_empty());
+//!
+//! let mut state = WizardState::new(Setup::default());
+//!
+//! // Next is gated by validation
+//! assert!(!wizard.can_advance(&state));
+//! state.data.name = "demo".into();
+//! assert!(wizard.can_advance(&state));
+//! assert!(wizard.next(&mut state).is_action());
+//! assert_eq!(state.current_page, 1);
+//! ```
+
+use std::cmp::Ordering;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::Paragraph,
+};
+
+use crate::components::button::{ButtonGroup, ButtonGroupState, ButtonStyle};
+use crate::components::step_display::{
+    Step, StepDisplay, StepDisplayLayout, StepDisplayState, StepDisplayStyle, StepStatus,
+};
+use crate::traits::{ClickRegionRegistry, EventResult};
+
+/// Renders the content of a wizard page.
+pub type WizardRenderFn<S> = Box<dyn Fn(&mut Frame, Rect, &mut S)>;
+
+/// Checks whether the collected state allows leaving a page forward.
+pub type WizardValidateFn<S> = Box<dyn Fn(&S) -> bool>;
+
+/// Hook invoked when leaving a page.
+pub type WizardLeaveFn<S> = Box<dyn Fn(&mut S)>;
+
+/// Actions emitted by the wizard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WizardAction {
+    /// The current page changed.
+    PageChanged {
+        /// Page that was left
+        from: usize,
+        /// Page that is now shown
+        to: usize,
+    },
+    /// Finish was activated on the last page. Read the collected state from
+    /// [`WizardState::data`].
+    Finished,
+    /// The wizard was cancelled (after confirmation if dirty).
+    Cancelled,
+}
+
+/// Focusable footer targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WizardFocus {
+    /// The page content (keys are forwarded to the application)
+    #[default]
+    Content,
+    /// The Back button
+    Back,
+    /// The Next / Finish button
+    Next,
+    /// The Cancel button
+    Cancel,
+}
+
+/// State for a wizard.
+#[derive(Debug, Clone)]
+pub struct WizardState<S> {
+    /// Collected page state.
+    pub data: S,
+    /// Index of the page currently shown.
+    pub current_page: usize,
+    /// Currently focused target.
+    pub focus: WizardFocus,
+    /// Whether the cancel confirmation prompt is showing.
+    pub confirming_cancel: bool,
+    /// Click regions for footer buttons.
+    pub click_regions: ClickRegionRegistry<WizardFocus>,
+}
+
+impl<S: Default> Default for WizardState<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S> WizardState<S> {
+    /// Create a new wizard state on the first page.
+    pub fn new(data: S) -> Self {
+        Self {
+            data,
+            current_page: 0,
+            focus: WizardFocus::Content,
+            confirming_cancel: false,
+            click_regions: ClickRegionRegistry::new(),
+        }
+    }
+
+    /// Check if the first page is shown.
+    pub fn is_first_page(&self) -> bool {
+        self.current_page == 0
+    }
+
+    /// Check if the page content has focus.
+    pub fn is_content_focused(&self) -> bool {
+        self.focus == WizardFocus::Content
+    }
+
+    /// Consume the state and return the collected data.
+    pub fn into_data(self) -> S {
+        self.data
+    }
+}
+
+/// A single wizard page.
+pub struct WizardPage<S> {
+    /// Unique page identifier.
+    pub id: String,
+    /// Title shown in the progress header.
+    pub title: String,
+    render: WizardRenderFn<S>,
+    validate: Option<WizardValidateFn<S>>,
+    on_leave: Option<WizardLeaveFn<S>>,
+}
+
+impl<S> WizardPage<S> {
+    /// Create a page with an id, header title, and render closure.
+    pub fn new(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        render: impl Fn(&mut Frame, Rect, &mut S) + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            render: Box::new(render),
+            validate: None,
+            on_leave: None,
+        }
+    }
+
+    /// Gate the Next/Finish button on a validation closure.
+    pub fn validate(mut self, validate: impl Fn(&S) -> bool + 'static) -> Self {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Run a hook whenever the page is left (Back, Next, or Finish).
+    pub fn on_leave(mut self, on_leave: impl Fn(&mut S) + 'static) -> Self {
+        self.on_leave = Some(Box::new(on_leave));
+        self
+    }
+
+    /// Check whether this page's validation passes.
+    pub fn is_valid(&self, data: &S) -> bool {
+        self.validate.as_ref().is_none_or(|validate| validate(data))
+    }
+}
+
+/// Footer buttons, left to right.
+const FOOTER_BUTTONS: [WizardFocus; 3] =
+    [WizardFocus::Cancel, WizardFocus::Back, WizardFocus::Next];
+
+/// Style for the wizard.
+#[derive(Debug, Clone)]
+pub struct WizardStyle {
+    /// Style for the [`StepDisplay`] progress header
+    pub steps: StepDisplayStyle,
+    /// Style for the [`ButtonGroup`] footer buttons
+    pub button_style: ButtonStyle,
+    /// Style for the cancel confirmation prompt
+    pub confirm_style: Style,
+    /// Back button label
+    pub back_label: &'static str,
+    /// Next button label
+    pub next_label: &'static str,
+    /// Finish button label (last page)
+    pub finish_label: &'static str,
+    /// Cancel button label
+    pub cancel_label: &'static str,
+    /// Cancel confirmation prompt
+    pub confirm_message: &'static str,
+}
+
+impl Default for WizardStyle {
+    fn default() -> Self {
+        Self {
+            steps: StepDisplayStyle::default(),
+            button_style: ButtonStyle::default(),
+            confirm_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            back_label: "Back",
+            next_label: "Next",
+            finish_label: "Finish",
+            cancel_label: "Cancel",
+            confirm_message: "Discard changes? (y/n)",
+        }
+    }
+}
+
+impl From<&crate::theme::Theme> for WizardStyle {
+    fn from(theme: &crate::theme::Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            steps: StepDisplayStyle::from(theme),
+            button_style: ButtonStyle::from(theme),
+            confirm_style: Style::default().fg(p.warning).add_modifier(Modifier::BOLD),
+            ..Default::default()
+        }
+    }
+}
+
+/// Multi-page wizard.
+///
+/// Holds the page definitions; all mutable progress lives in [`WizardState`].
+pub struct Wizard<S> {
+    pages: Vec<WizardPage<S>>,
+    style: WizardStyle,
+    is_dirty: Option<WizardValidateFn<S>>,
+}
+
+impl<S> Default for Wizard<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Wizard<S> {
+    /// Create an empty wizard.
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            style: WizardStyle::default(),
+            is_dirty: None,
+        }
+    }
+
+    /// Add a page.
+    pub fn page(mut self, page: WizardPage<S>) -> Self {
+        self.pages.push(page);
+        self
+    }
+
+    /// Set the style.
+    pub fn style(mut self, style: WizardStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Apply a theme to derive the style.
+    pub fn theme(self, theme: &crate::theme::Theme) -> Self {
+        self.style(WizardStyle::from(theme))
+    }
+
+    /// Ask for confirmation on Esc/Cancel while this closure returns true.
+    pub fn dirty_when(mut self, is_dirty: impl Fn(&S) -> bool + 'static) -> Self {
+        self.is_dirty = Some(Box::new(is_dirty));
+        self
+    }
+
+    /// Number of pages.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Get the page currently shown.
+    pub fn current_page<'s>(&'s self, state: &WizardState<S>) -> Option<&'s WizardPage<S>> {
+        self.pages.get(state.current_page)
+    }
+
+    /// Check if the last page is shown.
+    pub fn is_last_page(&self, state: &WizardState<S>) -> bool {
+        state.current_page + 1 >= self.pages.len()
+    }
+
+    /// Check if the current page passes validation.
+    pub fn can_advance(&self, state: &WizardState<S>) -> bool {
+        self.current_page(state)
+            .is_some_and(|page| page.is_valid(&state.data))
+    }
+
+    /// Check if the collected state has unsaved changes.
+    pub fn is_dirty(&self, state: &WizardState<S>) -> bool {
+        self.is_dirty
+            .as_ref()
+            .is_some_and(|is_dirty| is_dirty(&state.data))
+    }
+
+    fn leave_current(&self, state: &mut WizardState<S>) {
+        if let Some(on_leave) = self
+            .pages
+            .get(state.current_page)
+            .and_then(|page| page.on_leave.as_ref())
+        {
+            on_leave(&mut state.data);
+        }
+    }
+
+    /// Advance to the next page, or finish on the last page.
+    ///
+    /// Does nothing if the current page fails validation.
+    pub fn next(&self, state: &mut WizardState<S>) -> EventResult<WizardAction> {
+        if !self.can_advance(state) {
+            return EventResult::Consumed;
+        }
+
+        self.leave_current(state);
+        if self.is_last_page(state) {
+            return EventResult::Action(WizardAction::Finished);
+        }
+
+        let from = state.current_page;
+        state.current_page += 1;
+        EventResult::Action(WizardAction::PageChanged {
+            from,
+            to: state.current_page,
+        })
+    }
+
+    /// Go back to the previous page.
+    pub fn back(&self, state: &mut WizardState<S>) -> EventResult<WizardAction> {
+        if state.is_first_page() {
+            return EventResult::Consumed;
+        }
+
+        self.leave_current(state);
+        let from = state.current_page;
+        state.current_page -= 1;
+        if state.focus == WizardFocus::Back && state.is_first_page() {
+            state.focus = WizardFocus::Next;
+        }
+        EventResult::Action(WizardAction::PageChanged {
+            from,
+            to: state.current_page,
+        })
+    }
+
+    /// Cancel the wizard, asking for confirmation first if dirty.
+    pub fn cancel(&self, state: &mut WizardState<S>) -> EventResult<WizardAction> {
+        if self.is_dirty(state) {
+            state.confirming_cancel = true;
+            EventResult::Consumed
+        } else {
+            EventResult::Action(WizardAction::Cancelled)
+        }
+    }
+
+    /// Focus targets currently reachable with Tab.
+    fn focus_order(&self, state: &WizardState<S>) -> Vec<WizardFocus> {
+        let mut order = vec![WizardFocus::Content];
+        if !state.is_first_page() {
+            order.push(WizardFocus::Back);
+        }
+        if self.can_advance(state) {
+            order.push(WizardFocus::Next);
+        }
+        order.push(WizardFocus::Cancel);
+        order
+    }
+
+    fn cycle_focus(&self, state: &mut WizardState<S>, forward: bool) {
+        let order = self.focus_order(state);
+        let idx = order.iter().position(|f| *f == state.focus).unwrap_or(0);
+        let next = if forward {
+            (idx + 1) % order.len()
+        } else {
+            (idx + order.len() - 1) % order.len()
+        };
+        state.focus = order[next];
+    }
+
+    fn activate(
+        &self,
+        state: &mut WizardState<S>,
+        target: WizardFocus,
+    ) -> EventResult<WizardAction> {
+        match target {
+            WizardFocus::Content => EventResult::NotHandled,
+            WizardFocus::Back => self.back(state),
+            WizardFocus::Next => self.next(state),
+            WizardFocus::Cancel => self.cancel(state),
+        }
+    }
+
+    /// Render the wizard: progress header, current page, and footer.
+    pub fn render(&self, frame: &mut Frame, area: Rect, state: &mut WizardState<S>) {
+        state.click_regions.clear();
+        if area.width == 0 || area.height < 3 {
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        let header = self.header_state(state);
+        frame.render_widget(
+            StepDisplay::new(&header)
+                .style(self.style.steps.clone())
+                .layout(StepDisplayLayout::Horizontal),
+            chunks[0],
+        );
+
+        if let Some(page) = self.pages.get(state.current_page) {
+            (page.render)(frame, chunks[1], &mut state.data);
+        }
+
+        self.render_footer(frame, chunks[2], state);
+    }
+
+    /// Progress header state: pages before the current one are completed.
+    fn header_state(&self, state: &WizardState<S>) -> StepDisplayState {
+        let steps = self
+            .pages
+            .iter()
+            .enumerate()
+            .map(|(idx, page)| {
+                let mut step = Step::new(page.title.clone());
+                step.status = match idx.cmp(&state.current_page) {
+                    Ordering::Less => StepStatus::Completed,
+                    Ordering::Equal => StepStatus::Running,
+                    Ordering::Greater => StepStatus::Pending,
+                };
+                step
+            })
+            .collect();
+        StepDisplayState::new(steps)
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, state: &mut WizardState<S>) {
+        if state.confirming_cancel {
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    self.style.confirm_message,
+                    self.style.confirm_style,
+                )),
+                area,
+            );
+            return;
+        }
+
+        let next_label = if self.is_last_page(state) {
+            self.style.finish_label
+        } else {
+            self.style.next_label
+        };
+        let labels = [self.style.cancel_label, self.style.back_label, next_label];
+
+        let mut buttons = ButtonGroupState::new(FOOTER_BUTTONS)
+            .with_enabled(1, !state.is_first_page())
+            .with_enabled(2, self.can_advance(state));
+        buttons.focus_value(&state.focus);
+
+        let regions = ButtonGroup::new(&labels, &buttons)
+            .style(self.style.button_style.clone())
+            .alignment(Alignment::Right)
+            .render_stateful(area, frame.buffer_mut());
+        for region in regions {
+            state
+                .click_regions
+                .register(region.area, FOOTER_BUTTONS[region.data]);
+        }
+    }
+
+    /// Handle a keyboard event.
+    ///
+    /// Keys the wizard does not own return `NotHandled` so they can be
+    /// forwarded to the current page.
+    pub fn handle_key(
+        &self,
+        state: &mut WizardState<S>,
+        key: &KeyEvent,
+    ) -> EventResult<WizardAction> {
+        if state.confirming_cancel {
+            return match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    state.confirming_cancel = false;
+                    EventResult::Action(WizardAction::Cancelled)
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    state.confirming_cancel = false;
+                    EventResult::Consumed
+                }
+                // Modal while confirming
+                _ => EventResult::Consumed,
+            };
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Right if ctrl => self.next(state),
+            KeyCode::Left if ctrl => self.back(state),
+            KeyCode::Esc => self.cancel(state),
+            KeyCode::Tab if !key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.cycle_focus(state, true);
+                EventResult::Consumed
+            }
+            KeyCode::BackTab | KeyCode::Tab => {
+                self.cycle_focus(state, false);
+                EventResult::Consumed
+            }
+            KeyCode::Left if !state.is_content_focused() => {
+                self.cycle_focus(state, false);
+                if state.is_content_focused() {
+                    self.cycle_focus(state, false);
+                }
+                EventResult::Consumed
+            }
+            KeyCode::Right if !state.is_content_focused() => {
+                self.cycle_focus(state, true);
+                if state.is_content_focused() {
+                    self.cycle_focus(state, true);
+                }
+                EventResult::Consumed
+            }
+            KeyCode::Enter | KeyCode::Char(' ') if !state.is_content_focused() => {
+                self.activate(state, state.focus)
+            }
+            _ => EventResult::NotHandled,
+        }
+    }
+
+    /// Handle a mouse event on the footer buttons.
+    pub fn handle_mouse(
+        &self,
+        state: &mut WizardState<S>,
+        mouse: &MouseEvent,
+    ) -> EventResult<WizardAction> {
+        if state.confirming_cancel {
+            return EventResult::NotHandled;
+        }
+
+        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+            if let Some(&target) = state.click_regions.handle_click(mouse.column, mouse.row) {
+                state.focus = target;
+                return self.activate(state, target);
+            }
+        }
+
+        EventResult::NotHandled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct Data {
+        name: String,
+        leaves: u32,
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::CONTROL)
+    }
+
+    fn wizard() -> Wizard<Data> {
+        Wizard::new()
+            .page(
+                WizardPage::new("name", "Name", |_, _, _| {})
+                    .validate(|data: &Data| !data.name.is_empty())
+                    .on_leave(|data| data.leaves += 1),
+            )
+            .page(WizardPage::new("options", "Options", |_, _, _| {}))
+            .page(WizardPage::new("confirm", "Confirm", |_, _, _| {}))
+            .dirty_when(|data| !data.name.is_empty())
+    }
+
+    #[test]
+    fn test_validation_gates_next() {
+        let wizard = wizard();
+        let mut state = WizardState::new(Data::default());
+
+        assert!(!wizard.can_advance(&state));
+        assert_eq!(
+            wizard.handle_key(&mut state, &ctrl(KeyCode::Right)),
+            EventResult::Consumed
+        );
+        assert_eq!(state.current_page, 0);
+
+        state.data.name = "demo".into();
+        assert_eq!(
+            wizard.handle_key(&mut state, &ctrl(KeyCode::Right)),
+            EventResult::Action(WizardAction::PageChanged { from: 0, to: 1 })
+        );
+        assert_eq!(state.current_page, 1);
+        assert_eq!(state.data.leaves, 1);
+    }
+
+    #[test]
+    fn test_back_and_finish() {
+        let wizard = wizard();
+        let mut state = WizardState::new(Data {
+            name: "demo".into(),
+            leaves: 0,
+        });
+
+        // Back on first page is a no-op
+        assert_eq!(wizard.back(&mut state), EventResult::Consumed);
+
+        wizard.next(&mut state);
+        wizard.next(&mut state);
+        assert!(wizard.is_last_page(&state));
+
+        assert_eq!(
+            wizard.handle_key(&mut state, &ctrl(KeyCode::Left)),
+            EventResult::Action(WizardAction::PageChanged { from: 2, to: 1 })
+        );
+
+        wizard.next(&mut state);
+        assert_eq!(
+            wizard.next(&mut state),
+            EventResult::Action(WizardAction::Finished)
+        );
+        assert_eq!(state.into_data().name, "demo");
+    }
+
+    #[test]
+    fn test_escape_confirms_when_dirty() {
+        let wizard = wizard();
+        let mut state = WizardState::new(Data::default());
+
+        // Clean: cancel immediately
+        assert_eq!(
+            wizard.handle_key(&mut state, &key(KeyCode::Esc)),
+            EventResult::Action(WizardAction::Cancelled)
+        );
+
+        // Dirty: ask first
+        state.data.name = "demo".into();
+        assert_eq!(
+            wizard.handle_key(&mut state, &key(KeyCode::Esc)),
+            EventResult::Consumed
+        );
+        assert!(state.confirming_cancel);
+
+        // 'n' dismisses the prompt
+        wizard.handle_key(&mut state, &key(KeyCode::Char('n')));
+        assert!(!state.confirming_cancel);
+
+        wizard.handle_key(&mut state, &key(KeyCode::Esc));
+        assert_eq!(
+            wizard.handle_key(&mut state, &key(KeyCode::Char('y'))),
+            EventResult::Action(WizardAction::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_focus_cycle_skips_disabled_buttons() {
+        let wizard = wizard();
+        let mut state = WizardState::new(Data::default());
+
+        // First page, invalid: Content -> Cancel -> Content
+        wizard.handle_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(state.focus, WizardFocus::Cancel);
+        wizard.handle_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(state.focus, WizardFocus::Content);
+
+        state.data.name = "demo".into();
+        wizard.handle_key(&mut state, &key(KeyCode::Tab));
+        assert_eq!(state.focus, WizardFocus::Next);
+
+        assert_eq!(
+            wizard.handle_key(&mut state, &key(KeyCode::Enter)),
+            EventResult::Action(WizardAction::PageChanged { from: 0, to: 1 })
+        );
+    }
+
+    #[test]
+    fn test_content_keys_not_handled() {
+        let wizard = wizard();
+        let mut state = WizardState::new(Data::default());
+
+        assert_eq!(
+            wizard.handle_key(&mut state, &key(KeyCode::Char('a'))),
+            EventResult::NotHandled
+        );
+        assert_eq!(
+            wizard.handle_key(&mut state, &key(KeyCode::Enter)),
+            EventResult::NotHandled
+        );
+    }
+
+    #[test]
+    fn test_render_and_click_next() {
+        let rendered = Rc::new(Cell::new(false));
+        let flag = Rc::clone(&rendered);
+        let wizard: Wizard<Data> = Wizard::new()
+            .page(WizardPage::new("a", "First", move |_, _, _| flag.set(true)))
+            .page(WizardPage::new("b", "Second", |_, _, _| {}));
+        let mut state = WizardState::new(Data::default());
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+        terminal
+            .draw(|frame| wizard.render(frame, frame.area(), &mut state))
+            .unwrap();
+        assert!(rendered.get());
+
+        // StepDisplay header and right-aligned ButtonGroup footer
+        let buffer = terminal.backend().buffer();
+        let row = |y| (0..40).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(0).trim_end(), "[▶] First ─ [ ] Second");
+        assert_eq!(row(9), "                   Cancel   Back   Next ");
+        assert_eq!(buffer[(4, 0)].fg, StepStatus::Running.color());
+        assert_eq!(buffer[(29, 9)].fg, ButtonStyle::default().disabled_fg);
+
+        let next = state
+            .click_regions
+            .regions()
+            .iter()
+            .find(|r| r.data == WizardFocus::Next)
+            .map(|r| r.area)
+            .unwrap();
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: next.x,
+            row: next.y,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(
+            wizard.handle_mouse(&mut state, &click),
+            EventResult::Action(WizardAction::PageChanged { from: 0, to: 1 })
+        );
+    }
+}
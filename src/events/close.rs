@@ -0,0 +1,190 @@
+//! Close key routing
+//!
+//! Esc means something different to each layer of a UI: it cancels a
+//! search, closes a dropdown, dismisses a dialog, or quits the application.
+//! When layers are nested, the key must reach the innermost layer first and
+//! stop at the first layer that uses it.
+//!
+//! The key handlers in this crate follow one rule: they consume Esc only to
+//! leave an inner mode (search, copy mode, a submenu), to close a popup they
+//! own, or to close themselves. When there is nothing to close they leave
+//! the key unhandled, so it can propagate. Offer Esc to each layer in turn:
+//!
+//! 1. Inner modes of the focused component (search, copy mode, inline editors)
+//! 2. Popups (`Select` dropdowns, `ContextMenu`, `MenuBar` dropdowns)
+//! 3. Dialogs (`PopupDialog`, `HotkeyDialog`)
+//! 4. The application
+//!
+//! [`CloseRequest`] records how one layer responded. Handler results convert
+//! into it with [`From`], and [`CloseRequest::or_else`] chains the layers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use crossterm::event::{KeyCode, KeyEvent};
+//! use ratatui_interact::components::{DiffViewerState, handle_diff_viewer_key};
+//! use ratatui_interact::events::CloseRequest;
+//!
+//! let mut viewer = DiffViewerState::from_unified_diff("@@ -1 +1 @@\n-a\n+b\n");
+//! viewer.start_search();
+//! let mut quit = false;
+//!
+//! let esc = KeyEvent::from(KeyCode::Esc);
+//! for _ in 0..2 {
+//!     CloseRequest::from(handle_diff_viewer_key(&mut viewer, &esc)).or_else(|| {
+//!         quit = true;
+//!         CloseRequest::Close
+//!     });
+//! }
+//! // The first Esc cancelled the search, the second reached the app
+//! assert!(!viewer.search.active);
+//! assert!(quit);
+//! ```
+
+use crate::traits::{ContainerAction, EventResult};
+
+/// How a layer responded to a close key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseRequest {
+    /// The key left an inner mode or closed a popup; stop propagating.
+    Consumed,
+    /// The layer closed itself or asks its owner to close it; stop propagating.
+    Close,
+    /// The layer had nothing to close; offer the key to the next layer out.
+    Propagate,
+}
+
+impl CloseRequest {
+    /// Check if the key should be offered to the next layer out.
+    pub fn propagates(&self) -> bool {
+        *self == CloseRequest::Propagate
+    }
+
+    /// Check if the layer closed or asked to be closed.
+    pub fn is_close(&self) -> bool {
+        *self == CloseRequest::Close
+    }
+
+    /// Return this request if the key was used, otherwise offer it to the
+    /// next layer out by evaluating `f`.
+    pub fn or_else<F>(self, f: F) -> CloseRequest
+    where
+        F: FnOnce() -> CloseRequest,
+    {
+        match self {
+            CloseRequest::Propagate => f(),
+            other => other,
+        }
+    }
+}
+
+/// For handlers that return whether the key was handled.
+impl From<bool> for CloseRequest {
+    fn from(handled: bool) -> Self {
+        if handled {
+            CloseRequest::Consumed
+        } else {
+            CloseRequest::Propagate
+        }
+    }
+}
+
+impl From<EventResult<ContainerAction>> for CloseRequest {
+    fn from(result: EventResult<ContainerAction>) -> Self {
+        match result {
+            EventResult::NotHandled => CloseRequest::Propagate,
+            EventResult::Action(ContainerAction::Close) => CloseRequest::Close,
+            _ => CloseRequest::Consumed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{
+        DialogConfig, DialogState, DiffViewerState, PopupDialog, SelectState,
+        handle_diff_viewer_key, handle_select_key,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    #[test]
+    fn test_or_else_stops_at_first_used_layer() {
+        let mut reached = false;
+        let result = CloseRequest::Consumed.or_else(|| {
+            reached = true;
+            CloseRequest::Close
+        });
+        assert_eq!(result, CloseRequest::Consumed);
+        assert!(!reached);
+
+        assert_eq!(
+            CloseRequest::Propagate.or_else(|| CloseRequest::Close),
+            CloseRequest::Close
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(CloseRequest::from(true), CloseRequest::Consumed);
+        assert_eq!(CloseRequest::from(false), CloseRequest::Propagate);
+        assert!(CloseRequest::from(EventResult::Action(ContainerAction::Close)).is_close());
+        assert!(CloseRequest::from(EventResult::<ContainerAction>::NotHandled).propagates());
+    }
+
+    /// A dialog holding a select and a diff viewer, inside the app.
+    #[test]
+    fn test_nested_layers_close_innermost_first() {
+        struct Content {
+            viewer: DiffViewerState,
+            select: SelectState,
+        }
+
+        let config = DialogConfig::new("Diff");
+        let mut dialog_state = DialogState::new(Content {
+            viewer: DiffViewerState::from_unified_diff("@@ -1 +1 @@\n-a\n+b\n"),
+            select: SelectState::new(3),
+        });
+        dialog_state.show();
+        dialog_state.children.viewer.start_search();
+        dialog_state.children.select.open();
+
+        let mut app_quit = false;
+        let mut press_esc = |state: &mut DialogState<Content>| {
+            let key = KeyEvent::from(KeyCode::Esc);
+            let mut dialog = PopupDialog::new(&config, state, |_, _, _| {});
+            let dialog_result = dialog.handle_key_with(key, |key, content| {
+                // The open dropdown sits above the viewer
+                let result = CloseRequest::from(handle_select_key(&key, &mut content.select))
+                    .or_else(|| handle_diff_viewer_key(&mut content.viewer, &key).into());
+                if result.propagates() {
+                    EventResult::<()>::NotHandled
+                } else {
+                    EventResult::Consumed
+                }
+            });
+            CloseRequest::from(dialog_result).or_else(|| {
+                app_quit = true;
+                CloseRequest::Close
+            })
+        };
+
+        // 1: closes the select dropdown
+        assert_eq!(press_esc(&mut dialog_state), CloseRequest::Consumed);
+        assert!(!dialog_state.children.select.is_open);
+        assert!(dialog_state.children.viewer.search.active);
+
+        // 2: cancels the viewer search
+        assert_eq!(press_esc(&mut dialog_state), CloseRequest::Consumed);
+        assert!(!dialog_state.children.viewer.search.active);
+        assert!(dialog_state.is_visible());
+
+        // 3: closes the dialog
+        assert_eq!(press_esc(&mut dialog_state), CloseRequest::Close);
+        assert!(!dialog_state.is_visible());
+
+        // 4: reaches the application
+        assert_eq!(press_esc(&mut dialog_state), CloseRequest::Close);
+        assert!(app_quit);
+    }
+}
@@ -1,83 +1,92 @@
 //! Event handler helpers
 //!
 //! Utility functions for common event handling patterns.
+//!
+//! The key predicates are shorthands for [`KeyMatcher`] values; use the
+//! matchers directly to store or combine bindings.
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
+use super::KeyMatcher;
+
 /// Check if a key event is an activation key (Enter or Space).
 ///
 /// Used for activating buttons and checkboxes.
 pub fn is_activate_key(key: &KeyEvent) -> bool {
-    matches!(key.code, KeyCode::Enter | KeyCode::Char(' '))
+    KeyMatcher::activate().matches(key)
 }
 
 /// Check if a key event is a navigation key.
 ///
 /// Includes Tab, BackTab, and arrow keys.
 pub fn is_navigation_key(key: &KeyEvent) -> bool {
-    matches!(
-        key.code,
-        KeyCode::Tab
-            | KeyCode::BackTab
-            | KeyCode::Up
-            | KeyCode::Down
-            | KeyCode::Left
-            | KeyCode::Right
-    )
+    KeyMatcher::navigation().matches(key)
 }
 
 /// Check if a key event is Tab (forward navigation).
 pub fn is_tab(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Tab && !key.modifiers.contains(KeyModifiers::SHIFT)
+    KeyMatcher::tab().matches(key)
 }
 
 /// Check if a key event is Shift+Tab or BackTab (backward navigation).
 pub fn is_backtab(key: &KeyEvent) -> bool {
-    key.code == KeyCode::BackTab
-        || (key.code == KeyCode::Tab && key.modifiers.contains(KeyModifiers::SHIFT))
+    KeyMatcher::backtab().matches(key)
 }
 
 /// Check if a key event is the close/escape key.
 pub fn is_close_key(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Esc
+    KeyMatcher::key(KeyCode::Esc).any_modifiers().matches(key)
 }
 
 /// Check if a key event is Enter.
 pub fn is_enter(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Enter
+    KeyMatcher::key(KeyCode::Enter).any_modifiers().matches(key)
 }
 
 /// Check if a key event is Space.
 pub fn is_space(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Char(' ')
+    KeyMatcher::key(KeyCode::Char(' '))
+        .any_modifiers()
+        .matches(key)
 }
 
 /// Check if a key event is Backspace.
 pub fn is_backspace(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Backspace
+    KeyMatcher::key(KeyCode::Backspace)
+        .any_modifiers()
+        .matches(key)
 }
 
 /// Check if a key event is Delete.
 pub fn is_delete(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Delete
+    KeyMatcher::key(KeyCode::Delete)
+        .any_modifiers()
+        .matches(key)
 }
 
 /// Check if a key event is an arrow key.
 pub fn is_arrow_key(key: &KeyEvent) -> bool {
-    matches!(
-        key.code,
-        KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right
-    )
+    KeyMatcher::arrow().matches(key)
+}
+
+/// Check if a key event is an arrow key that moves focus forward (Down or Right).
+pub fn is_next_arrow(key: &KeyEvent) -> bool {
+    KeyMatcher::next_arrow().matches(key)
+}
+
+/// Check if a key event is an arrow key that moves focus backward (Up or Left).
+pub fn is_prev_arrow(key: &KeyEvent) -> bool {
+    KeyMatcher::prev_arrow().matches(key)
 }
 
 /// Check if a key event is Home.
 pub fn is_home(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Home
+    KeyMatcher::key(KeyCode::Home).any_modifiers().matches(key)
 }
 
 /// Check if a key event is End.
 pub fn is_end(key: &KeyEvent) -> bool {
-    key.code == KeyCode::End
+    KeyMatcher::key(KeyCode::End).any_modifiers().matches(key)
 }
 
 /// Get the character from a key event if it's a printable character.
@@ -148,27 +157,27 @@ pub fn has_shift(key: &KeyEvent) -> bool {
 
 /// Check if this is Ctrl+A (select all / move to start).
 pub fn is_ctrl_a(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Char('a') && has_ctrl(key)
+    KeyMatcher::ctrl('a').any_modifiers().matches(key)
 }
 
 /// Check if this is Ctrl+E (move to end).
 pub fn is_ctrl_e(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Char('e') && has_ctrl(key)
+    KeyMatcher::ctrl('e').any_modifiers().matches(key)
 }
 
 /// Check if this is Ctrl+U (delete to start of line).
 pub fn is_ctrl_u(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Char('u') && has_ctrl(key)
+    KeyMatcher::ctrl('u').any_modifiers().matches(key)
 }
 
 /// Check if this is Ctrl+K (delete to end of line).
 pub fn is_ctrl_k(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Char('k') && has_ctrl(key)
+    KeyMatcher::ctrl('k').any_modifiers().matches(key)
 }
 
 /// Check if this is Ctrl+W (delete word backward).
 pub fn is_ctrl_w(key: &KeyEvent) -> bool {
-    key.code == KeyCode::Char('w') && has_ctrl(key)
+    KeyMatcher::ctrl('w').any_modifiers().matches(key)
 }
 
 #[cfg(test)]
@@ -235,6 +244,21 @@ mod tests {
         assert!(!is_backtab(&make_key(KeyCode::Tab, KeyModifiers::NONE)));
     }
 
+    #[test]
+    fn test_next_prev_arrows() {
+        assert!(is_next_arrow(&make_key(KeyCode::Down, KeyModifiers::NONE)));
+        assert!(is_next_arrow(&make_key(KeyCode::Right, KeyModifiers::NONE)));
+        assert!(!is_next_arrow(&make_key(KeyCode::Up, KeyModifiers::NONE)));
+        assert!(!is_next_arrow(&make_key(
+            KeyCode::Down,
+            KeyModifiers::CONTROL
+        )));
+
+        assert!(is_prev_arrow(&make_key(KeyCode::Up, KeyModifiers::NONE)));
+        assert!(is_prev_arrow(&make_key(KeyCode::Left, KeyModifiers::NONE)));
+        assert!(!is_prev_arrow(&make_key(KeyCode::Tab, KeyModifiers::NONE)));
+    }
+
     #[test]
     fn test_get_char() {
         assert_eq!(
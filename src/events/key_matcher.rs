@@ -0,0 +1,485 @@
+//! Composable key matchers
+//!
+//! A [`KeyMatcher`] is a key binding stored as a value: one chord such as
+//! `Ctrl+Enter`, or a list of alternatives such as `Ctrl+Enter / Ctrl+Space`.
+//! Matchers parse from and display as the usual chord syntax, so the same
+//! value can come from a config file, dispatch key events, and label hint
+//! footers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use ratatui_interact::events::KeyMatcher;
+//!
+//! let submit: KeyMatcher = "ctrl+enter | ctrl+space".parse().unwrap();
+//!
+//! assert!(submit.matches(&KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL)));
+//! assert!(!submit.matches(&KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+//! assert_eq!(submit.to_string(), "Ctrl+Enter / Ctrl+Space");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Error returned when a key chord string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid key chord: {0:?}")]
+pub struct KeyMatcherParseError(pub String);
+
+/// A single key with modifiers.
+///
+/// Letters match case-insensitively, with Shift+letter matching both
+/// `Char('A')` and `Char('a')` with `SHIFT`. Shift is ignored for other
+/// characters, since it is implied by the character itself (e.g. `?`).
+/// BackTab is treated as Shift+Tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// The key
+    pub code: KeyCode,
+    /// Modifiers that must be held
+    pub modifiers: KeyModifiers,
+    /// Modifiers that may additionally be held without affecting the match
+    pub ignored: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Create a chord matching `code` with exactly `modifiers`.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let (code, modifiers) = normalize(code, modifiers);
+        Self {
+            code,
+            modifiers,
+            ignored: KeyModifiers::NONE,
+        }
+    }
+
+    /// Allow these modifiers to be held as well.
+    pub fn ignoring(mut self, modifiers: KeyModifiers) -> Self {
+        self.ignored |= modifiers;
+        self
+    }
+
+    /// Allow any modifiers beyond the required ones.
+    pub fn any_modifiers(self) -> Self {
+        self.ignoring(KeyModifiers::all())
+    }
+
+    /// Check if a key event matches this chord.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let (code, modifiers) = normalize(key.code, key.modifiers);
+        let (want_code, want_modifiers) = normalize(self.code, self.modifiers);
+        let ignored = self.ignored - want_modifiers;
+        code == want_code && modifiers - ignored == want_modifiers
+    }
+}
+
+impl From<KeyCode> for KeyChord {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
+/// Fold the ways terminals report the same chord into one form.
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::BackTab => (KeyCode::Tab, modifiers | KeyModifiers::SHIFT),
+        KeyCode::Char(c) if c.is_uppercase() => (
+            KeyCode::Char(c.to_lowercase().next().unwrap_or(c)),
+            modifiers | KeyModifiers::SHIFT,
+        ),
+        KeyCode::Char(c) if !c.is_alphabetic() && c != ' ' => {
+            (code, modifiers - KeyModifiers::SHIFT)
+        }
+        _ => (code, modifiers),
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (code, modifiers) = normalize(self.code, self.modifiers);
+        for (modifier, name) in MODIFIER_NAMES {
+            if modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        match code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) if !modifiers.is_empty() => {
+                write!(f, "{}", c.to_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => match KEY_NAMES.iter().find(|(_, c)| *c == code) {
+                Some((name, _)) => f.write_str(name),
+                None => write!(f, "{}", code),
+            },
+        }
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = KeyMatcherParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || KeyMatcherParseError(s.to_string());
+        let s = s.trim();
+
+        // A trailing "+" after a separator is the plus key itself
+        let (prefix, key) = match s.strip_suffix("++") {
+            Some(prefix) => (Some(prefix), "+"),
+            None if s == "+" => (None, "+"),
+            None => match s.rsplit_once('+') {
+                Some((prefix, key)) => (Some(prefix), key),
+                None => (None, s),
+            },
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for name in prefix.into_iter().flat_map(|p| p.split('+')) {
+            modifiers |= match name.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                "super" | "cmd" | "win" => KeyModifiers::SUPER,
+                _ => return Err(error()),
+            };
+        }
+
+        let key = key.trim();
+        let lower = key.to_ascii_lowercase();
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c.to_lowercase().next().unwrap_or(c)),
+            _ if lower == "space" => KeyCode::Char(' '),
+            _ if lower.len() > 1 && lower.starts_with('f') => {
+                KeyCode::F(lower[1..].parse().map_err(|_| error())?)
+            }
+            _ => KEY_NAMES
+                .iter()
+                .chain(KEY_ALIASES)
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, code)| *code)
+                .ok_or_else(error)?,
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+/// Modifier names in display order.
+const MODIFIER_NAMES: [(KeyModifiers, &str); 4] = [
+    (KeyModifiers::CONTROL, "Ctrl"),
+    (KeyModifiers::ALT, "Alt"),
+    (KeyModifiers::SHIFT, "Shift"),
+    (KeyModifiers::SUPER, "Super"),
+];
+
+/// Display names of named keys.
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+    ("Enter", KeyCode::Enter),
+    ("Esc", KeyCode::Esc),
+    ("Tab", KeyCode::Tab),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Insert", KeyCode::Insert),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+];
+
+/// Alternative spellings accepted when parsing.
+const KEY_ALIASES: &[(&str, KeyCode)] = &[
+    ("Return", KeyCode::Enter),
+    ("Escape", KeyCode::Esc),
+    ("BackTab", KeyCode::BackTab),
+    ("Del", KeyCode::Delete),
+    ("Ins", KeyCode::Insert),
+    ("PgUp", KeyCode::PageUp),
+    ("PgDn", KeyCode::PageDown),
+];
+
+/// A key binding: one chord or a list of alternatives.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KeyMatcher {
+    /// A single chord
+    Chord(KeyChord),
+    /// Matches if any of the chords match
+    AnyOf(Vec<KeyChord>),
+}
+
+impl KeyMatcher {
+    /// Match `code` with exactly `modifiers`.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self::Chord(KeyChord::new(code, modifiers))
+    }
+
+    /// Match `code` with no modifiers.
+    pub fn key(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    /// Match Ctrl+`c`.
+    pub fn ctrl(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    /// Match Alt+`c`.
+    pub fn alt(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    /// Match any of the given matchers.
+    pub fn any_of(matchers: impl IntoIterator<Item = KeyMatcher>) -> Self {
+        let chords: Vec<KeyChord> = matchers
+            .into_iter()
+            .flat_map(|m| m.chords().to_vec())
+            .collect();
+        match chords.as_slice() {
+            [chord] => Self::Chord(*chord),
+            _ => Self::AnyOf(chords),
+        }
+    }
+
+    /// Match this or `other`.
+    pub fn or(self, other: impl Into<KeyMatcher>) -> Self {
+        Self::any_of([self, other.into()])
+    }
+
+    /// Allow any modifiers beyond the required ones on every chord.
+    pub fn any_modifiers(self) -> Self {
+        self.map(KeyChord::any_modifiers)
+    }
+
+    /// Allow these modifiers to be held as well on every chord.
+    pub fn ignoring(self, modifiers: KeyModifiers) -> Self {
+        self.map(|chord| chord.ignoring(modifiers))
+    }
+
+    fn map(self, f: impl Fn(KeyChord) -> KeyChord) -> Self {
+        match self {
+            Self::Chord(chord) => Self::Chord(f(chord)),
+            Self::AnyOf(chords) => Self::AnyOf(chords.into_iter().map(f).collect()),
+        }
+    }
+
+    /// The chords this matcher accepts.
+    pub fn chords(&self) -> &[KeyChord] {
+        match self {
+            Self::Chord(chord) => std::slice::from_ref(chord),
+            Self::AnyOf(chords) => chords,
+        }
+    }
+
+    /// Check if a key event matches.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        self.chords().iter().any(|chord| chord.matches(key))
+    }
+
+    /// Enter or Space, with any modifiers.
+    pub fn activate() -> Self {
+        Self::key(KeyCode::Enter)
+            .or(Self::key(KeyCode::Char(' ')))
+            .any_modifiers()
+    }
+
+    /// Tab or BackTab, or an arrow key, with any modifiers.
+    pub fn navigation() -> Self {
+        Self::any_of([
+            Self::key(KeyCode::Tab),
+            Self::key(KeyCode::BackTab),
+            Self::arrow(),
+        ])
+        .any_modifiers()
+    }
+
+    /// Tab without Shift.
+    pub fn tab() -> Self {
+        Self::key(KeyCode::Tab).ignoring(KeyModifiers::all() - KeyModifiers::SHIFT)
+    }
+
+    /// Shift+Tab or BackTab.
+    pub fn backtab() -> Self {
+        Self::key(KeyCode::BackTab).any_modifiers()
+    }
+
+    /// Any arrow key, with any modifiers.
+    pub fn arrow() -> Self {
+        Self::any_of([
+            Self::key(KeyCode::Up),
+            Self::key(KeyCode::Down),
+            Self::key(KeyCode::Left),
+            Self::key(KeyCode::Right),
+        ])
+        .any_modifiers()
+    }
+
+    /// Down or Right without modifiers.
+    pub fn next_arrow() -> Self {
+        Self::key(KeyCode::Down).or(Self::key(KeyCode::Right))
+    }
+
+    /// Up or Left without modifiers.
+    pub fn prev_arrow() -> Self {
+        Self::key(KeyCode::Up).or(Self::key(KeyCode::Left))
+    }
+}
+
+impl From<KeyChord> for KeyMatcher {
+    fn from(chord: KeyChord) -> Self {
+        Self::Chord(chord)
+    }
+}
+
+impl From<KeyCode> for KeyMatcher {
+    fn from(code: KeyCode) -> Self {
+        Self::key(code)
+    }
+}
+
+impl fmt::Display for KeyMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chord) in self.chords().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" / ")?;
+            }
+            write!(f, "{}", chord)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for KeyMatcher {
+    type Err = KeyMatcherParseError;
+
+    /// Parse chords like `"ctrl+enter"`, with alternatives separated by `/`
+    /// or `|` (e.g. `"Up/Down"`). A separator right after `+` or at the start
+    /// is the key itself, as in `"Ctrl+/"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chords = Vec::new();
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            let is_key = s[start..i].trim().is_empty() || s[..i].ends_with('+');
+            if matches!(c, '/' | '|') && !is_key {
+                chords.push(s[start..i].parse()?);
+                start = i + 1;
+            }
+        }
+        chords.push(s[start..].parse()?);
+        Ok(Self::any_of(chords.into_iter().map(Self::Chord)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_display_round_trip() {
+        for s in [
+            "Ctrl+Enter",
+            "Ctrl+S",
+            "Ctrl+Shift+K",
+            "Alt+Up",
+            "Shift+Tab",
+            "Super+Space",
+            "Ctrl+/",
+            "Ctrl++",
+            "q",
+            "?",
+            "F5",
+            "Esc",
+            "PageDown",
+            "Ctrl+Enter / Ctrl+Space",
+        ] {
+            let matcher: KeyMatcher = s.parse().unwrap();
+            assert_eq!(matcher.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_parse_normalizes_spelling() {
+        let cases = [
+            ("ctrl+enter", "Ctrl+Enter"),
+            ("control+return", "Ctrl+Enter"),
+            ("CTRL+s", "Ctrl+S"),
+            ("shift+ctrl+a", "Ctrl+Shift+A"),
+            ("escape", "Esc"),
+            ("BackTab", "Shift+Tab"),
+            ("Up/Down", "Up / Down"),
+            ("ctrl+enter|ctrl+space", "Ctrl+Enter / Ctrl+Space"),
+            ("/", "/"),
+            ("pgup", "PageUp"),
+        ];
+        for (input, expected) in cases {
+            let matcher: KeyMatcher = input.parse().unwrap();
+            assert_eq!(matcher.to_string(), expected, "parsing {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for s in ["", "Ctrl+", "Hyper+A", "Ctrl+Foo", "Fx", "a/"] {
+            assert!(s.parse::<KeyMatcher>().is_err(), "{:?} should not parse", s);
+        }
+    }
+
+    #[test]
+    fn test_matches_exact_modifiers() {
+        let matcher: KeyMatcher = "Ctrl+Enter".parse().unwrap();
+        assert!(matcher.matches(&key(KeyCode::Enter, KeyModifiers::CONTROL)));
+        assert!(!matcher.matches(&key(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!matcher.matches(&key(
+            KeyCode::Enter,
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        )));
+    }
+
+    #[test]
+    fn test_matches_letter_case_and_shift() {
+        let ctrl_shift_k: KeyMatcher = "Ctrl+Shift+K".parse().unwrap();
+        assert!(ctrl_shift_k.matches(&key(
+            KeyCode::Char('K'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+        assert!(ctrl_shift_k.matches(&key(KeyCode::Char('K'), KeyModifiers::CONTROL)));
+        assert!(!ctrl_shift_k.matches(&key(KeyCode::Char('k'), KeyModifiers::CONTROL)));
+
+        // Shift is implied by the character for punctuation
+        let question: KeyMatcher = "?".parse().unwrap();
+        assert!(question.matches(&key(KeyCode::Char('?'), KeyModifiers::SHIFT)));
+        assert!(question.matches(&key(KeyCode::Char('?'), KeyModifiers::NONE)));
+
+        let shift_tab: KeyMatcher = "Shift+Tab".parse().unwrap();
+        assert!(shift_tab.matches(&key(KeyCode::BackTab, KeyModifiers::SHIFT)));
+        assert!(shift_tab.matches(&key(KeyCode::BackTab, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn test_any_of_and_ignored_modifiers() {
+        let matcher = KeyMatcher::ctrl('a').or(KeyCode::Home);
+        assert!(matcher.matches(&key(KeyCode::Char('a'), KeyModifiers::CONTROL)));
+        assert!(matcher.matches(&key(KeyCode::Home, KeyModifiers::NONE)));
+        assert!(!matcher.matches(&key(KeyCode::End, KeyModifiers::NONE)));
+        assert_eq!(matcher.chords().len(), 2);
+
+        let loose = KeyMatcher::ctrl('a').any_modifiers();
+        assert!(loose.matches(&key(
+            KeyCode::Char('a'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        )));
+        assert!(!loose.matches(&key(KeyCode::Char('a'), KeyModifiers::NONE)));
+        assert_eq!(loose.to_string(), "Ctrl+A");
+    }
+}
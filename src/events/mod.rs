@@ -1,7 +1,12 @@
 //! Event handling utilities
 //!
-//! Helper functions for working with keyboard and mouse events.
+//! Helper functions for working with keyboard and mouse events, and
+//! [`KeyMatcher`] for key bindings stored as values.
 
+mod close;
 mod handlers;
+mod key_matcher;
 
+pub use close::*;
 pub use handlers::*;
+pub use key_matcher::{KeyChord, KeyMatcher, KeyMatcherParseError};
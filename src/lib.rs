@@ -151,6 +151,7 @@
 //! }
 //! ```
 
+pub mod capabilities;
 pub mod components;
 pub mod events;
 pub mod state;
@@ -168,67 +169,108 @@ pub mod utils;
 pub mod prelude {
     // Interactive Components
     pub use crate::components::{
-        Button, ButtonAction, ButtonState, ButtonStyle, ButtonVariant, CheckBox, CheckBoxAction,
-        CheckBoxState, CheckBoxStyle, ContextMenu, ContextMenuAction, ContextMenuItem,
-        ContextMenuState, ContextMenuStyle, DialogConfig, DialogFocusTarget, DialogState, Input,
-        InputAction, InputState, InputStyle, Menu, MenuBar, MenuBarAction, MenuBarClickTarget,
-        MenuBarItem, MenuBarState, MenuBarStyle, PopupDialog, calculate_menu_bar_height,
-        calculate_menu_height, handle_context_menu_key, handle_context_menu_mouse,
-        handle_menu_bar_key, handle_menu_bar_mouse, is_context_menu_trigger,
-        menu_bar_dropdown_height,
+        Button, ButtonAction, ButtonGroup, ButtonGroupAction, ButtonGroupState, ButtonSize,
+        ButtonState, ButtonStyle, ButtonVariant, CheckBox, CheckBoxAction, CheckBoxState,
+        CheckBoxStyle, ContextMenu, ContextMenuAction, ContextMenuItem, ContextMenuProvider,
+        ContextMenuState, ContextMenuStyle, DialogAction, DialogConfig, DialogFocusTarget,
+        DialogState, EscapeAction, IconPosition, Input, InputAction, InputMask, InputMode,
+        InputState, InputStyle, KeyValueColumn, KeyValueEditor, KeyValueEditorAction,
+        KeyValueEditorState, KeyValueEditorStyle, KeyValueEntry, MaskSlot, Menu, MenuBar,
+        MenuBarAction, MenuBarClickTarget, MenuBarItem, MenuBarState, MenuBarStateSave,
+        MenuBarStyle, PopupDialog, ToggleGroup, ToggleGroupAction, ToggleGroupState,
+        TypedContextMenu, TypedContextMenuAction, TypedContextMenuItem, TypedMenu, TypedMenuBar,
+        TypedMenuBarAction, TypedMenuBarItem, calculate_menu_bar_height, calculate_menu_height,
+        calculate_menu_height_for_items, handle_button_group_key, handle_button_group_mouse,
+        handle_button_key, handle_button_mouse, handle_checkbox_key, handle_context_menu_key,
+        handle_context_menu_key_with_provider, handle_context_menu_mouse,
+        handle_key_value_editor_key, handle_key_value_editor_mouse, handle_menu_bar_key,
+        handle_menu_bar_key_with_style, handle_menu_bar_mouse, handle_toggle_group_key,
+        handle_toggle_group_mouse, is_context_menu_trigger, menu_bar_dropdown_height,
+        render_button_tooltip,
     };
 
     // Display Components
     pub use crate::components::{
-        AnimatedText, AnimatedTextEffect, AnimatedTextState, AnimatedTextStyle, ParagraphExt,
-        Progress, ProgressStyle, ScrollableContent, ScrollableContentAction,
-        ScrollableContentState, ScrollableContentStyle, Toast, ToastDismissPolicy, ToastId,
-        ToastItem, ToastOrder, ToastPlacement, ToastStack, ToastStackLayout, ToastStackState,
-        ToastState, ToastStyle, WaveDirection, handle_scrollable_content_key,
-        handle_scrollable_content_mouse,
+        AnimatedText, AnimatedTextEffect, AnimatedTextState, AnimatedTextStyle, BusyOverlay,
+        BusyOverlayAction, BusyOverlayState, BusyOverlayStyle, MiniProgress, MiniProgressKind,
+        Notification, NotificationCenter, NotificationCenterAction, NotificationCenterState,
+        NotificationCenterStyle, NotificationId, ParagraphAction, ParagraphContent, ParagraphExt,
+        ParagraphExtState, Progress, ProgressHistory, ProgressSparkline, ProgressState,
+        ProgressStyle, ScreenFlash, ScrollableContent, ScrollableContentAction,
+        ScrollableContentState, ScrollableContentStyle, Toast, ToastAttention, ToastDismissPolicy,
+        ToastId, ToastItem, ToastOrder, ToastPlacement, ToastPulse, ToastPulseTarget, ToastStack,
+        ToastStackLayout, ToastStackState, ToastState, ToastStyle, WaveDirection,
+        handle_busy_overlay_key, handle_busy_overlay_mouse, handle_notification_center_key,
+        handle_notification_center_mouse, handle_paragraph_ext_key, handle_paragraph_ext_mouse,
+        handle_scrollable_content_key, handle_scrollable_content_mouse,
     };
 
     // Utility Components
-    pub use crate::components::{MousePointer, MousePointerState, MousePointerStyle};
+    pub use crate::components::{
+        FocusRing, InteractiveScrollbar, MousePointer, MousePointerState, MousePointerStyle,
+        ScrollbarDragState, ScrollbarPart, apply_focus,
+    };
 
     // Navigation Components
     pub use crate::components::{
-        EntryType, FileEntry, FileExplorer, FileExplorerState, FileExplorerStyle, ListPicker,
-        ListPickerState, ListPickerStyle, key_hints_footer,
+        EntryType, FileEntry, FileExplorer, FileExplorerAction, FileExplorerState,
+        FileExplorerStyle, FileSource, ListGroup, ListPicker, ListPickerSnapshot, ListPickerState,
+        ListPickerStyle, OptionDetails, SearchMatchMode, handle_file_explorer_mouse,
+        handle_file_explorer_nav_key, handle_file_explorer_search_key,
+        handle_file_explorer_selection_key, handle_list_picker_key,
+        handle_list_picker_scrollbar_mouse, key_hints_footer,
     };
 
     // Tree Components
     pub use crate::components::{
-        FlatNode, TreeNode, TreeStyle, TreeView, TreeViewState, get_selected_id,
+        ClipboardOp, FlatNode, TreeClipboard, TreeNode, TreeStyle, TreeView, TreeViewAction,
+        TreeViewSnapshot, TreeViewState, get_selected_id, handle_tree_view_clipboard_key,
+        handle_tree_view_nav_key, handle_tree_view_retry_key, handle_tree_view_scrollbar_mouse,
     };
 
     // Layout Components
     pub use crate::components::{
-        Orientation, SplitPane, SplitPaneAction, SplitPaneState, SplitPaneStyle,
-        handle_split_pane_key, handle_split_pane_mouse,
+        Orientation, SplitPane, SplitPaneAction, SplitPaneFocus, SplitPaneKeyRoute, SplitPaneKeys,
+        SplitPaneState, SplitPaneStyle, handle_split_pane_double_click,
+        handle_split_pane_focus_key, handle_split_pane_key, handle_split_pane_mouse,
     };
 
     // Viewer Components
     pub use crate::components::{
-        DiffData, DiffHunk, DiffLine, DiffLineType, DiffViewMode, DiffViewer, DiffViewerAction,
-        DiffViewerState, DiffViewerStyle, LogViewer, LogViewerState, LogViewerStyle, SearchState,
-        Step, StepDisplay, StepDisplayState, StepDisplayStyle, StepStatus, SubStep,
-        handle_diff_viewer_key, handle_diff_viewer_mouse, step_display_height,
+        DiffData, DiffHunk, DiffLine, DiffLineType, DiffSide, DiffViewMode, DiffViewer,
+        DiffViewerAction, DiffViewerSnapshot, DiffViewerState, DiffViewerStyle, DiffViewerTheme,
+        HighlightRule, LogColumn, LogColumns, LogLevel, LogRecord, LogViewer, LogViewerAction,
+        LogViewerSnapshot, LogViewerState, LogViewerStyle, SearchState, SelectionMode, Step,
+        StepDisplay, StepDisplayLayout, StepDisplayState, StepDisplayStyle, StepStatus, SubStep,
+        TextSelection, TextSelectionAction, format_hunk_as_patch, handle_diff_viewer_key,
+        handle_diff_viewer_mouse, handle_diff_viewer_scrollbar_mouse, handle_log_viewer_copy_key,
+        handle_log_viewer_scroll_key, handle_log_viewer_scrollbar_mouse, handle_step_display_key,
+        handle_step_display_mouse, step_display_height,
     };
 
     // Dialog Components
     pub use crate::components::{
-        CategoryClickRegion, HotkeyCategory, HotkeyClickRegion, HotkeyDialog, HotkeyDialogAction,
-        HotkeyDialogState, HotkeyDialogStyle, HotkeyEntryData, HotkeyFocus, HotkeyProvider,
-        handle_hotkey_dialog_key, handle_hotkey_dialog_mouse, render_hotkey_dialog,
+        CategoryClickRegion, HotkeyCategory, HotkeyCategoryLayout, HotkeyClickRegion, HotkeyDialog,
+        HotkeyDialogAction, HotkeyDialogState, HotkeyDialogStyle, HotkeyEntryData, HotkeyFocus,
+        HotkeyProvider, handle_hotkey_dialog_key, handle_hotkey_dialog_mouse, render_hotkey_dialog,
+    };
+    pub use crate::components::{
+        HelpOverlay, HelpOverlayState, HelpScope, KeyHint, KeyHintRegistry,
+        handle_help_overlay_key, handle_help_overlay_mouse, is_help_key,
+    };
+    pub use crate::components::{
+        Wizard, WizardAction, WizardFocus, WizardPage, WizardState, WizardStyle,
     };
 
     // Theme
+    pub use crate::capabilities::Capabilities;
     pub use crate::theme::{ColorPalette, Theme};
 
     // Utilities
     pub use crate::utils::{
-        clean_for_display, format_size, pad_to_width, parse_ansi_to_spans, truncate_to_width,
+        TableBuilder, clean_for_display, format_relative, format_size, format_table,
+        format_table_with_header, pad_to_width, parse_ansi_to_spans, truncate_middle,
+        truncate_to_width,
     };
 
     // Clipboard utilities
@@ -239,25 +281,30 @@ pub mod prelude {
 
     // Mouse capture utilities
     pub use crate::utils::{
-        MouseCaptureState, disable_mouse_capture, enable_mouse_capture, set_mouse_capture,
-        toggle_mouse_capture,
+        MouseCaptureCoordinator, MouseCaptureState, disable_mouse_capture, enable_mouse_capture,
+        set_mouse_capture, toggle_mouse_capture,
     };
 
     // Traits
     pub use crate::traits::{
         ClickRegion, ClickRegionRegistry, Clickable, Container, ContainerAction, EventResult,
-        FocusId, Focusable, PopupContainer,
+        FocusId, Focusable, FocusableWidget, MenuAction, PopupContainer, PopupOwner,
     };
 
     // State management
-    pub use crate::state::FocusManager;
+    pub use crate::state::{
+        FocusManager, FocusNavigationMode, Loadable, LoadableAction, NavigationHistory,
+        OverlayLayer, OverlayManager, PageMove, PageSize, SafeArea, ScrollMemory,
+        close_popups_on_focus_change, handle_loadable_retry_key,
+    };
 
     // Event helpers
     pub use crate::events::{
-        get_char, get_mouse_pos, get_scroll, has_alt, has_ctrl, has_shift, is_activate_key,
-        is_backspace, is_backtab, is_close_key, is_ctrl_a, is_ctrl_e, is_ctrl_k, is_ctrl_u,
-        is_ctrl_w, is_delete, is_end, is_enter, is_home, is_left_click, is_mouse_drag,
-        is_mouse_move, is_navigation_key, is_right_click, is_space, is_tab,
+        CloseRequest, KeyChord, KeyMatcher, KeyMatcherParseError, get_char, get_mouse_pos,
+        get_scroll, has_alt, has_ctrl, has_shift, is_activate_key, is_backspace, is_backtab,
+        is_close_key, is_ctrl_a, is_ctrl_e, is_ctrl_k, is_ctrl_u, is_ctrl_w, is_delete, is_end,
+        is_enter, is_home, is_left_click, is_mouse_drag, is_mouse_move, is_navigation_key,
+        is_next_arrow, is_prev_arrow, is_right_click, is_space, is_tab,
     };
 }
 
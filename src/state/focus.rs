@@ -43,6 +43,23 @@
 
 use std::hash::Hash;
 
+use crossterm::event::KeyEvent;
+
+use crate::events::{is_backtab, is_next_arrow, is_prev_arrow, is_tab};
+use crate::traits::{FocusId, FocusableWidget, PopupOwner};
+
+/// Which keys move focus between elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FocusNavigationMode {
+    /// Tab/Shift+Tab, routed by the widget layer (default).
+    #[default]
+    Tab,
+    /// Up/Down and Left/Right arrow keys only.
+    ArrowKeys,
+    /// Both Tab/Shift+Tab and arrow keys.
+    Both,
+}
+
 /// Focus manager for Tab navigation.
 ///
 /// Manages a list of focusable elements and tracks which one currently has focus.
@@ -58,6 +75,8 @@ pub struct FocusManager<T: Clone + Eq + Hash = usize> {
     elements: Vec<T>,
     /// Current focus index.
     current_index: Option<usize>,
+    /// Which keys [`FocusManager::handle_key`] consumes.
+    navigation_mode: FocusNavigationMode,
 }
 
 impl<T: Clone + Eq + Hash> Default for FocusManager<T> {
@@ -72,6 +91,7 @@ impl<T: Clone + Eq + Hash> FocusManager<T> {
         Self {
             elements: Vec::new(),
             current_index: None,
+            navigation_mode: FocusNavigationMode::Tab,
         }
     }
 
@@ -80,6 +100,43 @@ impl<T: Clone + Eq + Hash> FocusManager<T> {
         Self {
             elements: Vec::with_capacity(capacity),
             current_index: None,
+            navigation_mode: FocusNavigationMode::Tab,
+        }
+    }
+
+    /// Set which keys move focus in [`FocusManager::handle_key`].
+    pub fn set_navigation_mode(&mut self, mode: FocusNavigationMode) {
+        self.navigation_mode = mode;
+    }
+
+    /// Get the current navigation mode.
+    pub fn navigation_mode(&self) -> FocusNavigationMode {
+        self.navigation_mode
+    }
+
+    /// Move focus in response to a key press.
+    ///
+    /// In `ArrowKeys` mode, Down/Right call [`next`](Self::next) and Up/Left
+    /// call [`prev`](Self::prev). `Both` additionally handles Tab/Shift+Tab.
+    /// In `Tab` mode this always returns `false`, leaving Tab routing to the
+    /// widget layer.
+    ///
+    /// Returns `true` if the key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        let (tab, arrows) = match self.navigation_mode {
+            FocusNavigationMode::Tab => return false,
+            FocusNavigationMode::ArrowKeys => (false, true),
+            FocusNavigationMode::Both => (true, true),
+        };
+
+        if (arrows && is_next_arrow(key)) || (tab && is_tab(key)) {
+            self.next();
+            true
+        } else if (arrows && is_prev_arrow(key)) || (tab && is_backtab(key)) {
+            self.prev();
+            true
+        } else {
+            false
         }
     }
 
@@ -253,6 +310,59 @@ impl<T: Clone + Eq + Hash> FocusManager<T> {
     }
 }
 
+impl<T: Clone + Eq + Hash + Into<FocusId>> FocusManager<T> {
+    /// Set `focused` on each widget whose focus ID matches the current
+    /// element, and clear it on the rest.
+    ///
+    /// Replaces writing `state.focused = focus.is_focused(&id)` for every
+    /// widget after each focus change.
+    pub fn sync_from_vec(&self, widgets: &mut [&mut dyn FocusableWidget]) {
+        let current = self.current().cloned().map(Into::into);
+        for widget in widgets.iter_mut() {
+            widget.set_focused(current == Some(widget.focus_id()));
+        }
+    }
+}
+
+/// Close the popups of components that no longer have focus.
+///
+/// Call once per frame, after handling input, with each popup-owning state
+/// and the element it is registered under. Every owner other than the
+/// focused element gets [`PopupOwner::on_focus_lost`], so a dropdown or menu
+/// left open when focus moves away closes instead of rendering over the
+/// newly focused area. Register only popups that belong to a focus element;
+/// a free-floating context menu should not be listed here.
+///
+/// Returns `true` if any popup was closed.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::components::SelectState;
+/// use ratatui_interact::state::{FocusManager, close_popups_on_focus_change};
+///
+/// let mut focus = FocusManager::new();
+/// focus.register_all(["fruit", "submit"]);
+/// let mut fruit = SelectState::new(3);
+/// fruit.open();
+///
+/// focus.next();
+/// assert!(close_popups_on_focus_change(&focus, &mut [("fruit", &mut fruit)]));
+/// assert!(!fruit.is_open);
+/// ```
+pub fn close_popups_on_focus_change<T: Clone + Eq + Hash>(
+    focus: &FocusManager<T>,
+    owners: &mut [(T, &mut dyn PopupOwner)],
+) -> bool {
+    let mut closed = false;
+    for (element, owner) in owners.iter_mut() {
+        if !focus.is_focused(element) {
+            closed |= owner.on_focus_lost();
+        }
+    }
+    closed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -457,6 +567,65 @@ mod tests {
         assert!(!manager.has_focus());
     }
 
+    fn key(code: crossterm::event::KeyCode) -> KeyEvent {
+        KeyEvent::new(code, crossterm::event::KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_handle_key_tab_mode() {
+        use crossterm::event::KeyCode;
+
+        let mut manager = FocusManager::new();
+        manager.register_all([TestElement::First, TestElement::Second]);
+        assert_eq!(manager.navigation_mode(), FocusNavigationMode::Tab);
+
+        assert!(!manager.handle_key(&key(KeyCode::Tab)));
+        assert!(!manager.handle_key(&key(KeyCode::Down)));
+        assert_eq!(manager.current(), Some(&TestElement::First));
+    }
+
+    #[test]
+    fn test_handle_key_arrow_mode() {
+        use crossterm::event::KeyCode;
+
+        let mut manager = FocusManager::new();
+        manager.register_all([TestElement::First, TestElement::Second, TestElement::Third]);
+        manager.set_navigation_mode(FocusNavigationMode::ArrowKeys);
+
+        assert!(!manager.handle_key(&key(KeyCode::Tab)));
+        assert_eq!(manager.current(), Some(&TestElement::First));
+
+        assert!(manager.handle_key(&key(KeyCode::Down)));
+        assert_eq!(manager.current(), Some(&TestElement::Second));
+
+        assert!(manager.handle_key(&key(KeyCode::Right)));
+        assert_eq!(manager.current(), Some(&TestElement::Third));
+
+        assert!(manager.handle_key(&key(KeyCode::Up)));
+        assert!(manager.handle_key(&key(KeyCode::Left)));
+        assert_eq!(manager.current(), Some(&TestElement::First));
+
+        assert!(!manager.handle_key(&key(KeyCode::Enter)));
+    }
+
+    #[test]
+    fn test_handle_key_both_mode() {
+        use crossterm::event::KeyCode;
+
+        let mut manager = FocusManager::new();
+        manager.register_all([TestElement::First, TestElement::Second]);
+        manager.set_navigation_mode(FocusNavigationMode::Both);
+
+        assert!(manager.handle_key(&key(KeyCode::Tab)));
+        assert_eq!(manager.current(), Some(&TestElement::Second));
+
+        assert!(manager.handle_key(&key(KeyCode::BackTab)));
+        assert_eq!(manager.current(), Some(&TestElement::First));
+
+        assert!(manager.handle_key(&key(KeyCode::Down)));
+        assert_eq!(manager.current(), Some(&TestElement::Second));
+    }
+
     #[test]
     fn test_integer_focus_manager() {
         let mut manager: FocusManager<usize> = FocusManager::new();
@@ -466,4 +635,86 @@ mod tests {
         manager.next();
         assert_eq!(manager.current(), Some(&1));
     }
+
+    #[test]
+    fn test_sync_from_vec() {
+        use crate::components::{ButtonState, CheckBoxState, InputState};
+
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        enum FormElement {
+            Name,
+            Subscribe,
+            Submit,
+        }
+
+        impl From<FormElement> for FocusId {
+            fn from(element: FormElement) -> Self {
+                FocusId::new(element as u32)
+            }
+        }
+
+        let mut name = InputState::empty().with_focus_id(FormElement::Name.into());
+        let mut subscribe = CheckBoxState::new(false).with_focus_id(FormElement::Subscribe.into());
+        let mut submit = ButtonState::enabled().with_focus_id(FormElement::Submit.into());
+
+        let mut manager = FocusManager::new();
+        manager.register_all([
+            FormElement::Name,
+            FormElement::Subscribe,
+            FormElement::Submit,
+        ]);
+
+        manager.sync_from_vec(&mut [&mut name, &mut subscribe, &mut submit]);
+        assert!(name.focused);
+        assert!(!subscribe.focused);
+        assert!(!submit.focused);
+
+        manager.next();
+        manager.next();
+        manager.sync_from_vec(&mut [&mut name, &mut subscribe, &mut submit]);
+        assert!(!name.focused);
+        assert!(!subscribe.focused);
+        assert!(submit.focused);
+
+        manager.unfocus();
+        manager.sync_from_vec(&mut [&mut name, &mut subscribe, &mut submit]);
+        assert!(!name.focused && !subscribe.focused && !submit.focused);
+    }
+
+    #[test]
+    fn test_close_popups_on_focus_change() {
+        use crate::components::{ContextMenuState, MenuBarState, SelectState};
+
+        let mut focus = FocusManager::new();
+        focus.register_all([TestElement::First, TestElement::Second, TestElement::Third]);
+        let mut select = SelectState::new(3);
+        let mut menu = ContextMenuState::new();
+        let mut menu_bar = MenuBarState::new();
+        select.open();
+        menu.open_at(1, 1);
+        menu_bar.open_menu(0);
+
+        // Only the focused element keeps its popup
+        assert!(close_popups_on_focus_change(
+            &focus,
+            &mut [
+                (TestElement::First, &mut select),
+                (TestElement::Second, &mut menu),
+                (TestElement::Third, &mut menu_bar),
+            ],
+        ));
+        assert!(select.is_open);
+        assert!(!menu.is_open && !menu_bar.is_open);
+
+        focus.next();
+        assert!(close_popups_on_focus_change(
+            &focus,
+            &mut [(TestElement::First, &mut select)]
+        ));
+        assert!(!select.is_open);
+        assert!(!close_popups_on_focus_change(
+            &focus,
+            &mut [(TestElement::First, &mut select)]
+        ));
+    }
 }
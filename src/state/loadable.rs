@@ -0,0 +1,214 @@
+//! Loadable - Data that arrives after the first frame
+//!
+//! Options fetched from an API or children listed from a remote directory
+//! are not there when a component first renders. [`Loadable`] tracks which
+//! phase such data is in. `Select`, `ListPicker`, and `TreeView` follow the
+//! same conventions for it: a spinner while loading, the error with a retry
+//! hint when loading failed, and [`LoadableAction::RetryRequested`] when the
+//! user asks to try again.
+//!
+//! The spinner is driven by the caller's tick counter rather than a clock,
+//! so every loading indicator in the app animates in step.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::state::Loadable;
+//!
+//! let mut options: Loadable<Vec<String>> = Loadable::default();
+//! let tick = 7;
+//! options.set_loading(tick);
+//! assert!(options.is_loading());
+//!
+//! // Later, when the request completes
+//! options.set_loaded(vec!["alpha".into(), "beta".into()]);
+//! assert_eq!(options.loaded().map(Vec::len), Some(2));
+//! ```
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// Actions emitted for [`Loadable`] data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadableAction {
+    /// The user asked to retry a failed load.
+    RetryRequested,
+}
+
+/// The loading phase of some data, holding the data once it arrives.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Loadable<T> {
+    /// Nothing requested yet.
+    #[default]
+    NotLoaded,
+    /// Waiting for the data since the caller's tick `since_tick`.
+    Loading {
+        /// Caller tick at which loading started.
+        since_tick: u64,
+    },
+    /// The data arrived.
+    Loaded(T),
+    /// Loading failed with this message.
+    Failed(String),
+}
+
+impl<T> Loadable<T> {
+    /// Start loading at the caller's `tick`, dropping any previous data.
+    pub fn set_loading(&mut self, tick: u64) {
+        *self = Loadable::Loading { since_tick: tick };
+    }
+
+    /// Replace the state with loaded `data` in one step.
+    ///
+    /// Returns the previously loaded data, if any, so nothing ever sees a
+    /// mix of old and new data.
+    pub fn set_loaded(&mut self, data: T) -> Option<T> {
+        match std::mem::replace(self, Loadable::Loaded(data)) {
+            Loadable::Loaded(old) => Some(old),
+            _ => None,
+        }
+    }
+
+    /// Mark loading as failed with `message`.
+    pub fn set_failed(&mut self, message: impl Into<String>) {
+        *self = Loadable::Failed(message.into());
+    }
+
+    /// Check if nothing has been requested yet.
+    pub fn is_not_loaded(&self) -> bool {
+        matches!(self, Loadable::NotLoaded)
+    }
+
+    /// Check if loading is in progress.
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Loadable::Loading { .. })
+    }
+
+    /// Check if the data has arrived.
+    pub fn is_loaded(&self) -> bool {
+        matches!(self, Loadable::Loaded(_))
+    }
+
+    /// Check if loading failed.
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Loadable::Failed(_))
+    }
+
+    /// Check if loading is in progress or failed, i.e. the component shows
+    /// a placeholder instead of its data.
+    pub fn is_pending(&self) -> bool {
+        self.is_loading() || self.is_failed()
+    }
+
+    /// The loaded data.
+    pub fn loaded(&self) -> Option<&T> {
+        match self {
+            Loadable::Loaded(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The loaded data, mutably.
+    pub fn loaded_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Loadable::Loaded(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The failure message.
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Loadable::Failed(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Ticks spent loading as of the caller's tick `now`, for picking a
+    /// spinner frame.
+    pub fn loading_ticks(&self, now: u64) -> Option<u64> {
+        match self {
+            Loadable::Loading { since_tick } => Some(now.saturating_sub(*since_tick)),
+            _ => None,
+        }
+    }
+
+    /// The same phase without the data.
+    pub fn phase(&self) -> Loadable<()> {
+        match self {
+            Loadable::NotLoaded => Loadable::NotLoaded,
+            Loadable::Loading { since_tick } => Loadable::Loading {
+                since_tick: *since_tick,
+            },
+            Loadable::Loaded(_) => Loadable::Loaded(()),
+            Loadable::Failed(message) => Loadable::Failed(message.clone()),
+        }
+    }
+}
+
+/// Handle the retry key for failed data.
+///
+/// Enter or `r` on failed data returns [`LoadableAction::RetryRequested`];
+/// the caller then starts loading again. Other phases ignore the key.
+pub fn handle_loadable_retry_key<T>(key: &KeyEvent, data: &Loadable<T>) -> Option<LoadableAction> {
+    match key.code {
+        KeyCode::Enter | KeyCode::Char('r') if data.is_failed() => {
+            Some(LoadableAction::RetryRequested)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transitions() {
+        let mut data: Loadable<Vec<u32>> = Loadable::default();
+        assert!(data.is_not_loaded());
+        assert!(!data.is_pending());
+
+        data.set_loading(10);
+        assert!(data.is_loading());
+        assert_eq!(data.loading_ticks(13), Some(3));
+        assert_eq!(data.loaded(), None);
+
+        data.set_failed("timeout");
+        assert_eq!(data.error(), Some("timeout"));
+        assert_eq!(data.loading_ticks(13), None);
+
+        assert_eq!(data.set_loaded(vec![1, 2]), None);
+        assert_eq!(data.loaded(), Some(&vec![1, 2]));
+        assert_eq!(data.phase(), Loadable::Loaded(()));
+    }
+
+    #[test]
+    fn test_stale_data_replaced_atomically() {
+        let mut data = Loadable::Loaded(vec!["old"]);
+        let stale = data.set_loaded(vec!["new", "newer"]);
+        assert_eq!(stale, Some(vec!["old"]));
+        assert_eq!(data, Loadable::Loaded(vec!["new", "newer"]));
+
+        // Reloading drops the stale data rather than showing it as current
+        data.set_loading(0);
+        assert_eq!(data.loaded(), None);
+        assert_eq!(data.set_loaded(vec!["fresh"]), None);
+    }
+
+    #[test]
+    fn test_retry_key() {
+        let enter = KeyEvent::from(KeyCode::Enter);
+        let r = KeyEvent::from(KeyCode::Char('r'));
+        let failed: Loadable<()> = Loadable::Failed("boom".into());
+        assert_eq!(
+            handle_loadable_retry_key(&enter, &failed),
+            Some(LoadableAction::RetryRequested)
+        );
+        assert_eq!(
+            handle_loadable_retry_key(&r, &failed),
+            Some(LoadableAction::RetryRequested)
+        );
+        let loading: Loadable<()> = Loadable::Loading { since_tick: 0 };
+        assert_eq!(handle_loadable_retry_key(&enter, &loading), None);
+    }
+}
@@ -5,7 +5,28 @@
 //! # Components
 //!
 //! - [`FocusManager`] - Manages keyboard focus and Tab navigation
+//! - [`Loadable`] - Data that is loading, loaded, or failed to load
+//! - [`NavigationHistory`] - Browser-style back/forward history
+//! - [`OverlayManager`] - Stable stacking order for popups
+//! - [`PageMove`] - Shared Home/End/PageUp/PageDown handling, with [`PageSize`]
+//! - [`SafeArea`] - Frame area minus reserved bars, for placing overlays
+//! - [`ScrollMemory`] - Per-content scroll snapshots restored on content swaps
+//! - [`sync_selection`] - Keep a selection on the same item across data refreshes
 
 mod focus;
+mod loadable;
+mod navigation;
+mod overlay;
+mod paging;
+mod safe_area;
+mod scroll_memory;
+mod selection;
 
-pub use focus::FocusManager;
+pub use focus::{FocusManager, FocusNavigationMode, close_popups_on_focus_change};
+pub use loadable::{Loadable, LoadableAction, handle_loadable_retry_key};
+pub use navigation::NavigationHistory;
+pub use overlay::{OverlayLayer, OverlayManager};
+pub use paging::{PageMove, PageSize};
+pub use safe_area::SafeArea;
+pub use scroll_memory::ScrollMemory;
+pub use selection::sync_selection;
@@ -0,0 +1,228 @@
+//! Navigation History - Browser-style back/forward stacks
+//!
+//! `NavigationHistory` tracks the current location plus the entries you can
+//! go back and forward to. Pushing a new entry clears the forward stack, like
+//! following a link in a browser. Keep one history per tab to give each
+//! [`TabView`](crate::components::TabView) tab its own trail, and rebuild a
+//! [`Breadcrumb`](crate::components::Breadcrumb) from the current entry with
+//! [`BreadcrumbState::from_path`](crate::components::BreadcrumbState::from_path).
+//!
+//! Enable the `serde` feature to serialize histories.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::state::NavigationHistory;
+//!
+//! let mut history = NavigationHistory::new("/");
+//! history.push("/docs");
+//! history.push("/docs/api");
+//!
+//! assert_eq!(history.back(), Some(&"/docs"));
+//! assert_eq!(history.back(), Some(&"/"));
+//! assert!(!history.can_go_back());
+//!
+//! assert_eq!(history.forward(), Some(&"/docs"));
+//!
+//! // Pushing drops the forward entries
+//! history.push("/blog");
+//! assert!(!history.can_go_forward());
+//! ```
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::events::has_alt;
+
+/// Back/forward navigation history over entries of type `T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavigationHistory<T> {
+    /// Entries behind the current one, oldest first.
+    back: Vec<T>,
+    /// The current entry.
+    current: T,
+    /// Entries ahead of the current one, nearest last.
+    forward: Vec<T>,
+    /// Maximum number of entries kept in the back stack (`None` = unbounded).
+    max_back: Option<usize>,
+}
+
+impl<T> NavigationHistory<T> {
+    /// Create a history starting at `initial`.
+    pub fn new(initial: T) -> Self {
+        Self {
+            back: Vec::new(),
+            current: initial,
+            forward: Vec::new(),
+            max_back: None,
+        }
+    }
+
+    /// Limit how many entries the back stack keeps, dropping the oldest.
+    pub fn with_max_back(mut self, max: usize) -> Self {
+        self.max_back = Some(max);
+        self.trim_back();
+        self
+    }
+
+    /// The current entry.
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Navigate to `entry`, clearing the forward stack.
+    pub fn push(&mut self, entry: T) {
+        let previous = std::mem::replace(&mut self.current, entry);
+        self.back.push(previous);
+        self.forward.clear();
+        self.trim_back();
+    }
+
+    /// Replace the current entry without recording history (e.g. a redirect).
+    pub fn replace(&mut self, entry: T) {
+        self.current = entry;
+    }
+
+    /// Go back one entry, returning the new current entry.
+    pub fn back(&mut self) -> Option<&T> {
+        let entry = self.back.pop()?;
+        let previous = std::mem::replace(&mut self.current, entry);
+        self.forward.push(previous);
+        Some(&self.current)
+    }
+
+    /// Go forward one entry, returning the new current entry.
+    pub fn forward(&mut self) -> Option<&T> {
+        let entry = self.forward.pop()?;
+        let previous = std::mem::replace(&mut self.current, entry);
+        self.back.push(previous);
+        Some(&self.current)
+    }
+
+    /// Check if there is an entry to go back to.
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    /// Check if there is an entry to go forward to.
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Number of entries behind the current one.
+    pub fn back_len(&self) -> usize {
+        self.back.len()
+    }
+
+    /// Number of entries ahead of the current one.
+    pub fn forward_len(&self) -> usize {
+        self.forward.len()
+    }
+
+    /// Move back or forward in response to a key press.
+    ///
+    /// Alt+Left goes back and Alt+Right goes forward. Returns the new current
+    /// entry if the history moved.
+    ///
+    /// Mouse back/forward buttons are not reported by crossterm, so map them
+    /// to [`back`](Self::back) and [`forward`](Self::forward) yourself if your
+    /// backend exposes them.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> Option<&T> {
+        if !has_alt(key) {
+            return None;
+        }
+        match key.code {
+            KeyCode::Left => self.back(),
+            KeyCode::Right => self.forward(),
+            _ => None,
+        }
+    }
+
+    /// Get the keyboard bindings this history handles, for help displays.
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[("Alt+Left", "Go back"), ("Alt+Right", "Go forward")]
+    }
+
+    fn trim_back(&mut self) {
+        if let Some(max) = self.max_back
+            && self.back.len() > max
+        {
+            let excess = self.back.len() - max;
+            self.back.drain(..excess);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_push_back_forward() {
+        let mut history = NavigationHistory::new(1);
+        history.push(2);
+        history.push(3);
+        assert_eq!(history.current(), &3);
+        assert_eq!(history.back_len(), 2);
+
+        assert_eq!(history.back(), Some(&2));
+        assert_eq!(history.back(), Some(&1));
+        assert_eq!(history.back(), None);
+        assert_eq!(history.current(), &1);
+        assert_eq!(history.forward_len(), 2);
+
+        assert_eq!(history.forward(), Some(&2));
+        assert_eq!(history.forward(), Some(&3));
+        assert_eq!(history.forward(), None);
+    }
+
+    #[test]
+    fn test_push_clears_forward() {
+        let mut history = NavigationHistory::new("a");
+        history.push("b");
+        history.back();
+        assert!(history.can_go_forward());
+
+        history.push("c");
+        assert!(!history.can_go_forward());
+        assert_eq!(history.back(), Some(&"a"));
+    }
+
+    #[test]
+    fn test_replace_keeps_stacks() {
+        let mut history = NavigationHistory::new("a");
+        history.push("b");
+        history.replace("b2");
+        assert_eq!(history.current(), &"b2");
+        assert_eq!(history.back_len(), 1);
+    }
+
+    #[test]
+    fn test_max_back_drops_oldest() {
+        let mut history = NavigationHistory::new(0).with_max_back(2);
+        for i in 1..=4 {
+            history.push(i);
+        }
+        assert_eq!(history.back_len(), 2);
+        assert_eq!(history.back(), Some(&3));
+        assert_eq!(history.back(), Some(&2));
+        assert_eq!(history.back(), None);
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut history = NavigationHistory::new("a");
+        history.push("b");
+
+        let alt = |code| KeyEvent::new(code, KeyModifiers::ALT);
+        assert_eq!(
+            history.handle_key(&KeyEvent::new(KeyCode::Left, KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(history.handle_key(&alt(KeyCode::Left)), Some(&"a"));
+        assert_eq!(history.handle_key(&alt(KeyCode::Left)), None);
+        assert_eq!(history.handle_key(&alt(KeyCode::Right)), Some(&"b"));
+        assert_eq!(history.handle_key(&alt(KeyCode::Up)), None);
+    }
+}
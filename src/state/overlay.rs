@@ -0,0 +1,355 @@
+//! Overlay Manager - Stable stacking for popups rendered in one frame
+//!
+//! Dropdowns, menus, toasts, and dialogs each clear their area and draw over
+//! whatever is already in the buffer, so when several are open at once the
+//! app's render order decides which one ends up on top. The
+//! `OverlayManager` collects popups during the frame and draws them at the
+//! end in a fixed order: by [`OverlayLayer`], then in registration order
+//! within a layer.
+//!
+//! Widgets that produce popups have `render_into_overlays` variants that
+//! register with a manager instead of drawing straight into the frame. They
+//! still return their click regions immediately. The manager also reports
+//! the cells its overlays cover, so the app can skip expensive drawing that
+//! would end up hidden.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{Terminal, backend::TestBackend, layout::Rect, widgets::Paragraph};
+//! use ratatui_interact::components::{ContextMenu, ContextMenuItem, ContextMenuState, Toast};
+//! use ratatui_interact::state::{OverlayLayer, OverlayManager};
+//!
+//! let items = vec![ContextMenuItem::action("copy", "Copy")];
+//! let mut menu_state = ContextMenuState::new();
+//! menu_state.open_at(2, 2);
+//!
+//! let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+//! terminal
+//!     .draw(|frame| {
+//!         let screen = frame.area();
+//!         let mut overlays = OverlayManager::new();
+//!
+//!         // Registration order doesn't matter: toasts always end up on top
+//!         Toast::new("Saved").render_into_overlays(screen, &mut overlays);
+//!         let (_menu_area, _regions) =
+//!             ContextMenu::new(&items, &menu_state).render_into_overlays(screen, &mut overlays);
+//!
+//!         // Custom popups can register a draw closure
+//!         overlays.push(OverlayLayer::Dialog, Rect::new(20, 8, 10, 1), |frame| {
+//!             frame.render_widget(Paragraph::new("custom"), Rect::new(20, 8, 10, 1));
+//!         });
+//!
+//!         // Skip background drawing that would be hidden anyway
+//!         if !overlays.covers(Rect::new(2, 2, 4, 1)) {
+//!             frame.render_widget(Paragraph::new("background"), screen);
+//!         }
+//!
+//!         overlays.render(frame);
+//!     })
+//!     .unwrap();
+//! ```
+
+use ratatui::{
+    Frame,
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::Style,
+    widgets::{Clear, Widget},
+};
+
+/// Stacking layer of an overlay, lowest first.
+///
+/// Overlays draw in this order, so a dropdown opened from a dialog appears
+/// over the dialog, and toasts appear over everything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OverlayLayer {
+    /// Dialogs and panels: `PopupDialog`, `HotkeyDialog`, `HelpOverlay`,
+    /// `NotificationCenter`, `BusyOverlay`.
+    Dialog,
+    /// Dropdowns anchored to a widget: `Select` and `MenuBar` dropdowns.
+    Dropdown,
+    /// Context menus.
+    Menu,
+    /// Toast notifications.
+    Toast,
+}
+
+/// Symbol marking cells an overlay capture has not drawn to.
+const UNTOUCHED: &str = "\u{F8FF}";
+
+enum OverlayContent<'a> {
+    /// Cells rendered at registration time, blitted where they changed.
+    Captured(Buffer),
+    /// Draw closure run at render time, after clearing its area.
+    Deferred(Box<dyn FnOnce(&mut Frame) + 'a>),
+}
+
+struct Overlay<'a> {
+    layer: OverlayLayer,
+    /// Cells the overlay draws opaquely, as non-overlapping rects.
+    covered: Vec<Rect>,
+    content: OverlayContent<'a>,
+}
+
+/// Collects popups during a frame and renders them in a stable order.
+///
+/// Create one per frame, register overlays while rendering, and call
+/// [`render`](Self::render) last.
+#[derive(Default)]
+pub struct OverlayManager<'a> {
+    overlays: Vec<Overlay<'a>>,
+}
+
+impl std::fmt::Debug for OverlayManager<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OverlayManager")
+            .field("len", &self.overlays.len())
+            .field("covered_areas", &self.covered_areas())
+            .finish()
+    }
+}
+
+impl<'a> OverlayManager<'a> {
+    /// Create an empty overlay manager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a draw closure for `area` on `layer`.
+    ///
+    /// At render time the manager clears `area`, then runs `draw`.
+    pub fn push(&mut self, layer: OverlayLayer, area: Rect, draw: impl FnOnce(&mut Frame) + 'a) {
+        let covered = if area.is_empty() {
+            Vec::new()
+        } else {
+            vec![area]
+        };
+        self.overlays.push(Overlay {
+            layer,
+            covered,
+            content: OverlayContent::Deferred(Box::new(draw)),
+        });
+    }
+
+    /// Render a popup now into an offscreen buffer spanning `screen`, and
+    /// register the cells it drew on `layer`.
+    ///
+    /// Returns whatever `render` returns, typically click regions. Cells
+    /// whose symbol `render` left alone but whose style it changed (e.g. a
+    /// dimmed backdrop) are applied as a style patch and don't count as
+    /// covered. Nothing is registered if `render` draws nothing.
+    pub fn capture<R>(
+        &mut self,
+        layer: OverlayLayer,
+        screen: Rect,
+        render: impl FnOnce(&mut Buffer) -> R,
+    ) -> R {
+        let mut buf = Buffer::filled(screen, untouched_cell());
+        let result = render(&mut buf);
+        let untouched = untouched_cell();
+        if buf.content.iter().any(|cell| *cell != untouched) {
+            self.overlays.push(Overlay {
+                layer,
+                covered: opaque_rects(&buf),
+                content: OverlayContent::Captured(buf),
+            });
+        }
+        result
+    }
+
+    /// Number of registered overlays.
+    pub fn len(&self) -> usize {
+        self.overlays.len()
+    }
+
+    /// Whether no overlays are registered.
+    pub fn is_empty(&self) -> bool {
+        self.overlays.is_empty()
+    }
+
+    /// Areas covered by registered overlays.
+    ///
+    /// Rects from different overlays may overlap.
+    pub fn covered_areas(&self) -> Vec<Rect> {
+        self.overlays
+            .iter()
+            .flat_map(|overlay| overlay.covered.iter().copied())
+            .collect()
+    }
+
+    /// Whether the cell at (`x`, `y`) is hidden by an overlay.
+    pub fn is_covered(&self, x: u16, y: u16) -> bool {
+        self.overlays
+            .iter()
+            .flat_map(|overlay| &overlay.covered)
+            .any(|rect| rect.contains((x, y).into()))
+    }
+
+    /// Whether every cell of `area` is hidden by overlays.
+    ///
+    /// Drawing into such an area is wasted work.
+    pub fn covers(&self, area: Rect) -> bool {
+        !area.is_empty() && area.positions().all(|pos| self.is_covered(pos.x, pos.y))
+    }
+
+    /// Draw all overlays into the frame, lowest layer first.
+    pub fn render(mut self, frame: &mut Frame) {
+        // Stable sort keeps registration order within a layer
+        self.overlays.sort_by_key(|overlay| overlay.layer);
+        for overlay in self.overlays {
+            match overlay.content {
+                OverlayContent::Captured(captured) => blit(&captured, frame.buffer_mut()),
+                OverlayContent::Deferred(draw) => {
+                    for &area in &overlay.covered {
+                        Clear.render(area, frame.buffer_mut());
+                    }
+                    draw(frame);
+                }
+            }
+        }
+    }
+}
+
+fn untouched_cell() -> Cell {
+    let mut cell = Cell::default();
+    cell.set_symbol(UNTOUCHED);
+    cell
+}
+
+/// Rows of cells whose symbol was drawn, merged into rects.
+fn opaque_rects(buf: &Buffer) -> Vec<Rect> {
+    let area = buf.area;
+    let mut rects: Vec<Rect> = Vec::new();
+    for y in area.top()..area.bottom() {
+        let mut x = area.left();
+        while x < area.right() {
+            if buf[(x, y)].symbol() == UNTOUCHED {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < area.right() && buf[(x, y)].symbol() != UNTOUCHED {
+                x += 1;
+            }
+            let run = Rect::new(start, y, x - start, 1);
+            // Extend the rect from the previous row when the run lines up
+            match rects
+                .iter_mut()
+                .find(|r| r.x == run.x && r.width == run.width && r.bottom() == y)
+            {
+                Some(rect) => rect.height += 1,
+                None => rects.push(run),
+            }
+        }
+    }
+    rects
+}
+
+/// Copy the cells a capture drew to into `buf`.
+fn blit(captured: &Buffer, buf: &mut Buffer) {
+    let untouched = untouched_cell();
+    let area = captured.area.intersection(buf.area);
+    for pos in area.positions() {
+        let cell = &captured[pos];
+        if *cell == untouched {
+            continue;
+        }
+        if cell.symbol() == UNTOUCHED {
+            buf[pos].set_style(style_patch(cell, &untouched));
+        } else {
+            buf[pos] = cell.clone();
+        }
+    }
+}
+
+/// The style changes made to an otherwise untouched cell.
+fn style_patch(cell: &Cell, untouched: &Cell) -> Style {
+    let mut style = Style::default().add_modifier(cell.modifier);
+    if cell.fg != untouched.fg {
+        style = style.fg(cell.fg);
+    }
+    if cell.bg != untouched.bg {
+        style = style.bg(cell.bg);
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend, style::Color, widgets::Paragraph};
+
+    fn draw(overlays: OverlayManager<'_>) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Paragraph::new("..........\n..........\n.........."),
+                    frame.area(),
+                );
+                overlays.render(frame);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+    }
+
+    #[test]
+    fn test_layers_render_in_order() {
+        let screen = Rect::new(0, 0, 10, 3);
+        let mut overlays = OverlayManager::new();
+        // Registered first, but the toast layer draws last
+        overlays.capture(OverlayLayer::Toast, screen, |buf| {
+            buf.set_string(2, 1, "TT", Style::default());
+        });
+        overlays.capture(OverlayLayer::Dropdown, screen, |buf| {
+            Clear.render(Rect::new(1, 0, 4, 2), buf);
+            buf.set_string(1, 1, "DDDD", Style::default());
+        });
+
+        let buf = draw(overlays);
+        assert_eq!(row(&buf, 0), ".    .....");
+        assert_eq!(row(&buf, 1), ".DTTD.....");
+        assert_eq!(row(&buf, 2), "..........");
+    }
+
+    #[test]
+    fn test_deferred_overlay_clears_its_area() {
+        let mut overlays = OverlayManager::new();
+        overlays.push(OverlayLayer::Dialog, Rect::new(0, 1, 4, 1), |frame| {
+            frame.render_widget(Paragraph::new("ab"), Rect::new(0, 1, 4, 1));
+        });
+
+        let buf = draw(overlays);
+        assert_eq!(row(&buf, 1), "ab  ......");
+    }
+
+    #[test]
+    fn test_covered_areas() {
+        let screen = Rect::new(0, 0, 10, 3);
+        let mut overlays = OverlayManager::new();
+        assert!(overlays.is_empty());
+        overlays.capture(OverlayLayer::Menu, screen, |buf| {
+            Clear.render(Rect::new(2, 0, 3, 2), buf);
+            // Style-only changes are translucent
+            buf.set_style(Rect::new(0, 2, 10, 1), Style::default().bg(Color::Blue));
+        });
+
+        assert_eq!(overlays.len(), 1);
+        assert_eq!(overlays.covered_areas(), vec![Rect::new(2, 0, 3, 2)]);
+        assert!(overlays.is_covered(4, 1));
+        assert!(!overlays.is_covered(4, 2));
+        assert!(overlays.covers(Rect::new(2, 0, 2, 2)));
+        assert!(!overlays.covers(Rect::new(1, 0, 2, 2)));
+
+        let buf = draw(overlays);
+        assert_eq!(row(&buf, 0), "..   .....");
+        assert_eq!(row(&buf, 2), "..........");
+        assert_eq!(buf[(0, 2)].bg, Color::Blue);
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+    }
+}
@@ -0,0 +1,183 @@
+//! Paging - Shared Home/End/PageUp/PageDown keys for lists and viewers
+//!
+//! `ListPicker`, `TreeView`, `FileExplorer`, `LogViewer`, and the open
+//! `Select` dropdown all map the same keys to the same moves:
+//!
+//! | Key | Move |
+//! |-----|------|
+//! | Home / End | First / last row |
+//! | PageUp / PageDown | One page, sized by the state's [`PageSize`] |
+//! | Ctrl+U / Ctrl+D | Half a viewport, regardless of [`PageSize`] |
+//!
+//! Pages are measured against the viewport height the caller passes in (or
+//! the `visible_height` the caller stores on the state), which should be
+//! the height the component was last rendered with.
+//!
+//! # Selection and scroll
+//!
+//! Components with a selection (list picker, tree view, file explorer,
+//! select dropdown) move the *selection* and let the scroll follow it: the
+//! selection moves by a page, then the viewport scrolls just enough to keep
+//! it visible. Viewers without a selection (log viewer) move the scroll
+//! offset itself by the same amounts. Moves clamp at both ends, so PageUp
+//! on the first row or PageDown on the last one does nothing.
+//!
+//! # Example
+//!
+//! ```rust
+//! use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+//! use ratatui_interact::state::{PageMove, PageSize};
+//!
+//! let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+//! let page_down = KeyEvent::from(KeyCode::PageDown);
+//!
+//! // 100 rows in a 20-row viewport
+//! let half = PageMove::from_key(&ctrl_d).unwrap();
+//! assert_eq!(half.apply(0, 100, 20, PageSize::Full), 10);
+//!
+//! let page = PageMove::from_key(&page_down).unwrap();
+//! assert_eq!(page.apply(0, 100, 20, PageSize::Full), 20);
+//! assert_eq!(page.apply(0, 100, 20, PageSize::Fixed(5)), 5);
+//! assert_eq!(page.apply(95, 100, 20, PageSize::Full), 99);
+//! ```
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// How far PageUp and PageDown move.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PageSize {
+    /// A whole viewport.
+    #[default]
+    Full,
+    /// Half a viewport, vim style.
+    Half,
+    /// A fixed number of rows.
+    Fixed(usize),
+}
+
+impl PageSize {
+    /// Rows moved per page in a viewport of `viewport` rows (at least one).
+    pub fn rows(self, viewport: usize) -> usize {
+        match self {
+            PageSize::Full => viewport,
+            PageSize::Half => viewport / 2,
+            PageSize::Fixed(rows) => rows,
+        }
+        .max(1)
+    }
+}
+
+/// A paging move bound to a navigation key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PageMove {
+    /// To the first row (Home).
+    First,
+    /// To the last row (End).
+    Last,
+    /// Up one page (PageUp).
+    PageUp,
+    /// Down one page (PageDown).
+    PageDown,
+    /// Up half a viewport (Ctrl+U).
+    HalfPageUp,
+    /// Down half a viewport (Ctrl+D).
+    HalfPageDown,
+}
+
+impl PageMove {
+    /// The move bound to `key`, if any.
+    pub fn from_key(key: &KeyEvent) -> Option<Self> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Home => Some(PageMove::First),
+            KeyCode::End => Some(PageMove::Last),
+            KeyCode::PageUp if !ctrl => Some(PageMove::PageUp),
+            KeyCode::PageDown if !ctrl => Some(PageMove::PageDown),
+            KeyCode::Char('u') if ctrl => Some(PageMove::HalfPageUp),
+            KeyCode::Char('d') if ctrl => Some(PageMove::HalfPageDown),
+            _ => None,
+        }
+    }
+
+    /// The row reached from `index` among `len` rows, shown in a viewport of
+    /// `viewport` rows.
+    pub fn apply(self, index: usize, len: usize, viewport: usize, page: PageSize) -> usize {
+        let Some(last) = len.checked_sub(1) else {
+            return 0;
+        };
+        let index = index.min(last);
+        match self {
+            PageMove::First => 0,
+            PageMove::Last => last,
+            PageMove::PageUp => index.saturating_sub(page.rows(viewport)),
+            PageMove::PageDown => (index + page.rows(viewport)).min(last),
+            PageMove::HalfPageUp => index.saturating_sub(PageSize::Half.rows(viewport)),
+            PageMove::HalfPageDown => (index + PageSize::Half.rows(viewport)).min(last),
+        }
+    }
+
+    /// Whether the move goes towards the end.
+    pub fn is_forward(self) -> bool {
+        matches!(
+            self,
+            PageMove::Last | PageMove::PageDown | PageMove::HalfPageDown
+        )
+    }
+
+    /// `(chord, description)` pairs for the paging keys, for help displays.
+    pub fn describe_keys() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("Home/End", "First/last"),
+            ("PgUp/PgDn", "Page up/down"),
+            ("Ctrl+U/Ctrl+D", "Half page up/down"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size_rows() {
+        assert_eq!(PageSize::Full.rows(20), 20);
+        assert_eq!(PageSize::Half.rows(20), 10);
+        assert_eq!(PageSize::Fixed(3).rows(20), 3);
+        // Always moves at least one row
+        assert_eq!(PageSize::Half.rows(1), 1);
+        assert_eq!(PageSize::Full.rows(0), 1);
+    }
+
+    #[test]
+    fn test_apply_clamps_at_both_ends() {
+        assert_eq!(PageMove::PageUp.apply(0, 50, 10, PageSize::Full), 0);
+        assert_eq!(PageMove::PageDown.apply(49, 50, 10, PageSize::Full), 49);
+        assert_eq!(PageMove::HalfPageDown.apply(47, 50, 10, PageSize::Full), 49);
+        assert_eq!(PageMove::HalfPageUp.apply(3, 50, 10, PageSize::Full), 0);
+        assert_eq!(PageMove::Last.apply(0, 50, 10, PageSize::Full), 49);
+        assert_eq!(PageMove::Last.apply(0, 0, 10, PageSize::Full), 0);
+        // Ctrl+D ignores the page size
+        assert_eq!(
+            PageMove::HalfPageDown.apply(0, 50, 10, PageSize::Fixed(1)),
+            5
+        );
+    }
+
+    #[test]
+    fn test_from_key() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        assert_eq!(PageMove::from_key(&ctrl('d')), Some(PageMove::HalfPageDown));
+        assert_eq!(PageMove::from_key(&ctrl('u')), Some(PageMove::HalfPageUp));
+        assert_eq!(
+            PageMove::from_key(&KeyEvent::from(KeyCode::Home)),
+            Some(PageMove::First)
+        );
+        assert_eq!(
+            PageMove::from_key(&KeyEvent::from(KeyCode::Char('d'))),
+            None
+        );
+        // Ctrl+PgUp/PgDn are left for tab switching
+        let ctrl_page = KeyEvent::new(KeyCode::PageDown, KeyModifiers::CONTROL);
+        assert_eq!(PageMove::from_key(&ctrl_page), None);
+    }
+}
@@ -0,0 +1,143 @@
+//! Safe Area - The part of the frame overlays may cover
+//!
+//! Apps often keep a status bar, tab strip, or command line pinned to an
+//! edge of the frame. Popups positioned against the full frame area will
+//! happily draw over those bars. A [`SafeArea`] is the frame area with the
+//! reserved edges taken off; overlay placement functions that take a screen
+//! `Rect` also accept a `SafeArea`, so dialogs center within it, menus and
+//! dropdowns flip to stay inside it, and toasts stack inside it.
+//!
+//! `SafeArea::new(frame.area())` reserves nothing, which is the same as
+//! passing `frame.area()` directly.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::layout::Rect;
+//! use ratatui_interact::state::SafeArea;
+//!
+//! // One-row status bar at the bottom, two-row tab strip at the top
+//! let safe = SafeArea::new(Rect::new(0, 0, 80, 24))
+//!     .reserve_top(2)
+//!     .reserve_bottom(1);
+//!
+//! assert_eq!(safe.area(), Rect::new(0, 2, 80, 21));
+//! ```
+
+use ratatui::layout::Rect;
+
+/// The region of the frame that overlays may be placed in.
+///
+/// Converts to and from [`Rect`], so it can be passed wherever overlay
+/// placement takes a screen area.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SafeArea {
+    area: Rect,
+}
+
+impl SafeArea {
+    /// Create a safe area covering `area` with no reserved edges.
+    pub fn new(area: Rect) -> Self {
+        Self { area }
+    }
+
+    /// Reserve `rows` at the top edge.
+    pub fn reserve_top(self, rows: u16) -> Self {
+        self.reserve(rows, 0, 0, 0)
+    }
+
+    /// Reserve `rows` at the bottom edge.
+    pub fn reserve_bottom(self, rows: u16) -> Self {
+        self.reserve(0, rows, 0, 0)
+    }
+
+    /// Reserve `cols` at the left edge.
+    pub fn reserve_left(self, cols: u16) -> Self {
+        self.reserve(0, 0, cols, 0)
+    }
+
+    /// Reserve `cols` at the right edge.
+    pub fn reserve_right(self, cols: u16) -> Self {
+        self.reserve(0, 0, 0, cols)
+    }
+
+    /// Reserve cells on each edge at once.
+    ///
+    /// Reservations larger than the remaining area shrink it to zero size
+    /// rather than wrapping.
+    pub fn reserve(self, top: u16, bottom: u16, left: u16, right: u16) -> Self {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.area;
+        let top = top.min(height);
+        let left = left.min(width);
+        Self {
+            area: Rect::new(
+                x + left,
+                y + top,
+                (width - left).saturating_sub(right),
+                (height - top).saturating_sub(bottom),
+            ),
+        }
+    }
+
+    /// The area overlays may cover.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+impl From<Rect> for SafeArea {
+    fn from(area: Rect) -> Self {
+        Self::new(area)
+    }
+}
+
+impl From<SafeArea> for Rect {
+    fn from(safe: SafeArea) -> Self {
+        safe.area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_reservation_is_full_area() {
+        let area = Rect::new(3, 4, 80, 24);
+        assert_eq!(SafeArea::new(area).area(), area);
+        assert_eq!(Rect::from(SafeArea::from(area)), area);
+    }
+
+    #[test]
+    fn test_reserve_edges() {
+        let safe = SafeArea::new(Rect::new(0, 0, 80, 24))
+            .reserve_top(1)
+            .reserve_bottom(2)
+            .reserve_left(3)
+            .reserve_right(4);
+        assert_eq!(safe.area(), Rect::new(3, 1, 73, 21));
+        assert_eq!(
+            SafeArea::new(Rect::new(0, 0, 80, 24))
+                .reserve(1, 2, 3, 4)
+                .area(),
+            safe.area()
+        );
+    }
+
+    #[test]
+    fn test_oversized_reservation_saturates() {
+        let safe = SafeArea::new(Rect::new(0, 0, 10, 5))
+            .reserve_bottom(4)
+            .reserve_top(3);
+        assert_eq!(safe.area().height, 0);
+        assert!(safe.area().y <= 5);
+
+        let safe = SafeArea::new(Rect::new(0, 0, 10, 5)).reserve_left(20);
+        assert_eq!(safe.area(), Rect::new(10, 0, 0, 5));
+    }
+}
@@ -0,0 +1,146 @@
+//! Scroll memory - Restore scroll positions when content is swapped back in
+//!
+//! Viewers that switch between several documents (files in a diff tool, log
+//! sources, tree roots) reset their scroll and selection on every swap.
+//! [`ScrollMemory`] keeps a snapshot per content key so switching back
+//! restores where the user was. Component states provide `snapshot()` and
+//! `apply_snapshot()`; applying clamps the snapshot to the current content,
+//! so a snapshot taken of a longer document is always safe to apply.
+//!
+//! [`DiffViewerState::set_diff_keyed`](crate::components::DiffViewerState::set_diff_keyed)
+//! and [`LogViewerState::set_content_keyed`](crate::components::LogViewerState::set_content_keyed)
+//! do this automatically.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::ListPickerState;
+//! use ratatui_interact::state::ScrollMemory;
+//!
+//! let mut memory = ScrollMemory::new(16);
+//! let mut picker = ListPickerState::new(50);
+//! picker.selected_index = 42;
+//!
+//! // Switch to another list, remembering this one
+//! memory.save("fruits", picker.snapshot());
+//!
+//! // Switch back later: the list shrank, so the selection is clamped
+//! let mut picker = ListPickerState::new(10);
+//! if let Some(snapshot) = memory.restore(&"fruits") {
+//!     picker.apply_snapshot(snapshot);
+//! }
+//! assert_eq!(picker.selected_index, 9);
+//! ```
+
+use std::collections::VecDeque;
+
+/// Snapshots keyed by content identity, evicting the least recently used.
+#[derive(Debug, Clone)]
+pub struct ScrollMemory<K, S> {
+    /// Most recently used last
+    entries: VecDeque<(K, S)>,
+    capacity: usize,
+}
+
+impl<K, S> Default for ScrollMemory<K, S> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl<K, S> ScrollMemory<K, S> {
+    /// Number of keys kept by [`Default`].
+    pub const DEFAULT_CAPACITY: usize = 32;
+
+    /// Create a memory keeping at most `capacity` keys (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Maximum number of keys kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of stored snapshots.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no snapshots are stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forget all snapshots.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K: PartialEq, S> ScrollMemory<K, S> {
+    /// Store `snapshot` under `key`, replacing any previous one.
+    ///
+    /// Drops the least recently used snapshot when over capacity.
+    pub fn save(&mut self, key: K, snapshot: S) {
+        self.remove(&key);
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, snapshot));
+    }
+
+    /// The snapshot stored under `key`, marking it as recently used.
+    pub fn restore(&mut self, key: &K) -> Option<&S> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+        let entry = self.entries.remove(index)?;
+        self.entries.push_back(entry);
+        self.entries.back().map(|(_, snapshot)| snapshot)
+    }
+
+    /// Remove and return the snapshot stored under `key`.
+    pub fn remove(&mut self, key: &K) -> Option<S> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+        self.entries.remove(index).map(|(_, snapshot)| snapshot)
+    }
+
+    /// Check if a snapshot is stored under `key`.
+    pub fn contains(&self, key: &K) -> bool {
+        self.entries.iter().any(|(k, _)| k == key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_restore() {
+        let mut memory = ScrollMemory::new(4);
+        memory.save("a", 1);
+        memory.save("b", 2);
+        memory.save("a", 3);
+        assert_eq!(memory.len(), 2);
+        assert_eq!(memory.restore(&"a"), Some(&3));
+        assert_eq!(memory.restore(&"c"), None);
+        assert_eq!(memory.remove(&"b"), Some(2));
+        assert!(!memory.contains(&"b"));
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut memory = ScrollMemory::new(2);
+        memory.save("a", 1);
+        memory.save("b", 2);
+        // Restoring "a" makes "b" the oldest
+        memory.restore(&"a");
+        memory.save("c", 3);
+        assert!(memory.contains(&"a"));
+        assert!(!memory.contains(&"b"));
+        assert!(memory.contains(&"c"));
+        assert_eq!(ScrollMemory::<u8, u8>::new(0).capacity(), 1);
+    }
+}
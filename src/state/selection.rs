@@ -0,0 +1,88 @@
+//! Sticky selection - Keep the cursor on the same item across data refreshes
+//!
+//! Lists backed by live data (process lists, directory listings) are rebuilt
+//! every few seconds. Resetting the selection index on each rebuild yanks the
+//! cursor away from what the user was looking at. [`sync_selection`] maps
+//! the old selection onto the new items by key instead, and keeps the item
+//! at the same viewport row.
+//!
+//! [`ListPickerState::sync_items`](crate::components::ListPickerState::sync_items),
+//! [`TreeViewState::sync_nodes`](crate::components::TreeViewState::sync_nodes),
+//! and [`FileExplorerState::sync_entries`](crate::components::FileExplorerState::sync_entries)
+//! build on it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::state::sync_selection;
+//!
+//! let old = ["init", "sshd", "bash"];
+//! let new = ["cron", "init", "sshd", "bash"];
+//!
+//! // "sshd" was selected on viewport row 1
+//! let (selected, scroll) = sync_selection(&old, &new, 1, 0);
+//! assert_eq!(new[selected], "sshd");
+//! assert_eq!(selected - scroll, 1);
+//! ```
+
+/// Map a selection from `old_keys` onto `new_keys`.
+///
+/// Returns the new `(selected, scroll)`. If the selected key survives, the
+/// selection follows it and the scroll shifts so it stays on the same
+/// viewport row when possible. Otherwise the selection falls back to the
+/// nearest index. Both are 0 when `new_keys` is empty.
+pub fn sync_selection<K: PartialEq>(
+    old_keys: &[K],
+    new_keys: &[K],
+    selected: usize,
+    scroll: usize,
+) -> (usize, usize) {
+    if new_keys.is_empty() {
+        return (0, 0);
+    }
+
+    let index = old_keys
+        .get(selected)
+        .and_then(|key| new_keys.iter().position(|k| k == key))
+        .unwrap_or_else(|| selected.min(new_keys.len() - 1));
+    let row = selected.saturating_sub(scroll);
+    (index, index.saturating_sub(row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_above_selection() {
+        let old = ["a", "b", "c", "d"];
+        let new = ["x", "y", "a", "b", "c", "d"];
+        assert_eq!(sync_selection(&old, &new, 3, 1), (5, 3));
+    }
+
+    #[test]
+    fn test_selected_item_removed() {
+        let old = ["a", "b", "c", "d"];
+        let new = ["a", "b", "d"];
+        assert_eq!(sync_selection(&old, &new, 2, 0), (2, 0));
+
+        // Removing the last item clamps to the new end
+        let new = ["a", "b", "c"];
+        assert_eq!(sync_selection(&old, &new, 3, 1), (2, 0));
+    }
+
+    #[test]
+    fn test_complete_reorder() {
+        let old = ["a", "b", "c", "d"];
+        let new = ["d", "c", "b", "a"];
+        assert_eq!(sync_selection(&old, &new, 0, 0), (3, 3));
+        assert_eq!(sync_selection(&old, &new, 3, 2), (0, 0));
+    }
+
+    #[test]
+    fn test_empty_new_items() {
+        let old = ["a", "b"];
+        let new: [&str; 0] = [];
+        assert_eq!(sync_selection(&old, &new, 1, 1), (0, 0));
+    }
+}
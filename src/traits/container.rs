@@ -50,17 +50,22 @@ use ratatui::{Frame, layout::Rect};
 /// Result of handling an event.
 ///
 /// Used by containers to indicate how an event was processed.
+///
+/// The action payload defaults to [`ContainerAction`], but composite
+/// components can bubble their own richer action type (for example a
+/// `DiffViewerAction` from a viewer embedded in a dialog) by using
+/// `EventResult<MyAction>` and converting with [`EventResult::map`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum EventResult {
+pub enum EventResult<A = ContainerAction> {
     /// Event was consumed, no further handling needed.
     Consumed,
     /// Event was not handled, should propagate to parent.
     NotHandled,
     /// Event triggered a specific action.
-    Action(ContainerAction),
+    Action(A),
 }
 
-impl EventResult {
+impl<A> EventResult<A> {
     /// Check if the event was consumed (either Consumed or Action).
     pub fn is_consumed(&self) -> bool {
         !matches!(self, EventResult::NotHandled)
@@ -72,12 +77,48 @@ impl EventResult {
     }
 
     /// Get the action if this is an Action result.
-    pub fn action(&self) -> Option<&ContainerAction> {
+    pub fn action(&self) -> Option<&A> {
+        match self {
+            EventResult::Action(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Consume the result and return the action, if any.
+    pub fn into_action(self) -> Option<A> {
         match self {
             EventResult::Action(action) => Some(action),
             _ => None,
         }
     }
+
+    /// Convert the action payload, keeping `Consumed` and `NotHandled` as-is.
+    ///
+    /// Useful for bubbling a child's action up through a parent that wraps
+    /// it in its own action type.
+    pub fn map<B, F>(self, f: F) -> EventResult<B>
+    where
+        F: FnOnce(A) -> B,
+    {
+        match self {
+            EventResult::Consumed => EventResult::Consumed,
+            EventResult::NotHandled => EventResult::NotHandled,
+            EventResult::Action(action) => EventResult::Action(f(action)),
+        }
+    }
+
+    /// Return this result if it was consumed, otherwise evaluate `f`.
+    ///
+    /// Lets a parent forward unhandled events to a child handler.
+    pub fn or_else<F>(self, f: F) -> EventResult<A>
+    where
+        F: FnOnce() -> EventResult<A>,
+    {
+        match self {
+            EventResult::NotHandled => f(),
+            other => other,
+        }
+    }
 }
 
 /// Actions that containers can emit.
@@ -263,21 +304,21 @@ mod tests {
 
     #[test]
     fn test_event_result_consumed() {
-        assert!(EventResult::Consumed.is_consumed());
+        assert!(EventResult::<ContainerAction>::Consumed.is_consumed());
         assert!(EventResult::Action(ContainerAction::Close).is_consumed());
-        assert!(!EventResult::NotHandled.is_consumed());
+        assert!(!EventResult::<ContainerAction>::NotHandled.is_consumed());
     }
 
     #[test]
     fn test_event_result_action() {
-        assert!(!EventResult::Consumed.is_action());
-        assert!(!EventResult::NotHandled.is_action());
+        assert!(!EventResult::<ContainerAction>::Consumed.is_action());
+        assert!(!EventResult::<ContainerAction>::NotHandled.is_action());
         assert!(EventResult::Action(ContainerAction::Close).is_action());
 
         let result = EventResult::Action(ContainerAction::Submit);
         assert_eq!(result.action(), Some(&ContainerAction::Submit));
 
-        assert_eq!(EventResult::Consumed.action(), None);
+        assert_eq!(EventResult::<ContainerAction>::Consumed.action(), None);
     }
 
     #[test]
@@ -296,6 +337,35 @@ mod tests {
         assert_eq!(ContainerAction::Close.custom_name(), None);
     }
 
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum ChildAction {
+        Apply,
+    }
+
+    #[test]
+    fn test_event_result_custom_payload() {
+        let result: EventResult<ChildAction> = EventResult::Action(ChildAction::Apply);
+        assert!(result.is_action());
+        assert_eq!(result.action(), Some(&ChildAction::Apply));
+
+        let mapped = result.map(|_| ContainerAction::Submit);
+        assert_eq!(mapped, EventResult::Action(ContainerAction::Submit));
+        assert_eq!(mapped.into_action(), Some(ContainerAction::Submit));
+
+        let consumed: EventResult<ChildAction> = EventResult::Consumed;
+        assert_eq!(consumed.map(|_| 1), EventResult::<i32>::Consumed);
+    }
+
+    #[test]
+    fn test_event_result_or_else() {
+        let forwarded: EventResult = EventResult::NotHandled.or_else(|| EventResult::Consumed);
+        assert_eq!(forwarded, EventResult::Consumed);
+
+        let kept = EventResult::Action(ContainerAction::Close)
+            .or_else(|| EventResult::Action(ContainerAction::Submit));
+        assert_eq!(kept, EventResult::Action(ContainerAction::Close));
+    }
+
     struct TestContainer {
         preferred_width: u16,
         preferred_height: u16,
@@ -135,6 +135,38 @@ pub trait Focusable {
     }
 }
 
+/// Focus state shared by component state structs.
+///
+/// Implemented by [`InputState`](crate::components::InputState),
+/// [`TextAreaState`](crate::components::TextAreaState),
+/// [`CheckBoxState`](crate::components::CheckBoxState),
+/// [`ButtonState`](crate::components::ButtonState), and
+/// [`SelectState`](crate::components::SelectState), so
+/// [`FocusManager::sync_from_vec`](crate::state::FocusManager::sync_from_vec)
+/// can update a whole form's `focused` flags in one call.
+pub trait FocusableWidget {
+    /// Returns the focus ID this state is registered under.
+    fn focus_id(&self) -> FocusId;
+
+    /// Returns true if this state currently has focus.
+    fn is_focused(&self) -> bool;
+
+    /// Set the focus state.
+    fn set_focused(&mut self, focused: bool);
+}
+
+/// Component state that owns a popup, which should close when the
+/// component loses focus.
+///
+/// Implemented by [`SelectState`](crate::components::SelectState),
+/// [`ContextMenuState`](crate::components::ContextMenuState), and
+/// [`MenuBarState`](crate::components::MenuBarState). See
+/// [`close_popups_on_focus_change`](crate::state::close_popups_on_focus_change).
+pub trait PopupOwner {
+    /// Close the popup, returning whether one was open.
+    fn on_focus_lost(&mut self) -> bool;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,57 @@
+//! Menu action trait
+//!
+//! [`TypedContextMenu`](crate::components::TypedContextMenu) and
+//! [`TypedMenuBar`](crate::components::TypedMenuBar) are generic over the
+//! action an item triggers. With an enum as the action type, selecting an
+//! item hands back the enum value, so a typo in an ID is a compile error
+//! instead of a match arm that never fires. `String` implements the trait,
+//! and [`ContextMenu`](crate::components::ContextMenu),
+//! [`MenuBar`](crate::components::MenuBar), and their item and action types
+//! are aliases for the `String` versions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_interact::components::{TypedContextMenuAction, TypedContextMenuItem};
+//! use ratatui_interact::traits::MenuAction;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum EditAction {
+//!     Copy,
+//!     Paste,
+//! }
+//!
+//! impl MenuAction for EditAction {
+//!     fn id(&self) -> &str {
+//!         match self {
+//!             EditAction::Copy => "copy",
+//!             EditAction::Paste => "paste",
+//!         }
+//!     }
+//! }
+//!
+//! let items = vec![
+//!     TypedContextMenuItem::from_action(EditAction::Copy, "Copy"),
+//!     TypedContextMenuItem::from_action(EditAction::Paste, "Paste"),
+//! ];
+//! assert_eq!(items[0].get_action(), Some(&EditAction::Copy));
+//! assert_eq!(items[1].id(), Some("paste"));
+//!
+//! let action = TypedContextMenuAction::Select(EditAction::Copy);
+//! match action {
+//!     TypedContextMenuAction::Select(EditAction::Copy) => { /* copy */ }
+//!     _ => {}
+//! }
+//! ```
+
+/// An action triggered by a menu item.
+pub trait MenuAction: Clone + PartialEq {
+    /// Stable string ID for this action, e.g. for logging or config files.
+    fn id(&self) -> &str;
+}
+
+impl MenuAction for String {
+    fn id(&self) -> &str {
+        self
+    }
+}
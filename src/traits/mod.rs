@@ -6,14 +6,19 @@
 //! # Traits
 //!
 //! - [`Focusable`] - For components that can receive keyboard focus
+//! - [`FocusableWidget`] - For component states synced from a focus manager
+//! - [`PopupOwner`] - For component states whose popup closes on focus loss
 //! - [`Clickable`] - For components that respond to mouse clicks
 //! - [`Container`] - For components that manage child components
 //! - [`PopupContainer`] - Extension of Container for popup dialogs
+//! - [`MenuAction`] - For the actions menu items trigger
 
 mod clickable;
 mod container;
 mod focusable;
+mod menu_action;
 
 pub use clickable::{ClickRegion, ClickRegionRegistry, Clickable};
 pub use container::{Container, ContainerAction, EventResult, PopupContainer};
-pub use focusable::{FocusId, Focusable};
+pub use focusable::{FocusId, Focusable, FocusableWidget, PopupOwner};
+pub use menu_action::MenuAction;
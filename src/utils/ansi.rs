@@ -252,6 +252,82 @@ pub fn render_markdown_to_lines(markdown: &str) -> Vec<Line<'static>> {
         .collect()
 }
 
+/// Convert markdown text to styled Lines and locate its headings.
+///
+/// Returns the rendered lines plus the index of the rendered line for each
+/// heading, in document order. Pass the indices to
+/// [`ParagraphExt::headings`](crate::components::ParagraphExt::headings) for
+/// heading navigation.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::utils::ansi::render_markdown_with_headings;
+///
+/// let (lines, headings) = render_markdown_with_headings("# Intro\ntext\n## Usage\nmore");
+/// assert_eq!(headings.len(), 2);
+/// assert!(headings[0] < headings[1] && headings[1] < lines.len());
+/// ```
+pub fn render_markdown_with_headings(markdown: &str) -> (Vec<Line<'static>>, Vec<usize>) {
+    let lines = render_markdown_to_lines(markdown);
+
+    // Match each source heading to the next rendered line containing its text
+    let mut headings = Vec::new();
+    let mut next = 0;
+    for heading in markdown_heading_texts(markdown) {
+        let found = lines[next..].iter().position(|line| {
+            let text: String = line
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect();
+            strip_emphasis(&text).contains(&heading)
+        });
+        if let Some(offset) = found {
+            headings.push(next + offset);
+            next += offset + 1;
+        }
+    }
+
+    (lines, headings)
+}
+
+/// Remove inline emphasis and code markers.
+fn strip_emphasis(text: &str) -> String {
+    text.chars()
+        .filter(|c| !matches!(c, '*' | '_' | '`'))
+        .collect()
+}
+
+/// Plain text of each ATX heading, skipping fenced code blocks.
+fn markdown_heading_texts(markdown: &str) -> Vec<String> {
+    let mut headings = Vec::new();
+    let mut in_fence = false;
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if !(1..=6).contains(&level) {
+            continue;
+        }
+        let rest = &trimmed[level..];
+        if !rest.starts_with(' ') {
+            continue;
+        }
+        let text = strip_emphasis(rest.trim().trim_end_matches('#').trim());
+        if !text.is_empty() {
+            headings.push(text);
+        }
+    }
+    headings
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,4 +370,21 @@ mod tests {
         let spans = parse_ansi_to_spans("");
         assert_eq!(spans.len(), 1);
     }
+
+    #[test]
+    fn test_markdown_headings() {
+        let markdown = "# Title\nIntro\n```\n# not a heading\n```\n## **Bold** part\n#nospace";
+        let (lines, headings) = render_markdown_with_headings(markdown);
+        assert_eq!(headings.len(), 2);
+        let text = |idx: usize| {
+            let text: String = lines[idx]
+                .spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect();
+            strip_emphasis(&text)
+        };
+        assert!(text(headings[0]).contains("Title"));
+        assert!(text(headings[1]).contains("Bold part"));
+    }
 }
@@ -20,11 +20,19 @@
 //! assert_eq!(clean, "carriage return");
 //! ```
 
+use std::borrow::Cow;
 use std::sync::LazyLock;
 
+use ratatui::layout::Alignment;
 use regex::Regex;
 use unicode_width::UnicodeWidthStr;
 
+/// Spaces that [`spaces`] borrows from
+const SPACES: &str = match std::str::from_utf8(&[b' '; 256]) {
+    Ok(spaces) => spaces,
+    Err(_) => panic!("spaces are valid UTF-8"),
+};
+
 /// Regex to match ANSI escape sequences (colors, cursor movement, etc.)
 static ANSI_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     // Matches:
@@ -128,6 +136,57 @@ pub fn truncate_to_width(s: &str, max_width: usize) -> String {
     format!("{}...", &clean[..end_idx])
 }
 
+/// Truncate a string to fit within a maximum display width, eliding the middle.
+///
+/// Keeps the start and end of the string, which is useful for paths and
+/// values whose suffix matters.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::utils::display::truncate_middle;
+///
+/// assert_eq!(truncate_middle("Hello", 10), "Hello");
+/// assert_eq!(truncate_middle("/usr/local/bin/tool", 11), "/usr...tool");
+/// ```
+pub fn truncate_middle(s: &str, max_width: usize) -> String {
+    let clean = clean_for_display(s);
+    if clean.width() <= max_width {
+        return clean;
+    }
+    if max_width <= 3 {
+        return ".".repeat(max_width);
+    }
+
+    let available = max_width - 3;
+    let head_target = available.div_ceil(2);
+    let tail_target = available / 2;
+
+    let mut head = String::new();
+    let mut head_width = 0;
+    for ch in clean.chars() {
+        let ch_width = unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+        if head_width + ch_width > head_target {
+            break;
+        }
+        head_width += ch_width;
+        head.push(ch);
+    }
+
+    let mut tail = Vec::new();
+    let mut tail_width = 0;
+    for ch in clean.chars().rev() {
+        let ch_width = unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+        if tail_width + ch_width > tail_target {
+            break;
+        }
+        tail_width += ch_width;
+        tail.push(ch);
+    }
+
+    format!("{}...{}", head, tail.into_iter().rev().collect::<String>())
+}
+
 /// Pad a string to a specific display width with spaces.
 ///
 /// If the string is already wider than target, it's returned as-is.
@@ -153,6 +212,52 @@ pub fn pad_to_width(s: &str, target_width: usize) -> String {
     format!("{}{}", s, " ".repeat(padding))
 }
 
+/// A run of `count` spaces for padding spans.
+///
+/// Borrows from a static buffer for up to 256 spaces, so render code can pad
+/// every row without allocating.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::utils::display::spaces;
+///
+/// assert_eq!(spaces(3), "   ");
+/// assert_eq!(spaces(300).len(), 300);
+/// ```
+pub fn spaces(count: usize) -> Cow<'static, str> {
+    match SPACES.get(..count) {
+        Some(spaces) => Cow::Borrowed(spaces),
+        None => Cow::Owned(" ".repeat(count)),
+    }
+}
+
+/// Borrow up to `len` characters of `s`, starting at character `start`.
+///
+/// Equivalent to `s.chars().skip(start).take(len).collect::<String>()`
+/// without allocating.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::utils::display::char_slice;
+///
+/// assert_eq!(char_slice("héllo wörld", 1, 4), "éllo");
+/// assert_eq!(char_slice("héllo", 3, 10), "lo");
+/// assert_eq!(char_slice("héllo", 10, 2), "");
+/// ```
+pub fn char_slice(s: &str, start: usize, len: usize) -> &str {
+    let mut boundaries = s.char_indices().map(|(i, _)| i).chain([s.len()]);
+    let Some(begin) = boundaries.nth(start) else {
+        return "";
+    };
+    let end = match len {
+        0 => begin,
+        len => boundaries.nth(len - 1).unwrap_or(s.len()),
+    };
+    &s[begin..end]
+}
+
 /// Format a byte count as a human-readable file size.
 ///
 /// # Example
@@ -182,6 +287,247 @@ pub fn format_size(bytes: u64) -> String {
     }
 }
 
+/// Format an elapsed duration in milliseconds as a short relative time.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::utils::display::format_relative;
+///
+/// assert_eq!(format_relative(500), "just now");
+/// assert_eq!(format_relative(42_000), "42s ago");
+/// assert_eq!(format_relative(5 * 60_000), "5m ago");
+/// assert_eq!(format_relative(3 * 3_600_000), "3h ago");
+/// assert_eq!(format_relative(2 * 86_400_000), "2d ago");
+/// ```
+pub fn format_relative(elapsed_ms: i64) -> String {
+    const SECOND: i64 = 1000;
+    const MINUTE: i64 = 60 * SECOND;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    if elapsed_ms < SECOND {
+        "just now".to_string()
+    } else if elapsed_ms < MINUTE {
+        format!("{}s ago", elapsed_ms / SECOND)
+    } else if elapsed_ms < HOUR {
+        format!("{}m ago", elapsed_ms / MINUTE)
+    } else if elapsed_ms < DAY {
+        format!("{}h ago", elapsed_ms / HOUR)
+    } else {
+        format!("{}d ago", elapsed_ms / DAY)
+    }
+}
+
+/// Lay out `rows` as aligned text columns.
+///
+/// Column widths come from the widest cell (by display width) in each
+/// column. Cells are aligned by `align`, with missing entries left-aligned,
+/// and joined by `separator`. Short rows are padded with empty cells, so
+/// every returned row has the same width.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Alignment;
+/// use ratatui_interact::utils::display::format_table;
+///
+/// let rows = vec![
+///     vec!["main.rs".to_string(), "1.2 KB".to_string()],
+///     vec!["lib.rs".to_string(), "18 B".to_string()],
+/// ];
+/// let lines = format_table(&rows, &[Alignment::Left, Alignment::Right], "  ");
+/// assert_eq!(lines, ["main.rs  1.2 KB", "lib.rs     18 B"]);
+/// ```
+pub fn format_table(rows: &[Vec<String>], align: &[Alignment], separator: &str) -> Vec<String> {
+    let widths = column_widths(rows);
+    rows.iter()
+        .map(|row| format_row(row, &widths, align, separator))
+        .collect()
+}
+
+/// Lay out `rows` under `header`, with a dashed underline after the header.
+///
+/// All columns are left-aligned; see [`format_table`] and [`TableBuilder`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_interact::utils::display::format_table_with_header;
+///
+/// let header = vec!["Key".to_string(), "Action".to_string()];
+/// let rows = vec![vec!["Ctrl+S".to_string(), "Save".to_string()]];
+/// let lines = format_table_with_header(header, &rows, " | ");
+/// assert_eq!(lines, ["Key    | Action", "------ | ------", "Ctrl+S | Save  "]);
+/// ```
+pub fn format_table_with_header(
+    header: Vec<String>,
+    rows: &[Vec<String>],
+    separator: &str,
+) -> Vec<String> {
+    TableBuilder::new()
+        .headers(header)
+        .rows(rows.iter().cloned())
+        .separator(separator)
+        .build()
+}
+
+/// Fluent builder for [`format_table`] output.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Alignment;
+/// use ratatui_interact::utils::display::TableBuilder;
+///
+/// let lines = TableBuilder::new()
+///     .headers(["Name", "Size"])
+///     .row(["a-very-long-file-name.txt", "12 KB"])
+///     .alignments([Alignment::Left, Alignment::Right])
+///     .max_col_width(10)
+///     .build();
+/// assert_eq!(lines[0], "Name         Size");
+/// assert_eq!(lines[1], "----------  -----");
+/// assert_eq!(lines[2], "a-very-...  12 KB");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableBuilder {
+    /// Body rows
+    pub rows: Vec<Vec<String>>,
+    /// Header row, underlined when present
+    pub headers: Option<Vec<String>>,
+    /// Per-column alignment; missing columns are left-aligned
+    pub alignments: Vec<Alignment>,
+    /// Cells wider than this are truncated with "..."
+    pub max_col_width: Option<usize>,
+    /// Text between columns
+    pub separator: String,
+}
+
+impl Default for TableBuilder {
+    fn default() -> Self {
+        Self {
+            rows: Vec::new(),
+            headers: None,
+            alignments: Vec::new(),
+            max_col_width: None,
+            separator: "  ".to_string(),
+        }
+    }
+}
+
+impl TableBuilder {
+    /// Create an empty table separated by two spaces.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the header row.
+    pub fn headers<S: Into<String>>(mut self, headers: impl IntoIterator<Item = S>) -> Self {
+        self.headers = Some(headers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Append a body row.
+    pub fn row<S: Into<String>>(mut self, cells: impl IntoIterator<Item = S>) -> Self {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Append body rows.
+    pub fn rows(mut self, rows: impl IntoIterator<Item = Vec<String>>) -> Self {
+        self.rows.extend(rows);
+        self
+    }
+
+    /// Set the per-column alignment.
+    pub fn alignments(mut self, alignments: impl IntoIterator<Item = Alignment>) -> Self {
+        self.alignments = alignments.into_iter().collect();
+        self
+    }
+
+    /// Truncate cells wider than `width`.
+    pub fn max_col_width(mut self, width: usize) -> Self {
+        self.max_col_width = Some(width);
+        self
+    }
+
+    /// Set the text between columns.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Lay out the header, its underline, and the body rows.
+    pub fn build(&self) -> Vec<String> {
+        let clip = |row: &Vec<String>| -> Vec<String> {
+            match self.max_col_width {
+                Some(max) => row
+                    .iter()
+                    .map(|cell| truncate_to_width(cell, max))
+                    .collect(),
+                None => row.clone(),
+            }
+        };
+        let header = self.headers.as_ref().map(clip);
+        let rows: Vec<Vec<String>> = self.rows.iter().map(clip).collect();
+
+        let mut all = rows.clone();
+        all.extend(header.clone());
+        let widths = column_widths(&all);
+
+        let mut lines = Vec::with_capacity(rows.len() + 2);
+        if let Some(header) = header {
+            lines.push(format_row(
+                &header,
+                &widths,
+                &self.alignments,
+                &self.separator,
+            ));
+            let underline: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+            lines.push(underline.join(&self.separator));
+        }
+        lines.extend(
+            rows.iter()
+                .map(|row| format_row(row, &widths, &self.alignments, &self.separator)),
+        );
+        lines
+    }
+}
+
+/// Widest cell of each column
+fn column_widths(rows: &[Vec<String>]) -> Vec<usize> {
+    let mut widths = Vec::new();
+    for row in rows {
+        if widths.len() < row.len() {
+            widths.resize(row.len(), 0);
+        }
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+    widths
+}
+
+/// One row padded to `widths`, each cell aligned by `align`
+fn format_row(row: &[String], widths: &[usize], align: &[Alignment], separator: &str) -> String {
+    let cells: Vec<String> = widths
+        .iter()
+        .enumerate()
+        .map(|(i, &width)| {
+            let cell = row.get(i).map(String::as_str).unwrap_or("");
+            let padding = width.saturating_sub(cell.width());
+            let left = match align.get(i).copied().unwrap_or(Alignment::Left) {
+                Alignment::Left => 0,
+                Alignment::Center => padding / 2,
+                Alignment::Right => padding,
+            };
+            format!("{}{}{}", spaces(left), cell, spaces(padding - left))
+        })
+        .collect();
+    cells.join(separator)
+}
+
 /// Calculate the display width of a string.
 ///
 /// This is a convenience wrapper around `unicode_width::UnicodeWidthStr::width()`.
@@ -228,12 +574,41 @@ mod tests {
         assert_eq!(truncate_to_width("this is a long string", 10), "this is...");
     }
 
+    #[test]
+    fn test_truncate_middle() {
+        assert_eq!(truncate_middle("short", 10), "short");
+        assert_eq!(truncate_middle("abcdefghijkl", 9), "abc...jkl");
+        assert_eq!(truncate_middle("abcdefghijkl", 8), "abc...kl");
+        assert_eq!(truncate_middle("abcdefghijkl", 2), "..");
+        assert_eq!(truncate_middle("你好世界你好", 7), "你...好");
+    }
+
     #[test]
     fn test_pad_to_width() {
         assert_eq!(pad_to_width("hi", 5), "hi   ");
         assert_eq!(pad_to_width("hello", 3), "hello");
     }
 
+    #[test]
+    fn test_spaces_borrows_short_runs() {
+        assert!(matches!(spaces(0), Cow::Borrowed("")));
+        assert!(matches!(spaces(256), Cow::Borrowed(_)));
+        assert!(matches!(spaces(257), Cow::Owned(_)));
+        assert!(spaces(257).chars().all(|c| c == ' '));
+    }
+
+    #[test]
+    fn test_char_slice_matches_skip_take() {
+        let text = "a你b好 ünïcödé";
+        let count = text.chars().count();
+        for start in 0..count + 2 {
+            for len in 0..count + 2 {
+                let expected: String = text.chars().skip(start).take(len).collect();
+                assert_eq!(char_slice(text, start, len), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(0), "0 B");
@@ -242,4 +617,50 @@ mod tests {
         assert_eq!(format_size(1048576), "1.0 MB");
         assert_eq!(format_size(1073741824), "1.0 GB");
     }
+
+    #[test]
+    fn test_format_relative() {
+        assert_eq!(format_relative(-5), "just now");
+        assert_eq!(format_relative(999), "just now");
+        assert_eq!(format_relative(1_000), "1s ago");
+        assert_eq!(format_relative(59_999), "59s ago");
+        assert_eq!(format_relative(60_000), "1m ago");
+        assert_eq!(format_relative(3_600_000), "1h ago");
+        assert_eq!(format_relative(86_400_000), "1d ago");
+    }
+
+    fn strings(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[test]
+    fn test_format_table_alignment_and_wide_chars() {
+        let rows = vec![
+            strings(&["你好", "1", "x"]),
+            strings(&["abc", "100", "long"]),
+        ];
+        let align = [Alignment::Left, Alignment::Right, Alignment::Center];
+        assert_eq!(
+            format_table(&rows, &align, "|"),
+            ["你好|  1| x  ", "abc |100|long"]
+        );
+    }
+
+    #[test]
+    fn test_format_table_ragged_rows() {
+        let rows = vec![strings(&["a"]), strings(&["bb", "c"])];
+        assert_eq!(format_table(&rows, &[], " "), ["a   ", "bb c"]);
+        assert!(format_table(&[], &[], " ").is_empty());
+    }
+
+    #[test]
+    fn test_table_builder_header_and_max_width() {
+        let lines = TableBuilder::new()
+            .headers(["Key", "Description"])
+            .row(["q", "Quit the application now"])
+            .max_col_width(8)
+            .separator(" ")
+            .build();
+        assert_eq!(lines, ["Key Descr...", "--- --------", "q   Quit ..."]);
+    }
 }
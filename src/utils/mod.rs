@@ -14,15 +14,18 @@ pub mod display;
 pub mod mouse_capture;
 pub mod view_copy;
 
-pub use ansi::{parse_ansi_to_spans, render_markdown_to_lines};
+pub use ansi::{parse_ansi_to_spans, render_markdown_to_lines, render_markdown_with_headings};
 pub use clipboard::{
     ClipboardResult, copy_lines_to_clipboard, copy_to_clipboard, get_from_clipboard,
     is_clipboard_available,
 };
-pub use display::{clean_for_display, format_size, pad_to_width, truncate_to_width};
+pub use display::{
+    TableBuilder, char_slice, clean_for_display, format_relative, format_size, format_table,
+    format_table_with_header, pad_to_width, spaces, truncate_middle, truncate_to_width,
+};
 pub use mouse_capture::{
-    MouseCaptureState, disable_mouse_capture, enable_mouse_capture, set_mouse_capture,
-    toggle_mouse_capture,
+    MouseCaptureCoordinator, MouseCaptureState, disable_mouse_capture, enable_mouse_capture,
+    set_mouse_capture, toggle_mouse_capture,
 };
 pub use view_copy::{
     ExitStrategy, ViewCopyAction, ViewCopyConfig, ViewCopyMode, clear_main_screen,
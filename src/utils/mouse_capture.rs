@@ -21,13 +21,19 @@
 //!     println!("Select text with your mouse!");
 //! }
 //! ```
+//!
+//! For finer-grained control, [`MouseCaptureCoordinator`] keeps capture on
+//! globally but releases it while the pointer is over registered passthrough
+//! regions (such as a log viewer in copy mode).
 
 use std::io::{self, Write};
+use std::time::{Duration, Instant};
 
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableMouseCapture, EnableMouseCapture, MouseEvent, MouseEventKind},
     execute,
 };
+use ratatui::layout::Rect;
 
 /// State for mouse capture management
 ///
@@ -162,6 +168,232 @@ pub fn set_mouse_capture<W: Write>(
     Ok(())
 }
 
+/// Coordinates mouse capture with per-frame passthrough regions.
+///
+/// While capture is on, registered passthrough regions behave as if capture
+/// were off, so the terminal's native text selection works there. The
+/// coordinator watches `Moved` events and releases capture when the pointer
+/// enters a region, debouncing changes so crossing a border does not thrash.
+///
+/// Call [`clear_regions`](Self::clear_regions) at the start of each frame,
+/// register regions while rendering, feed mouse and key events in, and call
+/// [`apply`](Self::apply) once per loop iteration.
+///
+/// # Terminal quirks
+///
+/// - Once capture is released the app receives no mouse events, so it cannot
+///   see the pointer leave the region. Capture is restored on the next key
+///   press, or after [`with_passthrough_timeout`](Self::with_passthrough_timeout)
+///   if one is set. Keep the timeout generous: re-enabling capture cancels a
+///   selection that is still being dragged.
+/// - Some emulators ignore an enable request unless capture was explicitly
+///   disabled first. [`with_full_cycle`](Self::with_full_cycle) sends a
+///   disable/enable pair whenever capture is restored.
+/// - Enabling "any event" tracking is required to receive `Moved` events at
+///   all; crossterm's `EnableMouseCapture` does this.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use crossterm::event::{KeyModifiers, MouseEvent, MouseEventKind};
+/// use ratatui::layout::Rect;
+/// use ratatui_interact::utils::MouseCaptureCoordinator;
+///
+/// let mut coordinator = MouseCaptureCoordinator::new(true);
+/// coordinator.register_passthrough(Rect::new(0, 0, 40, 10));
+///
+/// let moved = MouseEvent {
+///     kind: MouseEventKind::Moved,
+///     column: 5,
+///     row: 5,
+///     modifiers: KeyModifiers::NONE,
+/// };
+/// let now = Instant::now();
+/// coordinator.handle_mouse(&moved, now);
+///
+/// let mut out = Vec::new();
+/// coordinator.apply(&mut out, now + Duration::from_millis(200)).unwrap();
+/// assert!(!coordinator.is_capture_enabled());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MouseCaptureCoordinator {
+    state: MouseCaptureState,
+    capture_outside: bool,
+    regions: Vec<Rect>,
+    pointer_inside: bool,
+    pending_since: Option<Instant>,
+    released_at: Option<Instant>,
+    lock: Option<bool>,
+    debounce: Duration,
+    passthrough_timeout: Option<Duration>,
+    full_cycle: bool,
+}
+
+impl MouseCaptureCoordinator {
+    /// Create a coordinator.
+    ///
+    /// `capture_enabled` is both the current terminal state and the desired
+    /// state outside passthrough regions.
+    pub fn new(capture_enabled: bool) -> Self {
+        Self {
+            state: MouseCaptureState::new(capture_enabled),
+            capture_outside: capture_enabled,
+            regions: Vec::new(),
+            pointer_inside: false,
+            pending_since: None,
+            released_at: None,
+            lock: None,
+            debounce: Duration::from_millis(150),
+            passthrough_timeout: None,
+            full_cycle: false,
+        }
+    }
+
+    /// Set how long the pointer must stay on one side of a region border
+    /// before capture changes (default 150ms).
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Restore capture after it has been released for this long.
+    pub fn with_passthrough_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.passthrough_timeout = timeout;
+        self
+    }
+
+    /// Send a full disable/enable cycle when restoring capture.
+    pub fn with_full_cycle(mut self, full_cycle: bool) -> Self {
+        self.full_cycle = full_cycle;
+        self
+    }
+
+    /// Remove all passthrough regions. Call at the start of each frame.
+    pub fn clear_regions(&mut self) {
+        self.regions.clear();
+    }
+
+    /// Register a passthrough region for this frame.
+    pub fn register_passthrough(&mut self, area: Rect) {
+        if area.width > 0 && area.height > 0 {
+            self.regions.push(area);
+        }
+    }
+
+    /// Passthrough regions registered this frame.
+    pub fn regions(&self) -> &[Rect] {
+        &self.regions
+    }
+
+    /// Set whether capture should be on outside passthrough regions.
+    pub fn set_capture_outside(&mut self, enabled: bool) {
+        self.capture_outside = enabled;
+    }
+
+    /// Force capture on or off regardless of regions until [`unlock`](Self::unlock).
+    pub fn lock(&mut self, enabled: bool) {
+        self.lock = Some(enabled);
+    }
+
+    /// Release the override lock.
+    pub fn unlock(&mut self) {
+        self.lock = None;
+    }
+
+    /// Check if the override lock is active.
+    pub fn is_locked(&self) -> bool {
+        self.lock.is_some()
+    }
+
+    /// Check if the pointer was last seen over a passthrough region.
+    pub fn is_pointer_in_passthrough(&self) -> bool {
+        self.pointer_inside
+    }
+
+    /// The effective terminal capture state.
+    pub fn state(&self) -> MouseCaptureState {
+        self.state
+    }
+
+    /// Check if mouse capture is currently enabled in the terminal.
+    pub fn is_capture_enabled(&self) -> bool {
+        self.state.is_enabled()
+    }
+
+    /// The capture state the coordinator is converging to.
+    pub fn target(&self) -> bool {
+        self.lock
+            .unwrap_or(self.capture_outside && !self.pointer_inside)
+    }
+
+    /// Feed a mouse event.
+    ///
+    /// Returns `true` if the event was a `Moved` event over a passthrough
+    /// region; such events should not be handled by the app.
+    pub fn handle_mouse(&mut self, mouse: &MouseEvent, now: Instant) -> bool {
+        if mouse.kind != MouseEventKind::Moved {
+            return false;
+        }
+        let inside = self
+            .regions
+            .iter()
+            .any(|r| r.contains((mouse.column, mouse.row).into()));
+        if inside != self.pointer_inside {
+            self.pointer_inside = inside;
+            self.pending_since = Some(now);
+        }
+        inside
+    }
+
+    /// Feed a key event.
+    ///
+    /// The pointer position is unknown while capture is released, so any key
+    /// press restores capture.
+    pub fn handle_key(&mut self, now: Instant) {
+        if self.pointer_inside {
+            self.pointer_inside = false;
+            self.pending_since = Some(now);
+        }
+    }
+
+    /// Apply any pending capture change to the terminal.
+    ///
+    /// Returns `true` if the capture state changed.
+    pub fn apply<W: Write>(&mut self, writer: &mut W, now: Instant) -> io::Result<bool> {
+        if let (Some(timeout), Some(released)) = (self.passthrough_timeout, self.released_at)
+            && self.pointer_inside
+            && now.saturating_duration_since(released) >= timeout
+        {
+            self.pointer_inside = false;
+            self.pending_since = None;
+        }
+
+        let target = self.target();
+        if target == self.state.is_enabled() {
+            self.pending_since = None;
+            return Ok(false);
+        }
+
+        // Lock and explicit state changes apply immediately
+        let debounced = self.lock.is_some()
+            || self
+                .pending_since
+                .is_none_or(|since| now.saturating_duration_since(since) >= self.debounce);
+        if !debounced {
+            return Ok(false);
+        }
+
+        if target && self.full_cycle {
+            disable_mouse_capture(writer)?;
+        }
+        set_mouse_capture(writer, &mut self.state, target)?;
+        self.pending_since = None;
+        self.released_at = (!target).then_some(now);
+        Ok(true)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,4 +497,132 @@ mod tests {
         assert!(!buffer.is_empty());
         assert!(state.is_copy_mode());
     }
+
+    fn moved(column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Moved,
+            column,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_coordinator_passthrough_debounced() {
+        let mut out = Vec::new();
+        let start = Instant::now();
+        let mut coordinator = MouseCaptureCoordinator::new(true);
+        coordinator.register_passthrough(Rect::new(10, 10, 20, 5));
+
+        assert!(!coordinator.handle_mouse(&moved(0, 0), start));
+        assert!(coordinator.handle_mouse(&moved(15, 12), start));
+        assert!(!coordinator.target());
+
+        // Not yet debounced
+        assert!(
+            !coordinator
+                .apply(&mut out, start + Duration::from_millis(50))
+                .unwrap()
+        );
+        assert!(coordinator.is_capture_enabled());
+
+        assert!(
+            coordinator
+                .apply(&mut out, start + Duration::from_millis(150))
+                .unwrap()
+        );
+        assert!(!coordinator.is_capture_enabled());
+        assert!(!out.is_empty());
+    }
+
+    #[test]
+    fn test_coordinator_no_thrash_on_border() {
+        let mut out = Vec::new();
+        let start = Instant::now();
+        let mut coordinator = MouseCaptureCoordinator::new(true);
+        coordinator.register_passthrough(Rect::new(10, 10, 20, 5));
+
+        // Cross in and straight back out within the debounce window
+        coordinator.handle_mouse(&moved(10, 10), start);
+        coordinator.handle_mouse(&moved(9, 10), start + Duration::from_millis(20));
+        assert!(
+            !coordinator
+                .apply(&mut out, start + Duration::from_millis(500))
+                .unwrap()
+        );
+        assert!(coordinator.is_capture_enabled());
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_coordinator_key_restores_capture() {
+        let mut out = Vec::new();
+        let start = Instant::now();
+        let mut coordinator = MouseCaptureCoordinator::new(true).with_debounce(Duration::ZERO);
+        coordinator.register_passthrough(Rect::new(0, 0, 10, 10));
+        coordinator.handle_mouse(&moved(1, 1), start);
+        coordinator.apply(&mut out, start).unwrap();
+        assert!(!coordinator.is_capture_enabled());
+
+        coordinator.handle_key(start);
+        assert!(coordinator.apply(&mut out, start).unwrap());
+        assert!(coordinator.is_capture_enabled());
+    }
+
+    #[test]
+    fn test_coordinator_passthrough_timeout() {
+        let mut out = Vec::new();
+        let start = Instant::now();
+        let mut coordinator = MouseCaptureCoordinator::new(true)
+            .with_debounce(Duration::ZERO)
+            .with_passthrough_timeout(Some(Duration::from_secs(5)));
+        coordinator.register_passthrough(Rect::new(0, 0, 10, 10));
+        coordinator.handle_mouse(&moved(1, 1), start);
+        coordinator.apply(&mut out, start).unwrap();
+        assert!(!coordinator.is_capture_enabled());
+
+        assert!(
+            !coordinator
+                .apply(&mut out, start + Duration::from_secs(1))
+                .unwrap()
+        );
+        assert!(
+            coordinator
+                .apply(&mut out, start + Duration::from_secs(5))
+                .unwrap()
+        );
+        assert!(coordinator.is_capture_enabled());
+    }
+
+    #[test]
+    fn test_coordinator_lock_and_full_cycle() {
+        let mut out = Vec::new();
+        let start = Instant::now();
+        let mut coordinator = MouseCaptureCoordinator::new(true).with_full_cycle(true);
+        coordinator.register_passthrough(Rect::new(0, 0, 10, 10));
+
+        coordinator.lock(true);
+        coordinator.handle_mouse(&moved(1, 1), start);
+        assert!(
+            !coordinator
+                .apply(&mut out, start + Duration::from_secs(1))
+                .unwrap()
+        );
+        assert!(coordinator.is_capture_enabled());
+
+        coordinator.lock(false);
+        assert!(coordinator.apply(&mut out, start).unwrap());
+        assert!(!coordinator.is_capture_enabled());
+
+        // Restoring capture sends disable + enable
+        coordinator.unlock();
+        coordinator.handle_key(start);
+        out.clear();
+        coordinator.lock(true);
+        coordinator.apply(&mut out, start).unwrap();
+        let mut expected = Vec::new();
+        disable_mouse_capture(&mut expected).unwrap();
+        enable_mouse_capture(&mut expected).unwrap();
+        assert_eq!(out, expected);
+    }
 }
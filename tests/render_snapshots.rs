@@ -0,0 +1,183 @@
+//! Buffer snapshots of the menu, diff, and list render paths
+//!
+//! The snapshots in `tests/snapshots/` were recorded with the render code
+//! from before those paths were changed to borrow instead of allocate, so
+//! any difference in symbols or styles shows up as a failure here. The
+//! scenarios stay clear of output changed on purpose since then: icons on
+//! disabled context menu items, and the side-by-side split of an even
+//! width. Set `UPDATE_SNAPSHOTS=1` to re-record them after an intended
+//! output change.
+
+use std::path::PathBuf;
+
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer, layout::Rect, widgets::Widget};
+use ratatui_interact::components::{
+    ContextMenu, ContextMenuItem, ContextMenuState, DiffViewer, DiffViewerState, ListPicker,
+    ListPickerState, Menu, MenuBar, MenuBarItem, MenuBarState,
+};
+
+/// Compare `buffer` with `tests/snapshots/<name>.txt`
+fn assert_snapshot(name: &str, buffer: &Buffer) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots"]
+        .iter()
+        .collect::<PathBuf>()
+        .join(format!("{name}.txt"));
+    let actual = format!("{buffer:?}\n");
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("reading {}: {err}", path.display()));
+    assert!(
+        actual == expected,
+        "{name} differs from its snapshot\n--- expected\n{expected}\n--- actual\n{actual}"
+    );
+}
+
+/// Unified diff text with `hunks` hunks of 7 lines each
+fn diff_text(hunks: usize) -> String {
+    let mut text = String::from("--- a/src/lib.rs\n+++ b/src/lib.rs\n");
+    for hunk in 0..hunks {
+        let start = hunk * 10 + 1;
+        text.push_str(&format!("@@ -{start},4 +{start},5 @@ fn hunk_{hunk}()\n"));
+        text.push_str("     let value = compute(input, options);\n");
+        text.push_str("-    if value > threshold {\n");
+        text.push_str("+    if value > threshold && !options.allow_large {\n");
+        text.push_str("+        return Err(Error::TooLarge { value, threshold });\n");
+        text.push_str("-    }\n");
+        text.push_str("     Ok(value)\n");
+    }
+    text
+}
+
+fn render_diff(state: &DiffViewerState) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 73, 16));
+    DiffViewer::new(state)
+        .title("lib.rs")
+        .render(buf.area, &mut buf);
+    buf
+}
+
+#[test]
+fn menu_bar_dropdown_snapshot() {
+    let menus = vec![
+        Menu::new("File").items(vec![
+            MenuBarItem::action("new", "New").shortcut("Ctrl+N"),
+            MenuBarItem::action("open", "Open...").shortcut("Ctrl+O"),
+            MenuBarItem::separator(),
+            MenuBarItem::submenu("Recent", vec![MenuBarItem::action("recent1", "notes.txt")]),
+            MenuBarItem::action("save", "Save")
+                .shortcut("Ctrl+S")
+                .enabled(false),
+            MenuBarItem::action("quit", "Quit").shortcut("Ctrl+Q"),
+        ]),
+        Menu::new("Edit"),
+        Menu::new("Help").enabled(false),
+    ];
+    let mut state = MenuBarState::new();
+    state.open_menu(0);
+    state.next_item(&menus[0].items);
+    state.next_item(&menus[0].items);
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+    terminal
+        .draw(|frame| {
+            MenuBar::new(&menus, &state).render_stateful(frame, frame.area());
+        })
+        .unwrap();
+    assert_snapshot("menu_bar_dropdown", terminal.backend().buffer());
+}
+
+fn render_context_menu(items: &[ContextMenuItem]) -> Buffer {
+    let mut state = ContextMenuState::new();
+    state.open_at(3, 2);
+    state.highlight_next(items);
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+    terminal
+        .draw(|frame| {
+            ContextMenu::new(items, &state).render_stateful(frame, frame.area());
+        })
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn context_menu_snapshot() {
+    let items = vec![
+        ContextMenuItem::action("copy", "Copy")
+            .icon("⧉")
+            .shortcut("Ctrl+C"),
+        ContextMenuItem::action("paste", "Paste")
+            .icon("⎘")
+            .shortcut("Ctrl+V"),
+        ContextMenuItem::separator(),
+        ContextMenuItem::submenu("Share", vec![ContextMenuItem::action("email", "Email")])
+            .icon("↗"),
+        ContextMenuItem::action("delete", "Delete").icon("✗"),
+    ];
+    assert_snapshot("context_menu", &render_context_menu(&items));
+
+    // Disabled items, without icons
+    let items = vec![
+        ContextMenuItem::action("undo", "Undo").shortcut("Ctrl+Z"),
+        ContextMenuItem::action("redo", "Redo")
+            .shortcut("Ctrl+Y")
+            .enabled(false),
+        ContextMenuItem::separator(),
+        ContextMenuItem::submenu("Format", vec![ContextMenuItem::action("bold", "Bold")])
+            .enabled(false),
+    ];
+    assert_snapshot("context_menu_disabled", &render_context_menu(&items));
+}
+
+#[test]
+fn diff_viewer_unified_snapshot() {
+    let mut state = DiffViewerState::from_unified_diff(&diff_text(40));
+    // Start mid-hunk, well past the first hunks
+    state.scroll_y = 101;
+    assert_snapshot("diff_viewer_unified", &render_diff(&state));
+
+    state.scroll_x = 6;
+    assert_snapshot("diff_viewer_unified_scrolled", &render_diff(&state));
+}
+
+#[test]
+fn diff_viewer_side_by_side_snapshot() {
+    let mut state = DiffViewerState::from_unified_diff(&diff_text(40));
+    state.toggle_view_mode();
+    state.scroll_y = 101;
+    assert_snapshot("diff_viewer_side_by_side", &render_diff(&state));
+}
+
+#[test]
+fn diff_viewer_search_snapshot() {
+    let mut state = DiffViewerState::from_unified_diff(&diff_text(40));
+    state.start_search();
+    state.search.query = "allow_large".into();
+    state.update_search();
+    state.next_match();
+    assert_snapshot("diff_viewer_search", &render_diff(&state));
+}
+
+#[test]
+fn list_picker_snapshot() {
+    let entries: Vec<String> = (0..30).map(|i| format!("List entry {i}")).collect();
+    let mut state = ListPickerState::new(entries.len());
+    state.select(12);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    ListPicker::new(&entries, &state)
+        .title("Entries")
+        .render(buf.area, &mut buf);
+    assert_snapshot("list_picker", &buf);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    ListPicker::new(&entries, &state)
+        .search("entry 1")
+        .render(buf.area, &mut buf);
+    assert_snapshot("list_picker_search", &buf);
+}